@@ -0,0 +1,92 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_checked_fibonacci);
+    register_unsigned_demos!(runner, demo_checked_fibonacci_pair);
+    register_unsigned_demos!(runner, demo_checked_lucas);
+
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci);
+    register_unsigned_benches!(runner, benchmark_checked_fibonacci_pair);
+    register_unsigned_benches!(runner, benchmark_checked_lucas);
+}
+
+fn demo_checked_fibonacci<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("F_{n} = {:?}", T::checked_fibonacci(n));
+    }
+}
+
+fn demo_checked_fibonacci_pair<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("(F_{n}, F_({n} - 1)) = {:?}", T::checked_fibonacci_pair(n));
+    }
+}
+
+fn demo_checked_lucas<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("L_{n} = {:?}", T::checked_lucas(n));
+    }
+}
+
+fn benchmark_checked_fibonacci<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci(n)))],
+    );
+}
+
+fn benchmark_checked_fibonacci_pair<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_fibonacci_pair(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_fibonacci_pair(n)))],
+    );
+}
+
+fn benchmark_checked_lucas<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}::checked_lucas(u64)", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(T::checked_lucas(n)))],
+    );
+}
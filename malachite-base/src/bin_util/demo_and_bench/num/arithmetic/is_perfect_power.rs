@@ -0,0 +1,131 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_express_as_power_unsigned);
+    register_signed_demos!(runner, demo_express_as_power_signed);
+    register_unsigned_demos!(runner, demo_is_perfect_power_unsigned);
+    register_signed_demos!(runner, demo_is_perfect_power_signed);
+
+    register_unsigned_benches!(runner, benchmark_express_as_power_unsigned);
+    register_signed_benches!(runner, benchmark_express_as_power_signed);
+    register_unsigned_benches!(runner, benchmark_is_perfect_power_unsigned);
+    register_signed_benches!(runner, benchmark_is_perfect_power_signed);
+}
+
+fn demo_express_as_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        println!("{}.express_as_power() = {:?}", n, n.express_as_power());
+    }
+}
+
+fn demo_express_as_power_signed<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in signed_gen::<T>().get(gm, config).take(limit) {
+        println!("({}).express_as_power() = {:?}", n, n.express_as_power());
+    }
+}
+
+fn demo_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>().get(gm, config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{n} is a perfect power");
+        } else {
+            println!("{n} is not a perfect power");
+        }
+    }
+}
+
+fn demo_is_perfect_power_signed<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in signed_gen::<T>().get(gm, config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{n} is a perfect power");
+        } else {
+            println!("{n} is not a perfect power");
+        }
+    }
+}
+
+fn benchmark_express_as_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.express_as_power()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.express_as_power()))],
+    );
+}
+
+fn benchmark_express_as_power_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.express_as_power()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.express_as_power()))],
+    );
+}
+
+fn benchmark_is_perfect_power_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
+
+fn benchmark_is_perfect_power_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.is_perfect_power()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.is_perfect_power()))],
+    );
+}
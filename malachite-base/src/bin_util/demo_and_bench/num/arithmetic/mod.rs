@@ -20,8 +20,10 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     floor::register(runner);
     gcd::register(runner);
+    is_perfect_power::register(runner);
     is_power_of_2::register(runner);
     kronecker_symbol::register(runner);
     lcm::register(runner);
@@ -30,6 +32,7 @@ pub(crate) fn register(runner: &mut Runner) {
     log_base_power_of_2::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_add::register(runner);
     mod_mul::register(runner);
     mod_neg::register(runner);
@@ -48,6 +51,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
     neg::register(runner);
@@ -123,8 +127,10 @@ mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod floor;
 mod gcd;
+mod is_perfect_power;
 mod is_power_of_2;
 mod kronecker_symbol;
 mod lcm;
@@ -134,6 +140,7 @@ mod log_base_power_of_2;
 mod mod_add;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
 mod mod_neg;
 mod mod_op;
@@ -151,6 +158,7 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
 mod neg;
@@ -0,0 +1,39 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::pair_2_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_mod_sqrt);
+    register_unsigned_benches!(runner, benchmark_mod_sqrt);
+}
+
+fn demo_mod_sqrt<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in unsigned_pair_gen_var_16::<T>().get(gm, config).take(limit) {
+        if let Some(root) = x.mod_sqrt(m) {
+            println!("{root}² ≡ {x} mod {m}");
+        } else {
+            println!("{x} is not a square mod {m}");
+        }
+    }
+}
+
+fn benchmark_mod_sqrt<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.mod_sqrt({})", T::NAME, T::NAME),
+        BenchmarkType::Single,
+        unsigned_pair_gen_var_16::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(x, m)| no_out!(x.mod_sqrt(m)))],
+    );
+}
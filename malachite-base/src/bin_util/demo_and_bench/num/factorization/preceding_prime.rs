@@ -0,0 +1,75 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::{signed_bit_bucketer, unsigned_bit_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_preceding_prime_unsigned);
+    register_signed_demos!(runner, demo_preceding_prime_signed);
+
+    register_unsigned_benches!(runner, benchmark_preceding_prime_unsigned);
+    register_signed_benches!(runner, benchmark_preceding_prime_signed);
+}
+
+fn demo_preceding_prime_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for n in unsigned_gen::<T>()
+        .get(gm, config)
+        .filter(|&n| n > T::TWO)
+        .take(limit)
+    {
+        println!("{}.preceding_prime() = {}", n, n.preceding_prime());
+    }
+}
+
+fn demo_preceding_prime_signed<T: PrimitiveSigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in signed_gen::<T>()
+        .get(gm, config)
+        .filter(|&n| n > T::TWO)
+        .take(limit)
+    {
+        println!("{}.preceding_prime() = {}", n, n.preceding_prime());
+    }
+}
+
+fn benchmark_preceding_prime_unsigned<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.preceding_prime()", T::NAME),
+        BenchmarkType::Single,
+        unsigned_gen::<T>().get(gm, config).filter(|&n| n > T::TWO),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.preceding_prime()))],
+    );
+}
+
+fn benchmark_preceding_prime_signed<T: PrimitiveSigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.preceding_prime()", T::NAME),
+        BenchmarkType::Single,
+        signed_gen::<T>().get(gm, config).filter(|&n| n > T::TWO),
+        gm.name(),
+        limit,
+        file_name,
+        &signed_bit_bucketer(),
+        &mut [("Malachite", &mut |n| no_out!(n.preceding_prime()))],
+    );
+}
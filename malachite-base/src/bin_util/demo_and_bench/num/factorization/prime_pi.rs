@@ -0,0 +1,41 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::bench::bucketers::unsigned_bit_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_unsigned_demos!(runner, demo_prime_pi);
+
+    register_unsigned_benches!(runner, benchmark_prime_pi_algorithms);
+}
+
+fn demo_prime_pi<T: PrimitiveUnsigned>(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5::<T>().get(gm, config).take(limit) {
+        println!("{}.prime_pi() = {}", n, n.prime_pi());
+    }
+}
+
+fn benchmark_prime_pi_algorithms<T: PrimitiveUnsigned>(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        &format!("{}.prime_pi()", T::NAME),
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5::<T>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_bit_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(n.prime_pi())),
+            ("using primes_less_than_or_equal_to", &mut |n| {
+                no_out!(T::primes_less_than_or_equal_to(&n).count())
+            }),
+        ],
+    );
+}
@@ -1,7 +1,7 @@
 use crate::chars::crement::increment_char;
 use crate::chars::CharType;
 use crate::comparison::traits::Min;
-use std::ops::RangeInclusive;
+use core::ops::RangeInclusive;
 
 /// Generates all ASCII [`char`]s, in ascending order.
 ///
@@ -25,7 +25,7 @@ use std::ops::RangeInclusive;
 /// );
 /// ```
 pub const fn ascii_chars_increasing() -> RangeInclusive<char> {
-    char::MIN..='\u{7f}'
+    <char as Min>::MIN..='\u{7f}'
 }
 
 /// Generates all [`char`]s, in ascending order.
@@ -52,7 +52,7 @@ pub const fn ascii_chars_increasing() -> RangeInclusive<char> {
 /// );
 /// ```
 pub const fn chars_increasing() -> RangeInclusive<char> {
-    char::MIN..=char::MAX
+    <char as Min>::MIN..=char::MAX
 }
 
 /// Generates all [`char`]s, in a friendly order, so that more familiar [`char`] come first.
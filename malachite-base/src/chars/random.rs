@@ -5,6 +5,7 @@ use crate::comparison::traits::Min;
 use crate::num::random::{random_unsigned_inclusive_range, RandomUnsignedInclusiveRange};
 use crate::random::Seed;
 use crate::vecs::{random_values_from_vec, RandomValuesFromVec};
+use alloc::vec::Vec;
 
 /// Uniformly generates random [`char`]s in a closed interval.
 ///
@@ -72,7 +73,7 @@ impl Iterator for WeightedGraphicRandomCharRange {
 /// ```
 #[inline]
 pub fn random_chars(seed: Seed) -> RandomCharRange {
-    random_char_inclusive_range(seed, char::MIN, char::MAX)
+    random_char_inclusive_range(seed, <char as Min>::MIN, char::MAX)
 }
 
 /// Uniformly generates random ASCII [`char`]s.
@@ -104,7 +105,7 @@ pub fn random_chars(seed: Seed) -> RandomCharRange {
 /// ```
 #[inline]
 pub fn random_ascii_chars(seed: Seed) -> RandomCharRange {
-    random_char_inclusive_range(seed, char::MIN, '\u{7f}')
+    random_char_inclusive_range(seed, <char as Min>::MIN, '\u{7f}')
 }
 
 /// Uniformly generates random [`char`]s in the half-open interval $[a, b)$.
@@ -249,7 +250,7 @@ pub fn graphic_weighted_random_chars(
 ) -> WeightedGraphicRandomCharRange {
     graphic_weighted_random_char_inclusive_range(
         seed,
-        char::MIN,
+        <char as Min>::MIN,
         char::MAX,
         p_numerator,
         p_denominator,
@@ -308,7 +309,7 @@ pub fn graphic_weighted_random_ascii_chars(
 ) -> WeightedGraphicRandomCharRange {
     graphic_weighted_random_char_inclusive_range(
         seed,
-        char::MIN,
+        <char as Min>::MIN,
         '\u{7f}',
         p_numerator,
         p_denominator,
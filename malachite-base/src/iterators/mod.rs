@@ -2,10 +2,15 @@ use crate::bools::random::{weighted_random_bools, WeightedRandomBools};
 use crate::num::basic::traits::Zero;
 use crate::random::Seed;
 use crate::vecs::{random_values_from_vec, RandomValuesFromVec};
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use core::hash::Hash;
 use itertools::Itertools;
-use std::collections::{HashSet, VecDeque};
-use std::fmt::Display;
-use std::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
 /// Generates all the nonzero values of a provided iterator.
 ///
@@ -162,6 +167,7 @@ pub fn count_is_at_most<I: Iterator>(xs: I, n: usize) -> bool {
 /// assert_eq!(is_unique([1, 2, 3, 1].iter()), false);
 /// assert_eq!(is_unique((0..).map(|i| i / 2)), false);
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn is_unique<I: Iterator>(xs: I) -> bool
 where
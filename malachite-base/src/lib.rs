@@ -67,7 +67,13 @@
 //!   However, doctests don't require it, since they only test the public interface.
 //! - `bin_build`: This feature is used to build the code for demos and benchmarks, which also
 //!   takes a long time to build. Enabling this feature also enables `test_build`.
+//! - `std`: Enabled by default. Without it, this crate only depends on `core` and `alloc`, so it
+//!   can be used on targets that have an allocator but no standard library. The functions that
+//!   rely on [`Hash`](std::hash::Hash), such as those that generate
+//!   [`HashSet`](std::collections::HashSet)s, are only available with `std`, and `test_build` and
+//!   `bin_build` require it.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(
     clippy::cast_lossless,
     clippy::explicit_into_iter_loop,
@@ -102,7 +108,11 @@
     unstable_name_collisions
 )]
 
+#[macro_use]
+extern crate alloc;
 extern crate itertools;
+#[cfg(not(feature = "std"))]
+extern crate libm;
 extern crate rand;
 extern crate rand_chacha;
 extern crate sha3;
@@ -50,10 +50,17 @@ macro_rules! impl_abs_primitive_float {
 
             /// This is a wrapper over the `abs` functions in the standard library, for example
             /// [this one](f32::abs).
+            #[cfg(feature = "std")]
             #[inline]
             fn abs(self) -> $f {
                 $f::abs(self)
             }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            fn abs(self) -> $f {
+                libm::Libm::<$f>::fabs(self)
+            }
         }
 
         impl AbsAssign for $f {
@@ -7,10 +7,17 @@ macro_rules! impl_ceiling {
 
             /// This is a wrapper over the `ceil` functions in the standard library, for example
             /// [this one](f32::ceil).
+            #[cfg(feature = "std")]
             #[inline]
             fn ceiling(self) -> $f {
                 $f::ceil(self)
             }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            fn ceiling(self) -> $f {
+                libm::Libm::<$f>::ceil(self)
+            }
         }
 
         impl CeilingAssign for $f {
@@ -0,0 +1,180 @@
+use crate::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedFibonacciPair, CheckedLucas, Fibonacci, FibonacciPair, Lucas,
+};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Returns $(F_n, F_{n-1})$. Since the Fibonacci numbers grow exponentially, the loop runs at most
+// `T::WIDTH * 3 / 2` times before overflowing.
+fn checked_fibonacci_pair<T: PrimitiveUnsigned>(n: u64) -> Option<(T, T)> {
+    let mut f = T::ZERO;
+    let mut f_prev = T::ONE;
+    for _ in 0..n {
+        let f_next = f.checked_add(f_prev)?;
+        f_prev = f;
+        f = f_next;
+    }
+    Some((f, f_prev))
+}
+
+// $L_n = F_n + 2F_{n-1}$, and $L_n \geq F_n$, so if $F_n$ overflows, so does $L_n$.
+fn checked_lucas<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    let (f, f_prev) = checked_fibonacci_pair::<T>(n)?;
+    f_prev.checked_mul(T::TWO)?.checked_add(f)
+}
+
+macro_rules! impl_fibonacci {
+    ($t:ident) => {
+        impl CheckedFibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the input is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(F_n) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad F_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $F_0 = 0$, $F_1 = 1$, $F_n = F_{n-1} + F_{n-2}$, and $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci).
+            #[inline]
+            fn checked_fibonacci(n: u64) -> Option<$t> {
+                checked_fibonacci_pair::<$t>(n).map(|p| p.0)
+            }
+        }
+
+        impl CheckedFibonacciPair for $t {
+            /// Computes the $n$th and $(n-1)$th Fibonacci numbers.
+            ///
+            /// If $F_n$ is too large to be represented, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}((F_n, F_{n-1})) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad F_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $F_{-1} = 1$ and $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci_pair).
+            #[inline]
+            fn checked_fibonacci_pair(n: u64) -> Option<($t, $t)> {
+                checked_fibonacci_pair(n)
+            }
+        }
+
+        impl CheckedLucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the input is too large, the function returns `None`.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(L_n) & \text{if} \\quad L_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad L_n \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $L_0 = 2$, $L_1 = 1$, $L_n = L_{n-1} + L_{n-2}$, and $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_lucas).
+            #[inline]
+            fn checked_lucas(n: u64) -> Option<$t> {
+                checked_lucas(n)
+            }
+        }
+
+        impl Fibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// If the input is too large, the function panics. For a function that returns `None`
+            /// instead, try [`checked_fibonacci`](CheckedFibonacci::checked_fibonacci).
+            ///
+            /// $$
+            /// f(n) = F_n,
+            /// $$
+            /// where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci).
+            #[inline]
+            fn fibonacci(n: u64) -> $t {
+                $t::checked_fibonacci(n).unwrap()
+            }
+        }
+
+        impl FibonacciPair for $t {
+            /// Computes the $n$th and $(n-1)$th Fibonacci numbers.
+            ///
+            /// If $F_n$ is too large, the function panics. For a function that returns `None`
+            /// instead, try
+            /// [`checked_fibonacci_pair`](CheckedFibonacciPair::checked_fibonacci_pair).
+            ///
+            /// $$
+            /// f(n) = (F_n, F_{n-1}),
+            /// $$
+            /// where $F_{-1} = 1$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci_pair).
+            #[inline]
+            fn fibonacci_pair(n: u64) -> ($t, $t) {
+                $t::checked_fibonacci_pair(n).unwrap()
+            }
+        }
+
+        impl Lucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// If the input is too large, the function panics. For a function that returns `None`
+            /// instead, try [`checked_lucas`](CheckedLucas::checked_lucas).
+            ///
+            /// $$
+            /// f(n) = L_n,
+            /// $$
+            /// where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#lucas).
+            #[inline]
+            fn lucas(n: u64) -> $t {
+                $t::checked_lucas(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_fibonacci);
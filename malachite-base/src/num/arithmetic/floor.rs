@@ -7,10 +7,17 @@ macro_rules! impl_floor {
 
             /// This is a wrapper over the `floor` functions in the standard library, for example
             /// [this one](f32::floor).
+            #[cfg(feature = "std")]
             #[inline]
             fn floor(self) -> $f {
                 $f::floor(self)
             }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            fn floor(self) -> $f {
+                libm::Libm::<$f>::floor(self)
+            }
         }
 
         impl FloorAssign for $f {
@@ -0,0 +1,136 @@
+use crate::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower, Parity, Square};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::Primes;
+
+fn express_as_power_unsigned<T: PrimitiveUnsigned>(n: T) -> Option<(T, u64)> {
+    if n < T::TWO {
+        return Some((n, 2));
+    }
+    let mut x = n;
+    let mut e = 1;
+    // If x = y^(pq) for primes p <= q, then x is a pth power whose pth root is a qth power. So
+    // trying the prime exponents in ascending order, and replacing x by its root whenever one is
+    // found, produces the largest exponent.
+    for p in u64::primes() {
+        // The `p`th power of a number greater than 1 has more than `p` significant bits.
+        if p >= x.significant_bits() {
+            break;
+        }
+        // An even number can only be a `p`th power if `p` divides its number of trailing zeros.
+        let zeros = x.trailing_zeros();
+        if zeros != 0 && zeros % p != 0 {
+            continue;
+        }
+        while let Some(root) = x.checked_root(p) {
+            x = root;
+            e *= p;
+        }
+    }
+    if e == 1 {
+        None
+    } else {
+        Some((x, e))
+    }
+}
+
+macro_rules! impl_express_as_power_unsigned {
+    ($t:ident) => {
+        impl ExpressAsPower for $t {
+            /// Expresses a number as $x^k$, where $k > 1$ is as large as possible, or returns
+            /// `None` if the number is not a perfect power.
+            ///
+            /// 0 and 1 can be raised to any power, so they are returned as $0^2$ and $1^2$.
+            ///
+            /// Prime exponents are tried in ascending order, using
+            /// [`CheckedRoot`](super::traits::CheckedRoot). Exponents greater than or equal to the
+            /// number's number of significant bits, and exponents that don't divide the number of
+            /// trailing zeros of an even number, are skipped.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            #[inline]
+            fn express_as_power(&self) -> Option<($t, u64)> {
+                express_as_power_unsigned(*self)
+            }
+        }
+
+        impl IsPerfectPower for $t {
+            /// Determines whether a number is a perfect power; that is, whether it can be written
+            /// as $x^k$ for some $x$ and some $k > 1$.
+            ///
+            /// 0 and 1 are perfect powers.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                express_as_power_unsigned(*self).is_some()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_express_as_power_unsigned);
+
+macro_rules! impl_express_as_power_signed {
+    ($u:ident, $s:ident) => {
+        impl ExpressAsPower for $s {
+            /// Expresses a number as $x^k$, where $k > 1$ is as large as possible, or returns
+            /// `None` if the number is not a perfect power.
+            ///
+            /// 0 and 1 can be raised to any power, so they are returned as $0^2$ and $1^2$. $-1$
+            /// can be raised to any odd power, so it is returned as $(-1)^3$. Other negative
+            /// numbers can only be odd powers, so the largest odd exponent is returned.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            fn express_as_power(&self) -> Option<($s, u64)> {
+                if *self >= 0 {
+                    express_as_power_unsigned(self.unsigned_abs())
+                        .map(|(x, e)| ($s::wrapping_from(x), e))
+                } else if *self == -1 {
+                    Some((-1, 3))
+                } else {
+                    let (mut x, mut e) = express_as_power_unsigned(self.unsigned_abs())?;
+                    while e.even() {
+                        x = x.square();
+                        e >>= 1;
+                    }
+                    if e == 1 {
+                        None
+                    } else {
+                        Some(($s::wrapping_from(x).wrapping_neg(), e))
+                    }
+                }
+            }
+        }
+
+        impl IsPerfectPower for $s {
+            /// Determines whether a number is a perfect power; that is, whether it can be written
+            /// as $x^k$ for some $x$ and some $k > 1$.
+            ///
+            /// 0, 1, and $-1$ are perfect powers. Other negative numbers are perfect powers if they
+            /// are odd powers.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                self.express_as_power().is_some()
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_express_as_power_signed);
@@ -873,6 +873,86 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
 pub mod factorial;
+/// Traits for computing Fibonacci numbers, pairs of consecutive Fibonacci numbers, and Lucas
+/// numbers. Each function has a trait whose implementations panic if the result cannot be
+/// represented, and a checked trait whose implementations return `None` in that case. The traits
+/// are [`Fibonacci`](traits::Fibonacci),
+/// [`FibonacciPair`](traits::FibonacciPair), [`Lucas`](traits::Lucas),
+/// [`CheckedFibonacci`](traits::CheckedFibonacci),
+/// [`CheckedFibonacciPair`](traits::CheckedFibonacciPair), and
+/// [`CheckedLucas`](traits::CheckedLucas).
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(3), 2);
+/// assert_eq!(u8::fibonacci(10), 55);
+/// assert_eq!(u32::fibonacci(20), 6765);
+/// assert_eq!(u64::fibonacci(93), 12200160415121876738);
+/// ```
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(1), Some(1));
+/// assert_eq!(u8::checked_fibonacci(10), Some(55));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u32::checked_fibonacci(47), Some(2971215073));
+/// assert_eq!(u32::checked_fibonacci(48), None);
+/// ```
+///
+/// # fibonacci_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::FibonacciPair;
+///
+/// assert_eq!(u8::fibonacci_pair(0), (0, 1));
+/// assert_eq!(u8::fibonacci_pair(1), (1, 0));
+/// assert_eq!(u8::fibonacci_pair(2), (1, 1));
+/// assert_eq!(u8::fibonacci_pair(10), (55, 34));
+/// assert_eq!(u32::fibonacci_pair(20), (6765, 4181));
+/// ```
+///
+/// # checked_fibonacci_pair
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacciPair;
+///
+/// assert_eq!(u8::checked_fibonacci_pair(0), Some((0, 1)));
+/// assert_eq!(u8::checked_fibonacci_pair(10), Some((55, 34)));
+/// assert_eq!(u8::checked_fibonacci_pair(13), Some((233, 144)));
+/// assert_eq!(u8::checked_fibonacci_pair(14), None);
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(3), 4);
+/// assert_eq!(u8::lucas(10), 123);
+/// assert_eq!(u32::lucas(20), 15127);
+/// ```
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(1), Some(1));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// assert_eq!(u32::checked_lucas(46), Some(4106118243));
+/// assert_eq!(u32::checked_lucas(47), None);
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -913,6 +993,36 @@ pub mod floor;
 /// assert_eq!(x, 6);
 /// ```
 pub mod gcd;
+/// [`ExpressAsPower`](traits::ExpressAsPower) and [`IsPerfectPower`](traits::IsPerfectPower),
+/// traits for determining whether a number is a perfect power and for expressing it as one.
+///
+/// # express_as_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::ExpressAsPower;
+///
+/// assert_eq!(0u8.express_as_power(), Some((0, 2)));
+/// assert_eq!(1u16.express_as_power(), Some((1, 2)));
+/// assert_eq!(10u32.express_as_power(), None);
+/// assert_eq!(64u32.express_as_power(), Some((2, 6)));
+/// assert_eq!(1000000u64.express_as_power(), Some((10, 6)));
+/// assert_eq!((-1i8).express_as_power(), Some((-1, 3)));
+/// assert_eq!((-64i16).express_as_power(), Some((-4, 3)));
+/// assert_eq!((-100i32).express_as_power(), None);
+/// assert_eq!(i64::MIN.express_as_power(), Some((-2, 63)));
+/// ```
+///
+/// # is_perfect_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+///
+/// assert_eq!(0u8.is_perfect_power(), true);
+/// assert_eq!(1u16.is_perfect_power(), true);
+/// assert_eq!(10u32.is_perfect_power(), false);
+/// assert_eq!(64u32.is_perfect_power(), true);
+/// assert_eq!((-64i16).is_perfect_power(), true);
+/// assert_eq!((-100i32).is_perfect_power(), false);
+/// ```
+pub mod is_perfect_power;
 /// [`IsPowerOf2`](traits::IsPowerOf2), a trait for determining whether a number is an integer
 /// power of 2.
 ///
@@ -1174,6 +1284,31 @@ pub mod mod_inverse;
 /// assert_eq!(100u16.mod_is_reduced(&101), true);
 /// ```
 pub mod mod_is_reduced;
+/// [`ModLog`](traits::ModLog), a trait for finding the discrete logarithm of a number with respect
+/// to a base, modulo another number.
+///
+/// # mod_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLog;
+///
+/// assert_eq!(3u8.mod_log(2, 11), Some(8));
+/// assert_eq!(1u8.mod_log(2, 11), Some(0));
+/// assert_eq!(0u8.mod_log(2, 11), None);
+/// assert_eq!(3u8.mod_log(2, 7), None);
+/// assert_eq!(13u8.mod_log(5, 23), Some(14));
+///
+/// // The base need not be coprime to the modulus
+/// assert_eq!(0u8.mod_log(2, 8), Some(3));
+/// assert_eq!(8u8.mod_log(2, 24), Some(3));
+/// assert_eq!(9u8.mod_log(3, 18), Some(2));
+///
+/// assert_eq!(123456789u32.mod_log(5, 1000000007), Some(981640996));
+/// assert_eq!(
+///     1234567891011u64.mod_log(37, (1 << 61) - 1),
+///     Some(1248484803653020093)
+/// );
+/// ```
+pub mod mod_log;
 /// Traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](traits::ModMul), [`ModMulAssign`](traits::ModMulAssign),
@@ -1996,6 +2131,23 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt), a trait for finding square roots of numbers modulo another
+/// number.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(3u8.mod_sqrt(7), None);
+/// assert_eq!(0u32.mod_sqrt(1), Some(0));
+/// assert_eq!(10u32.mod_sqrt(13), Some(6));
+/// assert_eq!(4u32.mod_sqrt(15), Some(7));
+/// assert_eq!(17u32.mod_sqrt(64), Some(9));
+/// assert_eq!(12u32.mod_sqrt(36), None);
+/// assert_eq!(9u64.mod_sqrt(1000000007), Some(3));
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
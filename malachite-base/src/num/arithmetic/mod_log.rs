@@ -0,0 +1,288 @@
+use crate::num::arithmetic::mod_sqrt::mod_pow_wide;
+use crate::num::arithmetic::traits::ModLog;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+// Returns the smallest $d$ such that $g^d \equiv h \mod m$, where $g$ has order $n$, using the
+// baby-step giant-step algorithm. Returns `None` if there is no such $d$.
+fn baby_step_giant_step<T: PrimitiveUnsigned>(g: T, h: T, n: T, m: T) -> Option<T> {
+    let s = n.ceiling_sqrt();
+    let mut baby_steps = BTreeMap::new();
+    let mut x = T::ONE;
+    let mut j = T::ZERO;
+    while j < s {
+        baby_steps.entry(x).or_insert(j);
+        x.mod_mul_assign(g, m);
+        j += T::ONE;
+    }
+    // x is now g^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h;
+    let mut i = T::ZERO;
+    while i < s {
+        if let Some(&j) = baby_steps.get(&y) {
+            return Some(i * s + j);
+        }
+        y.mod_mul_assign(giant_step, m);
+        i += T::ONE;
+    }
+    None
+}
+
+// Given the factorization of a multiple of the order of `g` mod `m`, returns the factorization of
+// the order.
+fn order_factorization<T: PrimitiveUnsigned>(g: T, m: T, factors: &[(T, u64)]) -> Vec<(T, u64)> {
+    let mut n = T::ONE;
+    for &(p, k) in factors {
+        n *= p.pow(k);
+    }
+    let mut order_factors = Vec::new();
+    for &(p, k) in factors {
+        n /= p.pow(k);
+        let mut x = mod_pow_wide(g, n, m);
+        let mut e = 0;
+        while x != T::ONE {
+            x = mod_pow_wide(x, p, m);
+            e += 1;
+        }
+        if e != 0 {
+            n *= p.pow(e);
+            order_factors.push((p, e));
+        }
+    }
+    order_factors
+}
+
+// Returns the smallest $x$ such that $g^x \equiv h \mod m$, where $g$ is coprime to $m$ and has
+// order $n$, with the given factorization. Returns `None` if there is no such $x$.
+fn pohlig_hellman<T: PrimitiveUnsigned>(
+    g: T,
+    h: T,
+    m: T,
+    n: T,
+    order_factors: &[(T, u64)],
+) -> Option<T> {
+    let g_inverse = g.mod_inverse(m).unwrap();
+    let mut x = T::ZERO;
+    let mut modulus = T::ONE;
+    for &(q, e) in order_factors {
+        // Find x mod q^e by working in the subgroup of order q^e, one base-q digit at a time.
+        let q_e = q.pow(e);
+        let cofactor = n / q_e;
+        let g_q_inverse = mod_pow_wide(g_inverse, cofactor, m);
+        let h_q = mod_pow_wide(h, cofactor, m);
+        // gamma has order q.
+        let gamma = mod_pow_wide(mod_pow_wide(g, cofactor, m), q.pow(e - 1), m);
+        let mut x_q = T::ZERO;
+        let mut q_i = T::ONE;
+        for i in 0..e {
+            let t = mod_pow_wide(g_q_inverse, x_q, m).mod_mul(h_q, m);
+            let d = baby_step_giant_step(gamma, mod_pow_wide(t, q.pow(e - 1 - i), m), q, m)?;
+            x_q += d * q_i;
+            q_i *= q;
+        }
+        // Combine with the previous residues using the Chinese remainder theorem.
+        if modulus == T::ONE {
+            x = x_q;
+        } else {
+            let t = x_q
+                .mod_sub(x % q_e, q_e)
+                .mod_mul((modulus % q_e).mod_inverse(q_e).unwrap(), q_e);
+            x += modulus * t;
+        }
+        modulus *= q_e;
+    }
+    Some(x)
+}
+
+// Returns the factorization of $\phi(m)$.
+fn totient_factorization<T: PrimitiveUnsigned>(m: T) -> Vec<(T, u64)> {
+    let mut factors = BTreeMap::new();
+    for (p, k) in m.factor() {
+        if k != 1 {
+            *factors.entry(p).or_insert(0) += k - 1;
+        }
+        for (q, j) in (p - T::ONE).factor() {
+            *factors.entry(q).or_insert(0) += j;
+        }
+    }
+    factors.into_iter().collect()
+}
+
+fn mod_log_unsigned<T: PrimitiveUnsigned>(h: T, g: T, m: T) -> Option<T> {
+    assert!(h < m, "h must be reduced mod m, but {h} >= {m}");
+    assert!(g < m, "g must be reduced mod m, but {g} >= {m}");
+    // Divide out the common factors of g and m. If d = gcd(g, m) and g^x ≡ h mod m with x > 0,
+    // then d | h and (g / d) g^(x - 1) ≡ h / d mod m / d. Each step produces a coefficient c that
+    // is coprime to the new modulus.
+    let mut m = m;
+    let mut h = h;
+    let mut c = T::ONE % m;
+    let mut k = T::ZERO;
+    loop {
+        if c == h {
+            return Some(k);
+        }
+        let g = g % m;
+        let d = g.gcd(m);
+        if d == T::ONE {
+            break;
+        }
+        if !h.divisible_by(d) {
+            return None;
+        }
+        m /= d;
+        h /= d;
+        c = (c % m).mod_mul((g / d) % m, m);
+        k += T::ONE;
+    }
+    // Now c g^x ≡ h mod m, with g and c coprime to m.
+    let g = g % m;
+    let h = h.mod_mul(c.mod_inverse(m).unwrap(), m);
+    Some(mod_log_coprime(h, g, m, &totient_factorization(m))? + k)
+}
+
+// Returns the smallest $x$ such that $g^x \equiv h \mod m$, where $g$ is coprime to $m$, and the
+// order of $g$ divides the product of the prime powers in `factors`.
+fn mod_log_coprime<T: PrimitiveUnsigned>(h: T, g: T, m: T, factors: &[(T, u64)]) -> Option<T> {
+    let order_factors = order_factorization(g, m, factors);
+    let mut n = T::ONE;
+    for &(q, e) in &order_factors {
+        n *= q.pow(e);
+    }
+    let x = pohlig_hellman(g, h, m, n, &order_factors)?;
+    if mod_pow_wide(g, x, m) == h {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Computes the discrete logarithm of a number $y$ with respect to a base $g$, modulo another
+/// number $m$, given the prime factorization of a multiple of the multiplicative order of $g$.
+/// Returns `None` if there is no such logarithm. Assumes $y$ and $g$ are already reduced modulo
+/// $m$.
+///
+/// The base must be coprime to $m$. Any multiple of the order can be used; for example, the
+/// factorization of $p - 1$ when $m = p$ is prime, or of $\phi(m)$ in general. This avoids the
+/// factorizations performed by [`ModLog::mod_log`].
+///
+/// $$
+/// f(y, g, m) = \\begin{cases}
+///     \operatorname{Some}(\min\\{x : g^x \equiv y \mod m\\})
+///         & \text{if} \\quad \exists x : g^x \equiv y \mod m, \\\\
+///     \operatorname{None} & \text{otherwise}.
+/// \\end{cases}
+/// $$
+///
+/// The Pohlig-Hellman algorithm reduces the problem to subgroups of prime order, where the
+/// baby-step giant-step algorithm is used.
+///
+/// # Worst-case complexity
+/// $T(n) = O(2^{n/2})$
+///
+/// $M(n) = O(2^{n/2})$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+///
+/// # Panics
+/// Panics if `y` or `base` are greater than or equal to `m`, if `base` is not coprime to `m`, or if
+/// the product of the prime powers is not a multiple of the order of `base`. The primes are not
+/// checked for primality; if they are not prime, the result is unspecified.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::mod_log::mod_log_with_order_factorization;
+///
+/// // 2^61 - 1 is prime, and 2^61 - 2 = 2 * 3^2 * 5^2 * 7 * 11 * 13 * 31 * 41 * 61 * 151 * 331
+/// // * 1321
+/// let p = (1u64 << 61) - 1;
+/// let factors = [
+///     (2, 1),
+///     (3, 2),
+///     (5, 2),
+///     (7, 1),
+///     (11, 1),
+///     (13, 1),
+///     (31, 1),
+///     (41, 1),
+///     (61, 1),
+///     (151, 1),
+///     (331, 1),
+///     (1321, 1),
+/// ];
+/// assert_eq!(
+///     mod_log_with_order_factorization(1234567891011, 37, p, &factors),
+///     Some(1248484803653020093)
+/// );
+/// assert_eq!(mod_log_with_order_factorization(3u8, 2, 7, &[(3, 1)]), None);
+/// ```
+pub fn mod_log_with_order_factorization<T: PrimitiveUnsigned>(
+    y: T,
+    base: T,
+    m: T,
+    order_factors: &[(T, u64)],
+) -> Option<T> {
+    assert!(y < m, "y must be reduced mod m, but {y} >= {m}");
+    assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+    assert_eq!(base.gcd(m), T::ONE, "base must be coprime to m");
+    if m == T::ONE {
+        return Some(T::ZERO);
+    }
+    let mut product = T::ONE;
+    for &(p, k) in order_factors {
+        product *= p.pow(k);
+    }
+    assert_eq!(
+        mod_pow_wide(base, product, m),
+        T::ONE,
+        "the order of base must divide the product of the prime powers"
+    );
+    mod_log_coprime(y, base, m, order_factors)
+}
+
+macro_rules! impl_mod_log {
+    ($t:ident) => {
+        impl ModLog<$t> for $t {
+            type Output = $t;
+
+            /// Computes the discrete logarithm of a number with respect to a base $g$, modulo
+            /// another number $m$: the smallest $x$ such that $g^x \equiv y \mod m$. Returns
+            /// `None` if there is no such $x$. Assumes the first number and the base are already
+            /// reduced modulo $m$.
+            ///
+            /// $$
+            /// f(y, g, m) = \\begin{cases}
+            ///     \operatorname{Some}(\min\\{x : g^x \equiv y \mod m\\})
+            ///         & \text{if} \\quad \exists x : g^x \equiv y \mod m, \\\\
+            ///     \operatorname{None} & \text{otherwise}.
+            /// \\end{cases}
+            /// $$
+            ///
+            /// Common factors of $g$ and $m$ are removed first. Then the order of $g$ is found
+            /// from the factorization of $\phi(m)$, and the Pohlig-Hellman algorithm reduces the
+            /// problem to subgroups of prime order, where the baby-step giant-step algorithm is
+            /// used. If the factorization of the order of $g$ is already known, use
+            /// [`mod_log_with_order_factorization`] instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/2})$
+            ///
+            /// $M(n) = O(2^{n/2})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` or `base` are greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_log#mod_log).
+            #[inline]
+            fn mod_log(self, base: $t, m: $t) -> Option<$t> {
+                mod_log_unsigned(self, base, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_log);
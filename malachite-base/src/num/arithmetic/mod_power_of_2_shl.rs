@@ -2,7 +2,7 @@ use crate::num::arithmetic::traits::{ModPowerOf2Shl, ModPowerOf2ShlAssign, Unsig
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
-use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
+use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
 
 fn mod_power_of_2_shl_unsigned<T: PrimitiveUnsigned + Shl<U, Output = T>, U: PrimitiveUnsigned>(
     x: T,
@@ -0,0 +1,289 @@
+use crate::num::arithmetic::traits::{ModSqrt, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use core::cmp::min;
+
+// Like `ModPow`, but the exponent may be as large as the modulus.
+pub(crate) fn mod_pow_wide<T: PrimitiveUnsigned>(x: T, exp: T, m: T) -> T {
+    let data = T::precompute_mod_mul_data(&m);
+    let mut out = T::ONE;
+    for bit in exp.bits().rev() {
+        out.mod_mul_precomputed_assign(out, m, &data);
+        if bit {
+            out.mod_mul_precomputed_assign(x, m, &data);
+        }
+    }
+    out
+}
+
+// Returns the smallest positive quadratic non-residue mod the odd prime `p`.
+fn non_residue<T: PrimitiveUnsigned>(p: T) -> T {
+    let mut z = T::TWO;
+    while z.legendre_symbol(p) != -1 {
+        z += T::ONE;
+    }
+    z
+}
+
+// Tonelli-Shanks. `x` is a nonzero quadratic residue mod the odd prime `p`, and `p - 1 = q * 2^s`
+// with `q` odd.
+fn mod_sqrt_prime_tonelli_shanks<T: PrimitiveUnsigned>(x: T, p: T, q: T, s: u64) -> T {
+    let mut c = mod_pow_wide(non_residue(p), q, p);
+    let mut t = mod_pow_wide(x, q, p);
+    let mut r = mod_pow_wide(x, (q >> 1u32) + T::ONE, p);
+    let mut m = s;
+    while t != T::ONE {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut u = t;
+        while u != T::ONE {
+            u = u.mod_mul(u, p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.mod_mul(b, p);
+        }
+        r.mod_mul_assign(b, p);
+        c = b.mod_mul(b, p);
+        t.mod_mul_assign(c, p);
+        m = i;
+    }
+    r
+}
+
+// Cipolla's algorithm. `x` is a nonzero quadratic residue mod the odd prime `p`.
+fn mod_sqrt_prime_cipolla<T: PrimitiveUnsigned>(x: T, p: T) -> T {
+    // Find a such that w = a^2 - x is a non-residue. Then (a + sqrt(w))^((p + 1) / 2), computed in
+    // F_p(sqrt(w)), is a square root of x.
+    let mut a = T::ZERO;
+    let w = loop {
+        a += T::ONE;
+        let w = a.mod_mul(a, p).mod_sub(x, p);
+        if w.legendre_symbol(p) == -1 {
+            break w;
+        }
+    };
+    let mul = |(x_0, x_1): (T, T), (y_0, y_1): (T, T)| {
+        (
+            x_0.mod_mul(y_0, p)
+                .mod_add(x_1.mod_mul(y_1, p).mod_mul(w, p), p),
+            x_0.mod_mul(y_1, p).mod_add(x_1.mod_mul(y_0, p), p),
+        )
+    };
+    let mut out = (T::ONE, T::ZERO);
+    for bit in ((p >> 1u32) + T::ONE).bits().rev() {
+        out = mul(out, out);
+        if bit {
+            out = mul(out, (a, T::ONE));
+        }
+    }
+    out.0
+}
+
+// Returns the smaller square root of `x` mod the prime `p`, if `x` is a quadratic residue. `x` must
+// be reduced.
+fn mod_sqrt_prime<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    if x == T::ZERO || p == T::TWO {
+        return Some(x);
+    }
+    if x.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let p_minus_1 = p - T::ONE;
+    let s = p_minus_1.trailing_zeros();
+    let r = if s == 1 {
+        mod_pow_wide(x, (p >> 2u32) + T::ONE, p)
+    } else if s * (s - 1) > (p.significant_bits() << 3) + 20 {
+        // Tonelli-Shanks takes O(s^2) multiplications and Cipolla's algorithm takes O(log p), but
+        // each of Cipolla's steps is more expensive. This is the usual crossover point.
+        mod_sqrt_prime_cipolla(x, p)
+    } else {
+        mod_sqrt_prime_tonelli_shanks(x, p, p_minus_1 >> s, s)
+    };
+    Some(min(r, p - r))
+}
+
+// Returns a square root of `x` mod `p^k`, where `x` is coprime to the prime `p`, `k > 0`, and
+// `q = p^k`. `x` must be reduced.
+fn mod_sqrt_prime_power_coprime<T: PrimitiveUnsigned>(x: T, p: T, k: u64, q: T) -> Option<T> {
+    if p == T::TWO {
+        // x is odd. Every odd number is a square mod 2, only numbers congruent to 1 are squares mod
+        // 4, and only numbers congruent to 1 mod 8 are squares mod higher powers of 2.
+        return match k {
+            1 => Some(T::ONE),
+            2 => Some(T::ONE).filter(|_| x.mod_power_of_2(2) == T::ONE),
+            _ if x.mod_power_of_2(3) != T::ONE => None,
+            _ => {
+                // If r^2 ≡ x mod 2^i, then either r or r + 2^(i - 1) is a square root mod
+                // 2^(i + 1).
+                let mut r = T::ONE;
+                for i in 3..k {
+                    if r.mod_mul(r, q).mod_power_of_2(i + 1) != x.mod_power_of_2(i + 1) {
+                        r.set_bit(i - 1);
+                    }
+                }
+                Some(min(r, q - r))
+            }
+        };
+    }
+    let mut r = mod_sqrt_prime(x % p, p)?;
+    if k != 1 {
+        // Hensel lifting, via Newton's method: each step doubles the power of p modulo which r is
+        // a square root of x.
+        loop {
+            let d = r.mod_mul(r, q).mod_sub(x, q);
+            if d == T::ZERO {
+                break;
+            }
+            let inverse = r.mod_add(r, q).mod_inverse(q).unwrap();
+            r.mod_sub_assign(d.mod_mul(inverse, q), q);
+        }
+        r = min(r, q - r);
+    }
+    Some(r)
+}
+
+// Returns a square root of `x` mod `p^k`, where `p` is prime, `k > 0`, and `q = p^k`. `x` must be
+// reduced.
+fn mod_sqrt_prime_power<T: PrimitiveUnsigned>(x: T, p: T, k: u64, q: T) -> Option<T> {
+    if x == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Write x = p^v * y, with y coprime to p. Since x < p^k, v < k. A square root of x exists if
+    // and only if v is even and y is a square mod p^(k - v), and then p^(v / 2) times a square
+    // root of y is a square root of x.
+    let mut v = 0u64;
+    let mut y = x;
+    let mut p_v = T::ONE;
+    while y % p == T::ZERO {
+        y /= p;
+        v += 1;
+        p_v *= p;
+    }
+    if v == 0 {
+        return mod_sqrt_prime_power_coprime(x, p, k, q);
+    }
+    if v.odd() {
+        return None;
+    }
+    let p_half_v = p.pow(v >> 1);
+    Some(mod_sqrt_prime_power_coprime(y, p, k - v, q / p_v)? * p_half_v)
+}
+
+fn mod_sqrt_with_factorization_helper<T: PrimitiveUnsigned>(
+    x: T,
+    factors: &[(T, u64)],
+) -> Option<T> {
+    // Find square roots modulo each prime power, and combine them using the Chinese remainder
+    // theorem.
+    let mut root = T::ZERO;
+    let mut m = T::ONE;
+    for &(p, k) in factors {
+        let q = p.pow(k);
+        let r = mod_sqrt_prime_power(x % q, p, k, q)?;
+        if m == T::ONE {
+            root = r;
+        } else {
+            let t = r
+                .mod_sub(root % q, q)
+                .mod_mul((m % q).mod_inverse(q).unwrap(), q);
+            root += m * t;
+        }
+        m *= q;
+    }
+    Some(root)
+}
+
+/// Finds a square root of a number modulo another number $m$, given the prime factorization of
+/// $m$, or returns `None` if there is none. Assumes the first number is already reduced modulo $m$.
+///
+/// The factorization is a list of distinct primes paired with their multiplicities, as returned by
+/// [`Factor::factor`](crate::num::factorization::traits::Factor::factor). This function is useful
+/// when the factorization of $m$ is already known, since factoring $m$ is the most expensive part
+/// of [`ModSqrt::mod_sqrt`].
+///
+/// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+///
+/// A square root is found modulo each prime power dividing $m$, and the results are combined using
+/// the Chinese remainder theorem. If $m$ is prime, the smaller of the two square roots is returned;
+/// otherwise, the root returned is one of possibly many.
+///
+/// # Worst-case complexity
+/// $T(n) = O(n^2)$
+///
+/// $M(n) = O(1)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+///
+/// # Panics
+/// Panics if the factorization contains an exponent of zero, if the product of the prime powers
+/// does not fit in a `T`, or if `x` is greater than or equal to that product. The primes are not
+/// checked for primality; if they are not prime, the result is unspecified.
+///
+/// # Examples
+/// ```
+/// use malachite_base::num::arithmetic::mod_sqrt::mod_sqrt_with_factorization;
+///
+/// let factors = [(8191u64, 1), (2147483647, 1)];
+/// assert_eq!(mod_sqrt_with_factorization(4u64, &factors), Some(2));
+/// assert_eq!(mod_sqrt_with_factorization(3u64, &factors), None);
+/// assert_eq!(mod_sqrt_with_factorization(36u8, &[(3, 4)]), Some(6));
+/// ```
+pub fn mod_sqrt_with_factorization<T: PrimitiveUnsigned>(x: T, factors: &[(T, u64)]) -> Option<T> {
+    assert!(factors.iter().all(|&(_, k)| k != 0));
+    let m = factors
+        .iter()
+        .try_fold(T::ONE, |m, &(p, k)| m.checked_mul(p.checked_pow(k)?))
+        .expect("the product of the prime powers must fit in T");
+    assert!(
+        x < m,
+        "x must be reduced modulo the product of the prime powers"
+    );
+    mod_sqrt_with_factorization_helper(x, factors)
+}
+
+fn mod_sqrt_unsigned<T: PrimitiveUnsigned>(x: T, m: T) -> Option<T> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    mod_sqrt_with_factorization_helper(x, &m.factor())
+}
+
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds a square root of a number modulo another number $m$, or returns `None` if
+            /// there is none. Assumes the first number is already reduced modulo $m$.
+            ///
+            /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+            ///
+            /// If $m$ is prime, the smaller of the two square roots is returned. Otherwise, $m$
+            /// is factored, a square root is found modulo each prime power dividing $m$, and the
+            /// results are combined using the Chinese remainder theorem; the root returned is one
+            /// of possibly many. If the factorization of $m$ is already known, use
+            /// [`mod_sqrt_with_factorization`] instead.
+            ///
+            /// Square roots modulo odd primes are found using the Tonelli-Shanks algorithm, or
+            /// Cipolla's algorithm if $p - 1$ is divisible by a large power of 2. They are lifted
+            /// to roots modulo prime powers using Hensel's lemma.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, m: $t) -> Option<$t> {
+                mod_sqrt_unsigned(self, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_sqrt);
@@ -79,25 +79,59 @@ macro_rules! impl_pow_primitive_float {
         impl Pow<i64> for $t {
             type Output = $t;
 
-            /// This is a wrapper over the `powi` functions in the standard library, for example
-            /// [this one](f32::powi).
-            #[inline]
+            /// Raises a number to an integer power.
+            ///
+            /// The power is computed by repeated squaring, the same way as the `powi` functions in
+            /// the standard library (for example [this one](f32::powi)) compute it, and its
+            /// reciprocal is taken if the exponent is negative. Unlike `powi`, any [`i64`]
+            /// exponent is accepted, and `std` is not required.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `exp.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::pow#pow_assign).
             fn pow(self, exp: i64) -> $t {
-                self.powi(i32::exact_from(exp))
+                let mut base = self;
+                let mut remaining = exp.unsigned_abs();
+                let mut power = 1.0;
+                loop {
+                    if remaining.odd() {
+                        power *= base;
+                    }
+                    remaining >>= 1;
+                    if remaining == 0 {
+                        break;
+                    }
+                    base *= base;
+                }
+                if exp < 0 {
+                    1.0 / power
+                } else {
+                    power
+                }
             }
         }
 
         impl PowAssign<i64> for $t {
-            /// Raises a number to a power, in place.
+            /// Raises a number to an integer power, in place.
             ///
             /// # Worst-case complexity
-            /// Constant time and additional memory.
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `exp.significant_bits()`.
             ///
             /// # Examples
             /// See [here](super::pow#pow_assign).
             #[inline]
             fn pow_assign(&mut self, exp: i64) {
-                *self = self.powi(i32::exact_from(exp));
+                *self = Pow::pow(*self, exp);
             }
         }
 
@@ -106,10 +140,17 @@ macro_rules! impl_pow_primitive_float {
 
             /// This is a wrapper over the `powf` functions in the standard library, for example
             /// [this one](f32::powf).
+            #[cfg(feature = "std")]
             #[inline]
             fn pow(self, exp: $t) -> $t {
                 self.powf(exp)
             }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            fn pow(self, exp: $t) -> $t {
+                libm::Libm::<$t>::pow(self, exp)
+            }
         }
 
         impl PowAssign<$t> for $t {
@@ -122,7 +163,7 @@ macro_rules! impl_pow_primitive_float {
             /// See [here](super::pow#pow_assign).
             #[inline]
             fn pow_assign(&mut self, exp: $t) {
-                *self = self.powf(exp);
+                *self = Pow::pow(*self, exp);
             }
         }
     };
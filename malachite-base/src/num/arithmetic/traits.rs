@@ -1,6 +1,6 @@
 use crate::num::basic::traits::Two;
 use crate::rounding_modes::RoundingMode;
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
 /// Takes the absolute value of a number. Assumes that the number has a representable absolute
 /// number.
@@ -23,6 +23,30 @@ pub trait UnsignedAbs {
     fn unsigned_abs(self) -> Self::Output;
 }
 
+/// Computes the arccosine (inverse cosine) of a number.
+pub trait Acos {
+    type Output;
+
+    fn acos(self) -> Self::Output;
+}
+
+/// Replaces a number with its arccosine.
+pub trait AcosAssign {
+    fn acos_assign(&mut self);
+}
+
+/// Computes the inverse hyperbolic cosine of a number.
+pub trait Acosh {
+    type Output;
+
+    fn acosh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic cosine.
+pub trait AcoshAssign {
+    fn acosh_assign(&mut self);
+}
+
 /// Adds a number and the product of two other numbers.
 pub trait AddMul<Y = Self, Z = Self> {
     type Output;
@@ -51,6 +75,67 @@ pub trait ArithmeticCheckedShr<RHS> {
     fn arithmetic_checked_shr(self, other: RHS) -> Option<Self::Output>;
 }
 
+/// Computes the arcsine (inverse sine) of a number.
+pub trait Asin {
+    type Output;
+
+    fn asin(self) -> Self::Output;
+}
+
+/// Replaces a number with its arcsine.
+pub trait AsinAssign {
+    fn asin_assign(&mut self);
+}
+
+/// Computes the inverse hyperbolic sine of a number.
+pub trait Asinh {
+    type Output;
+
+    fn asinh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic sine.
+pub trait AsinhAssign {
+    fn asinh_assign(&mut self);
+}
+
+/// Computes the arctangent (inverse tangent) of a number.
+pub trait Atan {
+    type Output;
+
+    fn atan(self) -> Self::Output;
+}
+
+/// Replaces a number with its arctangent.
+pub trait AtanAssign {
+    fn atan_assign(&mut self);
+}
+
+/// Computes the two-argument arctangent of two numbers $y$ and $x$: the angle between the positive
+/// $x$-axis and the point $(x, y)$.
+pub trait Atan2<RHS = Self> {
+    type Output;
+
+    fn atan2(self, other: RHS) -> Self::Output;
+}
+
+/// Replaces a number $y$ with the two-argument arctangent of $y$ and another number $x$.
+pub trait Atan2Assign<RHS = Self> {
+    fn atan2_assign(&mut self, other: RHS);
+}
+
+/// Computes the inverse hyperbolic tangent of a number.
+pub trait Atanh {
+    type Output;
+
+    fn atanh(self) -> Self::Output;
+}
+
+/// Replaces a number with its inverse hyperbolic tangent.
+pub trait AtanhAssign {
+    fn atanh_assign(&mut self);
+}
+
 pub trait BinomialCoefficient<T = Self> {
     fn binomial_coefficient(n: T, k: T) -> Self;
 }
@@ -156,6 +241,30 @@ pub trait CoprimeWith<RHS = Self> {
     fn coprime_with(self, other: RHS) -> bool;
 }
 
+/// Computes the cosine of a number.
+pub trait Cos {
+    type Output;
+
+    fn cos(self) -> Self::Output;
+}
+
+/// Replaces a number with its cosine.
+pub trait CosAssign {
+    fn cos_assign(&mut self);
+}
+
+/// Computes the hyperbolic cosine of a number.
+pub trait Cosh {
+    type Output;
+
+    fn cosh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic cosine.
+pub trait CoshAssign {
+    fn cosh_assign(&mut self);
+}
+
 /// Divides two numbers, assuming the first exactly divides the second.
 ///
 /// If it doesn't, the `div_exact` function may panic or return a meaningless result.
@@ -294,6 +403,54 @@ pub trait EqMod<RHS = Self, M = Self> {
     fn eq_mod(self, other: RHS, m: M) -> bool;
 }
 
+/// Computes the exponential of a number, $e^x$.
+pub trait Exp {
+    type Output;
+
+    fn exp(self) -> Self::Output;
+}
+
+/// Replaces a number with its exponential.
+pub trait ExpAssign {
+    fn exp_assign(&mut self);
+}
+
+/// Computes 2 raised to the power of a number, $2^x$.
+pub trait Exp2 {
+    type Output;
+
+    fn exp2(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $2^x$.
+pub trait Exp2Assign {
+    fn exp2_assign(&mut self);
+}
+
+/// Computes 10 raised to the power of a number, $10^x$.
+pub trait Exp10 {
+    type Output;
+
+    fn exp10(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $10^x$.
+pub trait Exp10Assign {
+    fn exp10_assign(&mut self);
+}
+
+/// Computes $e^x-1$, the exponential of a number minus 1.
+pub trait Expm1 {
+    type Output;
+
+    fn expm1(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $e^x-1$.
+pub trait Expm1Assign {
+    fn expm1_assign(&mut self);
+}
+
 /// Computes the GCD (greatest common divisor) of two numbers $a$ and $b$, and also the
 /// coefficients $x$ and $y$ in Bézout's identity $ax+by=\gcd(a,b)$.
 ///
@@ -349,6 +506,37 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes the $n$th Fibonacci number.
+pub trait Fibonacci {
+    fn fibonacci(n: u64) -> Self;
+}
+
+/// Computes the $n$th Fibonacci number, returning `None` if it is too large to be represented.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+}
+
+/// Computes the $n$th and $(n-1)$th Fibonacci numbers.
+pub trait FibonacciPair: Sized {
+    fn fibonacci_pair(n: u64) -> (Self, Self);
+}
+
+/// Computes the $n$th and $(n-1)$th Fibonacci numbers, returning `None` if they are too large to
+/// be represented.
+pub trait CheckedFibonacciPair: Sized {
+    fn checked_fibonacci_pair(n: u64) -> Option<(Self, Self)>;
+}
+
+/// Computes the $n$th Lucas number.
+pub trait Lucas {
+    fn lucas(n: u64) -> Self;
+}
+
+/// Computes the $n$th Lucas number, returning `None` if it is too large to be represented.
+pub trait CheckedLucas: Sized {
+    fn checked_lucas(n: u64) -> Option<Self>;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -373,6 +561,18 @@ pub trait GcdAssign<RHS = Self> {
     fn gcd_assign(&mut self, other: RHS);
 }
 
+/// Expresses a number as $x^k$, where $k > 1$ is as large as possible, or returns `None` if the
+/// number is not a perfect power.
+pub trait ExpressAsPower: Sized {
+    fn express_as_power(&self) -> Option<(Self, u64)>;
+}
+
+/// Determines whether a number is a perfect power; that is, whether it can be written as $x^k$ for
+/// some $x$ and some $k > 1$.
+pub trait IsPerfectPower {
+    fn is_perfect_power(&self) -> bool;
+}
+
 /// Determines whether a number is an integer power of 2.
 pub trait IsPowerOf2 {
     fn is_power_of_2(&self) -> bool;
@@ -414,6 +614,54 @@ pub trait KroneckerSymbol<RHS = Self> {
     fn kronecker_symbol(self, other: RHS) -> i8;
 }
 
+/// Computes the natural logarithm of a number.
+pub trait Ln {
+    type Output;
+
+    fn ln(self) -> Self::Output;
+}
+
+/// Replaces a number with its natural logarithm.
+pub trait LnAssign {
+    fn ln_assign(&mut self);
+}
+
+/// Computes $\ln(1+x)$, the natural logarithm of 1 plus a number.
+pub trait Ln1p {
+    type Output;
+
+    fn ln_1p(self) -> Self::Output;
+}
+
+/// Replaces a number $x$ with $\ln(1+x)$.
+pub trait Ln1pAssign {
+    fn ln_1p_assign(&mut self);
+}
+
+/// Computes the base-2 logarithm of a number.
+pub trait Log2 {
+    type Output;
+
+    fn log2(self) -> Self::Output;
+}
+
+/// Replaces a number with its base-2 logarithm.
+pub trait Log2Assign {
+    fn log2_assign(&mut self);
+}
+
+/// Computes the base-10 logarithm of a number.
+pub trait Log10 {
+    type Output;
+
+    fn log10(self) -> Self::Output;
+}
+
+/// Replaces a number with its base-10 logarithm.
+pub trait Log10Assign {
+    fn log10_assign(&mut self);
+}
+
 /// Calculates the base-$b$ logarithm of a number, or returns `None` if the number is not a perfect
 /// power of $b$.
 pub trait CheckedLogBase<B = Self> {
@@ -922,6 +1170,23 @@ pub trait ModSquarePrecomputedAssign<RHS = Self, M = Self>: ModPowPrecomputed<RH
     fn mod_square_precomputed_assign(&mut self, m: M, data: &Self::Data);
 }
 
+/// Finds the discrete logarithm of a number with respect to a base, modulo another number $m$,
+/// returning `None` if there is none. Assumes the number and the base are already reduced modulo
+/// $m$.
+pub trait ModLog<B = Self, M = Self> {
+    type Output;
+
+    fn mod_log(self, base: B, m: M) -> Option<Self::Output>;
+}
+
+/// Finds a square root of a number modulo another number $m$, returning `None` if there is none.
+/// Assumes the input is already reduced modulo $m$.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, m: M) -> Option<Self::Output>;
+}
+
 /// Adds two numbers modulo a third number $m$. Assumes the inputs are already reduced modulo $m$.
 pub trait ModSub<RHS = Self, M = Self> {
     type Output;
@@ -1184,6 +1449,30 @@ pub trait ReciprocalAssign {
     fn reciprocal_assign(&mut self);
 }
 
+/// Finds the reciprocal (multiplicative inverse) of the square root of a number.
+pub trait ReciprocalSqrt {
+    type Output;
+
+    fn reciprocal_sqrt(self) -> Self::Output;
+}
+
+/// Replaces a number with the reciprocal (multiplicative inverse) of its square root.
+pub trait ReciprocalSqrtAssign {
+    fn reciprocal_sqrt_assign(&mut self);
+}
+
+/// Takes the $n$th root of a number.
+pub trait Root<POW> {
+    type Output;
+
+    fn root(self, pow: POW) -> Self::Output;
+}
+
+/// Replaces a number with its $n$th root.
+pub trait RootAssign<POW> {
+    fn root_assign(&mut self, pow: POW);
+}
+
 /// Finds the floor of the $n$th root of a number.
 pub trait FloorRoot<POW> {
     type Output;
@@ -1452,6 +1741,37 @@ pub trait Sign {
     fn sign(&self) -> Ordering;
 }
 
+/// Computes the sine of a number.
+pub trait Sin {
+    type Output;
+
+    fn sin(self) -> Self::Output;
+}
+
+/// Replaces a number with its sine.
+pub trait SinAssign {
+    fn sin_assign(&mut self);
+}
+
+/// Computes the sine and cosine of a number.
+pub trait SinCos {
+    type Output;
+
+    fn sin_cos(self) -> (Self::Output, Self::Output);
+}
+
+/// Computes the hyperbolic sine of a number.
+pub trait Sinh {
+    type Output;
+
+    fn sinh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic sine.
+pub trait SinhAssign {
+    fn sinh_assign(&mut self);
+}
+
 /// Takes the square root of a number.
 pub trait Sqrt {
     type Output;
@@ -1534,6 +1854,30 @@ pub trait SubMulAssign<Y = Self, Z = Self> {
     fn sub_mul_assign(&mut self, y: Y, z: Z);
 }
 
+/// Computes the tangent of a number.
+pub trait Tan {
+    type Output;
+
+    fn tan(self) -> Self::Output;
+}
+
+/// Replaces a number with its tangent.
+pub trait TanAssign {
+    fn tan_assign(&mut self);
+}
+
+/// Computes the hyperbolic tangent of a number.
+pub trait Tanh {
+    type Output;
+
+    fn tanh(self) -> Self::Output;
+}
+
+/// Replaces a number with its hyperbolic tangent.
+pub trait TanhAssign {
+    fn tanh_assign(&mut self);
+}
+
 /// Takes the absolute value of a number, wrapping around at the boundary of the type.
 pub trait WrappingAbs {
     type Output;
@@ -6,18 +6,18 @@ use crate::num::arithmetic::traits::{
     CheckedBinomialCoefficient, CheckedDiv, CheckedMul, CheckedNeg, CheckedPow, CheckedRoot,
     CheckedSqrt, CheckedSquare, CheckedSub, CheckedSubMul, DivAssignMod, DivAssignRem, DivExact,
     DivExactAssign, DivMod, DivRem, DivRound, DivRoundAssign, DivisibleBy, DivisibleByPowerOf2,
-    EqMod, EqModPowerOf2, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt, FloorSqrtAssign,
-    JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModPowerOf2, ModPowerOf2Assign,
-    OverflowingAdd, OverflowingAddAssign, OverflowingAddMul, OverflowingAddMulAssign,
-    OverflowingDiv, OverflowingDivAssign, OverflowingMul, OverflowingMulAssign, OverflowingNeg,
-    OverflowingNegAssign, OverflowingPow, OverflowingPowAssign, OverflowingSquare,
-    OverflowingSquareAssign, OverflowingSub, OverflowingSubAssign, OverflowingSubMul,
-    OverflowingSubMulAssign, Parity, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
-    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple,
-    RoundToMultipleAssign, RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
-    SaturatingAdd, SaturatingAddAssign, SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul,
-    SaturatingMulAssign, SaturatingPow, SaturatingPowAssign, SaturatingSquare,
-    SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
+    EqMod, EqModPowerOf2, ExpressAsPower, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt,
+    FloorSqrtAssign, IsPerfectPower, JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign,
+    ModPowerOf2, ModPowerOf2Assign, OverflowingAdd, OverflowingAddAssign, OverflowingAddMul,
+    OverflowingAddMulAssign, OverflowingDiv, OverflowingDivAssign, OverflowingMul,
+    OverflowingMulAssign, OverflowingNeg, OverflowingNegAssign, OverflowingPow,
+    OverflowingPowAssign, OverflowingSquare, OverflowingSquareAssign, OverflowingSub,
+    OverflowingSubAssign, OverflowingSubMul, OverflowingSubMulAssign, Parity, Pow, PowAssign,
+    PowerOf2, RemPowerOf2, RemPowerOf2Assign, RotateLeft, RotateLeftAssign, RotateRight,
+    RotateRightAssign, RoundToMultiple, RoundToMultipleAssign, RoundToMultipleOfPowerOf2,
+    RoundToMultipleOfPowerOf2Assign, SaturatingAdd, SaturatingAddAssign, SaturatingAddMul,
+    SaturatingAddMulAssign, SaturatingMul, SaturatingMulAssign, SaturatingPow, SaturatingPowAssign,
+    SaturatingSquare, SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
     SaturatingSubMulAssign, ShlRound, ShlRoundAssign, ShrRound, ShrRoundAssign, Sign, Square,
     SquareAssign, SubMul, SubMulAssign, WrappingAdd, WrappingAddAssign, WrappingAddMul,
     WrappingAddMulAssign, WrappingDiv, WrappingDivAssign, WrappingMul, WrappingMulAssign,
@@ -31,20 +31,21 @@ use crate::num::conversion::traits::{
     OverflowingFrom, OverflowingInto, RoundingFrom, RoundingInto, SaturatingFrom, SaturatingInto,
     ToSci, ToStringBase, WrappingFrom, WrappingInto,
 };
+use crate::num::factorization::traits::{NextPrime, PrecedingPrime};
 use crate::num::float::NiceFloat;
 use crate::num::logic::traits::{
     BitAccess, BitBlockAccess, BitConvertible, BitIterable, BitScan, CountOnes, CountZeros,
     LeadingZeros, LowMask, NotAssign, SignificantBits, TrailingZeros,
 };
 use crate::num::random::HasRandomPrimitiveInts;
-use std::fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex};
-use std::hash::Hash;
-use std::iter::{Product, Sum};
-use std::ops::{
+use core::fmt::{Binary, Debug, Display, LowerHex, Octal, UpperHex};
+use core::hash::Hash;
+use core::iter::{Product, Sum};
+use core::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
     Mul, MulAssign, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
-use std::str::FromStr;
+use core::str::FromStr;
 
 /// Defines functions on primitive integer types: uxx, ixx, usize, and isize.
 ///
@@ -168,6 +169,7 @@ pub trait PrimitiveInt:
     + ExactInto<u64>
     + ExactInto<u8>
     + ExactInto<usize>
+    + ExpressAsPower
     + ExtendedGcd<Self>
     + FloorRoot<u64, Output = Self>
     + FloorRootAssign<u64>
@@ -180,6 +182,7 @@ pub trait PrimitiveInt:
     + HasRandomPrimitiveInts
     + Hash
     + IsInteger
+    + IsPerfectPower
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
     + LeadingZeros
@@ -195,6 +198,7 @@ pub trait PrimitiveInt:
     + Mul<Self, Output = Self>
     + MulAssign<Self>
     + Named
+    + NextPrime
     + Not<Output = Self>
     + NotAssign
     + Octal
@@ -250,6 +254,7 @@ pub trait PrimitiveInt:
     + Pow<u64, Output = Self>
     + PowAssign<u64>
     + PowerOf2<u64>
+    + PrecedingPrime
     + Product
     + Rem<Self, Output = Self>
     + RemAssign<Self>
@@ -562,7 +567,7 @@ macro_rules! impl_basic_traits_primitive_int {
         /// # Examples
         /// See [here](self).
         impl Min for $t {
-            const MIN: $t = std::$t::MIN;
+            const MIN: $t = core::$t::MIN;
         }
 
         /// The highest value representable by this type.
@@ -572,7 +577,7 @@ macro_rules! impl_basic_traits_primitive_int {
         /// # Examples
         /// See [here](self).
         impl Max for $t {
-            const MAX: $t = std::$t::MAX;
+            const MAX: $t = core::$t::MAX;
         }
     };
 }
@@ -8,7 +8,7 @@ use crate::num::basic::integers::PrimitiveInt;
 use crate::num::basic::traits::NegativeOne;
 use crate::num::logic::traits::CheckedHammingDistance;
 use crate::num::random::{HasRandomSignedRange, RandomSignedChunkable};
-use std::ops::Neg;
+use core::ops::Neg;
 
 /// Defines functions on primitive signed integer types: ixx and isize.
 pub trait PrimitiveSigned:
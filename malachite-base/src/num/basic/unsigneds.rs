@@ -1,20 +1,22 @@
 use crate::num::arithmetic::traits::{
     CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase, CeilingLogBase2,
-    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedLcm, CheckedLogBase,
-    CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial, CheckedNextPowerOf2,
-    CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial,
-    FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign,
-    ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul, ModMulAssign, ModMulPrecomputed,
-    ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign, ModPowPrecomputed,
-    ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign, ModPowerOf2Inverse,
-    ModPowerOf2IsReduced, ModPowerOf2Mul, ModPowerOf2MulAssign, ModPowerOf2Neg,
-    ModPowerOf2NegAssign, ModPowerOf2Pow, ModPowerOf2PowAssign, ModPowerOf2Shl,
-    ModPowerOf2ShlAssign, ModPowerOf2Shr, ModPowerOf2ShrAssign, ModPowerOf2Square,
-    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModSquare, ModSquareAssign,
-    ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod,
-    NegModAssign, NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign,
-    Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ,
-    XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedFibonacci,
+    CheckedFibonacciPair, CheckedLcm, CheckedLogBase, CheckedLogBase2, CheckedLogBasePowerOf2,
+    CheckedLucas, CheckedMultifactorial, CheckedNextPowerOf2, CheckedPrimorial,
+    CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial, Fibonacci,
+    FibonacciPair, FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2,
+    Lcm, LcmAssign, Lucas, ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModLog, ModMul,
+    ModMulAssign, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow,
+    ModPowAssign, ModPowPrecomputed, ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add,
+    ModPowerOf2AddAssign, ModPowerOf2Inverse, ModPowerOf2IsReduced, ModPowerOf2Mul,
+    ModPowerOf2MulAssign, ModPowerOf2Neg, ModPowerOf2NegAssign, ModPowerOf2Pow,
+    ModPowerOf2PowAssign, ModPowerOf2Shl, ModPowerOf2ShlAssign, ModPowerOf2Shr,
+    ModPowerOf2ShrAssign, ModPowerOf2Square, ModPowerOf2SquareAssign, ModPowerOf2Sub,
+    ModPowerOf2SubAssign, ModSqrt, ModSquare, ModSquareAssign, ModSquarePrecomputed,
+    ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod, NegModAssign,
+    NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign, Primorial,
+    RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ, XXAddYYToZZ,
+    XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::traits::{
@@ -22,7 +24,10 @@ use crate::num::conversion::traits::{
     SciMantissaAndExponent, VecFromOtherType, VecFromOtherTypeSlice,
 };
 use crate::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use crate::num::factorization::traits::Primes;
+use crate::num::factorization::traits::{
+    CarmichaelLambda, CheckedDivisorSum, DivisorCount, DivisorSum, Divisors, Factor, IsPrime,
+    Moebius, PrimePi, Primes, Totient,
+};
 use crate::num::logic::traits::{BitBlockAccess, HammingDistance};
 
 /// Defines functions on primitive unsigned integer types: uxx and usize.
@@ -31,10 +36,14 @@ pub trait PrimitiveUnsigned:
     + CeilingLogBase<Output = u64>
     + CeilingLogBase2<Output = u64>
     + CeilingLogBasePowerOf2<u64, Output = u64>
+    + CarmichaelLambda
     + CeilingDivAssignNegMod<Self, ModOutput = Self>
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
+    + CheckedDivisorSum
     + CheckedDoubleFactorial
     + CheckedFactorial
+    + CheckedFibonacci
+    + CheckedFibonacciPair
     + CheckedMultifactorial
     + CheckedPrimorial
     + CheckedSubfactorial
@@ -42,8 +51,12 @@ pub trait PrimitiveUnsigned:
     + CheckedLogBase<Output = u64>
     + CheckedLogBase2<Output = u64>
     + CheckedLogBasePowerOf2<u64, Output = u64>
+    + CheckedLucas
     + CheckedNextPowerOf2<Output = Self>
     + CoprimeWith<Self>
+    + DivisorCount
+    + DivisorSum
+    + Divisors
     + DoubleFactorial
     + Digits<u8>
     + Digits<u16>
@@ -52,7 +65,10 @@ pub trait PrimitiveUnsigned:
     + Digits<u128>
     + Digits<usize>
     + ExtendedGcd<Self, Gcd = Self>
+    + Factor
     + Factorial
+    + Fibonacci
+    + FibonacciPair
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
     + FloorLogBasePowerOf2<u64, Output = u64>
@@ -68,9 +84,12 @@ pub trait PrimitiveUnsigned:
     + HammingDistance
     + IntegerMantissaAndExponent<Self, u64>
     + IsPowerOf2
+    + IsPrime
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
+    + Lucas
     + ModIsReduced<Self>
+    + ModLog<Self, Self, Output = Self>
     + ModAdd<Self, Self, Output = Self>
     + ModAddAssign<Self, Self>
     + ModInverse<Self, Output = Self>
@@ -132,12 +151,14 @@ pub trait PrimitiveUnsigned:
     + ModPowerOf2SubAssign<Self>
     + ModPowPrecomputed<u64, Self, Output = Self>
     + ModPowPrecomputedAssign<u64, Self>
+    + ModSqrt<Self, Output = Self>
     + ModSquare<Self, Output = Self>
     + ModSquareAssign<Self>
     + ModSquarePrecomputed<u64, Self, Output = Self>
     + ModSquarePrecomputedAssign<u64, Self>
     + ModSub<Self, Self, Output = Self>
     + ModSubAssign<Self, Self>
+    + Moebius
     + Multifactorial
     + NegMod<Self, Output = Self>
     + NegModAssign<Self>
@@ -157,6 +178,7 @@ pub trait PrimitiveUnsigned:
     + PowerOf2DigitIterable<u64>
     + PowerOf2DigitIterable<u128>
     + PowerOf2DigitIterable<usize>
+    + PrimePi
     + Primes<I = PrimesIterator<Self>, LI = PrimesLessThanIterator<Self>>
     + PrimitiveInt
     + Primorial
@@ -167,6 +189,7 @@ pub trait PrimitiveUnsigned:
     + SqrtRem<SqrtOutput = Self, RemOutput = Self>
     + SqrtAssignRem<RemOutput = Self>
     + Subfactorial
+    + Totient
     + VecFromOtherType<u8>
     + VecFromOtherType<u16>
     + VecFromOtherType<u32>
@@ -2,6 +2,7 @@ use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::{
     ConvertibleFrom, Digits, ExactFrom, PowerOf2Digits, WrappingFrom,
 };
+use alloc::vec::Vec;
 use itertools::Itertools;
 
 pub_test! {unsigned_to_digits_asc_naive<
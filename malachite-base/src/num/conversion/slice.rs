@@ -5,6 +5,7 @@ use crate::num::conversion::traits::{
     FromOtherTypeSlice, SplitInHalf, VecFromOtherType, VecFromOtherTypeSlice, WrappingFrom,
 };
 use crate::rounding_modes::RoundingMode;
+use alloc::vec::Vec;
 
 const fn from_other_type_slice_ident<T: PrimitiveUnsigned>(xs: &[T]) -> T {
     if xs.is_empty() {
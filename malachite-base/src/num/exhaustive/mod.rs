@@ -13,10 +13,10 @@ use crate::tuples::exhaustive::{
     exhaustive_dependent_pairs, lex_dependent_pairs, ExhaustiveDependentPairs,
     ExhaustiveDependentPairsYsGenerator, LexDependentPairs,
 };
+use alloc::vec::{IntoIter, Vec};
+use core::iter::{once, Chain, Once, Rev};
+use core::marker::PhantomData;
 use itertools::{Interleave, Itertools};
-use std::iter::{once, Chain, Once, Rev};
-use std::marker::PhantomData;
-use std::vec::IntoIter;
 
 /// Generates all primitive integers in an interval.
 ///
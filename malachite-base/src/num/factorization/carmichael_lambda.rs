@@ -0,0 +1,48 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::CarmichaelLambda;
+
+fn carmichael_lambda_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO, "Cannot take the Carmichael function of 0");
+    let mut lambda = T::ONE;
+    for (p, k) in n.factor() {
+        // λ(2^k) = φ(2^k) / 2 when k >= 3; otherwise, λ(p^k) = φ(p^k).
+        let lambda_p = if p == T::TWO && k >= 3 {
+            T::power_of_2(k - 2)
+        } else {
+            p.pow(k - 1) * (p - T::ONE)
+        };
+        lambda = lambda.lcm(lambda_p);
+    }
+    lambda
+}
+
+macro_rules! impl_carmichael_lambda {
+    ($t:ident) => {
+        impl CarmichaelLambda for $t {
+            /// Computes the Carmichael function of a number: the smallest positive exponent $e$
+            /// such that $a^e \equiv 1 \mod n$ for every $a$ coprime to $n$.
+            ///
+            /// $\lambda(n)$ is the least common multiple of $\lambda(p^k)$ over the prime powers
+            /// $p^k$ exactly dividing $n$, where $\lambda(2^k) = 2^{k-2}$ for $k \geq 3$ and
+            /// $\lambda(p^k) = \varphi(p^k)$ otherwise.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::carmichael_lambda#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                carmichael_lambda_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_carmichael_lambda);
@@ -0,0 +1,37 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::DivisorCount;
+
+fn divisor_count_unsigned<T: PrimitiveUnsigned>(n: T) -> u64 {
+    assert_ne!(n, T::ZERO, "Cannot count the divisors of 0");
+    n.factor().iter().map(|&(_, k)| k + 1).product()
+}
+
+macro_rules! impl_divisor_count {
+    ($t:ident) => {
+        impl DivisorCount for $t {
+            /// Counts the positive divisors of a number.
+            ///
+            /// $$
+            /// f(n) = d(n) = \prod_{p^k \\| n} (k + 1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_count#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> u64 {
+                divisor_count_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisor_count);
@@ -0,0 +1,84 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{CheckedDivisorSum, DivisorSum};
+
+fn checked_divisor_sum_unsigned<T: PrimitiveUnsigned>(n: T, k: u64) -> Option<T> {
+    assert_ne!(n, T::ZERO, "Cannot sum the divisors of 0");
+    let mut sum = T::ONE;
+    for (p, e) in n.factor() {
+        if k == 0 {
+            sum = sum.checked_mul(T::exact_from(e + 1))?;
+        } else {
+            // 1 + q + q^2 + ... + q^e, where q = p^k
+            let q = p.checked_pow(k)?;
+            let mut p_sum = T::ONE;
+            for _ in 0..e {
+                p_sum = p_sum.checked_mul(q)?.checked_add(T::ONE)?;
+            }
+            sum = sum.checked_mul(p_sum)?;
+        }
+    }
+    Some(sum)
+}
+
+macro_rules! impl_divisor_sum {
+    ($t:ident) => {
+        impl DivisorSum for $t {
+            /// Computes the sum of the $k$th powers of the positive divisors of a number.
+            ///
+            /// $$
+            /// f(n, k) = \sigma_k(n) = \sum_{d | n} d^k.
+            /// $$
+            ///
+            /// In particular, $\sigma_0(n)$ is the number of divisors of $n$ and $\sigma_1(n)$ is
+            /// their sum.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(2^{n/4} + kn)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0 or if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sum#divisor_sum).
+            #[inline]
+            fn divisor_sum(&self, k: u64) -> $t {
+                checked_divisor_sum_unsigned(*self, k).unwrap()
+            }
+        }
+
+        impl CheckedDivisorSum for $t {
+            /// Computes the sum of the $k$th powers of the positive divisors of a number,
+            /// returning `None` if the result is not representable.
+            ///
+            /// $$
+            /// f(n, k) = \\begin{cases}
+            ///     \operatorname{Some}(\sigma_k(n)) & \text{if} \\quad \sigma_k(n) < 2^W, \\\\
+            ///     \operatorname{None} & \text{if} \\quad \sigma_k(n) \geq 2^W,
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH` and $\sigma_k(n) = \sum_{d | n} d^k$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, k) = O(2^{n/4} + kn)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisor_sum#checked_divisor_sum).
+            #[inline]
+            fn checked_divisor_sum(&self, k: u64) -> Option<$t> {
+                checked_divisor_sum_unsigned(*self, k)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisor_sum);
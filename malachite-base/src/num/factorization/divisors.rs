@@ -0,0 +1,126 @@
+use crate::num::basic::traits::One;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Divisors;
+use alloc::vec::Vec;
+use core::ops::MulAssign;
+
+/// An iterator that generates the positive divisors of a number.
+///
+/// The divisors are generated in "odometer" order rather than in ascending order: with the
+/// number's prime factorization $p_1^{k_1} p_2^{k_2} \cdots p_m^{k_m}$, where $p_1 < p_2 < \ldots <
+/// p_m$, the exponent of $p_1$ varies fastest. The first divisor generated is 1 and the last is the
+/// number itself.
+///
+/// This `struct` is created by [`Divisors::divisors`] and [`divisors_from_factorization`]; see
+/// their documentation for more.
+#[derive(Clone, Debug)]
+pub struct DivisorsIterator<T> {
+    factors: Vec<(T, u64)>,
+    exponents: Vec<u64>,
+    // `products[i]` is the product of `factors[j].0 ^ exponents[j]` for all `j >= i`. The last
+    // element is always 1.
+    products: Vec<T>,
+    done: bool,
+}
+
+impl<T: Clone + One + for<'a> MulAssign<&'a T>> Iterator for DivisorsIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let divisor = self.products[0].clone();
+        if let Some(i) = self
+            .factors
+            .iter()
+            .zip(self.exponents.iter())
+            .position(|(&(_, k), &e)| e < k)
+        {
+            self.exponents[i] += 1;
+            self.products[i] *= &self.factors[i].0;
+            for j in 0..i {
+                self.exponents[j] = 0;
+                self.products[j] = self.products[i].clone();
+            }
+        } else {
+            self.done = true;
+        }
+        Some(divisor)
+    }
+}
+
+/// Returns an iterator that generates the positive divisors of a number, given its prime
+/// factorization.
+///
+/// The factorization is a list of distinct primes, each paired with its multiplicity, as returned
+/// by [`Factor::factor`]. The divisors are not generated in ascending order; see
+/// [`DivisorsIterator`] for details.
+///
+/// The output length is $\prod_i (k_i + 1)$, where the $k_i$ are the multiplicities.
+///
+/// # Worst-case complexity per iteration
+/// $T(n) = O(n)$
+///
+/// $M(n) = O(n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is the number of significant bits of the
+/// number whose divisors are being generated.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::divisors::divisors_from_factorization;
+///
+/// assert_eq!(
+///     divisors_from_factorization(vec![(2u32, 2), (3, 1)]).collect_vec(),
+///     &[1, 2, 4, 3, 6, 12]
+/// );
+/// assert_eq!(divisors_from_factorization(Vec::<(u32, u64)>::new()).collect_vec(), &[1]);
+/// ```
+pub fn divisors_from_factorization<T: Clone + One>(factors: Vec<(T, u64)>) -> DivisorsIterator<T> {
+    let len = factors.len();
+    DivisorsIterator {
+        factors,
+        exponents: vec![0; len],
+        products: vec![T::ONE; len + 1],
+        done: false,
+    }
+}
+
+fn divisors_unsigned<T: PrimitiveUnsigned>(n: T) -> DivisorsIterator<T> {
+    assert_ne!(n, T::ZERO, "Cannot generate the divisors of 0");
+    divisors_from_factorization(n.factor())
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl Divisors for $t {
+            type I = DivisorsIterator<$t>;
+
+            /// Returns an iterator that generates the positive divisors of a number.
+            ///
+            /// The divisors are not generated in ascending order; see [`DivisorsIterator`] for
+            /// details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            /// Each iteration then takes $O(n)$ time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> DivisorsIterator<$t> {
+                divisors_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_divisors);
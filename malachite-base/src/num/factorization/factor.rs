@@ -0,0 +1,260 @@
+use crate::num::arithmetic::traits::{CheckedSqrt, FloorSqrt, Gcd, Parity};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::ExactFrom;
+use crate::num::factorization::traits::Factor;
+use alloc::vec::Vec;
+use core::cmp::min;
+
+// Primes less than this are removed by trial division before any other method is tried.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+// The multipliers used by SQUFOF, as suggested by Gower and Wagstaff: the squarefree products of
+// 3, 5, 7, and 11.
+const SQUFOF_MULTIPLIERS: [u64; 16] =
+    [1, 3, 5, 7, 11, 15, 21, 33, 35, 55, 77, 105, 165, 231, 385, 1155];
+
+// The number of steps Brent's variant of Pollard's rho takes between gcd computations.
+const POLLARD_RHO_BATCH_SIZE: u64 = 128;
+
+// The number of iterations of Pollard's rho that are tried before falling back to SQUFOF.
+const POLLARD_RHO_INITIAL_ITERATIONS: u64 = 1 << 12;
+
+// Attempts to find a factor of `n` using Shanks' square forms factorization with multiplier `k`.
+// `n` must be odd, composite, and not a perfect square, and `kn` must be less than 2^62, so that
+// all intermediate values fit in an `i64`.
+fn squfof_with_multiplier(n: u64, k: u64) -> Option<u64> {
+    let kn = i64::exact_from(n * k);
+    let p_0 = kn.floor_sqrt();
+    let mut q = kn - p_0 * p_0;
+    if q == 0 {
+        return None;
+    }
+    let mut q_prev = 1;
+    let mut p = p_0;
+    let limit = 6 * (p_0 << 1).floor_sqrt();
+    // Step forward through the continued fraction expansion of sqrt(kn) until a square form is
+    // found.
+    let mut root = None;
+    for i in 2..limit {
+        let b = (p_0 + p) / q;
+        let p_next = b * q - p;
+        let q_next = q_prev + b * (p - p_next);
+        p = p_next;
+        q_prev = q;
+        q = q_next;
+        if i.even() {
+            if let Some(r) = q.checked_sqrt() {
+                root = Some(r);
+                break;
+            }
+        }
+    }
+    let r = root?;
+    // Step through the reduced form until the P values repeat.
+    p += (p_0 - p) / r * r;
+    q_prev = r;
+    q = (kn - p * p) / q_prev;
+    loop {
+        let b = (p_0 + p) / q;
+        let p_next = b * q - p;
+        let q_next = q_prev + b * (p - p_next);
+        q_prev = q;
+        q = q_next;
+        if p_next == p {
+            break;
+        }
+        p = p_next;
+    }
+    let f = n.gcd(q_prev.unsigned_abs());
+    if f != 1 && f != n {
+        Some(f)
+    } else {
+        None
+    }
+}
+
+fn squfof(n: u64) -> Option<u64> {
+    SQUFOF_MULTIPLIERS
+        .iter()
+        .take_while(|&&k| n.checked_mul(k).map_or(false, |kn| kn >> 62 == 0))
+        .find_map(|&k| squfof_with_multiplier(n, k))
+}
+
+fn abs_diff<T: PrimitiveUnsigned>(x: T, y: T) -> T {
+    if x > y {
+        x - y
+    } else {
+        y - x
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm,
+// iterating x -> x^2 + c mod n. `n` must be odd and composite. Gives up after roughly
+// `max_iterations` iterations, or if the sequence cycles modulo `n` before a factor is found.
+fn pollard_rho<T: PrimitiveUnsigned>(n: T, c: T, max_iterations: u64) -> Option<T> {
+    let data = T::precompute_mod_mul_data(&n);
+    let f = |x: T| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let mut x;
+    let mut y = T::TWO;
+    let mut ys = T::TWO;
+    let mut q = T::ONE;
+    let mut g;
+    let mut r = 1;
+    loop {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        g = T::ONE;
+        let mut k = 0;
+        while k < r && g == T::ONE {
+            ys = y;
+            for _ in 0..min(POLLARD_RHO_BATCH_SIZE, r - k) {
+                y = f(y);
+                q = q.mod_mul_precomputed(abs_diff(x, y), n, &data);
+            }
+            g = q.gcd(n);
+            k += POLLARD_RHO_BATCH_SIZE;
+        }
+        if g != T::ONE {
+            break;
+        }
+        if r >= max_iterations {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == n {
+        // The batched gcd overshot; backtrack one step at a time.
+        loop {
+            ys = f(ys);
+            g = abs_diff(x, ys).gcd(n);
+            if g != T::ONE {
+                break;
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Finds a nontrivial factor of `n`, which must be odd, composite, and not a perfect square.
+//
+// Pollard's rho quickly finds small factors, so it is tried first, for a limited number of
+// iterations. If it fails, SQUFOF, whose running time depends only on the size of `n`, is tried
+// if `n` is small enough. Finally, Pollard's rho is run without a limit, with different
+// polynomials, until it succeeds.
+fn find_factor<T: PrimitiveUnsigned>(n: T) -> T {
+    if let Some(f) = pollard_rho(n, T::ONE, POLLARD_RHO_INITIAL_ITERATIONS) {
+        return f;
+    }
+    if n.significant_bits() < u64::WIDTH - 1 {
+        if let Some(f) = squfof(n.wrapping_into()) {
+            return T::wrapping_from(f);
+        }
+    }
+    let mut c = T::TWO;
+    loop {
+        if let Some(f) = pollard_rho(n, c, u64::MAX) {
+            return f;
+        }
+        c += T::ONE;
+    }
+}
+
+// Factors `n`, which has no prime factors less than `TRIAL_DIVISION_LIMIT`, and pushes its prime
+// factors onto `factors`, each with multiplicity `e`. The same prime may be pushed more than once.
+fn factor_cofactor<T: PrimitiveUnsigned>(n: T, e: u64, factors: &mut Vec<(T, u64)>) {
+    if n.is_prime() {
+        factors.push((n, e));
+    } else if let Some(r) = n.checked_sqrt() {
+        factor_cofactor(r, e << 1, factors);
+    } else {
+        let d = find_factor(n);
+        factor_cofactor(d, e, factors);
+        factor_cofactor(n.div_exact(d), e, factors);
+    }
+}
+
+// Sorts a list of prime factors and combines the multiplicities of equal primes.
+fn sort_and_merge_factors<T: Ord>(factors: &mut Vec<(T, u64)>) {
+    factors.sort_unstable_by(|(p, _), (q, _)| p.cmp(q));
+    factors.dedup_by(|(p, e), (q, f)| {
+        if p == q {
+            *f += *e;
+            true
+        } else {
+            false
+        }
+    });
+}
+
+fn factor_unsigned<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO, "Cannot factor 0");
+    let mut factors = Vec::new();
+    let zeros = n.trailing_zeros();
+    if zeros != 0 {
+        factors.push((T::TWO, zeros));
+        n >>= zeros;
+    }
+    let limit = T::saturating_from(TRIAL_DIVISION_LIMIT);
+    for p in T::primes_less_than(&limit).skip(1) {
+        if p.checked_square().map_or(true, |p_squared| p_squared > n) {
+            if n != T::ONE {
+                factors.push((n, 1));
+            }
+            return factors;
+        }
+        let mut e = 0;
+        while n.divisible_by(p) {
+            n /= p;
+            e += 1;
+        }
+        if e != 0 {
+            factors.push((p, e));
+        }
+    }
+    if n != T::ONE {
+        factor_cofactor(n, 1, &mut factors);
+        sort_and_merge_factors(&mut factors);
+    }
+    factors
+}
+
+macro_rules! impl_factor {
+    ($t:ident) => {
+        impl Factor for $t {
+            /// Factors a number into primes.
+            ///
+            /// The result is a list of the distinct prime factors in ascending order, each paired
+            /// with its multiplicity. Factoring 1 produces an empty list.
+            ///
+            /// Small factors are found by trial division. Larger ones are found by first running
+            /// Brent's variant of Pollard's rho algorithm for a limited number of iterations;
+            /// if that fails, Shanks' square forms factorization (SQUFOF) is tried, and if that
+            /// fails too, Pollard's rho is run again without a limit.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            #[inline]
+            fn factor(&self) -> Vec<($t, u64)> {
+                factor_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_factor);
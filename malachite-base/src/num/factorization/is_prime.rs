@@ -0,0 +1,242 @@
+use crate::num::arithmetic::traits::{
+    FloorSqrt, JacobiSymbol, ModAdd, ModMul, ModMulAssign, ModPowPrecomputed, ModSquare,
+    ModSquareAssign, ModSquarePrecomputed, ModSub, Parity, Square,
+};
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::IsPrime;
+use crate::num::logic::traits::{BitIterable, SignificantBits};
+
+// The primes less than 59. Any number less than 59^2 = 3481 with none of these as factors is prime.
+const SMALL_PRIMES: [u64; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+// Miller-Rabin with these bases is deterministic for all n < 2^32 (Jaeschke).
+const BASES_U32: [u64; 3] = [2, 7, 61];
+
+// Miller-Rabin with these bases is deterministic for all n < 2^64 (Sinclair).
+const BASES_U64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+// Miller-Rabin with the first 13 primes as bases is deterministic for all n <
+// 3317044064679887385961981 (Sorenson and Webster).
+const MILLER_RABIN_13_PRIMES_LIMIT: u128 = 3317044064679887385961981;
+
+// Returns `None` if `n` has a factor among `SMALL_PRIMES`, other than itself, and otherwise whether
+// `n` is small enough to be proven prime by that fact.
+fn trial_divide_small(n: u64) -> Option<bool> {
+    for &p in &SMALL_PRIMES {
+        if n == p {
+            return Some(true);
+        }
+        if n % p == 0 {
+            return None;
+        }
+    }
+    Some(n < 3481)
+}
+
+// Determines whether odd `n` > 2 is a strong probable prime to base `a`, where 1 < `a` < `n`.
+// `n - 1 = d * 2^s`, with `d` odd.
+fn is_strong_probable_prime_u64(n: u64, d: u64, s: u64, a: u64, data: &(u64, u64)) -> bool {
+    let mut x = a.mod_pow_precomputed(d, n, data);
+    let n_minus_1 = n - 1;
+    if x == 1 || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = x.mod_square_precomputed(n, data);
+        if x == n_minus_1 {
+            return true;
+        }
+    }
+    false
+}
+
+pub_test! {is_prime_u64(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    match trial_divide_small(n) {
+        None => return false,
+        Some(true) => return true,
+        Some(false) => {}
+    }
+    let n_minus_1 = n - 1;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s;
+    let s = u64::from(s);
+    let data = u64::precompute_mod_pow_data(&n);
+    let bases: &[u64] = if n >> 32 == 0 { &BASES_U32 } else { &BASES_U64 };
+    bases.iter().all(|&a| {
+        let a = a % n;
+        a == 0 || is_strong_probable_prime_u64(n, d, s, a, &data)
+    })
+}}
+
+// Unlike `ModPow<u64>`, this accepts exponents that don't fit in a `u64`.
+fn mod_pow_u128(x: u128, exp: u128, n: u128) -> u128 {
+    let mut out = 1;
+    for bit in exp.bits().rev() {
+        out.mod_square_assign(n);
+        if bit {
+            out.mod_mul_assign(x, n);
+        }
+    }
+    out
+}
+
+fn is_strong_probable_prime_u128(n: u128, d: u128, s: u64, a: u128) -> bool {
+    let mut x = mod_pow_u128(a, d, n);
+    let n_minus_1 = n - 1;
+    if x == 1 || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == n_minus_1 {
+            return true;
+        }
+    }
+    false
+}
+
+// Halves `x` modulo odd `n`, where `x < n`.
+fn mod_half_u128(x: u128, n: u128) -> u128 {
+    if x.even() {
+        x >> 1
+    } else {
+        // (x + n) / 2, without overflowing
+        (x >> 1) + (n >> 1) + 1
+    }
+}
+
+// Determines whether odd `n` > 2^64, which is not a perfect square and has no small factors, is a strong
+// Lucas probable prime, with parameters chosen using Selfridge's method A: D is the first of 5, -7,
+// 9, -11, ... for which the Jacobi symbol (D/n) is -1, P = 1, and Q = (1 - D) / 4.
+fn is_strong_lucas_probable_prime_u128(n: u128) -> bool {
+    // Since n has no small factors, |D| < n throughout.
+    let mut abs_d: u128 = 5;
+    let mut negative = false;
+    let d_mod_n = loop {
+        let d_mod_n = if negative { n - abs_d } else { abs_d };
+        match d_mod_n.jacobi_symbol(n) {
+            -1 => break d_mod_n,
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    let q = if negative {
+        (abs_d + 1) >> 2
+    } else {
+        n - ((abs_d - 1) >> 2)
+    };
+    let n_plus_1 = n + 1;
+    let s = n_plus_1.trailing_zeros();
+    let d = n_plus_1 >> s;
+    // Compute U_d, V_d, and Q^d, starting from U_1 = 1, V_1 = P = 1, and Q^1 = Q.
+    let mut u = 1;
+    let mut v = 1;
+    let mut q_k = q;
+    for bit in d.bits().rev().skip(1) {
+        // U_{2k} = U_k * V_k, V_{2k} = V_k^2 - 2Q^k
+        u.mod_mul_assign(v, n);
+        v = v.mod_square(n).mod_sub(q_k.mod_add(q_k, n), n);
+        q_k.mod_square_assign(n);
+        if bit {
+            // U_{2k+1} = (P * U_{2k} + V_{2k}) / 2, V_{2k+1} = (D * U_{2k} + P * V_{2k}) / 2
+            let new_u = mod_half_u128(u.mod_add(v, n), n);
+            v = mod_half_u128(d_mod_n.mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k.mod_mul_assign(q, n);
+        }
+    }
+    if u == 0 || v == 0 {
+        return true;
+    }
+    for _ in 1..s {
+        // V_{2k} = V_k^2 - 2Q^k
+        v = v.mod_square(n).mod_sub(q_k.mod_add(q_k, n), n);
+        if v == 0 {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}
+
+pub_test! {is_prime_u128(n: u128) -> bool {
+    if n.significant_bits() <= u64::WIDTH {
+        return is_prime_u64(u64::wrapping_from(n));
+    }
+    if SMALL_PRIMES.iter().any(|&p| n % u128::from(p) == 0) {
+        return false;
+    }
+    let n_minus_1 = n - 1;
+    let s = n_minus_1.trailing_zeros();
+    let d = n_minus_1 >> s;
+    let s = u64::from(s);
+    if n < MILLER_RABIN_13_PRIMES_LIMIT {
+        SMALL_PRIMES[..13]
+            .iter()
+            .all(|&a| is_strong_probable_prime_u128(n, d, s, u128::from(a)))
+    } else {
+        // Baillie-PSW: a base-2 strong probable prime test followed by a strong Lucas test. No
+        // counterexamples are known.
+        is_strong_probable_prime_u128(n, d, s, 2)
+            && n.floor_sqrt().square() != n
+            && is_strong_lucas_probable_prime_u128(n)
+    }
+}}
+
+macro_rules! impl_is_prime {
+    ($t:ident) => {
+        impl IsPrime for $t {
+            /// Determines whether a number is prime.
+            ///
+            /// The test is a deterministic variant of Miller-Rabin, using sets of bases that are
+            /// known to have no counterexamples in the type's range.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::is_prime#is_prime).
+            #[inline]
+            fn is_prime(&self) -> bool {
+                is_prime_u64(u64::wrapping_from(*self))
+            }
+        }
+    };
+}
+impl_is_prime!(u8);
+impl_is_prime!(u16);
+impl_is_prime!(u32);
+impl_is_prime!(u64);
+impl_is_prime!(usize);
+
+impl IsPrime for u128 {
+    /// Determines whether a `u128` is prime.
+    ///
+    /// Below $3.3 \times 10^{24}$, the test is a deterministic variant of Miller-Rabin, using the
+    /// first 13 primes as bases. Above that, the Baillie-PSW test is used; no counterexamples to
+    /// it are known.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        is_prime_u128(*self)
+    }
+}
@@ -1,3 +1,172 @@
+/// [`CarmichaelLambda`](traits::CarmichaelLambda), a trait for computing the Carmichael function of
+/// a number.
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+///
+/// assert_eq!(1u8.carmichael_lambda(), 1);
+/// assert_eq!(8u8.carmichael_lambda(), 2);
+/// assert_eq!(9u16.carmichael_lambda(), 6);
+/// assert_eq!(561u32.carmichael_lambda(), 80);
+/// assert_eq!(65535u32.carmichael_lambda(), 256);
+/// assert_eq!(u64::MAX.carmichael_lambda(), 17153064960);
+/// ```
+pub mod carmichael_lambda;
+/// [`DivisorCount`](traits::DivisorCount), a trait for counting the positive divisors of a number.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorCount;
+///
+/// assert_eq!(1u8.divisor_count(), 1);
+/// assert_eq!(12u8.divisor_count(), 6);
+/// assert_eq!(36u16.divisor_count(), 9);
+/// assert_eq!(720720u32.divisor_count(), 240);
+/// assert_eq!(u64::MAX.divisor_count(), 128);
+/// ```
+pub mod divisor_count;
+/// [`DivisorSum`](traits::DivisorSum) and [`CheckedDivisorSum`](traits::CheckedDivisorSum), traits
+/// for computing the sum of the $k$th powers of the positive divisors of a number.
+///
+/// # divisor_sum
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorSum;
+///
+/// assert_eq!(1u8.divisor_sum(1), 1);
+/// assert_eq!(12u8.divisor_sum(0), 6);
+/// assert_eq!(12u8.divisor_sum(1), 28);
+/// assert_eq!(12u8.divisor_sum(2), 210);
+/// assert_eq!(100u16.divisor_sum(1), 217);
+/// assert_eq!(720720u32.divisor_sum(1), 3249792);
+/// assert_eq!(4294967295u64.divisor_sum(1), 7304603328);
+/// ```
+///
+/// # checked_divisor_sum
+/// ```
+/// use malachite_base::num::factorization::traits::CheckedDivisorSum;
+///
+/// assert_eq!(12u8.checked_divisor_sum(1), Some(28));
+/// assert_eq!(12u8.checked_divisor_sum(2), Some(210));
+/// assert_eq!(36u8.checked_divisor_sum(2), None);
+/// assert_eq!(255u8.checked_divisor_sum(1), None);
+/// assert_eq!(4294967295u64.checked_divisor_sum(1), Some(7304603328));
+/// assert_eq!(u64::MAX.checked_divisor_sum(1), None);
+/// ```
+pub mod divisor_sum;
+/// [`Divisors`](traits::Divisors), a trait for generating the positive divisors of a number, and
+/// [`DivisorsIterator`](divisors::DivisorsIterator), the iterator that generates them.
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+/// assert_eq!(12u8.divisors().collect_vec(), &[1, 2, 4, 3, 6, 12]);
+/// assert_eq!(
+///     360u32.divisors().sorted().collect_vec(),
+///     &[
+///         1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 18, 20, 24, 30, 36, 40, 45, 60, 72, 90, 120, 180,
+///         360
+///     ]
+/// );
+/// assert_eq!(u64::MAX.divisors().count(), 128);
+/// ```
+pub mod divisors;
+/// [`Factor`](traits::Factor), a trait for factoring a number into primes.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor(), &[]);
+/// assert_eq!(2u16.factor(), &[(2, 1)]);
+/// assert_eq!(360u32.factor(), &[(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(
+///     u64::MAX.factor(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]
+/// );
+/// assert_eq!(
+///     18446744073709551557u64.factor(),
+///     &[(18446744073709551557, 1)]
+/// );
+/// assert_eq!(
+///     (u128::from(u64::MAX) + 2).factor(),
+///     &[(274177, 1), (67280421310721, 1)]
+/// );
+/// ```
+pub mod factor;
+/// [`IsPrime`](traits::IsPrime), a trait for determining whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::factorization::traits::IsPrime;
+///
+/// assert_eq!(0u8.is_prime(), false);
+/// assert_eq!(1u16.is_prime(), false);
+/// assert_eq!(2u32.is_prime(), true);
+/// assert_eq!(97u64.is_prime(), true);
+/// assert_eq!(561u64.is_prime(), false);
+/// assert_eq!(u64::MAX.is_prime(), false);
+/// assert_eq!(18446744073709551557u64.is_prime(), true);
+/// assert_eq!(((1u128 << 127) - 1).is_prime(), true);
+/// ```
+pub mod is_prime;
+/// [`Moebius`](traits::Moebius), a trait for computing the Möbius function of a number.
+///
+/// # moebius
+/// ```
+/// use malachite_base::num::factorization::traits::Moebius;
+///
+/// assert_eq!(1u8.moebius(), 1);
+/// assert_eq!(2u8.moebius(), -1);
+/// assert_eq!(12u16.moebius(), 0);
+/// assert_eq!(65535u32.moebius(), 1);
+/// assert_eq!(u64::MAX.moebius(), -1);
+/// ```
+pub mod moebius;
+/// [`NextPrime`](traits::NextPrime), a trait for finding the smallest prime greater than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), 2);
+/// assert_eq!(2u16.next_prime(), 3);
+/// assert_eq!(100u32.next_prime(), 101);
+/// assert_eq!(1000000000u64.next_prime(), 1000000007);
+/// assert_eq!((-10i32).next_prime(), 2);
+/// assert_eq!(126i8.next_prime(), 127);
+/// ```
+pub mod next_prime;
+/// [`PrecedingPrime`](traits::PrecedingPrime), a trait for finding the largest prime less than a
+/// number.
+///
+/// # preceding_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PrecedingPrime;
+///
+/// assert_eq!(3u8.preceding_prime(), 2);
+/// assert_eq!(100u16.preceding_prime(), 97);
+/// assert_eq!(1000000007u32.preceding_prime(), 999999937);
+/// assert_eq!(u64::MAX.preceding_prime(), 18446744073709551557);
+/// assert_eq!(i8::MAX.preceding_prime(), 113);
+/// ```
+pub mod preceding_prime;
+/// [`PrimePi`](traits::PrimePi), a trait for counting the primes less than or equal to a number.
+///
+/// # prime_pi
+/// ```
+/// use malachite_base::num::factorization::traits::PrimePi;
+///
+/// assert_eq!(0u8.prime_pi(), 0);
+/// assert_eq!(10u8.prime_pi(), 4);
+/// assert_eq!(100u16.prime_pi(), 25);
+/// assert_eq!(1000000u32.prime_pi(), 78498);
+/// assert_eq!(10000000000u64.prime_pi(), 455052511);
+/// ```
+pub mod prime_pi;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -49,5 +218,19 @@ pub mod prime_sieve;
 /// );
 /// ```
 pub mod primes;
-/// Various traits for generating primes, primality testing, and factorization.
+/// [`Totient`](traits::Totient), a trait for computing Euler's totient function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::factorization::traits::Totient;
+///
+/// assert_eq!(1u8.totient(), 1);
+/// assert_eq!(12u8.totient(), 4);
+/// assert_eq!(100u16.totient(), 40);
+/// assert_eq!(561u32.totient(), 320);
+/// assert_eq!(u64::MAX.totient(), 9208981628670443520);
+/// ```
+pub mod totient;
+/// Various traits for generating primes, primality testing, factorization, and arithmetic functions
+/// derived from factorizations.
 pub mod traits;
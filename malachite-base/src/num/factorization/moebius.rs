@@ -0,0 +1,49 @@
+use crate::num::arithmetic::traits::Parity;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Moebius;
+
+fn moebius_unsigned<T: PrimitiveUnsigned>(n: T) -> i8 {
+    assert_ne!(n, T::ZERO, "Cannot take the Möbius function of 0");
+    let factors = n.factor();
+    if factors.iter().any(|&(_, k)| k > 1) {
+        0
+    } else if factors.len().even() {
+        1
+    } else {
+        -1
+    }
+}
+
+macro_rules! impl_moebius {
+    ($t:ident) => {
+        impl Moebius for $t {
+            /// Computes the Möbius function of a number.
+            ///
+            /// $$
+            /// f(n) = \mu(n) = \\begin{cases}
+            ///     0 & \text{if} \\quad p^2 | n \\ \text{for some prime} \\ p, \\\\
+            ///     (-1)^k & \text{if} \\quad n \\ \text{is the product of} \\ k \\
+            ///         \text{distinct primes}.
+            /// \\end{cases}
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::moebius#moebius).
+            #[inline]
+            fn moebius(&self) -> i8 {
+                moebius_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_moebius);
@@ -0,0 +1,78 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::NextPrime;
+
+fn next_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    if n < T::TWO {
+        return T::TWO;
+    }
+    let step = if n.even() { T::ONE } else { T::TWO };
+    let mut p = n
+        .checked_add(step)
+        .expect("The next prime is greater than the type's maximum value");
+    while !p.is_prime() {
+        p = p
+            .checked_add(T::TWO)
+            .expect("The next prime is greater than the type's maximum value");
+    }
+    p
+}
+
+macro_rules! impl_next_prime_unsigned {
+    ($t:ident) => {
+        impl NextPrime for $t {
+            /// Finds the smallest prime greater than a number.
+            ///
+            /// Successive odd numbers are tested using [`IsPrime`](super::traits::IsPrime).
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if the next prime is greater than the type's maximum value.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            #[inline]
+            fn next_prime(&self) -> $t {
+                next_prime_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_next_prime_unsigned);
+
+macro_rules! impl_next_prime_signed {
+    ($u:ident, $s:ident) => {
+        impl NextPrime for $s {
+            /// Finds the smallest prime greater than a number.
+            ///
+            /// If the number is less than 2, the result is 2.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if the next prime is greater than the type's maximum value.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            fn next_prime(&self) -> $s {
+                if *self < 2 {
+                    2
+                } else {
+                    $s::try_from(next_prime_unsigned(self.unsigned_abs()))
+                        .expect("The next prime is greater than the type's maximum value")
+                }
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_next_prime_signed);
@@ -0,0 +1,70 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::PrecedingPrime;
+
+fn preceding_prime_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    assert!(n > T::TWO, "There is no prime less than {n}");
+    if n == T::from(3u8) {
+        return T::TWO;
+    }
+    let mut p = if n.even() { n - T::ONE } else { n - T::TWO };
+    // This terminates, since 3 is prime.
+    while !p.is_prime() {
+        p -= T::TWO;
+    }
+    p
+}
+
+macro_rules! impl_preceding_prime_unsigned {
+    ($t:ident) => {
+        impl PrecedingPrime for $t {
+            /// Finds the largest prime less than a number.
+            ///
+            /// Successive odd numbers are tested using [`IsPrime`](super::traits::IsPrime).
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is less than or equal to 2.
+            ///
+            /// # Examples
+            /// See [here](super::preceding_prime#preceding_prime).
+            #[inline]
+            fn preceding_prime(&self) -> $t {
+                preceding_prime_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_preceding_prime_unsigned);
+
+macro_rules! impl_preceding_prime_signed {
+    ($u:ident, $s:ident) => {
+        impl PrecedingPrime for $s {
+            /// Finds the largest prime less than a number.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is less than or equal to 2.
+            ///
+            /// # Examples
+            /// See [here](super::preceding_prime#preceding_prime).
+            fn preceding_prime(&self) -> $s {
+                assert!(*self > 2, "There is no prime less than {self}");
+                $s::wrapping_from(preceding_prime_unsigned(self.unsigned_abs()))
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_preceding_prime_signed);
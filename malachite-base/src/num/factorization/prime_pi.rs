@@ -0,0 +1,241 @@
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt};
+use crate::num::conversion::traits::{ExactFrom, WrappingFrom};
+use crate::num::factorization::prime_sieve::{limbs_prime_sieve_size, limbs_prime_sieve_u64};
+use crate::num::factorization::traits::{PrimePi, Primes};
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+// Up to this value, primes are counted directly using a prime sieve. Larger values use Meissel's
+// formula, which also sieves all primes up to this value (or the square root of its argument, if
+// that is larger) so that small values of pi can be looked up.
+const SIEVE_LIMIT: u64 = 1 << 26;
+
+// phi(x, a) for a <= WHEEL_PRIMES.len() is computed using the periodicity of the numbers that are
+// not divisible by the first few primes.
+const WHEEL_PRIMES: [u64; 6] = [2, 3, 5, 7, 11, 13];
+
+// The product of `WHEEL_PRIMES`.
+const WHEEL_SIZE: u64 = 30030;
+
+// The number of integers in [1, `WHEEL_SIZE`] that are not divisible by any of the
+// `WHEEL_PRIMES`.
+const WHEEL_TOTIENT: u64 = 5760;
+
+// phi(x, a) is precomputed for all x less than `PHI_CACHE_SIZE` and all a less than
+// `PHI_CACHE_PRIMES`, since most of the leaves of phi's recursion fall in this range.
+const PHI_CACHE_SIZE: usize = 1 << 15;
+
+const PHI_CACHE_PRIMES: usize = 100;
+
+fn prime_pi_by_sieve(n: u64) -> u64 {
+    match n {
+        0 | 1 => 0,
+        2 => 1,
+        3 | 4 => 2,
+        n => {
+            let mut sieve = vec![0; limbs_prime_sieve_size::<u64>(n)];
+            limbs_prime_sieve_u64(&mut sieve, n) + 2
+        }
+    }
+}
+
+struct MeisselContext {
+    // All primes less than or equal to `limit`. Since `limit` is at least the square root of the
+    // argument, this dominates the memory used for large arguments; see the `PrimePi` docs.
+    primes: Vec<u32>,
+    limit: u64,
+    // `wheel_counts[r]` is the number of integers in [1, r] that are not divisible by any of the
+    // `WHEEL_PRIMES`.
+    wheel_counts: Vec<u16>,
+    // `phi_cache[a][x]` is phi(x, a).
+    phi_cache: Vec<Vec<u16>>,
+}
+
+impl MeisselContext {
+    fn new(limit: u64) -> MeisselContext {
+        let mut wheel_counts = Vec::with_capacity(usize::exact_from(WHEEL_SIZE));
+        let mut count = 0;
+        for r in 0..WHEEL_SIZE {
+            if r != 0 && WHEEL_PRIMES.iter().all(|&p| r % p != 0) {
+                count += 1;
+            }
+            wheel_counts.push(count);
+        }
+        let primes: Vec<u32> = u32::primes_less_than_or_equal_to(&u32::exact_from(limit)).collect();
+        let mut phi_cache = Vec::with_capacity(PHI_CACHE_PRIMES);
+        let mut sieve = vec![true; PHI_CACHE_SIZE];
+        sieve[0] = false;
+        for a in 0..PHI_CACHE_PRIMES {
+            if a != 0 {
+                let p = usize::exact_from(primes[a - 1]);
+                for i in (p..PHI_CACHE_SIZE).step_by(p) {
+                    sieve[i] = false;
+                }
+            }
+            let mut count = 0;
+            phi_cache.push(
+                sieve
+                    .iter()
+                    .map(|&b| {
+                        if b {
+                            count += 1;
+                        }
+                        count
+                    })
+                    .collect(),
+            );
+        }
+        MeisselContext {
+            primes,
+            limit,
+            wheel_counts,
+            phi_cache,
+        }
+    }
+
+    // Returns the `i`th prime, where the first prime, 2, is the 0th.
+    fn prime(&self, i: usize) -> u64 {
+        u64::from(self.primes[i])
+    }
+
+    // Returns pi(x), where `x` <= `self.limit`.
+    fn small_pi(&self, x: u64) -> u64 {
+        u64::wrapping_from(self.primes.partition_point(|&p| u64::from(p) <= x))
+    }
+
+    // Returns phi(x, a), the number of integers in [1, x] that are not divisible by any of the
+    // first `a` primes, where `a` <= `WHEEL_PRIMES.len()`.
+    fn wheel_phi(&self, x: u64, a: usize) -> u64 {
+        if a == 0 {
+            x
+        } else if a == WHEEL_PRIMES.len() {
+            x / WHEEL_SIZE * WHEEL_TOTIENT
+                + u64::from(self.wheel_counts[usize::exact_from(x % WHEEL_SIZE)])
+        } else {
+            self.wheel_phi(x, a - 1) - self.wheel_phi(x / WHEEL_PRIMES[a - 1], a - 1)
+        }
+    }
+
+    // Returns phi(x, a), the number of integers in [1, x] that are not divisible by any of the
+    // first `a` primes.
+    fn phi(&self, x: u64, a: usize) -> u64 {
+        if a <= WHEEL_PRIMES.len() {
+            return self.wheel_phi(x, a);
+        }
+        if a < PHI_CACHE_PRIMES && x < PHI_CACHE_SIZE as u64 {
+            return u64::from(self.phi_cache[a][usize::wrapping_from(x)]);
+        }
+        let p = self.prime(a);
+        if x <= self.limit && p.checked_mul(p).map_or(true, |p_squared| x < p_squared) {
+            // The numbers counted are 1 and the primes greater than the ath prime.
+            let pi = self.small_pi(x);
+            return if pi >= u64::wrapping_from(a) {
+                pi - u64::wrapping_from(a) + 1
+            } else {
+                min(x, 1)
+            };
+        }
+        // phi(x, a) = phi(x, a - 1) - phi(x / p_a, a - 1), unrolled down to the wheel.
+        let mut result = self.wheel_phi(x, WHEEL_PRIMES.len());
+        for i in WHEEL_PRIMES.len()..a {
+            let p = self.prime(i);
+            let y = x / p;
+            if y < p {
+                // From here on, each phi(y, i) is 1 if y is positive and 0 otherwise.
+                let count = min(a, usize::exact_from(self.small_pi(x)));
+                result -= u64::exact_from(count.saturating_sub(i));
+                break;
+            }
+            result -= self.phi(y, i);
+        }
+        result
+    }
+
+    // Returns pi(x). If `x` > `self.limit`, Meissel's formula is used:
+    //
+    // pi(x) = phi(x, a) + a - 1 - sum_{a < i <= b} (pi(x / p_i) - i + 1),
+    //
+    // where a = pi(x^(1/3)) and b = pi(x^(1/2)).
+    fn pi(&self, x: u64) -> u64 {
+        if x <= self.limit {
+            return self.small_pi(x);
+        }
+        let a = self.small_pi(x.floor_root(3));
+        let b = self.small_pi(x.floor_sqrt());
+        let mut result = self.phi(x, usize::exact_from(a)) + a - 1 + (a + b - 1) * (b - a) / 2;
+        for i in usize::exact_from(a)..usize::exact_from(b) {
+            result -= self.pi(x / self.prime(i));
+        }
+        result
+    }
+}
+
+fn prime_pi_u64(n: u64) -> u64 {
+    if n <= SIEVE_LIMIT {
+        prime_pi_by_sieve(n)
+    } else {
+        let limit = max(n.floor_sqrt(), min(n.floor_root(3).pow(2), SIEVE_LIMIT));
+        MeisselContext::new(limit).pi(n)
+    }
+}
+
+macro_rules! impl_prime_pi {
+    ($t:ident) => {
+        impl PrimePi for $t {
+            /// Counts the primes less than or equal to a number.
+            ///
+            /// Small values are handled by counting the primes in a prime sieve. Larger ones use
+            /// Meissel's formula, which expresses $\pi(n)$ in terms of $\pi$ at smaller arguments
+            /// and Legendre's function $\phi(x, a)$, the number of integers in $[1, x]$ with no
+            /// prime factor among the first $a$ primes.
+            ///
+            /// In practice, this is only suitable for arguments up to about $10^{14}$, which take
+            /// tens of seconds; each further factor of 10 multiplies the running time by more than
+            /// 10. All primes up to $\max(\sqrt{n}, 2^{26})$ are kept in memory, 4 bytes each.
+            /// That is about 16 MB for arguments less than about $4.5 \times 10^{15}$, but would
+            /// be about 800 MB for arguments near $2^{64}$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^n/n^3)$
+            ///
+            /// $M(n) = O(2^{n/2})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::prime_pi#prime_pi).
+            #[inline]
+            fn prime_pi(&self) -> u64 {
+                prime_pi_u64(u64::wrapping_from(*self))
+            }
+        }
+    };
+}
+impl_prime_pi!(u8);
+impl_prime_pi!(u16);
+impl_prime_pi!(u32);
+impl_prime_pi!(u64);
+impl_prime_pi!(usize);
+
+impl PrimePi for u128 {
+    /// Counts the primes less than or equal to a `u128`.
+    ///
+    /// See the implementation for [`u64`] for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^n/n^3)$
+    ///
+    /// $M(n) = O(2^{n/2})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $2^{64}$.
+    ///
+    /// # Examples
+    /// See [here](super::prime_pi#prime_pi).
+    #[inline]
+    fn prime_pi(&self) -> u64 {
+        prime_pi_u64(u64::exact_from(*self))
+    }
+}
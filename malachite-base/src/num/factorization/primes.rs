@@ -5,7 +5,8 @@ use crate::num::factorization::prime_sieve::{
 };
 use crate::num::factorization::traits::Primes;
 use crate::num::logic::traits::TrailingZeros;
-use std::marker::PhantomData;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 
 // This differs from the identically-named function in malachite-nz; this one returns None if there
 // are no more false bits.
@@ -0,0 +1,43 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Totient;
+
+fn totient_unsigned<T: PrimitiveUnsigned>(n: T) -> T {
+    assert_ne!(n, T::ZERO, "Cannot take the totient of 0");
+    let mut totient = T::ONE;
+    for (p, k) in n.factor() {
+        totient *= p.pow(k - 1) * (p - T::ONE);
+    }
+    totient
+}
+
+macro_rules! impl_totient {
+    ($t:ident) => {
+        impl Totient for $t {
+            /// Computes Euler's totient function of a number: the number of positive integers less
+            /// than or equal to it that are coprime to it.
+            ///
+            /// $$
+            /// f(n) = \varphi(n) = n \prod_{p | n} \left ( 1 - \frac{1}{p} \right ),
+            /// $$
+            /// where the product is taken over the distinct primes dividing $n$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $t {
+                totient_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_totient);
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 pub trait Primes {
     type I: Iterator<Item = Self>;
     type LI: Iterator<Item = Self>;
@@ -8,3 +10,71 @@ pub trait Primes {
 
     fn primes() -> Self::I;
 }
+
+/// Determines whether a number is prime.
+pub trait IsPrime {
+    fn is_prime(&self) -> bool;
+}
+
+/// Factors a number into primes.
+///
+/// The factors are returned as a list of distinct primes in ascending order, each paired with its
+/// multiplicity.
+pub trait Factor: Sized {
+    fn factor(&self) -> Vec<(Self, u64)>;
+}
+
+/// Computes Euler's totient function of a number: the number of positive integers less than or
+/// equal to it that are coprime to it.
+pub trait Totient: Sized {
+    fn totient(&self) -> Self;
+}
+
+/// Computes the Carmichael function of a number: the smallest positive exponent $e$ such that
+/// $a^e \equiv 1 \mod n$ for every $a$ coprime to the number $n$.
+pub trait CarmichaelLambda: Sized {
+    fn carmichael_lambda(&self) -> Self;
+}
+
+/// Counts the positive divisors of a number.
+pub trait DivisorCount {
+    fn divisor_count(&self) -> u64;
+}
+
+/// Computes the sum of the $k$th powers of the positive divisors of a number.
+pub trait DivisorSum: Sized {
+    fn divisor_sum(&self, k: u64) -> Self;
+}
+
+/// Computes the sum of the $k$th powers of the positive divisors of a number, returning `None` if
+/// the result is not representable.
+pub trait CheckedDivisorSum: Sized {
+    fn checked_divisor_sum(&self, k: u64) -> Option<Self>;
+}
+
+/// Computes the Möbius function of a number.
+pub trait Moebius {
+    fn moebius(&self) -> i8;
+}
+
+/// Generates the positive divisors of a number.
+pub trait Divisors: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+}
+
+/// Finds the smallest prime greater than a number.
+pub trait NextPrime: Sized {
+    fn next_prime(&self) -> Self;
+}
+
+/// Finds the largest prime less than a number.
+pub trait PrecedingPrime: Sized {
+    fn preceding_prime(&self) -> Self;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimePi {
+    fn prime_pi(&self) -> u64;
+}
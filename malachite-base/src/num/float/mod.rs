@@ -1,8 +1,8 @@
 use crate::num::basic::floats::PrimitiveFloat;
-use std::cmp::Ordering;
-use std::fmt::{self, Debug, Display, Formatter};
-use std::hash::{Hash, Hasher};
-use std::str::FromStr;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 /// `NiceFloat` is a wrapper around primitive float types that provides nicer [`Eq`], [`Ord`],
 /// [`Hash`], [`Display`], and [`FromStr`] instances.
@@ -208,7 +208,7 @@ macro_rules! impl_fmt_ryu_string {
                         out_bytes_hi[0] = b'.';
                         out_bytes_hi[1] = b'0';
                         out_bytes_hi[2..].copy_from_slice(in_bytes_hi);
-                        f.write_str(std::str::from_utf8(&out_bytes).unwrap())
+                        f.write_str(core::str::from_utf8(&out_bytes).unwrap())
                     } else {
                         panic!("Unexpected Ryu string: {}", printed);
                     }
@@ -5,7 +5,7 @@ use crate::num::basic::signeds::PrimitiveSigned;
 use crate::num::basic::unsigneds::PrimitiveUnsigned;
 use crate::num::conversion::traits::ExactInto;
 use crate::random::Seed;
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 /// Generates random unsigned integers from a truncated geometric distribution.
 #[derive(Clone, Debug)]
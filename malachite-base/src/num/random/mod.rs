@@ -19,13 +19,16 @@ use crate::num::random::geometric::{
 use crate::random::{Seed, EXAMPLE_SEED};
 use crate::rounding_modes::RoundingMode;
 use crate::vecs::{random_values_from_vec, RandomValuesFromVec};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::convert::identity;
+use core::fmt::Debug;
+use core::marker::PhantomData;
 use itertools::Itertools;
 use rand::Rng;
 use rand_chacha::ChaCha20Rng;
-use std::collections::HashMap;
-use std::convert::identity;
-use std::fmt::Debug;
-use std::marker::PhantomData;
 
 // Uniformly generates random primitive integers.
 #[doc(hidden)]
@@ -1353,7 +1356,7 @@ pub fn random_primitive_floats<T: PrimitiveFloat>(seed: Seed) -> RandomPrimitive
 pub struct SpecialRandomPositiveFiniteFloats<T: PrimitiveFloat> {
     seed: Seed,
     sci_exponents: GeometricRandomSignedRange<i64>,
-    range_map: HashMap<i64, GeometricRandomNaturalValues<u64>>,
+    range_map: BTreeMap<i64, GeometricRandomNaturalValues<u64>>,
     ranges: VariableRangeGenerator,
     mean_precision_n: u64,
     mean_precision_d: u64,
@@ -1454,7 +1457,7 @@ pub fn special_random_positive_finite_primitive_floats<T: PrimitiveFloat>(
             mean_sci_exponent_numerator,
             mean_sci_exponent_denominator,
         ),
-        range_map: HashMap::new(),
+        range_map: BTreeMap::new(),
         ranges: variable_range_generator(seed.fork("ranges")),
         mean_precision_n: mean_precision_numerator,
         mean_precision_d: mean_precision_denominator,
@@ -2008,7 +2011,7 @@ pub struct SpecialRandomPositiveFiniteFloatInclusiveRange<T: PrimitiveFloat> {
     ae: i64, // sci_exponent
     be: i64,
     sci_exponents: GeometricRandomSignedRange<i64>,
-    precision_range_map: HashMap<i64, Vec<(i64, u64, u64)>>,
+    precision_range_map: BTreeMap<i64, Vec<(i64, u64, u64)>>,
     precision_indices: GeometricRandomNaturalValues<usize>,
     ranges: VariableRangeGenerator,
 }
@@ -2091,7 +2094,7 @@ fn special_random_positive_finite_float_inclusive_range<T: PrimitiveFloat>(
             mean_sci_exponent_numerator,
             mean_sci_exponent_denominator,
         ),
-        precision_range_map: HashMap::new(),
+        precision_range_map: BTreeMap::new(),
         precision_indices: geometric_random_unsigneds(
             seed.fork("precisions"),
             mean_precision_numerator,
@@ -12,9 +12,11 @@ use crate::num::random::{
     RandomUnsignedRange,
 };
 use crate::random::Seed;
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::iter::{repeat, Repeat};
+use core::marker::PhantomData;
 use itertools::Itertools;
-use std::iter::{repeat, Repeat};
-use std::marker::PhantomData;
 
 /// Generates bits from a striped random sequence.
 ///
@@ -288,31 +290,6 @@ impl<T: PrimitiveSigned> Iterator for StripedRandomNegativeSigneds<T> {
     }
 }
 
-/// Generates random unsigned integers less than a positive limit, from a random striped
-/// distribution.
-///
-/// This `struct` is created by [`striped_random_unsigneds_less_than`]; see its documentation for
-/// more.
-#[derive(Clone, Debug)]
-struct StripedRandomUnsignedsLessThan<T: PrimitiveUnsigned> {
-    pub(crate) xs: StripedRandomUnsignedBitChunks<T>,
-    pub(crate) limit: T,
-}
-
-impl<T: PrimitiveUnsigned> Iterator for StripedRandomUnsignedsLessThan<T> {
-    type Item = T;
-
-    #[inline]
-    fn next(&mut self) -> Option<T> {
-        loop {
-            let x = self.xs.next();
-            if x.unwrap() < self.limit {
-                return x;
-            }
-        }
-    }
-}
-
 /// Generates random unsigned integers from a random striped distribution.
 ///
 /// See [here](self) for more information.
@@ -1,8 +1,8 @@
 use crate::orderings::ORDERINGS;
 use crate::random::Seed;
 use crate::slices::{random_values_from_slice, RandomValuesFromSlice};
-use std::cmp::Ordering;
-use std::iter::Cloned;
+use core::cmp::Ordering;
+use core::iter::Cloned;
 
 pub type RandomOrderings = Cloned<RandomValuesFromSlice<'static, Ordering>>;
 
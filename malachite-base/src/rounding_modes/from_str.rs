@@ -1,5 +1,6 @@
 use crate::rounding_modes::RoundingMode;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use core::str::FromStr;
 
 impl FromStr for RoundingMode {
     type Err = String;
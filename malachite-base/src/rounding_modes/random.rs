@@ -1,7 +1,7 @@
 use crate::random::Seed;
 use crate::rounding_modes::{RoundingMode, ROUNDING_MODES};
 use crate::slices::{random_values_from_slice, RandomValuesFromSlice};
-use std::iter::Cloned;
+use core::iter::Cloned;
 
 /// Uniformly generates random [`RoundingMode`]s.
 pub type RandomRoundingModes = Cloned<RandomValuesFromSlice<'static, RoundingMode>>;
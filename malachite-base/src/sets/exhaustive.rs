@@ -2,8 +2,11 @@ use crate::vecs::exhaustive::{
     ExhaustiveOrderedUniqueCollections, LexFixedLengthOrderedUniqueCollections,
     LexOrderedUniqueCollections, ShortlexOrderedUniqueCollections,
 };
-use std::collections::{BTreeSet, HashSet};
-use std::hash::Hash;
+use alloc::collections::BTreeSet;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
 /// Generates [`HashSet`]s of a given size with elements from a single iterator.
 ///
@@ -55,6 +58,7 @@ use std::hash::Hash;
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn lex_hash_sets_fixed_length<I: Iterator>(
     k: u64,
     xs: I,
@@ -115,6 +119,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn shortlex_hash_sets<I: Clone + Iterator>(
     xs: I,
 ) -> ShortlexOrderedUniqueCollections<I, HashSet<I::Item>>
@@ -170,6 +175,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn shortlex_hash_sets_min_length<I: Clone + Iterator>(
     min_length: u64,
     xs: I,
@@ -229,6 +235,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn shortlex_hash_sets_length_range<I: Clone + Iterator>(
     mut a: u64,
     mut b: u64,
@@ -295,6 +302,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn shortlex_hash_sets_length_inclusive_range<I: Clone + Iterator>(
     a: u64,
     b: u64,
@@ -356,6 +364,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn lex_hash_sets<I: Clone + Iterator>(xs: I) -> LexOrderedUniqueCollections<I, HashSet<I::Item>>
 where
     I::Item: Clone + Eq + Hash,
@@ -409,6 +418,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn lex_hash_sets_min_length<I: Clone + Iterator>(
     min_length: u64,
     xs: I,
@@ -468,6 +478,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn lex_hash_sets_length_range<I: Clone + Iterator>(
     mut a: u64,
     mut b: u64,
@@ -534,6 +545,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn lex_hash_sets_length_inclusive_range<I: Clone + Iterator>(
     a: u64,
     b: u64,
@@ -592,6 +604,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn exhaustive_hash_sets_fixed_length<I: Clone + Iterator>(
     k: u64,
     xs: I,
@@ -649,6 +662,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn exhaustive_hash_sets<I: Clone + Iterator>(
     xs: I,
 ) -> ExhaustiveOrderedUniqueCollections<I, HashSet<I::Item>>
@@ -701,6 +715,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn exhaustive_hash_sets_min_length<I: Clone + Iterator>(
     min_length: u64,
     xs: I,
@@ -757,6 +772,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn exhaustive_hash_sets_length_range<I: Clone + Iterator>(
     a: u64,
     b: u64,
@@ -818,6 +834,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn exhaustive_hash_sets_length_inclusive_range<I: Clone + Iterator>(
     a: u64,
     b: u64,
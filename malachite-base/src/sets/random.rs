@@ -8,13 +8,17 @@ use crate::num::random::{
     RandomUnsignedRange,
 };
 use crate::random::Seed;
-use std::collections::{BTreeSet, HashSet};
-use std::hash::Hash;
+use alloc::collections::BTreeSet;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
 /// Generates random [`HashSet`]s of a fixed length, where the [`Vec`]s have no repeated elements,
 /// and the elements are in ascending order.
 ///
 /// This `struct` is created by [`random_hash_sets_fixed_length`]; see its documentation for more.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
 pub struct RandomHashSetsFixedLength<I: Iterator>
 where
@@ -24,6 +28,7 @@ where
     xs: I,
 }
 
+#[cfg(feature = "std")]
 impl<I: Iterator> Iterator for RandomHashSetsFixedLength<I>
 where
     I::Item: Eq + Hash,
@@ -88,6 +93,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets_fixed_length<I: Iterator>(len: u64, xs: I) -> RandomHashSetsFixedLength<I>
 where
     I::Item: Eq + Hash,
@@ -99,12 +105,14 @@ where
 }
 
 /// Generates random [`HashSet`]s with lengths from an iterator.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
 pub struct RandomHashSets<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> {
     lengths: I,
     xs: J,
 }
 
+#[cfg(feature = "std")]
 impl<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> Iterator
     for RandomHashSets<T, I, J>
 {
@@ -178,6 +186,7 @@ impl<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> Iterator
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets_from_length_iterator<
     T: Eq + Hash,
     I: Iterator<Item = u64>,
@@ -257,6 +266,7 @@ pub fn random_hash_sets_from_length_iterator<
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets<I: Iterator>(
     seed: Seed,
     xs_gen: &dyn Fn(Seed) -> I,
@@ -346,6 +356,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets_min_length<I: Iterator>(
     seed: Seed,
     min_length: u64,
@@ -433,6 +444,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets_length_range<I: Iterator>(
     seed: Seed,
     a: u64,
@@ -511,6 +523,7 @@ where
 /// }
 /// ```
 #[inline]
+#[cfg(feature = "std")]
 pub fn random_hash_sets_length_inclusive_range<I: Iterator>(
     seed: Seed,
     a: u64,
@@ -3,6 +3,7 @@ use crate::num::basic::traits::Zero;
 use crate::num::conversion::traits::ExactFrom;
 use crate::num::random::{random_unsigneds_less_than, RandomUnsignedsLessThan};
 use crate::random::Seed;
+use alloc::vec::Vec;
 use rand::prelude::SliceRandom;
 use rand_chacha::ChaCha20Rng;
 
@@ -1,7 +1,10 @@
 use crate::named::Named;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Binary, Debug, LowerHex, Octal, UpperHex};
 use itertools::Itertools;
+#[cfg(feature = "std")]
 use std::collections::HashSet;
-use std::fmt::{Binary, Debug, LowerHex, Octal, UpperHex};
 
 /// Sorts the characters of a string slice and returns them in a new [`String`].
 ///
@@ -43,6 +46,7 @@ pub fn string_sort(s: &str) -> String {
 /// assert_eq!(string_unique("Hello, world!"), "Helo, wrd!");
 /// assert_eq!(string_unique("Mississippi"), "Misp");
 /// ```
+#[cfg(feature = "std")]
 pub fn string_unique(s: &str) -> String {
     let mut chars = HashSet::new();
     let mut nub = String::new();
@@ -75,6 +79,7 @@ pub fn string_unique(s: &str) -> String {
 /// assert_eq!(string_is_subset("Hello, World!", "Hello, world!"), false);
 /// assert_eq!(string_is_subset("j", "Mississippi"), false);
 /// ```
+#[cfg(feature = "std")]
 pub fn string_is_subset(s: &str, t: &str) -> bool {
     let t_chars: HashSet<char> = t.chars().collect();
     s.chars().all(|c| t_chars.contains(&c))
@@ -0,0 +1,23 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn factor_naive<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u64)> {
+    assert_ne!(n, T::ZERO);
+    let mut factors = Vec::new();
+    let mut d = T::TWO;
+    while n != T::ONE {
+        if d.checked_square().map_or(true, |d_squared| d_squared > n) {
+            factors.push((n, 1));
+            break;
+        }
+        let mut e = 0;
+        while n.divisible_by(d) {
+            n /= d;
+            e += 1;
+        }
+        if e != 0 {
+            factors.push((d, e));
+        }
+        d += T::ONE;
+    }
+    factors
+}
@@ -0,0 +1,20 @@
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn is_prime_naive<T: PrimitiveUnsigned>(n: T) -> bool {
+    match n {
+        n if n < T::TWO => false,
+        n if n == T::TWO => true,
+        n if n.even() => false,
+        n => {
+            let limit = n.floor_sqrt();
+            let mut f = T::from(3u8);
+            while f <= limit {
+                if n.divisible_by(f) {
+                    return false;
+                }
+                f += T::TWO;
+            }
+            true
+        }
+    }
+}
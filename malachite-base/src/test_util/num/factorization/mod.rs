@@ -1,2 +1,4 @@
+pub mod factor;
+pub mod is_prime;
 pub mod prime_sieve;
 pub mod primes;
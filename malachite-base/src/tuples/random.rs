@@ -1,6 +1,6 @@
 use crate::random::Seed;
-use std::cmp::Ordering;
-use std::iter::{repeat, Repeat};
+use core::cmp::Ordering;
+use core::iter::{repeat, Repeat};
 
 /// Generates random units; repeats `()`.
 ///
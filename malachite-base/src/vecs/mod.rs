@@ -2,9 +2,11 @@ use crate::num::conversion::traits::ExactFrom;
 use crate::num::random::{random_unsigneds_less_than, RandomUnsignedsLessThan};
 use crate::random::Seed;
 use crate::slices::advance_indices;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
 use rand::prelude::SliceRandom;
 use rand_chacha::ChaCha20Rng;
-use std::str::FromStr;
 
 /// Inserts several copies of a value at the left (beginning) of a [`Vec`].
 ///
@@ -13,10 +13,14 @@ use crate::sets::random::{
     RandomBTreeSetsFixedLength,
 };
 use crate::vecs::exhaustive::validate_oi_map;
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+#[cfg(feature = "std")]
+use core::iter::{repeat, Repeat};
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::hash::Hash;
-use std::iter::{repeat, Repeat};
 
 /// Generates random [`Vec`]s of a given length using elements from a single iterator.
 ///
@@ -1234,12 +1238,14 @@ where
 
 /// Generates random [`Vec`]s with lengths from an iterator, where the [`Vec`]s have no repeated
 /// elements.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
 pub struct RandomUniqueVecs<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> {
     lengths: I,
     xs: J,
 }
 
+#[cfg(feature = "std")]
 impl<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> Iterator
     for RandomUniqueVecs<T, I, J>
 {
@@ -1316,6 +1322,7 @@ impl<T: Eq + Hash, I: Iterator<Item = u64>, J: Iterator<Item = T>> Iterator
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs_from_length_iterator<
     T: Eq + Hash,
@@ -1335,6 +1342,7 @@ pub fn random_unique_vecs_from_length_iterator<
 /// Generates random [`Vec`]s of a fixed length, where the [`Vec`]s have no repeated elements.
 ///
 /// This `enum` is created by [`random_unique_vecs_fixed_length`]; see its documentation for more.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
 pub enum RandomUniqueVecsFixedLength<I: Iterator>
 where
@@ -1346,6 +1354,7 @@ where
     GreaterThan2(RandomUniqueVecs<I::Item, Repeat<u64>, I>),
 }
 
+#[cfg(feature = "std")]
 impl<I: Iterator> Iterator for RandomUniqueVecsFixedLength<I>
 where
     I::Item: Eq + Hash,
@@ -1401,6 +1410,7 @@ where
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs_fixed_length<I: Iterator>(
     len: u64,
@@ -1473,6 +1483,7 @@ where
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs<I: Iterator>(
     seed: Seed,
@@ -1549,6 +1560,7 @@ where
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs_min_length<I: Iterator>(
     seed: Seed,
@@ -1628,6 +1640,7 @@ where
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs_length_range<I: Iterator>(
     seed: Seed,
@@ -1698,6 +1711,7 @@ where
 ///     ]
 /// );
 /// ```
+#[cfg(feature = "std")]
 #[inline]
 pub fn random_unique_vecs_length_inclusive_range<I: Iterator>(
     seed: Seed,
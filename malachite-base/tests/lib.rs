@@ -195,8 +195,10 @@ pub mod num {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod floor;
         pub mod gcd;
+        pub mod is_perfect_power;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
         pub mod lcm;
@@ -206,6 +208,7 @@ pub mod num {
         pub mod mod_add;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
@@ -223,6 +226,7 @@ pub mod num {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
         pub mod neg;
@@ -373,8 +377,19 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod carmichael_lambda;
+        pub mod divisor_count;
+        pub mod divisor_sum;
+        pub mod divisors;
+        pub mod factor;
+        pub mod is_prime;
+        pub mod moebius;
+        pub mod next_prime;
+        pub mod preceding_prime;
+        pub mod prime_pi;
         pub mod prime_sieve;
         pub mod primes;
+        pub mod totient;
     }
     pub mod float {
         pub mod basic {
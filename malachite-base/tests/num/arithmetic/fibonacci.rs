@@ -0,0 +1,209 @@
+use malachite_base::num::arithmetic::traits::Fibonacci;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::fibonacci(n), out);
+    }
+    test::<u8>(0, 0);
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(3, 2);
+    test::<u8>(4, 3);
+    test::<u8>(5, 5);
+    test::<u8>(10, 55);
+    test::<u8>(13, 233);
+    test::<u32>(20, 6765);
+    test::<u32>(47, 2971215073);
+    test::<u64>(93, 12200160415121876738);
+    test::<u128>(186, 332825110087067562321196029789634457848);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_fibonacci(n), out);
+    }
+    test::<u8>(0, Some(0));
+    test::<u8>(1, Some(1));
+    test::<u8>(2, Some(1));
+    test::<u8>(10, Some(55));
+    test::<u8>(13, Some(233));
+    test::<u32>(47, Some(2971215073));
+    test::<u64>(93, Some(12200160415121876738));
+
+    test::<u8>(14, None);
+    test::<u32>(48, None);
+    test::<u64>(94, None);
+    test::<u128>(187, None);
+    test::<u64>(u64::MAX, None);
+}
+
+#[test]
+fn test_fibonacci_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: (T, T)) {
+        assert_eq!(T::fibonacci_pair(n), out);
+    }
+    test::<u8>(0, (0, 1));
+    test::<u8>(1, (1, 0));
+    test::<u8>(2, (1, 1));
+    test::<u8>(3, (2, 1));
+    test::<u8>(10, (55, 34));
+    test::<u8>(13, (233, 144));
+    test::<u32>(20, (6765, 4181));
+}
+
+fn fibonacci_pair_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci_pair(200));
+}
+
+#[test]
+fn fibonacci_pair_fail() {
+    apply_fn_to_unsigneds!(fibonacci_pair_fail_helper);
+}
+
+#[test]
+fn test_checked_fibonacci_pair() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<(T, T)>) {
+        assert_eq!(T::checked_fibonacci_pair(n), out);
+    }
+    test::<u8>(0, Some((0, 1)));
+    test::<u8>(1, Some((1, 0)));
+    test::<u8>(10, Some((55, 34)));
+    test::<u8>(13, Some((233, 144)));
+
+    test::<u8>(14, None);
+    test::<u64>(94, None);
+}
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(10, 123);
+    test::<u8>(11, 199);
+    test::<u32>(20, 15127);
+    test::<u32>(46, 4106118243);
+    test::<u64>(92, 16860207025497407047);
+    test::<u128>(184, 284266580942632122201475224120405260207);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+#[test]
+fn test_checked_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: Option<T>) {
+        assert_eq!(T::checked_lucas(n), out);
+    }
+    test::<u8>(0, Some(2));
+    test::<u8>(1, Some(1));
+    test::<u8>(11, Some(199));
+    test::<u32>(46, Some(4106118243));
+
+    test::<u8>(12, None);
+    test::<u32>(47, None);
+    test::<u64>(93, None);
+    test::<u128>(185, None);
+}
+
+fn checked_fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let of = T::checked_fibonacci(n);
+        assert_eq!(T::checked_fibonacci_pair(n).map(|p| p.0), of);
+        if let Some(f) = of {
+            assert_eq!(T::fibonacci(n), f);
+        }
+        if n != u64::MAX && of.is_none() {
+            assert!(T::checked_fibonacci(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(f) = T::checked_fibonacci(n + 2) {
+            assert_eq!(T::fibonacci(n + 1) + T::fibonacci(n), f);
+        }
+        if let Some(f) = T::checked_fibonacci(n) {
+            assert_eq!(T::exact_from(u128::fibonacci(n)), f);
+        }
+    });
+}
+
+#[test]
+fn checked_fibonacci_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_properties_helper);
+}
+
+fn checked_fibonacci_pair_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let op = T::checked_fibonacci_pair(n);
+        if let Some((f, f_prev)) = op {
+            assert_eq!(T::fibonacci_pair(n), (f, f_prev));
+            if n != 0 {
+                assert_eq!(T::fibonacci(n - 1), f_prev);
+            }
+            if let Some((f_next, f_2)) = T::checked_fibonacci_pair(n + 1) {
+                assert_eq!(f_2, f);
+                assert_eq!(f_next, f + f_prev);
+            }
+        }
+    });
+}
+
+#[test]
+fn checked_fibonacci_pair_properties() {
+    apply_fn_to_unsigneds!(checked_fibonacci_pair_properties_helper);
+}
+
+fn checked_lucas_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen().test_properties(|n| {
+        let ol = T::checked_lucas(n);
+        if let Some(l) = ol {
+            assert_eq!(T::lucas(n), l);
+        }
+        if n != u64::MAX && ol.is_none() {
+            assert!(T::checked_lucas(n + 1).is_none());
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(l) = T::checked_lucas(n + 2) {
+            assert_eq!(T::lucas(n + 1) + T::lucas(n), l);
+        }
+        if n != 0 {
+            if let Some(l) = T::checked_lucas(n) {
+                // L_n = F_{n-1} + F_{n+1}
+                assert_eq!(T::fibonacci(n - 1) + T::fibonacci(n + 1), l);
+            }
+        }
+    });
+}
+
+#[test]
+fn checked_lucas_properties() {
+    apply_fn_to_unsigneds!(checked_lucas_properties_helper);
+}
@@ -0,0 +1,143 @@
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, Parity};
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+
+fn express_as_power_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: Option<(u8, u64)>| {
+        assert_eq!(
+            T::from(n).express_as_power(),
+            out.map(|(x, e)| (T::from(x), e))
+        );
+        assert_eq!(T::from(n).is_perfect_power(), out.is_some());
+    };
+    test(0, Some((0, 2)));
+    test(1, Some((1, 2)));
+    test(2, None);
+    test(3, None);
+    test(4, Some((2, 2)));
+    test(8, Some((2, 3)));
+    test(12, None);
+    test(16, Some((2, 4)));
+    test(27, Some((3, 3)));
+    test(32, Some((2, 5)));
+    test(36, Some((6, 2)));
+    test(64, Some((2, 6)));
+    test(100, Some((10, 2)));
+    test(128, Some((2, 7)));
+    test(200, None);
+    test(216, Some((6, 3)));
+    test(243, Some((3, 5)));
+    test(255, None);
+}
+
+fn express_as_power_signed_helper<T: PrimitiveSigned>() {
+    let test = |n: i8, out: Option<(i8, u64)>| {
+        assert_eq!(
+            T::from(n).express_as_power(),
+            out.map(|(x, e)| (T::from(x), e))
+        );
+        assert_eq!(T::from(n).is_perfect_power(), out.is_some());
+    };
+    test(0, Some((0, 2)));
+    test(1, Some((1, 2)));
+    test(-1, Some((-1, 3)));
+    test(-2, None);
+    test(-4, None);
+    test(4, Some((2, 2)));
+    test(-8, Some((-2, 3)));
+    test(-27, Some((-3, 3)));
+    test(-32, Some((-2, 5)));
+    test(64, Some((2, 6)));
+    test(-64, Some((-4, 3)));
+    test(-100, None);
+    test(-125, Some((-5, 3)));
+    test(127, None);
+}
+
+#[test]
+fn test_express_as_power() {
+    apply_fn_to_unsigneds!(express_as_power_helper);
+    apply_fn_to_signeds!(express_as_power_signed_helper);
+
+    assert_eq!(u16::MAX.express_as_power(), None);
+    assert_eq!(65536u32.express_as_power(), Some((2, 16)));
+    assert_eq!(59049u32.express_as_power(), Some((3, 10)));
+    assert_eq!(1000000u32.express_as_power(), Some((10, 6)));
+    assert_eq!(4294967296u64.express_as_power(), Some((2, 32)));
+    assert_eq!(4294836225u64.express_as_power(), Some((65535, 2)));
+    assert_eq!(10000000000000000000u64.express_as_power(), Some((10, 19)));
+    assert_eq!(
+        18446744030759878681u64.express_as_power(),
+        Some((4294967291, 2))
+    );
+    assert_eq!(u64::MAX.express_as_power(), None);
+    assert_eq!((1u128 << 127).express_as_power(), Some((2, 127)));
+    assert_eq!(3u128.pow(80).express_as_power(), Some((3, 80)));
+    assert_eq!((-128i8).express_as_power(), Some((-2, 7)));
+    assert_eq!(i32::MIN.express_as_power(), Some((-2, 31)));
+    assert_eq!(i64::MIN.express_as_power(), Some((-2, 63)));
+    assert_eq!((-(1i64 << 62)).express_as_power(), Some((-4, 31)));
+    assert_eq!((-(1i64 << 60)).express_as_power(), Some((-16, 15)));
+}
+
+fn express_as_power_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|n| {
+        let result = n.express_as_power();
+        assert_eq!(n.is_perfect_power(), result.is_some());
+        if let Some((x, e)) = result {
+            assert!(e > 1);
+            assert_eq!(x.pow(e), n);
+            if n > T::ONE {
+                assert!(!x.is_perfect_power());
+            }
+        } else {
+            assert!(n > T::ONE);
+            for e in 2..n.significant_bits() {
+                assert!(n.checked_root(e).is_none());
+            }
+        }
+    });
+
+    unsigned_gen::<T>().test_properties(|x| {
+        if x < T::TWO {
+            return;
+        }
+        for e in 2..T::WIDTH {
+            if let Some(n) = x.checked_pow(e) {
+                let (y, f) = n.express_as_power().unwrap();
+                assert_eq!(f % e, 0);
+                assert_eq!(y.pow(f / e), x);
+            }
+        }
+    });
+}
+
+fn express_as_power_properties_signed_helper<
+    U: PrimitiveUnsigned + WrappingFrom<S>,
+    S: PrimitiveSigned + WrappingFrom<U>,
+>() {
+    signed_gen::<S>().test_properties(|n| {
+        let result = n.express_as_power();
+        assert_eq!(n.is_perfect_power(), result.is_some());
+        if n >= S::ZERO {
+            assert_eq!(
+                result,
+                U::wrapping_from(n)
+                    .express_as_power()
+                    .map(|(x, e)| (S::wrapping_from(x), e))
+            );
+        } else if let Some((x, e)) = result {
+            assert!(e.odd());
+            assert!(x < S::ZERO);
+            assert_eq!(x.pow(e), n);
+        }
+    });
+}
+
+#[test]
+fn express_as_power_properties() {
+    apply_fn_to_unsigneds!(express_as_power_properties_helper);
+    apply_fn_to_unsigned_signed_pairs!(express_as_power_properties_signed_helper);
+}
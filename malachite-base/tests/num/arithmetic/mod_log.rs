@@ -0,0 +1,197 @@
+use malachite_base::num::arithmetic::mod_log::mod_log_with_order_factorization;
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_6, unsigned_pair_gen_var_16, unsigned_triple_gen_var_12,
+};
+use std::panic::catch_unwind;
+
+fn mod_log_helper<T: PrimitiveUnsigned>() {
+    let test = |h: u8, g: u8, m: u8, out: Option<u8>| {
+        let h = T::from(h);
+        let g = T::from(g);
+        let m = T::from(m);
+        let x = h.mod_log(g, m);
+        assert_eq!(x, out.map(T::from));
+        if let Some(x) = x {
+            assert_eq!(g.mod_pow(x.exact_into(), m), h);
+        }
+    };
+    test(0, 0, 1, Some(0));
+    test(1, 0, 2, Some(0));
+    test(0, 0, 2, Some(1));
+    test(0, 1, 2, None);
+    test(3, 2, 11, Some(8));
+    test(1, 2, 11, Some(0));
+    test(0, 2, 11, None);
+    test(7, 3, 17, Some(11));
+    test(3, 2, 7, None);
+    test(5, 2, 7, None);
+    test(13, 5, 23, Some(14));
+    test(1, 0, 5, Some(0));
+    test(0, 0, 5, Some(1));
+    test(0, 2, 8, Some(3));
+    test(4, 2, 8, Some(2));
+    test(6, 2, 10, Some(4));
+    test(8, 2, 24, Some(3));
+    test(16, 2, 24, Some(4));
+    test(9, 3, 18, Some(2));
+    test(0, 2, 32, Some(5));
+    test(1, 1, 100, Some(0));
+    test(2, 1, 100, None);
+}
+
+#[test]
+fn test_mod_log() {
+    apply_fn_to_unsigneds!(mod_log_helper);
+
+    let test = |h: u64, g: u64, m: u64, out: Option<u64>| {
+        assert_eq!(h.mod_log(g, m), out);
+        assert_eq!(
+            u128::from(h).mod_log(u128::from(g), u128::from(m)),
+            out.map(u128::from)
+        );
+    };
+    test(123456789, 5, 1000000007, Some(981640996));
+    test(1234567891011, 37, (1 << 61) - 1, Some(1248484803653020093));
+    // p - 1 = 2^2 * 11 * 137 * 547 * 5594472617641
+    test(3, 2, 18446744073709551557, Some(13738032082084024111));
+}
+
+#[test]
+fn test_mod_log_with_order_factorization() {
+    let test = |h: u64, g: u64, m: u64, factors: &[(u64, u64)], out: Option<u64>| {
+        let x = mod_log_with_order_factorization(h, g, m, factors);
+        assert_eq!(x, out);
+        assert_eq!(x, h.mod_log(g, m));
+        let factors_128: Vec<(u128, u64)> =
+            factors.iter().map(|&(p, k)| (u128::from(p), k)).collect();
+        assert_eq!(
+            mod_log_with_order_factorization(
+                u128::from(h),
+                u128::from(g),
+                u128::from(m),
+                &factors_128
+            ),
+            out.map(u128::from)
+        );
+    };
+    test(0, 0, 1, &[], Some(0));
+    test(3, 2, 11, &[(2, 1), (5, 1)], Some(8));
+    test(0, 2, 11, &[(2, 1), (5, 1)], None);
+    // A multiple of the order is enough
+    test(3, 2, 11, &[(2, 3), (3, 1), (5, 2)], Some(8));
+    test(4, 2, 7, &[(3, 1)], Some(2));
+    test(3, 2, 7, &[(3, 1)], None);
+    test(
+        123456789,
+        5,
+        1000000007,
+        &[(2, 1), (500000003, 1)],
+        Some(981640996),
+    );
+    test(
+        3,
+        2,
+        18446744073709551557,
+        &[(2, 2), (11, 1), (137, 1), (547, 1), (5594472617641, 1)],
+        Some(13738032082084024111),
+    );
+}
+
+fn mod_log_with_order_factorization_fail_helper<T: PrimitiveUnsigned>() {
+    // not reduced
+    assert_panic!(mod_log_with_order_factorization(
+        T::from(11u8),
+        T::TWO,
+        T::from(11u8),
+        &[(T::TWO, 1), (T::from(5u8), 1)]
+    ));
+    // base not coprime to m
+    assert_panic!(mod_log_with_order_factorization(
+        T::from(4u8),
+        T::TWO,
+        T::from(8u8),
+        &[(T::TWO, 2)]
+    ));
+    // not a multiple of the order
+    assert_panic!(mod_log_with_order_factorization(
+        T::from(3u8),
+        T::TWO,
+        T::from(11u8),
+        &[(T::TWO, 1)]
+    ));
+}
+
+#[test]
+fn mod_log_with_order_factorization_fail() {
+    apply_fn_to_unsigneds!(mod_log_with_order_factorization_fail_helper);
+}
+
+fn mod_log_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_log(T::ZERO, T::ZERO));
+    assert_panic!(T::from(10u8).mod_log(T::ONE, T::from(10u8)));
+    assert_panic!(T::ONE.mod_log(T::from(10u8), T::from(10u8)));
+}
+
+#[test]
+fn mod_log_fail() {
+    apply_fn_to_unsigneds!(mod_log_fail_helper);
+}
+
+fn mod_log_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_12::<T>().test_properties(|(h, g, m)| {
+        // Large moduli may require large baby-step giant-step tables.
+        if m.significant_bits() > 24 {
+            return;
+        }
+        let x = h.mod_log(g, m);
+        if g.coprime_with(m) {
+            assert_eq!(
+                mod_log_with_order_factorization(h, g, m, &m.totient().factor()),
+                x
+            );
+        }
+        if let Some(x) = x {
+            assert!(x < m);
+            let x: u64 = x.exact_into();
+            assert_eq!(g.mod_pow(x, m), h);
+            if m.significant_bits() <= 8 {
+                for y in 0..x {
+                    assert_ne!(g.mod_pow(y, m), h);
+                }
+            }
+        } else if m.significant_bits() <= 8 {
+            let mut y = T::ONE % m;
+            let n: u64 = m.exact_into();
+            for _ in 0..=n {
+                assert_ne!(y, h);
+                y.mod_mul_assign(g, m);
+            }
+        }
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties(|(g, m)| {
+        if m.significant_bits() > 24 {
+            return;
+        }
+        assert_eq!((T::ONE % m).mod_log(g, m), Some(T::ZERO));
+        let x = g.mod_log(g, m).unwrap();
+        assert!(x <= T::ONE);
+        let h = g.mod_pow(10, m);
+        assert!(h.mod_log(g, m).unwrap() <= T::from(10u8));
+    });
+
+    unsigned_gen_var_6::<T>().test_properties(|m| {
+        if m.significant_bits() > 24 {
+            return;
+        }
+        assert_eq!(T::ONE.mod_log(T::ONE, m), Some(T::ZERO));
+        assert_eq!(T::ZERO.mod_log(T::ONE, m), None);
+    });
+}
+
+#[test]
+fn mod_log_properties() {
+    apply_fn_to_unsigneds!(mod_log_properties_helper);
+}
@@ -0,0 +1,173 @@
+use malachite_base::num::arithmetic::mod_sqrt::mod_sqrt_with_factorization;
+use malachite_base::num::arithmetic::traits::{ModMul, ModSqrt};
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{unsigned_gen_var_6, unsigned_pair_gen_var_16};
+use std::panic::catch_unwind;
+
+fn mod_sqrt_helper<T: PrimitiveUnsigned>() {
+    let test = |x: u8, m: u8, out: Option<u8>| {
+        let x = T::from(x);
+        let m = T::from(m);
+        let root = x.mod_sqrt(m);
+        assert_eq!(root, out.map(T::from));
+        if let Some(root) = root {
+            assert_eq!(root.mod_mul(root, m), x);
+        }
+    };
+    test(0, 1, Some(0));
+    test(0, 2, Some(0));
+    test(1, 2, Some(1));
+    test(0, 7, Some(0));
+    test(1, 7, Some(1));
+    test(2, 7, Some(3));
+    test(3, 7, None);
+    test(4, 7, Some(2));
+    test(13, 17, Some(8));
+    test(3, 17, None);
+    test(2, 97, Some(14));
+    test(1, 4, Some(1));
+    test(3, 4, None);
+    test(4, 15, Some(7));
+    test(17, 64, Some(9));
+    test(3, 64, None);
+    test(9, 36, Some(9));
+    test(12, 36, None);
+    test(27, 81, None);
+    test(18, 81, None);
+    test(36, 81, Some(6));
+    test(0, 128, Some(0));
+    test(64, 128, Some(8));
+    test(32, 128, None);
+    test(254, 255, None);
+    test(253, 255, None);
+}
+
+#[test]
+fn test_mod_sqrt() {
+    apply_fn_to_unsigneds!(mod_sqrt_helper);
+
+    let test = |x: u64, m: u64, out: Option<u64>| {
+        assert_eq!(x.mod_sqrt(m), out);
+        assert_eq!(u128::from(x).mod_sqrt(u128::from(m)), out.map(u128::from));
+    };
+    // p - 1 is odd times a small power of 2, so Tonelli-Shanks is used.
+    test(2, 1000000007, Some(59713600));
+    test(5, 1000000007, None);
+    test(5, (1 << 61) - 1, Some(659791110852991619));
+    // p - 1 is divisible by a large power of 2, so Cipolla's algorithm is used.
+    test(7, 998244353, Some(116190042));
+    test(15, 998244353, Some(383299855));
+    test(3, 998244353, None);
+    test(2, 18446744069414584321, Some(1099494850304));
+    test(3, 18446744069414584321, Some(281474976579584));
+    // Composite moduli
+    test(4, 1000000014000000049, Some(2));
+    test(2, 1000000014000000049, Some(404504992771821330));
+    test(1000000000000000000, 1000000014000000049, Some(1000000000));
+    test(1000000014000000048, 1000000014000000049, None);
+
+    assert_eq!(
+        3u128.mod_sqrt(340282366920938463463374607431768211297),
+        Some(37588732492396219416677157024388183606)
+    );
+    assert_eq!(7u128.mod_sqrt(u128::MAX >> 1), None);
+}
+
+#[test]
+fn test_mod_sqrt_with_factorization() {
+    let test = |x: u64, factors: &[(u64, u64)], out: Option<u64>| {
+        let root = mod_sqrt_with_factorization(x, factors);
+        assert_eq!(root, out);
+        if let Some(root) = root {
+            let m = factors
+                .iter()
+                .map(|&(p, k)| p.pow(u32::try_from(k).unwrap()))
+                .product();
+            assert_eq!(root.mod_mul(root, m), x);
+        }
+        let factors_128: Vec<(u128, u64)> =
+            factors.iter().map(|&(p, k)| (u128::from(p), k)).collect();
+        assert_eq!(
+            mod_sqrt_with_factorization(u128::from(x), &factors_128),
+            out.map(u128::from)
+        );
+    };
+    test(0, &[], Some(0));
+    test(2, &[(7, 1)], Some(3));
+    test(36, &[(3, 4)], Some(6));
+    test(4, &[(3, 1), (5, 1)], Some(7));
+    test(4, &[(8191, 1), (2147483647, 1)], Some(2));
+    test(3, &[(8191, 1), (2147483647, 1)], None);
+    test(2, &[(1000000007, 2)], Some(404504992771821330));
+    test(5, &[(1000000007, 1)], None);
+
+    let p = 18446744073709551557u128;
+    let factors = [(p, 1), (3, 1)];
+    let root = mod_sqrt_with_factorization(4, &factors).unwrap();
+    assert_eq!(root.mod_mul(root, 3 * p), 4);
+}
+
+fn mod_sqrt_with_factorization_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(mod_sqrt_with_factorization(T::ONE, &[]));
+    assert_panic!(mod_sqrt_with_factorization(T::ONE, &[(T::from(3u8), 0)]));
+    assert_panic!(mod_sqrt_with_factorization(
+        T::from(9u8),
+        &[(T::from(3u8), 2)]
+    ));
+    assert_panic!(mod_sqrt_with_factorization(T::ONE, &[(T::TWO, T::WIDTH)]));
+    assert_panic!(mod_sqrt_with_factorization(
+        T::ONE,
+        &[(T::from(3u8), 1), (T::TWO, T::WIDTH - 1)]
+    ));
+}
+
+#[test]
+fn mod_sqrt_with_factorization_fail() {
+    apply_fn_to_unsigneds!(mod_sqrt_with_factorization_fail_helper);
+}
+
+fn mod_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_sqrt(T::ZERO));
+    assert_panic!(T::from(10u8).mod_sqrt(T::from(10u8)));
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_sqrt_fail_helper);
+}
+
+fn mod_sqrt_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_16::<T>().test_properties(|(x, m)| {
+        let root = x.mod_sqrt(m);
+        if let Some(root) = root {
+            assert!(root < m);
+            assert_eq!(root.mod_mul(root, m), x);
+            if m.is_prime() && root != T::ZERO {
+                assert!(root <= m - root);
+            }
+        } else if m.significant_bits() <= 8 {
+            let mut y = T::ZERO;
+            while y < m {
+                assert_ne!(y.mod_mul(y, m), x);
+                y += T::ONE;
+            }
+        }
+        assert!(x.mod_mul(x, m).mod_sqrt(m).is_some());
+        assert_eq!(mod_sqrt_with_factorization(x, &m.factor()), root);
+    });
+
+    unsigned_gen_var_6::<T>().test_properties(|m| {
+        assert_eq!(T::ZERO.mod_sqrt(m), Some(T::ZERO));
+        assert_eq!(T::ONE.mod_sqrt(m), Some(T::ONE));
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    // Factoring large moduli is slow, so u128 is skipped.
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+}
@@ -1,4 +1,4 @@
-use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::arithmetic::traits::{Parity, Pow};
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
@@ -37,6 +37,15 @@ fn test_pow() {
         assert_eq!(NiceFloat(x), NiceFloat(out));
     }
     test_i64_primitive_float::<f32>(2.0, 5, 32.0);
+    test_i64_primitive_float::<f32>(2.0, 0, 1.0);
+    test_i64_primitive_float::<f32>(2.0, -3, 0.125);
+    test_i64_primitive_float::<f64>(-3.0, 3, -27.0);
+    test_i64_primitive_float::<f64>(0.0, -1, f64::INFINITY);
+    test_i64_primitive_float::<f64>(-1.0, i64::MAX, -1.0);
+    test_i64_primitive_float::<f64>(-1.0, i64::MIN, 1.0);
+    test_i64_primitive_float::<f64>(0.5, 1 << 40, 0.0);
+    test_i64_primitive_float::<f64>(2.0, -(1 << 40), 0.0);
+    test_i64_primitive_float::<f64>(f64::NAN, 0, 1.0);
 
     fn test_primitive_float_primitive_float<T: PrimitiveFloat>(x: T, y: T, out: T) {
         assert_eq!(NiceFloat(x.pow(y)), NiceFloat(out));
@@ -95,4 +104,12 @@ fn pow_assign_properties() {
     apply_fn_to_unsigneds!(pow_assign_properties_helper_unsigned);
     apply_fn_to_signeds!(pow_assign_properties_helper_signed);
     apply_fn_to_primitive_floats!(pow_assign_properties_helper_primitive_float);
+
+    primitive_float_signed_pair_gen::<f32, i32>().test_properties(|(x, y)| {
+        assert_eq!(NiceFloat(Pow::pow(x, i64::from(y))), NiceFloat(x.powi(y)));
+    });
+
+    primitive_float_signed_pair_gen::<f64, i32>().test_properties(|(x, y)| {
+        assert_eq!(NiceFloat(Pow::pow(x, i64::from(y))), NiceFloat(x.powi(y)));
+    });
 }
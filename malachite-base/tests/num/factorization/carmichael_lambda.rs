@@ -0,0 +1,110 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::CarmichaelLambda;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_carmichael_lambda_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).carmichael_lambda(), T::from(out));
+    };
+    test(1, 1);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(8, 2);
+    test(9, 6);
+    test(12, 2);
+    test(16, 4);
+    test(32, 8);
+    test(36, 6);
+    test(97, 96);
+    test(100, 20);
+    test(128, 32);
+    test(255, 16);
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    apply_fn_to_unsigneds!(test_carmichael_lambda_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(u128::from(n).carmichael_lambda(), u128::from(out));
+    };
+    test(561, 80);
+    test(720720, 60);
+    test(65535, 256);
+    test(1000000007, 1000000006);
+    test(4294967295, 65536);
+    test(1 << 63, 1 << 61);
+    test(u64::MAX, 17153064960);
+    assert_eq!(u128::MAX.carmichael_lambda(), 321876063366081731297280);
+}
+
+fn carmichael_lambda_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.carmichael_lambda());
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    apply_fn_to_unsigneds!(carmichael_lambda_fail_helper);
+}
+
+fn carmichael_lambda_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let lambda = n.carmichael_lambda();
+    assert_ne!(lambda, T::ZERO);
+    assert!(n.totient().divisible_by(lambda));
+    if n.is_prime() {
+        assert_eq!(lambda, n - T::ONE);
+    }
+}
+
+fn carmichael_lambda_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO {
+            return;
+        }
+        carmichael_lambda_properties_helper_helper(n);
+        if n > T::from(u8::MAX) {
+            return;
+        }
+        // λ(n) is the smallest positive e such that a^e ≡ 1 mod n for all a coprime to n.
+        let lambda = n.carmichael_lambda();
+        let lambda_u64: u64 = lambda.exact_into();
+        let mut a = T::ONE;
+        while a < n {
+            if a.coprime_with(n) {
+                assert_eq!(a.mod_pow(lambda_u64, n), T::ONE);
+            }
+            a += T::ONE;
+        }
+        let mut e = 1;
+        while e < lambda_u64 {
+            if lambda_u64 % e == 0 {
+                let mut a = T::ONE;
+                let mut all_one = true;
+                while a < n {
+                    if a.coprime_with(n) && a.mod_pow(e, n) != T::ONE {
+                        all_one = false;
+                        break;
+                    }
+                    a += T::ONE;
+                }
+                assert!(!all_one);
+            }
+            e += 1;
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            carmichael_lambda_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    apply_fn_to_unsigneds!(carmichael_lambda_properties_helper);
+}
@@ -0,0 +1,101 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::DivisorCount;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_5, unsigned_pair_gen_var_27,
+};
+use std::panic::catch_unwind;
+
+fn test_divisor_count_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u64| {
+        assert_eq!(T::from(n).divisor_count(), out);
+    };
+    test(1, 1);
+    test(2, 2);
+    test(4, 3);
+    test(6, 4);
+    test(12, 6);
+    test(36, 9);
+    test(97, 2);
+    test(128, 8);
+    test(180, 18);
+    test(240, 20);
+    test(255, 8);
+}
+
+#[test]
+fn test_divisor_count() {
+    apply_fn_to_unsigneds!(test_divisor_count_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(u128::from(n).divisor_count(), out);
+    };
+    test(720720, 240);
+    test(1000000007, 2);
+    test(4294967295, 32);
+    test(1 << 63, 64);
+    test(u64::MAX, 128);
+    assert_eq!(u128::MAX.divisor_count(), 512);
+}
+
+fn divisor_count_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisor_count());
+}
+
+#[test]
+fn divisor_count_fail() {
+    apply_fn_to_unsigneds!(divisor_count_fail_helper);
+}
+
+fn divisor_count_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let count = n.divisor_count();
+    assert_ne!(count, 0);
+    assert_eq!(count == 2, n.is_prime());
+    assert_eq!(count, u64::wrapping_from(n.divisors().count()));
+    assert_eq!(T::exact_from(count), n.divisor_sum(0));
+}
+
+fn divisor_count_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO || n > T::from(u8::MAX) {
+            return;
+        }
+        divisor_count_properties_helper_helper(n);
+        let mut count = 0;
+        let mut d = T::ONE;
+        while d <= n {
+            if n.divisible_by(d) {
+                count += 1;
+            }
+            if d == n {
+                break;
+            }
+            d += T::ONE;
+        }
+        assert_eq!(n.divisor_count(), count);
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            divisor_count_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn divisor_count_properties() {
+    apply_fn_to_unsigneds!(divisor_count_properties_helper);
+
+    // d is multiplicative.
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.coprime_with(y) {
+            assert_eq!(
+                (u64::from(x) * u64::from(y)).divisor_count(),
+                x.divisor_count() * y.divisor_count()
+            );
+        }
+    });
+}
@@ -0,0 +1,143 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{CheckedDivisorSum, DivisorSum};
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_5, unsigned_pair_gen_var_27,
+};
+use std::panic::catch_unwind;
+
+fn test_divisor_sum_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, k: u64, out: u8| {
+        assert_eq!(T::from(n).divisor_sum(k), T::from(out));
+        assert_eq!(T::from(n).checked_divisor_sum(k), Some(T::from(out)));
+    };
+    test(1, 0, 1);
+    test(1, 1, 1);
+    test(1, 100, 1);
+    test(2, 0, 2);
+    test(2, 1, 3);
+    test(2, 2, 5);
+    test(2, 3, 9);
+    test(6, 1, 12);
+    test(6, 2, 50);
+    test(12, 0, 6);
+    test(12, 1, 28);
+    test(12, 2, 210);
+    test(28, 1, 56);
+    test(36, 1, 91);
+    test(97, 1, 98);
+    test(100, 1, 217);
+    test(128, 1, 255);
+    test(255, 0, 8);
+}
+
+#[test]
+fn test_divisor_sum() {
+    apply_fn_to_unsigneds!(test_divisor_sum_helper);
+
+    let test = |n: u64, k: u64, out: u64| {
+        assert_eq!(n.divisor_sum(k), out);
+        assert_eq!(n.checked_divisor_sum(k), Some(out));
+        assert_eq!(u128::from(n).divisor_sum(k), u128::from(out));
+    };
+    test(496, 1, 992);
+    test(8128, 1, 16256);
+    test(33550336, 1, 67100672);
+    test(33550336, 2, 1500833394289322);
+    test(720720, 1, 3249792);
+    test(720720, 2, 836657822000);
+    test(1000000007, 2, 1000000014000000050);
+    test(4294967295, 1, 7304603328);
+    test(1 << 63, 1, u64::MAX);
+    test(u64::MAX, 0, 128);
+
+    let test = |n: u128, k: u64, out: Option<u128>| {
+        assert_eq!(n.checked_divisor_sum(k), out);
+    };
+    test(4294967295, 2, Some(21390320049322900000));
+    test(1 << 63, 2, Some(113427455640312821154458202477256070485));
+    test(u128::from(u64::MAX), 1, Some(31421980989189888768));
+    test(u128::MAX, 1, None);
+}
+
+fn checked_divisor_sum_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, k: u64, out: Option<u8>| {
+        assert_eq!(T::from(n).checked_divisor_sum(k), out.map(T::from));
+    };
+    test(1, 1, Some(1));
+    test(12, 1, Some(28));
+    test(12, 2, Some(210));
+    test(255, 0, Some(8));
+}
+
+#[test]
+fn test_checked_divisor_sum() {
+    apply_fn_to_unsigneds!(checked_divisor_sum_helper);
+
+    assert_eq!(255u8.checked_divisor_sum(1), None);
+    assert_eq!(36u8.checked_divisor_sum(2), None);
+    assert_eq!(2u8.checked_divisor_sum(8), None);
+    assert_eq!(2u16.checked_divisor_sum(8), Some(257));
+    assert_eq!(4294967295u64.checked_divisor_sum(2), None);
+    assert_eq!(u64::MAX.checked_divisor_sum(1), None);
+    assert_eq!(3u64.checked_divisor_sum(100), None);
+}
+
+fn divisor_sum_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisor_sum(1));
+    assert_panic!(T::ZERO.checked_divisor_sum(1));
+    assert_panic!(T::MAX.divisor_sum(1));
+}
+
+#[test]
+fn divisor_sum_fail() {
+    apply_fn_to_unsigneds!(divisor_sum_fail_helper);
+}
+
+fn divisor_sum_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    assert_eq!(
+        n.checked_divisor_sum(0),
+        Some(T::exact_from(n.divisor_count()))
+    );
+    if let Some(sum) = n.checked_divisor_sum(1) {
+        assert!(sum >= n);
+        assert_eq!(sum == n + T::ONE, n.is_prime());
+    }
+}
+
+fn divisor_sum_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO || n > T::from(u8::MAX) {
+            return;
+        }
+        divisor_sum_properties_helper_helper(n);
+        for k in 0..4 {
+            let mut sum = Some(T::ZERO);
+            for d in n.divisors() {
+                sum = sum.and_then(|s| d.checked_pow(k).and_then(|p| s.checked_add(p)));
+            }
+            assert_eq!(n.checked_divisor_sum(k), sum);
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            divisor_sum_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn divisor_sum_properties() {
+    apply_fn_to_unsigneds!(divisor_sum_properties_helper);
+
+    // σ_k is multiplicative.
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.coprime_with(y) {
+            let x = u128::from(x);
+            let y = u128::from(y);
+            assert_eq!((x * y).divisor_sum(1), x.divisor_sum(1) * y.divisor_sum(1));
+        }
+    });
+}
@@ -0,0 +1,117 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::divisors::divisors_from_factorization;
+use malachite_base::num::factorization::traits::Divisors;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_divisors_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[u8]| {
+        assert_eq!(
+            T::from(n).divisors().collect_vec(),
+            out.iter().map(|&d| T::from(d)).collect_vec()
+        );
+    };
+    test(1, &[1]);
+    test(2, &[1, 2]);
+    test(4, &[1, 2, 4]);
+    test(6, &[1, 2, 3, 6]);
+    test(12, &[1, 2, 4, 3, 6, 12]);
+    test(30, &[1, 2, 3, 6, 5, 10, 15, 30]);
+    test(36, &[1, 2, 4, 3, 6, 12, 9, 18, 36]);
+    test(97, &[1, 97]);
+    test(128, &[1, 2, 4, 8, 16, 32, 64, 128]);
+    test(255, &[1, 3, 5, 15, 17, 51, 85, 255]);
+}
+
+#[test]
+fn test_divisors() {
+    apply_fn_to_unsigneds!(test_divisors_helper);
+
+    assert_eq!(1000000007u64.divisors().collect_vec(), &[1, 1000000007]);
+    assert_eq!(
+        1000006000009u64.divisors().collect_vec(),
+        &[1, 1000003, 1000006000009]
+    );
+    assert_eq!(
+        4294967295u64.divisors().sorted().collect_vec(),
+        &[
+            1, 3, 5, 15, 17, 51, 85, 255, 257, 771, 1285, 3855, 4369, 13107, 21845, 65535, 65537,
+            196611, 327685, 983055, 1114129, 3342387, 5570645, 16711935, 16843009, 50529027,
+            84215045, 252645135, 286331153, 858993459, 1431655765, 4294967295
+        ]
+    );
+    assert_eq!(u64::MAX.divisors().count(), 128);
+    assert_eq!(u64::MAX.divisors().last(), Some(u64::MAX));
+    assert_eq!(u128::MAX.divisors().count(), 512);
+}
+
+#[test]
+fn test_divisors_from_factorization() {
+    let test = |factors: Vec<(u32, u64)>, out: &[u32]| {
+        assert_eq!(divisors_from_factorization(factors).collect_vec(), out);
+    };
+    test(vec![], &[1]);
+    test(vec![(2, 1)], &[1, 2]);
+    test(vec![(2, 2), (3, 1)], &[1, 2, 4, 3, 6, 12]);
+    test(vec![(3, 1), (2, 2)], &[1, 3, 2, 6, 4, 12]);
+    test(vec![(5, 3)], &[1, 5, 25, 125]);
+}
+
+fn divisors_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisors());
+}
+
+#[test]
+fn divisors_fail() {
+    apply_fn_to_unsigneds!(divisors_fail_helper);
+}
+
+fn divisors_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let divisors = n.divisors().sorted().collect_vec();
+    assert!(is_strictly_ascending(divisors.iter()));
+    assert_eq!(divisors[0], T::ONE);
+    assert_eq!(*divisors.last().unwrap(), n);
+    assert_eq!(u64::wrapping_from(divisors.len()), n.divisor_count());
+    for &d in &divisors {
+        assert!(n.divisible_by(d));
+        assert!(divisors.binary_search(&(n / d)).is_ok());
+    }
+}
+
+fn divisors_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO {
+            return;
+        }
+        divisors_properties_helper_helper(n);
+        if n <= T::from(u8::MAX) {
+            let mut divisors = Vec::new();
+            let mut d = T::ONE;
+            while d <= n {
+                if n.divisible_by(d) {
+                    divisors.push(d);
+                }
+                if d == n {
+                    break;
+                }
+                d += T::ONE;
+            }
+            assert_eq!(n.divisors().sorted().collect_vec(), divisors);
+        }
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            divisors_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn divisors_properties() {
+    apply_fn_to_unsigneds!(divisors_properties_helper);
+}
@@ -0,0 +1,152 @@
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_5, unsigned_pair_gen,
+};
+use malachite_base::test_util::num::factorization::factor::factor_naive;
+use std::panic::catch_unwind;
+
+fn test_factor_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: &[(u8, u64)]| {
+        let n = T::from(n);
+        let out = out
+            .iter()
+            .map(|&(p, e)| (T::from(p), e))
+            .collect::<Vec<_>>();
+        assert_eq!(n.factor(), out);
+        assert_eq!(factor_naive(n), out);
+    };
+    test(1, &[]);
+    test(2, &[(2, 1)]);
+    test(3, &[(3, 1)]);
+    test(4, &[(2, 2)]);
+    test(6, &[(2, 1), (3, 1)]);
+    test(12, &[(2, 2), (3, 1)]);
+    test(60, &[(2, 2), (3, 1), (5, 1)]);
+    test(97, &[(97, 1)]);
+    test(128, &[(2, 7)]);
+    test(210, &[(2, 1), (3, 1), (5, 1), (7, 1)]);
+    test(243, &[(3, 5)]);
+    test(255, &[(3, 1), (5, 1), (17, 1)]);
+}
+
+#[test]
+fn test_factor() {
+    apply_fn_to_unsigneds!(test_factor_helper);
+
+    let test = |n: u64, out: &[(u64, u64)]| {
+        assert_eq!(n.factor(), out);
+        let out_128 = out
+            .iter()
+            .map(|&(p, e)| (u128::from(p), e))
+            .collect::<Vec<_>>();
+        assert_eq!(u128::from(n).factor(), out_128);
+    };
+    test(1000000007, &[(1000000007, 1)]);
+    test(1000006000009, &[(1000003, 2)]);
+    test(1000009000027000027, &[(1000003, 3)]);
+    test(4294967291 * 4294967279, &[(4294967279, 1), (4294967291, 1)]);
+    test(4294967291 * 4294967291, &[(4294967291, 2)]);
+    test(2147483647 * 2147483647 * 3, &[(3, 1), (2147483647, 2)]);
+    test(
+        1000003 * 1000033 * 1000037,
+        &[(1000003, 1), (1000033, 1), (1000037, 1)],
+    );
+    test(18446744073709551557, &[(18446744073709551557, 1)]);
+    test(1 << 63, &[(2, 63)]);
+    test(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+
+    let test = |n: u128, out: &[(u128, u64)]| {
+        assert_eq!(n.factor(), out);
+    };
+    test(18446744073709551617, &[(274177, 1), (67280421310721, 1)]);
+    test(
+        1099511627791 * 1099511627689,
+        &[(1099511627689, 1), (1099511627791, 1)],
+    );
+    test(
+        18446744073709551557 * 4294967291,
+        &[(4294967291, 1), (18446744073709551557, 1)],
+    );
+    test((1 << 127) - 1, &[((1 << 127) - 1, 1)]);
+    test(1 << 127, &[(2, 127)]);
+    test(
+        u128::MAX,
+        &[
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274177, 1),
+            (6700417, 1),
+            (67280421310721, 1),
+        ],
+    );
+}
+
+fn factor_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.factor());
+}
+
+#[test]
+fn factor_fail() {
+    apply_fn_to_unsigneds!(factor_fail_helper);
+}
+
+fn factor_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let factors = n.factor();
+    assert!(is_strictly_ascending(factors.iter().map(|(p, _)| p)));
+    let mut product = T::ONE;
+    for &(p, e) in &factors {
+        assert!(p.is_prime());
+        assert_ne!(e, 0);
+        product *= p.pow(e);
+    }
+    assert_eq!(product, n);
+}
+
+fn factor_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n != T::ZERO {
+            factor_properties_helper_helper(n);
+            assert_eq!(n.factor(), factor_naive(n));
+        }
+    });
+}
+
+#[test]
+fn factor_properties() {
+    apply_fn_to_unsigneds!(factor_properties_helper);
+
+    unsigned_gen_var_1::<u8>().test_properties(factor_properties_helper_helper);
+    unsigned_gen_var_1::<u16>().test_properties(factor_properties_helper_helper);
+    unsigned_gen_var_1::<u32>().test_properties(factor_properties_helper_helper);
+    unsigned_gen_var_1::<u64>().test_properties(factor_properties_helper_helper);
+    unsigned_gen_var_1::<usize>().test_properties(factor_properties_helper_helper);
+
+    unsigned_pair_gen::<u64, u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y)| {
+        if x != 0 && y != 0 {
+            let n = u128::from(x) * u128::from(y);
+            factor_properties_helper_helper(n);
+            let mut factors = x.factor();
+            factors.extend(y.factor().into_iter().map(|(p, e)| (u64::from(p), e)));
+            factors.sort_unstable();
+            let mut expected: Vec<(u128, u64)> = Vec::new();
+            for (p, e) in factors {
+                let p = u128::from(p);
+                match expected.last_mut() {
+                    Some((q, f)) if *q == p => *f += e,
+                    _ => expected.push((p, e)),
+                }
+            }
+            assert_eq!(n.factor(), expected);
+        }
+    });
+}
@@ -0,0 +1,121 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::{unsigned_gen, unsigned_gen_var_5, unsigned_pair_gen};
+use malachite_base::test_util::num::factorization::is_prime::is_prime_naive;
+
+fn test_is_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: bool| {
+        let n = T::from(n);
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(is_prime_naive(n), out);
+    };
+    test(0, false);
+    test(1, false);
+    test(2, true);
+    test(3, true);
+    test(4, false);
+    test(5, true);
+    test(6, false);
+    test(7, true);
+    test(8, false);
+    test(9, false);
+    test(10, false);
+    test(11, true);
+    test(12, false);
+    test(13, true);
+    test(25, false);
+    test(49, false);
+    test(97, true);
+    test(121, false);
+    test(127, true);
+    test(251, true);
+    test(253, false);
+}
+
+#[test]
+fn test_is_prime() {
+    apply_fn_to_unsigneds!(test_is_prime_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(u128::from(n).is_prime(), out);
+    };
+    // Carmichael numbers
+    test(561, false);
+    test(1105, false);
+    test(41041, false);
+    test(825265, false);
+    // strong pseudoprimes to base 2
+    test(2047, false);
+    test(3277, false);
+    test(4033, false);
+    // smallest strong pseudoprime to bases 2, 3, 5, and 7
+    test(3215031751, false);
+    test(3825123056546413051, false);
+    test(4294967291, true);
+    test(4294967295, false);
+    test(4294967297, false);
+    test(4294967311, true);
+    test(1000000007, true);
+    test(999999999989, true);
+    test(18446744073709551557, true);
+    test(18446744073709551559, false);
+    test(u64::MAX, false);
+    test(4611686014132420609, false);
+
+    let test = |n: u128, out| {
+        assert_eq!(n.is_prime(), out);
+    };
+    // smallest strong pseudoprime to the first 12 prime bases
+    test(318665857834031151167461, false);
+    // smallest strong pseudoprime to the first 13 prime bases
+    test(3317044064679887385961981, false);
+    test(18446744073709551629, true);
+    test(18446744073709551631, false);
+    test(340282366920938463463374607431768211297, true);
+    test(340282366920938463463374607431768211455, false);
+    test((1 << 127) - 1, true);
+    test((1 << 127) + 1, false);
+    // the square of the largest prime less than 2^64
+    test(340282366920938461286658806734041124249, false);
+    test(18446744073709551557 * 18446744073709551533, false);
+}
+
+fn is_prime_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        assert_eq!(n.is_prime(), is_prime_naive(n));
+    });
+
+    unsigned_gen::<T>().test_properties(|n| {
+        let is_prime = n.is_prime();
+        if is_prime {
+            assert!(n > T::ONE);
+        }
+        if let Some(p) = T::primes().take(20).find(|&p| p < n && n.divisible_by(p)) {
+            assert!(!is_prime, "{n} is divisible by {p}");
+        }
+    });
+
+    unsigned_pair_gen::<T, T>().test_properties(|(x, y)| {
+        if x > T::ONE && y > T::ONE {
+            if let Some(product) = x.checked_mul(y) {
+                assert!(!product.is_prime());
+            }
+        }
+    });
+}
+
+#[test]
+fn is_prime_properties() {
+    apply_fn_to_unsigneds!(is_prime_properties_helper);
+
+    unsigned_gen::<u16>().test_properties(|n| {
+        assert_eq!(n.is_prime(), is_prime_naive(n));
+    });
+
+    unsigned_pair_gen::<u64, u64>().test_properties(|(x, y)| {
+        if x > 1 && y > 1 {
+            assert!(!(u128::from(x) * u128::from(y)).is_prime());
+        }
+    });
+}
@@ -0,0 +1,93 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Moebius;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_gen_var_5, unsigned_pair_gen_var_27,
+};
+use std::panic::catch_unwind;
+
+fn test_moebius_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: i8| {
+        assert_eq!(T::from(n).moebius(), out);
+    };
+    test(1, 1);
+    test(2, -1);
+    test(3, -1);
+    test(4, 0);
+    test(6, 1);
+    test(8, 0);
+    test(12, 0);
+    test(30, -1);
+    test(97, -1);
+    test(210, 1);
+    test(255, -1);
+}
+
+#[test]
+fn test_moebius() {
+    apply_fn_to_unsigneds!(test_moebius_helper);
+
+    let test = |n: u64, out: i8| {
+        assert_eq!(n.moebius(), out);
+        assert_eq!(u128::from(n).moebius(), out);
+    };
+    test(65535, 1);
+    test(1000000007, -1);
+    test(1000006000009, 0);
+    test(4294967295, -1);
+    test(1 << 63, 0);
+    test(u64::MAX, -1);
+    assert_eq!(u128::MAX.moebius(), -1);
+}
+
+fn moebius_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.moebius());
+}
+
+#[test]
+fn moebius_fail() {
+    apply_fn_to_unsigneds!(moebius_fail_helper);
+}
+
+fn moebius_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let mu = n.moebius();
+    assert!(mu == -1 || mu == 0 || mu == 1);
+    if n.is_prime() {
+        assert_eq!(mu, -1);
+    }
+    assert_eq!(mu != 0, n.factor().iter().all(|&(_, k)| k == 1));
+}
+
+fn moebius_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO {
+            return;
+        }
+        moebius_properties_helper_helper(n);
+        // The sum of μ(d) over the divisors d of n is 1 if n is 1 and 0 otherwise.
+        let sum: i64 = n.divisors().map(|d| i64::from(d.moebius())).sum();
+        assert_eq!(sum, i64::from(n == T::ONE));
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            moebius_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn moebius_properties() {
+    apply_fn_to_unsigneds!(moebius_properties_helper);
+
+    // μ is multiplicative.
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        if x != 0 && y != 0 && x.coprime_with(y) {
+            assert_eq!(
+                (u64::from(x) * u64::from(y)).moebius(),
+                x.moebius() * y.moebius()
+            );
+        }
+    });
+}
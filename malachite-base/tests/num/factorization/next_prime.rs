@@ -0,0 +1,125 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::NextPrime;
+use malachite_base::test_util::generators::{
+    signed_gen, signed_gen_var_5, unsigned_gen, unsigned_gen_var_5,
+};
+use std::panic::catch_unwind;
+
+fn test_next_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).next_prime(), T::from(out));
+    };
+    test(0, 2);
+    test(1, 2);
+    test(2, 3);
+    test(3, 5);
+    test(4, 5);
+    test(7, 11);
+    test(23, 29);
+    test(89, 97);
+    test(100, 101);
+    test(241, 251);
+}
+
+fn test_next_prime_signed_helper<T: PrimitiveSigned>() {
+    let test = |n: i8, out: i8| {
+        assert_eq!(T::from(n).next_prime(), T::from(out));
+    };
+    test(-128, 2);
+    test(-1, 2);
+    test(0, 2);
+    test(1, 2);
+    test(2, 3);
+    test(100, 101);
+    test(113, 127);
+}
+
+#[test]
+fn test_next_prime() {
+    apply_fn_to_unsigneds!(test_next_prime_helper);
+    apply_fn_to_signeds!(test_next_prime_signed_helper);
+
+    assert_eq!(1000u16.next_prime(), 1009);
+    assert_eq!(65520u16.next_prime(), 65521);
+    assert_eq!(1000000000u32.next_prime(), 1000000007);
+    assert_eq!(4294967290u32.next_prime(), 4294967291);
+    assert_eq!(4294967291u64.next_prime(), 4294967311);
+    assert_eq!(18446744073709551556u64.next_prime(), 18446744073709551557);
+    assert_eq!(u128::from(u64::MAX).next_prime(), 18446744073709551629);
+    assert_eq!(i64::from(i32::MAX).next_prime(), 2147483659);
+}
+
+fn next_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::MAX.next_prime());
+}
+
+fn next_prime_signed_fail_helper<T: PrimitiveSigned>() {
+    assert_panic!(T::MAX.next_prime());
+}
+
+#[test]
+fn next_prime_fail() {
+    apply_fn_to_unsigneds!(next_prime_fail_helper);
+    apply_fn_to_signeds!(next_prime_signed_fail_helper);
+    assert_panic!(251u8.next_prime());
+    assert_panic!(127i8.next_prime());
+}
+
+fn next_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.preceding_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        if p > T::TWO {
+            assert!(p.preceding_prime() <= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        let p = n.next_prime();
+        let mut m = n + T::ONE;
+        while !m.is_prime() {
+            m += T::ONE;
+        }
+        assert_eq!(p, m);
+    });
+}
+
+fn next_prime_properties_signed_helper<
+    U: PrimitiveUnsigned + WrappingFrom<S>,
+    S: PrimitiveSigned + WrappingFrom<U>,
+>() {
+    let largest_prime = S::MAX.preceding_prime();
+    signed_gen::<S>().test_properties(|n| {
+        if n >= largest_prime {
+            return;
+        }
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p > S::ONE);
+        if n >= S::ZERO {
+            assert_eq!(U::wrapping_from(p), U::wrapping_from(n).next_prime());
+        }
+    });
+
+    signed_gen_var_5::<S>().test_properties(|n| {
+        if n < S::TWO {
+            assert_eq!(n.next_prime(), S::TWO);
+        }
+    });
+}
+
+#[test]
+fn next_prime_properties() {
+    apply_fn_to_unsigneds!(next_prime_properties_helper);
+    apply_fn_to_unsigned_signed_pairs!(next_prime_properties_signed_helper);
+}
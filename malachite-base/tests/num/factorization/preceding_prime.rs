@@ -0,0 +1,114 @@
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::PrecedingPrime;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_preceding_prime_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).preceding_prime(), T::from(out));
+    };
+    test(3, 2);
+    test(4, 3);
+    test(5, 3);
+    test(6, 5);
+    test(11, 7);
+    test(29, 23);
+    test(100, 97);
+    test(128, 127);
+    test(255, 251);
+}
+
+fn test_preceding_prime_signed_helper<T: PrimitiveSigned>() {
+    let test = |n: i8, out: i8| {
+        assert_eq!(T::from(n).preceding_prime(), T::from(out));
+    };
+    test(3, 2);
+    test(4, 3);
+    test(100, 97);
+    test(127, 113);
+}
+
+#[test]
+fn test_preceding_prime() {
+    apply_fn_to_unsigneds!(test_preceding_prime_helper);
+    apply_fn_to_signeds!(test_preceding_prime_signed_helper);
+
+    assert_eq!(u16::MAX.preceding_prime(), 65521);
+    assert_eq!(1000000007u32.preceding_prime(), 999999937);
+    assert_eq!(u32::MAX.preceding_prime(), 4294967291);
+    assert_eq!(4294967296u64.preceding_prime(), 4294967291);
+    assert_eq!(u64::MAX.preceding_prime(), 18446744073709551557);
+    assert_eq!(
+        u128::MAX.preceding_prime(),
+        340282366920938463463374607431768211297
+    );
+    assert_eq!(i64::MAX.preceding_prime(), 9223372036854775783);
+}
+
+fn preceding_prime_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.preceding_prime());
+    assert_panic!(T::ONE.preceding_prime());
+    assert_panic!(T::TWO.preceding_prime());
+}
+
+fn preceding_prime_signed_fail_helper<T: PrimitiveSigned>() {
+    assert_panic!(T::MIN.preceding_prime());
+    assert_panic!(T::NEGATIVE_ONE.preceding_prime());
+    assert_panic!(T::ZERO.preceding_prime());
+    assert_panic!(T::TWO.preceding_prime());
+}
+
+#[test]
+fn preceding_prime_fail() {
+    apply_fn_to_unsigneds!(preceding_prime_fail_helper);
+    apply_fn_to_signeds!(preceding_prime_signed_fail_helper);
+}
+
+fn preceding_prime_properties_helper<T: PrimitiveUnsigned>() {
+    let largest_prime = T::MAX.preceding_prime();
+    unsigned_gen::<T>().test_properties(|n| {
+        if n <= T::TWO {
+            return;
+        }
+        let p = n.preceding_prime();
+        assert!(p < n);
+        assert!(p.is_prime());
+        if p < largest_prime {
+            assert!(p.next_prime() >= n);
+        }
+    });
+
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n <= T::TWO {
+            return;
+        }
+        let p = n.preceding_prime();
+        let mut m = n - T::ONE;
+        while !m.is_prime() {
+            m -= T::ONE;
+        }
+        assert_eq!(p, m);
+    });
+}
+
+fn preceding_prime_properties_signed_helper<
+    U: PrimitiveUnsigned + WrappingFrom<S>,
+    S: PrimitiveSigned + WrappingFrom<U>,
+>() {
+    signed_gen::<S>().test_properties(|n| {
+        if n <= S::TWO {
+            return;
+        }
+        let p = n.preceding_prime();
+        assert!(p < n);
+        assert_eq!(U::wrapping_from(p), U::wrapping_from(n).preceding_prime());
+    });
+}
+
+#[test]
+fn preceding_prime_properties() {
+    apply_fn_to_unsigneds!(preceding_prime_properties_helper);
+    apply_fn_to_unsigned_signed_pairs!(preceding_prime_properties_signed_helper);
+}
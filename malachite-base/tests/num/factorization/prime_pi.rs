@@ -0,0 +1,68 @@
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::PrimePi;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use std::panic::catch_unwind;
+
+fn test_prime_pi_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u64| {
+        assert_eq!(T::from(n).prime_pi(), out);
+    };
+    test(0, 0);
+    test(1, 0);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(5, 3);
+    test(10, 4);
+    test(11, 5);
+    test(100, 25);
+    test(255, 54);
+}
+
+#[test]
+fn test_prime_pi() {
+    apply_fn_to_unsigneds!(test_prime_pi_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.prime_pi(), out);
+        assert_eq!(u128::from(n).prime_pi(), out);
+    };
+    test(1000, 168);
+    test(65535, 6542);
+    test(1000000, 78498);
+    test(10000000, 664579);
+    test(1 << 26, 3957809);
+    test((1 << 26) + 1, 3957809);
+    test(100000000, 5761455);
+    test(1000000000, 50847534);
+    test(u64::from(u32::MAX), 203280221);
+    test(10000000000, 455052511);
+    test(100000000000, 4118054813);
+    test(1000000000000, 37607912018);
+    test(10000000000000, 346065536839);
+}
+
+#[test]
+fn prime_pi_fail() {
+    assert_panic!((u128::from(u64::MAX) + 1).prime_pi());
+}
+
+fn prime_pi_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        let pi = n.prime_pi();
+        assert_eq!(
+            pi,
+            u64::exact_from(T::primes_less_than_or_equal_to(&n).count())
+        );
+        if n != T::ZERO {
+            let pi_prev = (n - T::ONE).prime_pi();
+            assert_eq!(pi - pi_prev, u64::from(n.is_prime()));
+        }
+    });
+}
+
+#[test]
+fn prime_pi_properties() {
+    apply_fn_to_unsigneds!(prime_pi_properties_helper);
+}
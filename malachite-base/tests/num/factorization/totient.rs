@@ -0,0 +1,100 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::Totient;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_gen_var_5};
+use std::panic::catch_unwind;
+
+fn test_totient_helper<T: PrimitiveUnsigned>() {
+    let test = |n: u8, out: u8| {
+        assert_eq!(T::from(n).totient(), T::from(out));
+    };
+    test(1, 1);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(8, 4);
+    test(9, 6);
+    test(12, 4);
+    test(36, 12);
+    test(97, 96);
+    test(100, 40);
+    test(128, 64);
+    test(255, 128);
+}
+
+#[test]
+fn test_totient() {
+    apply_fn_to_unsigneds!(test_totient_helper);
+
+    let test = |n: u64, out: u64| {
+        assert_eq!(n.totient(), out);
+        assert_eq!(u128::from(n).totient(), u128::from(out));
+    };
+    test(561, 320);
+    test(65535, 32768);
+    test(1000000007, 1000000006);
+    test(4294967295, 2147483648);
+    test(1 << 63, 1 << 62);
+    test(u64::MAX, 9208981628670443520);
+    assert_eq!(u128::MAX.totient(), 169875107699410294159549716941399654400);
+}
+
+fn totient_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.totient());
+}
+
+#[test]
+fn totient_fail() {
+    apply_fn_to_unsigneds!(totient_fail_helper);
+}
+
+fn totient_properties_helper_helper<T: PrimitiveUnsigned>(n: T) {
+    let totient = n.totient();
+    assert_ne!(totient, T::ZERO);
+    assert!(totient <= n);
+    assert_eq!(totient == n - T::ONE, n.is_prime());
+    if n > T::TWO {
+        assert!(totient.even());
+    }
+}
+
+fn totient_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_gen_var_5::<T>().test_properties(|n| {
+        if n == T::ZERO {
+            return;
+        }
+        totient_properties_helper_helper(n);
+        if n > T::from(u8::MAX) {
+            return;
+        }
+        let mut count = T::ZERO;
+        let mut k = T::ONE;
+        while k <= n {
+            if k.coprime_with(n) {
+                count += T::ONE;
+            }
+            if k == n {
+                break;
+            }
+            k += T::ONE;
+        }
+        assert_eq!(n.totient(), count);
+        // The totients of the divisors of n add up to n.
+        let mut sum = T::ZERO;
+        for d in n.divisors() {
+            sum += d.totient();
+        }
+        assert_eq!(sum, n);
+    });
+
+    unsigned_gen_var_1::<T>().test_properties(|n| {
+        if T::WIDTH <= u64::WIDTH {
+            totient_properties_helper_helper(n);
+        }
+    });
+}
+
+#[test]
+fn totient_properties() {
+    apply_fn_to_unsigneds!(totient_properties_helper);
+}
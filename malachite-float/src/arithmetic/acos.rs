@@ -0,0 +1,853 @@
+use crate::arithmetic::atan::{atan_fixed, pi_multiple_prec_round};
+use crate::arithmetic::exp::round_approximation;
+use crate::InnerFloat::{Finite, NaN, Zero};
+use crate::{float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign, FloorSqrt, IsPowerOf2, PowerOf2};
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Computes the arccosine of a finite, nonzero `Float`, given its sign, exponent, and significand.
+//
+// If $|x| > 1$, the result is NaN; $\arccos 1 = 0$ and $\arccos(-1) = \pi$. Otherwise $\arccos x =
+// 2\arctan\sqrt{(1-x)/(1+x)}$, which avoids the cancellation in $\pi/2 - \arcsin x$ when $x$ is
+// close to 1. Both $1-x$ and $1+x$ are computed exactly, and the fixed-point square root is within
+// 2 of the exact one. The arccosine of any rational number other than 1 is transcendental, so it
+// can always be rounded correctly once it is approximated closely enough.
+fn acos_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent > 1 || exponent == 1 && !significand.is_power_of_2() {
+        return (float_nan!(), Ordering::Equal);
+    } else if exponent == 1 {
+        return if sign {
+            (float_zero!(), Ordering::Equal)
+        } else {
+            pi_multiple_prec_round(true, 4, prec, rm)
+        };
+    }
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    let one = Natural::power_of_2(u64::exact_from(-low));
+    let (numerator, denominator) = if sign {
+        (&one - significand, one + significand)
+    } else {
+        (&one + significand, one - significand)
+    };
+    // The square root of (1 - x) / (1 + x) is about 2^(-half_small_bits) or larger
+    let half_small_bits =
+        (u64::exact_from(max(-low - i64::exact_from(numerator.significant_bits()), 0)) >> 1) + 1;
+    round_approximation(prec, rm, |working_prec| {
+        let scale = working_prec + half_small_bits + 10;
+        let root = ((&numerator << (scale << 1)) / &denominator).floor_sqrt();
+        let (a, a_error) = atan_fixed(&root, &Natural::TWO, scale);
+        (
+            Integer::from(a << 1u32),
+            a_error << 1u32,
+            -i64::exact_from(scale),
+        )
+    })
+}
+impl Float {
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arccosine is less than, equal to, or greater than
+    /// the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round`] instead. If both of these things are true, consider using
+    /// [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => acos_finite_prec_round(sign, exponent, &significand, prec, rm),
+            Float(Zero { .. }) => pi_multiple_prec_round(true, 2, prec, rm),
+            _ => (float_nan!(), Ordering::Equal),
+        }
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arccosine is less than, equal to, or greater
+    /// than the exact arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_ref`] instead. If both of these things are true, consider using
+    /// [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => acos_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            Float(Zero { .. }) => pi_multiple_prec_round(true, 2, prec, rm),
+            _ => (float_nan!(), Ordering::Equal),
+        }
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec(5);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec(20);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arccosine is less than, equal to, or greater than the exact
+    /// arccosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853981633974482");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arccosine is less than, equal to, or greater than the exact arccosine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Acos::acos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853981633974482");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).acos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn acos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arccosine is less than, equal to, or greater than the exact arccosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acos_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acos_round_assign`] instead. If both of these things are true, consider using
+    /// [`AcosAssign::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.81");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// ```
+    pub fn acos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (acos, o) = take(self).acos_prec_round(prec, rm);
+        *self = acos;
+        o
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arccosine is less than, equal to, or greater than the exact arccosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arccosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AcosAssign::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// ```
+    #[inline]
+    pub fn acos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acos_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arccosine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arccosine is less
+    /// than, equal to, or greater than the exact arccosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arccos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arccos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acos_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`AcosAssign::acos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arccosine is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.7853981633974482");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.acos_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// ```
+    #[inline]
+    pub fn acos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, rm)
+    }
+}
+
+impl Acos for Float {
+    type Output = Float;
+
+    /// Computes the arccosine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::acos_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::acos_round`].
+    /// If you want both of these things, consider using [`Float::acos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).acos().is_nan());
+    /// assert!((Float::INFINITY).acos().is_nan());
+    /// assert_eq!(ComparableFloat((Float::ONE).acos()), ComparableFloat(Float::ZERO));
+    /// assert!((Float::from(1.5)).acos().is_nan());
+    ///
+    /// assert_eq!((Float::from(0.5)).acos().to_string(), "1.0471975511965979");
+    /// assert_eq!((Float::from(-0.5)).acos().to_string(), "2.0943951023931957");
+    /// ```
+    #[inline]
+    fn acos(self) -> Float {
+        let prec = self.significant_bits();
+        self.acos_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Acos for &'a Float {
+    type Output = Float;
+
+    /// Computes the arccosine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::acos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).acos().is_nan());
+    /// assert!((&Float::INFINITY).acos().is_nan());
+    /// assert_eq!(ComparableFloat((&Float::ONE).acos()), ComparableFloat(Float::ZERO));
+    /// assert!((&Float::from(1.5)).acos().is_nan());
+    ///
+    /// assert_eq!((&Float::from(0.5)).acos().to_string(), "1.0471975511965979");
+    /// assert_eq!((&Float::from(-0.5)).acos().to_string(), "2.0943951023931957");
+    /// ```
+    #[inline]
+    fn acos(self) -> Float {
+        let prec = self.significant_bits();
+        self.acos_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AcosAssign for Float {
+    /// Computes the arccosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arccosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arccos x+\varepsilon.
+    /// $$
+    /// - If $\arccos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arccos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arccos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Acos`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acos_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acos_round_assign`]. If you want both of these things, consider using
+    /// [`Float::acos_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AcosAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.acos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ONE;
+    /// x.acos_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.acos_assign();
+    /// assert_eq!(x.to_string(), "1.0471975511965979");
+    /// ```
+    #[inline]
+    fn acos_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.acos_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,879 @@
+use crate::arithmetic::asinh::ln_of_fixed;
+use crate::arithmetic::exp::{fixed_from_significand, round_approximation};
+use crate::arithmetic::ln::ln_fixed;
+use crate::arithmetic::sqrt::sqrt_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{
+    Acosh, AcoshAssign, IsPowerOf2, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the inverse hyperbolic cosine of `x` if `x` is NaN, infinite, or zero.
+const fn acosh_special(x: &Float) -> Float {
+    match x {
+        float_infinity!() => float_infinity!(),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the inverse hyperbolic cosine of a finite, nonzero `Float`, given its sign, exponent,
+// and significand.
+//
+// If $x < 1$, the result is NaN, and $\operatorname{arcosh} 1 = 0$. If $x > 2^{w+10}$, where $w$
+// is the working precision, $\operatorname{arcosh} x$ is less than $\ln 2x$ by less than
+// $1/(4x^2)$. Otherwise $\operatorname{arcosh} x = \ln(x+\sqrt{x^2-1})$, where $x^2-1$ is computed
+// exactly. When $x = 1 + \delta$ is close to 1, the argument of the logarithm is about $1 +
+// \sqrt{2\delta}$, so it is computed with enough fractional bits that it is accurate relative to
+// its distance from 1. The inverse hyperbolic cosine of any rational number other than 1 is
+// transcendental, so it can always be rounded correctly once it is approximated closely enough.
+fn acosh_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if !sign || exponent < 1 {
+        return (float_nan!(), Ordering::Equal);
+    } else if exponent == 1 && significand.is_power_of_2() {
+        return (float_zero!(), Ordering::Equal);
+    }
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    // x^2 - 1, with square_scale fractional bits, and the number of leading zeros after the binary
+    // point in x - 1
+    let (square_minus_1, square_scale, small_bits) = if low < 0 {
+        let minus_low = u64::exact_from(-low);
+        let one = Natural::power_of_2(minus_low);
+        let small_bits = minus_low.saturating_sub((significand - &one).significant_bits());
+        (
+            significand.square() - one.square(),
+            minus_low << 1,
+            small_bits,
+        )
+    } else {
+        ((significand.square() << (low << 1)) - Natural::ONE, 0, 0)
+    };
+    let half_small_bits = (small_bits >> 1) + 1;
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, scale) = if exponent > i64::exact_from(working_prec) + 10 {
+            let (value, error, scale) = ln_fixed(significand, low + 1, working_prec);
+            (value, error + Natural::ONE, scale)
+        } else {
+            let y_scale = working_prec + half_small_bits + 20;
+            let (t, t_error) = fixed_from_significand(true, significand, low, y_scale);
+            let y = t.unsigned_abs() + sqrt_fixed(&square_minus_1, square_scale, y_scale);
+            ln_of_fixed(&y, &(t_error + Natural::TWO), y_scale, working_prec)
+        };
+        (value, error, -i64::exact_from(scale))
+    })
+}
+impl Float {
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic cosine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round`] instead. If both of these things are true, consider using
+    /// [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.811527");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => acosh_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (acosh_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic cosine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.811527");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => acosh_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (acosh_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec(5);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec(20);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.acosh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal
+    /// to, or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.acosh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic cosine is less than, equal to,
+    /// or greater than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::acosh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Acosh::acosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).acosh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn acosh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded inverse hyperbolic cosine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acosh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::acosh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::acosh_round_assign`] instead. If both of these things are true, consider using
+    /// [`AcoshAssign::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811525");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.811527");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// ```
+    pub fn acosh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (acosh, o) = take(self).acosh_prec_round(prec, rm);
+        *self = acosh;
+        o
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded inverse hyperbolic cosine is less than, equal to, or greater than the exact
+    /// inverse hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic cosine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::acosh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AcoshAssign::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811525");
+    /// ```
+    #[inline]
+    pub fn acosh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.acosh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded inverse
+    /// hyperbolic cosine is less than, equal to, or greater than the exact inverse hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::acosh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::acosh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AcoshAssign::acosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic cosine is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.8115262724608532");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.acosh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.811526272460853");
+    /// ```
+    #[inline]
+    pub fn acosh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Acosh for Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::acosh_round`]. If you want both of these things, consider using
+    /// [`Float::acosh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acosh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).acosh().is_nan());
+    /// assert_eq!((Float::INFINITY).acosh(), Float::INFINITY);
+    /// assert_eq!(ComparableFloat((Float::ONE).acosh()), ComparableFloat(Float::ZERO));
+    /// assert!((Float::ZERO).acosh().is_nan());
+    ///
+    /// assert_eq!((Float::from(1.5)).acosh().to_string(), "0.9624236501192069");
+    /// assert!((Float::from(0.5)).acosh().is_nan());
+    /// ```
+    #[inline]
+    fn acosh(self) -> Float {
+        let prec = self.significant_bits();
+        self.acosh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Acosh for &'a Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic cosine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acosh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::acosh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Acosh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).acosh().is_nan());
+    /// assert_eq!((&Float::INFINITY).acosh(), Float::INFINITY);
+    /// assert_eq!(ComparableFloat((&Float::ONE).acosh()), ComparableFloat(Float::ZERO));
+    /// assert!((&Float::ZERO).acosh().is_nan());
+    ///
+    /// assert_eq!((&Float::from(1.5)).acosh().to_string(), "0.9624236501192069");
+    /// assert!((&Float::from(0.5)).acosh().is_nan());
+    /// ```
+    #[inline]
+    fn acosh(self) -> Float {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AcoshAssign for Float {
+    /// Computes the inverse hyperbolic cosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arcosh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arcosh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arcosh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arcosh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Acosh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::acosh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::acosh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::acosh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AcoshAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.acosh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ONE;
+    /// x.acosh_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.acosh_assign();
+    /// assert_eq!(x.to_string(), "0.9624236501192069");
+    /// ```
+    #[inline]
+    fn acosh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.acosh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,1930 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, float_negative_zero, float_zero};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, min, Ordering};
+use std::mem::{swap, take};
+use std::ops::{Add, AddAssign};
+
+// The zero returned when two nonzero values cancel exactly. As in IEEE 754 and MPFR, this is
+// positive zero unless the rounding mode is `Floor`.
+pub(crate) const fn exact_cancellation_zero(rm: RoundingMode) -> Float {
+    if let RoundingMode::Floor = rm {
+        float_negative_zero!()
+    } else {
+        float_zero!()
+    }
+}
+
+// Adds two finite, nonzero values, given as signs, exponents, and significands, and rounds the
+// result to `prec` bits. The significands need not be normalized to a whole number of limbs, but
+// each exponent must be the exponent of its value, so that 2^(exponent - 1) <= |value| <
+// 2^exponent.
+//
+// If the smaller operand's exponent is low enough that it cannot affect any bit of the sum except
+// through the sticky bit, only the larger operand's significand is shifted, and the smaller
+// operand is replaced by a single bit below the rounding position. Otherwise, the sum is computed
+// exactly. Either way, the work done is proportional to `prec` plus the precisions of the inputs,
+// no matter how far apart the exponents are.
+pub(crate) fn add_finite_prec_round<'a>(
+    mut x_sign: bool,
+    mut x_exponent: i64,
+    mut x_significand: &'a Natural,
+    mut y_sign: bool,
+    mut y_exponent: i64,
+    mut y_significand: &'a Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if x_exponent < y_exponent {
+        swap(&mut x_sign, &mut y_sign);
+        swap(&mut x_exponent, &mut y_exponent);
+        swap(&mut x_significand, &mut y_significand);
+    }
+    // The value of the lowest bit of each significand
+    let x_low = x_exponent - i64::exact_from(x_significand.significant_bits());
+    let y_low = y_exponent - i64::exact_from(y_significand.significant_bits());
+    let cutoff = min(x_exponent - i64::exact_from(prec) - 2, x_low);
+    if y_exponent <= cutoff {
+        // |y| < 2^cutoff, and x is a multiple of 2^cutoff with at least prec + 2 bits, so x + y
+        // lies strictly between two consecutive multiples of 2^cutoff, and rounding it gives the
+        // same result as rounding the midpoint of those multiples.
+        let mut m = x_significand << (u64::exact_from(x_low - cutoff) + 1);
+        if x_sign == y_sign {
+            m += Natural::ONE;
+        } else {
+            m -= Natural::ONE;
+        }
+        let (f, o) = Float::from_natural_times_power_of_2_prec_round(
+            m,
+            cutoff - 1,
+            prec,
+            if x_sign { rm } else { -rm },
+        );
+        if x_sign {
+            (f, o)
+        } else {
+            (-f, o.reverse())
+        }
+    } else {
+        let low = min(x_low, y_low);
+        let sum = Integer::from_sign_and_abs(x_sign, x_significand << u64::exact_from(x_low - low))
+            + Integer::from_sign_and_abs(y_sign, y_significand << u64::exact_from(y_low - low));
+        if sum == 0u32 {
+            (exact_cancellation_zero(rm), Ordering::Equal)
+        } else {
+            Float::from_integer_times_power_of_2_prec_round(sum, low, prec, rm)
+        }
+    }
+}
+
+// Adds `x` and `y`, or `x` and `-y` if `negate_y` is true.
+pub(crate) fn add_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Ordering::Equal),
+        (Float(Infinity { sign: x_sign }), Float(Infinity { sign: y_sign })) => {
+            if *x_sign == (*y_sign != negate_y) {
+                (Float(Infinity { sign: *x_sign }), Ordering::Equal)
+            } else {
+                (float_nan!(), Ordering::Equal)
+            }
+        }
+        (Float(Infinity { sign }), _) => (Float(Infinity { sign: *sign }), Ordering::Equal),
+        (_, Float(Infinity { sign })) => (
+            Float(Infinity {
+                sign: *sign != negate_y,
+            }),
+            Ordering::Equal,
+        ),
+        (Float(Zero { sign: x_sign }), Float(Zero { sign: y_sign })) => (
+            if *x_sign == (*y_sign != negate_y) {
+                Float(Zero { sign: *x_sign })
+            } else {
+                exact_cancellation_zero(rm)
+            },
+            Ordering::Equal,
+        ),
+        (Float(Zero { .. }), y) => {
+            let mut sum = y.clone();
+            if negate_y {
+                sum.neg_assign();
+            }
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        (x, Float(Zero { .. })) => {
+            let mut sum = x.clone();
+            let o = sum.set_prec_round(prec, rm);
+            (sum, o)
+        }
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => add_finite_prec_round(
+            *x_sign,
+            *x_exponent,
+            x_significand,
+            *y_sign != negate_y,
+            *y_exponent,
+            y_significand,
+            prec,
+            rm,
+        ),
+    }
+}
+
+// Adds `x` and `y`, or `x` and `-y` if `negate_y` is true, taking `x` by value.
+pub(crate) fn add_prec_round_val_ref_helper(
+    mut x: Float,
+    y: &Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if let (Float(Finite { .. }), Float(Zero { .. })) = (&x, y) {
+        assert_ne!(prec, 0);
+        let o = x.set_prec_round(prec, rm);
+        (x, o)
+    } else {
+        add_prec_round_ref_ref_helper(&x, y, negate_y, prec, rm)
+    }
+}
+
+// Adds `x` and `y`, or `x` and `-y` if `negate_y` is true, taking both by value.
+pub(crate) fn add_prec_round_val_val_helper(
+    x: Float,
+    mut y: Float,
+    negate_y: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if let (Float(Zero { .. }), Float(Finite { .. })) = (&x, &y) {
+        assert_ne!(prec, 0);
+        if negate_y {
+            y.neg_assign();
+        }
+        let o = y.set_prec_round(prec, rm);
+        (y, o)
+    } else {
+        add_prec_round_val_ref_helper(x, &y, negate_y, prec, rm)
+    }
+}
+
+impl Float {
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,-\infty,p,m)=f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::add_round`] instead. If both of these things are true, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        add_prec_round_val_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,-\infty,p,m)=f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_val_ref`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_val_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,-\infty,p,m)=f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_ref_val`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_val_ref_helper(other, self, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the specified precision and with the specified
+    /// rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,-\infty,p,m)=f(-\infty,\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p,m)=0.0$
+    /// - $f(-0.0,-0.0,p,m)=-0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,p,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,p,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "6.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.85988");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_prec_round_ref_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,-\infty,p)=f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,-\infty,p)=f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,-\infty,p)=f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded sum is less than, equal to, or greater than the exact sum. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,-\infty,p)=f(-\infty,\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,p)=0.0$
+    /// - $f(-0.0,-0.0,p)=-0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=0.0$
+    /// - $f(x,-x,p)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "5.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by value. An [`Ordering`] is also returned, indicating whether the rounded sum is
+    /// less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,-\infty,m)=f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by value and the second by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,-\infty,m)=f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by reference and the second by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,-\infty,m)=f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Adds two [`Float`]s, rounding the result with the specified rounding mode. Both [`Float`]s
+    /// are taken by reference. An [`Ordering`] is also returned, indicating whether the rounded sum
+    /// is less than, equal to, or greater than the exact sum. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,-\infty,m)=f(-\infty,\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0,m)=0.0$
+    /// - $f(-0.0,-0.0,m)=-0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=0.0$ if $m$ is not `Floor`
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$ if $m$ is `Floor`
+    /// - $f(x,-x,m)=0.0$ if $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,-x,m)=-0.0$ if $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::add_round_assign`] instead. If both of these things are true,
+    /// consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.85988");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    pub fn add_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sum, o) = add_prec_round_val_val_helper(take(self), other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded sum is less than,
+    /// equal to, or greater than the exact sum. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::add_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact addition, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.85988");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    pub fn add_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (sum, o) = add_prec_round_val_ref_helper(take(self), other, false, prec, rm);
+        *self = sum;
+        o
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`]
+    /// is returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.add_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded sum is less than, equal to, or
+    /// greater than the exact sum. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the sum is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.add_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "5.85987");
+    /// ```
+    #[inline]
+    pub fn add_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.add_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded sum is less than, equal to, or greater than the exact sum.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::add_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `+=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    pub fn add_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign(other, prec, rm)
+    }
+
+    /// Adds a [`Float`] to a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded sum is less than, equal to, or greater than the
+    /// exact sum. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::add_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `+=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048839");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "5.859874482048838");
+    /// ```
+    #[inline]
+    pub fn add_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Add<Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s. Both [`Float`]s are taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::add_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::add_round`].
+    /// If you want both of these things, consider using [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) + Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(Float::from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(Float::from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(Float::from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Add<&'a Float> for Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s. The first [`Float`] is taken by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::add_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::add_round`].
+    /// If you want both of these things, consider using [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) + &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(Float::from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(Float::from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(Float::from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Add<Float> for &'a Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s. The first [`Float`] is taken by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::add_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::add_round`].
+    /// If you want both of these things, consider using [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) + Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) + Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) + Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY + Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) + Float::from(2.5), 4.0);
+    /// assert_eq!(&Float::from(1.5) + Float::from(-2.5), -1.0);
+    /// assert_eq!(&Float::from(-1.5) + Float::from(2.5), 1.0);
+    /// assert_eq!(&Float::from(-1.5) + Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Add<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Adds two [`Float`]s. Both [`Float`]s are taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\infty,-\infty)=f(-\infty,\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x$ is not NaN or $-\infty$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x$ is not NaN or $\infty$
+    /// - $f(0.0,0.0)=0.0$
+    /// - $f(-0.0,-0.0)=-0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=0.0$
+    /// - $f(x,-x)=0.0$ if $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::add_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::add_round`].
+    /// If you want both of these things, consider using [`Float::add_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) + &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) + &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) + &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY + &Float::NEGATIVE_INFINITY).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) + &Float::from(2.5), 4.0);
+    /// assert_eq!(&Float::from(1.5) + &Float::from(-2.5), -1.0);
+    /// assert_eq!(&Float::from(-1.5) + &Float::from(2.5), 1.0);
+    /// assert_eq!(&Float::from(-1.5) + &Float::from(-2.5), -4.0);
+    /// ```
+    #[inline]
+    fn add(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl AddAssign<Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place. The [`Float`] on the right-hand side is taken by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::add_round_assign`]. If you want both of these things, consider using
+    /// [`Float::add_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::from(2.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += Float::from(-2.5);
+    /// assert_eq!(x, -1.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> AddAssign<&'a Float> for Float {
+    /// Adds a [`Float`] to a [`Float`] in place. The [`Float`] on the right-hand side is taken by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the sum
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets x+y+\varepsilon.
+    /// $$
+    /// - If $x+y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `+` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::add_round_assign`]. If you want both of these things, consider using
+    /// [`Float::add_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::from(2.5);
+    /// assert_eq!(x, 4.0);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x += &Float::from(-2.5);
+    /// assert_eq!(x, -1.0);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.add_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,2214 @@
+use crate::arithmetic::add::{
+    add_finite_prec_round, add_prec_round_ref_ref_helper, add_prec_round_val_ref_helper,
+};
+use crate::arithmetic::mul::{mul_low_exponent, mul_special};
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use malachite_base::num::arithmetic::traits::{AddMul, AddMulAssign, NegAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the sign, significand, and low exponent of the exact product of two finite, nonzero
+// `Float`s.
+fn exact_product_ref_ref(y: &Float, z: &Float) -> (bool, Natural, i64) {
+    if let (
+        Float(Finite {
+            sign: y_sign,
+            exponent: y_exponent,
+            significand: y_significand,
+            ..
+        }),
+        Float(Finite {
+            sign: z_sign,
+            exponent: z_exponent,
+            significand: z_significand,
+            ..
+        }),
+    ) = (y, z)
+    {
+        (
+            y_sign == z_sign,
+            y_significand * z_significand,
+            mul_low_exponent(*y_exponent, y_significand, *z_exponent, z_significand),
+        )
+    } else {
+        unreachable!()
+    }
+}
+
+// Like `exact_product_ref_ref`, but takes both `Float`s by value, so that their significands can
+// be reused.
+fn exact_product_val_val(y: Float, z: Float) -> (bool, Natural, i64) {
+    if let (
+        Float(Finite {
+            sign: y_sign,
+            exponent: y_exponent,
+            significand: y_significand,
+            ..
+        }),
+        Float(Finite {
+            sign: z_sign,
+            exponent: z_exponent,
+            significand: z_significand,
+            ..
+        }),
+    ) = (y, z)
+    {
+        let low = mul_low_exponent(y_exponent, &y_significand, z_exponent, &z_significand);
+        (y_sign == z_sign, y_significand * z_significand, low)
+    } else {
+        unreachable!()
+    }
+}
+
+// Computes `x + p`, where `x` is finite (possibly zero) and `p` is the exact nonzero product whose
+// sign, significand, and low exponent are given. The result is rounded only once.
+fn add_exact_product_prec_round(
+    x: &Float,
+    product_sign: bool,
+    product: Natural,
+    low: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    match x {
+        Float(Finite {
+            sign: x_sign,
+            exponent: x_exponent,
+            significand: x_significand,
+            ..
+        }) => {
+            let product_exponent = low + i64::exact_from(product.significant_bits());
+            add_finite_prec_round(
+                *x_sign,
+                *x_exponent,
+                x_significand,
+                product_sign,
+                product_exponent,
+                &product,
+                prec,
+                rm,
+            )
+        }
+        Float(Zero { .. }) => Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(product_sign, product),
+            low,
+            prec,
+            rm,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+// Computes `x + y * z`, or `x - y * z` if `negate_product` is true, with a single rounding.
+pub(crate) fn add_mul_prec_round_ref_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    z: &Float,
+    negate_product: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if let Some(mut product) = mul_special(y, z) {
+        if negate_product {
+            product.neg_assign();
+        }
+        add_prec_round_ref_ref_helper(x, &product, false, prec, rm)
+    } else if let Float(NaN | Infinity { .. }) = x {
+        (x.clone(), Ordering::Equal)
+    } else {
+        let (sign, product, low) = exact_product_ref_ref(y, z);
+        add_exact_product_prec_round(x, sign != negate_product, product, low, prec, rm)
+    }
+}
+
+// Computes `x + y * z`, or `x - y * z` if `negate_product` is true, with a single rounding, taking
+// `x` by value.
+pub(crate) fn add_mul_prec_round_val_ref_ref_helper(
+    x: Float,
+    y: &Float,
+    z: &Float,
+    negate_product: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if let Some(mut product) = mul_special(y, z) {
+        if negate_product {
+            product.neg_assign();
+        }
+        add_prec_round_val_ref_helper(x, &product, false, prec, rm)
+    } else if let Float(NaN | Infinity { .. }) = x {
+        (x, Ordering::Equal)
+    } else {
+        let (sign, product, low) = exact_product_ref_ref(y, z);
+        add_exact_product_prec_round(&x, sign != negate_product, product, low, prec, rm)
+    }
+}
+
+// Computes `x + y * z`, or `x - y * z` if `negate_product` is true, with a single rounding, taking
+// all three `Float`s by value.
+pub(crate) fn add_mul_prec_round_val_val_val_helper(
+    x: Float,
+    y: Float,
+    z: Float,
+    negate_product: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    if let Some(mut product) = mul_special(&y, &z) {
+        if negate_product {
+            product.neg_assign();
+        }
+        add_prec_round_val_ref_helper(x, &product, false, prec, rm)
+    } else if let Float(NaN | Infinity { .. }) = x {
+        (x, Ordering::Equal)
+    } else {
+        let (sign, product, low) = exact_product_val_val(y, z);
+        add_exact_product_prec_round(&x, sign != negate_product, product, low, prec, rm)
+    }
+}
+
+impl Float {
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x+yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the three inputs,
+    /// consider using [`Float::add_mul_round`] instead. If both of these things are true, consider
+    /// using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-add, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985817");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_round(
+        self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_val_val_val_helper(self, y, z, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. The first [`Float`] is taken by
+    /// value and the other two by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x+yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec_val_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::add_mul_round_val_ref_ref`] instead. If both of these
+    /// things are true, consider using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-add, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985817");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_round_val_ref_ref(
+        self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_val_ref_ref_helper(self, y, z, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x+yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec_ref_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::add_mul_round_ref_ref_ref`] instead. If both of these
+    /// things are true, consider using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-add, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.8");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985817");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_ref_ref_ref_helper(self, y, z, false, prec, rm)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by value. An [`Ordering`]
+    /// is also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_round`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the three inputs, consider using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec(Float::from(E), Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_prec(Float::from(E), Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec(self, y: Float, z: Float, prec: u64) -> (Float, Ordering) {
+        self.add_mul_prec_round(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. The first [`Float`] is taken by value and the other two by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_round_val_ref_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the three inputs, consider using [`AddMul::add_mul`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_mul_prec_val_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_mul_prec_val_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_val_ref_ref(self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_mul_prec_round_val_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_round_ref_ref_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the three inputs, consider using [`AddMul::add_mul`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_mul_prec_ref_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "7.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).add_mul_prec_ref_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_ref_ref_ref(&self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.add_mul_prec_round_ref_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::add_mul_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.9858236817489106");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_mul_round(self, y: Float, z: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round(y, z, prec, rm)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the other two by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_mul_prec_round_val_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.9858236817489106");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_mul_round_val_ref_ref(
+        self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_val_ref_ref(y, z, prec, rm)
+    }
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=-yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_mul_prec_round_ref_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AddMul::add_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "6.9858236817489106");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).add_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn add_mul_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_ref_ref_ref(y, z, prec, rm)
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. The two [`Float`]s on the
+    /// right-hand side are taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::add_mul_round_assign`] instead. If both of these
+    /// things are true, consider using [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-add, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.985817");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// ```
+    pub fn add_mul_prec_round_assign(
+        &mut self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (result, o) = add_mul_prec_round_val_val_val_helper(take(self), y, z, false, prec, rm);
+        *self = result;
+        o
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. The two [`Float`]s on the
+    /// right-hand side are taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::add_mul_prec_assign_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::add_mul_round_assign_ref_ref`] instead. If both of
+    /// these things are true, consider using [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-add, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.8");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.985817");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// ```
+    pub fn add_mul_prec_round_assign_ref_ref(
+        &mut self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (result, o) = add_mul_prec_round_val_ref_ref_helper(take(self), y, z, false, prec, rm);
+        *self = result;
+        o
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. The two [`Float`]s on the right-hand side are
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the three inputs, consider using
+    /// [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_assign(Float::from(E), Float::from(SQRT_2), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_assign(Float::from(E), Float::from(SQRT_2), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_assign(&mut self, y: Float, z: Float, prec: u64) -> Ordering {
+        self.add_mul_prec_round_assign(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. The two [`Float`]s on the right-hand side are
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::add_mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_round_assign_ref_ref`] instead. If you know that your target precision
+    /// is the maximum of the precisions of the three inputs, consider using
+    /// [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_assign_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "7.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_prec_assign_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.985825");
+    /// ```
+    #[inline]
+    pub fn add_mul_prec_assign_ref_ref(&mut self, y: &Float, z: &Float, prec: u64) -> Ordering {
+        self.add_mul_prec_round_assign_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. The two [`Float`]s on the right-hand side are taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded result is less than, equal to, or
+    /// greater than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::add_mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_mul_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.9858236817489106");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// ```
+    #[inline]
+    pub fn add_mul_round_assign(&mut self, y: Float, z: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign(y, z, prec, rm)
+    }
+
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. The two [`Float`]s on the right-hand side are taken by reference.
+    /// An [`Ordering`] is returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x+yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x+yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::add_mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::add_mul_prec_round_assign_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`AddMulAssign::add_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "6.9858236817489106");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.add_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "6.98582368174891");
+    /// ```
+    #[inline]
+    pub fn add_mul_round_assign_ref_ref(
+        &mut self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign_ref_ref(y, z, prec, rm)
+    }
+}
+
+impl AddMul<Float, Float> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking all three by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::add_mul_round`]. If you want both of these things, consider using
+    /// [`Float::add_mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(Float::from(3.0), Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(Float::from(-3.0), Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .add_mul(Float::INFINITY, Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn add_mul(self, y: Float, z: Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round(y, z, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> AddMul<Float, &'a Float> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking the first two by value and
+    /// the third by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(Float::from(3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(Float::from(-3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .add_mul(Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn add_mul(self, y: Float, z: &'a Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_val_ref_ref(&y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> AddMul<&'a Float, Float> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking the first and third by
+    /// value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(&Float::from(3.0), Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(&Float::from(-3.0), Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .add_mul(&Float::INFINITY, Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn add_mul(self, y: &'a Float, z: Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_val_ref_ref(y, &z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> AddMul<&'a Float, &'b Float> for Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking the first by value and the
+    /// second and third by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(&Float::from(3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).add_mul(&Float::from(-3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .add_mul(&Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn add_mul(self, y: &'a Float, z: &'b Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_val_ref_ref(y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b, 'c> AddMul<&'a Float, &'b Float> for &'c Float {
+    type Output = Float;
+
+    /// Adds a [`Float`] and the product of two other [`Float`]s, taking all three by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=-\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=-0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(x,y,z)=0.0$ if $x=-yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_ref_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_ref_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_ref_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(2.0)).add_mul(&Float::from(3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(2.0)).add_mul(&Float::from(-3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert!((&Float::from(2.0))
+    ///     .add_mul(&Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn add_mul(self, y: &'a Float, z: &'b Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_ref_ref_ref(y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl AddMulAssign<Float, Float> for Float {
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, taking both [`Float`]s on the
+    /// right-hand side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`AddMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_assign`]. If you want both of these things, consider
+    /// using [`Float::add_mul_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(Float::from(3.0), Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(Float::from(-3.0), Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    /// ```
+    #[inline]
+    fn add_mul_assign(&mut self, y: Float, z: Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign(y, z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> AddMulAssign<Float, &'a Float> for Float {
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, taking the first [`Float`] on
+    /// the right-hand side by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`AddMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(Float::from(3.0), &Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(Float::from(-3.0), &Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    /// ```
+    #[inline]
+    fn add_mul_assign(&mut self, y: Float, z: &'a Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign_ref_ref(&y, z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> AddMulAssign<&'a Float, Float> for Float {
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, taking the first [`Float`] on
+    /// the right-hand side by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`AddMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(&Float::from(3.0), Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(&Float::from(-3.0), Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    /// ```
+    #[inline]
+    fn add_mul_assign(&mut self, y: &'a Float, z: Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign_ref_ref(y, &z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a, 'b> AddMulAssign<&'a Float, &'b Float> for Float {
+    /// Adds the product of two [`Float`]s to a [`Float`] in place, taking both [`Float`]s on the
+    /// right-hand side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x+yz+\varepsilon.
+    /// $$
+    /// - If $x+yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x+yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x+yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`AddMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::add_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::add_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::add_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AddMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(&Float::from(3.0), &Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.add_mul_assign(&Float::from(-3.0), &Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    /// ```
+    #[inline]
+    fn add_mul_assign(&mut self, y: &'a Float, z: &'b Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.add_mul_prec_round_assign_ref_ref(y, z, prec, RoundingMode::Nearest);
+    }
+}
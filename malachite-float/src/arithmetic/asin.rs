@@ -0,0 +1,874 @@
+use crate::arithmetic::atan::{atan_fixed, pi_multiple_prec_round};
+use crate::arithmetic::exp::{fixed_from_significand, round_approximation, round_near_x};
+use crate::arithmetic::sqrt::sqrt_fixed;
+use crate::InnerFloat::{Finite, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{
+    Asin, AsinAssign, IsPowerOf2, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the arcsine of `x` if `x` is NaN, infinite, or zero.
+const fn asin_special(x: &Float) -> Float {
+    match x {
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the arcsine of a finite, nonzero `Float`, given its sign, exponent, and significand.
+//
+// If $|x| > 1$, the result is NaN, and if $|x| = 1$, it is $\pm\pi/2$. If $|x| <
+// 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then $\arcsin x$ lies
+// between $x$ and $x + x^3/3$, so it rounds like a value just farther from zero than $x$.
+// Otherwise $\arcsin x = 2\arctan(x/(1+\sqrt{1-x^2}))$, where $1-x^2$ is computed exactly and the
+// argument of $\arctan$ is at most 1. The computed argument is within $e_x + 3$ of the exact one,
+// where $e_x$ is the error in converting $x$ to fixed point.
+fn asin_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent > 1 || exponent == 1 && !significand.is_power_of_2() {
+        return (float_nan!(), Ordering::Equal);
+    } else if exponent == 1 {
+        return pi_multiple_prec_round(sign, 2, prec, rm);
+    }
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    // 1 - x^2, with -2 * low fractional bits
+    let complement_scale = u64::exact_from(-low) << 1;
+    let complement = Natural::power_of_2(complement_scale) - significand.square();
+    let small_bits = u64::exact_from(-exponent);
+    round_approximation(prec, rm, |working_prec| {
+        let scale = working_prec + small_bits + 10;
+        let (t, t_error) = fixed_from_significand(true, significand, low, scale);
+        let denominator =
+            Natural::power_of_2(scale) + sqrt_fixed(&complement, complement_scale, scale);
+        let ratio = (t.unsigned_abs() << scale) / denominator;
+        let (a, a_error) = atan_fixed(&ratio, &(t_error + Natural::from(3u32)), scale);
+        (
+            Integer::from_sign_and_abs(sign, a << 1u32),
+            a_error << 1u32,
+            -i64::exact_from(scale),
+        )
+    })
+}
+impl Float {
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round`] instead. If both of these things are true, consider using
+    /// [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => asin_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (asin_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arcsine is less than, equal to, or greater than the
+    /// exact arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_ref`] instead. If both of these things are true, consider using
+    /// [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.81");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => asin_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (asin_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec(5);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec(20);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arcsine is less than, equal to, or greater than the exact
+    /// arcsine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.78");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asin_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arcsine is less than, equal to, or greater than the exact arcsine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Asin::asin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).asin_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded arcsine is less than, equal to, or greater than the exact arcsine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asin_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asin_round_assign`] instead. If both of these things are true, consider using
+    /// [`AsinAssign::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.81");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.7853975");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// ```
+    pub fn asin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (asin, o) = take(self).asin_prec_round(prec, rm);
+        *self = asin;
+        o
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded arcsine
+    /// is less than, equal to, or greater than the exact arcsine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arcsine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AsinAssign::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.78");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853985");
+    /// ```
+    #[inline]
+    pub fn asin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asin_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arcsine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arcsine is less
+    /// than, equal to, or greater than the exact arcsine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arcsin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arcsin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asin_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`AsinAssign::asin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arcsine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.7853981633974483");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.asin_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.7853981633974484");
+    /// ```
+    #[inline]
+    pub fn asin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, rm)
+    }
+}
+
+impl Asin for Float {
+    type Output = Float;
+
+    /// Computes the arcsine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::asin_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::asin_round`].
+    /// If you want both of these things, consider using [`Float::asin_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).asin().is_nan());
+    /// assert!((Float::INFINITY).asin().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).asin()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(0.5)).asin().to_string(), "0.5235987755982989");
+    /// assert_eq!((Float::from(-0.5)).asin().to_string(), "-0.5235987755982989");
+    /// assert!((Float::from(1.5)).asin().is_nan());
+    /// ```
+    #[inline]
+    fn asin(self) -> Float {
+        let prec = self.significant_bits();
+        self.asin_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Asin for &'a Float {
+    type Output = Float;
+
+    /// Computes the arcsine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asin_round_ref`]. If you want both of these things, consider using
+    /// [`Float::asin_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).asin().is_nan());
+    /// assert!((&Float::INFINITY).asin().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).asin()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(0.5)).asin().to_string(), "0.5235987755982989");
+    /// assert_eq!((&Float::from(-0.5)).asin().to_string(), "-0.5235987755982989");
+    /// assert!((&Float::from(1.5)).asin().is_nan());
+    /// ```
+    #[inline]
+    fn asin(self) -> Float {
+        let prec = self.significant_bits();
+        self.asin_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AsinAssign for Float {
+    /// Computes the arcsine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arcsine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arcsin x+\varepsilon.
+    /// $$
+    /// - If $\arcsin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arcsin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arcsin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Asin`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asin_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asin_round_assign`]. If you want both of these things, consider using
+    /// [`Float::asin_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AsinAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.asin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.asin_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.asin_assign();
+    /// assert_eq!(x.to_string(), "0.5235987755982989");
+    /// ```
+    #[inline]
+    fn asin_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.asin_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,908 @@
+use crate::arithmetic::exp::{fixed_from_significand, round_approximation, round_near_x};
+use crate::arithmetic::ln::ln_fixed;
+use crate::arithmetic::sqrt::sqrt_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Asinh, AsinhAssign, PowerOf2, Square, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the inverse hyperbolic sine of `x` if `x` is NaN, infinite, or zero.
+const fn asinh_special(x: &Float) -> Float {
+    match x {
+        Float(Infinity { sign }) => Float(Infinity { sign: *sign }),
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Given an approximation `y` of some $y \geq 1$, with `y_scale` fractional bits and an error of at
+// most `y_error`, approximates $\ln y$ as `ln_fixed` does. Since $y \geq 1$, the error in `y`
+// changes the logarithm by at most the same amount.
+pub(crate) fn ln_of_fixed(
+    y: &Natural,
+    y_error: &Natural,
+    y_scale: u64,
+    working_prec: u64,
+) -> (Integer, Natural, u64) {
+    let (value, error, scale) = ln_fixed(y, -i64::exact_from(y_scale), working_prec);
+    let y_error = if scale <= y_scale {
+        (y_error >> (y_scale - scale)) + Natural::ONE
+    } else {
+        y_error << (scale - y_scale)
+    };
+    (value, error + y_error, scale)
+}
+
+// Computes the inverse hyperbolic sine of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $|x| < 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then
+// $\operatorname{arsinh} x$ lies between $x$ and $x - x^3/6$, so it rounds like a value just closer
+// to zero than $x$. If $|x| > 2^{w+10}$, where $w$ is the working precision,
+// $\operatorname{arsinh}|x|$ exceeds $\ln 2|x|$ by less than $1/(4x^2)$. Otherwise
+// $\operatorname{arsinh}|x| = \ln(|x|+\sqrt{x^2+1})$, where $x^2+1$ is computed exactly and the
+// argument of the logarithm is computed with enough fractional bits that it is accurate relative
+// to its distance from 1. Since $\operatorname{arsinh} x$ is transcendental for any nonzero
+// rational $x$, it can always be rounded correctly once it is approximated closely enough.
+fn asinh_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, !sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    // x^2 + 1, with square_scale fractional bits
+    let (square_plus_1, square_scale) = if low < 0 {
+        let square_scale = u64::exact_from(-low) << 1;
+        (
+            significand.square() + Natural::power_of_2(square_scale),
+            square_scale,
+        )
+    } else {
+        ((significand.square() << (low << 1)) + Natural::ONE, 0)
+    };
+    let small_bits = u64::exact_from(max(-exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, scale) = if exponent > i64::exact_from(working_prec) + 10 {
+            let (value, error, scale) = ln_fixed(significand, low + 1, working_prec);
+            (value, error + Natural::ONE, scale)
+        } else {
+            let y_scale = working_prec + small_bits + 20;
+            let (t, t_error) = fixed_from_significand(true, significand, low, y_scale);
+            let y = t.unsigned_abs() + sqrt_fixed(&square_plus_1, square_scale, y_scale);
+            ln_of_fixed(&y, &(t_error + Natural::TWO), y_scale, working_prec)
+        };
+        (
+            if sign { value } else { -value },
+            error,
+            -i64::exact_from(scale),
+        )
+    })
+}
+impl Float {
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round`] instead. If both of these things are true, consider using
+    /// [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.862297");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => asinh_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (asinh_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic sine is
+    /// less than, equal to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.81");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.862297");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => asinh_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (asinh_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic sine is less than, equal to, or
+    /// greater than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec(5);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec(20);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.asinh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic sine is less than, equal
+    /// to, or greater than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn asinh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.asinh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded inverse hyperbolic sine is less than, equal to, or greater than the
+    /// exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic sine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::asinh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Asinh::asinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).asinh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn asinh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded inverse hyperbolic sine is less than, equal to, or greater
+    /// than the exact inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asinh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::asinh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::asinh_round_assign`] instead. If both of these things are true, consider using
+    /// [`AsinhAssign::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.81");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.862297");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// ```
+    pub fn asinh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (asinh, o) = take(self).asinh_prec_round(prec, rm);
+        *self = asinh;
+        o
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded inverse hyperbolic sine is less than, equal to, or greater than the exact
+    /// inverse hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic sine is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::asinh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AsinhAssign::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295");
+    /// ```
+    #[inline]
+    pub fn asinh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.asinh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic sine of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded inverse
+    /// hyperbolic sine is less than, equal to, or greater than the exact inverse hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::asinh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::asinh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AsinhAssign::asinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic sine is not exactly representable with
+    /// the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.862295743310848");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.asinh_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.8622957433108482");
+    /// ```
+    #[inline]
+    pub fn asinh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Asinh for Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic sine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::asinh_round`]. If you want both of these things, consider using
+    /// [`Float::asinh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asinh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).asinh().is_nan());
+    /// assert_eq!((Float::INFINITY).asinh(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).asinh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).asinh().to_string(), "1.1947632172871092");
+    /// assert_eq!((Float::from(-1.5)).asinh().to_string(), "-1.1947632172871092");
+    /// ```
+    #[inline]
+    fn asinh(self) -> Float {
+        let prec = self.significant_bits();
+        self.asinh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Asinh for &'a Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic sine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asinh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::asinh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Asinh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).asinh().is_nan());
+    /// assert_eq!((&Float::INFINITY).asinh(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).asinh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).asinh().to_string(), "1.1947632172871092");
+    /// assert_eq!((&Float::from(-1.5)).asinh().to_string(), "-1.1947632172871092");
+    /// ```
+    #[inline]
+    fn asinh(self) -> Float {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AsinhAssign for Float {
+    /// Computes the inverse hyperbolic sine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// sine is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{arsinh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{arsinh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{arsinh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{arsinh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Asinh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::asinh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::asinh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::asinh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AsinhAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.asinh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.asinh_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.asinh_assign();
+    /// assert_eq!(x.to_string(), "1.1947632172871092");
+    /// ```
+    #[inline]
+    fn asinh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.asinh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,948 @@
+use crate::arithmetic::exp::{fixed_from_significand, round_approximation, round_near_x};
+use crate::constants::pi::pi_fixed;
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity};
+use malachite_base::num::arithmetic::traits::{
+    Atan, AtanAssign, DivRound, FloorSqrt, Parity, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Rounds $m\pi/4$, negated if `sign` is `false`, to precision `prec`.
+pub(crate) fn pi_multiple_prec_round(
+    sign: bool,
+    m: u32,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    round_approximation(prec, rm, |working_prec| {
+        let (pi, error) = pi_fixed(working_prec);
+        let m = Natural::from(m);
+        (
+            Integer::from_sign_and_abs(sign, pi * &m),
+            error * m,
+            -i64::exact_from(working_prec) - 2,
+        )
+    })
+}
+
+// Approximates $\arctan t$, where $0 \leq t \lesssim 1$ is given by the fixed-point value `t` with
+// `scale` fractional bits and an error of at most `t_error`. The result has `scale` fractional
+// bits; its error is returned along with it.
+//
+// The argument is reduced $k$ times with $\arctan t = 2\arctan(t/(1+\sqrt{1+t^2}))$, which at
+// least halves it, and the Taylor series is summed for the reduced argument, which is at most
+// about $2^{-k}$. Each reduction step halves the error already present and adds at most 3, and
+// each computed term of the series is within 2 of the exact term, with a tail of less than 1. Since
+// the derivative of $\arctan$ is at most 1, an error in the argument is not amplified.
+fn atan_reduced_fixed(t: &Natural, t_error: &Natural, scale: u64) -> (Natural, Natural) {
+    let halvings = max(1, scale.floor_sqrt() >> 1);
+    let guard = halvings + scale.significant_bits() + 6;
+    let p = scale + guard;
+    let one = Natural::power_of_2(p);
+    let one_squared = Natural::power_of_2(p << 1);
+    let mut t = t << guard;
+    let mut error = t_error << guard;
+    let three = Natural::from(3u32);
+    for _ in 0..halvings {
+        let root = (&one_squared + (&t).square()).floor_sqrt();
+        t = (t << p) / (&one + root);
+        error = (error >> 1u32) + &three;
+    }
+    let t_squared = (&t).square() >> p;
+    let mut power = t.clone();
+    let mut sum = t;
+    let mut negative = Natural::ZERO;
+    let mut i = 0u64;
+    loop {
+        i += 1;
+        power = (power * &t_squared) >> p;
+        if power == 0u32 {
+            break;
+        }
+        let term = &power / Natural::from(2 * i + 1);
+        if i.odd() {
+            negative += term;
+        } else {
+            sum += term;
+        }
+    }
+    error += Natural::from(2 * i + 1);
+    (
+        (sum - negative) << halvings >> guard,
+        ((error << halvings) >> guard) + Natural::TWO,
+    )
+}
+
+// Approximates $\arctan t$, where $t \geq 0$ is given by the fixed-point value `t` with `scale`
+// fractional bits and an error of at most `t_error`, which must be less than $t$ if $t > 1$. The
+// result has `scale` fractional bits; its error is returned along with it.
+//
+// If $t > 1$, $\arctan t = \pi/2 - \arctan(1/t)$ is used instead. The computed $1/t$ differs from
+// the reciprocal of the exact argument by at most $e_t/(t(t-e_t))$, plus 1 for truncation.
+pub(crate) fn atan_fixed(t: &Natural, t_error: &Natural, scale: u64) -> (Natural, Natural) {
+    if t.significant_bits() <= scale || *t == Natural::power_of_2(scale) {
+        return atan_reduced_fixed(t, t_error, scale);
+    }
+    let inverse = Natural::power_of_2(scale << 1) / t;
+    let inverse_error = (t_error << (scale << 1))
+        .div_round(t * (t - t_error), RoundingMode::Ceiling)
+        .0
+        + Natural::ONE;
+    let (a, a_error) = atan_reduced_fixed(&inverse, &inverse_error, scale);
+    // pi with scale - 1 fractional bits is pi / 2 with scale fractional bits
+    let (half_pi, half_pi_error) = pi_fixed(scale - 1);
+    (half_pi - a, half_pi_error + a_error)
+}
+
+// Computes the arctangent of a finite, nonzero `Float`, given its sign, exponent, and significand.
+//
+// If $|x| < 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then
+// $\arctan x$ lies between $x$ and $x - x^3/3$, so it rounds like a value just closer to zero than
+// $x$. If $|x| > 2^{w+10}$, where $w$ is the working precision, $\arctan x$ is within $2^{-w-9}$
+// of $\pm\pi/2$. Otherwise the argument is converted to fixed point with enough fractional bits
+// that the result has full relative precision. The arctangent of any nonzero rational number is
+// transcendental, so it can always be rounded correctly once it is approximated closely enough.
+fn atan_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, !sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    let small_bits = u64::exact_from(max(-exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let scale = working_prec + small_bits + 10;
+        let (value, error) = if exponent > i64::exact_from(scale) {
+            let (half_pi, half_pi_error) = pi_fixed(scale - 1);
+            (half_pi, half_pi_error + Natural::ONE)
+        } else {
+            let (t, t_error) = fixed_from_significand(true, significand, low, scale);
+            atan_fixed(&t.unsigned_abs(), &t_error, scale)
+        };
+        (
+            Integer::from_sign_and_abs(sign, value),
+            error,
+            -i64::exact_from(scale),
+        )
+    })
+}
+impl Float {
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\pi/2$
+    /// - $f(-\infty,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round`] instead. If both of these things are true, consider using
+    /// [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.262629");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => atan_finite_prec_round(sign, exponent, &significand, prec, rm),
+            Float(Infinity { sign }) => pi_multiple_prec_round(sign, 2, prec, rm),
+            x => (x, Ordering::Equal),
+        }
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded arctangent is less than, equal to, or greater
+    /// than the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\pi/2$
+    /// - $f(-\infty,p,m)=-\pi/2$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_ref`] instead. If both of these things are true, consider using
+    /// [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.262629");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => atan_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            Float(Infinity { sign }) => pi_multiple_prec_round(*sign, 2, prec, rm),
+            x => (x.clone(), Ordering::Equal),
+        }
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\pi/2$
+    /// - $f(-\infty,p)=-\pi/2$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec(5);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec(20);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\pi/2$
+    /// - $f(-\infty,p)=-\pi/2$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atan_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\pi/2$
+    /// - $f(-\infty,m)=-\pi/2$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\pi/2$
+    /// - $f(-\infty,m)=-\pi/2$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atan::atan`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atan_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atan_round_assign`] instead. If both of these things are true, consider using
+    /// [`AtanAssign::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.262629");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    pub fn atan_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (atan, o) = take(self).atan_prec_round(prec, rm);
+        *self = atan;
+        o
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AtanAssign::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.262627");
+    /// ```
+    #[inline]
+    pub fn atan_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atan_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the arctangent of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\arctan x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\arctan x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atan_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`AtanAssign::atan_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the arctangent is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.2626272556789115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2626272556789118");
+    /// ```
+    #[inline]
+    pub fn atan_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, rm)
+    }
+}
+
+impl Atan for Float {
+    type Output = Float;
+
+    /// Computes the arctangent of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\pi/2$
+    /// - $f(-\infty)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::atan_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::atan_round`].
+    /// If you want both of these things, consider using [`Float::atan_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).atan().is_nan());
+    /// assert!((Float::INFINITY).atan() > 1.5);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).atan()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).atan().to_string(), "0.9827937232473291");
+    /// assert_eq!((Float::from(-1.5)).atan().to_string(), "-0.9827937232473291");
+    /// ```
+    #[inline]
+    fn atan(self) -> Float {
+        let prec = self.significant_bits();
+        self.atan_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Atan for &'a Float {
+    type Output = Float;
+
+    /// Computes the arctangent of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\pi/2$
+    /// - $f(-\infty)=-\pi/2$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan_round_ref`]. If you want both of these things, consider using
+    /// [`Float::atan_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).atan().is_nan());
+    /// assert!((&Float::INFINITY).atan() > 1.5);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).atan()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).atan().to_string(), "0.9827937232473291");
+    /// assert_eq!((&Float::from(-1.5)).atan().to_string(), "-0.9827937232473291");
+    /// ```
+    #[inline]
+    fn atan(self) -> Float {
+        let prec = self.significant_bits();
+        self.atan_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AtanAssign for Float {
+    /// Computes the arctangent of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the arctangent is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \arctan x+\varepsilon.
+    /// $$
+    /// - If $\arctan x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\arctan x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\arctan
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Atan`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AtanAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.atan_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.atan_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan_assign();
+    /// assert_eq!(x.to_string(), "0.9827937232473291");
+    /// ```
+    #[inline]
+    fn atan_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.atan_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
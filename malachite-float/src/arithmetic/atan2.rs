@@ -0,0 +1,2024 @@
+use crate::arithmetic::atan::{atan_fixed, pi_multiple_prec_round};
+use crate::arithmetic::exp::round_approximation;
+use crate::constants::pi::pi_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+
+// Returns the two-argument arctangent of `y` and `x` if either is NaN, infinite, or zero.
+fn atan2_special(y: &Float, x: &Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    match (y, x) {
+        (float_nan!(), _) | (_, float_nan!()) => (float_nan!(), Ordering::Equal),
+        (Float(Infinity { sign }), Float(Infinity { sign: x_sign })) => {
+            pi_multiple_prec_round(*sign, if *x_sign { 1 } else { 3 }, prec, rm)
+        }
+        (Float(Infinity { sign }), _) | (Float(Finite { sign, .. }), Float(Zero { .. })) => {
+            pi_multiple_prec_round(*sign, 2, prec, rm)
+        }
+        (Float(Zero { sign }), x) | (Float(Finite { sign, .. }), x) => {
+            if x.is_sign_positive() {
+                (Float(Zero { sign: *sign }), Ordering::Equal)
+            } else {
+                pi_multiple_prec_round(*sign, 4, prec, rm)
+            }
+        }
+    }
+}
+
+// Approximates $|y/x|$, where $|y| = n_y2^{\ell_y}$ and $|x| = n_x2^{\ell_x}$, as a fixed-point
+// value with `scale` fractional bits. The error is less than 1.
+fn quotient_fixed(
+    y_significand: &Natural,
+    y_low: i64,
+    x_significand: &Natural,
+    x_low: i64,
+    scale: u64,
+) -> Natural {
+    let shift = y_low - x_low + i64::exact_from(scale);
+    if shift >= 0 {
+        (y_significand << shift) / x_significand
+    } else {
+        y_significand / (x_significand << -shift)
+    }
+}
+
+// Computes the two-argument arctangent of finite, nonzero `Float`s $y$ and $x$, given their signs,
+// exponents, and significands.
+//
+// The result has the sign of $y$, and its absolute value is $\arctan|y/x|$ if $x > 0$ and $\pi -
+// \arctan|y/x|$ if $x < 0$. If $d$ is the difference of the exponents of $y$ and $x$, then $2^{d-1}
+// < |y/x| < 2^{d+1}$. With $w$ the working precision plus 10:
+// - If $d > w$, $\arctan|y/x|$ is within $2^{-w}$ of $\pi/2$.
+// - If $d < -w$ and $x < 0$, $\arctan|y/x| < 2^{-w}$.
+// - If $2d + 2 < -w$ and $x > 0$, $\arctan|y/x|$ is within a relative error of $2^{-w}$ of $|y/x|$.
+// Otherwise $|y/x|$ is converted to fixed point with enough fractional bits that the result has
+// full relative precision. The result is transcendental, so it can always be rounded correctly
+// once it is approximated closely enough.
+#[allow(clippy::too_many_arguments)]
+fn atan2_finite_prec_round(
+    y_sign: bool,
+    y_exponent: i64,
+    y_significand: &Natural,
+    x_sign: bool,
+    x_exponent: i64,
+    x_significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let y_low = y_exponent - i64::exact_from(y_significand.significant_bits());
+    let x_low = x_exponent - i64::exact_from(x_significand.significant_bits());
+    let d = y_exponent - x_exponent;
+    round_approximation(prec, rm, |working_prec| {
+        let w = working_prec + 10;
+        let signed_w = i64::exact_from(w);
+        let (value, error, scale) = if d > signed_w {
+            // pi with w - 1 fractional bits is pi / 2 with w fractional bits
+            let (half_pi, half_pi_error) = pi_fixed(w - 1);
+            (half_pi, half_pi_error + Natural::ONE, w)
+        } else if !x_sign && d < -signed_w {
+            let (pi, pi_error) = pi_fixed(w);
+            (pi, pi_error + Natural::ONE, w)
+        } else if x_sign && (d + 1) << 1 < -signed_w {
+            // The quotient has about w significant bits
+            let scale = w + u64::exact_from(-d);
+            let quotient = quotient_fixed(y_significand, y_low, x_significand, x_low, scale);
+            (quotient, Natural::from(3u32), scale)
+        } else {
+            let small_bits = if x_sign {
+                u64::exact_from(max(-d, 0)) + 1
+            } else {
+                0
+            };
+            let scale = w + small_bits;
+            let quotient = quotient_fixed(y_significand, y_low, x_significand, x_low, scale);
+            let (a, a_error) = atan_fixed(&quotient, &Natural::ONE, scale);
+            if x_sign {
+                (a, a_error, scale)
+            } else {
+                let (pi, pi_error) = pi_fixed(scale);
+                (pi - a, pi_error + a_error, scale)
+            }
+        };
+        (
+            Integer::from_sign_and_abs(y_sign, value),
+            error,
+            -i64::exact_from(scale),
+        )
+    })
+}
+
+impl Float {
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the specified precision and with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::atan2_round`] instead. If both of these things are true, consider using
+    /// [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_val_ref`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded arctangent is less than, equal to, or greater than
+    /// the exact arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_val`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn atan2_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(&other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the specified precision and with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_ref_ref`] instead. If both of these things are true,
+    /// consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575125");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match (self, other) {
+            (
+                Float(Finite {
+                    sign: y_sign,
+                    exponent: y_exponent,
+                    significand: y_significand,
+                    ..
+                }),
+                Float(Finite {
+                    sign: x_sign,
+                    exponent: x_exponent,
+                    significand: x_significand,
+                    ..
+                }),
+            ) => atan2_finite_prec_round(
+                *y_sign,
+                *y_exponent,
+                y_significand,
+                *x_sign,
+                *x_exponent,
+                x_significand,
+                prec,
+                rm,
+            ),
+            (y, x) => atan2_special(y, x, prec, rm),
+        }
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the nearest value of the specified precision. Both [`Float`]s are
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded arctangent
+    /// is less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the nearest value of the specified precision. The first [`Float`] is
+    /// taken by value and the second by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the nearest value of the specified precision. The first [`Float`] is
+    /// taken by reference and the second by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result to the nearest value of the specified precision. Both [`Float`]s are
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,p) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(y,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,p)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,p)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,p)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,p)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,p)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,p)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,p)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,p)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,p)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,p)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,p)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.84");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.atan2_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result with the specified rounding mode. Both [`Float`]s are taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded arctangent is less than, equal
+    /// to, or greater than the exact arctangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atan2_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result with the specified rounding mode. The first [`Float`] is taken by value
+    /// and the second by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result with the specified rounding mode. The first [`Float`] is taken by
+    /// reference and the second by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`),
+    /// rounding the result with the specified rounding mode. Both [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x,m) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(y,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\infty,m)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty,m)=\pm3\pi/4$
+    /// - $f(\pm\infty,x,m)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0,m)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0,m)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x,m)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x,m)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty,m)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty,m)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty,m)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty,m)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2::atan2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).atan2_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atan2_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned, indicating
+    /// whether the rounded arctangent is less than, equal to, or greater than the exact arctangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::atan2_round_assign`] instead. If both of these things are true,
+    /// consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn atan2_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (atan2, o) = self.atan2_prec_round_ref_ref(&other, prec, rm);
+        *self = atan2;
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded arctangent is less than, equal to, or greater than the exact
+    /// arctangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atan2_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::atan2_round_assign_ref`] instead. If both of these things
+    /// are true, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575125");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    pub fn atan2_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (atan2, o) = self.atan2_prec_round_ref_ref(other, prec, rm);
+        *self = atan2;
+        o
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using [`Atan2Assign::atan2_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded arctangent is less than, equal to, or greater than the exact arctangent. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the arctangent is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atan2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the two inputs, consider using
+    /// [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.84");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575115");
+    /// ```
+    #[inline]
+    pub fn atan2_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.atan2_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result with the specified rounding mode. The [`Float`] on the right-hand side
+    /// is taken by value. An [`Ordering`] is returned, indicating whether the rounded arctangent is
+    /// less than, equal to, or greater than the exact arctangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign(Float::from(E), RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$,
+    /// rounding the result with the specified rounding mode. The [`Float`] on the right-hand side
+    /// is taken by reference. An [`Ordering`] is returned, indicating whether the rounded
+    /// arctangent is less than, equal to, or greater than the exact arctangent. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p+1}$, where $p$ is
+    ///   the maximum precision of the inputs.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the
+    ///   maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::atan2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atan2_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Atan2Assign::atan2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the two-argument arctangent is not exactly representable with
+    /// the maximum precision of the inputs.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558464");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.atan2_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.8575117863558462");
+    /// ```
+    #[inline]
+    pub fn atan2_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Atan2<Float> for Float {
+    type Output = Float;
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`).
+    /// Both [`Float`]s are taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).atan2(Float::ONE).is_nan());
+    /// assert_eq!((Float::ONE).atan2(Float::INFINITY), Float::ZERO);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).atan2(Float::ONE)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).atan2(Float::from(-2.5)).to_string(), "2.6011731533192091");
+    /// assert_eq!((Float::from(-1.5)).atan2(Float::from(2.5)).to_string(), "-0.5404195002705842");
+    /// ```
+    #[inline]
+    fn atan2(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Atan2<&'a Float> for Float {
+    type Output = Float;
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`).
+    /// The first [`Float`] is taken by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).atan2(&Float::ONE).is_nan());
+    /// assert_eq!((Float::ONE).atan2(&Float::INFINITY), Float::ZERO);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).atan2(&Float::ONE)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).atan2(&Float::from(-2.5)).to_string(), "2.6011731533192091");
+    /// assert_eq!((Float::from(-1.5)).atan2(&Float::from(2.5)).to_string(), "-0.5404195002705842");
+    /// ```
+    #[inline]
+    fn atan2(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Atan2<Float> for &'a Float {
+    type Output = Float;
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`).
+    /// The first [`Float`] is taken by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).atan2(Float::ONE).is_nan());
+    /// assert_eq!((&Float::ONE).atan2(Float::INFINITY), Float::ZERO);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).atan2(Float::ONE)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).atan2(Float::from(-2.5)).to_string(), "2.6011731533192091");
+    /// assert_eq!((&Float::from(-1.5)).atan2(Float::from(2.5)).to_string(), "-0.5404195002705842");
+    /// ```
+    #[inline]
+    fn atan2(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Atan2<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Computes the two-argument arctangent of a pair of [`Float`]s $y$ (`self`) and $x$ (`other`).
+    /// Both [`Float`]s are taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// f(y,x) = \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(y,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\infty)=\pm\pi/4$
+    /// - $f(\pm\infty,-\infty)=\pm3\pi/4$
+    /// - $f(\pm\infty,x)=\pm\pi/2$ if $x$ is finite
+    /// - $f(y,\pm0.0)=\pi/2$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\pm0.0)=-\pi/2$ if $y$ is finite and $y<0.0$
+    /// - $f(\pm0.0,x)=\pm0.0$ if $x$ is $0.0$ or $x>0.0$
+    /// - $f(\pm0.0,x)=\pm\pi$ if $x$ is $-0.0$ or $x<0.0$
+    /// - $f(y,\infty)=0.0$ if $y$ is finite and $y>0.0$
+    /// - $f(y,\infty)=-0.0$ if $y$ is finite and $y<0.0$
+    /// - $f(y,-\infty)=\pi$ if $y$ is finite and $y>0.0$
+    /// - $f(y,-\infty)=-\pi$ if $y$ is finite and $y<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atan2_round`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).atan2(&Float::ONE).is_nan());
+    /// assert_eq!((&Float::ONE).atan2(&Float::INFINITY), Float::ZERO);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).atan2(&Float::ONE)),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).atan2(&Float::from(-2.5)).to_string(), "2.6011731533192091");
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).atan2(&Float::from(2.5)).to_string(),
+    ///     "-0.5404195002705842"
+    /// );
+    /// ```
+    #[inline]
+    fn atan2(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl Atan2Assign<Float> for Float {
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$. The
+    /// [`Float`] on the right-hand side is taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// See the [`Atan2::atan2`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan2_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(Float::NAN);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(Float::INFINITY);
+    /// assert_eq!(x, Float::ZERO);
+    /// ```
+    #[inline]
+    fn atan2_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> Atan2Assign<&'a Float> for Float {
+    /// Replaces a [`Float`] $y$ with the two-argument arctangent of $y$ and a [`Float`] $x$. The
+    /// [`Float`] on the right-hand side is taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// arctangent is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $\operatorname{atan2}(y,x)$ is the angle $\theta \in [-\pi,\pi]$ between the positive
+    /// $x$-axis and the point $(x,y)$, so that $\tan\theta = y/x$.
+    ///
+    /// $$
+    /// y \gets \operatorname{atan2}(y,x)+\varepsilon.
+    /// $$
+    /// - If $\operatorname{atan2}(y,x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored
+    ///   or assumed to be 0.
+    /// - If $\operatorname{atan2}(y,x)$ is finite and nonzero, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\operatorname{atan2}(y,x)|\rfloor-p}$, where $p$ is the maximum
+    ///   precision of the inputs.
+    ///
+    /// See the [`Atan2::atan2`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atan2_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atan2_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atan2_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atan2Assign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(&Float::NAN);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.atan2_assign(&Float::INFINITY);
+    /// assert_eq!(x, Float::ZERO);
+    /// ```
+    #[inline]
+    fn atan2_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.atan2_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,897 @@
+use crate::arithmetic::exp::{round_approximation, round_near_x};
+use crate::arithmetic::ln::ln_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Atanh, AtanhAssign, IsPowerOf2, PowerOf2};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the inverse hyperbolic tangent of `x` if `x` is NaN, infinite, or zero.
+const fn atanh_special(x: &Float) -> Float {
+    match x {
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the inverse hyperbolic tangent of a finite, nonzero `Float`, given its sign, exponent,
+// and significand.
+//
+// If $|x| > 1$, the result is NaN, and if $|x| = 1$, it is $\pm\infty$. If $|x| <
+// 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then
+// $\operatorname{artanh} x$ lies between $x$ and $x + x^3/2$, so it rounds like a value just
+// farther from zero than $x$. Otherwise $\operatorname{artanh}|x| = (\ln(1+|x|) - \ln(1-|x|))/2$,
+// where $1+|x|$ and $1-|x|$ are exact. Both logarithms are computed with full relative precision,
+// so when $|x|$ is small, so that they nearly cancel, their difference still has full relative
+// precision. Since $\operatorname{artanh} x$ is transcendental for any nonzero rational $x$, it can
+// always be rounded correctly once it is approximated closely enough.
+fn atanh_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent > 1 || exponent == 1 && !significand.is_power_of_2() {
+        return (float_nan!(), Ordering::Equal);
+    } else if exponent == 1 {
+        return (Float(Infinity { sign }), Ordering::Equal);
+    }
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    let one = Natural::power_of_2(u64::exact_from(-low));
+    let one_plus_x = &one + significand;
+    let one_minus_x = one - significand;
+    round_approximation(prec, rm, |working_prec| {
+        let (plus, plus_error, plus_scale) = ln_fixed(&one_plus_x, low, working_prec);
+        let (minus, minus_error, minus_scale) = ln_fixed(&one_minus_x, low, working_prec);
+        let scale = max(plus_scale, minus_scale);
+        let value = (plus << (scale - plus_scale)) - (minus << (scale - minus_scale));
+        let error = (plus_error << (scale - plus_scale)) + (minus_error << (scale - minus_scale));
+        (
+            if sign { value } else { -value },
+            error,
+            -i64::exact_from(scale) - 1,
+        )
+    })
+}
+impl Float {
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic tangent is
+    /// less than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round`] instead. If both of these things are true, consider using
+    /// [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.91");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.881374");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => atanh_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (atanh_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded inverse hyperbolic tangent is
+    /// less than, equal to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(1.0,p,m)=\infty$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.91");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.881374");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => atanh_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (atanh_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal to,
+    /// or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(1.0,p)=\infty$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec(5);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec(20);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.atanh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal
+    /// to, or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(1.0,p)=\infty$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.88");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn atanh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.atanh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(1.0,m)=\infty$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.881373587019543");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded inverse hyperbolic tangent is less than, equal to,
+    /// or greater than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(1.0,m)=\infty$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::atanh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Atanh::atanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.881373587019543");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(FRAC_1_SQRT_2).atanh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn atanh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded inverse hyperbolic tangent is less than, equal to, or greater
+    /// than the exact inverse hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atanh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::atanh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::atanh_round_assign`] instead. If both of these things are true, consider using
+    /// [`AtanhAssign::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.91");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.881373");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.881374");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// ```
+    pub fn atanh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (atanh, o) = take(self).atanh_prec_round(prec, rm);
+        *self = atanh;
+        o
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded inverse hyperbolic tangent is less than, equal to, or greater than the exact
+    /// inverse hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the inverse hyperbolic tangent is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::atanh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`AtanhAssign::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.88");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.881373");
+    /// ```
+    #[inline]
+    pub fn atanh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.atanh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// inverse hyperbolic tangent is less than, equal to, or greater than the exact inverse
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is not `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p+1}$, where $p$ is the
+    ///   precision of the input.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero and $m$ is `Nearest`, then
+    ///   $|\varepsilon| < 2^{\lfloor\log_2 |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the
+    ///   precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::atanh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::atanh_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`AtanhAssign::atanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the inverse hyperbolic tangent is not exactly representable
+    /// with the precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::FRAC_1_SQRT_2;
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.881373587019543");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    ///
+    /// let mut x = Float::from(FRAC_1_SQRT_2);
+    /// assert_eq!(x.atanh_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.8813735870195432");
+    /// ```
+    #[inline]
+    pub fn atanh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Atanh for Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(1.0)=\infty$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::atanh_round`]. If you want both of these things, consider using
+    /// [`Float::atanh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atanh;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeOne, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).atanh().is_nan());
+    /// assert_eq!((Float::NEGATIVE_ONE).atanh(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).atanh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert!((Float::from(1.5)).atanh().is_nan());
+    ///
+    /// assert_eq!((Float::from(0.5)).atanh().to_string(), "0.5493061443340549");
+    /// assert_eq!((Float::from(-0.5)).atanh().to_string(), "-0.5493061443340549");
+    /// ```
+    #[inline]
+    fn atanh(self) -> Float {
+        let prec = self.significant_bits();
+        self.atanh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Atanh for &'a Float {
+    type Output = Float;
+
+    /// Computes the inverse hyperbolic tangent of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(1.0)=\infty$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $|x|>1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atanh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::atanh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Atanh;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeInfinity, NegativeOne, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).atanh().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ONE).atanh(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).atanh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert!((&Float::from(1.5)).atanh().is_nan());
+    ///
+    /// assert_eq!((&Float::from(0.5)).atanh().to_string(), "0.5493061443340549");
+    /// assert_eq!((&Float::from(-0.5)).atanh().to_string(), "-0.5493061443340549");
+    /// ```
+    #[inline]
+    fn atanh(self) -> Float {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl AtanhAssign for Float {
+    /// Computes the inverse hyperbolic tangent of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the inverse hyperbolic
+    /// tangent is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \operatorname{artanh} x+\varepsilon.
+    /// $$
+    /// - If $\operatorname{artanh} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or
+    ///   assumed to be 0.
+    /// - If $\operatorname{artanh} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\operatorname{artanh} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Atanh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::atanh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::atanh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::atanh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AtanhAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.atanh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.atanh_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(0.5);
+    /// x.atanh_assign();
+    /// assert_eq!(x.to_string(), "0.5493061443340549");
+    /// ```
+    #[inline]
+    fn atanh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.atanh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
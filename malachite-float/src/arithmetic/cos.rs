@@ -0,0 +1,821 @@
+use crate::arithmetic::exp::{round_approximation, round_near_x};
+use crate::arithmetic::sin::sin_cos_fixed;
+use crate::InnerFloat::{Finite, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the cosine of `x` if `x` is NaN, infinite, or zero. The cosine of zero is 1, which is
+// returned with precision `prec`.
+fn cos_special(x: &Float, prec: u64) -> Float {
+    match x {
+        Float(Zero { .. }) => Float::one_prec(prec),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the cosine of a finite, nonzero `Float`, given its sign, exponent, and significand.
+//
+// If $|x| < 2^{-(p+1)/2}$, then $\cos x$ lies between $1 - x^2/2$ and 1, so it rounds like a
+// value just below 1. Otherwise successively better approximations are computed until one of them
+// can be rounded correctly; since $\cos x$ is transcendental for any nonzero rational $x$, this
+// always happens eventually.
+fn cos_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent << 1 < -i64::exact_from(prec) {
+        return round_near_x(true, 1, &Natural::ONE, false, prec, rm);
+    }
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    round_approximation(prec, rm, |working_prec| {
+        let (_, _, cos, cos_error, scale) =
+            sin_cos_fixed(sign, exponent, significand, low, working_prec);
+        (cos, cos_error, -i64::exact_from(scale))
+    })
+}
+impl Float {
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round`] instead. If both of these things are true, consider using [`Cos::cos`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.97");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => cos_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (cos_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded cosine is less than, equal to, or greater than the
+    /// exact cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_ref`] instead. If both of these things are true, consider using
+    /// [`Cos::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.97");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => cos_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (cos_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Cos::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec(5);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec(20);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded cosine is less than, equal to, or greater than the exact
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Cos::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_ref(5);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_prec_ref(20);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Cos::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Cos::cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cos_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded cosine is less than, equal to, or greater than the exact cosine. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cos_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cos_round_assign`] instead. If both of these things are true, consider using
+    /// [`CosAssign::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-0.999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    pub fn cos_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (cos, o) = take(self).cos_prec_round(prec, rm);
+        *self = cos;
+        o
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded cosine is
+    /// less than, equal to, or greater than the exact cosine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the cosine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cos_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`CosAssign::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cos_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the cosine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded cosine is less than, equal
+    /// to, or greater than the exact cosine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cos_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cos_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`CosAssign::cos_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the cosine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "-0.9999999999999999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cos_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "-1.0");
+    /// ```
+    #[inline]
+    pub fn cos_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, rm)
+    }
+}
+
+impl Cos for Float {
+    type Output = Float;
+
+    /// Computes the cosine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::cos_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::cos_round`].
+    /// If you want both of these things, consider using [`Float::cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).cos().is_nan());
+    /// assert!((Float::INFINITY).cos().is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO).cos(), Float::ONE);
+    ///
+    /// assert_eq!((Float::from(1.5)).cos().to_string(), "0.07073720166770291");
+    /// assert_eq!((Float::from(-1.5)).cos().to_string(), "0.07073720166770291");
+    /// ```
+    #[inline]
+    fn cos(self) -> Float {
+        let prec = self.significant_bits();
+        self.cos_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Cos for &'a Float {
+    type Output = Float;
+
+    /// Computes the cosine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::cos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::cos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cos;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).cos().is_nan());
+    /// assert!((&Float::INFINITY).cos().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO).cos(), Float::ONE);
+    ///
+    /// assert_eq!((&Float::from(1.5)).cos().to_string(), "0.07073720166770291");
+    /// assert_eq!((&Float::from(-1.5)).cos().to_string(), "0.07073720166770291");
+    /// ```
+    #[inline]
+    fn cos(self) -> Float {
+        let prec = self.significant_bits();
+        self.cos_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl CosAssign for Float {
+    /// Computes the cosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \cos x+\varepsilon.
+    /// $$
+    /// - If $\cos x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Cos`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cos_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::cos_round_assign`]. If you want both of these things, consider using
+    /// [`Float::cos_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CosAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.cos_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.cos_assign();
+    /// assert_eq!(x, Float::ONE);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.cos_assign();
+    /// assert_eq!(x.to_string(), "0.07073720166770291");
+    /// ```
+    #[inline]
+    fn cos_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.cos_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,843 @@
+use crate::arithmetic::exp::{round_approximation, round_near_x};
+use crate::arithmetic::sinh::{exp_pair_fixed, half_exp_fixed};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Cosh, CoshAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the hyperbolic cosine of `x` if `x` is NaN, infinite, or zero. The hyperbolic cosine of
+// zero is 1, which is returned with precision `prec`.
+fn cosh_special(x: &Float, prec: u64) -> Float {
+    match x {
+        Float(Infinity { .. }) => float_infinity!(),
+        Float(Zero { .. }) => Float::one_prec(prec),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the hyperbolic cosine of a finite, nonzero `Float`, given its exponent and significand.
+//
+// If $|x| < 2^{-(p+1)/2}$, then $\cosh x$ lies between 1 and $1 + x^2/2 + x^4/12$, so it rounds
+// like a value just above 1. If $|x|$ is large, $e^{-|x|}$ is negligible, and $\cosh x$ is
+// approximated by $e^{|x|}/2$. Otherwise $\cosh x = (e^{|x|}+e^{-|x|})/2$. Since $\cosh x$ is
+// transcendental for any nonzero rational $x$, it can always be rounded correctly once it is
+// approximated closely enough.
+fn cosh_finite_prec_round(
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent << 1 < -i64::exact_from(prec) {
+        return round_near_x(true, 1, &Natural::ONE, true, prec, rm);
+    }
+    assert!(
+        exponent <= 62,
+        "The hyperbolic cosine of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, exponent) =
+            if exponent > i64::exact_from((working_prec + 12).significant_bits()) {
+                half_exp_fixed(exponent, significand, low, working_prec)
+            } else {
+                let scale = working_prec + 10;
+                let (p, p_error, m, m_error) = exp_pair_fixed(exponent, significand, low, scale);
+                (p + m, p_error + m_error, -i64::exact_from(scale) - 1)
+            };
+        (Integer::from(value), error, exponent)
+    })
+}
+impl Float {
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosine is less
+    /// than, equal to, or greater than the exact hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round`] instead. If both of these things are true, consider using
+    /// [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59196");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                exponent,
+                significand,
+                ..
+            }) => cosh_finite_prec_round(exponent, &significand, prec, rm),
+            x => (cosh_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic cosine is less
+    /// than, equal to, or greater than the exact hyperbolic cosine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59196");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                exponent,
+                significand,
+                ..
+            }) => cosh_finite_prec_round(*exponent, significand, prec, rm),
+            x => (cosh_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosine is less than, equal to, or greater than the
+    /// exact hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosine is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec(20);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.cosh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic cosine is less than, equal to, or
+    /// greater than the exact hyperbolic cosine. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosine is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.cosh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic cosine is less than, equal to, or greater than the exact
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cosh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic cosine is less than, equal to, or greater than the
+    /// exact hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Cosh::cosh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).cosh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn cosh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded hyperbolic cosine is less than, equal to, or greater than the exact
+    /// hyperbolic cosine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cosh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::cosh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::cosh_round_assign`] instead. If both of these things are true, consider using
+    /// [`CoshAssign::cosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "12.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.59195");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "11.59196");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// ```
+    pub fn cosh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (cosh, o) = take(self).cosh_prec_round(prec, rm);
+        *self = cosh;
+        o
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded hyperbolic cosine is less than, equal to, or greater than the exact hyperbolic
+    /// cosine. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic cosine is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::cosh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`CoshAssign::cosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.59195");
+    /// ```
+    #[inline]
+    pub fn cosh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.cosh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic cosine of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// hyperbolic cosine is less than, equal to, or greater than the exact hyperbolic cosine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cosh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\cosh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::cosh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::cosh_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`CoshAssign::cosh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic cosine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "11.59195327552152");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.cosh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.591953275521519");
+    /// ```
+    #[inline]
+    pub fn cosh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Cosh for Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic cosine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::cosh_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::cosh_round`].
+    /// If you want both of these things, consider using [`Float::cosh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cosh;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, One
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).cosh().is_nan());
+    /// assert_eq!((Float::NEGATIVE_INFINITY).cosh(), Float::INFINITY);
+    /// assert_eq!((Float::NEGATIVE_ZERO).cosh(), Float::ONE);
+    ///
+    /// assert_eq!((Float::from(1.5)).cosh().to_string(), "2.3524096152432472");
+    /// assert_eq!((Float::from(-1.5)).cosh().to_string(), "2.3524096152432472");
+    /// ```
+    #[inline]
+    fn cosh(self) -> Float {
+        let prec = self.significant_bits();
+        self.cosh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Cosh for &'a Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic cosine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::cosh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::cosh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cosh;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, One
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).cosh().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).cosh(), Float::INFINITY);
+    /// assert_eq!((&Float::NEGATIVE_ZERO).cosh(), Float::ONE);
+    ///
+    /// assert_eq!((&Float::from(1.5)).cosh().to_string(), "2.3524096152432472");
+    /// assert_eq!((&Float::from(-1.5)).cosh().to_string(), "2.3524096152432472");
+    /// ```
+    #[inline]
+    fn cosh(self) -> Float {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl CoshAssign for Float {
+    /// Computes the hyperbolic cosine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \cosh x+\varepsilon.
+    /// $$
+    /// - If $\cosh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\cosh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\cosh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Cosh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::cosh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::cosh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::cosh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CoshAssign;
+    /// use malachite_base::num::basic::traits::{NaN, One, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.cosh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.cosh_assign();
+    /// assert_eq!(x, Float::ONE);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.cosh_assign();
+    /// assert_eq!(x.to_string(), "2.3524096152432472");
+    /// ```
+    #[inline]
+    fn cosh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.cosh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
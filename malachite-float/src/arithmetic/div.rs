@@ -0,0 +1,2175 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::DivMod;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+use std::ops::{Div, DivAssign};
+
+// Returns the quotient of `x` and `y` if either is NaN, infinite, or zero. The quotient is then
+// also NaN, infinite, or zero, so no rounding is necessary.
+pub(crate) fn div_special(x: &Float, y: &Float) -> Option<Float> {
+    match (x, y) {
+        (float_nan!(), _)
+        | (_, float_nan!())
+        | (Float(Infinity { .. }), Float(Infinity { .. }))
+        | (Float(Zero { .. }), Float(Zero { .. })) => Some(float_nan!()),
+        (
+            Float(Infinity { sign: x_sign }),
+            Float(Finite { sign: y_sign, .. } | Zero { sign: y_sign }),
+        )
+        | (Float(Finite { sign: x_sign, .. }), Float(Zero { sign: y_sign })) => {
+            Some(Float(Infinity {
+                sign: x_sign == y_sign,
+            }))
+        }
+        (
+            Float(Zero { sign: x_sign }),
+            Float(Finite { sign: y_sign, .. } | Infinity { sign: y_sign }),
+        )
+        | (Float(Finite { sign: x_sign, .. }), Float(Infinity { sign: y_sign })) => {
+            Some(Float(Zero {
+                sign: x_sign == y_sign,
+            }))
+        }
+        _ => None,
+    }
+}
+
+// Given the exponents and significands of two finite, nonzero `Float`s, returns the number of bits
+// by which the dividend's significand must be shifted left so that the integer quotient of the
+// significands has at least `prec + 2` significant bits, together with the exponent of the lowest
+// bit of that quotient.
+pub(crate) fn div_shift_and_low_exponent(
+    x_exponent: i64,
+    x_significand: &Natural,
+    y_exponent: i64,
+    y_significand: &Natural,
+    prec: u64,
+) -> (u64, i64) {
+    let x_bits = x_significand.significant_bits();
+    let y_bits = y_significand.significant_bits();
+    let shift = (prec + 2 + y_bits).saturating_sub(x_bits);
+    (
+        shift,
+        x_exponent - i64::exact_from(x_bits) - y_exponent + i64::exact_from(y_bits)
+            - i64::exact_from(shift),
+    )
+}
+
+// Divides the shifted significand of a dividend by the significand of a divisor and rounds the
+// quotient, whose lowest bit has exponent `low`. If the division is inexact, a 1 bit is appended to
+// the quotient; since the quotient has at least `prec + 2` significant bits, this sticky bit is
+// enough to round correctly and to determine the returned `Ordering`.
+pub(crate) fn div_significands_prec_round(
+    sign: bool,
+    shifted_x_significand: Natural,
+    y_significand: &Natural,
+    low: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let (quotient, remainder) = shifted_x_significand.div_mod(y_significand);
+    if remainder == 0u32 {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, quotient),
+            low,
+            prec,
+            rm,
+        )
+    } else {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, (quotient << 1u32) | Natural::ONE),
+            low - 1,
+            prec,
+            rm,
+        )
+    }
+}
+
+fn div_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let (shift, low) = div_shift_and_low_exponent(
+                *x_exponent,
+                x_significand,
+                *y_exponent,
+                y_significand,
+                prec,
+            );
+            div_significands_prec_round(
+                x_sign == y_sign,
+                x_significand << shift,
+                y_significand,
+                low,
+                prec,
+                rm,
+            )
+        }
+        _ => (div_special(x, y).unwrap(), Ordering::Equal),
+    }
+}
+
+fn div_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let (shift, low) = div_shift_and_low_exponent(
+                x_exponent,
+                &x_significand,
+                *y_exponent,
+                y_significand,
+                prec,
+            );
+            div_significands_prec_round(
+                x_sign == *y_sign,
+                x_significand << shift,
+                y_significand,
+                low,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => (div_special(&x, y).unwrap(), Ordering::Equal),
+    }
+}
+
+fn div_prec_round_ref_val_helper(
+    x: &Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let (shift, low) = div_shift_and_low_exponent(
+                *x_exponent,
+                x_significand,
+                y_exponent,
+                &y_significand,
+                prec,
+            );
+            div_significands_prec_round(
+                *x_sign == y_sign,
+                x_significand << shift,
+                &y_significand,
+                low,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => (div_special(x, &y).unwrap(), Ordering::Equal),
+    }
+}
+
+fn div_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let (shift, low) = div_shift_and_low_exponent(
+                x_exponent,
+                &x_significand,
+                y_exponent,
+                &y_significand,
+                prec,
+            );
+            div_significands_prec_round(
+                x_sign == y_sign,
+                x_significand << shift,
+                &y_significand,
+                low,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => (div_special(&x, &y).unwrap(), Ordering::Equal),
+    }
+}
+
+impl Float {
+    /// Divides two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::div_round`] instead. If both of these things are true, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        div_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the second by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_val_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by reference and the second by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_ref_val`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_ref_val_helper(self, other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p,m)=f(\pm0.0,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.155725");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).div_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        div_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded quotient is less than, equal to, or greater than the exact quotient. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by value and the second by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// The first [`Float`] is taken by reference and the second by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides two [`Float`]s, rounding the result to the nearest value of the specified precision.
+    /// Both [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded quotient is less than, equal to, or greater than the exact quotient. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,p)=f(\pm0.0,\pm0.0,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,p)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,p)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,p)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,p)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,p)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,p)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,p)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,p)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,p)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,p)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,p)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn div_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.div_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded quotient is less than, equal to, or greater than the exact quotient. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round(other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by value and the second by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by reference and the second by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Divides two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded quotient is less than, equal to, or greater than the exact quotient. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty,m)=f(\pm0.0,\pm0.0,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x,m)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x,m)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0,m)=\infty$ if $x>0.0$
+    /// - $f(x,0.0,m)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0,m)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=0.0$ if $x>0.0$
+    /// - $f(0.0,x,m)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x,m)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x,m)=0.0$ if $x<0.0$
+    /// - $f(x,\infty,m)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty,m)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty,m)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).div_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn div_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::div_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::div_round_assign`] instead. If both of these things are true,
+    /// consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.155725");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    pub fn div_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (quotient, o) = div_prec_round_val_val_helper(take(self), other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::div_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::div_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::div_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.155725");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    pub fn div_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (quotient, o) = div_prec_round_val_ref_helper(take(self), other, prec, rm);
+        *self = quotient;
+        o
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by value. An [`Ordering`]
+    /// is returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::div_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign(Float::from(E), 20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.div_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded quotient is less than, equal to, or
+    /// greater than the exact quotient. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the quotient is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::div_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.div_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_prec_assign_ref(&Float::from(E), 20),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727");
+    /// ```
+    #[inline]
+    pub fn div_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.div_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::div_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::div_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `/=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// ```
+    #[inline]
+    pub fn div_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign(other, prec, rm)
+    }
+
+    /// Divides a [`Float`] by a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x/y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x/y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::div_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::div_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `/=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "1.155727349790922");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.div_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "1.1557273497909217");
+    /// ```
+    #[inline]
+    pub fn div_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Div<Float> for Float {
+    type Output = Float;
+
+    /// Divides two [`Float`]s. Both [`Float`]s are taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_round`].
+    /// If you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(-1.5) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(1.5) / Float::INFINITY, Float::ZERO);
+    /// assert!((Float::ZERO / Float::NEGATIVE_ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) / Float::from(2.5), 0.6);
+    /// assert_eq!(Float::from(1.5) / Float::from(-2.5), -0.6);
+    /// assert_eq!(Float::from(-1.5) / Float::from(2.5), -0.6);
+    /// assert_eq!(Float::from(-1.5) / Float::from(-2.5), 0.6);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Div<&'a Float> for Float {
+    type Output = Float;
+
+    /// Divides two [`Float`]s. The first [`Float`] is taken by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_round`].
+    /// If you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(Float::from(-1.5) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(Float::from(1.5) / &Float::INFINITY, Float::ZERO);
+    /// assert!((Float::ZERO / &Float::NEGATIVE_ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) / &Float::from(2.5), 0.6);
+    /// assert_eq!(Float::from(1.5) / &Float::from(-2.5), -0.6);
+    /// assert_eq!(Float::from(-1.5) / &Float::from(2.5), -0.6);
+    /// assert_eq!(Float::from(-1.5) / &Float::from(-2.5), 0.6);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Div<Float> for &'a Float {
+    type Output = Float;
+
+    /// Divides two [`Float`]s. The first [`Float`] is taken by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_round`].
+    /// If you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) / Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) / Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) / Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(-1.5) / Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(&Float::from(1.5) / Float::INFINITY, Float::ZERO);
+    /// assert!((&Float::ZERO / Float::NEGATIVE_ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) / Float::from(2.5), 0.6);
+    /// assert_eq!(&Float::from(1.5) / Float::from(-2.5), -0.6);
+    /// assert_eq!(&Float::from(-1.5) / Float::from(2.5), -0.6);
+    /// assert_eq!(&Float::from(-1.5) / Float::from(-2.5), 0.6);
+    /// ```
+    #[inline]
+    fn div(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Div<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Divides two [`Float`]s. Both [`Float`]s are taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm\infty)=f(\pm0.0,\pm0.0)=\text{NaN}$
+    /// - $f(\infty,x)=\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(\infty,x)=-\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=-\infty$ if $x\geq 0.0$ and $x$ is finite
+    /// - $f(-\infty,x)=\infty$ if $x\leq -0.0$ and $x$ is finite
+    /// - $f(x,0.0)=\infty$ if $x>0.0$
+    /// - $f(x,0.0)=-\infty$ if $x<0.0$
+    /// - $f(x,-0.0)=-\infty$ if $x>0.0$
+    /// - $f(x,-0.0)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=0.0$ if $x>0.0$
+    /// - $f(0.0,x)=-0.0$ if $x<0.0$
+    /// - $f(-0.0,x)=-0.0$ if $x>0.0$
+    /// - $f(-0.0,x)=0.0$ if $x<0.0$
+    /// - $f(x,\infty)=0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,\infty)=-0.0$ if $x<0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=-0.0$ if $x>0.0$ and $x$ is finite
+    /// - $f(x,-\infty)=0.0$ if $x<0.0$ and $x$ is finite
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::div_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::div_round`].
+    /// If you want both of these things, consider using [`Float::div_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) / &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) / &Float::ZERO, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) / &Float::NEGATIVE_ZERO,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert_eq!(&Float::from(-1.5) / &Float::ZERO, Float::NEGATIVE_INFINITY);
+    /// assert_eq!(&Float::from(1.5) / &Float::INFINITY, Float::ZERO);
+    /// assert!((&Float::ZERO / &Float::NEGATIVE_ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) / &Float::from(2.5), 0.6);
+    /// assert_eq!(&Float::from(1.5) / &Float::from(-2.5), -0.6);
+    /// assert_eq!(&Float::from(-1.5) / &Float::from(2.5), -0.6);
+    /// assert_eq!(&Float::from(-1.5) / &Float::from(-2.5), 0.6);
+    /// ```
+    #[inline]
+    fn div(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl DivAssign<Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place. The [`Float`] on the right-hand side is taken
+    /// by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_round_assign`]. If you want both of these things, consider using
+    /// [`Float::div_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::INFINITY;
+    /// assert_eq!(x, Float::ZERO);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::from(2.5);
+    /// assert_eq!(x, 0.6);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= Float::from(-2.5);
+    /// assert_eq!(x, -0.6);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> DivAssign<&'a Float> for Float {
+    /// Divides a [`Float`] by a [`Float`] in place. The [`Float`] on the right-hand side is taken
+    /// by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// quotient is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x/y+\varepsilon.
+    /// $$
+    /// - If $x/y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x/y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x/y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `/` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::div_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::div_round_assign`]. If you want both of these things, consider using
+    /// [`Float::div_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::ZERO;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::NEGATIVE_ZERO;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::INFINITY;
+    /// assert_eq!(x, Float::ZERO);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::from(2.5);
+    /// assert_eq!(x, 0.6);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x /= &Float::from(-2.5);
+    /// assert_eq!(x, -0.6);
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.div_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
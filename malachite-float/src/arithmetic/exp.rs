@@ -0,0 +1,1048 @@
+use crate::constants::ln_2::ln_2_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{
+    DivRound, Exp, ExpAssign, FloorSqrt, PowerOf2, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the exponential of `x` if `x` is NaN, infinite, or zero. The exponential of zero is 1,
+// which is returned with precision `prec`.
+pub(crate) fn exp_special(x: &Float, prec: u64) -> Float {
+    match x {
+        float_nan!() => float_nan!(),
+        float_infinity!() => float_infinity!(),
+        Float(Infinity { sign: false }) => float_zero!(),
+        Float(Zero { .. }) => Float::one_prec(prec),
+        Float(Finite { .. }) => unreachable!(),
+    }
+}
+
+// Rounds a value that lies strictly between the finite, nonzero `Float` with the given sign,
+// exponent, and significand and its neighbor in the direction given by `above`, at a distance from
+// the `Float` of at most $2^{e-\max(b,p)-3}$, where $e$ is the exponent, $b$ the number of bits of
+// the significand, and $p$ the target precision.
+//
+// No `Float` of precision `prec`, and no midpoint between two such `Float`s, lies in that range, so
+// the value rounds like the `Float` with a 1 bit appended below it, on the appropriate side.
+pub(crate) fn round_near_x(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    above: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    let shift = max(bits, prec) + 3 - bits;
+    let shifted = significand << shift;
+    let nudged = if above == sign {
+        shifted + Natural::ONE
+    } else {
+        shifted - Natural::ONE
+    };
+    Float::from_integer_times_power_of_2_prec_round(
+        Integer::from_sign_and_abs(sign, nudged),
+        exponent - i64::exact_from(bits + shift),
+        prec,
+        rm,
+    )
+}
+
+// Rounds a quantity known to lie within `error * 2^exponent` of `value * 2^exponent`, provided that
+// both ends of that interval round to the same `Float` and that it lies outside the interval;
+// otherwise, returns `None`.
+pub(crate) fn round_if_determined(
+    value: Integer,
+    error: Natural,
+    exponent: i64,
+    prec: u64,
+    rm: RoundingMode,
+) -> Option<(Float, Ordering)> {
+    let error = Integer::from(error);
+    let lo = &value - &error;
+    let hi = value + error;
+    if lo == 0u32 || hi == 0u32 || (lo > 0u32) != (hi > 0u32) {
+        return None;
+    }
+    let (lo_rounded, lo_o) =
+        Float::from_integer_times_power_of_2_prec_round(lo, exponent, prec, rm);
+    let (hi_rounded, hi_o) =
+        Float::from_integer_times_power_of_2_prec_round(hi, exponent, prec, rm);
+    if lo_rounded != hi_rounded {
+        None
+    } else if lo_o != Ordering::Greater {
+        Some((lo_rounded, Ordering::Less))
+    } else if hi_o != Ordering::Less {
+        Some((hi_rounded, Ordering::Greater))
+    } else {
+        None
+    }
+}
+
+// Rounds a quantity that is not exactly representable, and is not a midpoint between two `Float`s
+// of precision `prec`, using successively better approximations.
+//
+// Given a working precision, `approximate` returns `(value, error, exponent)`, meaning that the
+// quantity lies within `error * 2^exponent` of `value * 2^exponent`. Both ends of that interval
+// are rounded; once they round to the same `Float` and it lies outside the interval, that `Float`
+// is the correctly rounded quantity. Otherwise the working precision is increased.
+pub(crate) fn round_approximation<F: FnMut(u64) -> (Integer, Natural, i64)>(
+    prec: u64,
+    rm: RoundingMode,
+    mut approximate: F,
+) -> (Float, Ordering) {
+    assert_ne!(rm, RoundingMode::Exact, "Inexact result");
+    let mut working_prec = prec + prec.significant_bits() + 10;
+    loop {
+        let (value, error, exponent) = approximate(working_prec);
+        if let Some(result) = round_if_determined(value, error, exponent, prec, rm) {
+            return result;
+        }
+        working_prec += working_prec >> 1;
+    }
+}
+
+// Converts `significand * 2^low` (negated if `sign` is `false`) to a fixed-point value with `scale`
+// fractional bits. The result is exact unless the lowest bits have to be truncated, in which case
+// the error is less than 1.
+pub(crate) fn fixed_from_significand(
+    sign: bool,
+    significand: &Natural,
+    low: i64,
+    scale: u64,
+) -> (Integer, Natural) {
+    let shift = low + i64::exact_from(scale);
+    if shift >= 0 {
+        (
+            Integer::from_sign_and_abs(sign, significand << shift),
+            Natural::ZERO,
+        )
+    } else {
+        (
+            Integer::from_sign_and_abs(sign, significand >> -shift),
+            Natural::ONE,
+        )
+    }
+}
+
+// Multiplies an exact value $x2^\ell$, given by `x` and `x_low` ($\ell$), by an approximation
+// `y`, with `y_scale` fractional bits and an error of at most `y_error`. The product has
+// `out_scale` fractional bits; its error is returned along with it.
+pub(crate) fn mul_fixed(
+    x: &Integer,
+    x_low: i64,
+    y: &Natural,
+    y_error: &Natural,
+    y_scale: u64,
+    out_scale: u64,
+) -> (Integer, Natural) {
+    let product = x * Integer::from(y);
+    let error = x.unsigned_abs_ref() * y_error;
+    let shift = i64::exact_from(y_scale) - i64::exact_from(out_scale) - x_low;
+    if shift <= 0 {
+        (product << -shift, error << -shift)
+    } else {
+        (product >> shift, (error >> shift) + Natural::TWO)
+    }
+}
+
+// Approximates $e^r$, where $|r| < 1.1$ is given by the fixed-point value `r` with `scale`
+// fractional bits and an error of at most `r_error`. Returns `(s, error, s_scale)`, where `s` has
+// `s_scale` fractional bits.
+//
+// The argument is divided by $2^k$, the Taylor series is summed, and the sum is squared $k$ times.
+// Each computed term of the series is within 5 of the exact term for the exact argument, and the
+// tail of the series left after the first zero term is less than 10; a change of at most `r_error`
+// in the argument changes the sum by at most twice that. Squaring an approximation `s` with error
+// `e` produces an error of at most `(2se + e^2) / 2^s_scale`, plus 1 for truncation.
+pub(crate) fn exp_small_fixed(r: Integer, r_error: Natural, scale: u64) -> (Natural, Natural, u64) {
+    let halvings = max(1, scale.floor_sqrt() >> 1);
+    let guard = scale.significant_bits() + 6;
+    let p = scale + halvings + guard;
+    // r / 2^halvings with p fractional bits has the same digits as r, shifted by guard bits
+    let r = r << guard;
+    let mut term = Integer::power_of_2(p);
+    let mut sum = term.clone();
+    let mut i = 0u64;
+    loop {
+        i += 1;
+        term = ((term * &r) >> p) / Integer::from(i);
+        if term == 0u32 {
+            break;
+        }
+        sum += &term;
+    }
+    let mut s = sum.unsigned_abs();
+    let mut error = Natural::from(5 * i + 10) + (r_error << (guard + 1));
+    for _ in 0..halvings {
+        error = ((((&s * &error) << 1u32) + (&error).square()) >> p) + Natural::TWO;
+        s = s.square() >> p;
+    }
+    (s, error, p)
+}
+
+// Approximates $e^t$, where $t$ is given by the fixed-point value `t` with `t_scale` fractional
+// bits and an error of at most `t_error`. Returns `(s, error, exponent)`, meaning that $e^t$ is
+// within `error * 2^exponent` of `s * 2^exponent`. `s` has roughly `out_scale` significant bits.
+//
+// $t$ is reduced to $r = t - k\ln 2$, with $|r| \leq (\ln 2)/2$, so that $e^t = 2^ke^r$. The
+// error in $k\ln 2$ is at most $|k|$ times the error in $\ln 2$; for this to be small, `t_scale`
+// must exceed `out_scale` by a few more bits than $k$ has.
+pub(crate) fn exp_fixed(
+    t: Integer,
+    t_error: Natural,
+    t_scale: u64,
+    out_scale: u64,
+) -> (Natural, Natural, i64) {
+    let (ln_2, ln_2_error) = ln_2_fixed(t_scale);
+    let k = (&t)
+        .div_round(Integer::from(&ln_2), RoundingMode::Nearest)
+        .0;
+    let r = t - &k * Integer::from(ln_2);
+    let r_error = t_error + k.unsigned_abs_ref() * ln_2_error;
+    let shift = t_scale - out_scale;
+    let (s, error, s_scale) =
+        exp_small_fixed(r >> shift, (r_error >> shift) + Natural::TWO, out_scale);
+    (s, error, i64::exact_from(&k) - i64::exact_from(s_scale))
+}
+
+// Computes the exponential of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $|x| < 2^{-p-3}$, $e^x$ is so close to 1 that it rounds like a value just above or below 1.
+// Otherwise successively better approximations are computed until one of them can be rounded
+// correctly; since $e^x$ is transcendental for any nonzero rational $x$, this always happens
+// eventually.
+fn exp_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent < -i64::exact_from(prec) - 2 {
+        return round_near_x(true, 1, &Natural::ONE, sign, prec, rm);
+    }
+    assert!(
+        exponent <= 62,
+        "The exponential of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    let integer_bits = u64::exact_from(max(exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let t_scale = working_prec + integer_bits + 8;
+        let (t, t_error) = fixed_from_significand(sign, significand, low, t_scale);
+        let (s, error, exponent) = exp_fixed(t, t_error, t_scale, working_prec);
+        (Integer::from(s), error, exponent)
+    })
+}
+
+impl Float {
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded exponential is less than, equal to, or greater than
+    /// the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round`] instead. If both of these things are true, consider using [`Exp::exp`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with precision
+    /// `prec`, if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "24.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (exp_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded exponential is less than, equal to, or greater
+    /// than the exact exponential. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_ref`] instead. If both of these things are true, consider using
+    /// [`Exp::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with precision
+    /// `prec`, if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "24.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (exp_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Exp::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec(5);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec(20);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Exp::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_ref(5);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_prec_ref(20);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded exponential is less than, equal to, or greater than the exact exponential.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp::exp`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded exponential is less than, equal to, or greater than the exact
+    /// exponential. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp_round_assign`] instead. If both of these things are true, consider using
+    /// [`ExpAssign::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with precision
+    /// `prec`, if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "24.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    pub fn exp_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (exp, o) = take(self).exp_prec_round(prec, rm);
+        *self = exp;
+        o
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result to the nearest value
+    /// of the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// exponential is less than, equal to, or greater than the exact exponential. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the exponential is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`ExpAssign::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.14069");
+    /// ```
+    #[inline]
+    pub fn exp_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded exponential is
+    /// less than, equal to, or greater than the exact exponential. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`ExpAssign::exp_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the exponential is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "23.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.140692632779267");
+    /// ```
+    #[inline]
+    pub fn exp_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, rm)
+    }
+}
+
+impl Exp for Float {
+    type Output = Float;
+
+    /// Computes the exponential of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::exp_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::exp_round`].
+    /// If you want both of these things, consider using [`Float::exp_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).exp().is_nan());
+    /// assert_eq!((Float::INFINITY).exp(), Float::INFINITY);
+    /// assert_eq!(ComparableFloat((Float::NEGATIVE_INFINITY).exp()), ComparableFloat(Float::ZERO));
+    /// assert_eq!((Float::NEGATIVE_ZERO).exp(), Float::ONE);
+    ///
+    /// assert_eq!((Float::from(1.5)).exp().to_string(), "4.4816890703380645");
+    /// assert_eq!((Float::from(-1.5)).exp().to_string(), "0.22313016014842982");
+    /// ```
+    #[inline]
+    fn exp(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Exp for &'a Float {
+    type Output = Float;
+
+    /// Computes the exponential of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::exp_round_ref`]. If you want both of these things, consider using
+    /// [`Float::exp_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).exp().is_nan());
+    /// assert_eq!((&Float::INFINITY).exp(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_INFINITY).exp()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!((&Float::NEGATIVE_ZERO).exp(), Float::ONE);
+    ///
+    /// assert_eq!((&Float::from(1.5)).exp().to_string(), "4.4816890703380645");
+    /// assert_eq!((&Float::from(-1.5)).exp().to_string(), "0.22313016014842982");
+    /// ```
+    #[inline]
+    fn exp(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl ExpAssign for Float {
+    /// Computes the exponential of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the exponential is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = e^x+\varepsilon.
+    /// $$
+    /// - If $e^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |e^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Exp`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp_round_assign`]. If you want both of these things, consider using
+    /// [`Float::exp_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpAssign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.exp_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.exp_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.exp_assign();
+    /// assert_eq!(x, Float::ONE);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.exp_assign();
+    /// assert_eq!(x.to_string(), "4.4816890703380645");
+    /// ```
+    #[inline]
+    fn exp_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.exp_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,865 @@
+use crate::arithmetic::exp::{
+    exp_fixed, exp_special, mul_fixed, round_approximation, round_near_x,
+};
+use crate::arithmetic::log10::ln_10_fixed;
+use crate::Float;
+use crate::InnerFloat::Finite;
+use malachite_base::num::arithmetic::traits::{Exp10, Exp10Assign, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Computes 10 raised to the power of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $x$ is a nonnegative integer, $10^x$ is an integer; it is computed exactly when it is no
+// larger than $10^{(p+1)/2}$, and otherwise it can't be a midpoint, since it is divisible by
+// $2^x$. For any other $x$, $10^x = e^{x\ln 10}$ is either transcendental or the reciprocal of an
+// integer with an odd factor, so it can always be rounded correctly once it is approximated
+// closely enough.
+fn exp10_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent < -i64::exact_from(prec) - 3 {
+        return round_near_x(true, 1, &Natural::ONE, sign, prec, rm);
+    }
+    assert!(
+        exponent <= 61,
+        "The exponential of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    if sign {
+        let zeros = significand.trailing_zeros().unwrap();
+        if low + i64::exact_from(zeros) >= 0 {
+            let pow = u64::exact_from(&if low >= 0 {
+                significand << low
+            } else {
+                significand >> -low
+            });
+            if pow <= (prec + 1) >> 1 {
+                return Float::from_natural_prec_round(Natural::from(10u32).pow(pow), prec, rm);
+            }
+        }
+    }
+    let integer_bits = u64::exact_from(max(exponent, 0));
+    let x = Integer::from_sign_and_abs_ref(sign, significand);
+    round_approximation(prec, rm, |working_prec| {
+        let t_scale = working_prec + integer_bits + 10;
+        let ln_10_scale = t_scale + integer_bits + 4;
+        let (ln_10, ln_10_error) = ln_10_fixed(ln_10_scale);
+        let (t, t_error) = mul_fixed(&x, low, &ln_10, &ln_10_error, ln_10_scale, t_scale);
+        let (s, error, exponent) = exp_fixed(t, t_error, t_scale, working_prec);
+        (Integer::from(s), error, exponent)
+    })
+}
+
+impl Float {
+    /// Raises 10 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round`] instead. If both of these things are true, consider using
+    /// [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.457");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp10_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (exp_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_ref`] instead. If both of these things are true, consider using
+    /// [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.457");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp10_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (exp_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec(5);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec(20);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp10_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded power is less than, equal to, or greater than the exact power. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round(prec, rm)
+    }
+
+    /// Raises 10 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded power is less than, equal to, or greater than the exact power. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp10_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp10::exp10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp10_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn exp10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_ref(prec, rm)
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp10_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp10_round_assign`] instead. If both of these things are true, consider using
+    /// [`Exp10Assign::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.34e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1385.457");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    pub fn exp10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (exp10, o) = take(self).exp10_prec_round(prec, rm);
+        *self = exp10;
+        o
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded power
+    /// is less than, equal to, or greater than the exact power. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp10_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Exp10Assign::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4e3");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.455");
+    /// ```
+    #[inline]
+    pub fn exp10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp10_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 10 to the power of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $10^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |10^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp10_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::exp10_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Exp10Assign::exp10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1385.4557313670109");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp10_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1385.4557313670107");
+    /// ```
+    #[inline]
+    pub fn exp10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_assign(prec, rm)
+    }
+}
+
+impl Exp10 for Float {
+    type Output = Float;
+
+    /// Raises 10 to the power of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::exp10_round`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).exp10().is_nan());
+    /// assert_eq!((Float::INFINITY).exp10(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_INFINITY).exp10()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!((Float::NEGATIVE_ZERO).exp10(), Float::ONE);
+    ///
+    /// assert_eq!((Float::from(1.5)).exp10().to_string(), "31.622776601683793");
+    /// assert_eq!((Float::from(-1.5)).exp10().to_string(), "0.03162277660168379");
+    /// ```
+    #[inline]
+    fn exp10(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp10_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Exp10 for &'a Float {
+    type Output = Float;
+
+    /// Raises 10 to the power of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp10_round_ref`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).exp10().is_nan());
+    /// assert_eq!((&Float::INFINITY).exp10(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_INFINITY).exp10()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!((&Float::NEGATIVE_ZERO).exp10(), Float::ONE);
+    ///
+    /// assert_eq!((&Float::from(1.5)).exp10().to_string(), "31.622776601683793");
+    /// assert_eq!((&Float::from(-1.5)).exp10().to_string(), "0.03162277660168379");
+    /// ```
+    #[inline]
+    fn exp10(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Exp10Assign for Float {
+    /// Raises 10 to the power of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 10^x+\varepsilon.
+    /// $$
+    /// - If $10^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $10^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |10^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Exp10`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp10_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp10_round_assign`]. If you want both of these things, consider using
+    /// [`Float::exp10_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp10Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.exp10_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.exp10_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.exp10_assign();
+    /// assert_eq!(x, Float::ONE);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.exp10_assign();
+    /// assert_eq!(x.to_string(), "31.622776601683793");
+    /// ```
+    #[inline]
+    fn exp10_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.exp10_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
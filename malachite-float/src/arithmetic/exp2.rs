@@ -0,0 +1,858 @@
+use crate::arithmetic::exp::{
+    exp_small_fixed, exp_special, mul_fixed, round_approximation, round_near_x,
+};
+use crate::constants::ln_2::ln_2_fixed;
+use crate::Float;
+use crate::InnerFloat::Finite;
+use malachite_base::num::arithmetic::traits::{Exp2, Exp2Assign, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Computes 2 raised to the power of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $x$ is an integer, $2^x$ is a power of 2. Otherwise $x$ is split into an integer $k$ and a
+// fraction $f$, with $|f| \leq 1/2$, and $2^x = 2^ke^{f\ln 2}$ is transcendental, so it can always
+// be rounded correctly once it is approximated closely enough.
+fn exp2_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if exponent < -i64::exact_from(prec) - 2 {
+        return round_near_x(true, 1, &Natural::ONE, sign, prec, rm);
+    }
+    assert!(
+        exponent <= 62,
+        "The exponential of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    let x = Integer::from_sign_and_abs_ref(sign, significand);
+    if low >= 0 || i64::exact_from(significand.trailing_zeros().unwrap()) >= -low {
+        let k = if low >= 0 { x << low } else { x >> -low };
+        return (
+            Float::power_of_2_prec(i64::exact_from(&k), prec),
+            Ordering::Equal,
+        );
+    }
+    let fraction_bits = u64::exact_from(-low);
+    let k: Integer = (&x + Integer::power_of_2(fraction_bits - 1)) >> fraction_bits;
+    let f = x - (&k << fraction_bits);
+    let k = i64::exact_from(&k);
+    round_approximation(prec, rm, |working_prec| {
+        let ln_2_scale = working_prec + 4;
+        let (ln_2, ln_2_error) = ln_2_fixed(ln_2_scale);
+        let (r, r_error) = mul_fixed(&f, low, &ln_2, &ln_2_error, ln_2_scale, working_prec);
+        let (s, error, s_scale) = exp_small_fixed(r, r_error, working_prec);
+        (Integer::from(s), error, k - i64::exact_from(s_scale))
+    })
+}
+
+impl Float {
+    /// Raises 2 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded power is less than, equal to, or greater than the
+    /// exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp2_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::exp2_round`] instead. If both of these things are true, consider using
+    /// [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.82497");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp2_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (exp_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Raises 2 to the power of a [`Float`], rounding the result to the specified precision and
+    /// with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded power is less than, equal to, or greater than
+    /// the exact power. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=0.0$
+    /// - $f(\pm0.0,p,m)=1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp2_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp2_round_ref`] instead. If both of these things are true, consider using
+    /// [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.82497");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => exp2_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (exp_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Raises 2 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp2_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec(5);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec(20);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_prec(self, prec: u64) -> (Float, Ordering) {
+        self.exp2_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 2 to the power of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded power is less than, equal to, or greater than the exact
+    /// power. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=0.0$
+    /// - $f(\pm0.0,p)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp2_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_ref(5);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_prec_ref(20);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.exp2_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 2 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded power is less than, equal to, or greater than the exact power. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp2_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.824977827076285");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp2_prec_round(prec, rm)
+    }
+
+    /// Raises 2 to the power of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded power is less than, equal to, or greater than the exact power. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=0.0$
+    /// - $f(\pm0.0,m)=1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp2_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp2::exp2`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.824977827076285");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).exp2_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn exp2_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.exp2_prec_round_ref(prec, rm)
+    }
+
+    /// Raises 2 to the power of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded power is less than, equal to, or greater than the exact power. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp2_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::exp2_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::exp2_round_assign`] instead. If both of these things are true, consider using
+    /// [`Exp2Assign::exp2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.82497");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "8.82498");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// ```
+    pub fn exp2_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (exp2, o) = take(self).exp2_prec_round(prec, rm);
+        *self = exp2;
+        o
+    }
+
+    /// Raises 2 to the power of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded power
+    /// is less than, equal to, or greater than the exact power. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the power is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::exp2_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp2_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Exp2Assign::exp2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "8.82498");
+    /// ```
+    #[inline]
+    pub fn exp2_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.exp2_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Raises 2 to the power of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded power is less
+    /// than, equal to, or greater than the exact power. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $2^x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |2^x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::exp2_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::exp2_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Exp2Assign::exp2_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the power is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.824977827076285");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.exp2_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "8.824977827076287");
+    /// ```
+    #[inline]
+    pub fn exp2_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.exp2_prec_round_assign(prec, rm)
+    }
+}
+
+impl Exp2 for Float {
+    type Output = Float;
+
+    /// Raises 2 to the power of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::exp2_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::exp2_round`].
+    /// If you want both of these things, consider using [`Float::exp2_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).exp2().is_nan());
+    /// assert_eq!((Float::INFINITY).exp2(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_INFINITY).exp2()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!((Float::NEGATIVE_ZERO).exp2(), Float::ONE);
+    ///
+    /// assert_eq!((Float::from(1.5)).exp2().to_string(), "2.8284271247461903");
+    /// assert_eq!((Float::from(-1.5)).exp2().to_string(), "0.35355339059327379");
+    /// ```
+    #[inline]
+    fn exp2(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp2_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Exp2 for &'a Float {
+    type Output = Float;
+
+    /// Raises 2 to the power of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=0.0$
+    /// - $f(\pm0.0)=1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp2_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp2_round_ref`]. If you want both of these things, consider using
+    /// [`Float::exp2_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp2;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).exp2().is_nan());
+    /// assert_eq!((&Float::INFINITY).exp2(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_INFINITY).exp2()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!((&Float::NEGATIVE_ZERO).exp2(), Float::ONE);
+    ///
+    /// assert_eq!((&Float::from(1.5)).exp2().to_string(), "2.8284271247461903");
+    /// assert_eq!((&Float::from(-1.5)).exp2().to_string(), "0.35355339059327379");
+    /// ```
+    #[inline]
+    fn exp2(self) -> Float {
+        let prec = self.significant_bits();
+        self.exp2_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Exp2Assign for Float {
+    /// Raises 2 to the power of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the power is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 2^x+\varepsilon.
+    /// $$
+    /// - If $2^x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $2^x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |2^x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Exp2`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::exp2_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::exp2_round_assign`]. If you want both of these things, consider using
+    /// [`Float::exp2_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Exp2Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.exp2_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.exp2_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.exp2_assign();
+    /// assert_eq!(x, Float::ONE);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.exp2_assign();
+    /// assert_eq!(x.to_string(), "2.8284271247461903");
+    /// ```
+    #[inline]
+    fn exp2_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.exp2_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
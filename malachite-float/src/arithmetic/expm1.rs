@@ -0,0 +1,890 @@
+use crate::arithmetic::exp::{
+    exp_fixed, fixed_from_significand, round_approximation, round_near_x,
+};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Expm1, Expm1Assign, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the exponential of `x`, minus 1, if `x` is NaN, infinite, or zero. The result for
+// negative infinity is -1, which is returned with precision `prec`.
+fn expm1_special(x: &Float, prec: u64) -> Float {
+    match x {
+        float_nan!() => float_nan!(),
+        float_infinity!() => float_infinity!(),
+        Float(Infinity { sign: false }) => Float::negative_one_prec(prec),
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        Float(Finite { .. }) => unreachable!(),
+    }
+}
+
+// Computes the exponential of a finite, nonzero `Float`, minus 1, given its sign, exponent, and
+// significand.
+//
+// If $|x| < 2^{-\max(b,p)-2}$, where $b$ is the number of bits of the significand, then $e^x-1$ is
+// within $x^2$ of $x$, which is close enough for it to round like a value just above $x$. If $x
+// \leq -(p+3)$, then $e^x-1$ rounds like a value just above -1. Otherwise successively better
+// approximations are computed until one of them can be rounded correctly; since $e^x-1$ is
+// transcendental for any nonzero rational $x$, this always happens eventually.
+fn expm1_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent < -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, true, prec, rm);
+    }
+    if !sign && exponent > i64::exact_from((prec + 3).significant_bits()) {
+        return round_near_x(false, 1, &Natural::ONE, true, prec, rm);
+    }
+    assert!(
+        exponent <= 62,
+        "The exponential of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(bits);
+    let integer_bits = u64::exact_from(max(exponent, 0));
+    let fraction_bits = u64::exact_from(max(-exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let out_scale = working_prec + fraction_bits + 4;
+        let t_scale = out_scale + integer_bits + 8;
+        let (t, t_error) = fixed_from_significand(sign, significand, low, t_scale);
+        let (s, error, exponent) = exp_fixed(t, t_error, t_scale, out_scale);
+        if exponent >= 0 {
+            // The subtracted 1 is an integer multiple of 2^exponent, up to an error of 1
+            (Integer::from(s), error + Natural::ONE, exponent)
+        } else {
+            (
+                Integer::from(s) - Integer::power_of_2(u64::exact_from(-exponent)),
+                error,
+                exponent,
+            )
+        }
+    })
+}
+
+impl Float {
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round`] instead. If both of these things are true, consider using
+    /// [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => expm1_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (expm1_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_ref`] instead. If both of these things are true, consider using
+    /// [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "23.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.14072");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => expm1_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (expm1_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec(5);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec(20);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec(self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result to the nearest value
+    /// of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_ref(5);
+    /// assert_eq!(x.to_string(), "22.0");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_prec_ref(20);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn expm1_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.expm1_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded result is less than, equal to, or greater than the exact result.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn expm1_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::expm1_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Expm1::expm1`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).expm1_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn expm1_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded result is less than, equal to, or greater than the exact
+    /// result. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::expm1_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::expm1_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::expm1_round_assign`] instead. If both of these things are true, consider using
+    /// [`Expm1Assign::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with precision `prec`,
+    /// if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "23.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "22.14072");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    pub fn expm1_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (expm1, o) = take(self).expm1_prec_round(prec, rm);
+        *self = expm1;
+        o
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it
+    /// also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::expm1_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Expm1Assign::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.14069");
+    /// ```
+    #[inline]
+    pub fn expm1_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.expm1_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the exponential of a [`Float`], minus 1, in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded result
+    /// is less than, equal to, or greater than the exact result. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $e^x-1$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::expm1_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::expm1_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Expm1Assign::expm1_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the result is not exactly representable with the precision of
+    /// the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "22.140692632779263");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.expm1_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "22.140692632779267");
+    /// ```
+    #[inline]
+    pub fn expm1_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_assign(prec, rm)
+    }
+}
+
+impl Expm1 for Float {
+    type Output = Float;
+
+    /// Computes the exponential of a [`Float`], minus 1. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::expm1_round`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Expm1;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).expm1().is_nan());
+    /// assert_eq!((Float::INFINITY).expm1(), Float::INFINITY);
+    /// assert_eq!((Float::NEGATIVE_INFINITY).expm1(), Float::NEGATIVE_ONE);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).expm1()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).expm1().to_string(), "3.481689070338065");
+    /// assert_eq!((Float::from(-1.5)).expm1().to_string(), "-0.7768698398515702");
+    /// ```
+    #[inline]
+    fn expm1(self) -> Float {
+        let prec = self.significant_bits();
+        self.expm1_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Expm1 for &'a Float {
+    type Output = Float;
+
+    /// Computes the exponential of a [`Float`], minus 1. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::expm1_round_ref`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Expm1;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).expm1().is_nan());
+    /// assert_eq!((&Float::INFINITY).expm1(), Float::INFINITY);
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).expm1(), Float::NEGATIVE_ONE);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).expm1()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).expm1().to_string(), "3.481689070338065");
+    /// assert_eq!((&Float::from(-1.5)).expm1().to_string(), "-0.7768698398515702");
+    /// ```
+    #[inline]
+    fn expm1(self) -> Float {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Expm1Assign for Float {
+    /// Computes the exponential of a [`Float`], minus 1, in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the result is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = e^x-1+\varepsilon.
+    /// $$
+    /// - If $e^x-1$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $e^x-1$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |e^x-1|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Expm1`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::expm1_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::expm1_round_assign`]. If you want both of these things, consider using
+    /// [`Float::expm1_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Expm1Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.expm1_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.expm1_assign();
+    /// assert_eq!(x, Float::NEGATIVE_ONE);
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.expm1_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.expm1_assign();
+    /// assert_eq!(x.to_string(), "3.481689070338065");
+    /// ```
+    #[inline]
+    fn expm1_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.expm1_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
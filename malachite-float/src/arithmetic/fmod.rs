@@ -0,0 +1,1996 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{DivMod, ModMul, ModPow, Parity};
+use malachite_base::num::basic::traits::{Two, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+use std::ops::{Rem, RemAssign};
+
+// Returns the remainder of `x` and `y` if `x` or `y` is NaN, `x` is infinite or zero, or `y` is
+// zero. The remainder is then NaN or zero, so no rounding is necessary. This function is used both
+// for truncated remainders and for IEEE remainders, which have the same special cases.
+pub(crate) const fn rem_special(x: &Float, y: &Float) -> Option<Float> {
+    match (x, y) {
+        (float_nan!(), _)
+        | (_, float_nan!())
+        | (Float(Infinity { .. }), _)
+        | (_, Float(Zero { .. })) => Some(float_nan!()),
+        (Float(Zero { sign }), _) => Some(Float(Zero { sign: *sign })),
+        _ => None,
+    }
+}
+
+// Given the sign, exponent, and significand of a finite, nonzero `Float` $x$ and the exponent and
+// significand of a finite, nonzero `Float` $y$, computes the exact remainder $x-ny$. If `nearest`
+// is false, $n$ is $x/y$ rounded towards zero; otherwise, it is the integer nearest to $x/y$, with
+// ties broken towards the even integer. If $|x|$ is small enough that $n$ must be zero, so that
+// the remainder is $x$ itself, `None` is returned. Otherwise, the sign and absolute value of the
+// remainder are returned, along with the exponent of its lowest bit.
+//
+// Both significands are stripped of their trailing zeros and aligned to the lower of their lowest
+// bits. If the dividend's lowest bit is the higher one and the exponents are far apart, the
+// aligned dividend would be huge, so the remainder is computed using modular exponentiation
+// instead. In that case the aligned divisor is odd, so $x/y$ is never halfway between two integers
+// and the parity of $n$ isn't needed.
+fn rem_finite(
+    x_sign: bool,
+    x_exponent: i64,
+    x_significand: &Natural,
+    y_exponent: i64,
+    y_significand: &Natural,
+    nearest: bool,
+) -> Option<(bool, Natural, i64)> {
+    // If |x| < |y|, the truncated quotient is zero, and if |x| < |y| / 2, the nearest quotient is
+    // zero too.
+    if x_exponent < y_exponent - i64::from(nearest) {
+        return None;
+    }
+    let x_zeros = x_significand.trailing_zeros().unwrap();
+    let y_zeros = y_significand.trailing_zeros().unwrap();
+    let x_odd = x_significand >> x_zeros;
+    let y_odd = y_significand >> y_zeros;
+    let x_low =
+        x_exponent - i64::exact_from(x_significand.significant_bits()) + i64::exact_from(x_zeros);
+    let y_low =
+        y_exponent - i64::exact_from(y_significand.significant_bits()) + i64::exact_from(y_zeros);
+    let (remainder, y_aligned, quotient_is_odd, low) = if x_low >= y_low {
+        let shift = u64::exact_from(x_low - y_low);
+        let remainder = if y_odd == 1u32 {
+            Natural::ZERO
+        } else if shift <= x_odd.significant_bits() + y_odd.significant_bits() {
+            (x_odd << shift).div_mod(&y_odd).1
+        } else {
+            x_odd
+                .div_mod(&y_odd)
+                .1
+                .mod_mul(Natural::TWO.mod_pow(Natural::from(shift), &y_odd), &y_odd)
+        };
+        (remainder, y_odd, false, y_low)
+    } else {
+        let y_aligned = y_odd << u64::exact_from(y_low - x_low);
+        let (quotient, remainder) = x_odd.div_mod(&y_aligned);
+        (remainder, y_aligned, quotient.odd(), x_low)
+    };
+    if nearest && remainder != 0u32 {
+        let twice_remainder = &remainder << 1u32;
+        if twice_remainder > y_aligned || twice_remainder == y_aligned && quotient_is_odd {
+            return Some((!x_sign, y_aligned - remainder, low));
+        }
+    }
+    Some((x_sign, remainder, low))
+}
+
+// Rounds the remainder returned by `rem_finite`. A zero remainder has the sign of the dividend.
+fn rem_round(
+    x_sign: bool,
+    (sign, remainder, low): (bool, Natural, i64),
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    if remainder == 0u32 {
+        (Float(Zero { sign: x_sign }), Ordering::Equal)
+    } else {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, remainder),
+            low,
+            prec,
+            rm,
+        )
+    }
+}
+
+// Computes the remainder of two `Float`s, taking both by value. If `nearest` is false, the quotient
+// is rounded towards zero; otherwise, it is rounded to the nearest integer.
+pub(crate) fn rem_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    nearest: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let remainder = match (&x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => rem_finite(
+            *x_sign,
+            *x_exponent,
+            x_significand,
+            y_exponent,
+            &y_significand,
+            nearest,
+        )
+        .map(|r| rem_round(*x_sign, r, prec, rm)),
+        (Float(Finite { .. }), Float(Infinity { .. })) => None,
+        (_, y) => Some((rem_special(&x, &y).unwrap(), Ordering::Equal)),
+    };
+    remainder.unwrap_or_else(|| {
+        let mut x = x;
+        let o = x.set_prec_round(prec, rm);
+        (x, o)
+    })
+}
+
+// Computes the remainder of two `Float`s, taking the dividend by value. If `nearest` is false, the
+// quotient is rounded towards zero; otherwise, it is rounded to the nearest integer.
+pub(crate) fn rem_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    nearest: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    let remainder = match (&x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => rem_finite(
+            *x_sign,
+            *x_exponent,
+            x_significand,
+            *y_exponent,
+            y_significand,
+            nearest,
+        )
+        .map(|r| rem_round(*x_sign, r, prec, rm)),
+        (Float(Finite { .. }), Float(Infinity { .. })) => None,
+        _ => Some((rem_special(&x, y).unwrap(), Ordering::Equal)),
+    };
+    remainder.unwrap_or_else(|| {
+        let mut x = x;
+        let o = x.set_prec_round(prec, rm);
+        (x, o)
+    })
+}
+
+// Computes the remainder of two `Float`s, taking the dividend by reference and the divisor by
+// value. If `nearest` is false, the quotient is rounded towards zero; otherwise, it is rounded to
+// the nearest integer.
+pub(crate) fn rem_prec_round_ref_val_helper(
+    x: &Float,
+    y: Float,
+    nearest: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            if let Some(r) = rem_finite(
+                *x_sign,
+                *x_exponent,
+                x_significand,
+                y_exponent,
+                &y_significand,
+                nearest,
+            ) {
+                return rem_round(*x_sign, r, prec, rm);
+            }
+        }
+        (Float(Finite { .. }), Float(Infinity { .. })) => {}
+        (_, y) => return (rem_special(x, &y).unwrap(), Ordering::Equal),
+    }
+    let mut x = x.clone();
+    let o = x.set_prec_round(prec, rm);
+    (x, o)
+}
+
+// Computes the remainder of two `Float`s, taking the dividend by reference. If `nearest` is false,
+// the quotient is rounded towards zero; otherwise, it is rounded to the nearest integer.
+pub(crate) fn rem_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    nearest: bool,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            if let Some(r) = rem_finite(
+                *x_sign,
+                *x_exponent,
+                x_significand,
+                *y_exponent,
+                y_significand,
+                nearest,
+            ) {
+                return rem_round(*x_sign, r, prec, rm);
+            }
+        }
+        (Float(Finite { .. }), Float(Infinity { .. })) => {}
+        _ => return (rem_special(x, y).unwrap(), Ordering::Equal),
+    }
+    let mut x = x.clone();
+    let o = x.set_prec_round(prec, rm);
+    (x, o)
+}
+
+impl Float {
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the specified precision and with the specified rounding mode. Both [`Float`]s are taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded remainder is less
+    /// than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::fmod_round`] instead. If both of these things are true, consider using `%`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        rem_prec_round_val_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the specified precision and with the specified rounding mode. The first [`Float`] is taken
+    /// by value and the second by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::fmod_round_val_ref`] instead. If both of these things are true,
+    /// consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_val_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the specified precision and with the specified rounding mode. The first [`Float`] is taken
+    /// by reference and the second by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::fmod_round_ref_val`] instead. If both of these things are true,
+    /// consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_ref_val_helper(self, other, false, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the specified precision and with the specified rounding mode. Both [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded remainder is
+    /// less than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::fmod_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).fmod_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_ref_ref_helper(self, other, false, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the nearest value of the specified precision. Both [`Float`]s are taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.fmod_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the nearest value of the specified precision. The first [`Float`] is taken by value and the
+    /// second by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// remainder is less than, equal to, or greater than the exact remainder. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.fmod_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the nearest value of the specified precision. The first [`Float`] is taken by reference and
+    /// the second by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// remainder is less than, equal to, or greater than the exact remainder. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.fmod_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result to
+    /// the nearest value of the specified precision. Both [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn fmod_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.fmod_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result with
+    /// the specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded remainder is less than, equal to, or greater than
+    /// the exact remainder. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using [`Float::fmod_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `%`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn fmod_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round(other, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result with
+    /// the specified rounding mode. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded remainder is
+    /// less than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::fmod_prec_round_val_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn fmod_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result with
+    /// the specified rounding mode. The first [`Float`] is taken by reference and the second by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded remainder is less
+    /// than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::fmod_prec_round_ref_val`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn fmod_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes the remainder of the truncated division of two [`Float`]s, rounding the result with
+    /// the specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded remainder is less than, equal to, or greater than
+    /// the exact remainder. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::fmod_prec_round_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `%` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).fmod_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn fmod_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by value. An [`Ordering`] is returned, indicating
+    /// whether the rounded remainder is less than, equal to, or greater than the exact remainder.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::fmod_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec_assign`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::fmod_round_assign`] instead. If both of these things are true,
+    /// consider using `%=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    pub fn fmod_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (remainder, o) = rem_prec_round_val_val_helper(take(self), other, false, prec, rm);
+        *self = remainder;
+        o
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result to the specified precision and with the specified rounding mode. The
+    /// [`Float`] on the right-hand side is taken by reference. An [`Ordering`] is returned,
+    /// indicating whether the rounded remainder is less than, equal to, or greater than the exact
+    /// remainder. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::fmod_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::fmod_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::fmod_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `%=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    pub fn fmod_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (remainder, o) = rem_prec_round_val_ref_helper(take(self), other, false, prec, rm);
+        *self = remainder;
+        o
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::fmod_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `%=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.fmod_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.fmod_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    #[inline]
+    pub fn fmod_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.fmod_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result to the nearest value of the specified precision. The [`Float`] on the
+    /// right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::fmod_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `%=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.fmod_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.fmod_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    #[inline]
+    pub fn fmod_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.fmod_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result with the specified rounding mode. The [`Float`] on the right-hand side
+    /// is taken by value. An [`Ordering`] is returned, indicating whether the rounded remainder is
+    /// less than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::fmod_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::fmod_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `%=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// ```
+    #[inline]
+    pub fn fmod_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_assign(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`],
+    /// rounding the result with the specified rounding mode. The [`Float`] on the right-hand side
+    /// is taken by reference. An [`Ordering`] is returned, indicating whether the rounded remainder
+    /// is less than, equal to, or greater than the exact remainder. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::fmod_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::fmod_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `%=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.fmod_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// ```
+    #[inline]
+    pub fn fmod_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Rem<Float> for Float {
+    type Output = Float;
+
+    /// Computes the remainder of the truncated division of two [`Float`]s. Both [`Float`]s are
+    /// taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::fmod_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::fmod_round`].
+    /// If you want both of these things, consider using [`Float::fmod_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) % Float::NAN).is_nan());
+    /// assert!((Float::from(1.5) % Float::ZERO).is_nan());
+    /// assert!((Float::INFINITY % Float::from(1.5)).is_nan());
+    /// assert_eq!(Float::from(1.5) % Float::INFINITY, 1.5);
+    ///
+    /// assert_eq!(Float::from(5.5) % Float::from(2.0), 1.5);
+    /// assert_eq!(Float::from(5.5) % Float::from(-2.0), 1.5);
+    /// assert_eq!(Float::from(-5.5) % Float::from(2.0), -1.5);
+    /// assert_eq!(Float::from(-5.5) % Float::from(-2.0), -1.5);
+    /// ```
+    #[inline]
+    fn rem(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Rem<&'a Float> for Float {
+    type Output = Float;
+
+    /// Computes the remainder of the truncated division of two [`Float`]s. The first [`Float`] is
+    /// taken by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::fmod_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::fmod_round`].
+    /// If you want both of these things, consider using [`Float::fmod_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) % &Float::NAN).is_nan());
+    /// assert!((Float::from(1.5) % &Float::ZERO).is_nan());
+    /// assert!((Float::INFINITY % &Float::from(1.5)).is_nan());
+    /// assert_eq!(Float::from(1.5) % &Float::INFINITY, 1.5);
+    ///
+    /// assert_eq!(Float::from(5.5) % &Float::from(2.0), 1.5);
+    /// assert_eq!(Float::from(5.5) % &Float::from(-2.0), 1.5);
+    /// assert_eq!(Float::from(-5.5) % &Float::from(2.0), -1.5);
+    /// assert_eq!(Float::from(-5.5) % &Float::from(-2.0), -1.5);
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Rem<Float> for &'a Float {
+    type Output = Float;
+
+    /// Computes the remainder of the truncated division of two [`Float`]s. The first [`Float`] is
+    /// taken by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::fmod_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::fmod_round`].
+    /// If you want both of these things, consider using [`Float::fmod_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) % Float::NAN).is_nan());
+    /// assert!((&Float::from(1.5) % Float::ZERO).is_nan());
+    /// assert!((&Float::INFINITY % Float::from(1.5)).is_nan());
+    /// assert_eq!(&Float::from(1.5) % Float::INFINITY, 1.5);
+    ///
+    /// assert_eq!(&Float::from(5.5) % Float::from(2.0), 1.5);
+    /// assert_eq!(&Float::from(5.5) % Float::from(-2.0), 1.5);
+    /// assert_eq!(&Float::from(-5.5) % Float::from(2.0), -1.5);
+    /// assert_eq!(&Float::from(-5.5) % Float::from(-2.0), -1.5);
+    /// ```
+    #[inline]
+    fn rem(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Rem<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Computes the remainder of the truncated division of two [`Float`]s. Both [`Float`]s are
+    /// taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// f(x,y) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,x)=f(x,\pm0.0)=\text{NaN}$
+    /// - $f(0.0,x)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::fmod_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::fmod_round`].
+    /// If you want both of these things, consider using [`Float::fmod_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) % &Float::NAN).is_nan());
+    /// assert!((&Float::from(1.5) % &Float::ZERO).is_nan());
+    /// assert!((&Float::INFINITY % &Float::from(1.5)).is_nan());
+    /// assert_eq!(&Float::from(1.5) % &Float::INFINITY, 1.5);
+    ///
+    /// assert_eq!(&Float::from(5.5) % &Float::from(2.0), 1.5);
+    /// assert_eq!(&Float::from(5.5) % &Float::from(-2.0), 1.5);
+    /// assert_eq!(&Float::from(-5.5) % &Float::from(2.0), -1.5);
+    /// assert_eq!(&Float::from(-5.5) % &Float::from(-2.0), -1.5);
+    /// ```
+    #[inline]
+    fn rem(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl RemAssign<Float> for Float {
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`]. The
+    /// [`Float`] on the right-hand side is taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `%` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::fmod_round_assign`]. If you want both of these things, consider using
+    /// [`Float::fmod_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= Float::INFINITY;
+    /// assert_eq!(x, 1.5);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= Float::from(0.5);
+    /// assert_eq!(x, Float::ZERO);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= Float::from(-1.0);
+    /// assert_eq!(x, 0.5);
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> RemAssign<&'a Float> for Float {
+    /// Replaces a [`Float`] with the remainder of its truncated division by another [`Float`]. The
+    /// [`Float`] on the right-hand side is taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// remainder is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be $x/y$, rounded towards zero to an integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `%` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::fmod_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::fmod_round_assign`]. If you want both of these things, consider using
+    /// [`Float::fmod_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= &Float::INFINITY;
+    /// assert_eq!(x, 1.5);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= &Float::from(0.5);
+    /// assert_eq!(x, Float::ZERO);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x %= &Float::from(-1.0);
+    /// assert_eq!(x, 0.5);
+    /// ```
+    #[inline]
+    fn rem_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.fmod_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,940 @@
+use crate::arithmetic::exp::round_approximation;
+use crate::constants::ln_2::ln_2_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{
+    DivRound, IsPowerOf2, Ln, LnAssign, PowerOf2, Square,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the natural logarithm of `x` if `x` is NaN, infinite, zero, or negative.
+pub(crate) const fn ln_special(x: &Float) -> Float {
+    match x {
+        float_infinity!() => float_infinity!(),
+        Float(Zero { .. }) => float_negative_infinity!(),
+        _ => float_nan!(),
+    }
+}
+
+// Approximates $\ln y$, where $y = n2^\ell > 0$ is given by `n` and `low` ($\ell$). Returns
+// `(value, error, scale)`, where `value` has `scale` fractional bits and roughly `working_prec`
+// significant bits.
+//
+// $y$ is written as $2^e\mu$, with $1/\sqrt{2} \lesssim \mu \lesssim \sqrt{2}$, so that $\ln y =
+// e\ln 2 + 2\operatorname{artanh}((\mu-1)/(\mu+1))$. The argument of $\operatorname{artanh}$ has
+// absolute value at most about 0.172, so each term of its series adds more than 5 bits. When $e =
+// 0$, the result is about $2(\mu-1)$, and the scale is increased accordingly so that small
+// logarithms are computed with full relative precision. The computed $(\mu-1)/(\mu+1)$ is within 1
+// of the exact quotient, each computed term is within 3 of the exact term, and the tail of the
+// series is less than 3.
+pub(crate) fn ln_fixed(n: &Natural, low: i64, working_prec: u64) -> (Integer, Natural, u64) {
+    let bits = n.significant_bits();
+    let mut e = low + i64::exact_from(bits);
+    let mut denominator_bits = bits;
+    // If n / 2^bits < 181/256, which is approximately 1/sqrt(2), double it
+    let top = if bits >= 8 {
+        n >> (bits - 8)
+    } else {
+        n << (8 - bits)
+    };
+    if top < 181u32 {
+        denominator_bits -= 1;
+        e -= 1;
+    }
+    let one = Natural::power_of_2(denominator_bits);
+    let (sign, difference) = if *n >= one {
+        (true, n - &one)
+    } else {
+        (false, &one - n)
+    };
+    let extra = if e == 0 && difference != 0u32 {
+        (denominator_bits + 2).saturating_sub(difference.significant_bits())
+    } else {
+        0
+    };
+    let scale = working_prec + extra + 10;
+    // The series is summed for |z|, so that all truncations are toward zero
+    let z = (difference << scale) / (n + one);
+    let z_squared = (&z).square() >> scale;
+    let mut power = z.clone();
+    let mut sum = z;
+    let mut i = 0u64;
+    loop {
+        i += 1;
+        power = (power * &z_squared) >> scale;
+        if power == 0u32 {
+            break;
+        }
+        sum += &power / Natural::from(2 * i + 1);
+    }
+    let mut value = Integer::from_sign_and_abs(sign, sum << 1u32);
+    let mut error = Natural::from(6 * i + 8);
+    if e != 0 {
+        let e_bits = e.unsigned_abs().significant_bits() + 2;
+        let (ln_2, ln_2_error) = ln_2_fixed(scale + e_bits);
+        value += (Integer::from(ln_2) * Integer::from(e)) >> e_bits;
+        error += ((ln_2_error * Natural::from(e.unsigned_abs())) >> e_bits) + Natural::TWO;
+    }
+    (value, error, scale)
+}
+
+// Divides two fixed-point approximations, `x` with `x_scale` fractional bits and error `x_error`,
+// and `y > y_error` with `y_scale` fractional bits and error `y_error`. The quotient has `x_scale`
+// fractional bits; its error is returned along with it.
+//
+// The quotient of the exact values differs from $x/y$ by at most $(e_xy + |x|e_y)/(y(y-e_y))$.
+pub(crate) fn div_fixed(
+    x: Integer,
+    x_error: &Natural,
+    y: &Natural,
+    y_error: &Natural,
+    y_scale: u64,
+) -> (Integer, Natural) {
+    let error = ((x_error * y + x.unsigned_abs_ref() * y_error) << y_scale)
+        .div_round(y * (y - y_error), RoundingMode::Ceiling)
+        .0
+        + Natural::ONE;
+    ((x << y_scale) / Integer::from(y), error)
+}
+
+// Computes the natural logarithm of a finite, positive `Float`, given its exponent and
+// significand. The logarithm of 1 is exactly 0; the logarithm of any other positive rational number
+// is transcendental, so it can always be rounded correctly once it is approximated closely enough.
+fn ln_finite_prec_round(
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    if exponent == 1 && significand.is_power_of_2() {
+        return (float_zero!(), Ordering::Equal);
+    }
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, scale) = ln_fixed(significand, low, working_prec);
+        (value, error, -i64::exact_from(scale))
+    })
+}
+
+impl Float {
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round`] instead. If both of these things are true, consider using [`Ln::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.144732");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => ln_finite_prec_round(exponent, &significand, prec, rm),
+            x => (ln_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_ref`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_ref`] instead. If both of these things are true, consider using [`Ln::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.19");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.144732");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => ln_finite_prec_round(*exponent, significand, prec, rm),
+            x => (ln_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round`] instead. If you know that your target precision is the precision of
+    /// the input, consider using [`Ln::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec(5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec(20);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Ln::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.12");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round`] instead.
+    /// If you know you'll be using the `Nearest` rounding mode, consider using [`Ln::ln`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using [`Ln::ln`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_round_assign`] instead. If both of these things are true, consider using
+    /// [`LnAssign::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.19");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.144732");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    pub fn ln_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (ln, o) = take(self).ln_prec_round(prec, rm);
+        *self = ln;
+        o
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`LnAssign::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.12");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.14473");
+    /// ```
+    #[inline]
+    pub fn ln_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`LnAssign::ln_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.1447298858493999");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.1447298858494002");
+    /// ```
+    #[inline]
+    pub fn ln_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, rm)
+    }
+}
+
+impl Ln for Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::ln_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::ln_round`]. If
+    /// you want both of these things, consider using [`Float::ln_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).ln().is_nan());
+    /// assert_eq!((Float::INFINITY).ln(), Float::INFINITY);
+    /// assert!((Float::NEGATIVE_INFINITY).ln().is_nan());
+    /// assert_eq!((Float::ZERO).ln(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((Float::ONE).ln()), ComparableFloat(Float::ZERO));
+    ///
+    /// assert_eq!((Float::from(1.5)).ln().to_string(), "0.40546510810816438");
+    /// assert_eq!((Float::from(0.25)).ln().to_string(), "-1.3862943611198906");
+    /// assert!((Float::from(-1.5)).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Ln for &'a Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::ln_round_ref`]. If you want both of these things, consider using
+    /// [`Float::ln_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).ln().is_nan());
+    /// assert_eq!((&Float::INFINITY).ln(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).ln().is_nan());
+    /// assert_eq!((&Float::ZERO).ln(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((&Float::ONE).ln()), ComparableFloat(Float::ZERO));
+    ///
+    /// assert_eq!((&Float::from(1.5)).ln().to_string(), "0.40546510810816438");
+    /// assert_eq!((&Float::from(0.25)).ln().to_string(), "-1.3862943611198906");
+    /// assert!((&Float::from(-1.5)).ln().is_nan());
+    /// ```
+    #[inline]
+    fn ln(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl LnAssign for Float {
+    /// Computes the natural logarithm of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \ln x+\varepsilon.
+    /// $$
+    /// - If $\ln x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\ln x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\ln
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Ln`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_round_assign`]. If you want both of these things, consider using
+    /// [`Float::ln_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::LnAssign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.ln_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.ln_assign();
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::ONE;
+    /// x.ln_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.ln_assign();
+    /// assert_eq!(x.to_string(), "0.40546510810816438");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.ln_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn ln_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.ln_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
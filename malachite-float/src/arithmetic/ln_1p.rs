@@ -0,0 +1,917 @@
+use crate::arithmetic::exp::{round_approximation, round_near_x};
+use crate::arithmetic::ln::ln_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_negative_infinity, Float};
+use malachite_base::num::arithmetic::traits::{IsPowerOf2, Ln1p, Ln1pAssign, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, min, Ordering};
+use std::mem::take;
+
+// Returns the natural logarithm of 1 plus `x` if `x` is NaN, infinite, or zero.
+const fn ln_1p_special(x: &Float) -> Float {
+    match x {
+        float_infinity!() => float_infinity!(),
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the natural logarithm of 1 plus a finite, nonzero `Float`, given its sign, exponent,
+// and significand.
+//
+// If $|x| < 2^{-\max(b,p)-2}$, where $b$ is the number of bits of the significand, then $\ln(1+x)$
+// is within $x^2/2$ of $x$, which is close enough for it to round like a value just below $x$.
+// Otherwise $1+x$ is computed exactly, unless $x$ is so large that 1 is negligible next to it, and
+// its logarithm is approximated; since $\ln(1+x)$ is transcendental for any nonzero rational $x >
+// -1$, it can always be rounded correctly once it is approximated closely enough.
+fn ln_1p_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent < -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, false, prec, rm);
+    }
+    if !sign && exponent >= 1 {
+        return if exponent == 1 && significand.is_power_of_2() {
+            (float_negative_infinity!(), Ordering::Equal)
+        } else {
+            (float_nan!(), Ordering::Equal)
+        };
+    }
+    let low = exponent - i64::exact_from(bits);
+    if low <= 0 {
+        let one = Natural::power_of_2(u64::exact_from(-low));
+        let sum = if sign {
+            one + significand
+        } else {
+            one - significand
+        };
+        return round_approximation(prec, rm, |working_prec| {
+            let (value, error, scale) = ln_fixed(&sum, low, working_prec);
+            (value, error, -i64::exact_from(scale))
+        });
+    }
+    // x is a positive integer. If it is very large, 1 + x is replaced by x + 2^(low - shift),
+    // which changes its logarithm by less than 2^(-bits - shift + 1).
+    round_approximation(prec, rm, |working_prec| {
+        let shift = min(u64::exact_from(low), working_prec + 16);
+        let sum = (significand << shift) + Natural::ONE;
+        let (value, mut error, scale) = ln_fixed(&sum, low - i64::exact_from(shift), working_prec);
+        if shift != u64::exact_from(low) {
+            error += Natural::ONE;
+        }
+        (value, error, -i64::exact_from(scale))
+    })
+}
+
+impl Float {
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round`] instead. If both of these things are true, consider using
+    /// [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.38");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.421082");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => ln_1p_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (ln_1p_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    /// - $f(-1.0,p,m)=-\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_ref`] instead. If both of these things are true, consider using
+    /// [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.38");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.421082");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => ln_1p_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (ln_1p_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec(5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec(20);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec(self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded logarithm is less than, equal to, or greater
+    /// than the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    /// - $f(-1.0,p)=-\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.ln_1p_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round(prec, rm)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    /// - $f(-1.0,m)=-\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::ln_1p_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Ln1p::ln_1p`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).ln_1p_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_1p_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::ln_1p_round_assign`] instead. If both of these things are true, consider using
+    /// [`Ln1pAssign::ln_1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.38");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.421082");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    pub fn ln_1p_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (ln_1p, o) = take(self).ln_1p_prec_round(prec, rm);
+        *self = ln_1p;
+        o
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::ln_1p_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Ln1pAssign::ln_1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.42108");
+    /// ```
+    #[inline]
+    pub fn ln_1p_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.ln_1p_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the natural logarithm of 1 plus a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\ln(1+x)$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::ln_1p_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::ln_1p_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Ln1pAssign::ln_1p_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4210804127942924");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.ln_1p_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4210804127942926");
+    /// ```
+    #[inline]
+    pub fn ln_1p_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_assign(prec, rm)
+    }
+}
+
+impl Ln1p for Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of 1 plus a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::ln_1p_round`]. If you want both of these things, consider using
+    /// [`Float::ln_1p_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1p;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).ln_1p().is_nan());
+    /// assert_eq!((Float::INFINITY).ln_1p(), Float::INFINITY);
+    /// assert!((Float::NEGATIVE_INFINITY).ln_1p().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).ln_1p()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!((Float::NEGATIVE_ONE).ln_1p(), Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!((Float::from(1.5)).ln_1p().to_string(), "0.9162907318741551");
+    /// assert_eq!((Float::from(-0.5)).ln_1p().to_string(), "-0.6931471805599453");
+    /// assert!((Float::from(-1.5)).ln_1p().is_nan());
+    /// ```
+    #[inline]
+    fn ln_1p(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Ln1p for &'a Float {
+    type Output = Float;
+
+    /// Computes the natural logarithm of 1 plus a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    /// - $f(-1.0)=-\infty$
+    /// - $f(x)=\text{NaN}$ if $x<-1.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_1p_round_ref`]. If you want both of these things, consider using
+    /// [`Float::ln_1p_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1p;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).ln_1p().is_nan());
+    /// assert_eq!((&Float::INFINITY).ln_1p(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).ln_1p().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).ln_1p()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!((&Float::NEGATIVE_ONE).ln_1p(), Float::NEGATIVE_INFINITY);
+    ///
+    /// assert_eq!((&Float::from(1.5)).ln_1p().to_string(), "0.9162907318741551");
+    /// assert_eq!((&Float::from(-0.5)).ln_1p().to_string(), "-0.6931471805599453");
+    /// assert!((&Float::from(-1.5)).ln_1p().is_nan());
+    /// ```
+    #[inline]
+    fn ln_1p(self) -> Float {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Ln1pAssign for Float {
+    /// Computes the natural logarithm of 1 plus a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \ln(1+x)+\varepsilon.
+    /// $$
+    /// - If $\ln(1+x)$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $\ln(1+x)$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\ln(1+x)|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Ln1p`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_1p_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::ln_1p_round_assign`]. If you want both of these things, consider using
+    /// [`Float::ln_1p_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Ln1pAssign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.ln_1p_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ONE;
+    /// x.ln_1p_assign();
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.ln_1p_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.ln_1p_assign();
+    /// assert_eq!(x.to_string(), "0.9162907318741551");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.ln_1p_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn ln_1p_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.ln_1p_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
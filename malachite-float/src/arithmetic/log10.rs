@@ -0,0 +1,903 @@
+use crate::arithmetic::exp::round_approximation;
+use crate::arithmetic::ln::{div_fixed, ln_fixed, ln_special};
+use crate::constants::ln_2::ln_2_fixed;
+use crate::Float;
+use crate::InnerFloat::Finite;
+use malachite_base::num::arithmetic::traits::{Log10, Log10Assign, Pow, PowerOf2};
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Approximates $\ln 10$ as a fixed-point value with `scale` fractional bits, returning it along
+// with its error.
+//
+// $\ln 10 = 3\ln 2 + 2\operatorname{artanh}(1/9)$. As in `ln_2_fixed`, each term of the series is
+// within 2 of the exact term, and the tail left after the first zero term is less than 2.
+pub(crate) fn ln_10_fixed(scale: u64) -> (Natural, Natural) {
+    let guard = scale.significant_bits() + 4;
+    let p = scale + guard;
+    let mut power = Natural::power_of_2(p + 1) / Natural::from(9u32);
+    let mut sum = power.clone();
+    let eighty_one = Natural::from(81u32);
+    let mut i = 0u64;
+    loop {
+        i += 1;
+        power /= &eighty_one;
+        if power == 0u32 {
+            break;
+        }
+        sum += &power / Natural::from(2 * i + 1);
+    }
+    let (ln_2, ln_2_error) = ln_2_fixed(p);
+    let three = Natural::from(3u32);
+    (
+        (sum + ln_2 * &three) >> guard,
+        ((Natural::from(2 * i + 2) + ln_2_error * three) >> guard) + Natural::TWO,
+    )
+}
+
+// Computes the base-10 logarithm of a finite, positive `Float`, given its exponent and
+// significand. If the `Float` is $10^k$ for some integer $k \geq 0$, the logarithm is $k$;
+// otherwise it is irrational, and in fact transcendental, so it can always be rounded correctly
+// once it is approximated closely enough.
+fn log10_finite_prec_round(
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let low = exponent - i64::exact_from(significand.significant_bits());
+    let zeros = significand.trailing_zeros().unwrap();
+    let twos = low + i64::exact_from(zeros);
+    if twos >= 0 {
+        let twos = u64::exact_from(twos);
+        let odd = significand >> zeros;
+        // 5^k has between 2k + 1 and 3k + 1 bits
+        let odd_bits = odd.significant_bits();
+        if odd_bits > twos << 1 && odd_bits <= 3 * twos + 1 && odd == Natural::from(5u32).pow(twos)
+        {
+            return Float::from_unsigned_prec_round(twos, prec, rm);
+        }
+    }
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, scale) = ln_fixed(significand, low, working_prec);
+        let ln_10_scale = working_prec + 10;
+        let (ln_10, ln_10_error) = ln_10_fixed(ln_10_scale);
+        let (quotient, error) = div_fixed(value, &error, &ln_10, &ln_10_error, ln_10_scale);
+        (quotient, error, -i64::exact_from(scale))
+    })
+}
+
+impl Float {
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round`] instead. If both of these things are true, consider using
+    /// [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.48");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => log10_finite_prec_round(exponent, &significand, prec, rm),
+            x => (ln_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded logarithm is less than, equal
+    /// to, or greater than the exact logarithm. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=-\infty$
+    /// - $f(1.0,p,m)=0.0$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_ref`] instead. If both of these things are true, consider using
+    /// [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.48");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => log10_finite_prec_round(*exponent, significand, prec, rm),
+            x => (ln_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec(5);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec(20);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec(self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded logarithm is less than, equal to, or greater than
+    /// the exact logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=-\infty$
+    /// - $f(1.0,p)=0.0$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.log10_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round(prec, rm)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded logarithm is less than, equal to, or greater than the exact
+    /// logarithm. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=-\infty$
+    /// - $f(1.0,m)=0.0$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::log10_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Log10::log10`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).log10_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn log10_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.log10_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded logarithm is less than, equal to, or greater than the exact logarithm.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::log10_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::log10_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::log10_round_assign`] instead. If both of these things are true, consider using
+    /// [`Log10Assign::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with precision
+    /// `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.48");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4971495");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    pub fn log10_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (log10, o) = take(self).log10_prec_round(prec, rm);
+        *self = log10;
+        o
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded logarithm is less than, equal to, or greater than the exact logarithm. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the logarithm is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::log10_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Log10Assign::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.4971499");
+    /// ```
+    #[inline]
+    pub fn log10_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.log10_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the base-10 logarithm of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// logarithm is less than, equal to, or greater than the exact logarithm. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\log_{10} x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\log_{10} x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::log10_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::log10_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Log10Assign::log10_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the logarithm is not exactly representable with the precision
+    /// of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4971498726941338");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.log10_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.49714987269413385");
+    /// ```
+    #[inline]
+    pub fn log10_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.log10_prec_round_assign(prec, rm)
+    }
+}
+
+impl Log10 for Float {
+    type Output = Float;
+
+    /// Computes the base-10 logarithm of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::log10_round`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).log10().is_nan());
+    /// assert_eq!((Float::INFINITY).log10(), Float::INFINITY);
+    /// assert!((Float::NEGATIVE_INFINITY).log10().is_nan());
+    /// assert_eq!((Float::ZERO).log10(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((Float::ONE).log10()), ComparableFloat(Float::ZERO));
+    ///
+    /// assert_eq!((Float::from(1.5)).log10().to_string(), "0.17609125905568124");
+    /// assert_eq!((Float::from(100.0)).log10(), 2.0);
+    /// assert!((Float::from(-1.5)).log10().is_nan());
+    /// ```
+    #[inline]
+    fn log10(self) -> Float {
+        let prec = self.significant_bits();
+        self.log10_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Log10 for &'a Float {
+    type Output = Float;
+
+    /// Computes the base-10 logarithm of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=-\infty$
+    /// - $f(1.0)=0.0$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::log10_round_ref`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).log10().is_nan());
+    /// assert_eq!((&Float::INFINITY).log10(), Float::INFINITY);
+    /// assert!((&Float::NEGATIVE_INFINITY).log10().is_nan());
+    /// assert_eq!((&Float::ZERO).log10(), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((&Float::ONE).log10()), ComparableFloat(Float::ZERO));
+    ///
+    /// assert_eq!((&Float::from(1.5)).log10().to_string(), "0.17609125905568124");
+    /// assert_eq!((&Float::from(100.0)).log10(), 2.0);
+    /// assert!((&Float::from(-1.5)).log10().is_nan());
+    /// ```
+    #[inline]
+    fn log10(self) -> Float {
+        let prec = self.significant_bits();
+        self.log10_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl Log10Assign for Float {
+    /// Computes the base-10 logarithm of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the logarithm is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \log_{10} x+\varepsilon.
+    /// $$
+    /// - If $\log_{10} x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\log_{10} x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\log_{10}
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Log10`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::log10_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::log10_round_assign`]. If you want both of these things, consider using
+    /// [`Float::log10_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Log10Assign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.log10_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.log10_assign();
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::ONE;
+    /// x.log10_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.log10_assign();
+    /// assert_eq!(x.to_string(), "0.17609125905568124");
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.log10_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn log10_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.log10_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -1,13 +1,177 @@
 /// Absolute value of [`Float`](super::Float)s.
 pub mod abs;
+/// Implementations of [`Acos`](malachite_base::num::arithmetic::traits::Acos) and
+/// [`AcosAssign`](malachite_base::num::arithmetic::traits::AcosAssign), traits for computing
+/// the arccosine of a number, and of functions for computing the arccosine of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod acos;
+/// Implementations of [`Acosh`](malachite_base::num::arithmetic::traits::Acosh) and
+/// [`AcoshAssign`](malachite_base::num::arithmetic::traits::AcoshAssign), traits for computing
+/// the inverse hyperbolic cosine of a number, and of functions for computing the inverse
+/// hyperbolic cosine of [`Float`](super::Float)s with precision and rounding mode.
+pub mod acosh;
+/// Addition of [`Float`](super::Float)s, and of [`Float`](super::Float)s with precision and
+/// rounding mode.
+pub mod add;
+/// Implementations of [`AddMul`](malachite_base::num::arithmetic::traits::AddMul) and
+/// [`AddMulAssign`](malachite_base::num::arithmetic::traits::AddMulAssign), traits for adding a
+/// number and the product of two other numbers, and of fused multiply-add functions for
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod add_mul;
+/// Implementations of [`Asin`](malachite_base::num::arithmetic::traits::Asin) and
+/// [`AsinAssign`](malachite_base::num::arithmetic::traits::AsinAssign), traits for computing
+/// the arcsine of a number, and of functions for computing the arcsine of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod asin;
+/// Implementations of [`Asinh`](malachite_base::num::arithmetic::traits::Asinh) and
+/// [`AsinhAssign`](malachite_base::num::arithmetic::traits::AsinhAssign), traits for computing
+/// the inverse hyperbolic sine of a number, and of functions for computing the inverse
+/// hyperbolic sine of [`Float`](super::Float)s with precision and rounding mode.
+pub mod asinh;
+/// Implementations of [`Atan`](malachite_base::num::arithmetic::traits::Atan) and
+/// [`AtanAssign`](malachite_base::num::arithmetic::traits::AtanAssign), traits for computing
+/// the arctangent of a number, and of functions for computing the arctangent of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod atan;
+/// Implementations of [`Atan2`](malachite_base::num::arithmetic::traits::Atan2) and
+/// [`Atan2Assign`](malachite_base::num::arithmetic::traits::Atan2Assign), traits for computing
+/// the two-argument arctangent of a pair of numbers, and of functions for computing the
+/// two-argument arctangent of [`Float`](super::Float)s with precision and rounding mode.
+pub mod atan2;
+/// Implementations of [`Atanh`](malachite_base::num::arithmetic::traits::Atanh) and
+/// [`AtanhAssign`](malachite_base::num::arithmetic::traits::AtanhAssign), traits for computing
+/// the inverse hyperbolic tangent of a number, and of functions for computing the inverse
+/// hyperbolic tangent of [`Float`](super::Float)s with precision and rounding mode.
+pub mod atanh;
+/// Implementations of [`Cos`](malachite_base::num::arithmetic::traits::Cos) and
+/// [`CosAssign`](malachite_base::num::arithmetic::traits::CosAssign), traits for computing the
+/// cosine of a number, and of functions for computing the cosine of [`Float`](super::Float)s
+/// with precision and rounding mode.
+pub mod cos;
+/// Implementations of [`Cosh`](malachite_base::num::arithmetic::traits::Cosh) and
+/// [`CoshAssign`](malachite_base::num::arithmetic::traits::CoshAssign), traits for computing
+/// the hyperbolic cosine of a number, and of functions for computing the hyperbolic cosine of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod cosh;
+/// Division of [`Float`](super::Float)s, and of [`Float`](super::Float)s with precision and
+/// rounding mode.
+pub mod div;
+/// Implementations of [`Exp`](malachite_base::num::arithmetic::traits::Exp) and
+/// [`ExpAssign`](malachite_base::num::arithmetic::traits::ExpAssign), traits for computing the
+/// exponential of a number, and of functions for computing the exponential of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod exp;
+/// Implementations of [`Exp10`](malachite_base::num::arithmetic::traits::Exp10) and
+/// [`Exp10Assign`](malachite_base::num::arithmetic::traits::Exp10Assign), traits for raising 10 to
+/// the power of a number, and of functions for raising 10 to the power of [`Float`](super::Float)s
+/// with precision and rounding mode.
+pub mod exp10;
+/// Implementations of [`Exp2`](malachite_base::num::arithmetic::traits::Exp2) and
+/// [`Exp2Assign`](malachite_base::num::arithmetic::traits::Exp2Assign), traits for raising 2 to the
+/// power of a number, and of functions for raising 2 to the power of [`Float`](super::Float)s with
+/// precision and rounding mode.
+pub mod exp2;
+/// Implementations of [`Expm1`](malachite_base::num::arithmetic::traits::Expm1) and
+/// [`Expm1Assign`](malachite_base::num::arithmetic::traits::Expm1Assign), traits for computing the
+/// exponential of a number, minus 1, and of functions for computing the exponential of
+/// [`Float`](super::Float)s, minus 1, with precision and rounding mode.
+pub mod expm1;
+/// Implementations of [`Rem`](std::ops::Rem) and [`RemAssign`](std::ops::RemAssign), for computing
+/// the remainder of the truncated division of [`Float`](super::Float)s, and of functions for
+/// computing this remainder with precision and rounding mode.
+pub mod fmod;
 /// An implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Implementations of [`Ln`](malachite_base::num::arithmetic::traits::Ln) and
+/// [`LnAssign`](malachite_base::num::arithmetic::traits::LnAssign), traits for computing the
+/// natural logarithm of a number, and of functions for computing the natural logarithm of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod ln;
+/// Implementations of [`Ln1p`](malachite_base::num::arithmetic::traits::Ln1p) and
+/// [`Ln1pAssign`](malachite_base::num::arithmetic::traits::Ln1pAssign), traits for computing the
+/// natural logarithm of 1 plus a number, and of functions for computing the natural logarithm of 1
+/// plus [`Float`](super::Float)s with precision and rounding mode.
+pub mod ln_1p;
+/// Implementations of [`Log10`](malachite_base::num::arithmetic::traits::Log10) and
+/// [`Log10Assign`](malachite_base::num::arithmetic::traits::Log10Assign), traits for computing the
+/// base-10 logarithm of a number, and of functions for computing the base-10 logarithm of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod log10;
+/// Implementations of [`Log2`](malachite_base::num::arithmetic::traits::Log2) and
+/// [`Log2Assign`](malachite_base::num::arithmetic::traits::Log2Assign), traits for computing the
+/// base-2 logarithm of a number, and of functions for computing the base-2 logarithm of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod log2;
+/// Multiplication of [`Float`](super::Float)s, and of [`Float`](super::Float)s with precision and
+/// rounding mode.
+pub mod mul;
 /// Negation of [`Float`](super::Float)s.
 pub mod neg;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
+/// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
+/// computing the reciprocal of a number, and of functions for computing the reciprocal of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod reciprocal;
+/// Implementations of
+/// [`ReciprocalSqrt`](malachite_base::num::arithmetic::traits::ReciprocalSqrt) and
+/// [`ReciprocalSqrtAssign`](malachite_base::num::arithmetic::traits::ReciprocalSqrtAssign), traits
+/// for computing the reciprocal of the square root of a number, and of functions for computing the
+/// reciprocal square root of [`Float`](super::Float)s with precision and rounding mode.
+pub mod reciprocal_sqrt;
+/// Functions for computing the IEEE 754 remainder of [`Float`](super::Float)s, with precision and
+/// rounding mode.
+pub mod remainder;
+/// Implementations of [`Root`](malachite_base::num::arithmetic::traits::Root) and
+/// [`RootAssign`](malachite_base::num::arithmetic::traits::RootAssign), traits for computing the
+/// $n$th root of a number, and of functions for computing the $n$th root of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod root;
 /// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
 /// determining the sign of a number.
 pub mod sign;
+/// Implementations of [`Sin`](malachite_base::num::arithmetic::traits::Sin) and
+/// [`SinAssign`](malachite_base::num::arithmetic::traits::SinAssign), traits for computing the
+/// sine of a number, and of functions for computing the sine of [`Float`](super::Float)s with
+/// precision and rounding mode.
+pub mod sin;
+/// An implementation of [`SinCos`](malachite_base::num::arithmetic::traits::SinCos), a trait
+/// for simultaneously computing the sine and cosine of a number, and of functions for
+/// simultaneously computing the sine and cosine of [`Float`](super::Float)s with precision and
+/// rounding mode.
+pub mod sin_cos;
+/// Implementations of [`Sinh`](malachite_base::num::arithmetic::traits::Sinh) and
+/// [`SinhAssign`](malachite_base::num::arithmetic::traits::SinhAssign), traits for computing
+/// the hyperbolic sine of a number, and of functions for computing the hyperbolic sine of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod sinh;
+/// Implementations of [`Sqrt`](malachite_base::num::arithmetic::traits::Sqrt) and
+/// [`SqrtAssign`](malachite_base::num::arithmetic::traits::SqrtAssign), traits for computing the
+/// square root of a number, and of functions for computing the square root of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod sqrt;
+/// Implementations of [`Square`](malachite_base::num::arithmetic::traits::Square) and
+/// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
+/// number, and of functions for squaring [`Float`](super::Float)s with precision and rounding mode.
+pub mod square;
+/// Subtraction of [`Float`](super::Float)s, and of [`Float`](super::Float)s with precision and
+/// rounding mode.
+pub mod sub;
+/// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
+/// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
+/// the product of two numbers from a number, and of fused multiply-subtract functions for
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod sub_mul;
+/// Implementations of [`Tan`](malachite_base::num::arithmetic::traits::Tan) and
+/// [`TanAssign`](malachite_base::num::arithmetic::traits::TanAssign), traits for computing the
+/// tangent of a number, and of functions for computing the tangent of [`Float`](super::Float)s
+/// with precision and rounding mode.
+pub mod tan;
+/// Implementations of [`Tanh`](malachite_base::num::arithmetic::traits::Tanh) and
+/// [`TanhAssign`](malachite_base::num::arithmetic::traits::TanhAssign), traits for computing
+/// the hyperbolic tangent of a number, and of functions for computing the hyperbolic tangent of
+/// [`Float`](super::Float)s with precision and rounding mode.
+pub mod tanh;
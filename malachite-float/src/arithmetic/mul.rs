@@ -0,0 +1,1939 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+use std::ops::{Mul, MulAssign};
+
+// Returns the product of `x` and `y` if either is NaN, infinite, or zero. The product is then
+// also NaN, infinite, or zero, so no rounding is necessary.
+pub(crate) fn mul_special(x: &Float, y: &Float) -> Option<Float> {
+    match (x, y) {
+        (float_nan!(), _)
+        | (_, float_nan!())
+        | (Float(Infinity { .. }), Float(Zero { .. }))
+        | (Float(Zero { .. }), Float(Infinity { .. })) => Some(float_nan!()),
+        (
+            Float(Infinity { sign: x_sign } | Finite { sign: x_sign, .. }),
+            Float(Infinity { sign: y_sign }),
+        )
+        | (Float(Infinity { sign: x_sign }), Float(Finite { sign: y_sign, .. })) => {
+            Some(Float(Infinity {
+                sign: x_sign == y_sign,
+            }))
+        }
+        (
+            Float(Zero { sign: x_sign } | Finite { sign: x_sign, .. }),
+            Float(Zero { sign: y_sign }),
+        )
+        | (Float(Zero { sign: x_sign }), Float(Finite { sign: y_sign, .. })) => Some(Float(Zero {
+            sign: x_sign == y_sign,
+        })),
+        _ => None,
+    }
+}
+
+// Given the exponents and significands of two finite, nonzero `Float`s, returns the exponent of
+// the lowest bit of their product's significand.
+pub(crate) fn mul_low_exponent(
+    x_exponent: i64,
+    x_significand: &Natural,
+    y_exponent: i64,
+    y_significand: &Natural,
+) -> i64 {
+    x_exponent - i64::exact_from(x_significand.significant_bits()) + y_exponent
+        - i64::exact_from(y_significand.significant_bits())
+}
+
+fn mul_prec_round_ref_ref_helper(
+    x: &Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(x_sign == y_sign, x_significand * y_significand),
+            mul_low_exponent(*x_exponent, x_significand, *y_exponent, y_significand),
+            prec,
+            rm,
+        ),
+        _ => (mul_special(x, y).unwrap(), Ordering::Equal),
+    }
+}
+
+fn mul_prec_round_val_ref_helper(
+    x: Float,
+    y: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let low = mul_low_exponent(x_exponent, &x_significand, *y_exponent, y_significand);
+            Float::from_integer_times_power_of_2_prec_round(
+                Integer::from_sign_and_abs(x_sign == *y_sign, x_significand * y_significand),
+                low,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => (mul_special(&x, y).unwrap(), Ordering::Equal),
+    }
+}
+
+fn mul_prec_round_val_val_helper(
+    x: Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    match (x, y) {
+        (
+            Float(Finite {
+                sign: x_sign,
+                exponent: x_exponent,
+                significand: x_significand,
+                ..
+            }),
+            Float(Finite {
+                sign: y_sign,
+                exponent: y_exponent,
+                significand: y_significand,
+                ..
+            }),
+        ) => {
+            let low = mul_low_exponent(x_exponent, &x_significand, y_exponent, &y_significand);
+            Float::from_integer_times_power_of_2_prec_round(
+                Integer::from_sign_and_abs(x_sign == y_sign, x_significand * y_significand),
+                low,
+                prec,
+                rm,
+            )
+        }
+        (x, y) => (mul_special(&x, &y).unwrap(), Ordering::Equal),
+    }
+}
+
+impl Float {
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec`] instead. If you
+    /// know that your target precision is the maximum of the precisions of the two inputs, consider
+    /// using [`Float::mul_round`] instead. If both of these things are true, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round(self, other: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        mul_prec_round_val_val_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the second by reference.
+    /// An [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_val_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_val_ref`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_val_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_val_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. The first [`Float`] is taken by reference and the second by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_ref_val`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_ref_val`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_val(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_val_ref_helper(other, self, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the specified precision and with the
+    /// specified rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p,m)=f(\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p,m)=f(x,\infty,p,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p,m)=f(x,-\infty,p,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p,m)=f(x,0.0,p,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p,m)=f(x,-0.0,p,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p,m)=f(-0.0,-0.0,p,m)=0.0$
+    /// - $f(0.0,-0.0,p,m)=f(-0.0,0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_ref_ref`] instead.
+    /// If you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_ref_ref`] instead. If both of these things are true,
+    /// consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.53975");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).mul_prec_round_ref_ref(&Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        mul_prec_round_ref_ref_helper(self, other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded product is less than, equal to, or greater than the exact product.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round`] instead. If you know that your target precision is the maximum of
+    /// the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_val_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded product is less than, equal
+    /// to, or greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_ref_val`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result to the nearest value of the specified
+    /// precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,p) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,p)=f(\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,p)=f(x,\infty,p)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,p)=f(x,-\infty,p)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,p)=f(x,0.0,p)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,p)=f(x,-0.0,p)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,p)=f(-0.0,-0.0,p)=0.0$
+    /// - $f(0.0,-0.0,p)=f(-0.0,0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_ref_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "8.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.mul_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by value and the second by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_val_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. The first
+    /// [`Float`] is taken by reference and the second by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded product is less than, equal to, or greater than the exact
+    /// product. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_ref_val`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Multiplies two [`Float`]s, rounding the result with the specified rounding mode. Both
+    /// [`Float`]s are taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded product is less than, equal to, or greater than the exact product. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,m) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0,m)=f(\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=\infty$ if $x>0.0$
+    /// - $f(\infty,x,m)=f(x,\infty,m)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x,m)=f(x,-\infty,m)=\infty$ if $x<0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x,m)=f(x,0.0,m)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x,m)=f(x,-0.0,m)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0,m)=f(-0.0,-0.0,m)=0.0$
+    /// - $f(0.0,-0.0,m)=f(-0.0,0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_ref_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).mul_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn mul_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded product is less
+    /// than, equal to, or greater than the exact product. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_assign`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::mul_round_assign`] instead. If both of these things are true,
+    /// consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.53975");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    pub fn mul_prec_round_assign(&mut self, other: Float, prec: u64, rm: RoundingMode) -> Ordering {
+        let (product, o) = mul_prec_round_val_val_helper(take(self), other, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] on the right-hand side is
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded product is
+    /// less than, equal to, or greater than the exact product. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::mul_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::mul_round_assign_ref`] instead. If both of these things are
+    /// true, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact multiplication, or if `prec`
+    /// is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.53975");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    pub fn mul_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (product, o) = mul_prec_round_val_ref_helper(take(self), other, prec, rm);
+        *self = product;
+        o
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] on the right-hand side is taken by value. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.mul_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] on the right-hand side is taken by reference. An
+    /// [`Ordering`] is returned, indicating whether the rounded product is less than, equal to, or
+    /// greater than the exact product. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the product is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_round_assign_ref`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the two inputs, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign_ref(&Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.mul_prec_assign_ref(&Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "8.53973");
+    /// ```
+    #[inline]
+    pub fn mul_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.mul_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by value. An [`Ordering`] is
+    /// returned, indicating whether the rounded product is less than, equal to, or greater than the
+    /// exact product. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::mul_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using `*=`
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign(other, prec, rm)
+    }
+
+    /// Multiplies a [`Float`] by a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. The [`Float`] on the right-hand side is taken by reference. An [`Ordering`]
+    /// is returned, indicating whether the rounded product is less than, equal to, or greater than
+    /// the exact product. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x*y$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x*y|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::mul_prec_round_assign_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using `*=` instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673568");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.mul_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "8.539734222673566");
+    /// ```
+    #[inline]
+    pub fn mul_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign_ref(other, prec, rm)
+    }
+}
+
+impl Mul<Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s. Both [`Float`]s are taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_round`].
+    /// If you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) * Float::from(2.5), 3.75);
+    /// assert_eq!(Float::from(1.5) * Float::from(-2.5), -3.75);
+    /// assert_eq!(Float::from(-1.5) * Float::from(2.5), -3.75);
+    /// assert_eq!(Float::from(-1.5) * Float::from(-2.5), 3.75);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round(other, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Mul<&'a Float> for Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s. The first [`Float`] is taken by value and the second by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_round`].
+    /// If you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((Float::from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(Float::from(1.5) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     Float::from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((Float::INFINITY * &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(Float::from(1.5) * &Float::from(2.5), 3.75);
+    /// assert_eq!(Float::from(1.5) * &Float::from(-2.5), -3.75);
+    /// assert_eq!(Float::from(-1.5) * &Float::from(2.5), -3.75);
+    /// assert_eq!(Float::from(-1.5) * &Float::from(-2.5), 3.75);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> Mul<Float> for &'a Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s. The first [`Float`] is taken by reference and the second by
+    /// value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_round`].
+    /// If you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) * Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) * Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) * Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) * Float::from(2.5), 3.75);
+    /// assert_eq!(&Float::from(1.5) * Float::from(-2.5), -3.75);
+    /// assert_eq!(&Float::from(-1.5) * Float::from(2.5), -3.75);
+    /// assert_eq!(&Float::from(-1.5) * Float::from(-2.5), 3.75);
+    /// ```
+    #[inline]
+    fn mul(self, other: Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> Mul<&'a Float> for &'b Float {
+    type Output = Float;
+
+    /// Multiplies two [`Float`]s. Both [`Float`]s are taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y) = x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x)=f(x,\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty,\pm0.0)=f(\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,x)=f(x,\infty)=\infty$ if $x>0.0$
+    /// - $f(\infty,x)=f(x,\infty)=-\infty$ if $x<0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=-\infty$ if $x>0.0$
+    /// - $f(-\infty,x)=f(x,-\infty)=\infty$ if $x<0.0$
+    /// - $f(0.0,x)=f(x,0.0)=0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(0.0,x)=f(x,0.0)=-0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=-0.0$ if $x$ is finite and $x>0.0$
+    /// - $f(-0.0,x)=f(x,-0.0)=0.0$ if $x$ is finite and $x<0.0$
+    /// - $f(0.0,0.0)=f(-0.0,-0.0)=0.0$
+    /// - $f(0.0,-0.0)=f(-0.0,0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::mul_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::mul_round`].
+    /// If you want both of these things, consider using [`Float::mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert!((&Float::from(1.5) * &Float::NAN).is_nan());
+    /// assert_eq!(&Float::from(1.5) * &Float::INFINITY, Float::INFINITY);
+    /// assert_eq!(
+    ///     &Float::from(1.5) * &Float::NEGATIVE_INFINITY,
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    /// assert!((&Float::INFINITY * &Float::ZERO).is_nan());
+    ///
+    /// assert_eq!(&Float::from(1.5) * &Float::from(2.5), 3.75);
+    /// assert_eq!(&Float::from(1.5) * &Float::from(-2.5), -3.75);
+    /// assert_eq!(&Float::from(-1.5) * &Float::from(2.5), -3.75);
+    /// assert_eq!(&Float::from(-1.5) * &Float::from(-2.5), 3.75);
+    /// ```
+    #[inline]
+    fn mul(self, other: &'a Float) -> Float {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl MulAssign<Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place. The [`Float`] on the right-hand side is
+    /// taken by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_round_assign`]. If you want both of these things, consider using
+    /// [`Float::mul_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::from(2.5);
+    /// assert_eq!(x, 3.75);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= Float::from(-2.5);
+    /// assert_eq!(x, -3.75);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign(other, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> MulAssign<&'a Float> for Float {
+    /// Multiplies a [`Float`] by a [`Float`] in place. The [`Float`] on the right-hand side is
+    /// taken by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// product is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets x*y+\varepsilon.
+    /// $$
+    /// - If $x*y$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x*y$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x*y|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the `*` documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::mul_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::mul_round_assign`]. If you want both of these things, consider using
+    /// [`Float::mul_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::NAN;
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::INFINITY;
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::NEGATIVE_INFINITY;
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::from(2.5);
+    /// assert_eq!(x, 3.75);
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x *= &Float::from(-2.5);
+    /// assert_eq!(x, -3.75);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &'a Float) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.mul_prec_round_assign_ref(other, prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,892 @@
+use crate::arithmetic::div::{div_shift_and_low_exponent, div_significands_prec_round};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Reciprocal, ReciprocalAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the reciprocal of `x` if `x` is NaN, infinite, or zero.
+fn reciprocal_special(x: &Float) -> Float {
+    match x {
+        float_nan!() => float_nan!(),
+        Float(Infinity { sign }) => Float(Zero { sign: *sign }),
+        Float(Zero { sign }) => Float(Infinity { sign: *sign }),
+        Float(Finite { .. }) => unreachable!(),
+    }
+}
+
+// Computes the reciprocal of a finite, nonzero `Float`, given its sign, exponent, and significand,
+// by dividing 1 by it.
+fn reciprocal_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let (shift, low) = div_shift_and_low_exponent(1, &Natural::ONE, exponent, significand, prec);
+    div_significands_prec_round(sign, Natural::ONE << shift, significand, low, prec, rm)
+}
+
+impl Float {
+    /// Takes the reciprocal of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal is less than, equal to, or greater than
+    /// the exact reciprocal. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=-0.0$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::reciprocal_round`] instead. If both of these things are true, consider using
+    /// [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.33");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.3183103");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => reciprocal_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (reciprocal_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Takes the reciprocal of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal is less than, equal to, or greater than
+    /// the exact reciprocal. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=-0.0$
+    /// - $f(0.0,p,m)=\infty$
+    /// - $f(-0.0,p,m)=-\infty$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_prec_ref`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_round_ref`] instead. If both of these things are true, consider
+    /// using [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.33");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.3183103");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => reciprocal_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (reciprocal_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Takes the reciprocal of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded reciprocal is less than, equal to, or greater than the exact
+    /// reciprocal. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=-0.0$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=-\infty$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec(5);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec(20);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_prec(self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded reciprocal is less than, equal to, or greater than the exact
+    /// reciprocal. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=-0.0$
+    /// - $f(0.0,p)=\infty$
+    /// - $f(-0.0,p)=-\infty$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.31");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded reciprocal is less than, equal to, or greater than the exact reciprocal. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=-0.0$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=-\infty$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::reciprocal_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.31830988618379064");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round(prec, rm)
+    }
+
+    /// Takes the reciprocal of a [`Float`], rounding the result with the specified rounding mode.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded reciprocal is less than, equal to, or greater than the exact reciprocal.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=-0.0$
+    /// - $f(0.0,m)=\infty$
+    /// - $f(-0.0,m)=-\infty$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`Reciprocal::reciprocal`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.31830988618379064");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the reciprocal of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded reciprocal is less than, equal to, or greater than the exact reciprocal. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_round_assign`] instead. If both of these things are true, consider
+    /// using [`ReciprocalAssign::reciprocal_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact division, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.31");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.33");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.31");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.3183098");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.3183103");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// ```
+    pub fn reciprocal_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (reciprocal, o) = take(self).reciprocal_prec_round(prec, rm);
+        *self = reciprocal;
+        o
+    }
+
+    /// Takes the reciprocal of a [`Float`] in place, rounding the result to the nearest value of
+    /// the specified precision. An [`Ordering`] is returned, indicating whether the rounded
+    /// reciprocal is less than, equal to, or greater than the exact reciprocal. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the reciprocal is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec_round_assign`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`ReciprocalAssign::reciprocal_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.31");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.3183098");
+    /// ```
+    #[inline]
+    pub fn reciprocal_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.reciprocal_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded reciprocal is
+    /// less than, equal to, or greater than the exact reciprocal. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::reciprocal_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`ReciprocalAssign::reciprocal_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_round_assign(RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.31830988618379064");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.reciprocal_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.31830988618379069");
+    /// ```
+    #[inline]
+    pub fn reciprocal_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round_assign(prec, rm)
+    }
+}
+
+impl Reciprocal for Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=-0.0$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec`] instead. If you want to specify the output precision, consider
+    /// using [`Float::reciprocal_round`]. If you want both of these things, consider using
+    /// [`Float::reciprocal_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).reciprocal().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((Float::INFINITY).reciprocal()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_INFINITY).reciprocal()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!((Float::ZERO).reciprocal(), Float::INFINITY);
+    /// assert_eq!(
+    ///     (Float::NEGATIVE_ZERO).reciprocal(),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    ///
+    /// assert_eq!(
+    ///     (Float::from(1.5)).reciprocal().to_string(),
+    ///     "0.6666666666666666"
+    /// );
+    /// assert_eq!(
+    ///     (Float::from(-1.5)).reciprocal().to_string(),
+    ///     "-0.6666666666666666"
+    /// );
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Reciprocal for &'a Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=-0.0$
+    /// - $f(0.0)=\infty$
+    /// - $f(-0.0)=-\infty$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_round_ref`]. If you want both of these things, consider
+    /// using [`Float::reciprocal_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).reciprocal().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::INFINITY).reciprocal()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_INFINITY).reciprocal()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    /// assert_eq!((&Float::ZERO).reciprocal(), Float::INFINITY);
+    /// assert_eq!(
+    ///     (&Float::NEGATIVE_ZERO).reciprocal(),
+    ///     Float::NEGATIVE_INFINITY
+    /// );
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(1.5)).reciprocal().to_string(),
+    ///     "0.6666666666666666"
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(-1.5)).reciprocal().to_string(),
+    ///     "-0.6666666666666666"
+    /// );
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round_ref(prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl ReciprocalAssign for Float {
+    /// Takes the reciprocal of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = 1/x+\varepsilon.
+    /// $$
+    /// - If $1/x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $1/x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |1/x|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Reciprocal`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_prec_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_round_assign`]. If you want both of these things,
+    /// consider using [`Float::reciprocal_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalAssign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.reciprocal_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.reciprocal_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::ZERO;
+    /// x.reciprocal_assign();
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.reciprocal_assign();
+    /// assert_eq!(x.to_string(), "-0.6666666666666666");
+    /// ```
+    #[inline]
+    fn reciprocal_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.reciprocal_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
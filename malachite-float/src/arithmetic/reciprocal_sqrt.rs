@@ -0,0 +1,876 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{
+    DivMod, Parity, PowerOf2, ReciprocalSqrt, ReciprocalSqrtAssign, SqrtRem,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the reciprocal square root of `x` if `x` is NaN, infinite, zero, or negative.
+const fn reciprocal_sqrt_special(x: &Float) -> Float {
+    match x {
+        float_infinity!() => float_zero!(),
+        Float(Zero { .. }) => float_infinity!(),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the reciprocal square root of a finite, positive `Float`, given its exponent and
+// significand.
+//
+// A power of 2 is divided by the significand, and the integer square root of the quotient is
+// taken. The power is chosen so that the exponent of the result's lowest bit is an integer and so
+// that the root has at least `prec + 2` significant bits. Since the floor of the square root of
+// the floor of a number is the floor of its square root, the root is exact if and only if both the
+// division and the square root are exact. Otherwise a 1 bit is appended to the root; this sticky
+// bit is enough to round correctly and to determine the returned `Ordering`.
+fn reciprocal_sqrt_finite_prec_round(
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    let low = exponent - i64::exact_from(bits);
+    let mut pow = bits + ((prec + 2) << 1);
+    if (low + i64::exact_from(pow)).odd() {
+        pow += 1;
+    }
+    let (quotient, quotient_remainder) = Natural::power_of_2(pow).div_mod(significand);
+    let (root, root_remainder) = quotient.sqrt_rem();
+    let low = -((low + i64::exact_from(pow)) >> 1);
+    if quotient_remainder == 0u32 && root_remainder == 0u32 {
+        Float::from_natural_times_power_of_2_prec_round(root, low, prec, rm)
+    } else {
+        Float::from_natural_times_power_of_2_prec_round(
+            (root << 1u32) | Natural::ONE,
+            low - 1,
+            prec,
+            rm,
+        )
+    }
+}
+
+impl Float {
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round`] instead. If both of these things are true, consider
+    /// using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.59");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.564189");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => reciprocal_sqrt_finite_prec_round(exponent, &significand, prec, rm),
+            x => (reciprocal_sqrt_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded reciprocal square root is less
+    /// than, equal to, or greater than the exact reciprocal square root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=0.0$
+    /// - $f(-\infty,p,m)=\text{NaN}$
+    /// - $f(\pm0.0,p,m)=\infty$
+    /// - $f(x,p,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_ref`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_ref`] instead. If both of these things are true,
+    /// consider using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.59");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.564189");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign: true,
+                exponent,
+                significand,
+                ..
+            }) => reciprocal_sqrt_finite_prec_round(*exponent, significand, prec, rm),
+            x => (reciprocal_sqrt_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec(5);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec(20);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec(self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded reciprocal square root is less than, equal to,
+    /// or greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=0.0$
+    /// - $f(-\infty,p)=\text{NaN}$
+    /// - $f(\pm0.0,p)=\infty$
+    /// - $f(x,p)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead. If you know that your target precision is
+    /// the precision of the input, consider using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_ref(5);
+    /// assert_eq!(x.to_string(), "0.56");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`], rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded reciprocal square root is less than, equal to, or
+    /// greater than the exact reciprocal square root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=0.0$
+    /// - $f(-\infty,m)=\text{NaN}$
+    /// - $f(\pm0.0,m)=\infty$
+    /// - $f(x,m)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`ReciprocalSqrt::reciprocal_sqrt`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).reciprocal_sqrt_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_ref(prec, rm)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded reciprocal square root is less than, equal to, or greater
+    /// than the exact reciprocal square root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec_round`] documentation for information on special
+    /// cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::reciprocal_sqrt_prec_assign`]
+    /// instead. If you know that your target precision is the precision of the input, consider
+    /// using [`Float::reciprocal_sqrt_round_assign`] instead. If both of these things are true,
+    /// consider using [`ReciprocalSqrtAssign::reciprocal_sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact reciprocal square root, or if
+    /// `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.59");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.564189");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    pub fn reciprocal_sqrt_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (reciprocal_sqrt, o) = take(self).reciprocal_sqrt_prec_round(prec, rm);
+        *self = reciprocal_sqrt;
+        o
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result to the
+    /// nearest value of the specified precision. An [`Ordering`] is returned, indicating whether
+    /// the rounded reciprocal square root is less than, equal to, or greater than the exact
+    /// reciprocal square root. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the reciprocal square root is equidistant from two [`Float`]s with the specified
+    /// precision, the [`Float`] with fewer 1s in its binary expansion is chosen. See
+    /// [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::reciprocal_sqrt_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead. If you know that your target precision
+    /// is the precision of the input, consider using
+    /// [`ReciprocalSqrtAssign::reciprocal_sqrt_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.56");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.56419");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.reciprocal_sqrt_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the reciprocal of the square root of a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// reciprocal square root is less than, equal to, or greater than the exact reciprocal square
+    /// root. Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $1/\sqrt{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::reciprocal_sqrt_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::reciprocal_sqrt_prec_round_assign`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`ReciprocalSqrtAssign::reciprocal_sqrt_assign`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.5641895835477564");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.reciprocal_sqrt_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.5641895835477563");
+    /// ```
+    #[inline]
+    pub fn reciprocal_sqrt_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_assign(prec, rm)
+    }
+}
+
+impl ReciprocalSqrt for Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of the square root of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=\infty$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round`]. If you want both of these things, consider
+    /// using [`Float::reciprocal_sqrt_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).reciprocal_sqrt().is_nan());
+    /// assert_eq!(ComparableFloat((Float::INFINITY).reciprocal_sqrt()), ComparableFloat(Float::ZERO));
+    /// assert!((Float::NEGATIVE_INFINITY).reciprocal_sqrt().is_nan());
+    /// assert_eq!((Float::NEGATIVE_ZERO).reciprocal_sqrt(), Float::INFINITY);
+    ///
+    /// assert_eq!((Float::from(4.0)).reciprocal_sqrt(), 0.5);
+    /// assert_eq!((Float::from(0.25)).reciprocal_sqrt(), 2.0);
+    /// assert!((Float::from(-4.0)).reciprocal_sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round(prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> ReciprocalSqrt for &'a Float {
+    type Output = Float;
+
+    /// Takes the reciprocal of the square root of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=0.0$
+    /// - $f(-\infty)=\text{NaN}$
+    /// - $f(\pm0.0)=\infty$
+    /// - $f(x)=\text{NaN}$ if $x<0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round_ref`]. If you want both of these things,
+    /// consider using [`Float::reciprocal_sqrt_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrt;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).reciprocal_sqrt().is_nan());
+    /// assert_eq!(ComparableFloat((&Float::INFINITY).reciprocal_sqrt()), ComparableFloat(Float::ZERO));
+    /// assert!((&Float::NEGATIVE_INFINITY).reciprocal_sqrt().is_nan());
+    /// assert_eq!((&Float::NEGATIVE_ZERO).reciprocal_sqrt(), Float::INFINITY);
+    ///
+    /// assert_eq!((&Float::from(4.0)).reciprocal_sqrt(), 0.5);
+    /// assert_eq!((&Float::from(0.25)).reciprocal_sqrt(), 2.0);
+    /// assert!((&Float::from(-4.0)).reciprocal_sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt(self) -> Float {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl ReciprocalSqrtAssign for Float {
+    /// Takes the reciprocal of the square root of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the reciprocal square
+    /// root is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = 1/\sqrt{x}+\varepsilon.
+    /// $$
+    /// - If $1/\sqrt{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be
+    ///   0.
+    /// - If $1/\sqrt{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |1/\sqrt{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`ReciprocalSqrt`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::reciprocal_sqrt_prec_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::reciprocal_sqrt_round_assign`]. If you want both of these things,
+    /// consider using [`Float::reciprocal_sqrt_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalSqrtAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.reciprocal_sqrt_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::INFINITY;
+    /// x.reciprocal_sqrt_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.reciprocal_sqrt_assign();
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::from(4.0);
+    /// x.reciprocal_sqrt_assign();
+    /// assert_eq!(x, 0.5);
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.reciprocal_sqrt_assign();
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn reciprocal_sqrt_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.reciprocal_sqrt_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,1408 @@
+use crate::arithmetic::fmod::{
+    rem_prec_round_ref_ref_helper, rem_prec_round_ref_val_helper, rem_prec_round_val_ref_helper,
+    rem_prec_round_val_val_helper,
+};
+use crate::Float;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+impl Float {
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. Both [`Float`]s are taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the two inputs,
+    /// consider using [`Float::remainder_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_prec_round(Float::from(E), 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_round(
+        self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_val_val_helper(self, other, true, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. The first [`Float`] is taken by value and
+    /// the second by reference. An [`Ordering`] is also returned, indicating whether the rounded
+    /// remainder is less than, equal to, or greater than the exact remainder. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec_val_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::remainder_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_val_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_round_val_ref(
+        self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_val_ref_helper(self, other, true, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. The first [`Float`] is taken by reference
+    /// and the second by value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// remainder is less than, equal to, or greater than the exact remainder. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec_ref_val`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::remainder_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_val(
+    ///     Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_round_ref_val(
+        &self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_ref_val_helper(self, other, true, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the specified
+    /// precision and with the specified rounding mode. Both [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p,m)=f(x,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,x,p,m)=f(x,\pm0.0,p,m)=\text{NaN}$
+    /// - $f(0.0,x,p,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::remainder_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_round_ref_ref(
+    ///     &Float::from(E),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_round_ref_ref(
+        &self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        rem_prec_round_ref_ref_helper(self, other, true, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the nearest value
+    /// of the specified precision. Both [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded remainder is less than, equal to, or greater than
+    /// the exact remainder. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec(self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.remainder_prec_round(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the nearest value
+    /// of the specified precision. The first [`Float`] is taken by value and the second by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded remainder is
+    /// less than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_val_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_val_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_val_ref(self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.remainder_prec_round_val_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the nearest value
+    /// of the specified precision. The first [`Float`] is taken by reference and the second by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded remainder is less
+    /// than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_ref_val(Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_ref_val(Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_ref_val(&self, other: Float, prec: u64) -> (Float, Ordering) {
+        self.remainder_prec_round_ref_val(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result to the nearest value
+    /// of the specified precision. Both [`Float`]s are taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded remainder is less than, equal to, or greater than
+    /// the exact remainder. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,p) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,p)=f(x,\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,x,p)=f(x,\pm0.0,p)=\text{NaN}$
+    /// - $f(0.0,x,p)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,p)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,p)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,p)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_ref_ref(&Float::from(E), 5);
+    /// assert_eq!(x.to_string(), "0.42");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_prec_ref_ref(&Float::from(E), 20);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn remainder_prec_ref_ref(&self, other: &Float, prec: u64) -> (Float, Ordering) {
+        self.remainder_prec_round_ref_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result with the specified
+    /// rounding mode. Both [`Float`]s are taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded remainder is less than, equal to, or greater than the exact
+    /// remainder. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using [`Float::remainder_prec_round`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) = Float::from(PI).remainder_round(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_round(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) = Float::from(PI).remainder_round(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn remainder_round(self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round(other, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result with the specified
+    /// rounding mode. The first [`Float`] is taken by value and the second by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::remainder_prec_round_val_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_val_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_val_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_val_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn remainder_round_val_ref(self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round_val_ref(other, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result with the specified
+    /// rounding mode. The first [`Float`] is taken by reference and the second by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded remainder is less than, equal
+    /// to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::remainder_prec_round_ref_val`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_val(Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_val(Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_val(Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn remainder_round_ref_val(&self, other: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round_ref_val(other, prec, rm)
+    }
+
+    /// Computes the IEEE 754 remainder of two [`Float`]s, rounding the result with the specified
+    /// rounding mode. Both [`Float`]s are taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded remainder is less than, equal to, or greater than the exact
+    /// remainder. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// f(x,y,m) = x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},x,m)=f(x,\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,x,m)=f(x,\pm0.0,m)=\text{NaN}$
+    /// - $f(0.0,x,m)=0.0$ if $x$ is not NaN or zero
+    /// - $f(-0.0,x,m)=-0.0$ if $x$ is not NaN or zero
+    /// - $f(x,y,m)=0.0$ if $x>0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    /// - $f(x,y,m)=-0.0$ if $x<0.0$, $y$ is finite and nonzero, and $x/y$ is an integer
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::remainder_prec_round_ref_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_ref(&Float::from(E), RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_ref(&Float::from(E), RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).remainder_round_ref_ref(&Float::from(E), RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn remainder_round_ref_ref(&self, other: &Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round_ref_ref(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] on the
+    /// right-hand side is taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::remainder_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::remainder_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign(Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    pub fn remainder_prec_round_assign(
+        &mut self,
+        other: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (remainder, o) = rem_prec_round_val_val_helper(take(self), other, true, prec, rm);
+        *self = remainder;
+        o
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result to the specified precision and with the specified rounding mode. The [`Float`] on the
+    /// right-hand side is taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded remainder is less than, equal to, or greater than the exact remainder. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::remainder_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::remainder_prec_assign_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the two
+    /// inputs, consider using [`Float::remainder_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact remainder, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 5, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233112");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_round_assign_ref(&Float::from(E), 20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    pub fn remainder_prec_round_assign_ref(
+        &mut self,
+        other: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (remainder, o) = rem_prec_round_val_ref_helper(take(self), other, true, prec, rm);
+        *self = remainder;
+        o
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] on the right-hand side
+    /// is taken by value. An [`Ordering`] is returned, indicating whether the rounded remainder is
+    /// less than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::remainder_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.remainder_prec_assign(Float::from(E), 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.remainder_prec_assign(Float::from(E), 20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    #[inline]
+    pub fn remainder_prec_assign(&mut self, other: Float, prec: u64) -> Ordering {
+        self.remainder_prec_round_assign(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result to the nearest value of the specified precision. The [`Float`] on the right-hand side
+    /// is taken by reference. An [`Ordering`] is returned, indicating whether the rounded remainder
+    /// is less than, equal to, or greater than the exact remainder. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the remainder is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-ny|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::remainder_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::remainder_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_assign_ref(&Float::from(E), 5),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.42");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_prec_assign_ref(&Float::from(E), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "0.4233108");
+    /// ```
+    #[inline]
+    pub fn remainder_prec_assign_ref(&mut self, other: &Float, prec: u64) -> Ordering {
+        self.remainder_prec_round_assign_ref(other, prec, RoundingMode::Nearest)
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// value. An [`Ordering`] is returned, indicating whether the rounded remainder is less than,
+    /// equal to, or greater than the exact remainder. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::remainder_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::remainder_prec_round_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign(Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign(Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign(Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// ```
+    #[inline]
+    pub fn remainder_round_assign(&mut self, other: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round_assign(other, prec, rm)
+    }
+
+    /// Replaces a [`Float`] with its IEEE 754 remainder modulo another [`Float`], rounding the
+    /// result with the specified rounding mode. The [`Float`] on the right-hand side is taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded remainder is less
+    /// than, equal to, or greater than the exact remainder. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. The remainder can
+    /// always be represented exactly with this precision, so the returned [`Ordering`] is always
+    /// `Equal`. See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// Let $n$ be the integer nearest to $x/y$, with ties broken in favor of the even integer.
+    ///
+    /// $$
+    /// x \gets x-ny+\varepsilon.
+    /// $$
+    /// - If $x-ny$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-ny$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-ny$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-ny|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::remainder_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::remainder_prec_round_assign_ref`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign_ref(&Float::from(E), RoundingMode::Floor),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign_ref(&Float::from(E), RoundingMode::Ceiling),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.remainder_round_assign_ref(&Float::from(E), RoundingMode::Nearest),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(x.to_string(), "0.42331082513074803");
+    /// ```
+    #[inline]
+    pub fn remainder_round_assign_ref(&mut self, other: &Float, rm: RoundingMode) -> Ordering {
+        let prec = max(self.significant_bits(), other.significant_bits());
+        self.remainder_prec_round_assign_ref(other, prec, rm)
+    }
+}
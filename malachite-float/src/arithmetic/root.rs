@@ -0,0 +1,909 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Mod, Parity, Root, RootAssign, RootRem};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the `exp`th root of `x` if `exp` is zero, or if `x` is NaN, infinite, zero, or negative
+// with `exp` even.
+fn root_special(x: &Float, exp: u64) -> Float {
+    match x {
+        _ if exp == 0 => float_nan!(),
+        Float(Infinity { sign }) if *sign || exp.odd() => Float(Infinity { sign: *sign }),
+        Float(Zero { sign }) => Float(Zero {
+            sign: *sign || exp.even(),
+        }),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the `exp`th root of a finite, nonzero `Float`, given its sign, exponent, and
+// significand. `exp` must be nonzero, and if `sign` is `false`, `exp` must be odd.
+//
+// The significand is shifted left so that the exponent of its lowest bit is divisible by `exp` and
+// so that its integer `exp`th root has at least `prec + 2` significant bits. If the root is
+// inexact, a 1 bit is appended to it; this sticky bit is enough to round correctly and to determine
+// the returned `Ordering`.
+pub(crate) fn root_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    exp: u64,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    let i_exp = i64::exact_from(exp);
+    let low = exponent - i64::exact_from(bits);
+    let mut shift = ((prec + 1) * exp + 1).saturating_sub(bits);
+    shift += u64::exact_from((low - i64::exact_from(shift)).mod_op(i_exp));
+    let (root, remainder) = (significand << shift).root_rem(exp);
+    let low = (low - i64::exact_from(shift)) / i_exp;
+    if remainder == 0u32 {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, root),
+            low,
+            prec,
+            rm,
+        )
+    } else {
+        Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, (root << 1u32) | Natural::ONE),
+            low - 1,
+            prec,
+            rm,
+        )
+    }
+}
+
+impl Float {
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned, indicating whether the rounded root is less than, equal
+    /// to, or greater than the exact root. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$
+    /// - $f(x,0,p,m)=\text{NaN}$
+    /// - $f(\infty,n,p,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round`] instead. If both of these things are true, consider using
+    /// [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.46459");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round(3, 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_round(self, exp: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) if exp != 0 && (sign || exp.odd()) => {
+                root_finite_prec_round(sign, exponent, &significand, exp, prec, rm)
+            }
+            x => (root_special(&x, exp), Ordering::Equal),
+        }
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded root is less
+    /// than, equal to, or greater than the exact root. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,p,m) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p,m)=\text{NaN}$
+    /// - $f(x,0,p,m)=\text{NaN}$
+    /// - $f(\infty,n,p,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p,m)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,p,m)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round_ref`] instead. If both of these things are true, consider using
+    /// [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.46459");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_round_ref(3, 20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_round_ref(&self, exp: u64, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) if exp != 0 && (*sign || exp.odd()) => {
+                root_finite_prec_round(*sign, *exponent, significand, exp, prec, rm)
+            }
+            x => (root_special(x, exp), Ordering::Equal),
+        }
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded root is less than, equal to, or greater than the
+    /// exact root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the root is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p)=\text{NaN}$
+    /// - $f(x,0,p)=\text{NaN}$
+    /// - $f(\infty,n,p)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,p)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec(3, 5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec(3, 20);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec(self, exp: u64, prec: u64) -> (Float, Ordering) {
+        self.root_prec_round(exp, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result to the nearest
+    /// value of the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded root is less than, equal to, or greater than
+    /// the exact root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the root is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n,p) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,p)=\text{NaN}$
+    /// - $f(x,0,p)=\text{NaN}$
+    /// - $f(\infty,n,p)=\infty$ if $n>0$
+    /// - $f(-\infty,n,p)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,p)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,p)=0.0$ if $n>0$
+    /// - $f(-0.0,n,p)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,p)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,p)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_ref(3, 5);
+    /// assert_eq!(x.to_string(), "1.44");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_prec_ref(3, 20);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn root_prec_ref(&self, exp: u64, prec: u64) -> (Float, Ordering) {
+        self.root_prec_round_ref(exp, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded root is less than, equal to, or greater than the exact root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,m)=\text{NaN}$
+    /// - $f(x,0,m)=\text{NaN}$
+    /// - $f(\infty,n,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,m)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,m)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_round(3, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn root_round(self, exp: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.root_prec_round(exp, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, rounding the result with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded root is less than, equal to, or greater than the
+    /// exact root. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,n,m) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n,m)=\text{NaN}$
+    /// - $f(x,0,m)=\text{NaN}$
+    /// - $f(\infty,n,m)=\infty$ if $n>0$
+    /// - $f(-\infty,n,m)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n,m)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n,m)=0.0$ if $n>0$
+    /// - $f(-0.0,n,m)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n,m)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n,m)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Root::root`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).root_round_ref(3, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn root_round_ref(&self, exp: u64, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.root_prec_round_ref(exp, prec, rm)
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded root is less than, equal to, or greater than the exact root.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::root_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::root_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::root_round_assign`] instead. If both of these things are true, consider using
+    /// [`RootAssign::root_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact root, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.46459");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.464592");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_round_assign(3, 20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// ```
+    pub fn root_prec_round_assign(&mut self, exp: u64, prec: u64, rm: RoundingMode) -> Ordering {
+        let (root, o) = take(self).root_prec_round(exp, prec, rm);
+        *self = root;
+        o
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, in place, rounding the result to
+    /// the nearest value of the specified precision. An [`Ordering`] is returned, indicating
+    /// whether the rounded root is less than, equal to, or greater than the exact root. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function sets the [`Float`] to
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// If the root is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::root_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`RootAssign::root_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec * exp)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_assign(3, 5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.44");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_prec_assign(3, 20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.464592");
+    /// ```
+    #[inline]
+    pub fn root_prec_assign(&mut self, exp: u64, prec: u64) -> Ordering {
+        self.root_prec_round_assign(exp, prec, RoundingMode::Nearest)
+    }
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, in place, rounding the result with
+    /// the specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// root is less than, equal to, or greater than the exact root. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::root_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::root_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`RootAssign::root_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_round_assign(3, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_round_assign(3, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.4645918875615234");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.root_round_assign(3, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.4645918875615231");
+    /// ```
+    #[inline]
+    pub fn root_round_assign(&mut self, exp: u64, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.root_prec_round_assign(exp, prec, rm)
+    }
+}
+
+impl Root<u64> for Float {
+    type Output = Float;
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the root is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=\text{NaN}$
+    /// - $f(x,0)=\text{NaN}$
+    /// - $f(\infty,n)=\infty$ if $n>0$
+    /// - $f(-\infty,n)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n)=0.0$ if $n>0$
+    /// - $f(-0.0,n)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::root_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::root_round`].
+    /// If you want both of these things, consider using [`Float::root_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Root;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).root(3).is_nan());
+    /// assert_eq!((Float::INFINITY).root(3), Float::INFINITY);
+    /// assert_eq!((Float::NEGATIVE_INFINITY).root(3), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((Float::NEGATIVE_ZERO).root(3)), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// assert_eq!((Float::from(8.0)).root(3), 2.0);
+    /// assert_eq!((Float::from(-8.0)).root(3), -2.0);
+    /// assert!((Float::from(-8.0)).root(2).is_nan());
+    /// assert!((Float::from(8.0)).root(0).is_nan());
+    /// ```
+    #[inline]
+    fn root(self, exp: u64) -> Float {
+        let prec = self.significant_bits();
+        self.root_prec_round(exp, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Root<u64> for &'a Float {
+    type Output = Float;
+
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`. The [`Float`] is taken by
+    /// reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the root is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,n) = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},n)=\text{NaN}$
+    /// - $f(x,0)=\text{NaN}$
+    /// - $f(\infty,n)=\infty$ if $n>0$
+    /// - $f(-\infty,n)=-\infty$ if $n$ is odd
+    /// - $f(-\infty,n)=\text{NaN}$ if $n$ is even
+    /// - $f(0.0,n)=0.0$ if $n>0$
+    /// - $f(-0.0,n)=-0.0$ if $n$ is odd
+    /// - $f(-0.0,n)=0.0$ if $n$ is even and $n>0$
+    /// - $f(x,n)=\text{NaN}$ if $x<0.0$ and $n$ is even
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::root_round_ref`]. If you want both of these things, consider using
+    /// [`Float::root_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Root;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).root(3).is_nan());
+    /// assert_eq!((&Float::INFINITY).root(3), Float::INFINITY);
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).root(3), Float::NEGATIVE_INFINITY);
+    /// assert_eq!(ComparableFloat((&Float::NEGATIVE_ZERO).root(3)), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// assert_eq!((&Float::from(8.0)).root(3), 2.0);
+    /// assert_eq!((&Float::from(-8.0)).root(3), -2.0);
+    /// assert!((&Float::from(-8.0)).root(2).is_nan());
+    /// assert!((&Float::from(8.0)).root(0).is_nan());
+    /// ```
+    #[inline]
+    fn root(self, exp: u64) -> Float {
+        let prec = self.significant_bits();
+        self.root_prec_round_ref(exp, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl RootAssign<u64> for Float {
+    /// Takes the $n$th root of a [`Float`], where $n$ is `exp`, in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the root is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sqrt\[n\]{x}+\varepsilon.
+    /// $$
+    /// - If $\sqrt\[n\]{x}$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to
+    ///   be 0.
+    /// - If $\sqrt\[n\]{x}$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2
+    ///   |\sqrt\[n\]{x}|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Root`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::root_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::root_round_assign`]. If you want both of these things, consider using
+    /// [`Float::root_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits() * exp`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::RootAssign;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.root_assign(3);
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.root_assign(3);
+    /// assert_eq!(x, Float::NEGATIVE_INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.root_assign(4);
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(-8.0);
+    /// x.root_assign(3);
+    /// assert_eq!(x, -2.0);
+    ///
+    /// let mut x = Float::from(-8.0);
+    /// x.root_assign(2);
+    /// assert!(x.is_nan());
+    /// ```
+    #[inline]
+    fn root_assign(&mut self, exp: u64) {
+        let prec = self.significant_bits();
+        self.root_prec_round_assign(exp, prec, RoundingMode::Nearest);
+    }
+}
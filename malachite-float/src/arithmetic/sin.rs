@@ -0,0 +1,957 @@
+use crate::arithmetic::exp::{fixed_from_significand, round_approximation, round_near_x};
+use crate::constants::pi::pi_fixed;
+use crate::InnerFloat::{Finite, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{
+    DivRound, FloorSqrt, ModPowerOf2, Parity, Pow, PowerOf2, Sin, SinAssign, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{Two, Zero as ZeroTrait};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the sine of `x` if `x` is NaN, infinite, or zero.
+const fn sin_special(x: &Float) -> Float {
+    match x {
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Approximates $\sin r$, where $0 \leq r \leq 1$ is given by the fixed-point value `r` with `scale`
+// fractional bits and an error of at most `r_error`. The result has `scale` fractional bits; its
+// error is returned along with it.
+//
+// The argument is divided by $3^k$, the Taylor series is summed, and $\sin 3a = 3\sin a - 4\sin^3
+// a$ is applied $k$ times. Each computed term of the series is within 2 of the exact term, and the
+// tail is less than 1. Every tripled value is at most $\sin(1/3)$, where the derivative of $3s -
+// 4s^3$ is between 1 and 3, so each tripling at most triples the error and adds at most 8. Since
+// the derivative of $\sin$ is at most 1, an error in the argument is not amplified.
+fn sin_small_fixed(r: &Natural, r_error: &Natural, scale: u64) -> (Natural, Natural) {
+    let triplings = max(1, scale.floor_sqrt() >> 1);
+    let guard = (triplings << 1) + scale.significant_bits() + 6;
+    let p = scale + guard;
+    let three = Natural::from(3u32);
+    let eight = Natural::from(8u32);
+    let power_of_3 = (&three).pow(triplings);
+    let a = (r << guard) / &power_of_3;
+    let mut error = (r_error << guard) / &power_of_3 + Natural::TWO;
+    let a_squared = (&a).square() >> p;
+    let mut term = a.clone();
+    let mut sum = a;
+    let mut negative = Natural::ZERO;
+    let mut i = 0u64;
+    loop {
+        i += 1;
+        term = ((term * &a_squared) >> p) / Natural::from((i << 1) * ((i << 1) + 1));
+        if term == 0u32 {
+            break;
+        }
+        if i.odd() {
+            negative += &term;
+        } else {
+            sum += &term;
+        }
+    }
+    error += Natural::from(2 * i + 1);
+    let mut s = sum - negative;
+    for _ in 0..triplings {
+        let cube = ((((&s).square() >> p) * &s) >> p) << 2u32;
+        s = s * &three - cube;
+        error = error * &three + &eight;
+    }
+    (s >> guard, (error >> guard) + Natural::TWO)
+}
+
+// Reduces a finite, nonzero $x = \pm n2^\ell$, given by `sign`, `significand` ($n$), `low` ($\ell$)
+// and its exponent, modulo $\pi/2$. Returns `(q, r_sign, r, r_error, scale)`, where $x = k\pi/2 +
+// r$ for some integer $k \equiv q \pmod 4$, $|r| \lesssim \pi/4$, and $|r|$ is approximated by `r`
+// with `scale` fractional bits and an error of at most `r_error`.
+//
+// $\pi/2$ is computed with more fractional bits than $|k|$ has integer bits, so that the error in
+// $k\pi/2$ stays small however large $x$ is. If $x$ is very close to a multiple of $\pi/2$, so
+// that the computed $r$ has fewer than `working_prec` significant bits, the reduction is repeated
+// with more fractional bits.
+fn reduce_mod_half_pi(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    low: i64,
+    working_prec: u64,
+) -> (u64, bool, Natural, Natural, u64) {
+    let integer_bits = u64::exact_from(max(exponent, 0));
+    let mut scale = working_prec + u64::exact_from(max(-exponent, 0)) + 10;
+    loop {
+        let guard = integer_bits + integer_bits.significant_bits() + 4;
+        let t = scale + guard;
+        let (half_pi, half_pi_error) = pi_fixed(t - 1);
+        let (x, x_error) = fixed_from_significand(true, significand, low, t);
+        let x = x.unsigned_abs();
+        let k = (&x).div_round(&half_pi, RoundingMode::Nearest).0;
+        let r = Integer::from(x) - Integer::from(&k * &half_pi);
+        let r_error = (x_error + &k * half_pi_error) >> guard;
+        let r_sign = (r >= 0u32) == sign;
+        let r = r.unsigned_abs() >> guard;
+        let r_bits = r.significant_bits();
+        if r_bits >= working_prec + 10 {
+            let mut q = u64::exact_from(&k.mod_power_of_2(2));
+            if !sign {
+                q = (4 - q) & 3;
+            }
+            return (q, r_sign, r, r_error + Natural::TWO, scale);
+        }
+        scale += working_prec + 10 - r_bits;
+    }
+}
+
+// Approximates $\sin x$ and $\cos x$, for a finite, nonzero $x = \pm n2^\ell$, given by `sign`,
+// `significand` ($n$), `low` ($\ell$), and its exponent. Returns `(sin, sin_error, cos, cos_error,
+// scale)`, where both approximations have `scale` fractional bits and roughly `working_prec`
+// significant bits.
+//
+// $x$ is reduced to $r$, with $|r| \lesssim \pi/4$, and $\sin x$ and $\cos x$ are $\pm\sin r$ and
+// $\pm\cos r$, in some order. $\cos r = \sqrt{1-\sin^2 r}$; since $\tan |r| < 1.1$, an error $e$ in
+// $\sin r$ causes an error of at most $1.1e$ in $\cos r$, and taking the square root adds at most
+// 1.
+pub(crate) fn sin_cos_fixed(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    low: i64,
+    working_prec: u64,
+) -> (Integer, Natural, Integer, Natural, u64) {
+    let (q, r_sign, r, r_error, scale) =
+        reduce_mod_half_pi(sign, exponent, significand, low, working_prec);
+    let (s, s_error) = sin_small_fixed(&r, &r_error, scale);
+    let c = (Natural::power_of_2(scale << 1) - (&s).square()).floor_sqrt();
+    let c_error = &s_error + (&s_error >> 2u32) + Natural::TWO;
+    // sin r has the sign of r; cos r is positive
+    let (sin, sin_error, sin_sign, cos, cos_error, cos_sign) = match q {
+        0 => (s, s_error, r_sign, c, c_error, true),
+        1 => (c, c_error, true, s, s_error, !r_sign),
+        2 => (s, s_error, !r_sign, c, c_error, false),
+        _ => (c, c_error, false, s, s_error, r_sign),
+    };
+    (
+        Integer::from_sign_and_abs(sin_sign, sin),
+        sin_error,
+        Integer::from_sign_and_abs(cos_sign, cos),
+        cos_error,
+        scale,
+    )
+}
+
+// Computes the sine of a finite, nonzero `Float`, given its sign, exponent, and significand.
+//
+// If $|x| < 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then $\sin
+// x$ lies between $x$ and $x - x^3/6$, so it rounds like a value just closer to zero than $x$.
+// Otherwise successively better approximations are computed until one of them can be rounded
+// correctly; since $\sin x$ is transcendental for any nonzero rational $x$, this always happens
+// eventually.
+fn sin_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, !sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    round_approximation(prec, rm, |working_prec| {
+        let (sin, sin_error, _, _, scale) =
+            sin_cos_fixed(sign, exponent, significand, low, working_prec);
+        (sin, sin_error, -i64::exact_from(scale))
+    })
+}
+impl Float {
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round`] instead. If both of these things are true, consider using [`Sin::sin`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sin_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (sin_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the specified precision and with
+    /// the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded sine is less than, equal to, or greater than the
+    /// exact sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\text{NaN}$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_ref`] instead. If both of these things are true, consider using
+    /// [`Sin::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sin_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (sin_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Sin::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec(5);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec(20);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result to the nearest value of the specified
+    /// precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded sine is less than, equal to, or greater than the exact sine. Although
+    /// `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\text{NaN}$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Sin::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_prec_ref(20);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sin_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Sin::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`], rounding the result with the specified rounding mode. The
+    /// [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\text{NaN}$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Sin::sin`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sin_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sin_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the specified precision
+    /// and with the specified rounding mode. An [`Ordering`] is returned, indicating whether the
+    /// rounded sine is less than, equal to, or greater than the exact sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_prec_assign`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_round_assign`] instead. If both of these things are true, consider using
+    /// [`SinAssign::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with precision `prec`,
+    /// or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.18e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.224649e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    pub fn sin_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sin, o) = take(self).sin_prec_round(prec, rm);
+        *self = sin;
+        o
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result to the nearest value of the
+    /// specified precision. An [`Ordering`] is returned, indicating whether the rounded sine is
+    /// less than, equal to, or greater than the exact sine. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the sine is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sin_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinAssign::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.25e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224647e-16");
+    /// ```
+    #[inline]
+    pub fn sin_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sin_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the sine of a [`Float`] in place, rounding the result with the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded sine is less than, equal
+    /// to, or greater than the exact sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sin_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinAssign::sin_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the sine is not exactly representable with the precision of
+    /// the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "1.224646799147353e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sin_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.2246467991473532e-16");
+    /// ```
+    #[inline]
+    pub fn sin_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, rm)
+    }
+}
+
+impl Sin for Float {
+    type Output = Float;
+
+    /// Computes the sine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::sin_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::sin_round`].
+    /// If you want both of these things, consider using [`Float::sin_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).sin().is_nan());
+    /// assert!((Float::INFINITY).sin().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).sin()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).sin().to_string(), "0.9974949866040544");
+    /// assert_eq!((Float::from(-1.5)).sin().to_string(), "-0.9974949866040544");
+    /// ```
+    #[inline]
+    fn sin(self) -> Float {
+        let prec = self.significant_bits();
+        self.sin_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sin for &'a Float {
+    type Output = Float;
+
+    /// Computes the sine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\text{NaN}$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_ref`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sin_round_ref`]. If you want both of these things, consider using
+    /// [`Float::sin_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sin;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).sin().is_nan());
+    /// assert!((&Float::INFINITY).sin().is_nan());
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).sin()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).sin().to_string(), "0.9974949866040544");
+    /// assert_eq!((&Float::from(-1.5)).sin().to_string(), "-0.9974949866040544");
+    /// ```
+    #[inline]
+    fn sin(self) -> Float {
+        let prec = self.significant_bits();
+        self.sin_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl SinAssign for Float {
+    /// Computes the sine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the sine is equidistant
+    /// from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in its binary
+    /// expansion is chosen. See [`RoundingMode`] for a description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sin x+\varepsilon.
+    /// $$
+    /// - If $\sin x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Sin`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sin_round_assign`]. If you want both of these things, consider using
+    /// [`Float::sin_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.sin_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.sin_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.sin_assign();
+    /// assert_eq!(x.to_string(), "0.9974949866040544");
+    /// ```
+    #[inline]
+    fn sin_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sin_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
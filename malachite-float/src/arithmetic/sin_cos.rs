@@ -0,0 +1,701 @@
+use crate::arithmetic::exp::{round_if_determined, round_near_x};
+use crate::arithmetic::sin::sin_cos_fixed;
+use crate::InnerFloat::{Finite, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::SinCos;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+
+// Returns the sine and cosine of `x` if `x` is NaN, infinite, or zero. The cosine of zero is 1,
+// which is returned with precision `prec`.
+fn sin_cos_special(x: &Float, prec: u64) -> (Float, Float) {
+    match x {
+        Float(Zero { sign }) => (Float(Zero { sign: *sign }), Float::one_prec(prec)),
+        _ => (float_nan!(), float_nan!()),
+    }
+}
+
+// Computes the sine and cosine of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// This works like `sin_finite_prec_round` and `cos_finite_prec_round`, except that both are
+// approximated together, and once one of them has been rounded, only the other one is still
+// checked.
+fn sin_cos_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> ((Float, Ordering), (Float, Ordering)) {
+    assert_ne!(rm, RoundingMode::Exact, "Inexact result");
+    let bits = significand.significant_bits();
+    let mut sin = if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        Some(round_near_x(sign, exponent, significand, !sign, prec, rm))
+    } else {
+        None
+    };
+    let mut cos = if exponent << 1 < -i64::exact_from(prec) {
+        Some(round_near_x(true, 1, &Natural::ONE, false, prec, rm))
+    } else {
+        None
+    };
+    let low = exponent - i64::exact_from(bits);
+    let mut working_prec = prec + prec.significant_bits() + 10;
+    while sin.is_none() || cos.is_none() {
+        let (s, s_error, c, c_error, scale) =
+            sin_cos_fixed(sign, exponent, significand, low, working_prec);
+        let exponent = -i64::exact_from(scale);
+        if sin.is_none() {
+            sin = round_if_determined(s, s_error, exponent, prec, rm);
+        }
+        if cos.is_none() {
+            cos = round_if_determined(c, c_error, exponent, prec, rm);
+        }
+        working_prec += working_prec >> 1;
+    }
+    (sin.unwrap(), cos.unwrap())
+}
+
+impl Float {
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by value.
+    /// An [`Ordering`] is also returned for each of them, indicating whether the rounded value is
+    /// less than, equal to, or greater than the exact value. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// This is faster than computing the sine and cosine separately.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round`] instead. If both of these things are true, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round(
+        self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Float, Ordering), (Float, Ordering)) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sin_cos_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => {
+                let (sin, cos) = sin_cos_special(&x, prec);
+                ((sin, Ordering::Equal), (cos, Ordering::Equal))
+            }
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results to the
+    /// nearest value of the specified precision. The [`Float`] is taken by value. An [`Ordering`]
+    /// is also returned for each of them, indicating whether the rounded value is less than, equal
+    /// to, or greater than the exact value. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec(self, prec: u64) -> ((Float, Ordering), (Float, Ordering)) {
+        self.sin_cos_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results with the
+    /// specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned
+    /// for each of them, indicating whether the rounded value is less than, equal to, or greater
+    /// than the exact value. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round(RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round(self, rm: RoundingMode) -> ((Float, Ordering), (Float, Ordering)) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round(prec, rm)
+    }
+
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results to the
+    /// specified precision and with the specified rounding mode. The [`Float`] is taken by
+    /// reference. An [`Ordering`] is also returned for each of them, indicating whether the rounded
+    /// value is less than, equal to, or greater than the exact value. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// This is faster than computing the sine and cosine separately.
+    ///
+    /// $$
+    /// f(x,p,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p,m)=(0.0,1.0)$
+    /// - $f(-0.0,p,m)=(-0.0,1.0)$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sin_cos_prec_ref`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sin_cos_round_ref`] instead. If both of these things are true, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.18e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.97");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.224649e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.999999");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_round_ref(
+        &self,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> ((Float, Ordering), (Float, Ordering)) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sin_cos_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => {
+                let (sin, cos) = sin_cos_special(x, prec);
+                ((sin, Ordering::Equal), (cos, Ordering::Equal))
+            }
+        }
+    }
+
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results to the
+    /// nearest value of the specified precision. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned for each of them, indicating whether the rounded value is less
+    /// than, equal to, or greater than the exact value. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the sine or cosine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the outputs have a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,p)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,p)=(0.0,1.0)$
+    /// - $f(-0.0,p)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)` plus the exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_ref(5);
+    /// assert_eq!(sin.to_string(), "1.25e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_prec_ref(20);
+    /// assert_eq!(sin.to_string(), "1.224647e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_prec_ref(&self, prec: u64) -> ((Float, Ordering), (Float, Ordering)) {
+        self.sin_cos_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Simultaneously computes the sine and cosine of a [`Float`], rounding the results with the
+    /// specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned for each of them, indicating whether the rounded value is less than, equal to, or
+    /// greater than the exact value. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the outputs is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sin x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_1| <
+    ///   2^{\lfloor\log_2 |\sin x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon_2| <
+    ///   2^{\lfloor\log_2 |\cos x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty,m)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0,m)=(0.0,1.0)$
+    /// - $f(-0.0,m)=(-0.0,1.0)$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sin_cos_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinCos::sin_cos`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(RoundingMode::Floor);
+    /// assert_eq!(sin.to_string(), "1.224646799147353e-16");
+    /// assert_eq!(o_sin, Ordering::Less);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-0.9999999999999999");
+    /// assert_eq!(o_cos, Ordering::Greater);
+    ///
+    /// let ((sin, o_sin), (cos, o_cos)) = Float::from(PI).sin_cos_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(sin.to_string(), "1.2246467991473532e-16");
+    /// assert_eq!(o_sin, Ordering::Greater);
+    /// assert_eq!(cos.to_string(), "-1.0");
+    /// assert_eq!(o_cos, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sin_cos_round_ref(&self, rm: RoundingMode) -> ((Float, Ordering), (Float, Ordering)) {
+        let prec = self.significant_bits();
+        self.sin_cos_prec_round_ref(prec, rm)
+    }
+}
+
+impl SinCos for Float {
+    type Output = Float;
+
+    /// Simultaneously computes the sine and cosine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the outputs have a precision, it is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sin_cos_round`]. If you want both of these things, consider using
+    /// [`Float::sin_cos_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let (sin, cos) = (Float::NAN).sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = (Float::NEGATIVE_ZERO).sin_cos();
+    /// assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+    /// assert_eq!(cos, Float::ONE);
+    ///
+    /// let (sin, cos) = (Float::from(1.5)).sin_cos();
+    /// assert_eq!(sin.to_string(), "0.9974949866040544");
+    /// assert_eq!(cos.to_string(), "0.07073720166770291");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let ((sin, _), (cos, _)) = self.sin_cos_prec_round(prec, RoundingMode::Nearest);
+        (sin, cos)
+    }
+}
+
+impl<'a> SinCos for &'a Float {
+    type Output = Float;
+
+    /// Simultaneously computes the sine and cosine of a [`Float`]. The [`Float`] is taken by
+    /// reference.
+    ///
+    /// If the outputs have a precision, it is the precision of the input. If the sine or cosine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = (\sin x+\varepsilon_1, \cos x+\varepsilon_2).
+    /// $$
+    /// - If $\sin x$ is zero or `NaN`, $\varepsilon_1$ may be ignored or assumed to be 0, and
+    ///   likewise for $\cos x$ and $\varepsilon_2$.
+    /// - If $\sin x$ is finite and nonzero, then $|\varepsilon_1| < 2^{\lfloor\log_2 |\sin
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    /// - If $\cos x$ is finite and nonzero, then $|\varepsilon_2| < 2^{\lfloor\log_2 |\cos
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=(\text{NaN},\text{NaN})$
+    /// - $f(\pm\infty)=(\text{NaN},\text{NaN})$
+    /// - $f(0.0)=(0.0,1.0)$
+    /// - $f(-0.0)=(-0.0,1.0)$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sin_cos_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sin_cos_round_ref`]. If you want both of these things, consider using
+    /// [`Float::sin_cos_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()` plus the
+    /// exponent of `self`, if it is positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinCos;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let (sin, cos) = (&Float::NAN).sin_cos();
+    /// assert!(sin.is_nan());
+    /// assert!(cos.is_nan());
+    ///
+    /// let (sin, cos) = (&Float::NEGATIVE_ZERO).sin_cos();
+    /// assert_eq!(ComparableFloat(sin), ComparableFloat(Float::NEGATIVE_ZERO));
+    /// assert_eq!(cos, Float::ONE);
+    ///
+    /// let (sin, cos) = (&Float::from(1.5)).sin_cos();
+    /// assert_eq!(sin.to_string(), "0.9974949866040544");
+    /// assert_eq!(cos.to_string(), "0.07073720166770291");
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (Float, Float) {
+        let prec = self.significant_bits();
+        let ((sin, _), (cos, _)) = self.sin_cos_prec_round_ref(prec, RoundingMode::Nearest);
+        (sin, cos)
+    }
+}
@@ -0,0 +1,916 @@
+use crate::arithmetic::exp::{
+    exp_fixed, fixed_from_significand, round_approximation, round_near_x,
+};
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{DivRound, PowerOf2, Sinh, SinhAssign};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the hyperbolic sine of `x` if `x` is NaN, infinite, or zero.
+const fn sinh_special(x: &Float) -> Float {
+    match x {
+        Float(Infinity { sign }) => Float(Infinity { sign: *sign }),
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Approximates $e^{|x|}$ and $e^{-|x|}$, for a finite, nonzero $x$ with absolute value $n2^\ell$,
+// given by its exponent, `significand` ($n$), and `low` ($\ell$), as fixed-point values with `scale`
+// fractional bits. Returns `(p, p_error, m, m_error)`, where `p` approximates $e^{|x|}$ and `m`
+// approximates $e^{-|x|}$.
+//
+// $e^{-|x|}$ is computed as the reciprocal of the approximation of $e^{|x|}$; the reciprocal of
+// the exact value differs from it by at most $e_p/(p(p-e_p))$, plus 1 for truncation.
+pub(crate) fn exp_pair_fixed(
+    exponent: i64,
+    significand: &Natural,
+    low: i64,
+    scale: u64,
+) -> (Natural, Natural, Natural, Natural) {
+    let t_scale = scale + u64::exact_from(max(exponent, 0)) + 8;
+    let (t, t_error) = fixed_from_significand(true, significand, low, t_scale);
+    let (s, s_error, s_exponent) = exp_fixed(t, t_error, t_scale, scale);
+    let shift = s_exponent + i64::exact_from(scale);
+    let (p, p_error) = if shift >= 0 {
+        (s << shift, s_error << shift)
+    } else {
+        (s >> -shift, (s_error >> -shift) + Natural::TWO)
+    };
+    let m = Natural::power_of_2(scale << 1) / &p;
+    let m_error = (&p_error << (scale << 1))
+        .div_round(&p * (&p - &p_error), RoundingMode::Ceiling)
+        .0
+        + Natural::ONE;
+    (p, p_error, m, m_error)
+}
+
+// Approximates $e^{|x|}/2$, for a finite, nonzero $x$ with absolute value $n2^\ell$, given by its
+// exponent, `significand` ($n$), and `low` ($\ell$). Returns `(s, error, exponent)`, as
+// `exp_fixed` does. If $|x| > w + 12$, where $w$ is `working_prec`, this is within a relative error
+// of $2^{-w-12}$ of both $\sinh|x|$ and $\cosh x$.
+pub(crate) fn half_exp_fixed(
+    exponent: i64,
+    significand: &Natural,
+    low: i64,
+    working_prec: u64,
+) -> (Natural, Natural, i64) {
+    let t_scale = working_prec + u64::exact_from(max(exponent, 0)) + 8;
+    let (t, t_error) = fixed_from_significand(true, significand, low, t_scale);
+    let (s, error, s_exponent) = exp_fixed(t, t_error, t_scale, working_prec);
+    let error = error + (&s >> (working_prec + 12)) + Natural::ONE;
+    (s, error, s_exponent - 1)
+}
+
+// Computes the hyperbolic sine of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $|x| < 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then
+// $\sinh x$ lies between $x$ and $x + x^3/3$, so it rounds like a value just farther from zero
+// than $x$. If $|x|$ is large, $e^{-|x|}$ is negligible, and $\sinh |x|$ is approximated by
+// $e^{|x|}/2$. Otherwise $\sinh |x| = (e^{|x|}-e^{-|x|})/2$, with enough fractional bits that the
+// cancellation for small $|x|$ does not lose relative precision. Since $\sinh x$ is transcendental
+// for any nonzero rational $x$, it can always be rounded correctly once it is approximated closely
+// enough.
+fn sinh_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, sign, prec, rm);
+    }
+    assert!(
+        exponent <= 62,
+        "The hyperbolic sine of a Float of this size cannot be represented"
+    );
+    let low = exponent - i64::exact_from(bits);
+    let small_bits = u64::exact_from(max(-exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error, exponent) =
+            if exponent > i64::exact_from((working_prec + 12).significant_bits()) {
+                half_exp_fixed(exponent, significand, low, working_prec)
+            } else {
+                let scale = working_prec + small_bits + 10;
+                let (p, p_error, m, m_error) = exp_pair_fixed(exponent, significand, low, scale);
+                (p - m, p_error + m_error, -i64::exact_from(scale) - 1)
+            };
+        (Integer::from_sign_and_abs(sign, value), error, exponent)
+    })
+}
+impl Float {
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by value. An [`Ordering`] is
+    /// also returned, indicating whether the rounded hyperbolic sine is less than, equal to, or
+    /// greater than the exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round`] instead. If both of these things are true, consider using
+    /// [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.54875");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sinh_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (sinh_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the specified precision
+    /// and with the specified rounding mode. The [`Float`] is taken by reference. An [`Ordering`]
+    /// is also returned, indicating whether the rounded hyperbolic sine is less than, equal to, or
+    /// greater than the exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=\infty$
+    /// - $f(-\infty,p,m)=-\infty$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "12.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.54875");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => sinh_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (sinh_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic sine is less than, equal to, or greater than the
+    /// exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic sine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec(20);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.sinh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result to the nearest value of the
+    /// specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic sine is less than, equal to, or greater than the
+    /// exact hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic sine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=\infty$
+    /// - $f(-\infty,p)=-\infty$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "11.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.sinh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether
+    /// the rounded hyperbolic sine is less than, equal to, or greater than the exact hyperbolic
+    /// sine. Although `NaN`s are not comparable to any [`Float`], whenever this function returns a
+    /// `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sinh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`], rounding the result with the specified rounding
+    /// mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic sine is less than, equal to, or greater than the exact
+    /// hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=\infty$
+    /// - $f(-\infty,m)=-\infty$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Sinh::sinh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sinh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sinh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`] in place, rounding the result to the specified
+    /// precision and with the specified rounding mode. An [`Ordering`] is returned, indicating
+    /// whether the rounded hyperbolic sine is less than, equal to, or greater than the exact
+    /// hyperbolic sine. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sinh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sinh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::sinh_round_assign`] instead. If both of these things are true, consider using
+    /// [`SinhAssign::sinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with
+    /// precision `prec`, if `prec` is zero, or if the exponent of the result is too large to be
+    /// represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "12.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.54874");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "11.54875");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// ```
+    pub fn sinh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (sinh, o) = take(self).sinh_prec_round(prec, rm);
+        *self = sinh;
+        o
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded hyperbolic sine is less than, equal to, or greater than the exact hyperbolic sine.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic sine is equidistant from two [`Float`]s with the specified precision, the
+    /// [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sinh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SinhAssign::sinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_assign(5), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.54874");
+    /// ```
+    #[inline]
+    pub fn sinh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.sinh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic sine of a [`Float`] in place, rounding the result with the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded hyperbolic sine
+    /// is less than, equal to, or greater than the exact hyperbolic sine. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\sinh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\sinh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::sinh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sinh_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SinhAssign::sinh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic sine is not exactly representable with the
+    /// precision of the input, or if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "11.548739357257748");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.sinh_round_assign(RoundingMode::Nearest), Ordering::Less);
+    /// assert_eq!(x.to_string(), "11.548739357257746");
+    /// ```
+    #[inline]
+    pub fn sinh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Sinh for Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic sine of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic sine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::sinh_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::sinh_round`].
+    /// If you want both of these things, consider using [`Float::sinh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sinh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).sinh().is_nan());
+    /// assert_eq!((Float::INFINITY).sinh(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).sinh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).sinh().to_string(), "2.1292794550948173");
+    /// assert_eq!((Float::from(-1.5)).sinh().to_string(), "-2.1292794550948173");
+    /// ```
+    #[inline]
+    fn sinh(self) -> Float {
+        let prec = self.significant_bits();
+        self.sinh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Sinh for &'a Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic sine of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic sine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=\infty$
+    /// - $f(-\infty)=-\infty$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sinh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::sinh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sinh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).sinh().is_nan());
+    /// assert_eq!((&Float::INFINITY).sinh(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).sinh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).sinh().to_string(), "2.1292794550948173");
+    /// assert_eq!((&Float::from(-1.5)).sinh().to_string(), "-2.1292794550948173");
+    /// ```
+    #[inline]
+    fn sinh(self) -> Float {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl SinhAssign for Float {
+    /// Computes the hyperbolic sine of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic sine is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \sinh x+\varepsilon.
+    /// $$
+    /// - If $\sinh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\sinh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\sinh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Sinh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sinh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::sinh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::sinh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the exponent of the result is too large to be represented.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SinhAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.sinh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.sinh_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.sinh_assign();
+    /// assert_eq!(x.to_string(), "2.1292794550948173");
+    /// ```
+    #[inline]
+    fn sinh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.sinh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,840 @@
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_infinity, float_nan, float_zero, Float};
+use malachite_base::num::arithmetic::traits::{Square, SquareAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::mem::take;
+
+// Returns the square of `x` if `x` is NaN, infinite, or zero.
+fn square_special(x: &Float) -> Float {
+    match x {
+        float_nan!() => float_nan!(),
+        Float(Infinity { .. }) => float_infinity!(),
+        Float(Zero { .. }) => float_zero!(),
+        Float(Finite { .. }) => unreachable!(),
+    }
+}
+
+// Given the exponent and significand of a finite, nonzero `Float`, returns the exponent of the
+// lowest bit of its square's significand.
+fn square_low_exponent(exponent: i64, significand: &Natural) -> i64 {
+    (exponent - i64::exact_from(significand.significant_bits())) << 1
+}
+
+impl Float {
+    /// Squares a [`Float`], rounding the result to the specified precision and with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded square is less than, equal to, or greater than the exact square.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function returns a `NaN`
+    /// it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::square_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::square_round`] instead. If both of these things are true, consider using
+    /// [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact squaring, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.86961");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn square_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                exponent,
+                significand,
+                ..
+            }) => {
+                let low = square_low_exponent(exponent, &significand);
+                Float::from_natural_times_power_of_2_prec_round(significand.square(), low, prec, rm)
+            }
+            x => (square_special(&x), Ordering::Equal),
+        }
+    }
+
+    /// Squares a [`Float`], rounding the result to the specified precision and with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded square is less than, equal to, or greater than the exact
+    /// square. Although `NaN`s are not comparable to any [`Float`], whenever this function returns
+    /// a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\pm\infty,p,m)=\infty$
+    /// - $f(\pm0.0,p,m)=0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::square_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::square_round_ref`] instead. If both of these things are true, consider using
+    /// [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact squaring, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.5");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.86961");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn square_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                exponent,
+                significand,
+                ..
+            }) => Float::from_natural_times_power_of_2_prec_round(
+                significand.square(),
+                square_low_exponent(*exponent, significand),
+                prec,
+                rm,
+            ),
+            x => (square_special(x), Ordering::Equal),
+        }
+    }
+
+    /// Squares a [`Float`], rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating whether the
+    /// rounded square is less than, equal to, or greater than the exact square. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the square is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec_round`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_prec(5);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec(20);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn square_prec(self, prec: u64) -> (Float, Ordering) {
+        self.square_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Squares a [`Float`], rounding the result to the nearest value of the specified precision.
+    /// The [`Float`] is taken by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded square is less than, equal to, or greater than the exact square. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// If the square is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\pm\infty,p)=\infty$
+    /// - $f(\pm0.0,p)=0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_ref(5);
+    /// assert_eq!(x.to_string(), "10.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_prec_ref(20);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn square_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.square_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Squares a [`Float`], rounding the result with the specified rounding mode. The [`Float`] is
+    /// taken by value. An [`Ordering`] is also returned, indicating whether the rounded square is
+    /// less than, equal to, or greater than the exact square. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::square_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.869604401089356");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn square_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.square_prec_round(prec, rm)
+    }
+
+    /// Squares a [`Float`], rounding the result with the specified rounding mode. The [`Float`] is
+    /// taken by reference. An [`Ordering`] is also returned, indicating whether the rounded square
+    /// is less than, equal to, or greater than the exact square. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\pm\infty,m)=\infty$
+    /// - $f(\pm0.0,m)=0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::square_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Square::square`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).square_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "9.869604401089356");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).square_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).square_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn square_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.square_prec_round_ref(prec, rm)
+    }
+
+    /// Squares a [`Float`] in place, rounding the result to the specified precision and with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded square
+    /// is less than, equal to, or greater than the exact square. Although `NaN`s are not comparable
+    /// to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::square_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::square_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::square_round_assign`] instead. If both of these things are true, consider using
+    /// [`SquareAssign::square_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact squaring, or if `prec` is
+    /// zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(5, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.5");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(5, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "10.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(5, RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "10.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(20, RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.8696");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(20, RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.86961");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_prec_round_assign(20, RoundingMode::Nearest),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// ```
+    pub fn square_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (square, o) = take(self).square_prec_round(prec, rm);
+        *self = square;
+        o
+    }
+
+    /// Squares a [`Float`] in place, rounding the result to the nearest value of the specified
+    /// precision. An [`Ordering`] is returned, indicating whether the rounded square is less than,
+    /// equal to, or greater than the exact square. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the square is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::square_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`SquareAssign::square_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.square_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "10.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.square_prec_assign(20), Ordering::Less);
+    /// assert_eq!(x.to_string(), "9.8696");
+    /// ```
+    #[inline]
+    pub fn square_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.square_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Squares a [`Float`] in place, rounding the result with the specified rounding mode. An
+    /// [`Ordering`] is returned, indicating whether the rounded square is less than, equal to, or
+    /// greater than the exact square. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $x^2$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x^2|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::square_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::square_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`SquareAssign::square_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the precision of the input is not high enough to represent the
+    /// output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.square_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "9.869604401089356");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_round_assign(RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.square_round_assign(RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "9.869604401089358");
+    /// ```
+    #[inline]
+    pub fn square_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.square_prec_round_assign(prec, rm)
+    }
+}
+
+impl Square for Float {
+    type Output = Float;
+
+    /// Squares a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::square_round`]. If you want both of these things, consider using
+    /// [`Float::square_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Square;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).square().is_nan());
+    /// assert_eq!((Float::INFINITY).square(), Float::INFINITY);
+    /// assert_eq!((Float::NEGATIVE_INFINITY).square(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).square()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).square(), 2.25);
+    /// assert_eq!((Float::from(-1.5)).square(), 2.25);
+    /// ```
+    #[inline]
+    fn square(self) -> Float {
+        let prec = self.significant_bits();
+        self.square_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Square for &'a Float {
+    type Output = Float;
+
+    /// Squares a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\pm\infty)=\infty$
+    /// - $f(\pm0.0)=0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::square_round_ref`]. If you want both of these things, consider using
+    /// [`Float::square_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Square;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).square().is_nan());
+    /// assert_eq!((&Float::INFINITY).square(), Float::INFINITY);
+    /// assert_eq!((&Float::NEGATIVE_INFINITY).square(), Float::INFINITY);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).square()),
+    ///     ComparableFloat(Float::ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).square(), 2.25);
+    /// assert_eq!((&Float::from(-1.5)).square(), 2.25);
+    /// ```
+    #[inline]
+    fn square(self) -> Float {
+        let prec = self.significant_bits();
+        self.square_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl SquareAssign for Float {
+    /// Squares a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the square is
+    /// equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s in
+    /// its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = x^2+\varepsilon.
+    /// $$
+    /// - If $x^2$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x^2$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x^2|\rfloor-p}$,
+    ///   where $p$ is the precision of the input.
+    ///
+    /// See the [`Square`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::square_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::square_round_assign`]. If you want both of these things, consider using
+    /// [`Float::square_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SquareAssign;
+    /// use malachite_base::num::basic::traits::{
+    ///     Infinity, NaN, NegativeInfinity, NegativeZero, Zero,
+    /// };
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.square_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_INFINITY;
+    /// x.square_assign();
+    /// assert_eq!(x, Float::INFINITY);
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.square_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::ZERO));
+    ///
+    /// let mut x = Float::from(-1.5);
+    /// x.square_assign();
+    /// assert_eq!(x, 2.25);
+    /// ```
+    #[inline]
+    fn square_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.square_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
@@ -0,0 +1,2047 @@
+use crate::arithmetic::add_mul::{
+    add_mul_prec_round_ref_ref_ref_helper, add_mul_prec_round_val_ref_ref_helper,
+    add_mul_prec_round_val_val_val_helper,
+};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{SubMul, SubMulAssign};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+impl Float {
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// value. An [`Ordering`] is also returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x-yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sub_mul_prec`] instead. If
+    /// you know that your target precision is the maximum of the precisions of the three inputs,
+    /// consider using [`Float::sub_mul_round`] instead. If both of these things are true, consider
+    /// using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-subtract, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.72");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702638");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_round(
+        self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_val_val_val_helper(self, y, z, true, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. The first [`Float`] is taken by
+    /// value and the other two by reference. An [`Ordering`] is also returned, indicating whether
+    /// the rounded result is less than, equal to, or greater than the exact result. Although `NaN`s
+    /// are not comparable to any [`Float`], whenever this function returns a `NaN` it also returns
+    /// `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x-yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sub_mul_prec_val_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::sub_mul_round_val_ref_ref`] instead. If both of these
+    /// things are true, consider using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-subtract, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.72");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702638");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_round_val_ref_ref(
+        self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_val_ref_ref_helper(self, y, z, true, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the
+    /// specified precision and with the specified rounding mode. All three [`Float`]s are taken by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The result is computed with a single rounding, as if $x-yz$ were first computed exactly.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,p,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p,m)=f(x,\text{NaN},z,p,m)=f(x,y,\text{NaN},p,m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p,m)=f(x,\pm0.0,\pm\infty,p,m)=\text{NaN}$
+    /// - $f(\infty,y,z,p,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,p,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,p,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,p,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,p,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,p,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sub_mul_prec_ref_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::sub_mul_round_ref_ref_ref`] instead. If both of these
+    /// things are true, consider using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-subtract, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.72");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     5,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702638");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     20,
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        add_mul_prec_round_ref_ref_ref_helper(self, y, z, true, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by value. An [`Ordering`]
+    /// is also returned, indicating whether the rounded result is less than, equal to, or greater
+    /// than the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_round`] instead. If you know that your target precision is the maximum
+    /// of the precisions of the three inputs, consider using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec(Float::from(E), Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_prec(Float::from(E), Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec(self, y: Float, z: Float, prec: u64) -> (Float, Ordering) {
+        self.sub_mul_prec_round(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. The first [`Float`] is taken by value and the other two by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_round_val_ref_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the three inputs, consider using [`SubMul::sub_mul`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).sub_mul_prec_val_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).sub_mul_prec_val_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_val_ref_ref(self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.sub_mul_prec_round_val_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result to the nearest
+    /// value of the specified precision. All three [`Float`]s are taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z,p) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,p)=f(x,\text{NaN},z,p)=f(x,y,\text{NaN},p)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,p)=f(x,\pm0.0,\pm\infty,p)=\text{NaN}$
+    /// - $f(\infty,y,z,p)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,p)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,p)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,p)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,p)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,p)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,p)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,p)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z,p)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z,p)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_round_ref_ref_ref`] instead. If you know that your target precision is
+    /// the maximum of the precisions of the three inputs, consider using [`SubMul::sub_mul`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).sub_mul_prec_ref_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5);
+    /// assert_eq!(x.to_string(), "-0.69");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from(PI).sub_mul_prec_ref_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20);
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_ref_ref_ref(&self, y: &Float, z: &Float, prec: u64) -> (Float, Ordering) {
+        self.sub_mul_prec_round_ref_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by value. An [`Ordering`] is also
+    /// returned, indicating whether the rounded result is less than, equal to, or greater than the
+    /// exact result. Although `NaN`s are not comparable to any [`Float`], whenever this function
+    /// returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using [`Float::sub_mul_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693239");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round(
+    ///     Float::from(E),
+    ///     Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sub_mul_round(self, y: Float, z: Float, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round(y, z, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result with the
+    /// specified rounding mode. The first [`Float`] is taken by value and the other two by
+    /// reference. An [`Ordering`] is also returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sub_mul_prec_round_val_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693239");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_val_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sub_mul_round_val_ref_ref(
+        self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_val_ref_ref(y, z, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], rounding the result with the
+    /// specified rounding mode. All three [`Float`]s are taken by reference. An [`Ordering`] is
+    /// also returned, indicating whether the rounded result is less than, equal to, or greater than
+    /// the exact result. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,y,z,m) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z,m)=f(x,\text{NaN},z,m)=f(x,y,\text{NaN},m)=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0,m)=f(x,\pm0.0,\pm\infty,m)=\text{NaN}$
+    /// - $f(\infty,y,z,m)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z,m)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z,m)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z,m)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z,m)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z,m)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z,m)=0.0$ if $yz=0.0$ and $m$ is not `Floor`
+    /// - $f(-0.0,y,z,m)=0.0$ if $yz=-0.0$ and $m$ is not `Floor`
+    /// - $f(0.0,y,z,m)=-0.0$ if $yz=0.0$ and $m$ is `Floor`
+    /// - $f(-0.0,y,z,m)=-0.0$ if $yz=-0.0$ and $m$ is `Floor`
+    /// - $f(x,y,z,m)=0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is not `Floor`
+    /// - $f(x,y,z,m)=-0.0$ if $x=yz$ and $x$ is finite and nonzero and $m$ is `Floor`
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sub_mul_prec_round_ref_ref_ref`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`SubMul::sub_mul`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Floor,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693239");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Ceiling,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).sub_mul_round_ref_ref_ref(
+    ///     &Float::from(E),
+    ///     &Float::from(SQRT_2),
+    ///     RoundingMode::Nearest,
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn sub_mul_round_ref_ref_ref(
+        &self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> (Float, Ordering) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_ref_ref_ref(y, z, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. The two [`Float`]s on the
+    /// right-hand side are taken by value. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sub_mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sub_mul_prec_assign`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::sub_mul_round_assign`] instead. If both of these
+    /// things are true, consider using [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-subtract, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.72");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702638");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign(
+    ///         Float::from(E),
+    ///         Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// ```
+    pub fn sub_mul_prec_round_assign(
+        &mut self,
+        y: Float,
+        z: Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (result, o) = add_mul_prec_round_val_val_val_helper(take(self), y, z, true, prec, rm);
+        *self = result;
+        o
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result to
+    /// the specified precision and with the specified rounding mode. The two [`Float`]s on the
+    /// right-hand side are taken by reference. An [`Ordering`] is returned, indicating whether the
+    /// rounded result is less than, equal to, or greater than the exact result. Although `NaN`s are
+    /// not comparable to any [`Float`], whenever this function sets the [`Float`] to `NaN` it also
+    /// returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sub_mul_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::sub_mul_prec_assign_ref_ref`]
+    /// instead. If you know that your target precision is the maximum of the precisions of the
+    /// three inputs, consider using [`Float::sub_mul_round_assign_ref_ref`] instead. If both of
+    /// these things are true, consider using [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but `prec` is too small for an exact fused multiply-subtract, or
+    /// if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.72");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         5,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702638");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         20,
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// ```
+    pub fn sub_mul_prec_round_assign_ref_ref(
+        &mut self,
+        y: &Float,
+        z: &Float,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let (result, o) = add_mul_prec_round_val_ref_ref_helper(take(self), y, z, true, prec, rm);
+        *self = result;
+        o
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result to
+    /// the nearest value of the specified precision. The two [`Float`]s on the right-hand side are
+    /// taken by value. An [`Ordering`] is returned, indicating whether the rounded result is less
+    /// than, equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sub_mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_round_assign`] instead. If you know that your target precision is the
+    /// maximum of the precisions of the three inputs, consider using
+    /// [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_assign(Float::from(E), Float::from(SQRT_2), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_assign(Float::from(E), Float::from(SQRT_2), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_assign(&mut self, y: Float, z: Float, prec: u64) -> Ordering {
+        self.sub_mul_prec_round_assign(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result to
+    /// the nearest value of the specified precision. The two [`Float`]s on the right-hand side are
+    /// taken by reference. An [`Ordering`] is returned, indicating whether the rounded result is
+    /// less than, equal to, or greater than the exact result. Although `NaN`s are not comparable to
+    /// any [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the result is equidistant from two [`Float`]s with the specified precision, the [`Float`]
+    /// with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of
+    /// the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::sub_mul_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_round_assign_ref_ref`] instead. If you know that your target precision
+    /// is the maximum of the precisions of the three inputs, consider using
+    /// [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_assign_ref_ref(&Float::from(E), &Float::from(SQRT_2), 5),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.69");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_prec_assign_ref_ref(&Float::from(E), &Float::from(SQRT_2), 20),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.702639");
+    /// ```
+    #[inline]
+    pub fn sub_mul_prec_assign_ref_ref(&mut self, y: &Float, z: &Float, prec: u64) -> Ordering {
+        self.sub_mul_prec_round_assign_ref_ref(y, z, prec, RoundingMode::Nearest)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. The two [`Float`]s on the right-hand side are taken by value.
+    /// An [`Ordering`] is returned, indicating whether the rounded result is less than, equal to,
+    /// or greater than the exact result. Although `NaN`s are not comparable to any [`Float`],
+    /// whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::sub_mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sub_mul_prec_round_assign`] instead. If you know you'll be using the `Nearest`
+    /// rounding mode, consider using [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Floor),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693239");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Ceiling),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign(Float::from(E), Float::from(SQRT_2), RoundingMode::Nearest),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// ```
+    #[inline]
+    pub fn sub_mul_round_assign(&mut self, y: Float, z: Float, rm: RoundingMode) -> Ordering {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign(y, z, prec, rm)
+    }
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, rounding the result with
+    /// the specified rounding mode. The two [`Float`]s on the right-hand side are taken by
+    /// reference. An [`Ordering`] is returned, indicating whether the rounded result is less than,
+    /// equal to, or greater than the exact result. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the maximum of the precision of the inputs. See
+    /// [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p+1}$, where $p$ is the maximum precision of the inputs.
+    /// - If $x-yz$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |x-yz|\rfloor-p}$, where $p$ is the maximum precision of the inputs.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs.
+    ///
+    /// See the [`Float::sub_mul_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using
+    /// [`Float::sub_mul_prec_round_assign_ref_ref`] instead. If you know you'll be using the
+    /// `Nearest` rounding mode, consider using [`SubMulAssign::sub_mul_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the maximum precision of the inputs is not high enough to
+    /// represent the output.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::{E, PI, SQRT_2};
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Floor
+    ///     ),
+    ///     Ordering::Less
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693239");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Ceiling
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(
+    ///     x.sub_mul_round_assign_ref_ref(
+    ///         &Float::from(E),
+    ///         &Float::from(SQRT_2),
+    ///         RoundingMode::Nearest
+    ///     ),
+    ///     Ordering::Greater
+    /// );
+    /// assert_eq!(x.to_string(), "-0.7026383745693238");
+    /// ```
+    #[inline]
+    pub fn sub_mul_round_assign_ref_ref(
+        &mut self,
+        y: &Float,
+        z: &Float,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign_ref_ref(y, z, prec, rm)
+    }
+}
+
+impl SubMul<Float, Float> for Float {
+    type Output = Float;
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], taking all three by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec`] instead. If you want to specify the output precision, consider using
+    /// [`Float::sub_mul_round`]. If you want both of these things, consider using
+    /// [`Float::sub_mul_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(Float::from(3.0), Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(Float::from(-3.0), Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .sub_mul(Float::INFINITY, Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn sub_mul(self, y: Float, z: Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round(y, z, prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> SubMul<Float, &'a Float> for Float {
+    type Output = Float;
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], taking the first two by value and
+    /// the third by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(Float::from(3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(Float::from(-3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .sub_mul(Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn sub_mul(self, y: Float, z: &'a Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_val_ref_ref(&y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a> SubMul<&'a Float, Float> for Float {
+    type Output = Float;
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], taking the first and third by
+    /// value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(&Float::from(3.0), Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(&Float::from(-3.0), Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .sub_mul(&Float::INFINITY, Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn sub_mul(self, y: &'a Float, z: Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_val_ref_ref(y, &z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b> SubMul<&'a Float, &'b Float> for Float {
+    type Output = Float;
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], taking the first by value and the
+    /// second and third by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_val_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_val_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_val_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(&Float::from(3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert_eq!(
+    ///     Float::from(2.0).sub_mul(&Float::from(-3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert!(Float::from(2.0)
+    ///     .sub_mul(&Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn sub_mul(self, y: &'a Float, z: &'b Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_val_ref_ref(y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl<'a, 'b, 'c> SubMul<&'a Float, &'b Float> for &'c Float {
+    type Output = Float;
+
+    /// Subtracts the product of two [`Float`]s from a [`Float`], taking all three by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,y,z) = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// Special cases, where $yz$ denotes the exact product of $y$ and $z$, including its sign if it
+    /// is zero:
+    /// - $f(\text{NaN},y,z)=f(x,\text{NaN},z)=f(x,y,\text{NaN})=\text{NaN}$
+    /// - $f(x,\pm\infty,\pm0.0)=f(x,\pm0.0,\pm\infty)=\text{NaN}$
+    /// - $f(\infty,y,z)=\text{NaN}$ if $yz=\infty$
+    /// - $f(-\infty,y,z)=\text{NaN}$ if $yz=-\infty$
+    /// - $f(\infty,y,z)=\infty$ if $yz$ is not NaN or $\infty$
+    /// - $f(-\infty,y,z)=-\infty$ if $yz$ is not NaN or $-\infty$
+    /// - $f(x,y,z)=-\infty$ if $yz=\infty$ and $x$ is not NaN or $\infty$
+    /// - $f(x,y,z)=\infty$ if $yz=-\infty$ and $x$ is not NaN or $-\infty$
+    /// - $f(0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(-0.0,y,z)=-0.0$ if $yz=0.0$
+    /// - $f(0.0,y,z)=0.0$ if $yz=0.0$
+    /// - $f(-0.0,y,z)=0.0$ if $yz=-0.0$
+    /// - $f(x,y,z)=0.0$ if $x=yz$ and $x$ is finite and nonzero
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_ref_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_ref_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_ref_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMul;
+    /// use malachite_base::num::basic::traits::{Infinity, Zero};
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     (&Float::from(2.0)).sub_mul(&Float::from(3.0), &Float::from(4.5)),
+    ///     -11.5
+    /// );
+    /// assert_eq!(
+    ///     (&Float::from(2.0)).sub_mul(&Float::from(-3.0), &Float::from(4.5)),
+    ///     15.5
+    /// );
+    /// assert!((&Float::from(2.0))
+    ///     .sub_mul(&Float::INFINITY, &Float::ZERO)
+    ///     .is_nan());
+    /// ```
+    #[inline]
+    fn sub_mul(self, y: &'a Float, z: &'b Float) -> Float {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_ref_ref_ref(y, z, prec, RoundingMode::Nearest)
+            .0
+    }
+}
+
+impl SubMulAssign<Float, Float> for Float {
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, taking both [`Float`]s on
+    /// the right-hand side by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`SubMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_assign`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_assign`]. If you want both of these things, consider
+    /// using [`Float::sub_mul_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(Float::from(3.0), Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(Float::from(-3.0), Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    /// ```
+    #[inline]
+    fn sub_mul_assign(&mut self, y: Float, z: Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign(y, z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> SubMulAssign<Float, &'a Float> for Float {
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, taking the first
+    /// [`Float`] on the right-hand side by value and the second by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`SubMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(Float::from(3.0), &Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(Float::from(-3.0), &Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    /// ```
+    #[inline]
+    fn sub_mul_assign(&mut self, y: Float, z: &'a Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign_ref_ref(&y, z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a> SubMulAssign<&'a Float, Float> for Float {
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, taking the first
+    /// [`Float`] on the right-hand side by reference and the second by value.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`SubMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(&Float::from(3.0), Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(&Float::from(-3.0), Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    /// ```
+    #[inline]
+    fn sub_mul_assign(&mut self, y: &'a Float, z: Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign_ref_ref(y, &z, prec, RoundingMode::Nearest);
+    }
+}
+
+impl<'a, 'b> SubMulAssign<&'a Float, &'b Float> for Float {
+    /// Subtracts the product of two [`Float`]s from a [`Float`] in place, taking both [`Float`]s on
+    /// the right-hand side by reference.
+    ///
+    /// If the output has a precision, it is the maximum of the precisions of the inputs. If the
+    /// result is equidistant from two [`Float`]s with the specified precision, the [`Float`] with
+    /// fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a description of the
+    /// `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = x-yz+\varepsilon.
+    /// $$
+    /// - If $x-yz$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $x-yz$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |x-yz|\rfloor-p}$,
+    ///   where $p$ is the maximum precision of the inputs.
+    ///
+    /// See the [`SubMul`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::sub_mul_prec_assign_ref_ref`] instead. If you want to specify the output precision,
+    /// consider using [`Float::sub_mul_round_assign_ref_ref`]. If you want both of these things,
+    /// consider using [`Float::sub_mul_prec_round_assign_ref_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), y.significant_bits(), z.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::SubMulAssign;
+    /// use malachite_float::Float;
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(&Float::from(3.0), &Float::from(4.5));
+    /// assert_eq!(x, -11.5);
+    ///
+    /// let mut x = Float::from(2.0);
+    /// x.sub_mul_assign(&Float::from(-3.0), &Float::from(4.5));
+    /// assert_eq!(x, 15.5);
+    /// ```
+    #[inline]
+    fn sub_mul_assign(&mut self, y: &'a Float, z: &'b Float) {
+        let prec = max(
+            max(self.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        self.sub_mul_prec_round_assign_ref_ref(y, z, prec, RoundingMode::Nearest);
+    }
+}
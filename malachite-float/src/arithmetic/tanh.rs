@@ -0,0 +1,856 @@
+use crate::arithmetic::exp::{round_approximation, round_near_x};
+use crate::arithmetic::ln::div_fixed;
+use crate::arithmetic::sinh::exp_pair_fixed;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use crate::{float_nan, Float};
+use malachite_base::num::arithmetic::traits::{Tanh, TanhAssign};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cmp::{max, Ordering};
+use std::mem::take;
+
+// Returns the hyperbolic tangent of `x` if `x` is NaN, infinite, or zero. The hyperbolic tangent
+// of $\pm\infty$ is $\pm1$, which is returned with precision `prec`.
+fn tanh_special(x: &Float, prec: u64) -> Float {
+    match x {
+        Float(Infinity { sign: true }) => Float::one_prec(prec),
+        Float(Infinity { sign: false }) => Float::negative_one_prec(prec),
+        Float(Zero { sign }) => Float(Zero { sign: *sign }),
+        _ => float_nan!(),
+    }
+}
+
+// Computes the hyperbolic tangent of a finite, nonzero `Float`, given its sign, exponent, and
+// significand.
+//
+// If $|x| < 2^{-(\max(b,p)+2)/2}$, where $b$ is the number of bits of the significand, then
+// $\tanh x$ lies between $x$ and $x - x^3/3$, so it rounds like a value just closer to zero than
+// $x$. If $|x| > p + 3$, then $1 - \tanh|x| < 2e^{-2|x|} < 2^{-p-2}$, so $\tanh x$ rounds like a
+// value just closer to zero than $\pm1$. Otherwise $\tanh|x| = (e^{|x|}-e^{-|x|})/(e^{|x|}+
+// e^{-|x|})$. Since $\tanh x$ is transcendental for any nonzero rational $x$, it can always be
+// rounded correctly once it is approximated closely enough.
+fn tanh_finite_prec_round(
+    sign: bool,
+    exponent: i64,
+    significand: &Natural,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let bits = significand.significant_bits();
+    if exponent << 1 <= -i64::exact_from(max(bits, prec)) - 2 {
+        return round_near_x(sign, exponent, significand, !sign, prec, rm);
+    } else if exponent > i64::exact_from((prec + 3).significant_bits()) {
+        return round_near_x(sign, 1, &Natural::ONE, !sign, prec, rm);
+    }
+    let low = exponent - i64::exact_from(bits);
+    let small_bits = u64::exact_from(max(-exponent, 0));
+    round_approximation(prec, rm, |working_prec| {
+        let scale = working_prec + small_bits + 10;
+        let (p, p_error, m, m_error) = exp_pair_fixed(exponent, significand, low, scale);
+        let error = p_error + m_error;
+        let (value, error) = div_fixed(Integer::from(&p - &m), &error, &(p + m), &error, scale);
+        (
+            if sign { value } else { -value },
+            error,
+            -i64::exact_from(scale),
+        )
+    })
+}
+impl Float {
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by value. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic tangent is less
+    /// than, equal to, or greater than the exact hyperbolic tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec`] instead. If you
+    /// know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round`] instead. If both of these things are true, consider using
+    /// [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.97");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.996271");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_round(self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => tanh_finite_prec_round(sign, exponent, &significand, prec, rm),
+            x => (tanh_special(&x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the specified
+    /// precision and with the specified rounding mode. The [`Float`] is taken by reference. An
+    /// [`Ordering`] is also returned, indicating whether the rounded hyperbolic tangent is less
+    /// than, equal to, or greater than the exact hyperbolic tangent. Although `NaN`s are not
+    /// comparable to any [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,p,m) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p,m)=\text{NaN}$
+    /// - $f(\infty,p,m)=1.0$
+    /// - $f(-\infty,p,m)=-1.0$
+    /// - $f(0.0,p,m)=0.0$
+    /// - $f(-0.0,p,m)=-0.0$
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec_ref`] instead. If
+    /// you know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round_ref`] instead. If both of these things are true, consider using
+    /// [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.97");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(5, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.996271");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_round_ref(20, RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_round_ref(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        match self {
+            Float(Finite {
+                sign,
+                exponent,
+                significand,
+                ..
+            }) => tanh_finite_prec_round(*sign, *exponent, significand, prec, rm),
+            x => (tanh_special(x, prec), Ordering::Equal),
+        }
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic tangent is less than, equal to, or greater than
+    /// the exact hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic tangent is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=1.0$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round`] instead. If you know that your target precision is the precision
+    /// of the input, consider using [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec(5);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec(20);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec(self, prec: u64) -> (Float, Ordering) {
+        self.tanh_prec_round(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result to the nearest value of
+    /// the specified precision. The [`Float`] is taken by reference. An [`Ordering`] is also
+    /// returned, indicating whether the rounded hyperbolic tangent is less than, equal to, or
+    /// greater than the exact hyperbolic tangent. Although `NaN`s are not comparable to any
+    /// [`Float`], whenever this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic tangent is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// f(x,p) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},p)=\text{NaN}$
+    /// - $f(\infty,p)=1.0$
+    /// - $f(-\infty,p)=-1.0$
+    /// - $f(0.0,p)=0.0$
+    /// - $f(-0.0,p)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round_ref`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_ref(5);
+    /// assert_eq!(x.to_string(), "1.0");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_prec_ref(20);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_prec_ref(&self, prec: u64) -> (Float, Ordering) {
+        self.tanh_prec_round_ref(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by value. An [`Ordering`] is also returned, indicating
+    /// whether the rounded hyperbolic tangent is less than, equal to, or greater than the exact
+    /// hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever this
+    /// function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=1.0$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_round(self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tanh_prec_round(prec, rm)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`], rounding the result with the specified
+    /// rounding mode. The [`Float`] is taken by reference. An [`Ordering`] is also returned,
+    /// indicating whether the rounded hyperbolic tangent is less than, equal to, or greater than
+    /// the exact hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function returns a `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// f(x,m) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN},m)=\text{NaN}$
+    /// - $f(\infty,m)=1.0$
+    /// - $f(-\infty,m)=-1.0$
+    /// - $f(0.0,m)=0.0$
+    /// - $f(-0.0,m)=-0.0$
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round_ref`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`Tanh::tanh`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from(PI).tanh_round_ref(RoundingMode::Nearest);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn tanh_round_ref(&self, rm: RoundingMode) -> (Float, Ordering) {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_ref(prec, rm)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`] in place, rounding the result to the
+    /// specified precision and with the specified rounding mode. An [`Ordering`] is returned,
+    /// indicating whether the rounded hyperbolic tangent is less than, equal to, or greater than
+    /// the exact hyperbolic tangent. Although `NaN`s are not comparable to any [`Float`], whenever
+    /// this function sets the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// See [`RoundingMode`] for a description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tanh_prec_round`] documentation for information on special cases.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::tanh_prec_assign`] instead.
+    /// If you know that your target precision is the precision of the input, consider using
+    /// [`Float::tanh_round_assign`] instead. If both of these things are true, consider using
+    /// [`TanhAssign::tanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with
+    /// precision `prec`, or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(5, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.97");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(5, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(5, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(20, RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.996271");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(20, RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.996272");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_round_assign(20, RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// ```
+    pub fn tanh_prec_round_assign(&mut self, prec: u64, rm: RoundingMode) -> Ordering {
+        let (tanh, o) = take(self).tanh_prec_round(prec, rm);
+        *self = tanh;
+        o
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`] in place, rounding the result to the nearest
+    /// value of the specified precision. An [`Ordering`] is returned, indicating whether the
+    /// rounded hyperbolic tangent is less than, equal to, or greater than the exact hyperbolic
+    /// tangent. Although `NaN`s are not comparable to any [`Float`], whenever this function sets
+    /// the [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// If the hyperbolic tangent is equidistant from two [`Float`]s with the specified precision,
+    /// the [`Float`] with fewer 1s in its binary expansion is chosen. See [`RoundingMode`] for a
+    /// description of the `Nearest` rounding mode.
+    ///
+    /// $$
+    /// x \gets = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$.
+    ///
+    /// If the output has a precision, it is `prec`.
+    ///
+    /// See the [`Float::tanh_prec`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_round_assign`] instead. If you know that your target precision is the
+    /// precision of the input, consider using [`TanhAssign::tanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.significant_bits(), prec)`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_assign(5), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "1.0");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_prec_assign(20), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.996272");
+    /// ```
+    #[inline]
+    pub fn tanh_prec_assign(&mut self, prec: u64) -> Ordering {
+        self.tanh_prec_round_assign(prec, RoundingMode::Nearest)
+    }
+
+    /// Computes the hyperbolic tangent of a [`Float`] in place, rounding the result with the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// hyperbolic tangent is less than, equal to, or greater than the exact hyperbolic tangent.
+    /// Although `NaN`s are not comparable to any [`Float`], whenever this function sets the
+    /// [`Float`] to `NaN` it also returns `Equal`.
+    ///
+    /// The precision of the output is the precision of the input. See [`RoundingMode`] for a
+    /// description of the possible rounding modes.
+    ///
+    /// $$
+    /// x \gets = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is not `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p+1}$, where $p$ is the precision of the input.
+    /// - If $\tanh x$ is finite and nonzero and $m$ is `Nearest`, then $|\varepsilon| <
+    ///   2^{\lfloor\log_2 |\tanh x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// If the output has a precision, it is the precision of the input.
+    ///
+    /// See the [`Float::tanh_round`] documentation for information on special cases.
+    ///
+    /// If you want to specify an output precision, consider using [`Float::tanh_prec_round_assign`]
+    /// instead. If you know you'll be using the `Nearest` rounding mode, consider using
+    /// [`TanhAssign::tanh_assign`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the hyperbolic tangent is not exactly representable with the
+    /// precision of the input.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    /// use std::f64::consts::PI;
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_round_assign(RoundingMode::Floor), Ordering::Less);
+    /// assert_eq!(x.to_string(), "0.9962720762207499");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_round_assign(RoundingMode::Ceiling), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    ///
+    /// let mut x = Float::from(PI);
+    /// assert_eq!(x.tanh_round_assign(RoundingMode::Nearest), Ordering::Greater);
+    /// assert_eq!(x.to_string(), "0.99627207622075");
+    /// ```
+    #[inline]
+    pub fn tanh_round_assign(&mut self, rm: RoundingMode) -> Ordering {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_assign(prec, rm)
+    }
+}
+
+impl Tanh for Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic tangent of a [`Float`]. The [`Float`] is taken by value.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic tangent
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using [`Float::tanh_prec`]
+    /// instead. If you want to specify the output precision, consider using [`Float::tanh_round`].
+    /// If you want both of these things, consider using [`Float::tanh_prec_round`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Tanh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((Float::NAN).tanh().is_nan());
+    /// assert_eq!((Float::INFINITY).tanh(), Float::ONE);
+    /// assert_eq!(
+    ///     ComparableFloat((Float::NEGATIVE_ZERO).tanh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((Float::from(1.5)).tanh().to_string(), "0.9051482536448664");
+    /// assert_eq!((Float::from(-1.5)).tanh().to_string(), "-0.9051482536448664");
+    /// ```
+    #[inline]
+    fn tanh(self) -> Float {
+        let prec = self.significant_bits();
+        self.tanh_prec_round(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl<'a> Tanh for &'a Float {
+    type Output = Float;
+
+    /// Computes the hyperbolic tangent of a [`Float`]. The [`Float`] is taken by reference.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic tangent
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// f(x) = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// Special cases:
+    /// - $f(\text{NaN})=\text{NaN}$
+    /// - $f(\infty)=1.0$
+    /// - $f(-\infty)=-1.0$
+    /// - $f(0.0)=0.0$
+    /// - $f(-0.0)=-0.0$
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_ref`] instead. If you want to specify the output precision, consider
+    /// using [`Float::tanh_round_ref`]. If you want both of these things, consider using
+    /// [`Float::tanh_prec_round_ref`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Tanh;
+    /// use malachite_base::num::basic::traits::{Infinity, NaN, NegativeZero, One};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// assert!((&Float::NAN).tanh().is_nan());
+    /// assert_eq!((&Float::INFINITY).tanh(), Float::ONE);
+    /// assert_eq!(
+    ///     ComparableFloat((&Float::NEGATIVE_ZERO).tanh()),
+    ///     ComparableFloat(Float::NEGATIVE_ZERO)
+    /// );
+    ///
+    /// assert_eq!((&Float::from(1.5)).tanh().to_string(), "0.9051482536448664");
+    /// assert_eq!((&Float::from(-1.5)).tanh().to_string(), "-0.9051482536448664");
+    /// ```
+    #[inline]
+    fn tanh(self) -> Float {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_ref(prec, RoundingMode::Nearest).0
+    }
+}
+
+impl TanhAssign for Float {
+    /// Computes the hyperbolic tangent of a [`Float`] in place.
+    ///
+    /// If the output has a precision, it is the precision of the input. If the hyperbolic tangent
+    /// is equidistant from two [`Float`]s with the specified precision, the [`Float`] with fewer 1s
+    /// in its binary expansion is chosen. See [`RoundingMode`] for a description of the `Nearest`
+    /// rounding mode.
+    ///
+    /// $$
+    /// x \gets = \tanh x+\varepsilon.
+    /// $$
+    /// - If $\tanh x$ is infinite, zero, or `NaN`, $\varepsilon$ may be ignored or assumed to be 0.
+    /// - If $\tanh x$ is finite and nonzero, then $|\varepsilon| < 2^{\lfloor\log_2 |\tanh
+    ///   x|\rfloor-p}$, where $p$ is the precision of the input.
+    ///
+    /// See the [`Tanh`] documentation for information on special cases.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::tanh_prec_assign`] instead. If you want to specify the output precision, consider
+    /// using [`Float::tanh_round_assign`]. If you want both of these things, consider using
+    /// [`Float::tanh_prec_round_assign`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{3/2} \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::TanhAssign;
+    /// use malachite_base::num::basic::traits::{NaN, NegativeZero};
+    /// use malachite_float::{ComparableFloat, Float};
+    ///
+    /// let mut x = Float::NAN;
+    /// x.tanh_assign();
+    /// assert!(x.is_nan());
+    ///
+    /// let mut x = Float::NEGATIVE_ZERO;
+    /// x.tanh_assign();
+    /// assert_eq!(ComparableFloat(x), ComparableFloat(Float::NEGATIVE_ZERO));
+    ///
+    /// let mut x = Float::from(1.5);
+    /// x.tanh_assign();
+    /// assert_eq!(x.to_string(), "0.9051482536448664");
+    /// ```
+    #[inline]
+    fn tanh_assign(&mut self) {
+        let prec = self.significant_bits();
+        self.tanh_prec_round_assign(prec, RoundingMode::Nearest);
+    }
+}
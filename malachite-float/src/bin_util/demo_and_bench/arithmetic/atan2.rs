@@ -0,0 +1,1141 @@
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    pair_2_pair_float_max_complexity_bucketer,
+    pair_2_quadruple_1_2_3_max_float_float_prec_bucketer,
+    pair_2_triple_1_2_3_max_float_float_prec_bucketer,
+    pair_2_triple_1_2_float_max_complexity_bucketer, pair_float_max_complexity_bucketer,
+    quadruple_1_2_3_max_float_float_prec_bucketer, triple_1_2_3_max_float_float_prec_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_5, float_float_rounding_mode_triple_gen_var_5_rm,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_7,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_7_rm,
+    float_float_unsigned_triple_gen_var_1, float_float_unsigned_triple_gen_var_1_rm,
+    float_pair_gen, float_pair_gen_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::cmp::max;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_atan2);
+    register_demo!(runner, demo_float_atan2_debug);
+    register_demo!(runner, demo_float_atan2_val_ref);
+    register_demo!(runner, demo_float_atan2_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_ref_val);
+    register_demo!(runner, demo_float_atan2_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_ref_ref);
+    register_demo!(runner, demo_float_atan2_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_assign);
+    register_demo!(runner, demo_float_atan2_assign_debug);
+    register_demo!(runner, demo_float_atan2_assign_ref);
+    register_demo!(runner, demo_float_atan2_assign_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec);
+    register_demo!(runner, demo_float_atan2_prec_debug);
+    register_demo!(runner, demo_float_atan2_prec_val_ref);
+    register_demo!(runner, demo_float_atan2_prec_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_ref_val);
+    register_demo!(runner, demo_float_atan2_prec_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_prec_ref_ref);
+    register_demo!(runner, demo_float_atan2_prec_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_assign);
+    register_demo!(runner, demo_float_atan2_prec_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec_assign_ref);
+    register_demo!(runner, demo_float_atan2_prec_assign_ref_debug);
+    register_demo!(runner, demo_float_atan2_round);
+    register_demo!(runner, demo_float_atan2_round_debug);
+    register_demo!(runner, demo_float_atan2_round_val_ref);
+    register_demo!(runner, demo_float_atan2_round_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_round_ref_val);
+    register_demo!(runner, demo_float_atan2_round_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_round_ref_ref);
+    register_demo!(runner, demo_float_atan2_round_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_round_assign);
+    register_demo!(runner, demo_float_atan2_round_assign_debug);
+    register_demo!(runner, demo_float_atan2_round_assign_ref);
+    register_demo!(runner, demo_float_atan2_round_assign_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round);
+    register_demo!(runner, demo_float_atan2_prec_round_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_val_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_val_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_val);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_val_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_ref_ref_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_assign);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_debug);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_ref);
+    register_demo!(runner, demo_float_atan2_prec_round_assign_ref_debug);
+
+    register_bench!(runner, benchmark_float_atan2_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_library_comparison);
+    register_bench!(runner, benchmark_float_atan2_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_prec_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atan2_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atan2_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atan2_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atan2_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_atan2(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).atan2({}) = {}",
+            x.clone(),
+            y.clone(),
+            x.clone().atan2(y.clone())
+        );
+    }
+}
+
+fn demo_float_atan2_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).atan2({:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.clone().atan2(y.clone()))
+        );
+    }
+}
+
+fn demo_float_atan2_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).atan2(&{}) = {}",
+            x.clone(),
+            y.clone(),
+            x.clone().atan2(&y)
+        );
+    }
+}
+
+fn demo_float_atan2_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).atan2(&{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.clone().atan2(&y))
+        );
+    }
+}
+
+fn demo_float_atan2_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{}).atan2({}) = {}",
+            x.clone(),
+            y.clone(),
+            (&x).atan2(y.clone())
+        );
+    }
+}
+
+fn demo_float_atan2_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).atan2({:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat((&x).atan2(y.clone()))
+        );
+    }
+}
+
+fn demo_float_atan2_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{}).atan2(&{}) = {}",
+            x.clone(),
+            y.clone(),
+            (&x).atan2(&y)
+        );
+    }
+}
+
+fn demo_float_atan2_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).atan2(&{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat((&x).atan2(&y))
+        );
+    }
+}
+
+fn demo_float_atan2_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atan2_assign(y.clone());
+        println!("x := {x_old}; x.atan2_assign({y}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atan2_assign(y.clone());
+        println!(
+            "x := {:#x}; x.atan2_assign({:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atan2_assign(&y);
+        println!("x := {x_old}; x.atan2_assign(&{y}); x = {x}");
+    }
+}
+
+fn demo_float_atan2_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atan2_assign(&y);
+        println!(
+            "x := {:#x}; x.atan2_assign(&{:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_prec({}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.clone().atan2_prec(y.clone(), prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_prec(y.clone(), prec);
+        println!(
+            "({:#x}).atan2_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_prec_val_ref(&{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.clone().atan2_prec_val_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_prec_val_ref(&y, prec);
+        println!(
+            "({:#x}).atan2_prec_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_prec_ref_val({}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.atan2_prec_ref_val(y.clone(), prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_prec_ref_val(y.clone(), prec);
+        println!(
+            "(&{:#x}).atan2_prec_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_prec_ref_ref(&{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.atan2_prec_ref_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_prec_ref_ref(&y, prec);
+        println!(
+            "(&{:#x}).atan2_prec_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_assign(y.clone(), prec);
+        println!("x := {x_old}; x.atan2_prec_assign({y}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_assign(y.clone(), prec);
+        println!(
+            "x := {:#x}; x.atan2_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_assign_ref(&y, prec);
+        println!("x := {x_old}; x.atan2_prec_assign_ref(&{y}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_assign_ref(&y, prec);
+        println!(
+            "x := {:#x}; x.atan2_prec_assign_ref(&{:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_round({}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.clone().atan2_round(y.clone(), rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_round(y.clone(), rm);
+        println!(
+            "({:#x}).atan2_round({:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_round_val_ref(&{}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.clone().atan2_round_val_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_round_val_ref(&y, rm);
+        println!(
+            "({:#x}).atan2_round_val_ref(&{:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_round_ref_val({}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.atan2_round_ref_val(y.clone(), rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_round_ref_val(y.clone(), rm);
+        println!(
+            "(&{:#x}).atan2_round_ref_val({:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_round_ref_ref(&{}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.atan2_round_ref_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_round_ref_ref(&y, rm);
+        println!(
+            "(&{:#x}).atan2_round_ref_ref(&{:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_round_assign(y.clone(), rm);
+        println!("x := {x_old}; x.atan2_round_assign({y}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_round_assign(y.clone(), rm);
+        println!(
+            "x := {:#x}; x.atan2_round_assign({:#x}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_round_assign_ref(&y, rm);
+        println!("x := {x_old}; x.atan2_round_assign_ref(&{y}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_5()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_round_assign_ref(&y, rm);
+        println!(
+            "x := {:#x}; x.atan2_round_assign_ref(&{:#x}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_prec_round({}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.clone().atan2_prec_round(y.clone(), prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_prec_round(y.clone(), prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round({:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atan2_prec_round_val_ref(&{}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.clone().atan2_prec_round_val_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.clone().atan2_prec_round_val_ref(&y, prec, rm);
+        println!(
+            "({:#x}).atan2_prec_round_val_ref(&{:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_prec_round_ref_val({}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.atan2_prec_round_ref_val(y.clone(), prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_prec_round_ref_val(y.clone(), prec, rm);
+        println!(
+            "(&{:#x}).atan2_prec_round_ref_val({:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atan2_prec_round_ref_ref(&{}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.atan2_prec_round_ref_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (product, o) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+        println!(
+            "(&{:#x}).atan2_prec_round_ref_ref(&{:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(product),
+            o
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign(y.clone(), prec, rm);
+        println!("x := {x_old}; x.atan2_prec_round_assign({y}, {prec}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign(y.clone(), prec, rm);
+        println!(
+            "x := {:#x}; x.atan2_prec_round_assign({:#x}, {}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {x_old}; x.atan2_prec_round_assign_ref(&{y}, {prec}, {rm:?}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_atan2_prec_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atan2_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {:#x}; x.atan2_prec_round_assign_ref(&{:#x}, {}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_atan2_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.atan2(Float)", &mut |(x, y)| no_out!(x.atan2(y))),
+            ("Float.atan2(&Float)", &mut |(x, y)| no_out!(x.atan2(&y))),
+            (
+                "(&Float).atan2(Float)",
+                &mut |(x, y)| no_out!((&x).atan2(y)),
+            ),
+            ("(&Float).atan2(&Float)", &mut |(x, y)| {
+                no_out!((&x).atan2(&y))
+            }),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_atan2_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2(Float)",
+        BenchmarkType::LibraryComparison,
+        float_pair_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y))| no_out!(x.atan2(y))),
+            ("rug", &mut |((x, y), _)| no_out!(x.atan2(&y))),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_assign(Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.atan2_assign(Float)", &mut |(mut x, y)| {
+                x.atan2_assign(y)
+            }),
+            ("Float.atan2_assign(&Float)", &mut |(mut x, y)| {
+                x.atan2_assign(&y)
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.atan2_prec(Float, u64)", &mut |(x, y, prec)| {
+                no_out!(x.atan2_prec(y, prec))
+            }),
+            (
+                "Float.atan2_prec_val_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_val_ref(&y, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_val(Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_ref_val(y, prec)),
+            ),
+            (
+                "(&Float).atan2_prec_ref_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.atan2_prec_ref_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec(Float, u64)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_triple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec))| {
+                no_out!(x.atan2_prec(y, prec))
+            }),
+            ("rug", &mut |((x, y, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x.atan2(&y)))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_assign(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.atan2_prec_assign(Float, u64)", &mut |(
+                mut x,
+                y,
+                prec,
+            )| {
+                no_out!(x.atan2_prec_assign(y, prec))
+            }),
+            (
+                "Float.atan2_prec_assign_ref(&Float, u64)",
+                &mut |(mut x, y, prec)| no_out!(x.atan2_prec_assign_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.atan2_round(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round(y, rm)),
+            ),
+            (
+                "Float.atan2_round_val_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_val_ref(&y, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_val(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_ref_val(y, rm)),
+            ),
+            (
+                "(&Float).atan2_round_ref_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.atan2_round_ref_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round(Float, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_rounding_mode_triple_gen_var_5_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, rm))| {
+                no_out!(x.atan2_round(y, rm))
+            }),
+            ("rug", &mut |((x, y, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    max(x.prec(), y.prec()),
+                    x.atan2_ref(&y),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_round_assign(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.atan2_round_assign(Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.atan2_round_assign(y, rm)),
+            ),
+            (
+                "Float.atan2_round_assign_ref(&Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.atan2_round_assign_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_7().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.atan2_prec_round(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round(y, prec, rm)),
+            ),
+            (
+                "Float.atan2_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_val_ref(&y, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_ref_val(y, prec, rm)),
+            ),
+            (
+                "(&Float).atan2_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.atan2_prec_round_ref_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_7_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec, rm))| {
+                no_out!(x.atan2_prec_round(y, prec, rm))
+            }),
+            ("rug", &mut |((x, y, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.atan2(&y),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atan2_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atan2_prec_round_assign(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_7().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.atan2_prec_round_assign(Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.atan2_prec_round_assign(y, prec, rm)),
+            ),
+            (
+                "Float.atan2_prec_round_assign_ref(&Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.atan2_prec_round_assign_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,667 @@
+use malachite_base::num::arithmetic::traits::{Atanh, AtanhAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_2_max_float_prec_bucketer, pair_1_float_complexity_bucketer,
+    pair_2_float_complexity_bucketer, pair_2_pair_1_2_max_float_prec_bucketer,
+    pair_2_pair_1_float_complexity_bucketer, pair_2_triple_1_2_max_float_prec_bucketer,
+    triple_1_2_max_float_prec_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_30,
+    float_rounding_mode_pair_gen_var_30_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_26,
+    float_unsigned_rounding_mode_triple_gen_var_26_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_atanh);
+    register_demo!(runner, demo_float_atanh_debug);
+    register_demo!(runner, demo_float_atanh_ref);
+    register_demo!(runner, demo_float_atanh_ref_debug);
+    register_demo!(runner, demo_float_atanh_assign);
+    register_demo!(runner, demo_float_atanh_assign_debug);
+    register_demo!(runner, demo_float_atanh_prec);
+    register_demo!(runner, demo_float_atanh_prec_debug);
+    register_demo!(runner, demo_float_atanh_prec_ref);
+    register_demo!(runner, demo_float_atanh_prec_ref_debug);
+    register_demo!(runner, demo_float_atanh_prec_assign);
+    register_demo!(runner, demo_float_atanh_prec_assign_debug);
+    register_demo!(runner, demo_float_atanh_round);
+    register_demo!(runner, demo_float_atanh_round_debug);
+    register_demo!(runner, demo_float_atanh_round_ref);
+    register_demo!(runner, demo_float_atanh_round_ref_debug);
+    register_demo!(runner, demo_float_atanh_round_assign);
+    register_demo!(runner, demo_float_atanh_round_assign_debug);
+    register_demo!(runner, demo_float_atanh_prec_round);
+    register_demo!(runner, demo_float_atanh_prec_round_debug);
+    register_demo!(runner, demo_float_atanh_prec_round_ref);
+    register_demo!(runner, demo_float_atanh_prec_round_ref_debug);
+    register_demo!(runner, demo_float_atanh_prec_round_assign);
+    register_demo!(runner, demo_float_atanh_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_atanh_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atanh_library_comparison);
+    register_bench!(runner, benchmark_float_atanh_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atanh_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atanh_prec_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atanh_prec_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atanh_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atanh_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atanh_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_atanh_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_atanh_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_atanh_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_atanh(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("({}).atanh() = {}", x.clone(), x.clone().atanh());
+    }
+}
+
+fn demo_float_atanh_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).atanh() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(x.clone().atanh())
+        );
+    }
+}
+
+fn demo_float_atanh_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).atanh() = {}", x.clone(), (&x).atanh());
+    }
+}
+
+fn demo_float_atanh_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).atanh() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).atanh())
+        );
+    }
+}
+
+fn demo_float_atanh_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atanh_assign();
+        println!("x := {x_old}; x.atanh_assign(); x = {x}");
+    }
+}
+
+fn demo_float_atanh_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.atanh_assign();
+        println!(
+            "x := {:#x}; x.atanh_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atanh_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atanh_prec({}) = {:?}",
+            x.clone(),
+            prec,
+            x.clone().atanh_prec(prec)
+        );
+    }
+}
+
+fn demo_float_atanh_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.clone().atanh_prec(prec);
+        println!(
+            "({:#x}).atanh_prec({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atanh_prec_ref({}) = {:?}",
+            x.clone(),
+            prec,
+            x.atanh_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_atanh_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.atanh_prec_ref(prec);
+        println!(
+            "(&{:#x}).atanh_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_prec_assign(prec);
+        println!("x := {x_old}; x.atanh_prec_assign({prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atanh_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.atanh_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atanh_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atanh_round({}) = {:?}",
+            x.clone(),
+            rm,
+            x.clone().atanh_round(rm)
+        );
+    }
+}
+
+fn demo_float_atanh_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.clone().atanh_round(rm);
+        println!(
+            "({:#x}).atanh_round({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atanh_round_ref({}) = {:?}",
+            x.clone(),
+            rm,
+            x.atanh_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_atanh_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.atanh_round_ref(rm);
+        println!(
+            "(&{:#x}).atanh_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_round_assign(rm);
+        println!("x := {x_old}; x.atanh_round_assign({rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atanh_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_30()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_round_assign(rm);
+        println!(
+            "x := {:#x}; x.atanh_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_atanh_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).atanh_prec_round({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.clone().atanh_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_atanh_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.clone().atanh_prec_round(prec, rm);
+        println!(
+            "({:#x}).atanh_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).atanh_prec_round_ref({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.atanh_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_atanh_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (atanh, o) = x.atanh_prec_round_ref(prec, rm);
+        println!(
+            "(&{:#x}).atanh_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(atanh),
+            o
+        );
+    }
+}
+
+fn demo_float_atanh_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.atanh_prec_round_assign({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_atanh_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_26()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.atanh_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.atanh_prec_round_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_atanh_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.atanh()", &mut |x| no_out!(x.atanh())),
+            ("(&Float).atanh()", &mut |x| no_out!((&x).atanh())),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_atanh_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!(x.atanh())),
+            ("rug", &mut |(x, _)| no_out!(x.atanh())),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_assign()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.atanh_assign()", &mut |mut x| x.atanh_assign())],
+    );
+}
+
+fn benchmark_float_atanh_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Float.atanh_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.atanh_prec(prec))
+            }),
+            ("(&Float).atanh_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.atanh_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| {
+                no_out!(x.atanh_prec(prec))
+            }),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x.atanh()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec_assign(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [("Float.atanh_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.atanh_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_atanh_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_30().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.atanh_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.atanh_round(rm))
+            }),
+            ("(&Float).atanh_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.atanh_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_30_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| no_out!(x.atanh_round(rm))),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug::Float::with_val_round(x.prec(), x.atanh_ref(), rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_round_assign(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_30().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.atanh_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.atanh_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_atanh_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_26().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.atanh_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.atanh_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).atanh_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.atanh_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_26_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.atanh_prec_round(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.atanh(),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_atanh_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.atanh_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_26().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [(
+            "Float.atanh_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.atanh_prec_round_assign(prec, rm)),
+        )],
+    );
+}
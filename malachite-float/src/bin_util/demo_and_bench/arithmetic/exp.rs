@@ -0,0 +1,659 @@
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_2_max_float_prec_bucketer, pair_1_float_complexity_bucketer,
+    pair_2_float_complexity_bucketer, pair_2_pair_1_2_max_float_prec_bucketer,
+    pair_2_pair_1_float_complexity_bucketer, pair_2_triple_1_2_max_float_prec_bucketer,
+    triple_1_2_max_float_prec_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_gen_var_6_rm, float_rounding_mode_pair_gen_var_11,
+    float_rounding_mode_pair_gen_var_11_rm, float_unsigned_pair_gen_var_2,
+    float_unsigned_pair_gen_var_2_rm, float_unsigned_rounding_mode_triple_gen_var_7,
+    float_unsigned_rounding_mode_triple_gen_var_7_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_exp);
+    register_demo!(runner, demo_float_exp_debug);
+    register_demo!(runner, demo_float_exp_ref);
+    register_demo!(runner, demo_float_exp_ref_debug);
+    register_demo!(runner, demo_float_exp_assign);
+    register_demo!(runner, demo_float_exp_assign_debug);
+    register_demo!(runner, demo_float_exp_prec);
+    register_demo!(runner, demo_float_exp_prec_debug);
+    register_demo!(runner, demo_float_exp_prec_ref);
+    register_demo!(runner, demo_float_exp_prec_ref_debug);
+    register_demo!(runner, demo_float_exp_prec_assign);
+    register_demo!(runner, demo_float_exp_prec_assign_debug);
+    register_demo!(runner, demo_float_exp_round);
+    register_demo!(runner, demo_float_exp_round_debug);
+    register_demo!(runner, demo_float_exp_round_ref);
+    register_demo!(runner, demo_float_exp_round_ref_debug);
+    register_demo!(runner, demo_float_exp_round_assign);
+    register_demo!(runner, demo_float_exp_round_assign_debug);
+    register_demo!(runner, demo_float_exp_prec_round);
+    register_demo!(runner, demo_float_exp_prec_round_debug);
+    register_demo!(runner, demo_float_exp_prec_round_ref);
+    register_demo!(runner, demo_float_exp_prec_round_ref_debug);
+    register_demo!(runner, demo_float_exp_prec_round_assign);
+    register_demo!(runner, demo_float_exp_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_exp_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_library_comparison);
+    register_bench!(runner, benchmark_float_exp_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_prec_library_comparison);
+    register_bench!(runner, benchmark_float_exp_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_round_library_comparison);
+    register_bench!(runner, benchmark_float_exp_round_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_exp_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_exp_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_exp(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen_var_6().get(gm, config).take(limit) {
+        println!("({}).exp() = {}", x.clone(), x.clone().exp());
+    }
+}
+
+fn demo_float_exp_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen_var_6().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).exp() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(x.clone().exp())
+        );
+    }
+}
+
+fn demo_float_exp_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen_var_6().get(gm, config).take(limit) {
+        println!("(&{}).exp() = {}", x.clone(), (&x).exp());
+    }
+}
+
+fn demo_float_exp_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen_var_6().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).exp() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).exp())
+        );
+    }
+}
+
+fn demo_float_exp_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen_var_6().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.exp_assign();
+        println!("x := {x_old}; x.exp_assign(); x = {x}");
+    }
+}
+
+fn demo_float_exp_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen_var_6().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.exp_assign();
+        println!(
+            "x := {:#x}; x.exp_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_exp_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).exp_prec({}) = {:?}",
+            x.clone(),
+            prec,
+            x.clone().exp_prec(prec)
+        );
+    }
+}
+
+fn demo_float_exp_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.clone().exp_prec(prec);
+        println!(
+            "({:#x}).exp_prec({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).exp_prec_ref({}) = {:?}",
+            x.clone(),
+            prec,
+            x.exp_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_exp_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.exp_prec_ref(prec);
+        println!(
+            "(&{:#x}).exp_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_prec_assign(prec);
+        println!("x := {x_old}; x.exp_prec_assign({prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_exp_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_2::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.exp_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_exp_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).exp_round({}) = {:?}",
+            x.clone(),
+            rm,
+            x.clone().exp_round(rm)
+        );
+    }
+}
+
+fn demo_float_exp_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.clone().exp_round(rm);
+        println!(
+            "({:#x}).exp_round({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).exp_round_ref({}) = {:?}",
+            x.clone(),
+            rm,
+            x.exp_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_exp_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.exp_round_ref(rm);
+        println!(
+            "(&{:#x}).exp_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_round_assign(rm);
+        println!("x := {x_old}; x.exp_round_assign({rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_exp_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_11()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_round_assign(rm);
+        println!(
+            "x := {:#x}; x.exp_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_exp_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).exp_prec_round({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.clone().exp_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_exp_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        println!(
+            "({:#x}).exp_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).exp_prec_round_ref({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.exp_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_exp_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (exp, o) = x.exp_prec_round_ref(prec, rm);
+        println!(
+            "(&{:#x}).exp_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(exp),
+            o
+        );
+    }
+}
+
+fn demo_float_exp_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.exp_prec_round_assign({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_exp_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_7()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.exp_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.exp_prec_round_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_exp_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen_var_6().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.exp()", &mut |x| no_out!(x.exp())),
+            ("(&Float).exp()", &mut |x| no_out!((&x).exp())),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_exp_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp()",
+        BenchmarkType::LibraryComparison,
+        float_gen_var_6_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!(x.exp())),
+            ("rug", &mut |(x, _)| no_out!(x.exp())),
+        ],
+    );
+}
+
+fn benchmark_float_exp_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_assign()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen_var_6().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.exp_assign()", &mut |mut x| x.exp_assign())],
+    );
+}
+
+fn benchmark_float_exp_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_2::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Float.exp_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.exp_prec(prec))
+            }),
+            ("(&Float).exp_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.exp_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_exp_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_2_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| no_out!(x.exp_prec(prec))),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x.exp()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_exp_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec_assign(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_2::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [("Float.exp_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.exp_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_exp_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.exp_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.exp_round(rm))
+            }),
+            ("(&Float).exp_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.exp_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_exp_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_11_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| no_out!(x.exp_round(rm))),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug::Float::with_val_round(x.prec(), x.exp_ref(), rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_exp_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_round_assign(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.exp_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.exp_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_exp_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_7().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.exp_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.exp_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).exp_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.exp_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_exp_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_7_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.exp_prec_round(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.exp(),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_exp_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.exp_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_7().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [(
+            "Float.exp_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.exp_prec_round_assign(prec, rm)),
+        )],
+    );
+}
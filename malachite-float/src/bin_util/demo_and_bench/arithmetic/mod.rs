@@ -2,14 +2,84 @@ use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
     abs::register(runner);
+    acos::register(runner);
+    acosh::register(runner);
+    add::register(runner);
+    add_mul::register(runner);
+    asin::register(runner);
+    asinh::register(runner);
+    atan::register(runner);
+    atan2::register(runner);
+    atanh::register(runner);
+    cos::register(runner);
+    cosh::register(runner);
+    div::register(runner);
+    exp::register(runner);
+    exp10::register(runner);
+    exp2::register(runner);
+    expm1::register(runner);
+    fmod::register(runner);
     is_power_of_2::register(runner);
+    ln::register(runner);
+    ln_1p::register(runner);
+    log10::register(runner);
+    log2::register(runner);
+    mul::register(runner);
     neg::register(runner);
     power_of_2::register(runner);
+    reciprocal::register(runner);
+    reciprocal_sqrt::register(runner);
+    remainder::register(runner);
+    root::register(runner);
     sign::register(runner);
+    sin::register(runner);
+    sin_cos::register(runner);
+    sinh::register(runner);
+    sqrt::register(runner);
+    square::register(runner);
+    sub::register(runner);
+    sub_mul::register(runner);
+    tan::register(runner);
+    tanh::register(runner);
 }
 
 mod abs;
+mod acos;
+mod acosh;
+mod add;
+mod add_mul;
+mod asin;
+mod asinh;
+mod atan;
+mod atan2;
+mod atanh;
+mod cos;
+mod cosh;
+mod div;
+mod exp;
+mod exp10;
+mod exp2;
+mod expm1;
+mod fmod;
 mod is_power_of_2;
+mod ln;
+mod ln_1p;
+mod log10;
+mod log2;
+mod mul;
 mod neg;
 mod power_of_2;
+mod reciprocal;
+mod reciprocal_sqrt;
+mod remainder;
+mod root;
 mod sign;
+mod sin;
+mod sin_cos;
+mod sinh;
+mod sqrt;
+mod square;
+mod sub;
+mod sub_mul;
+mod tan;
+mod tanh;
@@ -0,0 +1,725 @@
+use malachite_base::num::arithmetic::traits::{Root, RootAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    pair_1_float_complexity_bucketer, pair_2_pair_1_float_complexity_bucketer,
+    pair_2_quadruple_1_3_max_float_prec_bucketer, pair_2_triple_1_3_max_float_prec_bucketer,
+    pair_2_triple_1_float_complexity_bucketer, quadruple_1_3_max_float_prec_bucketer,
+    triple_1_3_max_float_prec_bucketer, triple_1_float_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_unsigned_pair_gen_var_1, float_unsigned_pair_gen_var_1_rm,
+    float_unsigned_rounding_mode_triple_gen_var_6,
+    float_unsigned_rounding_mode_triple_gen_var_6_rm,
+    float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1_rm,
+    float_unsigned_unsigned_triple_gen_var_1, float_unsigned_unsigned_triple_gen_var_1_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_root);
+    register_demo!(runner, demo_float_root_debug);
+    register_demo!(runner, demo_float_root_ref);
+    register_demo!(runner, demo_float_root_ref_debug);
+    register_demo!(runner, demo_float_root_assign);
+    register_demo!(runner, demo_float_root_assign_debug);
+    register_demo!(runner, demo_float_root_prec);
+    register_demo!(runner, demo_float_root_prec_debug);
+    register_demo!(runner, demo_float_root_prec_ref);
+    register_demo!(runner, demo_float_root_prec_ref_debug);
+    register_demo!(runner, demo_float_root_prec_assign);
+    register_demo!(runner, demo_float_root_prec_assign_debug);
+    register_demo!(runner, demo_float_root_round);
+    register_demo!(runner, demo_float_root_round_debug);
+    register_demo!(runner, demo_float_root_round_ref);
+    register_demo!(runner, demo_float_root_round_ref_debug);
+    register_demo!(runner, demo_float_root_round_assign);
+    register_demo!(runner, demo_float_root_round_assign_debug);
+    register_demo!(runner, demo_float_root_prec_round);
+    register_demo!(runner, demo_float_root_prec_round_debug);
+    register_demo!(runner, demo_float_root_prec_round_ref);
+    register_demo!(runner, demo_float_root_prec_round_ref_debug);
+    register_demo!(runner, demo_float_root_prec_round_assign);
+    register_demo!(runner, demo_float_root_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_root_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_library_comparison);
+    register_bench!(runner, benchmark_float_root_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_prec_library_comparison);
+    register_bench!(runner, benchmark_float_root_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_root_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_root_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_root_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_root_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_root(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("({}).root({}) = {}", x.clone(), exp, x.clone().root(exp));
+    }
+}
+
+fn demo_float_root_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({:#x}).root({}) = {:#x}",
+            ComparableFloatRef(&x),
+            exp,
+            ComparableFloat(x.clone().root(exp))
+        );
+    }
+}
+
+fn demo_float_root_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!("(&{}).root({}) = {}", x.clone(), exp, (&x).root(exp));
+    }
+}
+
+fn demo_float_root_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{:#x}).root({}) = {:#x}",
+            ComparableFloatRef(&x),
+            exp,
+            ComparableFloat((&x).root(exp))
+        );
+    }
+}
+
+fn demo_float_root_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.root_assign(exp);
+        println!("x := {x_old}; x.root_assign({exp}); x = {x}");
+    }
+}
+
+fn demo_float_root_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        x.root_assign(exp);
+        println!(
+            "x := {:#x}; x.root_assign({}); x = {:#x}",
+            ComparableFloat(x_old),
+            exp,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_root_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.root(u64)", &mut |(x, exp)| no_out!(x.root(exp))),
+            (
+                "(&Float).root(u64)",
+                &mut |(x, exp)| no_out!((&x).root(exp)),
+            ),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_root_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, exp))| no_out!(x.root(exp))),
+            ("rug", &mut |((x, exp), _)| {
+                no_out!(x.root(u32::exact_from(exp)))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_root_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_assign(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.root_assign(u64)", &mut |(mut x, exp)| {
+            no_out!(x.root_assign(exp))
+        })],
+    );
+}
+
+fn demo_float_root_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).root_prec({}, {}) = {:?}",
+            x.clone(),
+            exp,
+            prec,
+            x.clone().root_prec(exp, prec)
+        );
+    }
+}
+
+fn demo_float_root_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.clone().root_prec(exp, prec);
+        println!(
+            "({:#x}).root_prec({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            prec,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).root_prec_ref({}, {}) = {:?}",
+            x.clone(),
+            exp,
+            prec,
+            x.root_prec_ref(exp, prec)
+        );
+    }
+}
+
+fn demo_float_root_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.root_prec_ref(exp, prec);
+        println!(
+            "(&{:#x}).root_prec_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            prec,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_prec_assign(exp, prec);
+        println!("x := {x_old}; x.root_prec_assign({exp}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_root_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, prec) in float_unsigned_unsigned_triple_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_prec_assign(exp, prec);
+        println!(
+            "x := {:#x}; x.root_prec_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            exp,
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_root_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec(u64, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_unsigned_triple_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Float.root_prec(u64, u64)", &mut |(x, exp, prec)| {
+                no_out!(x.root_prec(exp, prec))
+            }),
+            ("(&Float).root_prec_ref(u64, u64)", &mut |(x, exp, prec)| {
+                no_out!(x.root_prec_ref(exp, prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_root_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec(u64, u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_unsigned_triple_gen_var_1_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, exp, prec))| {
+                no_out!(x.root_prec(exp, prec))
+            }),
+            ("rug", &mut |((x, exp, prec), _)| {
+                no_out!(rug::Float::with_val(
+                    u32::exact_from(prec),
+                    x.root_ref(u32::exact_from(exp))
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_root_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec_assign(u64, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_unsigned_triple_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [("Float.root_prec_assign(u64, u64)", &mut |(
+            mut x,
+            exp,
+            prec,
+        )| {
+            no_out!(x.root_prec_assign(exp, prec))
+        })],
+    );
+}
+
+fn demo_float_root_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).root_round({}, {}) = {:?}",
+            x.clone(),
+            exp,
+            rm,
+            x.clone().root_round(exp, rm)
+        );
+    }
+}
+
+fn demo_float_root_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.clone().root_round(exp, rm);
+        println!(
+            "({:#x}).root_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            rm,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).root_round_ref({}, {}) = {:?}",
+            x.clone(),
+            exp,
+            rm,
+            x.root_round_ref(exp, rm)
+        );
+    }
+}
+
+fn demo_float_root_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.root_round_ref(exp, rm);
+        println!(
+            "(&{:#x}).root_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            rm,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_round_assign(exp, rm);
+        println!("x := {x_old}; x.root_round_assign({exp}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_root_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, rm) in float_unsigned_rounding_mode_triple_gen_var_6()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_round_assign(exp, rm);
+        println!(
+            "x := {:#x}; x.root_round_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            exp,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_root_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_6().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.root_round(u64, RoundingMode)", &mut |(
+                x,
+                exp,
+                rm,
+            )| {
+                no_out!(x.root_round(exp, rm))
+            }),
+            (
+                "(&Float).root_round_ref(u64, RoundingMode)",
+                &mut |(x, exp, rm)| no_out!(x.root_round_ref(exp, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_root_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_6_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, exp, rm))| {
+                no_out!(x.root_round(exp, rm))
+            }),
+            ("rug", &mut |((x, exp, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    x.prec(),
+                    x.root_ref(u32::exact_from(exp)),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_root_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_round_assign(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_6().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_float_complexity_bucketer("x"),
+        &mut [(
+            "Float.root_round_assign(u64, RoundingMode)",
+            &mut |(mut x, exp, rm)| no_out!(x.root_round_assign(exp, rm)),
+        )],
+    );
+}
+
+fn demo_float_root_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).root_prec_round({}, {}, {}) = {:?}",
+            x.clone(),
+            exp,
+            prec,
+            rm,
+            x.clone().root_prec_round(exp, prec, rm)
+        );
+    }
+}
+
+fn demo_float_root_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.clone().root_prec_round(exp, prec, rm);
+        println!(
+            "({:#x}).root_prec_round({}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            prec,
+            rm,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).root_prec_round_ref({}, {}, {}) = {:?}",
+            x.clone(),
+            exp,
+            prec,
+            rm,
+            x.root_prec_round_ref(exp, prec, rm)
+        );
+    }
+}
+
+fn demo_float_root_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (root, o) = x.root_prec_round_ref(exp, prec, rm);
+        println!(
+            "(&{:#x}).root_prec_round_ref({}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            exp,
+            prec,
+            rm,
+            ComparableFloat(root),
+            o
+        );
+    }
+}
+
+fn demo_float_root_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_prec_round_assign(exp, prec, rm);
+        println!("x := {x_old}; x.root_prec_round_assign({exp}, {prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_root_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, exp, prec, rm) in float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.root_prec_round_assign(exp, prec, rm);
+        println!(
+            "x := {:#x}; x.root_prec_round_assign({}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            exp,
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_root_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec_round(u64, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.root_prec_round(u64, u64, RoundingMode)",
+                &mut |(x, exp, prec, rm)| no_out!(x.root_prec_round(exp, prec, rm)),
+            ),
+            (
+                "(&Float).root_prec_round_ref(u64, u64, RoundingMode)",
+                &mut |(x, exp, prec, rm)| no_out!(x.root_prec_round_ref(exp, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_root_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec_round(u64, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, exp, prec, rm))| {
+                no_out!(x.root_prec_round(exp, prec, rm))
+            }),
+            ("rug", &mut |((x, exp, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.root_ref(u32::exact_from(exp)),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_root_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.root_prec_round_assign(u64, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_3_max_float_prec_bucketer("x", "prec"),
+        &mut [(
+            "Float.root_prec_round_assign(u64, u64, RoundingMode)",
+            &mut |(mut x, exp, prec, rm)| no_out!(x.root_prec_round_assign(exp, prec, rm)),
+        )],
+    );
+}
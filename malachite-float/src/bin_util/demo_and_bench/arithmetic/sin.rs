@@ -0,0 +1,659 @@
+use malachite_base::num::arithmetic::traits::{Sin, SinAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_2_max_float_prec_bucketer, pair_1_float_complexity_bucketer,
+    pair_2_float_complexity_bucketer, pair_2_pair_1_2_max_float_prec_bucketer,
+    pair_2_pair_1_float_complexity_bucketer, pair_2_triple_1_2_max_float_prec_bucketer,
+    triple_1_2_max_float_prec_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_19,
+    float_rounding_mode_pair_gen_var_19_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_15,
+    float_unsigned_rounding_mode_triple_gen_var_15_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sin);
+    register_demo!(runner, demo_float_sin_debug);
+    register_demo!(runner, demo_float_sin_ref);
+    register_demo!(runner, demo_float_sin_ref_debug);
+    register_demo!(runner, demo_float_sin_assign);
+    register_demo!(runner, demo_float_sin_assign_debug);
+    register_demo!(runner, demo_float_sin_prec);
+    register_demo!(runner, demo_float_sin_prec_debug);
+    register_demo!(runner, demo_float_sin_prec_ref);
+    register_demo!(runner, demo_float_sin_prec_ref_debug);
+    register_demo!(runner, demo_float_sin_prec_assign);
+    register_demo!(runner, demo_float_sin_prec_assign_debug);
+    register_demo!(runner, demo_float_sin_round);
+    register_demo!(runner, demo_float_sin_round_debug);
+    register_demo!(runner, demo_float_sin_round_ref);
+    register_demo!(runner, demo_float_sin_round_ref_debug);
+    register_demo!(runner, demo_float_sin_round_assign);
+    register_demo!(runner, demo_float_sin_round_assign_debug);
+    register_demo!(runner, demo_float_sin_prec_round);
+    register_demo!(runner, demo_float_sin_prec_round_debug);
+    register_demo!(runner, demo_float_sin_prec_round_ref);
+    register_demo!(runner, demo_float_sin_prec_round_ref_debug);
+    register_demo!(runner, demo_float_sin_prec_round_assign);
+    register_demo!(runner, demo_float_sin_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_sin_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_library_comparison);
+    register_bench!(runner, benchmark_float_sin_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_prec_library_comparison);
+    register_bench!(runner, benchmark_float_sin_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_round_library_comparison);
+    register_bench!(runner, benchmark_float_sin_round_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sin_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sin_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_sin(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("({}).sin() = {}", x.clone(), x.clone().sin());
+    }
+}
+
+fn demo_float_sin_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sin() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(x.clone().sin())
+        );
+    }
+}
+
+fn demo_float_sin_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).sin() = {}", x.clone(), (&x).sin());
+    }
+}
+
+fn demo_float_sin_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).sin() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).sin())
+        );
+    }
+}
+
+fn demo_float_sin_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sin_assign();
+        println!("x := {x_old}; x.sin_assign(); x = {x}");
+    }
+}
+
+fn demo_float_sin_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sin_assign();
+        println!(
+            "x := {:#x}; x.sin_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sin_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sin_prec({}) = {:?}",
+            x.clone(),
+            prec,
+            x.clone().sin_prec(prec)
+        );
+    }
+}
+
+fn demo_float_sin_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.clone().sin_prec(prec);
+        println!(
+            "({:#x}).sin_prec({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sin_prec_ref({}) = {:?}",
+            x.clone(),
+            prec,
+            x.sin_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_sin_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.sin_prec_ref(prec);
+        println!(
+            "(&{:#x}).sin_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_prec_assign(prec);
+        println!("x := {x_old}; x.sin_prec_assign({prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sin_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.sin_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sin_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sin_round({}) = {:?}",
+            x.clone(),
+            rm,
+            x.clone().sin_round(rm)
+        );
+    }
+}
+
+fn demo_float_sin_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.clone().sin_round(rm);
+        println!(
+            "({:#x}).sin_round({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sin_round_ref({}) = {:?}",
+            x.clone(),
+            rm,
+            x.sin_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_sin_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.sin_round_ref(rm);
+        println!(
+            "(&{:#x}).sin_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_round_assign(rm);
+        println!("x := {x_old}; x.sin_round_assign({rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sin_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_19()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_round_assign(rm);
+        println!(
+            "x := {:#x}; x.sin_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sin_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sin_prec_round({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.clone().sin_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sin_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.clone().sin_prec_round(prec, rm);
+        println!(
+            "({:#x}).sin_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sin_prec_round_ref({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.sin_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sin_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sin, o) = x.sin_prec_round_ref(prec, rm);
+        println!(
+            "(&{:#x}).sin_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(sin),
+            o
+        );
+    }
+}
+
+fn demo_float_sin_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.sin_prec_round_assign({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sin_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_15()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sin_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.sin_prec_round_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_sin_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sin()", &mut |x| no_out!(x.sin())),
+            ("(&Float).sin()", &mut |x| no_out!((&x).sin())),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_sin_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!(x.sin())),
+            ("rug", &mut |(x, _)| no_out!(x.sin())),
+        ],
+    );
+}
+
+fn benchmark_float_sin_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_assign()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.sin_assign()", &mut |mut x| x.sin_assign())],
+    );
+}
+
+fn benchmark_float_sin_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Float.sin_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.sin_prec(prec))
+            }),
+            ("(&Float).sin_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.sin_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sin_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec))| no_out!(x.sin_prec(prec))),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x.sin()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sin_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec_assign(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [("Float.sin_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.sin_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_sin_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_19().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sin_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sin_round(rm))
+            }),
+            ("(&Float).sin_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sin_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sin_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_19_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| no_out!(x.sin_round(rm))),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug::Float::with_val_round(x.prec(), x.sin_ref(), rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sin_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_round_assign(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_19().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.sin_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.sin_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_sin_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_15().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.sin_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sin_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).sin_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sin_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sin_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_15_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.sin_prec_round(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.sin(),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sin_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sin_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_15().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [(
+            "Float.sin_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.sin_prec_round_assign(prec, rm)),
+        )],
+    );
+}
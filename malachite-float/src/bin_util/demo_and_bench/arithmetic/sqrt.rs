@@ -0,0 +1,665 @@
+use malachite_base::num::arithmetic::traits::{Sqrt, SqrtAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_2_max_float_prec_bucketer, pair_1_float_complexity_bucketer,
+    pair_2_float_complexity_bucketer, pair_2_pair_1_2_max_float_prec_bucketer,
+    pair_2_pair_1_float_complexity_bucketer, pair_2_triple_1_2_max_float_prec_bucketer,
+    triple_1_2_max_float_prec_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_rm, float_rounding_mode_pair_gen_var_9,
+    float_rounding_mode_pair_gen_var_9_rm, float_unsigned_pair_gen_var_1,
+    float_unsigned_pair_gen_var_1_rm, float_unsigned_rounding_mode_triple_gen_var_4,
+    float_unsigned_rounding_mode_triple_gen_var_4_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sqrt);
+    register_demo!(runner, demo_float_sqrt_debug);
+    register_demo!(runner, demo_float_sqrt_ref);
+    register_demo!(runner, demo_float_sqrt_ref_debug);
+    register_demo!(runner, demo_float_sqrt_assign);
+    register_demo!(runner, demo_float_sqrt_assign_debug);
+    register_demo!(runner, demo_float_sqrt_prec);
+    register_demo!(runner, demo_float_sqrt_prec_debug);
+    register_demo!(runner, demo_float_sqrt_prec_ref);
+    register_demo!(runner, demo_float_sqrt_prec_ref_debug);
+    register_demo!(runner, demo_float_sqrt_prec_assign);
+    register_demo!(runner, demo_float_sqrt_prec_assign_debug);
+    register_demo!(runner, demo_float_sqrt_round);
+    register_demo!(runner, demo_float_sqrt_round_debug);
+    register_demo!(runner, demo_float_sqrt_round_ref);
+    register_demo!(runner, demo_float_sqrt_round_ref_debug);
+    register_demo!(runner, demo_float_sqrt_round_assign);
+    register_demo!(runner, demo_float_sqrt_round_assign_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round);
+    register_demo!(runner, demo_float_sqrt_prec_round_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round_ref);
+    register_demo!(runner, demo_float_sqrt_prec_round_ref_debug);
+    register_demo!(runner, demo_float_sqrt_prec_round_assign);
+    register_demo!(runner, demo_float_sqrt_prec_round_assign_debug);
+
+    register_bench!(runner, benchmark_float_sqrt_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_prec_library_comparison);
+    register_bench!(runner, benchmark_float_sqrt_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sqrt_round_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_sqrt_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sqrt_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sqrt_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_sqrt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("({}).sqrt() = {}", x.clone(), x.clone().sqrt());
+    }
+}
+
+fn demo_float_sqrt_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sqrt() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat(x.clone().sqrt())
+        );
+    }
+}
+
+fn demo_float_sqrt_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("(&{}).sqrt() = {}", x.clone(), (&x).sqrt());
+    }
+}
+
+fn demo_float_sqrt_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).sqrt() = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloat((&x).sqrt())
+        );
+    }
+}
+
+fn demo_float_sqrt_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sqrt_assign();
+        println!("x := {x_old}; x.sqrt_assign(); x = {x}");
+    }
+}
+
+fn demo_float_sqrt_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for mut x in float_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sqrt_assign();
+        println!(
+            "x := {:#x}; x.sqrt_assign(); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sqrt_prec({}) = {:?}",
+            x.clone(),
+            prec,
+            x.clone().sqrt_prec(prec)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.clone().sqrt_prec(prec);
+        println!(
+            "({:#x}).sqrt_prec({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sqrt_prec_ref({}) = {:?}",
+            x.clone(),
+            prec,
+            x.sqrt_prec_ref(prec)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.sqrt_prec_ref(prec);
+        println!(
+            "(&{:#x}).sqrt_prec_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_assign(prec);
+        println!("x := {x_old}; x.sqrt_prec_assign({prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sqrt_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec) in float_unsigned_pair_gen_var_1::<u64>()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_assign(prec);
+        println!(
+            "x := {:#x}; x.sqrt_prec_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sqrt_round({}) = {:?}",
+            x.clone(),
+            rm,
+            x.clone().sqrt_round(rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.clone().sqrt_round(rm);
+        println!(
+            "({:#x}).sqrt_round({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sqrt_round_ref({}) = {:?}",
+            x.clone(),
+            rm,
+            x.sqrt_round_ref(rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.sqrt_round_ref(rm);
+        println!(
+            "(&{:#x}).sqrt_round_ref({}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            rm,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_round_assign(rm);
+        println!("x := {x_old}; x.sqrt_round_assign({rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sqrt_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, rm) in float_rounding_mode_pair_gen_var_9()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_round_assign(rm);
+        println!(
+            "x := {:#x}; x.sqrt_round_assign({}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sqrt_prec_round({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.clone().sqrt_prec_round(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.clone().sqrt_prec_round(prec, rm);
+        println!(
+            "({:#x}).sqrt_prec_round({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sqrt_prec_round_ref({}, {}) = {:?}",
+            x.clone(),
+            prec,
+            rm,
+            x.sqrt_prec_round_ref(prec, rm)
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sqrt, o) = x.sqrt_prec_round_ref(prec, rm);
+        println!(
+            "(&{:#x}).sqrt_prec_round_ref({}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            prec,
+            rm,
+            ComparableFloat(sqrt),
+            o
+        );
+    }
+}
+
+fn demo_float_sqrt_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_round_assign(prec, rm);
+        println!("x := {x_old}; x.sqrt_prec_round_assign({prec}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sqrt_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, prec, rm) in float_unsigned_rounding_mode_triple_gen_var_4()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sqrt_prec_round_assign(prec, rm);
+        println!(
+            "x := {:#x}; x.sqrt_prec_round_assign({}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_sqrt_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sqrt()", &mut |x| no_out!(x.sqrt())),
+            ("(&Float).sqrt()", &mut |x| no_out!((&x).sqrt())),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_sqrt_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt()",
+        BenchmarkType::LibraryComparison,
+        float_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, x)| no_out!(x.sqrt())),
+            ("rug", &mut |(x, _)| no_out!(x.sqrt())),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_assign()",
+        BenchmarkType::EvaluationStrategy,
+        float_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [("Float.sqrt_assign()", &mut |mut x| x.sqrt_assign())],
+    );
+}
+
+fn benchmark_float_sqrt_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Float.sqrt_prec(u64)", &mut |(x, prec)| {
+                no_out!(x.sqrt_prec(prec))
+            }),
+            ("(&Float).sqrt_prec_ref(u64)", &mut |(x, prec)| {
+                no_out!(x.sqrt_prec_ref(prec))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_pair_gen_var_1_rm::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Malachite",
+                &mut |(_, (x, prec))| no_out!(x.sqrt_prec(prec)),
+            ),
+            ("rug", &mut |((x, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x.sqrt()))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_assign(u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_pair_gen_var_1::<u64>().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [("Float.sqrt_prec_assign(u64)", &mut |(mut x, prec)| {
+            no_out!(x.sqrt_prec_assign(prec))
+        })],
+    );
+}
+
+fn benchmark_float_sqrt_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_9().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Float.sqrt_round(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sqrt_round(rm))
+            }),
+            ("(&Float).sqrt_round_ref(RoundingMode)", &mut |(x, rm)| {
+                no_out!(x.sqrt_round_ref(rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round(RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_rounding_mode_pair_gen_var_9_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("Malachite", &mut |(_, (x, rm))| no_out!(x.sqrt_round(rm))),
+            ("rug", &mut |((x, rm), _)| {
+                no_out!(rug::Float::with_val_round(x.prec(), x.sqrt_ref(), rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_round_assign(RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_rounding_mode_pair_gen_var_9().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Float.sqrt_round_assign(RoundingMode)", &mut |(
+            mut x,
+            rm,
+        )| {
+            no_out!(x.sqrt_round_assign(rm))
+        })],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_4().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            (
+                "Float.sqrt_prec_round(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sqrt_prec_round(prec, rm)),
+            ),
+            (
+                "(&Float).sqrt_prec_round_ref(u64, RoundingMode)",
+                &mut |(x, prec, rm)| no_out!(x.sqrt_prec_round_ref(prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_unsigned_rounding_mode_triple_gen_var_4_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, prec, rm))| {
+                no_out!(x.sqrt_prec_round(prec, rm))
+            }),
+            ("rug", &mut |((x, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    x.sqrt(),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sqrt_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sqrt_prec_round_assign(u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_unsigned_rounding_mode_triple_gen_var_4().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_max_float_prec_bucketer("x", "prec"),
+        &mut [(
+            "Float.sqrt_prec_round_assign(u64, RoundingMode)",
+            &mut |(mut x, prec, rm)| no_out!(x.sqrt_prec_round_assign(prec, rm)),
+        )],
+    );
+}
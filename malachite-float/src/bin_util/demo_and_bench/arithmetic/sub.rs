@@ -0,0 +1,1104 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    pair_2_pair_float_max_complexity_bucketer,
+    pair_2_quadruple_1_2_3_max_float_float_prec_bucketer,
+    pair_2_triple_1_2_3_max_float_float_prec_bucketer,
+    pair_2_triple_1_2_float_max_complexity_bucketer, pair_float_max_complexity_bucketer,
+    quadruple_1_2_3_max_float_float_prec_bucketer, triple_1_2_3_max_float_float_prec_bucketer,
+    triple_1_2_float_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_2, float_float_rounding_mode_triple_gen_var_2_rm,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_2_rm,
+    float_float_unsigned_triple_gen_var_1, float_float_unsigned_triple_gen_var_1_rm,
+    float_pair_gen, float_pair_gen_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::cmp::max;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sub);
+    register_demo!(runner, demo_float_sub_debug);
+    register_demo!(runner, demo_float_sub_val_ref);
+    register_demo!(runner, demo_float_sub_val_ref_debug);
+    register_demo!(runner, demo_float_sub_ref_val);
+    register_demo!(runner, demo_float_sub_ref_val_debug);
+    register_demo!(runner, demo_float_sub_ref_ref);
+    register_demo!(runner, demo_float_sub_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_assign);
+    register_demo!(runner, demo_float_sub_assign_debug);
+    register_demo!(runner, demo_float_sub_assign_ref);
+    register_demo!(runner, demo_float_sub_assign_ref_debug);
+    register_demo!(runner, demo_float_sub_prec);
+    register_demo!(runner, demo_float_sub_prec_debug);
+    register_demo!(runner, demo_float_sub_prec_val_ref);
+    register_demo!(runner, demo_float_sub_prec_val_ref_debug);
+    register_demo!(runner, demo_float_sub_prec_ref_val);
+    register_demo!(runner, demo_float_sub_prec_ref_val_debug);
+    register_demo!(runner, demo_float_sub_prec_ref_ref);
+    register_demo!(runner, demo_float_sub_prec_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_prec_assign);
+    register_demo!(runner, demo_float_sub_prec_assign_debug);
+    register_demo!(runner, demo_float_sub_prec_assign_ref);
+    register_demo!(runner, demo_float_sub_prec_assign_ref_debug);
+    register_demo!(runner, demo_float_sub_round);
+    register_demo!(runner, demo_float_sub_round_debug);
+    register_demo!(runner, demo_float_sub_round_val_ref);
+    register_demo!(runner, demo_float_sub_round_val_ref_debug);
+    register_demo!(runner, demo_float_sub_round_ref_val);
+    register_demo!(runner, demo_float_sub_round_ref_val_debug);
+    register_demo!(runner, demo_float_sub_round_ref_ref);
+    register_demo!(runner, demo_float_sub_round_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_round_assign);
+    register_demo!(runner, demo_float_sub_round_assign_debug);
+    register_demo!(runner, demo_float_sub_round_assign_ref);
+    register_demo!(runner, demo_float_sub_round_assign_ref_debug);
+    register_demo!(runner, demo_float_sub_prec_round);
+    register_demo!(runner, demo_float_sub_prec_round_debug);
+    register_demo!(runner, demo_float_sub_prec_round_val_ref);
+    register_demo!(runner, demo_float_sub_prec_round_val_ref_debug);
+    register_demo!(runner, demo_float_sub_prec_round_ref_val);
+    register_demo!(runner, demo_float_sub_prec_round_ref_val_debug);
+    register_demo!(runner, demo_float_sub_prec_round_ref_ref);
+    register_demo!(runner, demo_float_sub_prec_round_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_prec_round_assign);
+    register_demo!(runner, demo_float_sub_prec_round_assign_debug);
+    register_demo!(runner, demo_float_sub_prec_round_assign_ref);
+    register_demo!(runner, demo_float_sub_prec_round_assign_ref_debug);
+
+    register_bench!(runner, benchmark_float_sub_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_library_comparison);
+    register_bench!(runner, benchmark_float_sub_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_prec_library_comparison);
+    register_bench!(runner, benchmark_float_sub_prec_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_round_library_comparison);
+    register_bench!(runner, benchmark_float_sub_round_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_prec_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_prec_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sub_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_sub(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!("{} - {} = {}", x.clone(), y.clone(), x.clone() - y.clone());
+    }
+}
+
+fn demo_float_sub_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "{:#x} - {:#x} = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.clone() - y.clone())
+        );
+    }
+}
+
+fn demo_float_sub_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!("{} - &{} = {}", x.clone(), y.clone(), x.clone() - &y);
+    }
+}
+
+fn demo_float_sub_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "{:#x} - &{:#x} = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(x.clone() - &y)
+        );
+    }
+}
+
+fn demo_float_sub_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!("&{} - {} = {}", x.clone(), y.clone(), &x - y.clone());
+    }
+}
+
+fn demo_float_sub_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "&{:#x} - {:#x} = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(&x - y.clone())
+        );
+    }
+}
+
+fn demo_float_sub_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!("&{} - &{} = {}", x.clone(), y.clone(), &x - &y);
+    }
+}
+
+fn demo_float_sub_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y) in float_pair_gen().get(gm, config).take(limit) {
+        println!(
+            "&{:#x} - &{:#x} = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloat(&x - &y)
+        );
+    }
+}
+
+fn demo_float_sub_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x -= y.clone();
+        println!("x := {x_old}; x -= {y}; x = {x}");
+    }
+}
+
+fn demo_float_sub_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x -= y.clone();
+        println!(
+            "x := {:#x}; x -= {:#x}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x -= &y;
+        println!("x := {x_old}; x -= &{y}; x = {x}");
+    }
+}
+
+fn demo_float_sub_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y) in float_pair_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x -= &y;
+        println!(
+            "x := {:#x}; x -= &{:#x}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_prec({}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.clone().sub_prec(y.clone(), prec)
+        );
+    }
+}
+
+fn demo_float_sub_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_prec(y.clone(), prec);
+        println!(
+            "({:#x}).sub_prec({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_prec_val_ref(&{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.clone().sub_prec_val_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_sub_prec_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_prec_val_ref(&y, prec);
+        println!(
+            "({:#x}).sub_prec_val_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_prec_ref_val({}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.sub_prec_ref_val(y.clone(), prec)
+        );
+    }
+}
+
+fn demo_float_sub_prec_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_prec_ref_val(y.clone(), prec);
+        println!(
+            "(&{:#x}).sub_prec_ref_val({:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_prec_ref_ref(&{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            x.sub_prec_ref_ref(&y, prec)
+        );
+    }
+}
+
+fn demo_float_sub_prec_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_prec_ref_ref(&y, prec);
+        println!(
+            "(&{:#x}).sub_prec_ref_ref(&{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_assign(y.clone(), prec);
+        println!("x := {x_old}; x.sub_prec_assign({y}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_assign(y.clone(), prec);
+        println!(
+            "x := {:#x}; x.sub_prec_assign({:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_prec_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_assign_ref(&y, prec);
+        println!("x := {x_old}; x.sub_prec_assign_ref(&{y}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_prec_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec) in float_float_unsigned_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_assign_ref(&y, prec);
+        println!(
+            "x := {:#x}; x.sub_prec_assign_ref(&{:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_round({}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.clone().sub_round(y.clone(), rm)
+        );
+    }
+}
+
+fn demo_float_sub_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_round(y.clone(), rm);
+        println!(
+            "({:#x}).sub_round({:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_round_val_ref(&{}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.clone().sub_round_val_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_sub_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_round_val_ref(&y, rm);
+        println!(
+            "({:#x}).sub_round_val_ref(&{:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_round_ref_val({}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.sub_round_ref_val(y.clone(), rm)
+        );
+    }
+}
+
+fn demo_float_sub_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_round_ref_val(y.clone(), rm);
+        println!(
+            "(&{:#x}).sub_round_ref_val({:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_round_ref_ref(&{}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            rm,
+            x.sub_round_ref_ref(&y, rm)
+        );
+    }
+}
+
+fn demo_float_sub_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_round_ref_ref(&y, rm);
+        println!(
+            "(&{:#x}).sub_round_ref_ref(&{:#x}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_round_assign(y.clone(), rm);
+        println!("x := {x_old}; x.sub_round_assign({y}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_round_assign(y.clone(), rm);
+        println!(
+            "x := {:#x}; x.sub_round_assign({:#x}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_round_assign_ref(&y, rm);
+        println!("x := {x_old}; x.sub_round_assign_ref(&{y}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, rm) in float_float_rounding_mode_triple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_round_assign_ref(&y, rm);
+        println!(
+            "x := {:#x}; x.sub_round_assign_ref(&{:#x}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_prec_round({}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.clone().sub_prec_round(y.clone(), prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_prec_round(y.clone(), prec, rm);
+        println!(
+            "({:#x}).sub_prec_round({:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_prec_round_val_ref(&{}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.clone().sub_prec_round_val_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.clone().sub_prec_round_val_ref(&y, prec, rm);
+        println!(
+            "({:#x}).sub_prec_round_val_ref(&{:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_prec_round_ref_val({}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.sub_prec_round_ref_val(y.clone(), prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_prec_round_ref_val(y.clone(), prec, rm);
+        println!(
+            "(&{:#x}).sub_prec_round_ref_val({:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_prec_round_ref_ref(&{}, {}, {:?}) = {:?}",
+            x.clone(),
+            y.clone(),
+            prec,
+            rm,
+            x.sub_prec_round_ref_ref(&y, prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (sum, o) = x.sub_prec_round_ref_ref(&y, prec, rm);
+        println!(
+            "(&{:#x}).sub_prec_round_ref_ref(&{:#x}, {}, {:?}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            prec,
+            rm,
+            ComparableFloat(sum),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_round_assign(y.clone(), prec, rm);
+        println!("x := {x_old}; x.sub_prec_round_assign({y}, {prec}, {rm:?}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_round_assign(y.clone(), prec, rm);
+        println!(
+            "x := {:#x}; x.sub_prec_round_assign({:#x}, {}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_assign_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {x_old}; x.sub_prec_round_assign_ref(&{y}, {prec}, {rm:?}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_sub_prec_round_assign_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, prec, rm) in float_float_unsigned_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_prec_round_assign_ref(&y, prec, rm);
+        println!(
+            "x := {:#x}; x.sub_prec_round_assign_ref(&{:#x}, {}, {:?}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+fn benchmark_float_sub_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float - Float",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float - Float", &mut |(x, y)| no_out!(x - y)),
+            ("Float - &Float", &mut |(x, y)| no_out!(x - &y)),
+            ("&Float - Float", &mut |(x, y)| no_out!(&x - y)),
+            ("&Float - &Float", &mut |(x, y)| no_out!(&x - &y)),
+        ],
+    );
+}
+
+#[allow(clippy::no_effect, clippy::unnecessary_operation, unused_must_use)]
+fn benchmark_float_sub_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float - Float",
+        BenchmarkType::LibraryComparison,
+        float_pair_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y))| no_out!(x - y)),
+            ("rug", &mut |((x, y), _)| no_out!(x - y)),
+        ],
+    );
+}
+
+fn benchmark_float_sub_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float -= Float",
+        BenchmarkType::EvaluationStrategy,
+        float_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float -= Float", &mut |(mut x, y)| x -= y),
+            ("Float -= &Float", &mut |(mut x, y)| x -= &y),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.sub_prec(Float, u64)", &mut |(x, y, prec)| {
+                no_out!(x.sub_prec(y, prec))
+            }),
+            ("Float.sub_prec_val_ref(&Float, u64)", &mut |(
+                x,
+                y,
+                prec,
+            )| {
+                no_out!(x.sub_prec_val_ref(&y, prec))
+            }),
+            (
+                "(&Float).sub_prec_ref_val(Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.sub_prec_ref_val(y, prec)),
+            ),
+            (
+                "(&Float).sub_prec_ref_ref(&Float, u64)",
+                &mut |(x, y, prec)| no_out!(x.sub_prec_ref_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec(Float, u64)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_triple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec))| {
+                no_out!(x.sub_prec(y, prec))
+            }),
+            ("rug", &mut |((x, y, prec), _)| {
+                no_out!(rug::Float::with_val(u32::exact_from(prec), x - y))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec_assign(Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Float.sub_prec_assign(Float, u64)", &mut |(
+                mut x,
+                y,
+                prec,
+            )| {
+                no_out!(x.sub_prec_assign(y, prec))
+            }),
+            (
+                "Float.sub_prec_assign_ref(&Float, u64)",
+                &mut |(mut x, y, prec)| no_out!(x.sub_prec_assign_ref(&y, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_round(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Float.sub_round(Float, RoundingMode)", &mut |(x, y, rm)| {
+                no_out!(x.sub_round(y, rm))
+            }),
+            (
+                "Float.sub_round_val_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.sub_round_val_ref(&y, rm)),
+            ),
+            (
+                "(&Float).sub_round_ref_val(Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.sub_round_ref_val(y, rm)),
+            ),
+            (
+                "(&Float).sub_round_ref_ref(&Float, RoundingMode)",
+                &mut |(x, y, rm)| no_out!(x.sub_round_ref_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_round(Float, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_rounding_mode_triple_gen_var_2_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, rm))| {
+                no_out!(x.sub_round(y, rm))
+            }),
+            ("rug", &mut |((x, y, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    max(x.prec(), y.prec()),
+                    &x - &y,
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_round_assign(Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_rounding_mode_triple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_2_float_max_complexity_bucketer("x", "y"),
+        &mut [
+            (
+                "Float.sub_round_assign(Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.sub_round_assign(y, rm)),
+            ),
+            (
+                "Float.sub_round_assign_ref(&Float, RoundingMode)",
+                &mut |(mut x, y, rm)| no_out!(x.sub_round_assign_ref(&y, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.sub_prec_round(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.sub_prec_round(y, prec, rm)),
+            ),
+            (
+                "Float.sub_prec_round_val_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.sub_prec_round_val_ref(&y, prec, rm)),
+            ),
+            (
+                "(&Float).sub_prec_round_ref_val(Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.sub_prec_round_ref_val(y, prec, rm)),
+            ),
+            (
+                "(&Float).sub_prec_round_ref_ref(&Float, u64, RoundingMode)",
+                &mut |(x, y, prec, rm)| no_out!(x.sub_prec_round_ref_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec_round(Float, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_2_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, prec, rm))| {
+                no_out!(x.sub_prec_round(y, prec, rm))
+            }),
+            ("rug", &mut |((x, y, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(u32::exact_from(prec), x - y, rm))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_prec_round_assign(Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_unsigned_rounding_mode_quadruple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_max_float_float_prec_bucketer("x", "y", "prec"),
+        &mut [
+            (
+                "Float.sub_prec_round_assign(Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.sub_prec_round_assign(y, prec, rm)),
+            ),
+            (
+                "Float.sub_prec_round_assign_ref(&Float, u64, RoundingMode)",
+                &mut |(mut x, y, prec, rm)| no_out!(x.sub_prec_round_assign_ref(&y, prec, rm)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,1187 @@
+use malachite_base::num::arithmetic::traits::{SubMul, SubMulAssign};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    pair_2_quadruple_1_2_3_4_max_float_float_float_prec_bucketer,
+    pair_2_quadruple_1_2_3_float_max_complexity_bucketer,
+    pair_2_quintuple_1_2_3_4_max_float_float_float_prec_bucketer,
+    pair_2_triple_float_max_complexity_bucketer,
+    quadruple_1_2_3_4_max_float_float_float_prec_bucketer,
+    quadruple_1_2_3_float_max_complexity_bucketer,
+    quintuple_1_2_3_4_max_float_float_float_prec_bucketer, triple_float_max_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_float_float_rounding_mode_quadruple_gen_var_2,
+    float_float_float_rounding_mode_quadruple_gen_var_2_rm,
+    float_float_float_unsigned_quadruple_gen_var_1,
+    float_float_float_unsigned_quadruple_gen_var_1_rm,
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_2,
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_2_rm, float_triple_gen,
+    float_triple_gen_rm,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef};
+use std::cmp::max;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_sub_mul);
+    register_demo!(runner, demo_float_sub_mul_debug);
+    register_demo!(runner, demo_float_sub_mul_val_val_ref);
+    register_demo!(runner, demo_float_sub_mul_val_val_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_val_ref_val);
+    register_demo!(runner, demo_float_sub_mul_val_ref_val_debug);
+    register_demo!(runner, demo_float_sub_mul_val_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_val_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_ref_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_ref_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_assign);
+    register_demo!(runner, demo_float_sub_mul_assign_debug);
+    register_demo!(runner, demo_float_sub_mul_assign_val_ref);
+    register_demo!(runner, demo_float_sub_mul_assign_val_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_assign_ref_val);
+    register_demo!(runner, demo_float_sub_mul_assign_ref_val_debug);
+    register_demo!(runner, demo_float_sub_mul_assign_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_assign_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec);
+    register_demo!(runner, demo_float_sub_mul_prec_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_val_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_val_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_ref_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_ref_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_assign);
+    register_demo!(runner, demo_float_sub_mul_prec_assign_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_assign_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_assign_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_round);
+    register_demo!(runner, demo_float_sub_mul_round_debug);
+    register_demo!(runner, demo_float_sub_mul_round_val_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_round_val_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_round_ref_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_round_ref_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_round_assign);
+    register_demo!(runner, demo_float_sub_mul_round_assign_debug);
+    register_demo!(runner, demo_float_sub_mul_round_assign_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_round_assign_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_round);
+    register_demo!(runner, demo_float_sub_mul_prec_round_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_round_val_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_round_val_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_round_ref_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_round_ref_ref_ref_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_round_assign);
+    register_demo!(runner, demo_float_sub_mul_prec_round_assign_debug);
+    register_demo!(runner, demo_float_sub_mul_prec_round_assign_ref_ref);
+    register_demo!(runner, demo_float_sub_mul_prec_round_assign_ref_ref_debug);
+
+    register_bench!(runner, benchmark_float_sub_mul_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_mul_library_comparison);
+    register_bench!(runner, benchmark_float_sub_mul_assign_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_mul_prec_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_mul_prec_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sub_mul_prec_assign_evaluation_strategy
+    );
+    register_bench!(runner, benchmark_float_sub_mul_round_evaluation_strategy);
+    register_bench!(runner, benchmark_float_sub_mul_round_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_sub_mul_round_assign_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sub_mul_prec_round_evaluation_strategy
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sub_mul_prec_round_library_comparison
+    );
+    register_bench!(
+        runner,
+        benchmark_float_sub_mul_prec_round_assign_evaluation_strategy
+    );
+}
+
+fn demo_float_sub_mul(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).sub_mul({}, {}) = {}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            x.clone().sub_mul(y.clone(), z.clone())
+        );
+    }
+}
+
+fn demo_float_sub_mul_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sub_mul({:#x}, {:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            ComparableFloat(x.clone().sub_mul(y.clone(), z.clone()))
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).sub_mul({}, &{}) = {}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            x.clone().sub_mul(y.clone(), &z)
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sub_mul({:#x}, &{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            ComparableFloat(x.clone().sub_mul(y.clone(), &z))
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).sub_mul(&{}, {}) = {}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            x.clone().sub_mul(&y, z.clone())
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sub_mul(&{:#x}, {:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            ComparableFloat(x.clone().sub_mul(&y, z.clone()))
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({}).sub_mul(&{}, &{}) = {}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            x.clone().sub_mul(&y, &z)
+        );
+    }
+}
+
+fn demo_float_sub_mul_val_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "({:#x}).sub_mul(&{:#x}, &{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            ComparableFloat(x.clone().sub_mul(&y, &z))
+        );
+    }
+}
+
+fn demo_float_sub_mul_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{}).sub_mul(&{}, &{}) = {}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            (&x).sub_mul(&y, &z)
+        );
+    }
+}
+
+fn demo_float_sub_mul_ref_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        println!(
+            "(&{:#x}).sub_mul(&{:#x}, &{:#x}) = {:#x}",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            ComparableFloat((&x).sub_mul(&y, &z))
+        );
+    }
+}
+
+fn demo_float_sub_mul_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(y.clone(), z.clone());
+        println!("x := {x_old}; x.sub_mul_assign({y}, {z}); x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(y.clone(), z.clone());
+        println!(
+            "x := {:#x}; x.sub_mul_assign({:#x}, {:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_assign_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(y.clone(), &z);
+        println!("x := {x_old}; x.sub_mul_assign({y}, &{z}); x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_assign_val_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(y.clone(), &z);
+        println!(
+            "x := {:#x}; x.sub_mul_assign({:#x}, &{:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_assign_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(&y, z.clone());
+        println!("x := {x_old}; x.sub_mul_assign(&{y}, {z}); x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_assign_ref_val_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(&y, z.clone());
+        println!(
+            "x := {:#x}; x.sub_mul_assign(&{:#x}, {:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_assign_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(&y, &z);
+        println!("x := {x_old}; x.sub_mul_assign(&{y}, &{z}); x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_assign_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z) in float_triple_gen().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        x.sub_mul_assign(&y, &z);
+        println!(
+            "x := {:#x}; x.sub_mul_assign(&{:#x}, &{:#x}); x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_prec({}, {}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            x.clone().sub_mul_prec(y.clone(), z.clone(), prec)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_prec(y.clone(), z.clone(), prec);
+        println!(
+            "({:#x}).sub_mul_prec({:#x}, {:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_val_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_prec_val_ref_ref(&{}, &{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            x.clone().sub_mul_prec_val_ref_ref(&y, &z, prec)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_val_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_prec_val_ref_ref(&y, &z, prec);
+        println!(
+            "({:#x}).sub_mul_prec_val_ref_ref(&{:#x}, &{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_mul_prec_ref_ref_ref(&{}, &{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            x.sub_mul_prec_ref_ref_ref(&y, &z, prec)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_ref_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.sub_mul_prec_ref_ref_ref(&y, &z, prec);
+        println!(
+            "(&{:#x}).sub_mul_prec_ref_ref_ref(&{:#x}, &{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_assign(y.clone(), z.clone(), prec);
+        println!("x := {x_old}; x.sub_mul_prec_assign({y}, {z}, {prec}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_prec_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_assign(y.clone(), z.clone(), prec);
+        println!(
+            "x := {:#x}; x.sub_mul_prec_assign({:#x}, {:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_assign_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_assign_ref_ref(&y, &z, prec);
+        println!(
+            "x := {x_old}; x.sub_mul_prec_assign_ref_ref(&{y}, &{z}, {prec}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_assign_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec) in float_float_float_unsigned_quadruple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_assign_ref_ref(&y, &z, prec);
+        println!(
+            "x := {:#x}; x.sub_mul_prec_assign_ref_ref(&{:#x}, &{:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            prec,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_round({}, {}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            rm,
+            x.clone().sub_mul_round(y.clone(), z.clone(), rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_round(y.clone(), z.clone(), rm);
+        println!(
+            "({:#x}).sub_mul_round({:#x}, {:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_val_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_round_val_ref_ref(&{}, &{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            rm,
+            x.clone().sub_mul_round_val_ref_ref(&y, &z, rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_val_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_round_val_ref_ref(&y, &z, rm);
+        println!(
+            "({:#x}).sub_mul_round_val_ref_ref(&{:#x}, &{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_mul_round_ref_ref_ref(&{}, &{}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            rm,
+            x.sub_mul_round_ref_ref_ref(&y, &z, rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_ref_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.sub_mul_round_ref_ref_ref(&y, &z, rm);
+        println!(
+            "(&{:#x}).sub_mul_round_ref_ref_ref(&{:#x}, &{:#x}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_round_assign(y.clone(), z.clone(), rm);
+        println!("x := {x_old}; x.sub_mul_round_assign({y}, {z}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_round_assign(y.clone(), z.clone(), rm);
+        println!(
+            "x := {:#x}; x.sub_mul_round_assign({:#x}, {:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_round_assign_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_round_assign_ref_ref(&y, &z, rm);
+        println!("x := {x_old}; x.sub_mul_round_assign_ref_ref(&{y}, &{z}, {rm}) = {o:?}; x = {x}");
+    }
+}
+
+fn demo_float_sub_mul_round_assign_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, rm) in float_float_float_rounding_mode_quadruple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_round_assign_ref_ref(&y, &z, rm);
+        println!(
+            "x := {:#x}; x.sub_mul_round_assign_ref_ref(&{:#x}, &{:#x}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_prec_round({}, {}, {}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            rm,
+            x.clone().sub_mul_prec_round(y.clone(), z.clone(), prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_prec_round(y.clone(), z.clone(), prec, rm);
+        println!(
+            "({:#x}).sub_mul_prec_round({:#x}, {:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_val_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "({}).sub_mul_prec_round_val_ref_ref(&{}, &{}, {}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            rm,
+            x.clone().sub_mul_prec_round_val_ref_ref(&y, &z, prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_val_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.clone().sub_mul_prec_round_val_ref_ref(&y, &z, prec, rm);
+        println!(
+            "({:#x}).sub_mul_prec_round_val_ref_ref(&{:#x}, &{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_ref_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "(&{}).sub_mul_prec_round_ref_ref_ref(&{}, &{}, {}, {}) = {:?}",
+            x.clone(),
+            y.clone(),
+            z.clone(),
+            prec,
+            rm,
+            x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_ref_ref_ref_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (result, o) = x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        println!(
+            "(&{:#x}).sub_mul_prec_round_ref_ref_ref(&{:#x}, &{:#x}, {}, {}) = ({:#x}, {:?})",
+            ComparableFloatRef(&x),
+            ComparableFloatRef(&y),
+            ComparableFloatRef(&z),
+            prec,
+            rm,
+            ComparableFloat(result),
+            o
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_assign(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+        println!(
+            "x := {x_old}; x.sub_mul_prec_round_assign({y}, {z}, {prec}, {rm}) = {o:?}; x = {x}"
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_assign_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+        println!(
+            "x := {:#x}; x.sub_mul_prec_round_assign({:#x}, {:#x}, {}, {}) = {:?}; x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_assign_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (mut x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+        println!(
+            "x := {x_old}; x.sub_mul_prec_round_assign_ref_ref(&{y}, &{z}, {prec}, {rm}) = {o:?}; \
+            x = {x}"
+        );
+    }
+}
+
+fn demo_float_sub_mul_prec_round_assign_ref_ref_debug(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+) {
+    for (mut x, y, z, prec, rm) in float_float_float_unsigned_rounding_mode_quintuple_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        let x_old = x.clone();
+        let o = x.sub_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+        println!(
+            "x := {:#x}; x.sub_mul_prec_round_assign_ref_ref(&{:#x}, &{:#x}, {}, {}) = {:?}; \
+            x = {:#x}",
+            ComparableFloat(x_old),
+            ComparableFloat(y),
+            ComparableFloat(z),
+            prec,
+            rm,
+            o,
+            ComparableFloat(x)
+        );
+    }
+}
+
+fn benchmark_float_sub_mul_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul(Float, Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_triple_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            ("Float.sub_mul(Float, Float)", &mut |(x, y, z)| {
+                no_out!(x.sub_mul(y, z))
+            }),
+            ("Float.sub_mul(Float, &Float)", &mut |(x, y, z)| {
+                no_out!(x.sub_mul(y, &z))
+            }),
+            ("Float.sub_mul(&Float, Float)", &mut |(x, y, z)| {
+                no_out!(x.sub_mul(&y, z))
+            }),
+            ("Float.sub_mul(&Float, &Float)", &mut |(x, y, z)| {
+                no_out!(x.sub_mul(&y, &z))
+            }),
+            ("(&Float).sub_mul(&Float, &Float)", &mut |(x, y, z)| {
+                no_out!((&x).sub_mul(&y, &z))
+            }),
+        ],
+    );
+}
+
+#[allow(unused_must_use)]
+fn benchmark_float_sub_mul_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul(Float, Float)",
+        BenchmarkType::LibraryComparison,
+        float_triple_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_triple_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, z))| no_out!(x.sub_mul(y, z))),
+            ("rug", &mut |((x, y, z), _)| no_out!((-y).mul_add(&z, &x))),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_assign(Float, Float)",
+        BenchmarkType::EvaluationStrategy,
+        float_triple_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            ("Float.sub_mul_assign(Float, Float)", &mut |(
+                mut x,
+                y,
+                z,
+            )| {
+                x.sub_mul_assign(y, z)
+            }),
+            ("Float.sub_mul_assign(Float, &Float)", &mut |(
+                mut x,
+                y,
+                z,
+            )| {
+                x.sub_mul_assign(y, &z)
+            }),
+            ("Float.sub_mul_assign(&Float, Float)", &mut |(
+                mut x,
+                y,
+                z,
+            )| {
+                x.sub_mul_assign(&y, z)
+            }),
+            ("Float.sub_mul_assign(&Float, &Float)", &mut |(
+                mut x,
+                y,
+                z,
+            )| {
+                x.sub_mul_assign(&y, &z)
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec(Float, Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_unsigned_quadruple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            (
+                "Float.sub_mul_prec(Float, Float, u64)",
+                &mut |(x, y, z, prec)| no_out!(x.sub_mul_prec(y, z, prec)),
+            ),
+            (
+                "Float.sub_mul_prec_val_ref_ref(&Float, &Float, u64)",
+                &mut |(x, y, z, prec)| no_out!(x.sub_mul_prec_val_ref_ref(&y, &z, prec)),
+            ),
+            (
+                "(&Float).sub_mul_prec_ref_ref_ref(&Float, &Float, u64)",
+                &mut |(x, y, z, prec)| no_out!(x.sub_mul_prec_ref_ref_ref(&y, &z, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec(Float, Float, u64)",
+        BenchmarkType::LibraryComparison,
+        float_float_float_unsigned_quadruple_gen_var_1_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, z, prec))| {
+                no_out!(x.sub_mul_prec(y, z, prec))
+            }),
+            ("rug", &mut |((x, y, z, prec), _)| {
+                no_out!(rug::Float::with_val(
+                    u32::exact_from(prec),
+                    (-y).mul_add(&z, &x)
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec_assign(Float, Float, u64)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_unsigned_quadruple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            (
+                "Float.sub_mul_prec_assign(Float, Float, u64)",
+                &mut |(mut x, y, z, prec)| no_out!(x.sub_mul_prec_assign(y, z, prec)),
+            ),
+            (
+                "Float.sub_mul_prec_assign_ref_ref(&Float, &Float, u64)",
+                &mut |(mut x, y, z, prec)| no_out!(x.sub_mul_prec_assign_ref_ref(&y, &z, prec)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_round(Float, Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_rounding_mode_quadruple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            (
+                "Float.sub_mul_round(Float, Float, RoundingMode)",
+                &mut |(x, y, z, rm)| no_out!(x.sub_mul_round(y, z, rm)),
+            ),
+            (
+                "Float.sub_mul_round_val_ref_ref(&Float, &Float, RoundingMode)",
+                &mut |(x, y, z, rm)| no_out!(x.sub_mul_round_val_ref_ref(&y, &z, rm)),
+            ),
+            (
+                "(&Float).sub_mul_round_ref_ref_ref(&Float, &Float, RoundingMode)",
+                &mut |(x, y, z, rm)| no_out!(x.sub_mul_round_ref_ref_ref(&y, &z, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_round(Float, Float, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_float_rounding_mode_quadruple_gen_var_2_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quadruple_1_2_3_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, z, rm))| {
+                no_out!(x.sub_mul_round(y, z, rm))
+            }),
+            ("rug", &mut |((x, y, z, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    max(max(x.prec(), y.prec()), z.prec()),
+                    (-y).mul_add(&z, &x),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_round_assign(Float, Float, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_rounding_mode_quadruple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quadruple_1_2_3_float_max_complexity_bucketer("x", "y", "z"),
+        &mut [
+            (
+                "Float.sub_mul_round_assign(Float, Float, RoundingMode)",
+                &mut |(mut x, y, z, rm)| no_out!(x.sub_mul_round_assign(y, z, rm)),
+            ),
+            (
+                "Float.sub_mul_round_assign_ref_ref(&Float, &Float, RoundingMode)",
+                &mut |(mut x, y, z, rm)| no_out!(x.sub_mul_round_assign_ref_ref(&y, &z, rm)),
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_round_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec_round(Float, Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_unsigned_rounding_mode_quintuple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quintuple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            (
+                "Float.sub_mul_prec_round(Float, Float, u64, RoundingMode)",
+                &mut |(x, y, z, prec, rm)| no_out!(x.sub_mul_prec_round(y, z, prec, rm)),
+            ),
+            (
+                "Float.sub_mul_prec_round_val_ref_ref(&Float, &Float, u64, RoundingMode)",
+                &mut |(x, y, z, prec, rm)| {
+                    no_out!(x.sub_mul_prec_round_val_ref_ref(&y, &z, prec, rm))
+                },
+            ),
+            (
+                "(&Float).sub_mul_prec_round_ref_ref_ref(&Float, &Float, u64, RoundingMode)",
+                &mut |(x, y, z, prec, rm)| {
+                    no_out!(x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm))
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec_round(Float, Float, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        float_float_float_unsigned_rounding_mode_quintuple_gen_var_2_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_quintuple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            ("Malachite", &mut |(_, (x, y, z, prec, rm))| {
+                no_out!(x.sub_mul_prec_round(y, z, prec, rm))
+            }),
+            ("rug", &mut |((x, y, z, prec, rm), _)| {
+                no_out!(rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    (-y).mul_add(&z, &x),
+                    rm
+                ))
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_sub_mul_prec_round_assign_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.sub_mul_prec_round_assign(Float, Float, u64, RoundingMode)",
+        BenchmarkType::EvaluationStrategy,
+        float_float_float_unsigned_rounding_mode_quintuple_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &quintuple_1_2_3_4_max_float_float_float_prec_bucketer("x", "y", "z", "prec"),
+        &mut [
+            (
+                "Float.sub_mul_prec_round_assign(Float, Float, u64, RoundingMode)",
+                &mut |(mut x, y, z, prec, rm)| no_out!(x.sub_mul_prec_round_assign(y, z, prec, rm)),
+            ),
+            (
+                "Float.sub_mul_prec_round_assign_ref_ref(&Float, &Float, u64, RoundingMode)",
+                &mut |(mut x, y, z, prec, rm)| {
+                    no_out!(x.sub_mul_prec_round_assign_ref_ref(&y, &z, prec, rm))
+                },
+            ),
+        ],
+    );
+}
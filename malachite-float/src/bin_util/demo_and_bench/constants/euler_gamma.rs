@@ -0,0 +1,121 @@
+use malachite_base::test_util::bench::bucketers::{pair_1_bucketer, unsigned_direct_bucketer};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::common::rug_round_exact_from_rounding_mode;
+use malachite_float::test_util::constants::euler_gamma::{
+    rug_euler_gamma_prec, rug_euler_gamma_prec_round,
+};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, Float};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_euler_gamma_prec);
+    register_demo!(runner, demo_float_euler_gamma_prec_debug);
+    register_demo!(runner, demo_float_euler_gamma_prec_round);
+    register_demo!(runner, demo_float_euler_gamma_prec_round_debug);
+
+    register_bench!(runner, benchmark_float_euler_gamma_prec_library_comparison);
+    register_bench!(
+        runner,
+        benchmark_float_euler_gamma_prec_round_library_comparison
+    );
+}
+
+fn demo_float_euler_gamma_prec(gm: GenMode, config: &GenConfig, limit: usize) {
+    for p in unsigned_gen_var_11().get(gm, config).take(limit) {
+        println!("euler_gamma_prec({}) = {:?}", p, Float::euler_gamma_prec(p));
+    }
+}
+
+fn demo_float_euler_gamma_prec_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for p in unsigned_gen_var_11().get(gm, config).take(limit) {
+        let (x, o) = Float::euler_gamma_prec(p);
+        println!(
+            "euler_gamma_prec({}) = ({:#x}, {:?})",
+            p,
+            ComparableFloat(x),
+            o
+        );
+    }
+}
+
+fn demo_float_euler_gamma_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (p, rm) in unsigned_rounding_mode_pair_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "euler_gamma_prec_round({}, {}) = {:?}",
+            p,
+            rm,
+            Float::euler_gamma_prec_round(p, rm)
+        );
+    }
+}
+
+fn demo_float_euler_gamma_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (p, rm) in unsigned_rounding_mode_pair_gen_var_3()
+        .get(gm, config)
+        .take(limit)
+    {
+        let (x, o) = Float::euler_gamma_prec_round(p, rm);
+        println!(
+            "euler_gamma_prec_round({}, {}) = ({:#x}, {:?})",
+            p,
+            rm,
+            ComparableFloat(x),
+            o
+        );
+    }
+}
+
+fn benchmark_float_euler_gamma_prec_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::euler_gamma_prec(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_11().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |p| no_out!(Float::euler_gamma_prec(p))),
+            ("rug", &mut |p| no_out!(rug_euler_gamma_prec(p))),
+        ],
+    );
+}
+
+fn benchmark_float_euler_gamma_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::euler_gamma_prec_round(u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        unsigned_rounding_mode_pair_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_bucketer("prec"),
+        &mut [
+            ("Malachite", &mut |(p, rm)| {
+                no_out!(Float::euler_gamma_prec_round(p, rm))
+            }),
+            ("rug", &mut |(p, rm)| {
+                no_out!(rug_euler_gamma_prec_round(
+                    p,
+                    rug_round_exact_from_rounding_mode(rm)
+                ))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,15 @@
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    catalan::register(runner);
+    e::register(runner);
+    euler_gamma::register(runner);
+    ln_2::register(runner);
+    pi::register(runner);
+}
+
+mod catalan;
+mod e;
+mod euler_gamma;
+mod ln_2;
+mod pi;
@@ -0,0 +1,178 @@
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString};
+use malachite_base::test_util::bench::bucketers::{
+    pair_1_string_len_bucketer, string_len_bucketer, triple_2_bucketer,
+};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::{
+    string_from_sci_string_options_pair_gen_var_2, string_gen_var_15,
+};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::common::rug_round_exact_from_rounding_mode;
+use malachite_float::test_util::generators::string_unsigned_rounding_mode_triple_gen_var_1;
+use malachite_float::{ComparableFloat, Float};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_from_sci_string_prec_round);
+    register_demo!(runner, demo_float_from_sci_string_prec_round_debug);
+    register_demo!(runner, demo_float_from_sci_string);
+    register_demo!(runner, demo_float_from_sci_string_debug);
+    register_demo!(runner, demo_float_from_sci_string_with_options);
+    register_demo!(runner, demo_float_from_sci_string_with_options_debug);
+
+    register_bench!(
+        runner,
+        benchmark_float_from_sci_string_prec_round_library_comparison
+    );
+    register_bench!(runner, benchmark_float_from_sci_string);
+    register_bench!(runner, benchmark_float_from_sci_string_with_options);
+}
+
+fn demo_float_from_sci_string_prec_round(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (s, p, rm) in string_unsigned_rounding_mode_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "from_sci_string_prec_round({:?}, {}, {}) = {:?}",
+            s,
+            p,
+            rm,
+            Float::from_sci_string_prec_round(&s, FromSciStringOptions::default(), p, rm)
+        );
+    }
+}
+
+fn demo_float_from_sci_string_prec_round_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (s, p, rm) in string_unsigned_rounding_mode_triple_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "from_sci_string_prec_round({:?}, {}, {}) = {:?}",
+            s,
+            p,
+            rm,
+            Float::from_sci_string_prec_round(&s, FromSciStringOptions::default(), p, rm)
+                .map(|(x, o)| (ComparableFloat(x), o))
+        );
+    }
+}
+
+fn demo_float_from_sci_string(gm: GenMode, config: &GenConfig, limit: usize) {
+    for s in string_gen_var_15().get(gm, config).take(limit) {
+        println!(
+            "from_sci_string({:?}) = {:?}",
+            s,
+            Float::from_sci_string(&s)
+        );
+    }
+}
+
+fn demo_float_from_sci_string_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for s in string_gen_var_15().get(gm, config).take(limit) {
+        println!(
+            "from_sci_string({:?}) = {:?}",
+            s,
+            Float::from_sci_string(&s).map(ComparableFloat)
+        );
+    }
+}
+
+fn demo_float_from_sci_string_with_options(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (s, options) in string_from_sci_string_options_pair_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "from_sci_string_with_options({:?}, {:?}) = {:?}",
+            s,
+            options,
+            Float::from_sci_string_with_options(&s, options)
+        );
+    }
+}
+
+fn demo_float_from_sci_string_with_options_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (s, options) in string_from_sci_string_options_pair_gen_var_2()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "from_sci_string_with_options({:?}, {:?}) = {:?}",
+            s,
+            options,
+            Float::from_sci_string_with_options(&s, options).map(ComparableFloat)
+        );
+    }
+}
+
+fn benchmark_float_from_sci_string_prec_round_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::from_sci_string_prec_round(&str, FromSciStringOptions, u64, RoundingMode)",
+        BenchmarkType::LibraryComparison,
+        string_unsigned_rounding_mode_triple_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_2_bucketer("prec"),
+        &mut [
+            ("Malachite", &mut |(s, p, rm)| {
+                no_out!(Float::from_sci_string_prec_round(
+                    &s,
+                    FromSciStringOptions::default(),
+                    p,
+                    rm
+                ))
+            }),
+            ("rug", &mut |(s, p, rm)| {
+                if let Ok(x) = rug::Float::parse(&s) {
+                    no_out!(rug::Float::with_val_round(
+                        u32::exact_from(p),
+                        x,
+                        rug_round_exact_from_rounding_mode(rm)
+                    ))
+                }
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_from_sci_string(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Float::from_sci_string(&str)",
+        BenchmarkType::Single,
+        string_gen_var_15().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &string_len_bucketer(),
+        &mut [("Malachite", &mut |s| no_out!(Float::from_sci_string(&s)))],
+    );
+}
+
+fn benchmark_float_from_sci_string_with_options(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float::from_sci_string_with_options(&str, FromSciStringOptions)",
+        BenchmarkType::Single,
+        string_from_sci_string_options_pair_gen_var_2().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_string_len_bucketer("s"),
+        &mut [("Malachite", &mut |(s, options)| {
+            no_out!(Float::from_sci_string_with_options(&s, options))
+        })],
+    );
+}
@@ -0,0 +1,160 @@
+use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_float::test_util::bench::bucketers::{
+    float_complexity_bucketer, pair_1_float_complexity_bucketer,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_gen_var_3, float_to_sci_options_pair_gen, float_to_sci_options_pair_gen_var_1,
+};
+use malachite_float::ComparableFloatRef;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_float_to_sci);
+    register_demo!(runner, demo_float_to_sci_debug);
+    register_demo!(runner, demo_float_fmt_sci_valid);
+    register_demo!(runner, demo_float_fmt_sci_valid_debug);
+    register_demo!(runner, demo_float_to_sci_with_options);
+    register_demo!(runner, demo_float_to_sci_with_options_debug);
+
+    register_bench!(runner, benchmark_float_to_sci_algorithms);
+    register_bench!(runner, benchmark_float_fmt_sci_valid);
+    register_bench!(runner, benchmark_float_to_sci_with_options_algorithms);
+}
+
+fn demo_float_to_sci(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("{}.to_sci() = {}", x, x.to_sci());
+    }
+}
+
+fn demo_float_to_sci_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in float_gen().get(gm, config).take(limit) {
+        println!("{:#x}.to_sci() = {}", ComparableFloatRef(&x), x.to_sci());
+    }
+}
+
+fn demo_float_fmt_sci_valid(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, options) in float_to_sci_options_pair_gen().get(gm, config).take(limit) {
+        if x.fmt_sci_valid(options) {
+            println!("{x} can be converted to sci using {options:?}");
+        } else {
+            println!("{x} cannot be converted to sci using {options:?}");
+        }
+    }
+}
+
+fn demo_float_fmt_sci_valid_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, options) in float_to_sci_options_pair_gen().get(gm, config).take(limit) {
+        if x.fmt_sci_valid(options) {
+            println!(
+                "{:#x} can be converted to sci using {:?}",
+                ComparableFloatRef(&x),
+                options
+            );
+        } else {
+            println!(
+                "{:#x} cannot be converted to sci using {:?}",
+                ComparableFloatRef(&x),
+                options
+            );
+        }
+    }
+}
+
+fn demo_float_to_sci_with_options(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, options) in float_to_sci_options_pair_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "to_sci_with_options({}, {:?}) = {}",
+            x,
+            options,
+            x.to_sci_with_options(options)
+        );
+    }
+}
+
+fn demo_float_to_sci_with_options_debug(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, options) in float_to_sci_options_pair_gen_var_1()
+        .get(gm, config)
+        .take(limit)
+    {
+        println!(
+            "to_sci_with_options({:#x}, {:?}) = {}",
+            ComparableFloatRef(&x),
+            options,
+            x.to_sci_with_options(options)
+        );
+    }
+}
+
+fn benchmark_float_to_sci_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.to_sci()",
+        BenchmarkType::Algorithms,
+        float_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &float_complexity_bucketer("x"),
+        &mut [
+            ("default", &mut |x| no_out!(x.to_sci().to_string())),
+            ("using Rational", &mut |x| {
+                no_out!(Rational::exact_from(x).to_sci().to_string())
+            }),
+        ],
+    );
+}
+
+fn benchmark_float_fmt_sci_valid(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Float.fmt_sci_valid(ToSciOptions)",
+        BenchmarkType::Single,
+        float_to_sci_options_pair_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [("Malachite", &mut |(x, options)| {
+            no_out!(x.fmt_sci_valid(options))
+        })],
+    );
+}
+
+fn benchmark_float_to_sci_with_options_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Float.to_sci_with_options(ToSciOptions)",
+        BenchmarkType::Algorithms,
+        float_to_sci_options_pair_gen_var_1().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_1_float_complexity_bucketer("x"),
+        &mut [
+            ("default", &mut |(x, options)| {
+                no_out!(x.to_sci_with_options(options).to_string())
+            }),
+            ("using Rational", &mut |(x, options)| {
+                if x.is_finite() {
+                    no_out!(Rational::exact_from(x)
+                        .to_sci_with_options(options)
+                        .to_string())
+                }
+            }),
+        ],
+    );
+}
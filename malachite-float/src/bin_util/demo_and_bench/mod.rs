@@ -4,10 +4,12 @@ pub(crate) fn register(runner: &mut Runner) {
     arithmetic::register(runner);
     basic::register(runner);
     comparison::register(runner);
+    constants::register(runner);
     conversion::register(runner);
 }
 
 mod arithmetic;
 mod basic;
 mod comparison;
+mod constants;
 mod conversion;
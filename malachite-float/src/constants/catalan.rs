@@ -0,0 +1,151 @@
+use crate::constants::{binary_split, cached_fixed, constant_prec_round, FixedCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{Pow, Square};
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static CATALAN_CACHE: FixedCache = const { RefCell::new(None) };
+}
+
+// Lupaş's formula,
+// $$
+// G = \frac{1}{64}\sum_{k=1}^\infty (-1)^{k-1}\frac{2^{8k}(40k^2-24k+3)((2k)!)^3(k!)^2}
+// {k^3(2k-1)((4k)!)^2},
+// $$
+// is evaluated by summing the first $K - 1$ terms exactly, using binary splitting, and flooring the
+// sum.
+//
+// Since $\binom{2n}{n} \geq 4^n/(2\sqrt n)$, the $k$th term of the sum is less than $640 \cdot
+// 4^{-k}$, so the tail of the series is less than $2^{4-2K}$. Choosing $K > p/2 + 2$, where $p$ is
+// the scale, makes it less than $2^{-p}$, and the error is therefore less than 2.
+fn catalan_fixed_uncached(scale: u64) -> (Natural, Natural) {
+    let forty = Natural::from(40u32);
+    let (_, q, b, t) = binary_split(1, (scale >> 1) + 3, &|k| {
+        let k_cubed = Natural::from(k).pow(3);
+        let p = Integer::from(&k_cubed * Natural::from(2 * k - 1)) * Integer::from(-32);
+        let q = (Natural::from(4 * k - 1) * Natural::from(4 * k - 3)).square();
+        let a = Natural::from(k).square() * &forty - Natural::from(24 * k - 3);
+        let b = k_cubed * Natural::from(2 * k - 1);
+        (p, q, a, b)
+    });
+    (
+        (Natural::exact_from(-t) << scale) / ((b * q) << 6u32),
+        Natural::TWO,
+    )
+}
+
+// Approximates $G$ as a fixed-point value with `scale` fractional bits, returning it along with its
+// error.
+pub(crate) fn catalan_fixed(scale: u64) -> (Natural, Natural) {
+    cached_fixed(&CATALAN_CACHE, scale, catalan_fixed_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $G$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (The constant is believed to be
+    /// irrational, although this has not been proven; the rounded value is never equal to it.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $G$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p, m) = G+\varepsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::catalan_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::catalan_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.915");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::catalan_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.916");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::catalan_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.915965594177219015054603514932");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::catalan_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(prec, rm, catalan_fixed)
+    }
+
+    /// Returns an approximation of $G$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (The constant is
+    /// believed to be irrational, although this has not been proven; the rounded value is never
+    /// equal to it.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $G$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p) = G+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::catalan_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::catalan_prec(10);
+    /// assert_eq!(x.to_string(), "0.916");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::catalan_prec(100);
+    /// assert_eq!(x.to_string(), "0.9159655941772190150546035149325");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn catalan_prec(prec: u64) -> (Float, Ordering) {
+        Float::catalan_prec_round(prec, RoundingMode::Nearest)
+    }
+}
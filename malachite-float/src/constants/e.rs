@@ -0,0 +1,143 @@
+use crate::constants::{binary_split, cached_fixed, constant_prec_round, FixedCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{FloorLogBase2, PowerOf2};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static E_CACHE: FixedCache = const { RefCell::new(None) };
+}
+
+// $e = \sum_{k=0}^\infty 1/k!$. The first $K$ terms are summed exactly, using binary splitting,
+// and the sum is floored. The tail of the series is less than $2/K!$, and $K$ is chosen so that
+// $\sum_{k=1}^K \lfloor \log_2 k \rfloor \geq p + 2$, where $p$ is the scale, which makes the tail
+// less than $2^{-p-1}$. The error is therefore less than 2.
+fn e_fixed_uncached(scale: u64) -> (Natural, Natural) {
+    let mut k = 1u64;
+    let mut bits = 0;
+    while bits < scale + 2 {
+        k += 1;
+        bits += k.floor_log_base_2();
+    }
+    let (_, q, _, t) = binary_split(1, k, &|k| {
+        (Integer::ONE, Natural::from(k), Natural::ONE, Natural::ONE)
+    });
+    (
+        Natural::power_of_2(scale) + (Natural::exact_from(t) << scale) / q,
+        Natural::TWO,
+    )
+}
+
+// Approximates $e$ as a fixed-point value with `scale` fractional bits, returning it along with its
+// error.
+pub(crate) fn e_fixed(scale: u64) -> (Natural, Natural) {
+    cached_fixed(&E_CACHE, scale, e_fixed_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $e$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $e$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p, m) = e+\varepsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+2}$.
+    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::e_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::e_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "2.715");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::e_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "2.719");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::e_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::e_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "2.718281828459045235360287471354");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn e_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(prec, rm, e_fixed)
+    }
+
+    /// Returns an approximation of $e$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $e$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p) = e+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p+1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::e_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::e_prec(10);
+    /// assert_eq!(x.to_string(), "2.719");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::e_prec(100);
+    /// assert_eq!(x.to_string(), "2.718281828459045235360287471351");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn e_prec(prec: u64) -> (Float, Ordering) {
+        Float::e_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,182 @@
+use crate::constants::ln_2::ln_2_fixed;
+use crate::constants::{cached_fixed, constant_prec_round, FixedCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{CeilingLogBase2, PowerOf2, Square};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static EULER_GAMMA_CACHE: FixedCache = const { RefCell::new(None) };
+}
+
+// Sums $\sum_{k=l}^{h-1} c_k$ and $\sum_{k=l}^{h-1} c_k H_{l,k}$ exactly by binary splitting,
+// where $c_k = \prod_{j=l}^k 2^s/j^2$, $H_{l,k} = \sum_{j=l}^k 1/j$, and $l < h$. Returns $(Q, D, T,
+// C, V)$, where $Q = \prod_{k=l}^{h-1} k^2$, $D = \prod_{k=l}^{h-1} k$, $C/D = H_{l,h-1}$, and the
+// sums are $T/Q$ and $V/(QD)$.
+fn harmonic_binary_split(
+    lo: u64,
+    hi: u64,
+    shift: u64,
+) -> (Natural, Natural, Natural, Natural, Natural) {
+    if hi - lo == 1 {
+        let p = Natural::power_of_2(shift);
+        return (
+            Natural::from(lo).square(),
+            Natural::from(lo),
+            p.clone(),
+            Natural::ONE,
+            p,
+        );
+    }
+    let mid = lo + ((hi - lo) >> 1);
+    let (q_lo, d_lo, t_lo, c_lo, v_lo) = harmonic_binary_split(lo, mid, shift);
+    let (q_hi, d_hi, t_hi, c_hi, v_hi) = harmonic_binary_split(mid, hi, shift);
+    // The product of the numerators $2^s$ of the left half is $2^{s(m-l)}$.
+    let p_lo_shift = shift * (mid - lo);
+    let v = v_lo * &q_hi * &d_hi + ((&c_lo * &t_hi * &d_hi + v_hi * &d_lo) << p_lo_shift);
+    let t = t_lo * &q_hi + (t_hi << p_lo_shift);
+    let c = c_lo * &d_hi + &d_lo * c_hi;
+    (q_lo * q_hi, d_lo * d_hi, t, c, v)
+}
+
+// Brent and McMillan's algorithm. Let $n = 2^m$, $B_k = n^{2k}/(k!)^2$, and $A_k = B_k(H_k - \ln
+// n)$, where $H_k$ is the $k$th harmonic number. Then $0 < U/V - \gamma < \pi e^{-4n}$, where $U =
+// \sum_k A_k$ and $V = \sum_k B_k$; choosing $n > p/4$, where $p$ is the working scale, makes this
+// less than $2^{-p}$. Since $U/V = \sum_k B_k H_k/V - \ln n$, only the sums of $B_k$ and $B_kH_k$ are
+// needed, and the first $K = 6n$ terms of each are computed exactly by binary splitting.
+//
+// Since $K! \geq (K/e)^K$, $B_K < (e/6)^{12n} < 2^{-13n}$, and for $k \geq K$ each $B_k$ is less
+// than $1/36$ of the previous one. $V > 1$ and $H_k \leq k$, so truncating both sums changes $U/V$
+// by less than $3KB_K < 2^{-p}$. The quotient is floored, and $\ln n = m\ln 2$ is computed with the
+// error of `ln_2_fixed` multiplied by $m$.
+fn euler_gamma_fixed_uncached(scale: u64) -> (Natural, Natural) {
+    let guard = scale.significant_bits() + 10;
+    let p = scale + guard;
+    let m = ((p >> 2) + 1).ceiling_log_base_2();
+    let n = 1u64 << m;
+    let (q, d, t, _, v) = harmonic_binary_split(1, 6 * n, m << 1);
+    let (ln_2, ln_2_error) = ln_2_fixed(p);
+    let m = Natural::from(m);
+    let gamma = Integer::from((v << p) / (d * (q + t))) - Integer::from(ln_2 * &m);
+    let error = ln_2_error * m + Natural::from(3u32);
+    (
+        Natural::exact_from(gamma) >> guard,
+        (error >> guard) + Natural::TWO,
+    )
+}
+
+// Approximates $\gamma$ as a fixed-point value with `scale` fractional bits, returning it along
+// with its error.
+pub(crate) fn euler_gamma_fixed(scale: u64) -> (Natural, Natural) {
+    cached_fixed(&EULER_GAMMA_CACHE, scale, euler_gamma_fixed_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\gamma$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (The constant is believed to be
+    /// irrational, although this has not been proven; the rounded value is never equal to it.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing
+    /// $\gamma$ again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p, m) = \gamma+\varepsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::euler_gamma_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::euler_gamma_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.577");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::euler_gamma_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.578");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::euler_gamma_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::euler_gamma_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.577215664901532860606512090083");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(prec, rm, euler_gamma_fixed)
+    }
+
+    /// Returns an approximation of $\gamma$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (The constant is
+    /// believed to be irrational, although this has not been proven; the rounded value is never
+    /// equal to it.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing
+    /// $\gamma$ again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p) = \gamma+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::euler_gamma_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::euler_gamma_prec(10);
+    /// assert_eq!(x.to_string(), "0.577");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::euler_gamma_prec(100);
+    /// assert_eq!(x.to_string(), "0.5772156649015328606065120900823");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn euler_gamma_prec(prec: u64) -> (Float, Ordering) {
+        Float::euler_gamma_prec_round(prec, RoundingMode::Nearest)
+    }
+}
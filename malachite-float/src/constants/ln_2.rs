@@ -0,0 +1,147 @@
+use crate::constants::{binary_split, cached_fixed, constant_prec_round, FixedCache};
+use crate::Float;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static LN_2_CACHE: FixedCache = const { RefCell::new(None) };
+}
+
+// $\ln 2 = 2\operatorname{artanh}(1/3) = \sum_{k=0}^\infty 2/((2k+1)3^{2k+1})$. The first $K$
+// terms are summed exactly, using binary splitting, and the sum is floored. The tail of the series
+// is less than $9^{-K}$, so choosing $K > p/3$, where $p$ is the scale, makes it less than
+// $2^{-p}$. The error is therefore less than 2.
+fn ln_2_fixed_uncached(scale: u64) -> (Natural, Natural) {
+    let (_, q, b, t) = binary_split(0, scale / 3 + 1, &|k| {
+        if k == 0 {
+            (
+                Integer::TWO,
+                Natural::from(3u32),
+                Natural::ONE,
+                Natural::ONE,
+            )
+        } else {
+            (
+                Integer::ONE,
+                Natural::from(9u32),
+                Natural::ONE,
+                Natural::from(2 * k + 1),
+            )
+        }
+    });
+    ((Natural::exact_from(t) << scale) / (b * q), Natural::TWO)
+}
+
+// Approximates $\ln 2$ as a fixed-point value with `scale` fractional bits, returning it along with
+// its error.
+pub(crate) fn ln_2_fixed(scale: u64) -> (Natural, Natural) {
+    cached_fixed(&LN_2_CACHE, scale, ln_2_fixed_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\ln 2$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $\ln
+    /// 2$ again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p, m) = \ln 2+\varepsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p}$.
+    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::ln_2_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::ln_2_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.692");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::ln_2_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.693");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::ln_2_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(x.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::ln_2_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(x.to_string(), "0.693147180559945309417232121459");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(prec, rm, ln_2_fixed)
+    }
+
+    /// Returns an approximation of $\ln 2$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $\ln
+    /// 2$ again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p) = \ln 2+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p-1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::ln_2_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (x, o) = Float::ln_2_prec(10);
+    /// assert_eq!(x.to_string(), "0.693");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::ln_2_prec(100);
+    /// assert_eq!(x.to_string(), "0.693147180559945309417232121458");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn ln_2_prec(prec: u64) -> (Float, Ordering) {
+        Float::ln_2_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::arithmetic::exp::round_approximation;
+use crate::Float;
+use malachite_base::num::basic::traits::Two;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::{max, Ordering};
+use std::thread::LocalKey;
+
+// The most precise fixed-point approximation of a constant computed so far on the current thread,
+// stored as `(scale, value, error)`.
+pub(crate) type FixedCache = RefCell<Option<(u64, Natural, Natural)>>;
+
+// Approximates a constant as a fixed-point value with `scale` fractional bits, returning it along
+// with its error. `compute` does the same without caching.
+//
+// If `cache` holds an approximation with at least `scale` fractional bits, it is truncated, which
+// adds less than 1 to its error, and the truncated error is increased by 1 to account for its own
+// truncation. Otherwise a new approximation is computed and replaces the cached one. If there was a
+// cached approximation, the new one has at least 1.5 times as many fractional bits, so that
+// computing the constant to increasing precisions takes only a constant factor longer than
+// computing it to the final precision.
+pub(crate) fn cached_fixed(
+    cache: &'static LocalKey<FixedCache>,
+    scale: u64,
+    compute: fn(u64) -> (Natural, Natural),
+) -> (Natural, Natural) {
+    cache.with(|cache| {
+        let mut new_scale = scale;
+        if let Some((cached_scale, value, error)) = &*cache.borrow() {
+            if *cached_scale == scale {
+                return (value.clone(), error.clone());
+            } else if *cached_scale > scale {
+                let shift = cached_scale - scale;
+                return (value >> shift, (error >> shift) + Natural::TWO);
+            }
+            new_scale = max(scale, cached_scale + (cached_scale >> 1));
+        }
+        let (value, error) = compute(new_scale);
+        let shift = new_scale - scale;
+        let result = if shift == 0 {
+            (value.clone(), error.clone())
+        } else {
+            (&value >> shift, (&error >> shift) + Natural::TWO)
+        };
+        *cache.borrow_mut() = Some((new_scale, value, error));
+        result
+    })
+}
+
+// Sums $\sum_{k=l}^{h-1} \frac{a(k)}{b(k)}\prod_{j=l}^k \frac{p(j)}{q(j)}$ exactly by binary
+// splitting, where `term(k)` returns $(p(k), q(k), a(k), b(k))$ and $l < h$. Returns $(P, Q, B, T)$,
+// where $P$, $Q$, and $B$ are the products of the $p(k)$, $q(k)$, and $b(k)$, and the sum is
+// $T/(BQ)$.
+//
+// The two halves of the range are summed recursively and combined with a few multiplications, so if
+// the values returned by `term` have $O(\log k)$ bits, summing $n$ terms takes $O(M(n\log n)\log
+// n)$ time, where $M(n)$ is the time needed to multiply two $n$-bit numbers.
+pub(crate) fn binary_split<F: Fn(u64) -> (Integer, Natural, Natural, Natural)>(
+    lo: u64,
+    hi: u64,
+    term: &F,
+) -> (Integer, Natural, Natural, Integer) {
+    if hi - lo == 1 {
+        let (p, q, a, b) = term(lo);
+        let t = &p * Integer::from(a);
+        return (p, q, b, t);
+    }
+    let mid = lo + ((hi - lo) >> 1);
+    let (p_lo, q_lo, b_lo, t_lo) = binary_split(lo, mid, term);
+    let (p_hi, q_hi, b_hi, t_hi) = binary_split(mid, hi, term);
+    let t = t_lo * Integer::from(&b_hi * &q_hi) + t_hi * Integer::from(&b_lo) * &p_lo;
+    (p_lo * p_hi, q_lo * q_hi, b_lo * b_hi, t)
+}
+
+// Rounds a positive constant to precision `prec`, given a function that approximates it as a
+// fixed-point value with a given number of fractional bits. The constant must be at least 1/2, so
+// that the approximation has roughly as many significant bits as fractional bits, and must not be
+// a dyadic rational.
+pub(crate) fn constant_prec_round(
+    prec: u64,
+    rm: RoundingMode,
+    fixed: fn(u64) -> (Natural, Natural),
+) -> (Float, Ordering) {
+    assert_ne!(prec, 0);
+    round_approximation(prec, rm, |working_prec| {
+        let (value, error) = fixed(working_prec);
+        (Integer::from(value), error, -i64::exact_from(working_prec))
+    })
+}
+
+/// Catalan's constant, $G$.
+pub mod catalan;
+/// Euler's number, $e$.
+pub mod e;
+/// The Euler-Mascheroni constant, $\gamma$.
+pub mod euler_gamma;
+/// The natural logarithm of 2.
+pub mod ln_2;
+/// The constant $\pi$.
+pub mod pi;
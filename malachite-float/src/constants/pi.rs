@@ -0,0 +1,156 @@
+use crate::arithmetic::sqrt::sqrt_fixed;
+use crate::constants::{binary_split, cached_fixed, constant_prec_round, FixedCache};
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+thread_local! {
+    static PI_CACHE: FixedCache = const { RefCell::new(None) };
+}
+
+// The Chudnovskys' formula, $\pi = 426880\sqrt{10005}/S$, where
+// $$
+// S = \sum_{k=0}^\infty (-1)^k\frac{(6k)!(13591409+545140134k)}{(3k)!(k!)^3 640320^{3k}},
+// $$
+// is evaluated by summing the first $K$ terms of $S$ exactly, using binary splitting.
+//
+// Since $(6k)!/((3k)!(k!)^3) \leq 1728^k$ and $1728/640320^3 < 2^{-47}$, the $k$th term is less
+// than $2^{30}(k+1)2^{-47k}$, and $S > 2^{23}$. The series alternates, so choosing $K > p/47 + 1$,
+// where $p$ is the scale, makes the relative error of the partial sum, and hence that of the
+// computed $\pi$, much less than $2^{-p-2}$. The computed $\sqrt{10005}$ is within 1 of the exact
+// value, which changes the quotient by less than $1/30$, and the quotient is floored, so the total
+// error is less than 3.
+fn pi_fixed_uncached(scale: u64) -> (Natural, Natural) {
+    let c_cubed_over_24 = Natural::from(10939058860032000u64);
+    let (_, q, _, t) = binary_split(1, (scale / 47) + 2, &|k| {
+        (
+            -Integer::from(
+                Natural::from(6 * k - 5) * Natural::from(2 * k - 1) * Natural::from(6 * k - 1),
+            ),
+            &c_cubed_over_24 * Natural::from(k).pow(3),
+            Natural::from(13591409 + 545140134 * k),
+            Natural::ONE,
+        )
+    });
+    let s = Natural::exact_from(Integer::from(&q * Natural::from(13591409u32)) + t);
+    let sqrt_10005 = sqrt_fixed(&Natural::from(10005u32), 0, scale);
+    (
+        sqrt_10005 * q * Natural::from(426880u32) / s,
+        Natural::from(3u32),
+    )
+}
+
+// Approximates $\pi$ as a fixed-point value with `scale` fractional bits, returning it along with
+// its error.
+pub(crate) fn pi_fixed(scale: u64) -> (Natural, Natural) {
+    cached_fixed(&PI_CACHE, scale, pi_fixed_uncached)
+}
+
+impl Float {
+    /// Returns an approximation of $\pi$, with the given precision and rounded using the given
+    /// [`RoundingMode`]. An [`Ordering`] is also returned, indicating whether the rounded value is
+    /// less than or greater than the exact value of the constant. (Since the constant is
+    /// irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $\pi$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p, m) = \pi+\varepsilon.
+    /// $$
+    /// - If $m$ is not `Nearest`, then $|\varepsilon| < 2^{-p+2}$.
+    /// - If $m$ is `Nearest`, then $|\varepsilon| < 2^{-p+1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you know you'll be using `Nearest`, consider using [`Float::pi_prec`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero or if `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (pi, o) = Float::pi_prec_round(10, RoundingMode::Floor);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(10, RoundingMode::Ceiling);
+    /// assert_eq!(pi.to_string(), "3.145");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, RoundingMode::Floor);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec_round(100, RoundingMode::Ceiling);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383282");
+    /// assert_eq!(o, Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn pi_prec_round(prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        constant_prec_round(prec, rm, pi_fixed)
+    }
+
+    /// Returns an approximation of $\pi$, with the given precision and rounded to the nearest
+    /// [`Float`] of that precision. An [`Ordering`] is also returned, indicating whether the
+    /// rounded value is less than or greater than the exact value of the constant. (Since the
+    /// constant is irrational, the rounded value is never equal to the exact value.)
+    ///
+    /// The most precise approximation computed so far is cached on each thread, so computing $\pi$
+    /// again to the same or a lower precision is cheap.
+    ///
+    /// $$
+    /// f(p) = \pi+\varepsilon.
+    /// $$
+    /// - $|\varepsilon| < 2^{-p+1}$.
+    ///
+    /// The output has precision `prec`.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::pi_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let (pi, o) = Float::pi_prec(10);
+    /// assert_eq!(pi.to_string(), "3.141");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (pi, o) = Float::pi_prec(100);
+    /// assert_eq!(pi.to_string(), "3.141592653589793238462643383279");
+    /// assert_eq!(o, Ordering::Less);
+    /// ```
+    #[inline]
+    pub fn pi_prec(prec: u64) -> (Float, Ordering) {
+        Float::pi_prec_round(prec, RoundingMode::Nearest)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::conversion::string::from_string::from_hex_string;
+use crate::{ComparableFloat, Float, SerdeFloat};
+use std::convert::TryFrom;
+
+impl From<Float> for SerdeFloat {
+    #[inline]
+    fn from(x: Float) -> SerdeFloat {
+        SerdeFloat(format!("{:#x}", ComparableFloat(x)))
+    }
+}
+
+impl TryFrom<SerdeFloat> for Float {
+    type Error = String;
+
+    #[inline]
+    fn try_from(s: SerdeFloat) -> Result<Float, String> {
+        from_hex_string(&s.0).ok_or_else(|| format!("Unrecognized Float string '{}'", s.0))
+    }
+}
@@ -0,0 +1,410 @@
+use crate::arithmetic::exp::round_approximation;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::{CeilingLogBase2, FloorLogBase2, Pow};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, NegativeZero, Zero};
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, FromSciString, FromStringBase, IntegerMantissaAndExponent,
+};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::{min, Ordering};
+
+// Strings whose exponent is at least this large in absolute value are rejected, so that the
+// binary exponent of the result always fits in an `i64`.
+const MAX_EXPONENT: u64 = 1 << 58;
+
+// Recognizes the spellings of NaN and the infinities accepted by MPFR: "@nan@" and "@inf@" in any
+// base and, if the base is no greater than 16, also "nan", "inf", and "infinity". Case is
+// ignored, and an optional sign may precede any of these.
+fn special_from_sci_string(s: &str, base: u8) -> Option<Float> {
+    let (sign, s) = if let Some(s) = s.strip_prefix('-') {
+        (false, s)
+    } else {
+        (true, s.strip_prefix('+').unwrap_or(s))
+    };
+    let is = |t: &str| s.eq_ignore_ascii_case(t);
+    if is("@nan@") || base <= 16 && is("nan") {
+        Some(Float::NAN)
+    } else if is("@inf@") || base <= 16 && (is("inf") || is("infinity")) {
+        Some(if sign {
+            Float::INFINITY
+        } else {
+            Float::NEGATIVE_INFINITY
+        })
+    } else {
+        None
+    }
+}
+
+// Splits a finite number written in scientific notation into its sign, its digits (interpreted as
+// an integer), and its exponent, so that its value is $\pm n b^e$.
+fn parse_sci_string(s: &str, options: FromSciStringOptions) -> Option<(bool, Natural, i64)> {
+    let (digits, exponent) = preprocess_sci_string(s, options)?;
+    let (sign, digits) = match digits.split_first() {
+        Some((b'-', digits)) => (false, digits),
+        Some((b'+', digits)) => (true, digits),
+        _ => (true, &digits[..]),
+    };
+    if digits.is_empty() || digits[0] == b'+' || digits[0] == b'-' {
+        return None;
+    }
+    let n = Natural::from_string_base(options.get_base(), std::str::from_utf8(digits).ok()?)?;
+    Some((sign, n, exponent))
+}
+
+// Returns the number of significant digits of a number written in scientific notation: the
+// number of digits, ignoring leading zeros. Trailing zeros are significant.
+fn significant_digits(s: &str, options: FromSciStringOptions) -> Option<u64> {
+    let (digits, _) = preprocess_sci_string(s, options)?;
+    Some(
+        digits
+            .iter()
+            .skip_while(|&&c| c == b'+' || c == b'-' || c == b'0')
+            .count() as u64,
+    )
+}
+
+// Returns lower and upper bounds for $c^k$, where $k > 0$, each with precision `prec`.
+fn power_bounds(c: &Float, k: u64, prec: u64) -> (Float, Float) {
+    let mut lo = c.clone();
+    let mut hi = c.clone();
+    for i in (0..k.significant_bits() - 1).rev() {
+        lo = lo.square_prec_round(prec, RoundingMode::Floor).0;
+        hi = hi.square_prec_round(prec, RoundingMode::Ceiling).0;
+        if k.get_bit(i) {
+            lo = lo.mul_prec_round_val_ref(c, prec, RoundingMode::Floor).0;
+            hi = hi.mul_prec_round_val_ref(c, prec, RoundingMode::Ceiling).0;
+        }
+    }
+    (lo, hi)
+}
+
+// Rounds $\pm n b^e$, where $n > 0$.
+//
+// If $b = 2^t$ the value is converted directly. Otherwise $b = 2^tc$ with $c > 1$ odd. If $|e|$ is
+// small the exact value is constructed as a `Rational`. If $|e|$ is large, the value cannot be
+// exactly representable, or a midpoint between two representable values: when $e > 0$ the odd
+// part of $nc^e$ has more than `prec + 1` bits, and when $e < 0$ the denominator $c^{-e}$ exceeds
+// $n$ and so cannot divide it. In that case $c^{|e|}$ is bracketed by `Float`s and the value is
+// rounded using successively better approximations.
+fn from_significand_and_exponent(
+    sign: bool,
+    n: Natural,
+    exponent: i64,
+    base: u8,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    let t = base.trailing_zeros();
+    let c = base >> t;
+    if c == 1 {
+        return Float::from_integer_times_power_of_2_prec_round(
+            Integer::from_sign_and_abs(sign, n),
+            exponent * i64::from(t),
+            prec,
+            rm,
+        );
+    }
+    let k = exponent.unsigned_abs();
+    if k <= (prec + n.significant_bits() + 1) / c.floor_log_base_2() {
+        return Float::from_rational_prec_round(
+            Rational::from(Integer::from_sign_and_abs(sign, n))
+                * Rational::from(base).pow(exponent),
+            prec,
+            rm,
+        );
+    }
+    let c = Float::from(c);
+    let n_float = Float::from(n.clone());
+    round_approximation(prec, rm, |working_prec| {
+        let (lo, hi) = power_bounds(&c, k, working_prec);
+        let (lo, hi) = if exponent > 0 {
+            (lo, hi)
+        } else {
+            (
+                n_float
+                    .div_prec_round_ref_val(hi, working_prec, RoundingMode::Floor)
+                    .0,
+                n_float
+                    .div_prec_round_ref_val(lo, working_prec, RoundingMode::Ceiling)
+                    .0,
+            )
+        };
+        let (lo, lo_exponent) = lo.integer_mantissa_and_exponent();
+        let (hi, hi_exponent) = hi.integer_mantissa_and_exponent();
+        let low = min(lo_exponent, hi_exponent);
+        let lo = lo << u64::exact_from(lo_exponent - low);
+        let hi = hi << u64::exact_from(hi_exponent - low);
+        let (value, error) = if exponent > 0 {
+            (&lo * &n, (hi - lo) * &n)
+        } else {
+            (lo.clone(), hi - lo)
+        };
+        (
+            Integer::from_sign_and_abs(sign, value),
+            error,
+            low + exponent * i64::from(t),
+        )
+    })
+}
+
+impl Float {
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the given
+    /// precision, rounding using the given [`RoundingMode`]. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the value
+    /// written in the string.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive). The rounding
+    /// mode option is ignored; `rm` is used instead.
+    ///
+    /// If the base is greater than 10, the higher digits are represented by the letters `'a'`
+    /// through `'z'` or `'A'` through `'Z'`; the case doesn't matter and doesn't need to be
+    /// consistent.
+    ///
+    /// Exponents are allowed, and are indicated using the character `'e'` or `'E'`. If the base is
+    /// 15 or greater, an ambiguity arises where it may not be clear whether `'e'` is a digit or
+    /// an exponent indicator. To resolve this ambiguity, always use a `'+'` or `'-'` sign after
+    /// the exponent indicator when the base is 15 or greater. The exponent itself is always parsed
+    /// using base 10, and is a power of the base, not of 2.
+    ///
+    /// Decimal (or other-base) points are allowed.
+    ///
+    /// NaN may be written as `"@nan@"`, and infinity as `"@inf@"`. If the base is 16 or less, the
+    /// spellings `"nan"`, `"inf"`, and `"infinity"` are also accepted. These are case-insensitive
+    /// and may be preceded by a sign. A zero keeps its sign, so `"-0"` is converted to $-0.0$.
+    ///
+    /// If the string is unparseable, or if the absolute value of its exponent is $2^{58}$ or
+    /// greater, `None` is returned.
+    ///
+    /// The result is correctly rounded, even when the exponent is large.
+    ///
+    /// If you're only using [`RoundingMode::Nearest`], try using [`Float::from_sci_string_prec`]
+    /// instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm\log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(s.len(), prec)`, and $m$ is the
+    /// number of bits in the absolute value of the exponent.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the value cannot be represented exactly
+    /// with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let options = FromSciStringOptions::default();
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("0.1", options, 10, RoundingMode::Floor).unwrap();
+    /// assert_eq!(x.to_string(), "0.09998");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("0.1", options, 10, RoundingMode::Ceiling).unwrap();
+    /// assert_eq!(x.to_string(), "0.1001");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("-1.5e3", options, 10, RoundingMode::Exact).unwrap();
+    /// assert_eq!(x.to_string(), "-1500.0");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("1e-1000", options, 10, RoundingMode::Nearest)
+    ///         .unwrap();
+    /// assert_eq!(x.to_string(), "9.997e-1001");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("-inf", options, 10, RoundingMode::Floor).unwrap();
+    /// assert_eq!(x.to_string(), "-Infinity");
+    /// assert_eq!(o, Ordering::Equal);
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(3);
+    /// let (x, o) =
+    ///     Float::from_sci_string_prec_round("0.1", options, 10, RoundingMode::Nearest).unwrap();
+    /// assert_eq!(x.to_string(), "0.3335");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// assert!(Float::from_sci_string_prec_round("1.2.3", options, 10, RoundingMode::Floor)
+    ///     .is_none());
+    /// ```
+    pub fn from_sci_string_prec_round(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+        rm: RoundingMode,
+    ) -> Option<(Float, Ordering)> {
+        assert_ne!(prec, 0);
+        let base = options.get_base();
+        if let Some(x) = special_from_sci_string(s, base) {
+            return Some((x, Ordering::Equal));
+        }
+        let (sign, n, exponent) = parse_sci_string(s, options)?;
+        if n == 0u32 {
+            return Some((
+                if sign {
+                    Float::ZERO
+                } else {
+                    Float::NEGATIVE_ZERO
+                },
+                Ordering::Equal,
+            ));
+        }
+        if exponent.unsigned_abs() >= MAX_EXPONENT {
+            return None;
+        }
+        Some(from_significand_and_exponent(
+            sign, n, exponent, base, prec, rm,
+        ))
+    }
+
+    /// Converts a string, possibly in scientific notation, to a [`Float`] with the given
+    /// precision, rounding to the nearest [`Float`] of that precision. An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// value written in the string.
+    ///
+    /// See [`Float::from_sci_string_prec_round`] for a description of the accepted strings. The
+    /// rounding mode option of `options` is ignored.
+    ///
+    /// If you want to use a rounding mode other than `Nearest`, consider using
+    /// [`Float::from_sci_string_prec_round`] instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm\log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(s.len(), prec)`, and $m$ is the
+    /// number of bits in the absolute value of the exponent.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering;
+    ///
+    /// let options = FromSciStringOptions::default();
+    /// let (x, o) = Float::from_sci_string_prec("0.1", options, 10).unwrap();
+    /// assert_eq!(x.to_string(), "0.09998");
+    /// assert_eq!(o, Ordering::Less);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("0.1", options, 100).unwrap();
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(o, Ordering::Greater);
+    ///
+    /// let (x, o) = Float::from_sci_string_prec("NaN", options, 100).unwrap();
+    /// assert_eq!(x.to_string(), "NaN");
+    /// assert_eq!(o, Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn from_sci_string_prec(
+        s: &str,
+        options: FromSciStringOptions,
+        prec: u64,
+    ) -> Option<(Float, Ordering)> {
+        Float::from_sci_string_prec_round(s, options, prec, RoundingMode::Nearest)
+    }
+}
+
+impl FromSciString for Float {
+    /// Converts a string, possibly in scientific notation, to a [`Float`].
+    ///
+    /// The precision of the result is implied by the string. Let $d$ be the number of digits in the
+    /// string, excluding leading zeros, and $b$ the base. If $b$ is a power of 2, the precision is
+    /// $d\log_2 b$, which is enough to represent the value exactly. Otherwise it is
+    /// $\lceil d\log_2 b \rceil + 1$, the smallest precision that guarantees that the $d$ digits
+    /// can be recovered from the result. So `"0.1"` and `"1e100"` are parsed with a precision of
+    /// 5, and `"0.100"` with a precision of 11.
+    ///
+    /// Use [`FromSciStringOptions`] to specify the base (from 2 to 36, inclusive) and the rounding
+    /// mode, in case rounding is necessary. If the rounding mode is `Exact` and the value cannot
+    /// be represented exactly with the implied precision, `None` is returned.
+    ///
+    /// See [`Float::from_sci_string_prec_round`] for a description of the accepted strings.
+    ///
+    /// If the string is unparseable, `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm\log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is the number of
+    /// bits in the absolute value of the exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::string::options::FromSciStringOptions;
+    /// use malachite_base::num::conversion::traits::FromSciString;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from_sci_string("123").unwrap();
+    /// assert_eq!(x.to_string(), "123.0");
+    /// assert_eq!(x.get_prec(), Some(11));
+    ///
+    /// let x = Float::from_sci_string("0.1").unwrap();
+    /// assert_eq!(x.to_string(), "0.1");
+    /// assert_eq!(x.get_prec(), Some(5));
+    ///
+    /// let x = Float::from_sci_string("-1.25e-3").unwrap();
+    /// assert_eq!(x.to_string(), "-0.00125");
+    /// assert_eq!(x.get_prec(), Some(11));
+    ///
+    /// assert_eq!(Float::from_sci_string("-Infinity").unwrap().to_string(), "-Infinity");
+    /// assert!(Float::from_sci_string("abc").is_none());
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_base(16);
+    /// let x = Float::from_sci_string_with_options("ff.8", options).unwrap();
+    /// assert_eq!(x.to_string(), "255.5");
+    /// assert_eq!(x.get_prec(), Some(12));
+    ///
+    /// let mut options = FromSciStringOptions::default();
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert!(Float::from_sci_string_with_options("1.5", options).is_some());
+    /// assert!(Float::from_sci_string_with_options("0.1", options).is_none());
+    /// ```
+    fn from_sci_string_with_options(s: &str, options: FromSciStringOptions) -> Option<Float> {
+        let base = options.get_base();
+        if let Some(x) = special_from_sci_string(s, base) {
+            return Some(x);
+        }
+        let digits = significant_digits(s, options)?;
+        let prec = if digits == 0 {
+            1
+        } else if base.is_power_of_two() {
+            digits * u64::from(base.trailing_zeros())
+        } else {
+            Natural::from(base).pow(digits).ceiling_log_base_2() + 1
+        };
+        let rm = options.get_rounding_mode();
+        if rm == RoundingMode::Exact {
+            let (x, o) = Float::from_sci_string_prec_round(s, options, prec, RoundingMode::Floor)?;
+            if o == Ordering::Equal {
+                Some(x)
+            } else {
+                None
+            }
+        } else {
+            Float::from_sci_string_prec_round(s, options, prec, rm).map(|(x, _)| x)
+        }
+    }
+}
@@ -4,46 +4,137 @@ use malachite_base::num::conversion::string::options::FromSciStringOptions;
 use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_q::Rational;
+use std::cmp::Ordering;
 use std::str::FromStr;
 
-fn from_hex_string(s: &str) -> Float {
+// Parses the hexadecimal format produced by `{:#x}`, for example "-0x1.8#3", which specifies the
+// precision after the '#'.
+pub(crate) fn from_hex_string(s: &str) -> Option<Float> {
     match s {
-        "NaN" => Float::NAN,
-        "Infinity" => Float::INFINITY,
-        "-Infinity" => Float::NEGATIVE_INFINITY,
-        "0x0.0" => Float::ZERO,
-        "-0x0.0" => Float::NEGATIVE_ZERO,
+        "NaN" => Some(Float::NAN),
+        "Infinity" => Some(Float::INFINITY),
+        "-Infinity" => Some(Float::NEGATIVE_INFINITY),
+        "0x0.0" => Some(Float::ZERO),
+        "-0x0.0" => Some(Float::NEGATIVE_ZERO),
         s => {
             let (s, sign) = if let Some(s) = s.strip_prefix('-') {
                 (s, false)
             } else {
                 (s, true)
             };
-            let s = s.strip_prefix("0x").unwrap();
-            let hash_index = s.find('#').unwrap();
-            let precision = u64::from_str(&s[hash_index + 1..]).unwrap();
+            let s = s.strip_prefix("0x")?;
+            let hash_index = s.find('#')?;
+            let precision = u64::from_str(&s[hash_index + 1..]).ok()?;
+            if precision == 0 {
+                return None;
+            }
             let mut options = FromSciStringOptions::default();
             options.set_base(16);
-            let x = Float::from_rational_prec_round(
-                Rational::from_sci_string_with_options(&s[..hash_index], options).unwrap(),
-                precision,
-                RoundingMode::Exact,
-            )
-            .0;
-            if sign {
-                x
-            } else {
-                -x
+            let x = Rational::from_sci_string_with_options(&s[..hash_index], options)?;
+            if x == 0u32 {
+                return None;
             }
+            let (x, o) = Float::from_rational_prec_round(x, precision, RoundingMode::Floor);
+            if o != Ordering::Equal {
+                return None;
+            }
+            Some(if sign { x } else { -x })
         }
     }
 }
 
+impl FromStr for Float {
+    type Err = ();
+
+    /// Converts a string, possibly in scientific notation, to a [`Float`].
+    ///
+    /// The string is parsed using base 10, and the precision of the result is implied by the
+    /// number of significant digits in the string. See
+    /// [`from_sci_string_with_options`](Float::from_sci_string_with_options) for details, and
+    /// [`Float::from_sci_string_prec_round`] if you want to specify the precision and rounding
+    /// mode.
+    ///
+    /// If the string does not represent a valid [`Float`], an `Err` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm\log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is the number of
+    /// bits in the absolute value of the exponent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_float::Float;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Float::from_str("123.0").unwrap().to_string(), "123.0");
+    /// assert_eq!(Float::from_str("-0.0").unwrap().to_string(), "-0.0");
+    /// assert_eq!(Float::from_str("1.25e-3").unwrap().to_string(), "0.00125");
+    /// assert_eq!(Float::from_str("3.14159").unwrap().to_string(), "3.14159");
+    /// assert_eq!(Float::from_str("NaN").unwrap().to_string(), "NaN");
+    /// assert_eq!(Float::from_str("inf").unwrap().to_string(), "Infinity");
+    /// assert_eq!(Float::from_str("-Infinity").unwrap().to_string(), "-Infinity");
+    ///
+    /// assert!(Float::from_str("").is_err());
+    /// assert!(Float::from_str("1.2.3").is_err());
+    /// assert!(Float::from_str("--1").is_err());
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Float, ()> {
+        Float::from_sci_string(s).ok_or(())
+    }
+}
+
 impl FromStringBase for Float {
-    fn from_string_base(base: u8, s: &str) -> Option<Self> {
-        if base != 16 {
-            panic!();
+    /// Converts a string, in a specified base, to a [`Float`].
+    ///
+    /// If the base is 16 and the string is in the hexadecimal format produced by `{:#x}`, starting
+    /// with `"0x"` and with the precision following a `'#'` (for example, `"-0x1.8#3"`), that
+    /// exact [`Float`] is returned. Otherwise, the string is parsed as if by
+    /// [`from_sci_string_with_options`](Float::from_sci_string_with_options) with the given base.
+    ///
+    /// If the string does not represent a valid [`Float`], `None` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(nm\log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `s.len()`, and $m$ is the number of
+    /// bits in the absolute value of the exponent.
+    ///
+    /// # Panics
+    /// Panics if `base` is less than 2 or greater than 36.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::FromStringBase;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from_string_base(16, "-0x1.8#3").unwrap();
+    /// assert_eq!(x.to_string(), "-1.5");
+    /// assert_eq!(x.get_prec(), Some(3));
+    ///
+    /// let x = Float::from_string_base(16, "ff").unwrap();
+    /// assert_eq!(x.to_string(), "255.0");
+    /// assert_eq!(x.get_prec(), Some(8));
+    ///
+    /// let x = Float::from_string_base(2, "-101.1").unwrap();
+    /// assert_eq!(x.to_string(), "-5.5");
+    /// assert_eq!(x.get_prec(), Some(4));
+    ///
+    /// assert!(Float::from_string_base(10, "12a").is_none());
+    /// ```
+    fn from_string_base(base: u8, s: &str) -> Option<Float> {
+        assert!((2..=36).contains(&base), "base out of range");
+        if base == 16 && (s.contains('#') || s.starts_with("0x") || s.starts_with("-0x")) {
+            from_hex_string(s)
+        } else {
+            let mut options = FromSciStringOptions::default();
+            options.set_base(base);
+            Float::from_sci_string_with_options(s, options)
         }
-        Some(from_hex_string(s))
     }
 }
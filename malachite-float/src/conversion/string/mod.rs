@@ -1,2 +1,4 @@
+pub mod from_sci_string;
 pub mod from_string;
+pub mod to_sci;
 pub mod to_string;
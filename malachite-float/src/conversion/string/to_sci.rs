@@ -0,0 +1,415 @@
+use crate::Float;
+use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
+use malachite_base::num::arithmetic::traits::{
+    CheckedLogBase2, DivRound, DivisibleBy, Parity, Pow, ShrRound,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::string::options::{SciSizeOptions, ToSciOptions};
+use malachite_base::num::conversion::string::to_sci::write_exponent;
+use malachite_base::num::conversion::traits::{
+    ExactFrom, IntegerMantissaAndExponent, RoundingFrom, ToSci, ToStringBase,
+};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::natural::Natural;
+use std::cmp::Ordering;
+use std::fmt::{Formatter, Write};
+
+// Compares $2^em$ with $b^k$.
+fn cmp_with_power_of_base(m: &Natural, e: i64, base: u8, k: i64) -> Ordering {
+    let b = Natural::from(base);
+    let (mut lhs, mut rhs) = if k >= 0 {
+        (m.clone(), b.pow(k.unsigned_abs()))
+    } else {
+        (m * b.pow(k.unsigned_abs()), Natural::ONE)
+    };
+    if e >= 0 {
+        lhs <<= e.unsigned_abs();
+    } else {
+        rhs <<= e.unsigned_abs();
+    }
+    lhs.cmp(&rhs)
+}
+
+// Returns $\lfloor \log_b 2^em \rfloor$, where $m$ is positive.
+//
+// The logarithm is first estimated from the binary exponent and then corrected by exact
+// comparisons, so at most a few powers of $b$ are computed.
+fn floor_log_base_of_abs(m: &Natural, e: i64, base: u8) -> i64 {
+    let floor_log_2 = i64::exact_from(m.significant_bits()) - 1 + e;
+    if let Some(log_base) = base.checked_log_base_2() {
+        return floor_log_2
+            .div_round(i64::exact_from(log_base), RoundingMode::Floor)
+            .0;
+    }
+    let mut log = i64::rounding_from(
+        floor_log_2 as f64 / f64::from(base).log2(),
+        RoundingMode::Floor,
+    )
+    .0;
+    loop {
+        if cmp_with_power_of_base(m, e, base, log) == Ordering::Less {
+            log -= 1;
+        } else if cmp_with_power_of_base(m, e, base, log + 1) != Ordering::Less {
+            log += 1;
+        } else {
+            return log;
+        }
+    }
+}
+
+// Returns $2^emb^s$, rounded to an integer using the specified rounding mode.
+fn scale_and_round(m: Natural, e: i64, base: u8, scale: i64, rm: RoundingMode) -> Natural {
+    let b = Natural::from(base);
+    if scale >= 0 {
+        let n = m * b.pow(scale.unsigned_abs());
+        if e >= 0 {
+            n << e.unsigned_abs()
+        } else {
+            n.shr_round(e.unsigned_abs(), rm).0
+        }
+    } else {
+        let d = b.pow(scale.unsigned_abs());
+        if e >= 0 {
+            (m << e.unsigned_abs()).div_round(d, rm).0
+        } else {
+            m.div_round(d << e.unsigned_abs(), rm).0
+        }
+    }
+}
+
+// Returns the number of digits after the point in the base-$b$ expansion of $2^em$, where $m$ is
+// odd, or `None` if the expansion is non-terminating.
+fn length_after_point_in_small_base(e: i64, base: u8) -> Option<u64> {
+    if e >= 0 {
+        Some(0)
+    } else if base.even() {
+        Some(
+            e.unsigned_abs()
+                .div_round(u64::from(base.trailing_zeros()), RoundingMode::Ceiling)
+                .0,
+        )
+    } else {
+        None
+    }
+}
+
+fn fmt_zero(f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+    f.write_char('0')?;
+    let scale = if options.get_include_trailing_zeros() {
+        match options.get_size_options() {
+            SciSizeOptions::Complete => None,
+            SciSizeOptions::Scale(scale) => {
+                if scale == 0 {
+                    None
+                } else {
+                    Some(scale)
+                }
+            }
+            SciSizeOptions::Precision(precision) => {
+                if precision == 1 {
+                    None
+                } else {
+                    Some(precision - 1)
+                }
+            }
+        }
+    } else {
+        None
+    };
+    if let Some(scale) = scale {
+        f.write_char('.')?;
+        for _ in 0..scale {
+            f.write_char('0')?;
+        }
+    }
+    Ok(())
+}
+
+impl ToSci for Float {
+    /// Determines whether a [`Float`] can be converted to a string using
+    /// [`to_sci`](malachite_base::num::conversion::traits::ToSci::to_sci) and a particular set of
+    /// options.
+    ///
+    /// A finite [`Float`] is a dyadic rational, so its expansion with
+    /// [`SciSizeOptions::Complete`] is terminating iff the [`Float`] is an integer or the base is
+    /// even. NaN and infinities can always be formatted.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.complexity(), s)`, where
+    /// `s` depends on the size type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NaN;
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// assert!(Float::from(123u8).fmt_sci_valid(options));
+    /// assert!(Float::from(u128::MAX).fmt_sci_valid(options));
+    /// // u128::MAX has more than 16 significant digits
+    /// options.set_rounding_mode(RoundingMode::Exact);
+    /// assert!(!Float::from(u128::MAX).fmt_sci_valid(options));
+    /// options.set_precision(50);
+    /// assert!(Float::from(u128::MAX).fmt_sci_valid(options));
+    /// assert!(Float::NAN.fmt_sci_valid(options));
+    ///
+    /// let mut options = ToSciOptions::default();
+    /// options.set_size_complete();
+    /// // 1/8 is 0.125 in base 10...
+    /// assert!(Float::from(0.125).fmt_sci_valid(options));
+    ///
+    /// // ...but is non-terminating in base 3
+    /// options.set_base(3);
+    /// assert!(!Float::from(0.125).fmt_sci_valid(options));
+    /// ```
+    fn fmt_sci_valid(&self, options: ToSciOptions) -> bool {
+        if !matches!(self, Float(Finite { .. })) {
+            return true;
+        }
+        let base = options.get_base();
+        let (m, e) = self.integer_mantissa_and_exponent();
+        if let SciSizeOptions::Complete = options.get_size_options() {
+            return length_after_point_in_small_base(e, base).is_some();
+        }
+        if options.get_rounding_mode() != RoundingMode::Exact {
+            return true;
+        }
+        let scale = match options.get_size_options() {
+            SciSizeOptions::Precision(precision) => {
+                i64::exact_from(precision - 1) - floor_log_base_of_abs(&m, e, base)
+            }
+            SciSizeOptions::Scale(scale) => i64::exact_from(scale),
+            _ => unreachable!(),
+        };
+        if scale >= 0 {
+            // m is odd, so 2^e * m * b^scale is an integer iff the twos in b^scale cancel 2^e.
+            e >= 0
+                || u64::from(base.trailing_zeros())
+                    .checked_mul(scale.unsigned_abs())
+                    .map_or(true, |twos| twos >= e.unsigned_abs())
+        } else {
+            e >= 0
+                && (m << e.unsigned_abs())
+                    .divisible_by(Natural::from(base).pow(scale.unsigned_abs()))
+        }
+    }
+
+    /// Converts a [`Float`] to a string using a specified base, possibly formatting the number
+    /// using scientific notation.
+    ///
+    /// See [`ToSciOptions`](malachite_base::num::conversion::string::options::ToSciOptions) for
+    /// details on the available options. The digits are generated directly from the [`Float`]'s
+    /// significand and exponent, and the result is identical to the result of formatting the
+    /// [`Float`]'s exact [`Rational`](malachite_q::Rational) value with the same options. NaN is
+    /// written as `"NaN"`, infinities as `"Infinity"` and `"-Infinity"`, and negative zero with
+    /// a leading `'-'`.
+    ///
+    /// Unlike the [`Display`](std::fmt::Display) implementation, which chooses the number of
+    /// digits automatically, this makes it possible to format [`Float`]s with a fixed number of
+    /// significant digits or digits after the point.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.complexity(), s)`, where
+    /// `s` depends on the size type specified in `options`.
+    /// - If `options` has `scale` specified, then `s` is `options.scale`.
+    /// - If `options` has `precision` specified, then `s` is `options.precision`.
+    /// - If `options` has `size_complete` specified, then `s` is 0.
+    ///
+    /// # Panics
+    /// Panics if `options.rounding_mode` is `Exact`, but the size options are such that the input
+    /// must be rounded, or if the size options are `Complete` but the [`Float`] has a
+    /// non-terminating expansion in the specified base.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::{NegativeInfinity, NegativeZero};
+    /// use malachite_base::num::conversion::string::options::ToSciOptions;
+    /// use malachite_base::num::conversion::traits::ToSci;
+    /// use malachite_base::rounding_modes::RoundingMode;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::from(std::f64::consts::PI);
+    /// let mut options = ToSciOptions::default();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.141592653589793");
+    ///
+    /// options.set_precision(3);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.14");
+    ///
+    /// options.set_rounding_mode(RoundingMode::Ceiling);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.15");
+    ///
+    /// options = ToSciOptions::default();
+    /// options.set_scale(2);
+    /// options.set_include_trailing_zeros(true);
+    /// assert_eq!(Float::from(2.5).to_sci_with_options(options).to_string(), "2.50");
+    ///
+    /// options = ToSciOptions::default();
+    /// options.set_base(16);
+    /// options.set_uppercase();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "3.243F6A8885A3");
+    ///
+    /// options.set_size_complete();
+    /// options.set_base(2);
+    /// assert_eq!(
+    ///     Float::from(-5.5).to_sci_with_options(options).to_string(),
+    ///     "-101.1"
+    /// );
+    ///
+    /// let x = Float::power_of_2(1000i64);
+    /// let mut options = ToSciOptions::default();
+    /// options.set_precision(6);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.07151e301");
+    ///
+    /// options.set_e_uppercase();
+    /// options.set_force_exponent_plus_sign(true);
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "1.07151E+301");
+    ///
+    /// let x = Float::power_of_2(-30i64);
+    /// let mut options = ToSciOptions::default();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "9.313225746154785e-10");
+    ///
+    /// options.set_size_complete();
+    /// assert_eq!(x.to_sci_with_options(options).to_string(), "9.31322574615478515625e-10");
+    ///
+    /// assert_eq!(Float::NEGATIVE_ZERO.to_sci().to_string(), "-0");
+    /// assert_eq!(Float::NEGATIVE_INFINITY.to_sci().to_string(), "-Infinity");
+    /// ```
+    fn fmt_sci(&self, f: &mut Formatter, options: ToSciOptions) -> std::fmt::Result {
+        match self {
+            float_nan!() => return f.write_str("NaN"),
+            float_infinity!() => return f.write_str("Infinity"),
+            float_negative_infinity!() => return f.write_str("-Infinity"),
+            float_zero!() => return fmt_zero(f, options),
+            float_negative_zero!() => {
+                f.write_char('-')?;
+                return fmt_zero(f, options);
+            }
+            _ => {}
+        }
+        let mut rm = options.get_rounding_mode();
+        if self.is_sign_negative() {
+            f.write_char('-')?;
+            rm = -rm;
+        }
+        let base = options.get_base();
+        let (m, e) = self.integer_mantissa_and_exponent();
+        let mut trim_zeros = !options.get_include_trailing_zeros();
+        let mut log = floor_log_base_of_abs(&m, e, base);
+        // Here, precision 0 means that we're rounding down to zero
+        let (mut scale, mut precision) = match options.get_size_options() {
+            SciSizeOptions::Complete => {
+                trim_zeros = false;
+                let scale = length_after_point_in_small_base(e, base).unwrap_or_else(|| {
+                    panic!("{self} has a non-terminating expansion in base {base}")
+                });
+                let precision = i64::exact_from(scale) + log + 1;
+                assert!(precision > 0);
+                (i64::exact_from(scale), precision)
+            }
+            SciSizeOptions::Scale(scale) => {
+                (i64::exact_from(scale), i64::exact_from(scale) + log + 1)
+            }
+            SciSizeOptions::Precision(precision) => (
+                i64::exact_from(precision - 1) - log,
+                i64::exact_from(precision),
+            ),
+        };
+        let n = scale_and_round(m, e, base, scale, rm);
+        if precision <= 0 {
+            // e.g. we're in base 10, self is 0.01 or 0.000001, but scale is 1
+            if n == 0u32 {
+                return fmt_zero(f, options);
+            } else if n == 1u32 {
+                precision = 1;
+                log = -scale;
+            } else {
+                panic!("Bug: precision <= 0 must mean self.abs() rounds to 0 or 1");
+            };
+        }
+        let mut cs = if options.get_lowercase() {
+            n.to_string_base(base)
+        } else {
+            n.to_string_base_upper(base)
+        }
+        .into_bytes();
+        let mut precision = usize::exact_from(precision);
+        if cs.len() == precision + 1 {
+            // We rounded up to a power of the base, so precision is greater than we expected. If
+            // the options specify the precision, we need to adjust.
+            log += 1;
+            match options.get_size_options() {
+                SciSizeOptions::Complete => panic!(),
+                SciSizeOptions::Precision(_) => {
+                    scale -= 1;
+                    assert_eq!(cs.pop().unwrap(), b'0');
+                }
+                SciSizeOptions::Scale(_) => {
+                    precision += 1;
+                }
+            }
+        }
+        assert_eq!(cs.len(), precision);
+        if log <= options.get_neg_exp_threshold() || scale < 0 {
+            assert_ne!(log, 0);
+            // exponent
+            if trim_zeros {
+                let trailing_zeros = cs.iter().rev().take_while(|&&c| c == b'0').count();
+                precision -= trailing_zeros;
+                cs.truncate(precision);
+            }
+            if precision > 1 {
+                cs.push(0);
+                cs.copy_within(1..precision, 2);
+                cs[1] = b'.';
+            }
+            f.write_str(&String::from_utf8(cs).unwrap())?;
+            write_exponent(f, options, log)
+        } else if scale == 0 {
+            // no exponent or point
+            f.write_str(&String::from_utf8(cs).unwrap())
+        } else {
+            // no exponent
+            if trim_zeros {
+                let trailing_zeros = cs
+                    .iter()
+                    .rev()
+                    .take(usize::exact_from(scale))
+                    .take_while(|&&c| c == b'0')
+                    .count();
+                precision -= trailing_zeros;
+                cs.truncate(precision);
+            }
+            if log < 0 {
+                f.write_char('0')?;
+                f.write_char('.')?;
+                for _ in 0..-log - 1 {
+                    f.write_char('0')?;
+                }
+            } else {
+                let digits_before = usize::exact_from(log) + 1;
+                if precision > digits_before {
+                    cs.push(0);
+                    cs.copy_within(digits_before..precision, digits_before + 1);
+                    cs[digits_before] = b'.';
+                }
+            }
+            f.write_str(&String::from_utf8(cs).unwrap())
+        }
+    }
+}
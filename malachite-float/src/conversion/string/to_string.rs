@@ -2,9 +2,8 @@ use crate::InnerFloat::{Finite, Infinity, NaN, Zero};
 use crate::{ComparableFloat, ComparableFloatRef, Float};
 use malachite_base::num::arithmetic::traits::{Abs, ModPowerOf2, ShrRound};
 use malachite_base::num::conversion::string::options::ToSciOptions;
-use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_base::num::conversion::traits::ToSci;
 use malachite_base::rounding_modes::RoundingMode;
-use malachite_q::Rational;
 use std::fmt::{Debug, Display, Formatter, LowerHex, Result, Write};
 
 impl Display for Float {
@@ -20,15 +19,12 @@ impl Display for Float {
                 let mut higher = self.clone();
                 lower.decrement();
                 higher.increment();
-                let self_q = Rational::exact_from(self);
-                let lower_q = Rational::exact_from(lower);
-                let higher_q = Rational::exact_from(higher);
                 let mut options = ToSciOptions::default();
                 for precision in 1.. {
                     options.set_precision(precision);
-                    let s = self_q.to_sci_with_options(options).to_string();
-                    let s_lower = lower_q.to_sci_with_options(options).to_string();
-                    let s_higher = higher_q.to_sci_with_options(options).to_string();
+                    let s = self.to_sci_with_options(options).to_string();
+                    let s_lower = lower.to_sci_with_options(options).to_string();
+                    let s_higher = higher.to_sci_with_options(options).to_string();
                     if s != s_lower && s != s_higher {
                         return if s.contains('.') {
                             write!(f, "{s}")
@@ -82,10 +78,7 @@ impl LowerHex for Float {
                 if f.alternate() {
                     f.write_str("0x")?;
                 }
-                let s = Rational::exact_from(self)
-                    .abs()
-                    .to_sci_with_options(options)
-                    .to_string();
+                let s = self.abs().to_sci_with_options(options).to_string();
                 if s.contains('.') {
                     write!(f, "{s}")
                 } else if let Some(i) = s.find('E') {
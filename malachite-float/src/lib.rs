@@ -18,6 +18,10 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
 //!   default, [`u64`].
+//! - `enable_serde`: Enables serialization and deserialization using [serde](`https://serde.rs/`).
+//!   [`Float`]s are serialized as strings in the hexadecimal format produced by
+//!   [`ComparableFloat`]'s `{:#x}` formatting, which preserves the precision, the sign of zero, and
+//!   the special values NaN and $\pm\infty$.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
@@ -65,6 +69,9 @@
 
 #[macro_use]
 extern crate malachite_base;
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
 
 #[cfg(feature = "test_build")]
 extern crate itertools;
@@ -133,6 +140,8 @@ use std::ops::Deref;
 /// `Float`s whose precision is 64 bits or less can be represented without any memory allocation.
 /// (Unless Malachite is compiled with `32_bit_limbs`, in which case the limit is 32).
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SerdeFloat", into = "SerdeFloat"))]
 pub struct Float(pub(crate) InnerFloat);
 
 // We want to limit the visibility of the `NaN`, `Zero`, `Infinity`, and `Finite` constructors to
@@ -155,6 +164,10 @@ pub(crate) enum InnerFloat {
     },
 }
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub(crate) struct SerdeFloat(String);
+
 #[inline]
 pub(crate) fn significand_bits(significand: &Natural) -> u64 {
     significand.limb_count() << Limb::LOG_WIDTH
@@ -266,6 +279,8 @@ pub mod arithmetic;
 pub mod basic;
 /// Traits for comparing [`Float`]s for equality or order.
 pub mod comparison;
+/// Functions that compute mathematical constants, such as $\pi$, to any precision.
+pub mod constants;
 /// Traits for converting to and from [`Float`]s, including converting [`Float`]s to and from
 /// strings.
 pub mod conversion;
@@ -0,0 +1,69 @@
+use crate::Float;
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_add_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x + y, rm)
+}
+
+pub fn rug_add_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_add_prec_round(x, y, prec, Round::Nearest)
+}
+
+// The precision of a `rug::Float`, as defined by `Float::significant_bits`: special values and
+// zeros have precision 1.
+pub(crate) fn rug_significant_bits(x: &rug::Float) -> u64 {
+    if x.is_normal() {
+        u64::from(x.prec())
+    } else {
+        1
+    }
+}
+
+pub fn rug_add_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_add_prec_round(
+        x,
+        y,
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rm,
+    )
+}
+
+pub fn rug_add(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_add_round(x, y, Round::Nearest).0
+}
+
+// Computes the exact sum of two finite `Float`s as a `Rational`, and then rounds it.
+pub fn add_prec_round_naive(x: Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert!(x.is_finite() && y.is_finite());
+    let both_zero_with_same_sign =
+        x.is_zero() && y.is_zero() && x.is_sign_negative() == y.is_sign_negative();
+    let x_negative = x.is_sign_negative();
+    let sum = Rational::exact_from(x) + Rational::exact_from(y);
+    if sum == 0u32 {
+        let negative = if both_zero_with_same_sign {
+            x_negative
+        } else {
+            rm == RoundingMode::Floor
+        };
+        (
+            if negative {
+                Float::NEGATIVE_ZERO
+            } else {
+                Float::ZERO
+            },
+            Ordering::Equal,
+        )
+    } else {
+        Float::from_rational_prec_round(sum, prec, rm)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::Float;
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_add_mul_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), y.mul_add_ref(z, x), rm)
+}
+
+pub fn rug_add_mul_prec(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_add_mul_prec_round(x, y, z, prec, Round::Nearest)
+}
+
+pub(crate) fn rug_max_significant_bits_3(x: &rug::Float, y: &rug::Float, z: &rug::Float) -> u64 {
+    max(
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rug_significant_bits(z),
+    )
+}
+
+pub fn rug_add_mul_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_add_mul_prec_round(x, y, z, rug_max_significant_bits_3(x, y, z), rm)
+}
+
+pub fn rug_add_mul(x: &rug::Float, y: &rug::Float, z: &rug::Float) -> rug::Float {
+    rug_add_mul_round(x, y, z, Round::Nearest).0
+}
+
+// Computes `x + y * z` exactly as a `Rational`, for finite `Float`s, and then rounds it.
+pub fn add_mul_prec_round_naive(
+    x: Float,
+    y: Float,
+    z: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert!(x.is_finite() && y.is_finite() && z.is_finite());
+    let x_negative = x.is_sign_negative();
+    let product_negative = y.is_sign_negative() != z.is_sign_negative();
+    let both_zero_with_same_sign =
+        x.is_zero() && (y.is_zero() || z.is_zero()) && x_negative == product_negative;
+    let result = Rational::exact_from(x) + Rational::exact_from(y) * Rational::exact_from(z);
+    if result == 0u32 {
+        let negative = if both_zero_with_same_sign {
+            x_negative
+        } else {
+            rm == RoundingMode::Floor
+        };
+        (
+            if negative {
+                Float::NEGATIVE_ZERO
+            } else {
+                Float::ZERO
+            },
+            Ordering::Equal,
+        )
+    } else {
+        Float::from_rational_prec_round(result, prec, rm)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_atan2_prec_round(
+    y: &rug::Float,
+    x: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), y.atan2_ref(x), rm)
+}
+
+pub fn rug_atan2_prec(y: &rug::Float, x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_atan2_prec_round(y, x, prec, Round::Nearest)
+}
+
+pub fn rug_atan2_round(y: &rug::Float, x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_atan2_prec_round(
+        y,
+        x,
+        max(rug_significant_bits(y), rug_significant_bits(x)),
+        rm,
+    )
+}
+
+pub fn rug_atan2(y: &rug::Float, x: &rug::Float) -> rug::Float {
+    rug_atan2_round(y, x, Round::Nearest).0
+}
@@ -0,0 +1,76 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::Float;
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_fmod_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x % y, rm)
+}
+
+pub fn rug_fmod_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_fmod_prec_round(x, y, prec, Round::Nearest)
+}
+
+pub fn rug_fmod_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_fmod_prec_round(
+        x,
+        y,
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rm,
+    )
+}
+
+pub fn rug_fmod(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_fmod_round(x, y, Round::Nearest).0
+}
+
+// Computes $x-ny$ for two `Rational`s $x$ and $y$, where $y$ is nonzero and $n$ is $x/y$ rounded to
+// an integer using `rm`.
+pub fn rational_rem(x: &Rational, y: &Rational, rm: RoundingMode) -> Rational {
+    let n = Integer::rounding_from(x / y, rm).0;
+    x - Rational::from(n) * y
+}
+
+// Computes the exact remainder of a finite `Float` and a finite, nonzero `Float` as a `Rational`,
+// where the quotient is rounded to an integer using `quotient_rm`, and then rounds it.
+pub fn rem_prec_round_naive(
+    x: Float,
+    y: Float,
+    quotient_rm: RoundingMode,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    assert!(x.is_finite() && y.is_finite() && y != 0u32);
+    let negative = x.is_sign_negative();
+    let remainder = rational_rem(
+        &Rational::exact_from(x),
+        &Rational::exact_from(y),
+        quotient_rm,
+    );
+    if remainder == 0u32 {
+        (
+            if negative {
+                Float::NEGATIVE_ZERO
+            } else {
+                Float::ZERO
+            },
+            Ordering::Equal,
+        )
+    } else {
+        Float::from_rational_prec_round(remainder, prec, rm)
+    }
+}
+
+pub fn fmod_prec_round_naive(x: Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    rem_prec_round_naive(x, y, RoundingMode::Down, prec, rm)
+}
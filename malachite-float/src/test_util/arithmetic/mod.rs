@@ -0,0 +1,35 @@
+pub mod acos;
+pub mod acosh;
+pub mod add;
+pub mod add_mul;
+pub mod asin;
+pub mod asinh;
+pub mod atan;
+pub mod atan2;
+pub mod atanh;
+pub mod cos;
+pub mod cosh;
+pub mod div;
+pub mod exp;
+pub mod exp10;
+pub mod exp2;
+pub mod expm1;
+pub mod fmod;
+pub mod ln;
+pub mod ln_1p;
+pub mod log10;
+pub mod log2;
+pub mod mul;
+pub mod reciprocal;
+pub mod reciprocal_sqrt;
+pub mod remainder;
+pub mod root;
+pub mod sin;
+pub mod sin_cos;
+pub mod sinh;
+pub mod sqrt;
+pub mod square;
+pub mod sub;
+pub mod sub_mul;
+pub mod tan;
+pub mod tanh;
@@ -0,0 +1,53 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::Float;
+use malachite_base::num::basic::traits::{NegativeZero, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_mul_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x * y, rm)
+}
+
+pub fn rug_mul_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_mul_prec_round(x, y, prec, Round::Nearest)
+}
+
+pub fn rug_mul_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_mul_prec_round(
+        x,
+        y,
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rm,
+    )
+}
+
+pub fn rug_mul(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_mul_round(x, y, Round::Nearest).0
+}
+
+// Computes the exact product of two finite `Float`s as a `Rational`, and then rounds it.
+pub fn mul_prec_round_naive(x: Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert!(x.is_finite() && y.is_finite());
+    let negative = x.is_sign_negative() != y.is_sign_negative();
+    let product = Rational::exact_from(x) * Rational::exact_from(y);
+    if product == 0u32 {
+        (
+            if negative {
+                Float::NEGATIVE_ZERO
+            } else {
+                Float::ZERO
+            },
+            Ordering::Equal,
+        )
+    } else {
+        Float::from_rational_prec_round(product, prec, rm)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_reciprocal_prec_round(x: &rug::Float, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.recip_ref(), rm)
+}
+
+pub fn rug_reciprocal_prec(x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_reciprocal_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_reciprocal_round(x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_reciprocal_prec_round(x, rug_significant_bits(x), rm)
+}
+
+pub fn rug_reciprocal(x: &rug::Float) -> rug::Float {
+    rug_reciprocal_round(x, Round::Nearest).0
+}
+
+// Computes the exact reciprocal of a finite, nonzero `Float` as a `Rational`, and then rounds it.
+pub fn reciprocal_prec_round_naive(x: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert!(x.is_finite() && x != 0u32);
+    Float::from_rational_prec_round(Rational::exact_from(x).reciprocal(), prec, rm)
+}
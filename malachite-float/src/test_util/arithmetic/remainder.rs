@@ -0,0 +1,42 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::test_util::arithmetic::fmod::rem_prec_round_naive;
+use crate::Float;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_remainder_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.remainder_ref(y), rm)
+}
+
+pub fn rug_remainder_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_remainder_prec_round(x, y, prec, Round::Nearest)
+}
+
+pub fn rug_remainder_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_remainder_prec_round(
+        x,
+        y,
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rm,
+    )
+}
+
+pub fn rug_remainder(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_remainder_round(x, y, Round::Nearest).0
+}
+
+pub fn remainder_prec_round_naive(
+    x: Float,
+    y: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    rem_prec_round_naive(x, y, RoundingMode::Nearest, prec, rm)
+}
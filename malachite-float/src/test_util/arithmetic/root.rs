@@ -0,0 +1,25 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_root_prec_round(
+    x: &rug::Float,
+    exp: u64,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.root_ref(u32::exact_from(exp)), rm)
+}
+
+pub fn rug_root_prec(x: &rug::Float, exp: u64, prec: u64) -> (rug::Float, Ordering) {
+    rug_root_prec_round(x, exp, prec, Round::Nearest)
+}
+
+pub fn rug_root_round(x: &rug::Float, exp: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug_root_prec_round(x, exp, rug_significant_bits(x), rm)
+}
+
+pub fn rug_root(x: &rug::Float, exp: u64) -> rug::Float {
+    rug_root_round(x, exp, Round::Nearest).0
+}
@@ -0,0 +1,36 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use rug::ops::AssignRound;
+use std::cmp::Ordering;
+
+pub fn rug_sin_cos_prec_round(
+    x: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> ((rug::Float, Ordering), (rug::Float, Ordering)) {
+    let prec = u32::exact_from(prec);
+    let mut sin_cos = (rug::Float::new(prec), rug::Float::new(prec));
+    let (o_sin, o_cos) = sin_cos.assign_round(x.sin_cos_ref(), rm);
+    let (sin, cos) = sin_cos;
+    ((sin, o_sin), (cos, o_cos))
+}
+
+pub fn rug_sin_cos_prec(
+    x: &rug::Float,
+    prec: u64,
+) -> ((rug::Float, Ordering), (rug::Float, Ordering)) {
+    rug_sin_cos_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_sin_cos_round(
+    x: &rug::Float,
+    rm: Round,
+) -> ((rug::Float, Ordering), (rug::Float, Ordering)) {
+    rug_sin_cos_prec_round(x, rug_significant_bits(x), rm)
+}
+
+pub fn rug_sin_cos(x: &rug::Float) -> (rug::Float, rug::Float) {
+    let ((sin, _), (cos, _)) = rug_sin_cos_round(x, Round::Nearest);
+    (sin, cos)
+}
@@ -0,0 +1,36 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use crate::Float;
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_square_prec_round(x: &rug::Float, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.square_ref(), rm)
+}
+
+pub fn rug_square_prec(x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_square_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_square_round(x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_square_prec_round(x, rug_significant_bits(x), rm)
+}
+
+pub fn rug_square(x: &rug::Float) -> rug::Float {
+    rug_square_round(x, Round::Nearest).0
+}
+
+// Computes the exact square of a finite `Float` as a `Rational`, and then rounds it.
+pub fn square_prec_round_naive(x: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    assert!(x.is_finite());
+    let square = Rational::exact_from(x).square();
+    if square == 0u32 {
+        (Float::ZERO, Ordering::Equal)
+    } else {
+        Float::from_rational_prec_round(square, prec, rm)
+    }
+}
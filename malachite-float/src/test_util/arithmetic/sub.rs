@@ -0,0 +1,36 @@
+use crate::test_util::arithmetic::add::{add_prec_round_naive, rug_significant_bits};
+use crate::Float;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use rug::float::Round;
+use std::cmp::{max, Ordering};
+
+pub fn rug_sub_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x - y, rm)
+}
+
+pub fn rug_sub_prec(x: &rug::Float, y: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_sub_prec_round(x, y, prec, Round::Nearest)
+}
+
+pub fn rug_sub_round(x: &rug::Float, y: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_sub_prec_round(
+        x,
+        y,
+        max(rug_significant_bits(x), rug_significant_bits(y)),
+        rm,
+    )
+}
+
+pub fn rug_sub(x: &rug::Float, y: &rug::Float) -> rug::Float {
+    rug_sub_round(x, y, Round::Nearest).0
+}
+
+pub fn sub_prec_round_naive(x: Float, y: Float, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+    add_prec_round_naive(x, -y, prec, rm)
+}
@@ -0,0 +1,49 @@
+use crate::test_util::arithmetic::add_mul::{add_mul_prec_round_naive, rug_max_significant_bits_3};
+use crate::Float;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_sub_mul_prec_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    let neg_y = -y.clone();
+    rug::Float::with_val_round(u32::exact_from(prec), neg_y.mul_add_ref(z, x), rm)
+}
+
+pub fn rug_sub_mul_prec(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    prec: u64,
+) -> (rug::Float, Ordering) {
+    rug_sub_mul_prec_round(x, y, z, prec, Round::Nearest)
+}
+
+pub fn rug_sub_mul_round(
+    x: &rug::Float,
+    y: &rug::Float,
+    z: &rug::Float,
+    rm: Round,
+) -> (rug::Float, Ordering) {
+    rug_sub_mul_prec_round(x, y, z, rug_max_significant_bits_3(x, y, z), rm)
+}
+
+pub fn rug_sub_mul(x: &rug::Float, y: &rug::Float, z: &rug::Float) -> rug::Float {
+    rug_sub_mul_round(x, y, z, Round::Nearest).0
+}
+
+pub fn sub_mul_prec_round_naive(
+    x: Float,
+    y: Float,
+    z: Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    add_mul_prec_round_naive(x, -y, z, prec, rm)
+}
@@ -0,0 +1,20 @@
+use crate::test_util::arithmetic::add::rug_significant_bits;
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_tan_prec_round(x: &rug::Float, prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), x.tan_ref(), rm)
+}
+
+pub fn rug_tan_prec(x: &rug::Float, prec: u64) -> (rug::Float, Ordering) {
+    rug_tan_prec_round(x, prec, Round::Nearest)
+}
+
+pub fn rug_tan_round(x: &rug::Float, rm: Round) -> (rug::Float, Ordering) {
+    rug_tan_prec_round(x, rug_significant_bits(x), rm)
+}
+
+pub fn rug_tan(x: &rug::Float) -> rug::Float {
+    rug_tan_round(x, Round::Nearest).0
+}
@@ -184,3 +184,318 @@ pub fn pair_2_max_pair_1_complexity_pair_2_bucketer<'a, T>(
         bucketing_label: format!("max({x_name}.complexity(), {y_name})"),
     }
 }
+
+pub fn triple_1_2_float_max_complexity_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+) -> Bucketer<'a, (Float, Float, T)> {
+    Bucketer {
+        bucketing_function: &|(x, y, _)| usize::exact_from(max(x.complexity(), y.complexity())),
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity())"),
+    }
+}
+
+pub fn pair_2_triple_1_2_float_max_complexity_bucketer<'a, T, U>(
+    x_name: &'a str,
+    y_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, _))| {
+            usize::exact_from(max(x.complexity(), y.complexity()))
+        },
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity())"),
+    }
+}
+
+pub fn triple_1_2_3_max_float_float_prec_bucketer<'a>(
+    x_name: &'a str,
+    y_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, Float, u64)> {
+    Bucketer {
+        bucketing_function: &|(x, y, p)| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), *p))
+        },
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_triple_1_2_3_max_float_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, u64))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, p))| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), *p))
+        },
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn quadruple_1_2_3_max_float_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, Float, u64, T)> {
+    Bucketer {
+        bucketing_function: &|(x, y, p, _)| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), *p))
+        },
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_quadruple_1_2_3_max_float_float_prec_bucketer<'a, T, U>(
+    x_name: &'a str,
+    y_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, u64, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, p, _))| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), *p))
+        },
+        bucketing_label: format!("max({x_name}.complexity(), {y_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_pair_1_float_complexity_bucketer<'a, T, U>(
+    var_name: &'a str,
+) -> Bucketer<'a, (T, (Float, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, _))| usize::exact_from(x.complexity()),
+        bucketing_label: format!("{var_name}.complexity()"),
+    }
+}
+
+pub fn pair_1_2_max_float_prec_bucketer<'a>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, u64)> {
+    Bucketer {
+        bucketing_function: &|(x, p)| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_pair_1_2_max_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, u64))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, p))| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn triple_1_2_max_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, u64, T)> {
+    Bucketer {
+        bucketing_function: &|(x, p, _)| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_triple_1_2_max_float_prec_bucketer<'a, T, U>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, u64, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, p, _))| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn triple_1_float_complexity_bucketer<T, U>(var_name: &str) -> Bucketer<(Float, T, U)> {
+    Bucketer {
+        bucketing_function: &|(x, _, _)| usize::exact_from(x.complexity()),
+        bucketing_label: format!("{var_name}.complexity()"),
+    }
+}
+
+pub fn pair_2_triple_1_float_complexity_bucketer<T, U, V>(
+    var_name: &str,
+) -> Bucketer<(T, (Float, U, V))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, _, _))| usize::exact_from(x.complexity()),
+        bucketing_label: format!("{var_name}.complexity()"),
+    }
+}
+
+pub fn triple_1_3_max_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, T, u64)> {
+    Bucketer {
+        bucketing_function: &|(x, _, p)| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_triple_1_3_max_float_prec_bucketer<'a, T, U>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, U, u64))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, _, p))| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn quadruple_1_3_max_float_prec_bucketer<'a, T, U>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, T, u64, U)> {
+    Bucketer {
+        bucketing_function: &|(x, _, p, _)| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn pair_2_quadruple_1_3_max_float_prec_bucketer<'a, T, U, V>(
+    x_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, U, u64, V))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, _, p, _))| usize::exact_from(max(x.complexity(), *p)),
+        bucketing_label: format!("max({x_name}.complexity(), {p_name})"),
+    }
+}
+
+pub fn triple_float_max_complexity_bucketer<'a>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+) -> Bucketer<'a, (Float, Float, Float)> {
+    Bucketer {
+        bucketing_function: &|(x, y, z)| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), z.complexity()))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity())"
+        ),
+    }
+}
+
+pub fn pair_2_triple_float_max_complexity_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, Float))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, z))| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), z.complexity()))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity())"
+        ),
+    }
+}
+
+pub fn quadruple_1_2_3_float_max_complexity_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+) -> Bucketer<'a, (Float, Float, Float, T)> {
+    Bucketer {
+        bucketing_function: &|(x, y, z, _)| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), z.complexity()))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity())"
+        ),
+    }
+}
+
+pub fn pair_2_quadruple_1_2_3_float_max_complexity_bucketer<'a, T, U>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, Float, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, z, _))| {
+            usize::exact_from(max(max(x.complexity(), y.complexity()), z.complexity()))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity())"
+        ),
+    }
+}
+
+pub fn quadruple_1_2_3_4_max_float_float_float_prec_bucketer<'a>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, Float, Float, u64)> {
+    Bucketer {
+        bucketing_function: &|(x, y, z, p)| {
+            usize::exact_from(max(
+                max(max(x.complexity(), y.complexity()), z.complexity()),
+                *p,
+            ))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity(), {p_name})"
+        ),
+    }
+}
+
+pub fn pair_2_quadruple_1_2_3_4_max_float_float_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, Float, u64))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, z, p))| {
+            usize::exact_from(max(
+                max(max(x.complexity(), y.complexity()), z.complexity()),
+                *p,
+            ))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity(), {p_name})"
+        ),
+    }
+}
+
+pub fn quintuple_1_2_3_4_max_float_float_float_prec_bucketer<'a, T>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (Float, Float, Float, u64, T)> {
+    Bucketer {
+        bucketing_function: &|(x, y, z, p, _)| {
+            usize::exact_from(max(
+                max(max(x.complexity(), y.complexity()), z.complexity()),
+                *p,
+            ))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity(), {p_name})"
+        ),
+    }
+}
+
+pub fn pair_2_quintuple_1_2_3_4_max_float_float_float_prec_bucketer<'a, T, U>(
+    x_name: &'a str,
+    y_name: &'a str,
+    z_name: &'a str,
+    p_name: &'a str,
+) -> Bucketer<'a, (T, (Float, Float, Float, u64, U))> {
+    Bucketer {
+        bucketing_function: &|(_, (x, y, z, p, _))| {
+            usize::exact_from(max(
+                max(max(x.complexity(), y.complexity()), z.complexity()),
+                *p,
+            ))
+        },
+        bucketing_label: format!(
+            "max({x_name}.complexity(), {y_name}.complexity(), {z_name}.complexity(), {p_name})"
+        ),
+    }
+}
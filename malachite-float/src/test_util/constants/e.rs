@@ -0,0 +1,13 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::Round;
+use std::cmp::Ordering;
+
+pub fn rug_e_prec_round(prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    let mut e = rug::Float::with_val(u32::exact_from(prec), 1);
+    let o = e.exp_round(rm);
+    (e, o)
+}
+
+pub fn rug_e_prec(prec: u64) -> (rug::Float, Ordering) {
+    rug_e_prec_round(prec, Round::Nearest)
+}
@@ -0,0 +1,5 @@
+pub mod catalan;
+pub mod e;
+pub mod euler_gamma;
+pub mod ln_2;
+pub mod pi;
@@ -0,0 +1,11 @@
+use malachite_base::num::conversion::traits::ExactFrom;
+use rug::float::{Constant, Round};
+use std::cmp::Ordering;
+
+pub fn rug_pi_prec_round(prec: u64, rm: Round) -> (rug::Float, Ordering) {
+    rug::Float::with_val_round(u32::exact_from(prec), Constant::Pi, rm)
+}
+
+pub fn rug_pi_prec(prec: u64) -> (rug::Float, Ordering) {
+    rug_pi_prec_round(prec, Round::Nearest)
+}
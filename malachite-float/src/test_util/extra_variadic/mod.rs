@@ -14,6 +14,13 @@ fn unwrap_triple<X, Y, Z>((a, b, c): (Option<X>, Option<Y>, Option<Z>)) -> (X, Y
     (a.unwrap(), b.unwrap(), c.unwrap())
 }
 
+#[allow(clippy::missing_const_for_fn)]
+fn unwrap_quadruple<X, Y, Z, W>(
+    (a, b, c, d): (Option<X>, Option<Y>, Option<Z>, Option<W>),
+) -> (X, Y, Z, W) {
+    (a.unwrap(), b.unwrap(), c.unwrap(), d.unwrap())
+}
+
 exhaustive_tuples_1_input!(
     (pub(crate)),
     ExhaustiveTriples1Input,
@@ -35,6 +42,17 @@ custom_tuples!(
     [X, I, xs, xs_done, [0, output_type_xs_0], [1, output_type_xs_1]],
     [Y, J, ys, ys_done, [2, output_type_ys_2]]
 );
+custom_tuples!(
+    (pub(crate)),
+    ExhaustiveQuadruplesXXXY,
+    (X, X, X, Y),
+    (None, None, None, None),
+    unwrap_quadruple,
+    exhaustive_quadruples_xxxy,
+    exhaustive_quadruples_xxxy_custom_output,
+    [X, I, xs, xs_done, [0, output_type_xs_0], [1, output_type_xs_1], [2, output_type_xs_2]],
+    [Y, J, ys, ys_done, [3, output_type_ys_3]]
+);
 
 random_tuples!(
     (pub(crate)),
@@ -59,6 +77,19 @@ random_tuples!(
     [2, Z, K, zs, zs_gen],
     [3, W, L, ws, ws_gen]
 );
+random_tuples!(
+    (pub(crate)),
+    RandomQuintuples,
+    RandomQuintuplesFromSingle,
+    random_quintuples,
+    random_quintuples_from_single,
+    (I::Item, I::Item, I::Item, I::Item, I::Item),
+    [0, X, I, xs, xs_gen],
+    [1, Y, J, ys, ys_gen],
+    [2, Z, K, zs, zs_gen],
+    [3, W, L, ws, ws_gen],
+    [4, V, M, vs, vs_gen]
+);
 
 random_custom_tuples!(
     (pub(crate)),
@@ -23,6 +23,79 @@ pub fn float_pair_rm(xs: It<(Float, Float)>) -> It<((rug::Float, rug::Float), (F
     }))
 }
 
+pub fn float_triple_rm(
+    xs: It<(Float, Float, Float)>,
+) -> It<((rug::Float, rug::Float, rug::Float), (Float, Float, Float))> {
+    Box::new(xs.map(|(x, y, z)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                rug::Float::exact_from(&z),
+            ),
+            (x, y, z),
+        )
+    }))
+}
+
+pub fn float_float_float_unsigned_quadruple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, Float, Float, T)>,
+) -> It<(
+    (rug::Float, rug::Float, rug::Float, T),
+    (Float, Float, Float, T),
+)> {
+    Box::new(xs.map(|(x, y, z, p)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                rug::Float::exact_from(&z),
+                p,
+            ),
+            (x, y, z, p),
+        )
+    }))
+}
+
+pub fn float_float_float_rounding_mode_quadruple_rm(
+    xs: It<(Float, Float, Float, RoundingMode)>,
+) -> It<(
+    (rug::Float, rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, Float, RoundingMode),
+)> {
+    Box::new(xs.map(|(x, y, z, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                rug::Float::exact_from(&z),
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, y, z, rm),
+        )
+    }))
+}
+
+pub fn float_float_float_unsigned_rounding_mode_quintuple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, Float, Float, T, RoundingMode)>,
+) -> It<(
+    (rug::Float, rug::Float, rug::Float, T, rug::float::Round),
+    (Float, Float, Float, T, RoundingMode),
+)> {
+    Box::new(xs.map(|(x, y, z, p, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                rug::Float::exact_from(&z),
+                p,
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, y, z, p, rm),
+        )
+    }))
+}
+
 pub fn float_natural_pair_rm(
     xs: It<(Float, Natural)>,
 ) -> It<((rug::Float, rug::Integer), (Float, Natural))> {
@@ -68,6 +141,20 @@ pub fn float_primitive_float_pair_rm<T: PrimitiveFloat>(
     Box::new(xs.map(|(x, y)| ((rug::Float::exact_from(&x), y), (x, y))))
 }
 
+pub fn float_rounding_mode_pair_rm(
+    xs: It<(Float, RoundingMode)>,
+) -> It<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Box::new(xs.map(|(x, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, rm),
+        )
+    }))
+}
+
 pub fn float_unsigned_rounding_mode_triple_rm<T: PrimitiveUnsigned>(
     xs: It<(Float, T, RoundingMode)>,
 ) -> It<((rug::Float, T, rug::float::Round), (Float, T, RoundingMode))> {
@@ -82,3 +169,76 @@ pub fn float_unsigned_rounding_mode_triple_rm<T: PrimitiveUnsigned>(
         )
     }))
 }
+
+pub fn float_unsigned_unsigned_triple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, T, T)>,
+) -> It<((rug::Float, T, T), (Float, T, T))> {
+    Box::new(xs.map(|(x, exp, p)| ((rug::Float::exact_from(&x), exp, p), (x, exp, p))))
+}
+
+pub fn float_unsigned_unsigned_rounding_mode_quadruple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, T, T, RoundingMode)>,
+) -> It<(
+    (rug::Float, T, T, rug::float::Round),
+    (Float, T, T, RoundingMode),
+)> {
+    Box::new(xs.map(|(x, exp, p, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                exp,
+                p,
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, exp, p, rm),
+        )
+    }))
+}
+
+pub fn float_float_unsigned_triple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, Float, T)>,
+) -> It<((rug::Float, rug::Float, T), (Float, Float, T))> {
+    Box::new(xs.map(|(x, y, p)| {
+        (
+            (rug::Float::exact_from(&x), rug::Float::exact_from(&y), p),
+            (x, y, p),
+        )
+    }))
+}
+
+pub fn float_float_rounding_mode_triple_rm(
+    xs: It<(Float, Float, RoundingMode)>,
+) -> It<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Box::new(xs.map(|(x, y, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, y, rm),
+        )
+    }))
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_rm<T: PrimitiveUnsigned>(
+    xs: It<(Float, Float, T, RoundingMode)>,
+) -> It<(
+    (rug::Float, rug::Float, T, rug::float::Round),
+    (Float, Float, T, RoundingMode),
+)> {
+    Box::new(xs.map(|(x, y, p, rm)| {
+        (
+            (
+                rug::Float::exact_from(&x),
+                rug::Float::exact_from(&y),
+                p,
+                rug_round_exact_from_rounding_mode(rm),
+            ),
+            (x, y, p, rm),
+        )
+    }))
+}
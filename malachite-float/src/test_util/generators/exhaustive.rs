@@ -3,14 +3,21 @@ use crate::exhaustive::{
     exhaustive_non_negative_finite_floats, exhaustive_nonzero_finite_floats,
     exhaustive_positive_finite_floats,
 };
-use crate::test_util::extra_variadic::{exhaustive_triples_from_single, exhaustive_triples_xxy};
+use crate::test_util::arithmetic::fmod::rational_rem;
+use crate::test_util::extra_variadic::{
+    exhaustive_quadruples_xxxy, exhaustive_triples_from_single, exhaustive_triples_xxy,
+};
 use crate::{significand_bits, Float};
-use malachite_base::num::arithmetic::traits::IsPowerOf2;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, CheckedSqrt, IsPowerOf2, Parity, Pow, Reciprocal, Square,
+};
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::traits::NegativeInfinity;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
-use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::num::conversion::string::options::exhaustive::exhaustive_to_sci_options;
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, IsInteger, ToSci};
 use malachite_base::num::exhaustive::{
     exhaustive_positive_primitive_ints, exhaustive_primitive_floats, exhaustive_signeds,
     exhaustive_unsigneds,
@@ -18,7 +25,8 @@ use malachite_base::num::exhaustive::{
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
 use malachite_base::rounding_modes::RoundingMode;
-use malachite_base::test_util::generators::common::{reshape_2_1_to_3, It};
+use malachite_base::test_util::generators::common::{reshape_2_1_to_3, reshape_3_1_to_4, It};
+use malachite_base::test_util::generators::exhaustive::exhaustive_string_gen_var_15;
 use malachite_base::test_util::generators::exhaustive_pairs_big_tiny;
 use malachite_base::tuples::exhaustive::{
     exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples_xyy, lex_pairs,
@@ -29,6 +37,7 @@ use malachite_nz::natural::exhaustive::exhaustive_naturals;
 use malachite_nz::natural::Natural;
 use malachite_q::exhaustive::exhaustive_rationals;
 use malachite_q::Rational;
+use std::cmp::{max, Ordering};
 
 // -- Float --
 
@@ -56,6 +65,16 @@ pub fn exhaustive_float_gen_var_5() -> It<Float> {
     Box::new(exhaustive_non_negative_finite_floats())
 }
 
+// The exponential functions overflow when their input is too large, and inputs whose exponents
+// are somewhat smaller still produce results that `rug` can't represent.
+pub fn exp_input_valid(x: &Float) -> bool {
+    x.get_exponent().map_or(true, |e| e <= 26)
+}
+
+pub fn exhaustive_float_gen_var_6() -> It<Float> {
+    Box::new(exhaustive_floats().filter(exp_input_valid))
+}
+
 // -- (Float, Float) --
 
 pub fn exhaustive_float_pair_gen() -> It<(Float, Float)> {
@@ -72,6 +91,130 @@ pub fn exhaustive_float_triple_gen() -> It<(Float, Float, Float)> {
     Box::new(exhaustive_triples_from_single(exhaustive_floats()))
 }
 
+// -- (Float, Float, Float, PrimitiveUnsigned) --
+
+pub fn exhaustive_float_float_float_unsigned_quadruple_gen_var_1() -> It<(Float, Float, Float, u64)>
+{
+    Box::new(exhaustive_quadruples_xxxy(
+        exhaustive_floats(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+// -- (Float, Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn add_mul_prec_round_valid(
+    x: &Float,
+    y: &Float,
+    z: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || !z.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) + Rational::exact_from(y) * Rational::exact_from(z),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn sub_mul_prec_round_valid(
+    x: &Float,
+    y: &Float,
+    z: &Float,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || !z.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) - Rational::exact_from(y) * Rational::exact_from(z),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(
+) -> It<(Float, Float, Float, u64, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_quadruples_xxxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, ref z, p), rm)| add_mul_prec_round_valid(x, y, z, p, rm))
+        .map(|((x, y, z, p), rm)| (x, y, z, p, rm)),
+    )
+}
+
+pub fn exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(
+) -> It<(Float, Float, Float, u64, RoundingMode)> {
+    Box::new(
+        lex_pairs(
+            exhaustive_quadruples_xxxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, ref z, p), rm)| sub_mul_prec_round_valid(x, y, z, p, rm))
+        .map(|((x, y, z, p), rm)| (x, y, z, p, rm)),
+    )
+}
+
+// -- (Float, Float, Float, RoundingMode) --
+
+pub fn add_mul_round_valid(x: &Float, y: &Float, z: &Float, rm: RoundingMode) -> bool {
+    add_mul_prec_round_valid(
+        x,
+        y,
+        z,
+        max(
+            max(x.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        ),
+        rm,
+    )
+}
+
+pub fn sub_mul_round_valid(x: &Float, y: &Float, z: &Float, rm: RoundingMode) -> bool {
+    sub_mul_prec_round_valid(
+        x,
+        y,
+        z,
+        max(
+            max(x.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        ),
+        rm,
+    )
+}
+
+pub fn exhaustive_float_float_float_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, Float, Float, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, ref z), rm)| add_mul_round_valid(x, y, z, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_float_rounding_mode_quadruple_gen_var_2(
+) -> It<(Float, Float, Float, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, ref z), rm)| sub_mul_round_valid(x, y, z, rm)),
+    ))
+}
+
 // -- (Float, Float, Integer) --
 
 pub fn exhaustive_float_float_integer_triple_gen() -> It<(Float, Float, Integer)> {
@@ -118,6 +261,176 @@ pub fn exhaustive_float_float_unsigned_triple_gen<T: PrimitiveUnsigned>() -> It<
     ))
 }
 
+pub fn exhaustive_float_float_unsigned_triple_gen_var_1() -> It<(Float, Float, u64)> {
+    Box::new(exhaustive_triples_xxy(
+        exhaustive_floats(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn add_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) + Rational::exact_from(y),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn sub_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) - Rational::exact_from(y),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn mul_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) * Rational::exact_from(y),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn div_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || !y.is_finite()
+        || *y == 0u32
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x) / Rational::exact_from(y),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+fn rem_prec_round_valid(
+    x: &Float,
+    y: &Float,
+    quotient_rm: RoundingMode,
+    prec: u64,
+    rm: RoundingMode,
+) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || y.is_nan()
+        || *y == 0u32
+        || if y.is_finite() {
+            Float::from_rational_prec_round(
+                rational_rem(
+                    &Rational::exact_from(x),
+                    &Rational::exact_from(y),
+                    quotient_rm,
+                ),
+                prec,
+                RoundingMode::Floor,
+            )
+            .1 == Ordering::Equal
+        } else {
+            set_prec_round_valid(x, prec, rm)
+        }
+}
+
+pub fn fmod_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rem_prec_round_valid(x, y, RoundingMode::Down, prec, rm)
+}
+
+pub fn remainder_prec_round_valid(x: &Float, y: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rem_prec_round_valid(x, y, RoundingMode::Nearest, prec, rm)
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| add_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| sub_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| mul_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| div_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| fmod_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_6(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y, p), rm)| remainder_prec_round_valid(x, y, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_7(
+) -> It<(Float, Float, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xxy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref y, ref x, _), rm)| atan2_round_valid(y, x, rm)),
+    ))
+}
+
 // -- (Float, Float, Rational) --
 
 pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rational)> {
@@ -127,6 +440,81 @@ pub fn exhaustive_float_float_rational_triple_gen() -> It<(Float, Float, Rationa
     ))
 }
 
+// -- (Float, Float, RoundingMode) --
+
+pub fn add_round_valid(x: &Float, y: &Float, rm: RoundingMode) -> bool {
+    add_prec_round_valid(x, y, max(x.significant_bits(), y.significant_bits()), rm)
+}
+
+pub fn sub_round_valid(x: &Float, y: &Float, rm: RoundingMode) -> bool {
+    sub_prec_round_valid(x, y, max(x.significant_bits(), y.significant_bits()), rm)
+}
+
+pub fn mul_round_valid(x: &Float, y: &Float, rm: RoundingMode) -> bool {
+    mul_prec_round_valid(x, y, max(x.significant_bits(), y.significant_bits()), rm)
+}
+
+pub fn div_round_valid(x: &Float, y: &Float, rm: RoundingMode) -> bool {
+    div_prec_round_valid(x, y, max(x.significant_bits(), y.significant_bits()), rm)
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(lex_pairs(
+        exhaustive_pairs_from_single(exhaustive_floats()),
+        exhaustive_rounding_modes(),
+    )))
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_1() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y), rm)| add_round_valid(x, y, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_2() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y), rm)| sub_round_valid(x, y, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_3() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y), rm)| mul_round_valid(x, y, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_4() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, ref y), rm)| div_round_valid(x, y, rm)),
+    ))
+}
+
+pub fn exhaustive_float_float_rounding_mode_triple_gen_var_5() -> It<(Float, Float, RoundingMode)> {
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_from_single(exhaustive_floats()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref y, ref x), rm)| atan2_round_valid(y, x, rm)),
+    ))
+}
+
 // -- (Float, Integer) --
 
 pub fn exhaustive_float_integer_pair_gen() -> It<(Float, Integer)> {
@@ -213,48 +601,452 @@ pub fn exhaustive_float_signed_signed_triple_gen<T: PrimitiveSigned>() -> It<(Fl
     ))
 }
 
-// -- (Float, PrimitiveUnsigned) --
-
-pub fn exhaustive_float_unsigned_pair_gen<T: PrimitiveUnsigned>() -> It<(Float, T)> {
-    Box::new(exhaustive_pairs(
-        exhaustive_floats(),
-        exhaustive_unsigneds(),
+// -- (Float, PrimitiveUnsigned) --
+
+pub fn exhaustive_float_unsigned_pair_gen<T: PrimitiveUnsigned>() -> It<(Float, T)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_floats(),
+        exhaustive_unsigneds(),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> It<(Float, T)> {
+    Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_floats(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_pair_gen_var_2<T: PrimitiveUnsigned>() -> It<(Float, T)> {
+    Box::new(exhaustive_pairs_big_tiny(
+        exhaustive_floats().filter(exp_input_valid),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn exhaustive_float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> It<(Float, T, T)> {
+    Box::new(exhaustive_triples_xyy(
+        exhaustive_floats(),
+        exhaustive_unsigneds(),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+) -> It<(Float, T, T)> {
+    Box::new(exhaustive_triples_xyy(
+        exhaustive_floats(),
+        exhaustive_positive_primitive_ints(),
+    ))
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
+
+pub fn root_prec_round_valid(x: &Float, exp: u64, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || exp == 0
+        || !x.is_finite()
+        || *x == 0u32
+        || *x < 0u32 && exp.even()
+        || Rational::exact_from(x)
+            .checked_root(exp)
+            .map_or(false, |root| {
+                Float::from_rational_prec_round(root, prec, RoundingMode::Floor).1
+                    == Ordering::Equal
+            })
+}
+
+pub fn root_round_valid(x: &Float, exp: u64, rm: RoundingMode) -> bool {
+    root_prec_round_valid(x, exp, x.significant_bits(), rm)
+}
+
+pub fn exhaustive_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> It<(Float, u64, u64, RoundingMode)> {
+    reshape_3_1_to_4(Box::new(
+        lex_pairs(
+            exhaustive_triples_xyy(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, exp, p), rm)| root_prec_round_valid(x, exp, p, rm)),
+    ))
+}
+
+// -- (Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn set_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || x.is_zero() || x.get_prec().unwrap() <= p || {
+        let significand = x.significand_ref().unwrap();
+        significand_bits(significand) - significand.trailing_zeros().unwrap() <= p
+    }
+}
+
+pub fn square_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x).square(),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn reciprocal_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || *x == 0u32
+        || Float::from_rational_prec_round(
+            Rational::exact_from(x).reciprocal(),
+            prec,
+            RoundingMode::Floor,
+        )
+        .1 == Ordering::Equal
+}
+
+pub fn sqrt_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || *x <= 0u32
+        || Rational::exact_from(x)
+            .checked_sqrt()
+            .map_or(false, |sqrt| {
+                Float::from_rational_prec_round(sqrt, prec, RoundingMode::Floor).1
+                    == Ordering::Equal
+            })
+}
+
+pub fn reciprocal_sqrt_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || *x <= 0u32
+        || Rational::exact_from(x)
+            .checked_sqrt()
+            .map_or(false, |sqrt| {
+                Float::from_rational_prec_round(sqrt.reciprocal(), prec, RoundingMode::Floor).1
+                    == Ordering::Equal
+            })
+}
+
+pub fn exp10_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    exp_input_valid(x)
+        && (rm != RoundingMode::Exact
+            || !x.is_finite()
+            || u64::convertible_from(x) && {
+                let pow = u64::exact_from(x);
+                pow <= prec && Natural::from(5u32).pow(pow).significant_bits() <= prec
+            })
+}
+
+pub fn log2_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || *x <= 0u32
+        || x.is_power_of_2()
+            && Float::from_signed_prec_round(
+                x.get_exponent().unwrap() - 1,
+                prec,
+                RoundingMode::Floor,
+            )
+            .1 == Ordering::Equal
+}
+
+pub fn log10_prec_round_valid(x: &Float, prec: u64, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || !x.is_finite()
+        || *x <= 0u32
+        || x.is_integer() && {
+            let n = Natural::exact_from(x);
+            let pow = n.trailing_zeros().unwrap();
+            n == Natural::from(10u32).pow(pow)
+                && Float::from_unsigned_prec_round(pow, prec, RoundingMode::Floor).1
+                    == Ordering::Equal
+        }
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_1() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| set_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_2() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| square_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_3() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| reciprocal_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_4() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_5() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| reciprocal_sqrt_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_6() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, exp), rm)| root_round_valid(x, exp, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_7() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| exp_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_8() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| exp2_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_9() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| exp10_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_10() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| expm1_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_11() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| ln_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_12() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| ln_1p_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_13() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| log2_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_14() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, p), rm)| log10_prec_round_valid(x, p, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_15() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| sin_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_16() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| cos_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_17() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| tan_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_18() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| asin_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_19() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| acos_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_20() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| atan_round_valid(x, rm)),
+    ))
+}
+
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_21() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| sinh_round_valid(x, rm)),
     ))
 }
 
-pub fn exhaustive_float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> It<(Float, T)> {
-    Box::new(exhaustive_pairs_big_tiny(
-        exhaustive_floats(),
-        exhaustive_positive_primitive_ints(),
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_22() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| cosh_round_valid(x, rm)),
     ))
 }
 
-// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
-
-pub fn exhaustive_float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> It<(Float, T, T)> {
-    Box::new(exhaustive_triples_xyy(
-        exhaustive_floats(),
-        exhaustive_unsigneds(),
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_23() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| tanh_round_valid(x, rm)),
     ))
 }
 
-// -- (Float, PrimitiveUnsigned, RoundingMode) --
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_24() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| asinh_round_valid(x, rm)),
+    ))
+}
 
-pub fn set_prec_round_valid(x: &Float, p: u64, rm: RoundingMode) -> bool {
-    rm != RoundingMode::Exact || !x.is_finite() || x.is_zero() || x.get_prec().unwrap() <= p || {
-        let significand = x.significand_ref().unwrap();
-        significand_bits(significand) - significand.trailing_zeros().unwrap() <= p
-    }
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_25() -> It<(Float, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(
+        lex_pairs(
+            exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|&((ref x, _), rm)| acosh_round_valid(x, rm)),
+    ))
 }
 
-pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_1() -> It<(Float, u64, RoundingMode)>
+pub fn exhaustive_float_unsigned_rounding_mode_triple_gen_var_26() -> It<(Float, u64, RoundingMode)>
 {
     reshape_2_1_to_3(Box::new(
         lex_pairs(
             exhaustive_pairs_big_tiny(exhaustive_floats(), exhaustive_positive_primitive_ints()),
             exhaustive_rounding_modes(),
         )
-        .filter(|&((ref x, p), rm)| set_prec_round_valid(x, p, rm)),
+        .filter(|&((ref x, _), rm)| atanh_round_valid(x, rm)),
     ))
 }
 
@@ -407,6 +1199,296 @@ where
     )
 }
 
+pub fn square_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    square_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn reciprocal_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    reciprocal_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn sqrt_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    sqrt_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn reciprocal_sqrt_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    reciprocal_sqrt_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn exp_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp_input_valid(x) && (rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32)
+}
+
+pub fn exp2_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp_input_valid(x) && (rm != RoundingMode::Exact || !x.is_finite() || x.is_integer())
+}
+
+pub fn exp10_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp10_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn expm1_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp_input_valid(x) && (rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32)
+}
+
+pub fn ln_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x <= 0u32 || *x == 1u32
+}
+
+pub fn ln_1p_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x <= -1i32 || *x == 0u32
+}
+
+pub fn log2_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    log2_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+pub fn log10_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    log10_prec_round_valid(x, x.significant_bits(), rm)
+}
+
+// The trigonometric and hyperbolic functions of a finite, nonzero `Float` are never exactly
+// representable, except for the special values handled below.
+pub fn sin_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32
+}
+
+pub fn cos_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32
+}
+
+pub fn tan_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32
+}
+
+pub fn asin_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32 || *x > 1u32 || *x < -1i32
+}
+
+pub fn acos_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x >= 1u32 || *x < -1i32
+}
+
+pub fn atan_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || x.is_nan() || *x == 0u32
+}
+
+pub fn sinh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp_input_valid(x) && (rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32)
+}
+
+pub fn cosh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    exp_input_valid(x) && (rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32)
+}
+
+pub fn tanh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32
+}
+
+pub fn asinh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32
+}
+
+pub fn acosh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x <= 1u32
+}
+
+pub fn atanh_round_valid(x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact || !x.is_finite() || *x == 0u32 || *x >= 1u32 || *x <= -1i32
+}
+
+pub fn atan2_round_valid(y: &Float, x: &Float, rm: RoundingMode) -> bool {
+    rm != RoundingMode::Exact
+        || y.is_nan()
+        || x.is_nan()
+        || (*y == 0u32 && x.is_sign_positive())
+        || (y.is_finite() && x.is_infinite() && x.is_sign_positive())
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_7() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| square_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_8() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| reciprocal_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_9() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| sqrt_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_10() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| reciprocal_sqrt_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_11() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| exp_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_12() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| exp2_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_13() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| exp10_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_14() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| expm1_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_15() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| ln_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_16() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| ln_1p_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_17() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| log2_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_18() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| log10_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_19() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| sin_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_20() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| cos_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_21() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| tan_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_22() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| asin_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_23() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| acos_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_24() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| atan_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_25() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| sinh_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_26() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| cosh_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_27() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| tanh_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_28() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| asinh_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_29() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| acosh_round_valid(x, *rm)),
+    )
+}
+
+pub fn exhaustive_float_rounding_mode_pair_gen_var_30() -> It<(Float, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| atanh_round_valid(x, *rm)),
+    )
+}
+
+// -- (Float, ToSciOptions) --
+
+pub fn exhaustive_float_to_sci_options_pair_gen() -> It<(Float, ToSciOptions)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_floats(),
+        exhaustive_to_sci_options(),
+    ))
+}
+
+pub fn exhaustive_float_to_sci_options_pair_gen_var_1() -> It<(Float, ToSciOptions)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_floats(), exhaustive_to_sci_options())
+            .filter(|(x, options)| x.fmt_sci_valid(*options)),
+    )
+}
+
 // -- (Integer, PrimitiveUnsigned, RoundingMode) --
 
 // vars 1 through 2 are in malachite-nz.
@@ -573,6 +1655,17 @@ pub fn exhaustive_unsigned_unsigned_rounding_mode_triple_gen_var_7<T: PrimitiveU
     )))
 }
 
+// -- (PrimitiveUnsigned, RoundingMode) --
+
+// vars 1 through 2 are in malachite-base.
+
+pub fn exhaustive_unsigned_rounding_mode_pair_gen_var_3() -> It<(u64, RoundingMode)> {
+    Box::new(lex_pairs(
+        exhaustive_positive_primitive_ints(),
+        exhaustive_rounding_modes().filter(|rm| *rm != RoundingMode::Exact),
+    ))
+}
+
 // -- (Rational, PrimitiveUnsigned, RoundingMode) --
 
 pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_1(
@@ -597,3 +1690,16 @@ pub fn exhaustive_rational_unsigned_rounding_mode_triple_gen_var_2(
         exhaustive_rounding_modes().filter(|rm| *rm != RoundingMode::Exact),
     )))
 }
+
+// -- (String, PrimitiveUnsigned, RoundingMode) --
+
+pub fn exhaustive_string_unsigned_rounding_mode_triple_gen_var_1() -> It<(String, u64, RoundingMode)>
+{
+    reshape_2_1_to_3(Box::new(lex_pairs(
+        exhaustive_pairs_big_tiny(
+            exhaustive_string_gen_var_15(),
+            exhaustive_positive_primitive_ints(),
+        ),
+        exhaustive_rounding_modes().filter(|rm| *rm != RoundingMode::Exact),
+    )))
+}
@@ -1,7 +1,11 @@
 use crate::test_util::generators::common::{
+    float_float_float_rounding_mode_quadruple_rm, float_float_float_unsigned_quadruple_rm,
+    float_float_float_unsigned_rounding_mode_quintuple_rm, float_float_rounding_mode_triple_rm,
+    float_float_unsigned_rounding_mode_quadruple_rm, float_float_unsigned_triple_rm,
     float_integer_pair_rm, float_natural_pair_rm, float_pair_rm, float_primitive_float_pair_rm,
-    float_primitive_int_pair_rm, float_rational_pair_rm, float_rm,
-    float_unsigned_rounding_mode_triple_rm,
+    float_primitive_int_pair_rm, float_rational_pair_rm, float_rm, float_rounding_mode_pair_rm,
+    float_triple_rm, float_unsigned_rounding_mode_triple_rm,
+    float_unsigned_unsigned_rounding_mode_quadruple_rm, float_unsigned_unsigned_triple_rm,
 };
 use crate::test_util::generators::exhaustive::*;
 use crate::test_util::generators::random::*;
@@ -10,6 +14,7 @@ use crate::Float;
 use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::string::options::ToSciOptions;
 use malachite_base::num::conversion::traits::ConvertibleFrom;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::common::Generator;
@@ -88,6 +93,23 @@ pub fn float_gen_var_5() -> Generator<Float> {
     )
 }
 
+// All `Float`s that are valid inputs to `Float::exp` and the other exponential functions.
+pub fn float_gen_var_6() -> Generator<Float> {
+    Generator::new(
+        &exhaustive_float_gen_var_6,
+        &random_float_gen_var_6,
+        &special_random_float_gen_var_6,
+    )
+}
+
+pub fn float_gen_var_6_rm() -> Generator<(rug::Float, Float)> {
+    Generator::new(
+        &|| float_rm(exhaustive_float_gen_var_6()),
+        &|config| float_rm(random_float_gen_var_6(config)),
+        &|config| float_rm(special_random_float_gen_var_6(config)),
+    )
+}
+
 // -- (Float, Float) --
 
 pub fn float_pair_gen() -> Generator<(Float, Float)> {
@@ -125,6 +147,187 @@ pub fn float_triple_gen() -> Generator<(Float, Float, Float)> {
     )
 }
 
+pub fn float_triple_gen_rm(
+) -> Generator<((rug::Float, rug::Float, rug::Float), (Float, Float, Float))> {
+    Generator::new(
+        &|| float_triple_rm(exhaustive_float_triple_gen()),
+        &|config| float_triple_rm(random_float_triple_gen(config)),
+        &|config| float_triple_rm(special_random_float_triple_gen(config)),
+    )
+}
+
+// -- (Float, Float, Float, PrimitiveUnsigned) --
+
+// All `(Float, Float, Float, u64)` where the `u64` is positive.
+pub fn float_float_float_unsigned_quadruple_gen_var_1() -> Generator<(Float, Float, Float, u64)> {
+    Generator::new(
+        &exhaustive_float_float_float_unsigned_quadruple_gen_var_1,
+        &random_float_float_float_unsigned_quadruple_gen_var_1,
+        &special_random_float_float_float_unsigned_quadruple_gen_var_1,
+    )
+}
+
+pub fn float_float_float_unsigned_quadruple_gen_var_1_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::Float, u64),
+    (Float, Float, Float, u64),
+)> {
+    Generator::new(
+        &|| {
+            float_float_float_unsigned_quadruple_rm(
+                exhaustive_float_float_float_unsigned_quadruple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_quadruple_rm(
+                random_float_float_float_unsigned_quadruple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_quadruple_rm(
+                special_random_float_float_float_unsigned_quadruple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// -- (Float, Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::add_mul_prec_round`.
+pub fn float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(
+) -> Generator<(Float, Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1,
+        &random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1,
+        &special_random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1,
+    )
+}
+
+pub fn float_float_float_unsigned_rounding_mode_quintuple_gen_var_1_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                special_random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, Float, u64, RoundingMode)` that are valid inputs to
+// `Float::sub_mul_prec_round`.
+pub fn float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(
+) -> Generator<(Float, Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2,
+        &random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2,
+        &special_random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2,
+    )
+}
+
+pub fn float_float_float_unsigned_rounding_mode_quintuple_gen_var_2_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                exhaustive_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(config),
+            )
+        },
+        &|config| {
+            float_float_float_unsigned_rounding_mode_quintuple_rm(
+                special_random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(config),
+            )
+        },
+    )
+}
+
+// -- (Float, Float, Float, RoundingMode) --
+
+// All `(Float, Float, Float, RoundingMode)` that are valid inputs to `Float::add_mul_round`.
+pub fn float_float_float_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_float_rounding_mode_quadruple_gen_var_1,
+        &random_float_float_float_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_float_float_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+pub fn float_float_float_rounding_mode_quadruple_gen_var_1_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_float_rounding_mode_quadruple_rm(
+                exhaustive_float_float_float_rounding_mode_quadruple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_float_rounding_mode_quadruple_rm(
+                random_float_float_float_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_float_rounding_mode_quadruple_rm(
+                special_random_float_float_float_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, Float, RoundingMode)` that are valid inputs to `Float::sub_mul_round`.
+pub fn float_float_float_rounding_mode_quadruple_gen_var_2(
+) -> Generator<(Float, Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_float_rounding_mode_quadruple_gen_var_2,
+        &random_float_float_float_rounding_mode_quadruple_gen_var_2,
+        &special_random_float_float_float_rounding_mode_quadruple_gen_var_2,
+    )
+}
+
+pub fn float_float_float_rounding_mode_quadruple_gen_var_2_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_float_rounding_mode_quadruple_rm(
+                exhaustive_float_float_float_rounding_mode_quadruple_gen_var_2(),
+            )
+        },
+        &|config| {
+            float_float_float_rounding_mode_quadruple_rm(
+                random_float_float_float_rounding_mode_quadruple_gen_var_2(config),
+            )
+        },
+        &|config| {
+            float_float_float_rounding_mode_quadruple_rm(
+                special_random_float_float_float_rounding_mode_quadruple_gen_var_2(config),
+            )
+        },
+    )
+}
+
 // -- (Float, Float, Integer) --
 
 pub fn float_float_integer_triple_gen() -> Generator<(Float, Float, Integer)> {
@@ -175,353 +378,2179 @@ pub fn float_float_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(Flo
     )
 }
 
-// -- (Float, Float, Rational) --
-
-pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)> {
+// All `(Float, Float, u64)` where the `u64` is positive.
+pub fn float_float_unsigned_triple_gen_var_1() -> Generator<(Float, Float, u64)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_triple_gen_var_1,
+        &random_float_float_unsigned_triple_gen_var_1,
+        &special_random_float_float_unsigned_triple_gen_var_1,
+    )
+}
+
+pub fn float_float_unsigned_triple_gen_var_1_rm(
+) -> Generator<((rug::Float, rug::Float, u64), (Float, Float, u64))> {
+    Generator::new(
+        &|| float_float_unsigned_triple_rm(exhaustive_float_float_unsigned_triple_gen_var_1()),
+        &|config| {
+            float_float_unsigned_triple_rm(random_float_float_unsigned_triple_gen_var_1(config))
+        },
+        &|config| {
+            float_float_unsigned_triple_rm(special_random_float_float_unsigned_triple_gen_var_1(
+                config,
+            ))
+        },
+    )
+}
+
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::add_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_1_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::sub_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_2(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_2,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_2_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_2(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_2(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_2(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::mul_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_3(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_3,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_3_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_3(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_3(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_3(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::div_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_4(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_4,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_4_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_4(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_4(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_4(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::fmod_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_5_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_5(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::remainder_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_6(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_6,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_6,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_6,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_6_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_6(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_6(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_6(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, u64, RoundingMode)` that are valid inputs to `Float::atan2_prec_round`.
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_7(
+) -> Generator<(Float, Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_7,
+        &random_float_float_unsigned_rounding_mode_quadruple_gen_var_7,
+        &special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_7,
+    )
+}
+
+pub fn float_float_unsigned_rounding_mode_quadruple_gen_var_7_rm() -> Generator<(
+    (rug::Float, rug::Float, u64, rug::float::Round),
+    (Float, Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_float_unsigned_rounding_mode_quadruple_gen_var_7(),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                random_float_float_unsigned_rounding_mode_quadruple_gen_var_7(config),
+            )
+        },
+        &|config| {
+            float_float_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_float_unsigned_rounding_mode_quadruple_gen_var_7(config),
+            )
+        },
+    )
+}
+
+// -- (Float, Float, Rational) --
+
+pub fn float_float_rational_triple_gen() -> Generator<(Float, Float, Rational)> {
+    Generator::new(
+        &exhaustive_float_float_rational_triple_gen,
+        &random_float_float_rational_triple_gen,
+        &special_random_float_float_rational_triple_gen,
+    )
+}
+
+// -- (Float, Float, RoundingMode) --
+
+// All `(Float, Float, RoundingMode)`.
+pub fn float_float_rounding_mode_triple_gen() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen,
+        &random_float_float_rounding_mode_triple_gen,
+        &special_random_float_float_rounding_mode_triple_gen,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| float_float_rounding_mode_triple_rm(exhaustive_float_float_rounding_mode_triple_gen()),
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen(config))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::add_round`.
+pub fn float_float_rounding_mode_triple_gen_var_1() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_1,
+        &random_float_float_rounding_mode_triple_gen_var_1,
+        &special_random_float_float_rounding_mode_triple_gen_var_1,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_1_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_1(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::sub_round`.
+pub fn float_float_rounding_mode_triple_gen_var_2() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_2,
+        &random_float_float_rounding_mode_triple_gen_var_2,
+        &special_random_float_float_rounding_mode_triple_gen_var_2,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_2_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_2(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_2(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_2(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::mul_round`.
+pub fn float_float_rounding_mode_triple_gen_var_3() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_3,
+        &random_float_float_rounding_mode_triple_gen_var_3,
+        &special_random_float_float_rounding_mode_triple_gen_var_3,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_3_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_3(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_3(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_3(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::div_round`.
+pub fn float_float_rounding_mode_triple_gen_var_4() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_4,
+        &random_float_float_rounding_mode_triple_gen_var_4,
+        &special_random_float_float_rounding_mode_triple_gen_var_4,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_4_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_4(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_4(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_4(config),
+            )
+        },
+    )
+}
+
+// All `(Float, Float, RoundingMode)` that are valid inputs to `Float::atan2_round`.
+pub fn float_float_rounding_mode_triple_gen_var_5() -> Generator<(Float, Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_float_rounding_mode_triple_gen_var_5,
+        &random_float_float_rounding_mode_triple_gen_var_5,
+        &special_random_float_float_rounding_mode_triple_gen_var_5,
+    )
+}
+
+pub fn float_float_rounding_mode_triple_gen_var_5_rm() -> Generator<(
+    (rug::Float, rug::Float, rug::float::Round),
+    (Float, Float, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_float_rounding_mode_triple_rm(
+                exhaustive_float_float_rounding_mode_triple_gen_var_5(),
+            )
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(random_float_float_rounding_mode_triple_gen_var_5(
+                config,
+            ))
+        },
+        &|config| {
+            float_float_rounding_mode_triple_rm(
+                special_random_float_float_rounding_mode_triple_gen_var_5(config),
+            )
+        },
+    )
+}
+
+// -- (Float, Integer) --
+
+pub fn float_integer_pair_gen() -> Generator<(Float, Integer)> {
+    Generator::new(
+        &exhaustive_float_integer_pair_gen,
+        &random_float_integer_pair_gen,
+        &special_random_float_integer_pair_gen,
+    )
+}
+
+pub fn float_integer_pair_gen_rm() -> Generator<((rug::Float, rug::Integer), (Float, Integer))> {
+    Generator::new(
+        &|| float_integer_pair_rm(exhaustive_float_integer_pair_gen()),
+        &|config| float_integer_pair_rm(random_float_integer_pair_gen(config)),
+        &|config| float_integer_pair_rm(special_random_float_integer_pair_gen(config)),
+    )
+}
+
+pub fn float_integer_pair_gen_var_1() -> Generator<(Float, Integer)> {
+    Generator::new(
+        &exhaustive_float_integer_pair_gen_var_1,
+        &random_float_integer_pair_gen_var_1,
+        &special_random_float_integer_pair_gen_var_1,
+    )
+}
+
+// -- (Float, Integer, Integer) --
+
+pub fn float_integer_integer_triple_gen() -> Generator<(Float, Integer, Integer)> {
+    Generator::new(
+        &exhaustive_float_integer_integer_triple_gen,
+        &random_float_integer_integer_triple_gen,
+        &special_random_float_integer_integer_triple_gen,
+    )
+}
+
+// -- (Float, Natural) --
+
+pub fn float_natural_pair_gen() -> Generator<(Float, Natural)> {
+    Generator::new(
+        &exhaustive_float_natural_pair_gen,
+        &random_float_natural_pair_gen,
+        &special_random_float_natural_pair_gen,
+    )
+}
+
+pub fn float_natural_pair_gen_rm() -> Generator<((rug::Float, rug::Integer), (Float, Natural))> {
+    Generator::new(
+        &|| float_natural_pair_rm(exhaustive_float_natural_pair_gen()),
+        &|config| float_natural_pair_rm(random_float_natural_pair_gen(config)),
+        &|config| float_natural_pair_rm(special_random_float_natural_pair_gen(config)),
+    )
+}
+
+// All pairs of finite Floats and Naturals.
+pub fn float_natural_pair_gen_var_1() -> Generator<(Float, Natural)> {
+    Generator::new(
+        &exhaustive_float_natural_pair_gen_var_1,
+        &random_float_natural_pair_gen_var_1,
+        &special_random_float_natural_pair_gen_var_1,
+    )
+}
+
+// -- (Float, Natural, Natural) --
+
+pub fn float_natural_natural_triple_gen() -> Generator<(Float, Natural, Natural)> {
+    Generator::new(
+        &exhaustive_float_natural_natural_triple_gen,
+        &random_float_natural_natural_triple_gen,
+        &special_random_float_natural_natural_triple_gen,
+    )
+}
+
+// -- (Float, PrimitiveFloat) --
+
+pub fn float_primitive_float_pair_gen<T: PrimitiveFloat>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_primitive_float_pair_gen,
+        &random_float_primitive_float_pair_gen,
+        &special_random_float_primitive_float_pair_gen,
+    )
+}
+
+pub fn float_primitive_float_pair_gen_rm<T: PrimitiveFloat>(
+) -> Generator<((rug::Float, T), (Float, T))> {
+    Generator::new(
+        &|| float_primitive_float_pair_rm(exhaustive_float_primitive_float_pair_gen()),
+        &|config| float_primitive_float_pair_rm(random_float_primitive_float_pair_gen(config)),
+        &|config| {
+            float_primitive_float_pair_rm(special_random_float_primitive_float_pair_gen(config))
+        },
+    )
+}
+
+// -- (Float, PrimitiveFloat, PrimitiveFloat) --
+
+pub fn float_primitive_float_primitive_float_triple_gen<T: PrimitiveFloat>(
+) -> Generator<(Float, T, T)> {
+    Generator::new(
+        &exhaustive_float_primitive_float_primitive_float_triple_gen,
+        &random_float_primitive_float_primitive_float_triple_gen,
+        &special_random_float_primitive_float_primitive_float_triple_gen,
+    )
+}
+
+// -- (Float, PrimitiveSigned) --
+
+pub fn float_signed_pair_gen<T: PrimitiveSigned>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_signed_pair_gen,
+        &random_float_signed_pair_gen,
+        &special_random_float_signed_pair_gen,
+    )
+}
+
+pub fn float_signed_pair_gen_rm<T: PrimitiveSigned>() -> Generator<((rug::Float, T), (Float, T))> {
+    Generator::new(
+        &|| float_primitive_int_pair_rm(exhaustive_float_signed_pair_gen()),
+        &|config| float_primitive_int_pair_rm(random_float_signed_pair_gen(config)),
+        &|config| float_primitive_int_pair_rm(special_random_float_signed_pair_gen(config)),
+    )
+}
+
+// All `(Float, T)` where `T` is signed and small and the `Float` is in the range [1.0, 2.0).
+pub fn float_signed_pair_gen_var_1<T: PrimitiveSigned>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_signed_pair_gen_var_1,
+        &random_float_signed_pair_gen_var_1,
+        &special_random_float_signed_pair_gen_var_1,
+    )
+}
+
+// -- (Float, PrimitiveSigned, PrimitiveSigned) --
+
+pub fn float_signed_signed_triple_gen<T: PrimitiveSigned>() -> Generator<(Float, T, T)> {
+    Generator::new(
+        &exhaustive_float_signed_signed_triple_gen,
+        &random_float_primitive_int_primitive_int_triple_gen,
+        &special_random_float_signed_signed_triple_gen,
+    )
+}
+
+// -- (Float, PrimitiveUnsigned) --
+
+pub fn float_unsigned_pair_gen<T: PrimitiveUnsigned>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_unsigned_pair_gen,
+        &random_float_unsigned_pair_gen,
+        &special_random_float_unsigned_pair_gen,
+    )
+}
+
+type GT<T> = Generator<((rug::Float, T), (Float, T))>;
+pub fn float_unsigned_pair_gen_rm<T: PrimitiveUnsigned>() -> GT<T> {
+    Generator::new(
+        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen()),
+        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen(config)),
+        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen(config)),
+    )
+}
+
+// All `(Float, T)` where `T` is unsigned, small, and positive.
+pub fn float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_unsigned_pair_gen_var_1,
+        &random_float_unsigned_pair_gen_var_1,
+        &special_random_float_unsigned_pair_gen_var_1,
+    )
+}
+
+pub fn float_unsigned_pair_gen_var_1_rm<T: PrimitiveUnsigned>(
+) -> Generator<((rug::Float, T), (Float, T))> {
+    Generator::new(
+        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen_var_1()),
+        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen_var_1(config)),
+        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen_var_1(config)),
+    )
+}
+
+// All `(Float, T)` where `T` is unsigned, small, and positive, and the `Float` is a valid input to
+// `Float::exp_prec` and the other exponential functions.
+pub fn float_unsigned_pair_gen_var_2<T: PrimitiveUnsigned>() -> Generator<(Float, T)> {
+    Generator::new(
+        &exhaustive_float_unsigned_pair_gen_var_2,
+        &random_float_unsigned_pair_gen_var_2,
+        &special_random_float_unsigned_pair_gen_var_2,
+    )
+}
+
+pub fn float_unsigned_pair_gen_var_2_rm<T: PrimitiveUnsigned>(
+) -> Generator<((rug::Float, T), (Float, T))> {
+    Generator::new(
+        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen_var_2()),
+        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen_var_2(config)),
+        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen_var_2(config)),
+    )
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(Float, T, T)> {
+    Generator::new(
+        &exhaustive_float_unsigned_unsigned_triple_gen,
+        &random_float_primitive_int_primitive_int_triple_gen,
+        &special_random_float_unsigned_unsigned_triple_gen,
+    )
+}
+
+// All `(Float, T, T)` where both `T`s are positive and small.
+pub fn float_unsigned_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>() -> Generator<(Float, T, T)>
+{
+    Generator::new(
+        &exhaustive_float_unsigned_unsigned_triple_gen_var_1,
+        &random_float_unsigned_unsigned_triple_gen_var_1,
+        &special_random_float_unsigned_unsigned_triple_gen_var_1,
+    )
+}
+
+pub fn float_unsigned_unsigned_triple_gen_var_1_rm<T: PrimitiveUnsigned>(
+) -> Generator<((rug::Float, T, T), (Float, T, T))> {
+    Generator::new(
+        &|| float_unsigned_unsigned_triple_rm(exhaustive_float_unsigned_unsigned_triple_gen_var_1()),
+        &|config| {
+            float_unsigned_unsigned_triple_rm(random_float_unsigned_unsigned_triple_gen_var_1(
+                config,
+            ))
+        },
+        &|config| {
+            float_unsigned_unsigned_triple_rm(
+                special_random_float_unsigned_unsigned_triple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, u64, u64, RoundingMode)` that are valid inputs to `Float::root_prec_round`, where
+// both `u64`s are positive.
+pub fn float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Float, u64, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+        &random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+        &special_random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+pub fn float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1_rm() -> Generator<(
+    (rug::Float, u64, u64, rug::float::Round),
+    (Float, u64, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_unsigned_rounding_mode_quadruple_rm(
+                exhaustive_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_unsigned_unsigned_rounding_mode_quadruple_rm(
+                random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_unsigned_unsigned_rounding_mode_quadruple_rm(
+                special_random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// -- (Float, PrimitiveUnsigned, RoundingMode) --
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.set_prec`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_1() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_1,
+        &random_float_unsigned_rounding_mode_triple_gen_var_1,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_1,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_1_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_1(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_1(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_1(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::square_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_2() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_2,
+        &random_float_unsigned_rounding_mode_triple_gen_var_2,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_2,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_2_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_2(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_2(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_2(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::reciprocal_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_3() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_3,
+        &random_float_unsigned_rounding_mode_triple_gen_var_3,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_3,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_3_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_3(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_3(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_3(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_4() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_4,
+        &random_float_unsigned_rounding_mode_triple_gen_var_4,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_4,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_4_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_4(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_4(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_4(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::reciprocal_sqrt_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_5() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_5,
+        &random_float_unsigned_rounding_mode_triple_gen_var_5,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_5,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_5_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_5(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_5(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_5(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::root_round`, where the `u64`
+// is positive.
+pub fn float_unsigned_rounding_mode_triple_gen_var_6() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_6,
+        &random_float_unsigned_rounding_mode_triple_gen_var_6,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_6,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_6_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_6(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_6(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_6(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_7() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_7,
+        &random_float_unsigned_rounding_mode_triple_gen_var_7,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_7,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_7_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_7(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_7(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_7(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp2_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_8() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_8,
+        &random_float_unsigned_rounding_mode_triple_gen_var_8,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_8,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_8_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_8(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_8(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_8(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::exp10_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_9() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_9,
+        &random_float_unsigned_rounding_mode_triple_gen_var_9,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_9,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_9_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_9(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_9(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_9(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::expm1_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_10() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_10,
+        &random_float_unsigned_rounding_mode_triple_gen_var_10,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_10,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_10_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_10(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_10(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_10(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::ln_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_11() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_11,
+        &random_float_unsigned_rounding_mode_triple_gen_var_11,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_11,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_11_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_11(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_11(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_11(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::ln_1p_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_12() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_12,
+        &random_float_unsigned_rounding_mode_triple_gen_var_12,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_12,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_12_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_12(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_12(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_12(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::log2_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_13() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_13,
+        &random_float_unsigned_rounding_mode_triple_gen_var_13,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_13,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_13_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_13(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_13(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_13(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::log10_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_14() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_14,
+        &random_float_unsigned_rounding_mode_triple_gen_var_14,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_14,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_14_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_14(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_14(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_14(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sin_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_15() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_15,
+        &random_float_unsigned_rounding_mode_triple_gen_var_15,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_15,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_15_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_15(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_15(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_15(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::cos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_16() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_16,
+        &random_float_unsigned_rounding_mode_triple_gen_var_16,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_16,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_16_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_16(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_16(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_16(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::tan_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_17() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_17,
+        &random_float_unsigned_rounding_mode_triple_gen_var_17,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_17,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_17_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_17(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_17(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_17(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::asin_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_18() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_18,
+        &random_float_unsigned_rounding_mode_triple_gen_var_18,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_18,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_18_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_18(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_18(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_18(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::acos_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_19() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_19,
+        &random_float_unsigned_rounding_mode_triple_gen_var_19,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_19,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_19_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_19(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_19(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_19(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::atan_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_20() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_20,
+        &random_float_unsigned_rounding_mode_triple_gen_var_20,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_20,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_20_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_20(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_20(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_20(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::sinh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_21() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_21,
+        &random_float_unsigned_rounding_mode_triple_gen_var_21,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_21,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_21_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_21(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_21(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_21(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::cosh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_22() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_22,
+        &random_float_unsigned_rounding_mode_triple_gen_var_22,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_22,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_22_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_22(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_22(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_22(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::tanh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_23() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_23,
+        &random_float_unsigned_rounding_mode_triple_gen_var_23,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_23,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_23_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_23(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_23(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_23(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::asinh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_24() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_24,
+        &random_float_unsigned_rounding_mode_triple_gen_var_24,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_24,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_24_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_24(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_24(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_24(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::acosh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_25() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_25,
+        &random_float_unsigned_rounding_mode_triple_gen_var_25,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_25,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_25_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_25(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_25(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_25(config),
+            )
+        },
+    )
+}
+
+// All `(Float, u64, RoundingMode)` that are valid inputs to `Float::atanh_prec_round`.
+pub fn float_unsigned_rounding_mode_triple_gen_var_26() -> Generator<(Float, u64, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_26,
+        &random_float_unsigned_rounding_mode_triple_gen_var_26,
+        &special_random_float_unsigned_rounding_mode_triple_gen_var_26,
+    )
+}
+
+pub fn float_unsigned_rounding_mode_triple_gen_var_26_rm() -> Generator<(
+    (rug::Float, u64, rug::float::Round),
+    (Float, u64, RoundingMode),
+)> {
+    Generator::new(
+        &|| {
+            float_unsigned_rounding_mode_triple_rm(
+                exhaustive_float_unsigned_rounding_mode_triple_gen_var_26(),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                random_float_unsigned_rounding_mode_triple_gen_var_26(config),
+            )
+        },
+        &|config| {
+            float_unsigned_rounding_mode_triple_rm(
+                special_random_float_unsigned_rounding_mode_triple_gen_var_26(config),
+            )
+        },
+    )
+}
+
+// -- (Float, Rational) --
+
+pub fn float_rational_pair_gen() -> Generator<(Float, Rational)> {
+    Generator::new(
+        &exhaustive_float_rational_pair_gen,
+        &random_float_rational_pair_gen,
+        &special_random_float_rational_pair_gen,
+    )
+}
+
+pub fn float_rational_pair_gen_rm() -> Generator<((rug::Float, rug::Rational), (Float, Rational))> {
+    Generator::new(
+        &|| float_rational_pair_rm(exhaustive_float_rational_pair_gen()),
+        &|config| float_rational_pair_rm(random_float_rational_pair_gen(config)),
+        &|config| float_rational_pair_rm(special_random_float_rational_pair_gen(config)),
+    )
+}
+
+// All pairs of finite Floats and Rationals.
+pub fn float_rational_pair_gen_var_1() -> Generator<(Float, Rational)> {
+    Generator::new(
+        &exhaustive_float_rational_pair_gen_var_1,
+        &random_float_rational_pair_gen_var_1,
+        &special_random_float_rational_pair_gen_var_1,
+    )
+}
+
+// -- (Float, Rational, Rational) --
+
+pub fn float_rational_rational_triple_gen() -> Generator<(Float, Rational, Rational)> {
+    Generator::new(
+        &exhaustive_float_rational_rational_triple_gen,
+        &random_float_rational_rational_triple_gen,
+        &special_random_float_rational_rational_triple_gen,
+    )
+}
+
+// -- (Float, RoundingMode) --
+
+pub fn float_rounding_mode_pair_gen() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen,
+        &random_float_rounding_mode_pair_gen,
+        &special_random_float_rounding_mode_pair_gen,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Natural::rounding_from`.
+pub fn float_rounding_mode_pair_gen_var_1() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_1,
+        &random_float_rounding_mode_pair_gen_var_1,
+        &special_random_float_rounding_mode_pair_gen_var_1,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Integer::rounding_from`.
+pub fn float_rounding_mode_pair_gen_var_2() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_2,
+        &random_float_rounding_mode_pair_gen_var_2,
+        &special_random_float_rounding_mode_pair_gen_var_2,
+    )
+}
+
+// All `(Float, RoundingMode)` where the float is finite and nonzero.
+pub fn float_rounding_mode_pair_gen_var_3() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_3,
+        &random_float_rounding_mode_pair_gen_var_3,
+        &special_random_float_rounding_mode_pair_gen_var_3,
+    )
+}
+
+// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is unsigned.
+type GT2 = Generator<(Float, RoundingMode)>;
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn float_rounding_mode_pair_gen_var_4<T: PrimitiveUnsigned>() -> GT2
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_4::<T>,
+        &random_float_rounding_mode_pair_gen_var_4::<T>,
+        &special_random_float_rounding_mode_pair_gen_var_4::<T>,
+    )
+}
+
+// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is signed.
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn float_rounding_mode_pair_gen_var_5<T: PrimitiveSigned>() -> Generator<(Float, RoundingMode)>
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_5::<T>,
+        &random_float_rounding_mode_pair_gen_var_5::<T>,
+        &special_random_float_rounding_mode_pair_gen_var_5::<T>,
+    )
+}
+
+// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is a primitive
+// float.
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn float_rounding_mode_pair_gen_var_6<T: PrimitiveFloat>() -> Generator<(Float, RoundingMode)>
+where
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_6::<T>,
+        &random_float_rounding_mode_pair_gen_var_6::<T>,
+        &special_random_float_rounding_mode_pair_gen_var_6::<T>,
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::square_round`.
+pub fn float_rounding_mode_pair_gen_var_7() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_7,
+        &random_float_rounding_mode_pair_gen_var_7,
+        &special_random_float_rounding_mode_pair_gen_var_7,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_7_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_7()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_7(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_7(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::reciprocal_round`.
+pub fn float_rounding_mode_pair_gen_var_8() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_8,
+        &random_float_rounding_mode_pair_gen_var_8,
+        &special_random_float_rounding_mode_pair_gen_var_8,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_8_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_8()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_8(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_8(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::sqrt_round`.
+pub fn float_rounding_mode_pair_gen_var_9() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_9,
+        &random_float_rounding_mode_pair_gen_var_9,
+        &special_random_float_rounding_mode_pair_gen_var_9,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_9_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_9()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_9(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_9(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::reciprocal_sqrt_round`.
+pub fn float_rounding_mode_pair_gen_var_10() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_10,
+        &random_float_rounding_mode_pair_gen_var_10,
+        &special_random_float_rounding_mode_pair_gen_var_10,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_10_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_10()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_10(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_10(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::exp_round`.
+pub fn float_rounding_mode_pair_gen_var_11() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_11,
+        &random_float_rounding_mode_pair_gen_var_11,
+        &special_random_float_rounding_mode_pair_gen_var_11,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_11_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_11()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_11(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_11(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::exp2_round`.
+pub fn float_rounding_mode_pair_gen_var_12() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_12,
+        &random_float_rounding_mode_pair_gen_var_12,
+        &special_random_float_rounding_mode_pair_gen_var_12,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_12_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_12()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_12(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_12(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::exp10_round`.
+pub fn float_rounding_mode_pair_gen_var_13() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_13,
+        &random_float_rounding_mode_pair_gen_var_13,
+        &special_random_float_rounding_mode_pair_gen_var_13,
+    )
+}
+
+pub fn float_rounding_mode_pair_gen_var_13_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_13()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_13(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_13(config))
+        },
+    )
+}
+
+// All `(Float, RoundingMode)` that are valid inputs to `Float::expm1_round`.
+pub fn float_rounding_mode_pair_gen_var_14() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_float_rational_triple_gen,
-        &random_float_float_rational_triple_gen,
-        &special_random_float_float_rational_triple_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_14,
+        &random_float_rounding_mode_pair_gen_var_14,
+        &special_random_float_rounding_mode_pair_gen_var_14,
     )
 }
 
-// -- (Float, Integer) --
-
-pub fn float_integer_pair_gen() -> Generator<(Float, Integer)> {
+pub fn float_rounding_mode_pair_gen_var_14_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_integer_pair_gen,
-        &random_float_integer_pair_gen,
-        &special_random_float_integer_pair_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_14()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_14(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_14(config))
+        },
     )
 }
 
-pub fn float_integer_pair_gen_rm() -> Generator<((rug::Float, rug::Integer), (Float, Integer))> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::ln_round`.
+pub fn float_rounding_mode_pair_gen_var_15() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &|| float_integer_pair_rm(exhaustive_float_integer_pair_gen()),
-        &|config| float_integer_pair_rm(random_float_integer_pair_gen(config)),
-        &|config| float_integer_pair_rm(special_random_float_integer_pair_gen(config)),
+        &exhaustive_float_rounding_mode_pair_gen_var_15,
+        &random_float_rounding_mode_pair_gen_var_15,
+        &special_random_float_rounding_mode_pair_gen_var_15,
     )
 }
 
-pub fn float_integer_pair_gen_var_1() -> Generator<(Float, Integer)> {
+pub fn float_rounding_mode_pair_gen_var_15_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_integer_pair_gen_var_1,
-        &random_float_integer_pair_gen_var_1,
-        &special_random_float_integer_pair_gen_var_1,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_15()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_15(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_15(config))
+        },
     )
 }
 
-// -- (Float, Integer, Integer) --
-
-pub fn float_integer_integer_triple_gen() -> Generator<(Float, Integer, Integer)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::ln_1p_round`.
+pub fn float_rounding_mode_pair_gen_var_16() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_integer_integer_triple_gen,
-        &random_float_integer_integer_triple_gen,
-        &special_random_float_integer_integer_triple_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_16,
+        &random_float_rounding_mode_pair_gen_var_16,
+        &special_random_float_rounding_mode_pair_gen_var_16,
     )
 }
 
-// -- (Float, Natural) --
-
-pub fn float_natural_pair_gen() -> Generator<(Float, Natural)> {
+pub fn float_rounding_mode_pair_gen_var_16_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_natural_pair_gen,
-        &random_float_natural_pair_gen,
-        &special_random_float_natural_pair_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_16()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_16(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_16(config))
+        },
     )
 }
 
-pub fn float_natural_pair_gen_rm() -> Generator<((rug::Float, rug::Integer), (Float, Natural))> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::log2_round`.
+pub fn float_rounding_mode_pair_gen_var_17() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &|| float_natural_pair_rm(exhaustive_float_natural_pair_gen()),
-        &|config| float_natural_pair_rm(random_float_natural_pair_gen(config)),
-        &|config| float_natural_pair_rm(special_random_float_natural_pair_gen(config)),
+        &exhaustive_float_rounding_mode_pair_gen_var_17,
+        &random_float_rounding_mode_pair_gen_var_17,
+        &special_random_float_rounding_mode_pair_gen_var_17,
     )
 }
 
-// All pairs of finite Floats and Naturals.
-pub fn float_natural_pair_gen_var_1() -> Generator<(Float, Natural)> {
+pub fn float_rounding_mode_pair_gen_var_17_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_natural_pair_gen_var_1,
-        &random_float_natural_pair_gen_var_1,
-        &special_random_float_natural_pair_gen_var_1,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_17()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_17(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_17(config))
+        },
     )
 }
 
-// -- (Float, Natural, Natural) --
-
-pub fn float_natural_natural_triple_gen() -> Generator<(Float, Natural, Natural)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::log10_round`.
+pub fn float_rounding_mode_pair_gen_var_18() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_natural_natural_triple_gen,
-        &random_float_natural_natural_triple_gen,
-        &special_random_float_natural_natural_triple_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_18,
+        &random_float_rounding_mode_pair_gen_var_18,
+        &special_random_float_rounding_mode_pair_gen_var_18,
     )
 }
 
-// -- (Float, PrimitiveFloat) --
+pub fn float_rounding_mode_pair_gen_var_18_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
+    Generator::new(
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_18()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_18(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_18(config))
+        },
+    )
+}
 
-pub fn float_primitive_float_pair_gen<T: PrimitiveFloat>() -> Generator<(Float, T)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::sin_round`.
+pub fn float_rounding_mode_pair_gen_var_19() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_primitive_float_pair_gen,
-        &random_float_primitive_float_pair_gen,
-        &special_random_float_primitive_float_pair_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_19,
+        &random_float_rounding_mode_pair_gen_var_19,
+        &special_random_float_rounding_mode_pair_gen_var_19,
     )
 }
 
-pub fn float_primitive_float_pair_gen_rm<T: PrimitiveFloat>(
-) -> Generator<((rug::Float, T), (Float, T))> {
+pub fn float_rounding_mode_pair_gen_var_19_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &|| float_primitive_float_pair_rm(exhaustive_float_primitive_float_pair_gen()),
-        &|config| float_primitive_float_pair_rm(random_float_primitive_float_pair_gen(config)),
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_19()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_19(config)),
         &|config| {
-            float_primitive_float_pair_rm(special_random_float_primitive_float_pair_gen(config))
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_19(config))
         },
     )
 }
 
-// -- (Float, PrimitiveFloat, PrimitiveFloat) --
-
-pub fn float_primitive_float_primitive_float_triple_gen<T: PrimitiveFloat>(
-) -> Generator<(Float, T, T)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::cos_round`.
+pub fn float_rounding_mode_pair_gen_var_20() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_primitive_float_primitive_float_triple_gen,
-        &random_float_primitive_float_primitive_float_triple_gen,
-        &special_random_float_primitive_float_primitive_float_triple_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_20,
+        &random_float_rounding_mode_pair_gen_var_20,
+        &special_random_float_rounding_mode_pair_gen_var_20,
     )
 }
 
-// -- (Float, PrimitiveSigned) --
-
-pub fn float_signed_pair_gen<T: PrimitiveSigned>() -> Generator<(Float, T)> {
+pub fn float_rounding_mode_pair_gen_var_20_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_signed_pair_gen,
-        &random_float_signed_pair_gen,
-        &special_random_float_signed_pair_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_20()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_20(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_20(config))
+        },
     )
 }
 
-pub fn float_signed_pair_gen_rm<T: PrimitiveSigned>() -> Generator<((rug::Float, T), (Float, T))> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::tan_round`.
+pub fn float_rounding_mode_pair_gen_var_21() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &|| float_primitive_int_pair_rm(exhaustive_float_signed_pair_gen()),
-        &|config| float_primitive_int_pair_rm(random_float_signed_pair_gen(config)),
-        &|config| float_primitive_int_pair_rm(special_random_float_signed_pair_gen(config)),
+        &exhaustive_float_rounding_mode_pair_gen_var_21,
+        &random_float_rounding_mode_pair_gen_var_21,
+        &special_random_float_rounding_mode_pair_gen_var_21,
     )
 }
 
-// All `(Float, T)` where `T` is signed and small and the `Float` is in the range [1.0, 2.0).
-pub fn float_signed_pair_gen_var_1<T: PrimitiveSigned>() -> Generator<(Float, T)> {
+pub fn float_rounding_mode_pair_gen_var_21_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_signed_pair_gen_var_1,
-        &random_float_signed_pair_gen_var_1,
-        &special_random_float_signed_pair_gen_var_1,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_21()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_21(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_21(config))
+        },
     )
 }
 
-// -- (Float, PrimitiveSigned, PrimitiveSigned) --
-
-pub fn float_signed_signed_triple_gen<T: PrimitiveSigned>() -> Generator<(Float, T, T)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::asin_round`.
+pub fn float_rounding_mode_pair_gen_var_22() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_signed_signed_triple_gen,
-        &random_float_primitive_int_primitive_int_triple_gen,
-        &special_random_float_signed_signed_triple_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_22,
+        &random_float_rounding_mode_pair_gen_var_22,
+        &special_random_float_rounding_mode_pair_gen_var_22,
     )
 }
 
-// -- (Float, PrimitiveUnsigned) --
-
-pub fn float_unsigned_pair_gen<T: PrimitiveUnsigned>() -> Generator<(Float, T)> {
+pub fn float_rounding_mode_pair_gen_var_22_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_unsigned_pair_gen,
-        &random_float_unsigned_pair_gen,
-        &special_random_float_unsigned_pair_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_22()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_22(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_22(config))
+        },
     )
 }
 
-type GT<T> = Generator<((rug::Float, T), (Float, T))>;
-pub fn float_unsigned_pair_gen_rm<T: PrimitiveUnsigned>() -> GT<T> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::acos_round`.
+pub fn float_rounding_mode_pair_gen_var_23() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen()),
-        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen(config)),
-        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen(config)),
+        &exhaustive_float_rounding_mode_pair_gen_var_23,
+        &random_float_rounding_mode_pair_gen_var_23,
+        &special_random_float_rounding_mode_pair_gen_var_23,
     )
 }
 
-// All `(Float, T)` where `T` is unsigned, small, and positive.
-pub fn float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>() -> Generator<(Float, T)> {
+pub fn float_rounding_mode_pair_gen_var_23_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_unsigned_pair_gen_var_1,
-        &random_float_unsigned_pair_gen_var_1,
-        &special_random_float_unsigned_pair_gen_var_1,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_23()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_23(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_23(config))
+        },
     )
 }
 
-pub fn float_unsigned_pair_gen_var_1_rm<T: PrimitiveUnsigned>(
-) -> Generator<((rug::Float, T), (Float, T))> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::atan_round`.
+pub fn float_rounding_mode_pair_gen_var_24() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &|| float_primitive_int_pair_rm(exhaustive_float_unsigned_pair_gen_var_1()),
-        &|config| float_primitive_int_pair_rm(random_float_unsigned_pair_gen_var_1(config)),
-        &|config| float_primitive_int_pair_rm(special_random_float_unsigned_pair_gen_var_1(config)),
+        &exhaustive_float_rounding_mode_pair_gen_var_24,
+        &random_float_rounding_mode_pair_gen_var_24,
+        &special_random_float_rounding_mode_pair_gen_var_24,
     )
 }
 
-// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
-
-pub fn float_unsigned_unsigned_triple_gen<T: PrimitiveUnsigned>() -> Generator<(Float, T, T)> {
+pub fn float_rounding_mode_pair_gen_var_24_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_unsigned_unsigned_triple_gen,
-        &random_float_primitive_int_primitive_int_triple_gen,
-        &special_random_float_unsigned_unsigned_triple_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_24()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_24(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_24(config))
+        },
     )
 }
 
-// -- (Float, PrimitiveUnsigned, RoundingMode) --
-
-// All `(Float, u64, RoundingMode)` that are valid inputs to `Float.set_prec`.
-pub fn float_unsigned_rounding_mode_triple_gen_var_1() -> Generator<(Float, u64, RoundingMode)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::sinh_round`.
+pub fn float_rounding_mode_pair_gen_var_25() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_unsigned_rounding_mode_triple_gen_var_1,
-        &random_float_unsigned_rounding_mode_triple_gen_var_1,
-        &special_random_float_unsigned_rounding_mode_triple_gen_var_1,
+        &exhaustive_float_rounding_mode_pair_gen_var_25,
+        &random_float_rounding_mode_pair_gen_var_25,
+        &special_random_float_rounding_mode_pair_gen_var_25,
     )
 }
 
-pub fn float_unsigned_rounding_mode_triple_gen_var_1_rm() -> Generator<(
-    (rug::Float, u64, rug::float::Round),
-    (Float, u64, RoundingMode),
-)> {
+pub fn float_rounding_mode_pair_gen_var_25_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &|| {
-            float_unsigned_rounding_mode_triple_rm(
-                exhaustive_float_unsigned_rounding_mode_triple_gen_var_1(),
-            )
-        },
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_25()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_25(config)),
         &|config| {
-            float_unsigned_rounding_mode_triple_rm(
-                random_float_unsigned_rounding_mode_triple_gen_var_1(config),
-            )
-        },
-        &|config| {
-            float_unsigned_rounding_mode_triple_rm(
-                special_random_float_unsigned_rounding_mode_triple_gen_var_1(config),
-            )
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_25(config))
         },
     )
 }
 
-// -- (Float, Rational) --
-
-pub fn float_rational_pair_gen() -> Generator<(Float, Rational)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::cosh_round`.
+pub fn float_rounding_mode_pair_gen_var_26() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_rational_pair_gen,
-        &random_float_rational_pair_gen,
-        &special_random_float_rational_pair_gen,
+        &exhaustive_float_rounding_mode_pair_gen_var_26,
+        &random_float_rounding_mode_pair_gen_var_26,
+        &special_random_float_rounding_mode_pair_gen_var_26,
     )
 }
 
-pub fn float_rational_pair_gen_rm() -> Generator<((rug::Float, rug::Rational), (Float, Rational))> {
+pub fn float_rounding_mode_pair_gen_var_26_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &|| float_rational_pair_rm(exhaustive_float_rational_pair_gen()),
-        &|config| float_rational_pair_rm(random_float_rational_pair_gen(config)),
-        &|config| float_rational_pair_rm(special_random_float_rational_pair_gen(config)),
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_26()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_26(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_26(config))
+        },
     )
 }
 
-// All pairs of finite Floats and Rationals.
-pub fn float_rational_pair_gen_var_1() -> Generator<(Float, Rational)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::tanh_round`.
+pub fn float_rounding_mode_pair_gen_var_27() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_rational_pair_gen_var_1,
-        &random_float_rational_pair_gen_var_1,
-        &special_random_float_rational_pair_gen_var_1,
+        &exhaustive_float_rounding_mode_pair_gen_var_27,
+        &random_float_rounding_mode_pair_gen_var_27,
+        &special_random_float_rounding_mode_pair_gen_var_27,
     )
 }
 
-// -- (Float, Rational, Rational) --
-
-pub fn float_rational_rational_triple_gen() -> Generator<(Float, Rational, Rational)> {
+pub fn float_rounding_mode_pair_gen_var_27_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_rational_rational_triple_gen,
-        &random_float_rational_rational_triple_gen,
-        &special_random_float_rational_rational_triple_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_27()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_27(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_27(config))
+        },
     )
 }
 
-// -- (Float, RoundingMode) --
+// All `(Float, RoundingMode)` that are valid inputs to `Float::asinh_round`.
+pub fn float_rounding_mode_pair_gen_var_28() -> Generator<(Float, RoundingMode)> {
+    Generator::new(
+        &exhaustive_float_rounding_mode_pair_gen_var_28,
+        &random_float_rounding_mode_pair_gen_var_28,
+        &special_random_float_rounding_mode_pair_gen_var_28,
+    )
+}
 
-pub fn float_rounding_mode_pair_gen() -> Generator<(Float, RoundingMode)> {
+pub fn float_rounding_mode_pair_gen_var_28_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen,
-        &random_float_rounding_mode_pair_gen,
-        &special_random_float_rounding_mode_pair_gen,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_28()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_28(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_28(config))
+        },
     )
 }
 
-// All `(Float, RoundingMode)` that are valid inputs to `Natural::rounding_from`.
-pub fn float_rounding_mode_pair_gen_var_1() -> Generator<(Float, RoundingMode)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::acosh_round`.
+pub fn float_rounding_mode_pair_gen_var_29() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_1,
-        &random_float_rounding_mode_pair_gen_var_1,
-        &special_random_float_rounding_mode_pair_gen_var_1,
+        &exhaustive_float_rounding_mode_pair_gen_var_29,
+        &random_float_rounding_mode_pair_gen_var_29,
+        &special_random_float_rounding_mode_pair_gen_var_29,
     )
 }
 
-// All `(Float, RoundingMode)` that are valid inputs to `Integer::rounding_from`.
-pub fn float_rounding_mode_pair_gen_var_2() -> Generator<(Float, RoundingMode)> {
+pub fn float_rounding_mode_pair_gen_var_29_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_2,
-        &random_float_rounding_mode_pair_gen_var_2,
-        &special_random_float_rounding_mode_pair_gen_var_2,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_29()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_29(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_29(config))
+        },
     )
 }
 
-// All `(Float, RoundingMode)` where the float is finite and nonzero.
-pub fn float_rounding_mode_pair_gen_var_3() -> Generator<(Float, RoundingMode)> {
+// All `(Float, RoundingMode)` that are valid inputs to `Float::atanh_round`.
+pub fn float_rounding_mode_pair_gen_var_30() -> Generator<(Float, RoundingMode)> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_3,
-        &random_float_rounding_mode_pair_gen_var_3,
-        &special_random_float_rounding_mode_pair_gen_var_3,
+        &exhaustive_float_rounding_mode_pair_gen_var_30,
+        &random_float_rounding_mode_pair_gen_var_30,
+        &special_random_float_rounding_mode_pair_gen_var_30,
     )
 }
 
-// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is unsigned.
-type GT2 = Generator<(Float, RoundingMode)>;
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn float_rounding_mode_pair_gen_var_4<T: PrimitiveUnsigned>() -> GT2
-where
-    Float: PartialOrd<T>,
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+pub fn float_rounding_mode_pair_gen_var_30_rm(
+) -> Generator<((rug::Float, rug::float::Round), (Float, RoundingMode))> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_4::<T>,
-        &random_float_rounding_mode_pair_gen_var_4::<T>,
-        &special_random_float_rounding_mode_pair_gen_var_4::<T>,
+        &|| float_rounding_mode_pair_rm(exhaustive_float_rounding_mode_pair_gen_var_30()),
+        &|config| float_rounding_mode_pair_rm(random_float_rounding_mode_pair_gen_var_30(config)),
+        &|config| {
+            float_rounding_mode_pair_rm(special_random_float_rounding_mode_pair_gen_var_30(config))
+        },
     )
 }
 
-// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is signed.
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn float_rounding_mode_pair_gen_var_5<T: PrimitiveSigned>() -> Generator<(Float, RoundingMode)>
-where
-    Float: PartialOrd<T>,
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+// -- (Float, ToSciOptions) --
+
+pub fn float_to_sci_options_pair_gen() -> Generator<(Float, ToSciOptions)> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_5::<T>,
-        &random_float_rounding_mode_pair_gen_var_5::<T>,
-        &special_random_float_rounding_mode_pair_gen_var_5::<T>,
+        &exhaustive_float_to_sci_options_pair_gen,
+        &random_float_to_sci_options_pair_gen,
+        &special_random_float_to_sci_options_pair_gen,
     )
 }
 
-// All `(T, RoundingMode)` that are valid inputs to `T::rounding_from`, where `T` is a primitive
-// float.
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn float_rounding_mode_pair_gen_var_6<T: PrimitiveFloat>() -> Generator<(Float, RoundingMode)>
-where
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+pub fn float_to_sci_options_pair_gen_var_1() -> Generator<(Float, ToSciOptions)> {
     Generator::new(
-        &exhaustive_float_rounding_mode_pair_gen_var_6::<T>,
-        &random_float_rounding_mode_pair_gen_var_6::<T>,
-        &special_random_float_rounding_mode_pair_gen_var_6::<T>,
+        &exhaustive_float_to_sci_options_pair_gen_var_1,
+        &random_float_to_sci_options_pair_gen_var_1,
+        &special_random_float_to_sci_options_pair_gen_var_1,
     )
 }
 
@@ -656,6 +2685,19 @@ pub fn unsigned_unsigned_rounding_mode_triple_gen_var_6<T: PrimitiveUnsigned>(
     )
 }
 
+// -- (PrimitiveUnsigned, RoundingMode) --
+
+// vars 1 through 2 are in malachite-base.
+
+// All `(u64, RoundingMode)` that are valid inputs to functions like `Float::pi_prec_round`, which
+// compute irrational constants.
+pub fn unsigned_rounding_mode_pair_gen_var_3() -> Generator<(u64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_unsigned_rounding_mode_pair_gen_var_3,
+        &random_unsigned_rounding_mode_pair_gen_var_3,
+    )
+}
+
 // -- (Rational, PrimitiveUnsigned, RoundingMode) --
 
 // All `(Rational, u64, RoundingMode)` that are valid inputs to `Float::from_rational_prec_round`.
@@ -679,6 +2721,19 @@ pub fn rational_unsigned_rounding_mode_triple_gen_var_2() -> GT4 {
     )
 }
 
+// -- (String, PrimitiveUnsigned, RoundingMode) --
+
+// All `(String, u64, RoundingMode)` where the `String` only contains characters that occur in valid
+// decimal inputs to `Float::from_sci_string_prec_round` and does not end in an 'e' or 'E' followed
+// by an optional plus or minus sign and more than three digits, the `u64` is positive, and the
+// `RoundingMode` is not `Exact`.
+pub fn string_unsigned_rounding_mode_triple_gen_var_1() -> Generator<(String, u64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_string_unsigned_rounding_mode_triple_gen_var_1,
+        &random_string_unsigned_rounding_mode_triple_gen_var_1,
+    )
+}
+
 pub mod common;
 pub mod exhaustive;
 pub mod random;
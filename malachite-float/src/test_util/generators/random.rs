@@ -3,12 +3,24 @@ use crate::random::{
     random_nonzero_finite_floats, random_positive_finite_floats,
 };
 use crate::test_util::extra_variadic::{
-    random_triples, random_triples_from_single, random_triples_xxy, random_triples_xyy,
+    random_quadruples, random_quintuples, random_triples, random_triples_from_single,
+    random_triples_xxy, random_triples_xyy,
 };
 use crate::test_util::generators::{
-    from_primitive_float_prec_round_valid, integer_rounding_from_float_valid,
-    natural_rounding_from_float_valid, set_prec_round_valid, signed_rounding_from_float_valid,
-    unsigned_rounding_from_float_valid,
+    acos_round_valid, acosh_round_valid, add_mul_prec_round_valid, add_mul_round_valid,
+    add_prec_round_valid, add_round_valid, asin_round_valid, asinh_round_valid, atan2_round_valid,
+    atan_round_valid, atanh_round_valid, cos_round_valid, cosh_round_valid, div_prec_round_valid,
+    div_round_valid, exp10_prec_round_valid, exp10_round_valid, exp2_round_valid, exp_input_valid,
+    exp_round_valid, expm1_round_valid, fmod_prec_round_valid,
+    from_primitive_float_prec_round_valid, integer_rounding_from_float_valid, ln_1p_round_valid,
+    ln_round_valid, log10_prec_round_valid, log10_round_valid, log2_prec_round_valid,
+    log2_round_valid, mul_prec_round_valid, mul_round_valid, natural_rounding_from_float_valid,
+    reciprocal_prec_round_valid, reciprocal_round_valid, reciprocal_sqrt_prec_round_valid,
+    reciprocal_sqrt_round_valid, remainder_prec_round_valid, root_prec_round_valid,
+    root_round_valid, set_prec_round_valid, signed_rounding_from_float_valid, sin_round_valid,
+    sinh_round_valid, sqrt_prec_round_valid, sqrt_round_valid, square_prec_round_valid,
+    square_round_valid, sub_mul_prec_round_valid, sub_mul_round_valid, sub_prec_round_valid,
+    sub_round_valid, tan_round_valid, tanh_round_valid, unsigned_rounding_from_float_valid,
 };
 use crate::Float;
 use crate::InnerFloat::Finite;
@@ -17,7 +29,9 @@ use malachite_base::num::basic::floats::PrimitiveFloat;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::signeds::PrimitiveSigned;
 use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
-use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::num::conversion::string::options::random::random_to_sci_options;
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ToSci};
 use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::num::random::geometric::{
     geometric_random_positive_unsigneds, geometric_random_signeds,
@@ -27,6 +41,7 @@ use malachite_base::random::EXAMPLE_SEED;
 use malachite_base::rounding_modes::random::random_rounding_modes;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::test_util::generators::random::random_string_gen_var_15;
 use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
 use malachite_nz::integer::random::random_integers;
 use malachite_nz::integer::Integer;
@@ -108,6 +123,21 @@ pub fn random_float_gen_var_5(config: &GenConfig) -> It<Float> {
     ))
 }
 
+pub fn random_float_gen_var_6(config: &GenConfig) -> It<Float> {
+    Box::new(
+        random_floats(
+            EXAMPLE_SEED,
+            config.get_or("mean_exponent_n", 64),
+            config.get_or("mean_exponent_d", 1),
+            config.get_or("mean_precision_n", 64),
+            config.get_or("mean_precision_d", 1),
+            config.get_or("mean_zero_p_n", 1),
+            config.get_or("mean_zero_p_d", 64),
+        )
+        .filter(exp_input_valid),
+    )
+}
+
 // -- (Float, Float) --
 
 pub fn random_float_pair_gen(config: &GenConfig) -> It<(Float, Float)> {
@@ -148,6 +178,254 @@ pub fn random_float_triple_gen(config: &GenConfig) -> It<(Float, Float, Float)>
     )))
 }
 
+// -- (Float, Float, Float, PrimitiveUnsigned) --
+
+pub fn random_float_float_float_unsigned_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, Float, u64)> {
+    Box::new(random_quadruples(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, Float, Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quintuples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, ref z, p, rm)| add_mul_prec_round_valid(x, y, z, p, rm)),
+    )
+}
+
+pub fn random_float_float_float_unsigned_rounding_mode_quintuple_gen_var_2(
+    config: &GenConfig,
+) -> It<(Float, Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quintuples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, ref z, p, rm)| sub_mul_prec_round_valid(x, y, z, p, rm)),
+    )
+}
+
+// -- (Float, Float, Float, RoundingMode) --
+
+pub fn random_float_float_float_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, Float, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, ref z, rm)| add_mul_round_valid(x, y, z, rm)),
+    )
+}
+
+pub fn random_float_float_float_rounding_mode_quadruple_gen_var_2(
+    config: &GenConfig,
+) -> It<(Float, Float, Float, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, ref z, rm)| sub_mul_round_valid(x, y, z, rm)),
+    )
+}
+
 // -- (Float, Float, Integer) --
 
 pub fn random_float_float_integer_triple_gen(config: &GenConfig) -> It<(Float, Float, Integer)> {
@@ -254,9 +532,7 @@ pub fn random_float_float_primitive_int_triple_gen<T: PrimitiveInt>(
     ))
 }
 
-// -- (Float, Float, Rational) --
-
-pub fn random_float_float_rational_triple_gen(config: &GenConfig) -> It<(Float, Float, Rational)> {
+pub fn random_float_float_unsigned_triple_gen_var_1(config: &GenConfig) -> It<(Float, Float, u64)> {
     Box::new(random_triples_xxy(
         EXAMPLE_SEED,
         &|seed| {
@@ -271,24 +547,764 @@ pub fn random_float_float_rational_triple_gen(config: &GenConfig) -> It<(Float,
             )
         },
         &|seed| {
-            random_rationals(
+            geometric_random_positive_unsigneds(
                 seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
             )
         },
     ))
 }
 
-// -- (Float, Integer) --
+// -- (Float, Float, PrimitiveUnsigned, RoundingMode) --
 
-pub fn random_float_integer_pair_gen(config: &GenConfig) -> It<(Float, Integer)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| add_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_2(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| sub_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| mul_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| div_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| fmod_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_6(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, p, rm)| remainder_prec_round_valid(x, y, p, rm)),
+    )
+}
+
+pub fn random_float_float_unsigned_rounding_mode_quadruple_gen_var_7(
+    config: &GenConfig,
+) -> It<(Float, Float, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref y, ref x, _, rm)| atan2_round_valid(y, x, rm)),
+    )
+}
+
+// -- (Float, Float, Rational) --
+
+pub fn random_float_float_rational_triple_gen(config: &GenConfig) -> It<(Float, Float, Rational)> {
+    Box::new(random_triples_xxy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, Float, RoundingMode) --
+
+pub fn random_float_float_rounding_mode_triple_gen(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &random_rounding_modes,
+    ))
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, rm)| add_round_valid(x, y, rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_2(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, rm)| sub_round_valid(x, y, rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, rm)| mul_round_valid(x, y, rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, ref y, rm)| div_round_valid(x, y, rm)),
+    )
+}
+
+pub fn random_float_float_rounding_mode_triple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Float, Float, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref y, ref x, rm)| atan2_round_valid(y, x, rm)),
+    )
+}
+
+// -- (Float, Integer) --
+
+pub fn random_float_integer_pair_gen(config: &GenConfig) -> It<(Float, Integer)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_float_integer_pair_gen_var_1(config: &GenConfig) -> It<(Float, Integer)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_finite_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, Integer, Integer) --
+
+pub fn random_float_integer_integer_triple_gen(
+    config: &GenConfig,
+) -> It<(Float, Integer, Integer)> {
+    Box::new(random_triples_xyy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, Natural) --
+
+pub fn random_float_natural_pair_gen(config: &GenConfig) -> It<(Float, Natural)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_float_natural_pair_gen_var_1(config: &GenConfig) -> It<(Float, Natural)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_finite_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, Natural, Natural) --
+
+pub fn random_float_natural_natural_triple_gen(
+    config: &GenConfig,
+) -> It<(Float, Natural, Natural)> {
+    Box::new(random_triples_xyy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, PrimitiveFloat) --
+
+pub fn random_float_primitive_float_pair_gen<T: PrimitiveFloat>(
+    config: &GenConfig,
+) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            special_random_primitive_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+    ))
+}
+
+// -- (Float, PrimitiveFloat, PrimitiveFloat) --
+
+pub fn random_float_primitive_float_primitive_float_triple_gen<T: PrimitiveFloat>(
+    config: &GenConfig,
+) -> It<(Float, T, T)> {
+    Box::new(random_triples_xyy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            special_random_primitive_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+    ))
+}
+
+// -- (Float, PrimitiveInt, PrimitiveInt) --
+
+pub fn random_float_primitive_int_primitive_int_triple_gen<T: PrimitiveInt>(
+    config: &GenConfig,
+) -> It<(Float, T, T)> {
+    Box::new(random_triples_xyy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
                 config.get_or("mean_exponent_d", 1),
                 config.get_or("mean_precision_n", 64),
                 config.get_or("mean_precision_d", 1),
@@ -296,71 +1312,986 @@ pub fn random_float_integer_pair_gen(config: &GenConfig) -> It<(Float, Integer)>
                 config.get_or("mean_zero_p_d", 64),
             )
         },
+        &|seed| random_primitive_ints(seed),
+    ))
+}
+
+// -- (Float, PrimitiveSigned) --
+
+pub fn random_float_signed_pair_gen<T: PrimitiveSigned>(config: &GenConfig) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
         &|seed| {
-            random_integers(
+            random_floats(
                 seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &random_primitive_ints,
+    ))
+}
+
+// TODO use ranges
+pub fn random_float_signed_pair_gen_var_1<T: PrimitiveSigned>(
+    config: &GenConfig,
+) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_positive_finite_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+            )
+            .map(|mut x| {
+                if let Float(Finite { exponent, .. }) = &mut x {
+                    *exponent = 1;
+                }
+                x
+            })
+        },
+        &|seed| {
+            geometric_random_signeds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, PrimitiveUnsigned) --
+
+pub fn random_float_unsigned_pair_gen<T: PrimitiveUnsigned>(config: &GenConfig) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &random_primitive_ints,
+    ))
+}
+
+pub fn random_float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_float_unsigned_pair_gen_var_2<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Float, T)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+            .filter(exp_input_valid)
+        },
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned) --
+
+pub fn random_float_unsigned_unsigned_triple_gen_var_1<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Float, T, T)> {
+    Box::new(random_triples_xyy(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
             )
         },
     ))
 }
 
-pub fn random_float_integer_pair_gen_var_1(config: &GenConfig) -> It<(Float, Integer)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_finite_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            random_integers(
-                seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
-            )
-        },
-    ))
+// -- (Float, PrimitiveUnsigned, PrimitiveUnsigned, RoundingMode) --
+
+pub fn random_float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, u64, u64, RoundingMode)> {
+    Box::new(
+        random_quadruples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, exp, p, rm)| root_prec_round_valid(x, exp, p, rm)),
+    )
+}
+
+// -- (Float, PrimitiveUnsigned, RoundingMode) --
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| set_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_2(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| square_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_3(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| reciprocal_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_4(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| sqrt_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_5(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| reciprocal_sqrt_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_6(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, exp, rm)| root_round_valid(x, exp, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_7(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| exp_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_8(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| exp2_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_9(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| exp10_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_10(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| expm1_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_11(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| ln_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_12(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| ln_1p_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_13(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| log2_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_14(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, p, rm)| log10_prec_round_valid(x, p, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_15(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| sin_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_16(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| cos_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_17(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| tan_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_18(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| asin_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_19(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| acos_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_20(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| atan_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_21(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| sinh_round_valid(x, rm)),
+    )
+}
+
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_22(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| cosh_round_valid(x, rm)),
+    )
 }
 
-// -- (Float, Integer, Integer) --
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_23(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| tanh_round_valid(x, rm)),
+    )
+}
 
-pub fn random_float_integer_integer_triple_gen(
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_24(
     config: &GenConfig,
-) -> It<(Float, Integer, Integer)> {
-    Box::new(random_triples_xyy(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            random_integers(
-                seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
-            )
-        },
-    ))
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| asinh_round_valid(x, rm)),
+    )
 }
 
-// -- (Float, Natural) --
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_25(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| acosh_round_valid(x, rm)),
+    )
+}
 
-pub fn random_float_natural_pair_gen(config: &GenConfig) -> It<(Float, Natural)> {
+pub fn random_float_unsigned_rounding_mode_triple_gen_var_26(
+    config: &GenConfig,
+) -> It<(Float, u64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &|seed| {
+                geometric_random_positive_unsigneds(
+                    seed,
+                    config.get_or("mean_small_n", 64),
+                    config.get_or("mean_small_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|&(ref x, _, rm)| atanh_round_valid(x, rm)),
+    )
+}
+
+// -- (Float, Rational) --
+
+pub fn random_float_rational_pair_gen(config: &GenConfig) -> It<(Float, Rational)> {
     Box::new(random_pairs(
         EXAMPLE_SEED,
         &|seed| {
@@ -375,7 +2306,7 @@ pub fn random_float_natural_pair_gen(config: &GenConfig) -> It<(Float, Natural)>
             )
         },
         &|seed| {
-            random_naturals(
+            random_rationals(
                 seed,
                 config.get_or("mean_bits_n", 64),
                 config.get_or("mean_bits_d", 1),
@@ -384,7 +2315,7 @@ pub fn random_float_natural_pair_gen(config: &GenConfig) -> It<(Float, Natural)>
     ))
 }
 
-pub fn random_float_natural_pair_gen_var_1(config: &GenConfig) -> It<(Float, Natural)> {
+pub fn random_float_rational_pair_gen_var_1(config: &GenConfig) -> It<(Float, Rational)> {
     Box::new(random_pairs(
         EXAMPLE_SEED,
         &|seed| {
@@ -399,7 +2330,7 @@ pub fn random_float_natural_pair_gen_var_1(config: &GenConfig) -> It<(Float, Nat
             )
         },
         &|seed| {
-            random_naturals(
+            random_rationals(
                 seed,
                 config.get_or("mean_bits_n", 64),
                 config.get_or("mean_bits_d", 1),
@@ -408,11 +2339,11 @@ pub fn random_float_natural_pair_gen_var_1(config: &GenConfig) -> It<(Float, Nat
     ))
 }
 
-// -- (Float, Natural, Natural) --
+// -- (Float, Rational, Rational) --
 
-pub fn random_float_natural_natural_triple_gen(
+pub fn random_float_rational_rational_triple_gen(
     config: &GenConfig,
-) -> It<(Float, Natural, Natural)> {
+) -> It<(Float, Rational, Rational)> {
     Box::new(random_triples_xyy(
         EXAMPLE_SEED,
         &|seed| {
@@ -427,7 +2358,7 @@ pub fn random_float_natural_natural_triple_gen(
             )
         },
         &|seed| {
-            random_naturals(
+            random_rationals(
                 seed,
                 config.get_or("mean_bits_n", 64),
                 config.get_or("mean_bits_d", 1),
@@ -436,11 +2367,9 @@ pub fn random_float_natural_natural_triple_gen(
     ))
 }
 
-// -- (Float, PrimitiveFloat) --
+// -- (Float, RoundingMode) --
 
-pub fn random_float_primitive_float_pair_gen<T: PrimitiveFloat>(
-    config: &GenConfig,
-) -> It<(Float, T)> {
+pub fn random_float_rounding_mode_pair_gen(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(random_pairs(
         EXAMPLE_SEED,
         &|seed| {
@@ -454,178 +2383,490 @@ pub fn random_float_primitive_float_pair_gen<T: PrimitiveFloat>(
                 config.get_or("mean_zero_p_d", 64),
             )
         },
+        &random_rounding_modes,
+    ))
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_1(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| natural_rounding_from_float_valid(f, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_2(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| integer_rounding_from_float_valid(f, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_3(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
         &|seed| {
-            special_random_primitive_floats(
+            random_nonzero_finite_floats(
                 seed,
                 config.get_or("mean_exponent_n", 64),
                 config.get_or("mean_exponent_d", 1),
                 config.get_or("mean_precision_n", 64),
                 config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
             )
         },
+        &random_rounding_modes,
     ))
 }
 
-// -- (Float, PrimitiveFloat, PrimitiveFloat) --
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn random_float_rounding_mode_pair_gen_var_4<T: PrimitiveUnsigned>(
+    config: &GenConfig,
+) -> It<(Float, RoundingMode)>
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| unsigned_rounding_from_float_valid::<T>(f, *rm)),
+    )
+}
 
-pub fn random_float_primitive_float_primitive_float_triple_gen<T: PrimitiveFloat>(
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn random_float_rounding_mode_pair_gen_var_5<T: PrimitiveSigned>(
     config: &GenConfig,
-) -> It<(Float, T, T)> {
-    Box::new(random_triples_xyy(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            special_random_primitive_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-    ))
+) -> It<(Float, RoundingMode)>
+where
+    Float: PartialOrd<T>,
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| signed_rounding_from_float_valid::<T>(f, *rm)),
+    )
+}
+
+#[allow(clippy::type_repetition_in_bounds)]
+pub fn random_float_rounding_mode_pair_gen_var_6<T: PrimitiveFloat>(
+    config: &GenConfig,
+) -> It<(Float, RoundingMode)>
+where
+    for<'a> T: ConvertibleFrom<&'a Float>,
+{
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(f, rm)| *rm != RoundingMode::Exact || T::convertible_from(f)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_7(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| square_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_8(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| reciprocal_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_9(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| sqrt_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_10(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| reciprocal_sqrt_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_11(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| exp_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_12(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| exp2_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_13(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| exp10_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_14(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| expm1_round_valid(x, *rm)),
+    )
 }
 
-// -- (Float, PrimitiveInt, PrimitiveInt) --
+pub fn random_float_rounding_mode_pair_gen_var_15(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| ln_round_valid(x, *rm)),
+    )
+}
 
-pub fn random_float_primitive_int_primitive_int_triple_gen<T: PrimitiveInt>(
-    config: &GenConfig,
-) -> It<(Float, T, T)> {
-    Box::new(random_triples_xyy(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| random_primitive_ints(seed),
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_16(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| ln_1p_round_valid(x, *rm)),
+    )
 }
 
-// -- (Float, PrimitiveSigned) --
+pub fn random_float_rounding_mode_pair_gen_var_17(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| log2_round_valid(x, *rm)),
+    )
+}
 
-pub fn random_float_signed_pair_gen<T: PrimitiveSigned>(config: &GenConfig) -> It<(Float, T)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &random_primitive_ints,
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_18(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| log10_round_valid(x, *rm)),
+    )
 }
 
-// TODO use ranges
-pub fn random_float_signed_pair_gen_var_1<T: PrimitiveSigned>(
-    config: &GenConfig,
-) -> It<(Float, T)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_positive_finite_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-            )
-            .map(|mut x| {
-                if let Float(Finite { exponent, .. }) = &mut x {
-                    *exponent = 1;
-                }
-                x
-            })
-        },
-        &|seed| {
-            geometric_random_signeds(
-                seed,
-                config.get_or("mean_small_n", 64),
-                config.get_or("mean_small_d", 1),
-            )
-        },
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_19(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| sin_round_valid(x, *rm)),
+    )
 }
 
-// -- (Float, PrimitiveUnsigned) --
+pub fn random_float_rounding_mode_pair_gen_var_20(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| cos_round_valid(x, *rm)),
+    )
+}
 
-pub fn random_float_unsigned_pair_gen<T: PrimitiveUnsigned>(config: &GenConfig) -> It<(Float, T)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &random_primitive_ints,
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_21(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| tan_round_valid(x, *rm)),
+    )
 }
 
-pub fn random_float_unsigned_pair_gen_var_1<T: PrimitiveUnsigned>(
-    config: &GenConfig,
-) -> It<(Float, T)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            geometric_random_positive_unsigneds(
-                seed,
-                config.get_or("mean_small_n", 64),
-                config.get_or("mean_small_d", 1),
-            )
-        },
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_22(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| asin_round_valid(x, *rm)),
+    )
 }
 
-// -- (Float, PrimitiveUnsigned, RoundingMode) --
-
-pub fn random_float_unsigned_rounding_mode_triple_gen_var_1(
-    config: &GenConfig,
-) -> It<(Float, u64, RoundingMode)> {
+pub fn random_float_rounding_mode_pair_gen_var_23(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(
-        random_triples(
+        random_pairs(
             EXAMPLE_SEED,
             &|seed| {
                 random_floats(
@@ -638,118 +2879,55 @@ pub fn random_float_unsigned_rounding_mode_triple_gen_var_1(
                     config.get_or("mean_zero_p_d", 64),
                 )
             },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| acos_round_valid(x, *rm)),
+    )
+}
+
+pub fn random_float_rounding_mode_pair_gen_var_24(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
             &|seed| {
-                geometric_random_positive_unsigneds(
+                random_floats(
                     seed,
-                    config.get_or("mean_small_n", 64),
-                    config.get_or("mean_small_d", 1),
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
                 )
             },
             &random_rounding_modes,
         )
-        .filter(|&(ref x, p, rm)| set_prec_round_valid(x, p, rm)),
+        .filter(|(x, rm)| atan_round_valid(x, *rm)),
     )
 }
 
-// -- (Float, Rational) --
-
-pub fn random_float_rational_pair_gen(config: &GenConfig) -> It<(Float, Rational)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            random_rationals(
-                seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
-            )
-        },
-    ))
-}
-
-pub fn random_float_rational_pair_gen_var_1(config: &GenConfig) -> It<(Float, Rational)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_finite_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            random_rationals(
-                seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
-            )
-        },
-    ))
-}
-
-// -- (Float, Rational, Rational) --
-
-pub fn random_float_rational_rational_triple_gen(
-    config: &GenConfig,
-) -> It<(Float, Rational, Rational)> {
-    Box::new(random_triples_xyy(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &|seed| {
-            random_rationals(
-                seed,
-                config.get_or("mean_bits_n", 64),
-                config.get_or("mean_bits_d", 1),
-            )
-        },
-    ))
-}
-
-// -- (Float, RoundingMode) --
-
-pub fn random_float_rounding_mode_pair_gen(config: &GenConfig) -> It<(Float, RoundingMode)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-                config.get_or("mean_zero_p_n", 1),
-                config.get_or("mean_zero_p_d", 64),
-            )
-        },
-        &random_rounding_modes,
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_25(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| sinh_round_valid(x, *rm)),
+    )
 }
 
-pub fn random_float_rounding_mode_pair_gen_var_1(config: &GenConfig) -> It<(Float, RoundingMode)> {
+pub fn random_float_rounding_mode_pair_gen_var_26(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(
         random_pairs(
             EXAMPLE_SEED,
@@ -766,11 +2944,11 @@ pub fn random_float_rounding_mode_pair_gen_var_1(config: &GenConfig) -> It<(Floa
             },
             &random_rounding_modes,
         )
-        .filter(|(f, rm)| natural_rounding_from_float_valid(f, *rm)),
+        .filter(|(x, rm)| cosh_round_valid(x, *rm)),
     )
 }
 
-pub fn random_float_rounding_mode_pair_gen_var_2(config: &GenConfig) -> It<(Float, RoundingMode)> {
+pub fn random_float_rounding_mode_pair_gen_var_27(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(
         random_pairs(
             EXAMPLE_SEED,
@@ -787,34 +2965,32 @@ pub fn random_float_rounding_mode_pair_gen_var_2(config: &GenConfig) -> It<(Floa
             },
             &random_rounding_modes,
         )
-        .filter(|(f, rm)| integer_rounding_from_float_valid(f, *rm)),
+        .filter(|(x, rm)| tanh_round_valid(x, *rm)),
     )
 }
 
-pub fn random_float_rounding_mode_pair_gen_var_3(config: &GenConfig) -> It<(Float, RoundingMode)> {
-    Box::new(random_pairs(
-        EXAMPLE_SEED,
-        &|seed| {
-            random_nonzero_finite_floats(
-                seed,
-                config.get_or("mean_exponent_n", 64),
-                config.get_or("mean_exponent_d", 1),
-                config.get_or("mean_precision_n", 64),
-                config.get_or("mean_precision_d", 1),
-            )
-        },
-        &random_rounding_modes,
-    ))
+pub fn random_float_rounding_mode_pair_gen_var_28(config: &GenConfig) -> It<(Float, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_floats(
+                    seed,
+                    config.get_or("mean_exponent_n", 64),
+                    config.get_or("mean_exponent_d", 1),
+                    config.get_or("mean_precision_n", 64),
+                    config.get_or("mean_precision_d", 1),
+                    config.get_or("mean_zero_p_n", 1),
+                    config.get_or("mean_zero_p_d", 64),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| asinh_round_valid(x, *rm)),
+    )
 }
 
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn random_float_rounding_mode_pair_gen_var_4<T: PrimitiveUnsigned>(
-    config: &GenConfig,
-) -> It<(Float, RoundingMode)>
-where
-    Float: PartialOrd<T>,
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+pub fn random_float_rounding_mode_pair_gen_var_29(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(
         random_pairs(
             EXAMPLE_SEED,
@@ -831,18 +3007,11 @@ where
             },
             &random_rounding_modes,
         )
-        .filter(|(f, rm)| unsigned_rounding_from_float_valid::<T>(f, *rm)),
+        .filter(|(x, rm)| acosh_round_valid(x, *rm)),
     )
 }
 
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn random_float_rounding_mode_pair_gen_var_5<T: PrimitiveSigned>(
-    config: &GenConfig,
-) -> It<(Float, RoundingMode)>
-where
-    Float: PartialOrd<T>,
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+pub fn random_float_rounding_mode_pair_gen_var_30(config: &GenConfig) -> It<(Float, RoundingMode)> {
     Box::new(
         random_pairs(
             EXAMPLE_SEED,
@@ -859,17 +3028,37 @@ where
             },
             &random_rounding_modes,
         )
-        .filter(|(f, rm)| signed_rounding_from_float_valid::<T>(f, *rm)),
+        .filter(|(x, rm)| atanh_round_valid(x, *rm)),
     )
 }
 
-#[allow(clippy::type_repetition_in_bounds)]
-pub fn random_float_rounding_mode_pair_gen_var_6<T: PrimitiveFloat>(
-    config: &GenConfig,
-) -> It<(Float, RoundingMode)>
-where
-    for<'a> T: ConvertibleFrom<&'a Float>,
-{
+// -- (Float, ToSciOptions) --
+
+pub fn random_float_to_sci_options_pair_gen(config: &GenConfig) -> It<(Float, ToSciOptions)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_floats(
+                seed,
+                config.get_or("mean_exponent_n", 64),
+                config.get_or("mean_exponent_d", 1),
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+                config.get_or("mean_zero_p_n", 1),
+                config.get_or("mean_zero_p_d", 64),
+            )
+        },
+        &|seed| {
+            random_to_sci_options(
+                seed,
+                config.get_or("small_mean_n", 4),
+                config.get_or("small_mean_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_float_to_sci_options_pair_gen_var_1(config: &GenConfig) -> It<(Float, ToSciOptions)> {
     Box::new(
         random_pairs(
             EXAMPLE_SEED,
@@ -884,9 +3073,15 @@ where
                     config.get_or("mean_zero_p_d", 64),
                 )
             },
-            &random_rounding_modes,
+            &|seed| {
+                random_to_sci_options(
+                    seed,
+                    config.get_or("small_mean_n", 4),
+                    config.get_or("small_mean_d", 1),
+                )
+            },
         )
-        .filter(|(f, rm)| *rm != RoundingMode::Exact || T::convertible_from(f)),
+        .filter(|(x, options)| x.fmt_sci_valid(*options)),
     )
 }
 
@@ -1159,6 +3354,24 @@ pub fn random_unsigned_unsigned_rounding_mode_triple_gen_var_4<T: PrimitiveUnsig
     ))
 }
 
+// -- (PrimitiveUnsigned, RoundingMode) --
+
+// vars 1 through 2 are in malachite-base.
+
+pub fn random_unsigned_rounding_mode_pair_gen_var_3(config: &GenConfig) -> It<(u64, RoundingMode)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            )
+        },
+        &|seed| random_rounding_modes(seed).filter(|rm| *rm != RoundingMode::Exact),
+    ))
+}
+
 // -- (Rational, PrimitiveUnsigned, RoundingMode) --
 
 // var 1 is in malachite-nz
@@ -1215,3 +3428,23 @@ pub fn random_rational_unsigned_rounding_mode_triple_gen_var_2(
         &|seed| random_rounding_modes(seed).filter(|rm| *rm != RoundingMode::Exact),
     ))
 }
+
+// -- (String, PrimitiveUnsigned, RoundingMode) --
+
+pub fn random_string_unsigned_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(String, u64, RoundingMode)> {
+    Box::new(
+        random_string_gen_var_15(config)
+            .zip(geometric_random_positive_unsigneds(
+                EXAMPLE_SEED.fork("precisions"),
+                config.get_or("mean_small_n", 64),
+                config.get_or("mean_small_d", 1),
+            ))
+            .zip(
+                random_rounding_modes(EXAMPLE_SEED.fork("rounding_modes"))
+                    .filter(|rm| *rm != RoundingMode::Exact),
+            )
+            .map(|((s, prec), rm)| (s, prec, rm)),
+    )
+}
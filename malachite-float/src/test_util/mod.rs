@@ -1,5 +1,7 @@
+pub mod arithmetic;
 pub mod bench;
 pub mod common;
+pub mod constants;
 pub mod exhaustive;
 pub mod extra_variadic;
 pub mod generators;
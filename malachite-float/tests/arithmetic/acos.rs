@@ -0,0 +1,2219 @@
+use malachite_base::num::arithmetic::traits::{Acos, AcosAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, OneHalf, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::acos::{
+    rug_acos, rug_acos_prec, rug_acos_prec_round, rug_acos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_23, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_19,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_acos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let acos = x.clone().acos();
+        assert!(acos.is_valid());
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let acos_alt = (&x).acos();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        let mut acos_alt = x.clone();
+        acos_alt.acos_assign();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acos)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("-0.0", "-0x0.0", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "4.0", "0x4.0#1");
+    test("1.0", "0x1.000#10", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "NaN", "NaN");
+    test("3.1415926535897931", "0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("2.7182818284590451", "0x2.b7e151628aed2#53", "NaN", "NaN");
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "2.0", "0x2.0#1");
+    test("1.0e30", "0x1.0E+25#1", "NaN", "NaN");
+    test("15.999999999999998", "0xf.ffffffffffff8#53", "NaN", "NaN");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+    test("-0.5", "-0x0.8#1", "2.0", "0x2.0#1");
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+    );
+}
+
+#[test]
+fn test_acos_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_assign(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("-0.0", "-0x0.0", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 1, "4.0", "0x4.0#1", Ordering::Greater);
+    test("-1.0", "-0x1.0#1", 10, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.000#10", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.000#10", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.000#10", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("3.0", "0x3.0#2", 1, "NaN", "NaN", Ordering::Equal);
+    test("3.0", "0x3.0#2", 10, "NaN", "NaN", Ordering::Equal);
+    test("3.0", "0x3.0#2", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "1.230959417340774642882617711153",
+        "0x1.3b2028082e8d38dca0d0bae78#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.047", "0x1.0c0#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.047197551196597746154214461094",
+        "0x1.0c152382d73658465bb32e0f6#100",
+        Ordering::Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test("-0.5", "-0x0.8#1", 10, "2.094", "0x2.18#10", Ordering::Less);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "2.094395102393195492308428922187",
+        "0x2.182a4705ae6cb08cb7665c1ec#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        100,
+        "0.7853981633974482412575742692003",
+        "0x0.c90fdaa22168bd47c8d68b02f#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_acos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_round_assign(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.2309594173407745",
+        "0x1.3b2028082e8d3#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.2309594173407745",
+        "0x1.3b2028082e8d3#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.2309594173407747",
+        "0x1.3b2028082e8d4#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Floor,
+        "0.7853981633974482",
+        "0x0.c90fdaa22168b8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Ceiling,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Down,
+        "0.7853981633974482",
+        "0x0.c90fdaa22168b8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Up,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Nearest,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_acos_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acos.to_string(), out);
+        assert_eq!(to_hex_string(&acos), out_hex);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acos_alt = x.clone();
+        let o_alt = acos_alt.acos_prec_round_assign(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos), ComparableFloatRef(&acos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.232",
+        "0x1.3b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.232",
+        "0x1.3b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.23",
+        "0x1.3b0#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.570796326794896619231321691638",
+        "0x1.921fb54442d18469898cc516e#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.570796326794896619231321691638",
+        "0x1.921fb54442d18469898cc516e#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.049",
+        "0x1.0c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "1.049",
+        "0x1.0c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "1.047",
+        "0x1.0c0#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "2.094",
+        "0x2.18#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.098",
+        "0x2.19#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "2.094",
+        "0x2.18#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "2.098",
+        "0x2.19#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "2.094",
+        "0x2.18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn acos_prec_fail() {
+    assert_panic!(Float::NAN.acos_prec(0));
+    assert_panic!(Float::ONE.acos_prec(0));
+}
+
+#[test]
+fn acos_prec_ref_fail() {
+    assert_panic!(Float::NAN.acos_prec_ref(0));
+    assert_panic!(Float::ONE.acos_prec_ref(0));
+}
+
+#[test]
+fn acos_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.acos_prec_assign(0)
+    });
+}
+
+#[test]
+fn acos_round_fail() {
+    assert_panic!(Float::ONE_HALF.acos_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.acos_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).acos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE_HALF.acos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.acos_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.acos_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_19().test_properties(|(x, prec, rm)| {
+        let (acos, o) = x.clone().acos_prec_round(prec, rm);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x < 1u32 && x >= -1i32 {
+            assert!(acos.is_normal());
+            assert_eq!(acos.get_prec(), Some(prec));
+            assert!(acos > 0u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.acos_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(acos.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.acos_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn acos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (acos, o) = x.clone().acos_prec(prec);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_prec_ref(prec);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let (rug_acos, rug_o) = rug_acos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos)),
+            ComparableFloatRef(&acos)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn acos_round_properties() {
+    float_rounding_mode_pair_gen_var_23().test_properties(|(x, rm)| {
+        let (acos, o) = x.clone().acos_round(rm);
+        assert!(acos.is_valid());
+
+        let (acos_alt, o_alt) = x.acos_round_ref(rm);
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acos_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        let (acos_alt, o_alt) = x.acos_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acos, rug_o) = rug_acos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acos)),
+                ComparableFloatRef(&acos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn acos_properties() {
+    float_gen().test_properties(|x| {
+        let acos = x.clone().acos();
+        assert!(acos.is_valid());
+
+        let acos_alt = (&x).acos();
+        assert!(acos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+
+        let mut x_alt = x.clone();
+        x_alt.acos_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acos));
+
+        let acos_alt = x
+            .acos_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&acos_alt), ComparableFloatRef(&acos));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acos)
+        );
+    });
+
+    assert!(Float::NAN.acos().is_nan());
+    assert!(Float::INFINITY.acos().is_nan());
+    assert!(Float::NEGATIVE_INFINITY.acos().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ONE.acos()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert!(Float::from(2u32).acos().is_nan());
+}
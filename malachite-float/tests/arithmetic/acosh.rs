@@ -0,0 +1,2138 @@
+use malachite_base::num::arithmetic::traits::{Acosh, AcoshAssign};
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::acosh::{
+    rug_acosh, rug_acosh_prec, rug_acosh_prec_round, rug_acosh_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_29, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_25,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_acosh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let acosh = x.clone().acosh();
+        assert!(acosh.is_valid());
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let acosh_alt = (&x).acosh();
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+
+        let mut acosh_alt = x.clone();
+        acosh_alt.acosh_assign();
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acosh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.000#10", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "2.0", "0x2.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+    );
+    test("0.33333333333333337", "0x0.55555555555558#53", "NaN", "NaN");
+    test("8.0e-31", "0x1.0E-25#1", "NaN", "NaN");
+    test("1.0e30", "0x1.0E+25#1", "6.0e1", "0x4.0E+1#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "3.4647579066758629",
+        "0x3.76fa5fc9baf1c#53",
+    );
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("0.5", "0x0.8#1", "NaN", "NaN");
+}
+
+#[test]
+fn test_acosh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec(prec);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_assign(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_acosh, rug_o) = rug_acosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("1.0", "0x1.000#10", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.000#10", 10, "0.0", "0x0.0", Ordering::Equal);
+    test("1.0", "0x1.000#10", 100, "0.0", "0x0.0", Ordering::Equal);
+    test("3.0", "0x3.0#2", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.764",
+        "0x1.c38#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.762747174039086050465218649959",
+        "0x1.c34366179d426cc1b1f33d1ba#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.811526272460853065901319862625",
+        "0x1.cfc02f90106c17a3fd778845e#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.658",
+        "0x1.a88#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.657454454153077215400697256995",
+        "0x1.a84eef63326e764c4b7c9ea20#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("8.0e-31", "0x1.0E-25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("8.0e-31", "0x1.0E-25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("8.0e-31", "0x1.0E-25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "70.0078652365544762511404442673",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "3.464757906675862716687930288654",
+        "0x3.76fa5fc9baf1b160f4948ef50#100",
+        Ordering::Greater,
+    );
+    test("2.0", "0x2.0#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("2.0", "0x2.0#1", 10, "1.316", "0x1.510#10", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.316957896924816708625046347307",
+        "0x1.51242719804349be684bd0188#100",
+        Ordering::Less,
+    );
+    test("0.5", "0x0.8#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("0.5", "0x0.8#1", 100, "NaN", "NaN", Ordering::Equal);
+}
+
+#[test]
+fn test_acosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_round(rm);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_round_assign(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.8115262724608532",
+        "0x1.cfc02f90106c2#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.8115262724608532",
+        "0x1.cfc02f90106c2#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.811526272460853",
+        "0x1.cfc02f90106c1#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.6574544541530774",
+        "0x1.a84eef63326e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.6574544541530774",
+        "0x1.a84eef63326e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.6574544541530771",
+        "0x1.a84eef63326e7#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "3.4647579066758625",
+        "0x3.76fa5fc9baf1a#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "3.4647579066758629",
+        "0x3.76fa5fc9baf1c#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "3.4647579066758625",
+        "0x3.76fa5fc9baf1a#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "3.4647579066758629",
+        "0x3.76fa5fc9baf1c#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "3.4647579066758629",
+        "0x3.76fa5fc9baf1c#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_acosh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+        assert!(acosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(acosh.to_string(), out);
+        assert_eq!(to_hex_string(&acosh), out_hex);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut acosh_alt = x.clone();
+        let o_alt = acosh_alt.acosh_prec_round_assign(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh), ComparableFloatRef(&acosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.762",
+        "0x1.c30#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.764",
+        "0x1.c38#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.762",
+        "0x1.c30#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.764",
+        "0x1.c38#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.764",
+        "0x1.c38#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1.762747174039086050465218649959",
+        "0x1.c34366179d426cc1b1f33d1ba#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1.762747174039086050465218649961",
+        "0x1.c34366179d426cc1b1f33d1bc#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "1.762747174039086050465218649959",
+        "0x1.c34366179d426cc1b1f33d1ba#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "1.762747174039086050465218649961",
+        "0x1.c34366179d426cc1b1f33d1bc#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1.762747174039086050465218649959",
+        "0x1.c34366179d426cc1b1f33d1ba#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.811",
+        "0x1.cf8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.811",
+        "0x1.cf8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.812",
+        "0x1.d00#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.656",
+        "0x1.a80#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.658",
+        "0x1.a88#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "1.656",
+        "0x1.a80#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "1.658",
+        "0x1.a88#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.658",
+        "0x1.a88#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "70.1",
+        "0x46.2#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "70.1",
+        "0x46.2#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "3.461",
+        "0x3.76#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "3.461",
+        "0x3.76#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.316",
+        "0x1.510#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.318",
+        "0x1.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "1.316",
+        "0x1.510#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "1.318",
+        "0x1.518#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.316",
+        "0x1.510#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn acosh_prec_fail() {
+    assert_panic!(Float::NAN.acosh_prec(0));
+    assert_panic!(Float::ONE.acosh_prec(0));
+}
+
+#[test]
+fn acosh_prec_ref_fail() {
+    assert_panic!(Float::NAN.acosh_prec_ref(0));
+    assert_panic!(Float::ONE.acosh_prec_ref(0));
+}
+
+#[test]
+fn acosh_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.acosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn acosh_round_fail() {
+    assert_panic!(Float::from(3u32).acosh_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).acosh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.acosh_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acosh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).acosh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::from(3u32).acosh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).acosh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.acosh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn acosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_25().test_properties(|(x, prec, rm)| {
+        let (acosh, o) = x.clone().acosh_prec_round(prec, rm);
+        assert!(acosh.is_valid());
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acosh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 1u32 {
+            assert!(acosh.is_normal());
+            assert_eq!(acosh.get_prec(), Some(prec));
+            assert!(acosh > 0u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.acosh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(acosh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.acosh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn acosh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (acosh, o) = x.clone().acosh_prec(prec);
+        assert!(acosh.is_valid());
+
+        let (acosh_alt, o_alt) = x.acosh_prec_ref(prec);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acosh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let (rug_acosh, rug_o) = rug_acosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh)),
+            ComparableFloatRef(&acosh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn acosh_round_properties() {
+    float_rounding_mode_pair_gen_var_29().test_properties(|(x, rm)| {
+        let (acosh, o) = x.clone().acosh_round(rm);
+        assert!(acosh.is_valid());
+
+        let (acosh_alt, o_alt) = x.acosh_round_ref(rm);
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.acosh_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        let (acosh_alt, o_alt) = x.acosh_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_acosh, rug_o) = rug_acosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_acosh)),
+                ComparableFloatRef(&acosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn acosh_properties() {
+    float_gen().test_properties(|x| {
+        let acosh = x.clone().acosh();
+        assert!(acosh.is_valid());
+
+        let acosh_alt = (&x).acosh();
+        assert!(acosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+
+        let mut x_alt = x.clone();
+        x_alt.acosh_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&acosh));
+
+        let acosh_alt = x
+            .acosh_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&acosh_alt), ComparableFloatRef(&acosh));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_acosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&acosh)
+        );
+    });
+
+    assert!(Float::NAN.acosh().is_nan());
+    assert_eq!(Float::INFINITY.acosh(), Float::INFINITY);
+    assert!(Float::NEGATIVE_INFINITY.acosh().is_nan());
+    assert!(Float::ZERO.acosh().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ONE.acosh()),
+        ComparableFloat(Float::ZERO)
+    );
+}
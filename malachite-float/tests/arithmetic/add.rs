@@ -0,0 +1,3892 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::add::{
+    add_prec_round_naive, rug_add, rug_add_prec, rug_add_prec_round, rug_add_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_1,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_add() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let sum = x.clone() + y.clone();
+        assert!(sum.is_valid());
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let sum_alt = x.clone() + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let sum_alt = &x + &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        let mut sum_alt = x.clone();
+        sum_alt += y.clone();
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        let mut sum_alt = x.clone();
+        sum_alt += &y;
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_add(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&sum)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("NaN", "NaN", "0.0", "0x0.0", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", "Infinity", "Infinity",
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-0.0", "-0x0.0", "-1.0", "-0x1.0#1");
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "4.0", "0x4.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("2.0", "0x2.0#1", "1.0", "0x1.0#2", "3.0", "0x3.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-5.859874482048838",
+        "-0x5.dc20bbeb10900#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+    );
+    test("1.0", "0x1.0#1", "8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        "-1.0",
+        "-0x1.0#2",
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+    );
+}
+
+#[test]
+fn test_add_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_assign_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sum, rug_o) = rug_add_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-5.86",
+        "-0x5.dc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-5.859874482048838206793561766972",
+        "-0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "6.283185307179586231995926937088",
+        "0x6.487ed5110b460000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        100,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1267650600228229401496703205380.0",
+        "0x10000000000000000000000004.0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1267650600228229401496703205380.0",
+        "0x10000000000000000000000004.0#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_add_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_round(y.clone(), rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_round_assign_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) =
+                rug_add_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Down,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Up,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "5.859874482048839",
+        "0x5.dc20bbeb10904#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "5.859874482048839",
+        "0x5.dc20bbeb10904#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "5.859874482048838",
+        "0x5.dc20bbeb10900#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-5.859874482048839",
+        "-0x5.dc20bbeb10904#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-5.859874482048838",
+        "-0x5.dc20bbeb10900#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-5.859874482048838",
+        "-0x5.dc20bbeb10900#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-5.859874482048839",
+        "-0x5.dc20bbeb10904#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-5.859874482048838",
+        "-0x5.dc20bbeb10900#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        RoundingMode::Ceiling,
+        "-0.8",
+        "-0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        RoundingMode::Down,
+        "-0.8",
+        "-0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "6.283185307179586",
+        "0x6.487ed5110b460#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_add_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+        assert!(sum.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sum.to_string(), out);
+        assert_eq!(to_hex_string(&sum), out_hex);
+
+        let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign(y.clone(), prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sum_alt = x.clone();
+        let o_alt = sum_alt.add_prec_round_assign_ref(&y, prec, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum), ComparableFloatRef(&sum_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) = rug_add_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        "1.0",
+        "0x1.0#2",
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "5.87",
+        "0x5.de#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "5.87",
+        "0x5.de#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "5.86",
+        "0x5.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Up,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "5.859874482048838206793561766972",
+        "0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-5.87",
+        "-0x5.de#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-5.86",
+        "-0x5.dc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-5.86",
+        "-0x5.dc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-5.87",
+        "-0x5.de#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-5.86",
+        "-0x5.dc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "-5.859874482048838206793561766972",
+        "-0x5.dc20bbeb10902000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "6.29",
+        "0x6.4a#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "6.29",
+        "0x6.4a#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "6.28",
+        "0x6.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "6.283185307179586231995926937088",
+        "0x6.487ed5110b460000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-8.0e-31",
+        "-0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        RoundingMode::Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Floor,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Ceiling,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Down,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Up,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Nearest,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "2.4e-30",
+        "0x3.0E-25#2",
+        100,
+        RoundingMode::Exact,
+        "-0.9999999999999999999999999999976",
+        "-0x0.ffffffffffffffffffffffffd#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_prec_fail() {
+    assert_panic!(Float::NAN.add_prec(Float::NAN, 0));
+    assert_panic!(Float::ONE.add_prec(Float::TWO, 0));
+}
+
+#[test]
+fn add_prec_val_ref_fail() {
+    assert_panic!(Float::NAN.add_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.add_prec_val_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn add_prec_ref_val_fail() {
+    assert_panic!(Float::NAN.add_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::ONE.add_prec_ref_val(Float::TWO, 0));
+}
+
+#[test]
+fn add_prec_ref_ref_fail() {
+    assert_panic!(Float::NAN.add_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.add_prec_ref_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn add_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn add_round_fail() {
+    let three = Float::from(3u32);
+    let third = Float::from(1.0 / 3.0);
+    assert_panic!(three.clone().add_round(third.clone(), RoundingMode::Exact));
+    assert_panic!(three.clone().add_round_val_ref(&third, RoundingMode::Exact));
+    assert_panic!(three.add_round_ref_val(third.clone(), RoundingMode::Exact));
+    assert_panic!(three.add_round_ref_ref(&third, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = three.clone();
+        x.add_round_assign(third.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = three.clone();
+        x.add_round_assign_ref(&third, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn add_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).add_prec_round(Float::two_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).add_prec_round(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).add_prec_round_val_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).add_prec_round_ref_val(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).add_prec_round_ref_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.add_prec_round_assign(Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.add_prec_round_assign_ref(&Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_prec_round_properties_helper(x: Float, y: Float, prec: u64, rm: RoundingMode) {
+    let (sum, o) = x.clone().add_prec_round(y.clone(), prec, rm);
+    assert!(sum.is_valid());
+
+    let (sum_alt, o_alt) = x.clone().add_prec_round_val_ref(&y, prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, rm);
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.add_prec_round_assign(y.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.add_prec_round_assign_ref(&y, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() {
+        let (sum_alt, o_alt) = add_prec_round_naive(x.clone(), y.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+        if sum.is_normal() {
+            assert_eq!(sum.get_prec(), Some(prec));
+            assert_eq!(
+                sum.partial_cmp(&(Rational::exact_from(&x) + Rational::exact_from(&y))),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_sum, rug_o) = rug_add_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (sum_alt, o_alt) = x.sub_prec_round_ref_val(-&y, prec, rm);
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+    assert_eq!(o_alt, o);
+
+    if !sum.is_zero() {
+        let (sum_alt, o_alt) = (-&x).add_prec_round(-&y, prec, -rm);
+        assert_eq!(ComparableFloat(-sum_alt), ComparableFloat(sum.clone()));
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = x.add_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(
+                ComparableFloat(s.abs_negative_zero()),
+                ComparableFloat(sum.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.add_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn add_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, prec, rm)| {
+        add_prec_round_properties_helper(x, y, prec, rm);
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (sum, o) = x.add_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(sum.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (sum, o) = Float::NAN.add_prec_round_val_ref(&x, prec, rm);
+        assert!(sum.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if !x.is_nan() {
+            let (sum_alt, o_alt) = x.add_prec_round_ref_val(Float::ZERO, prec, rm);
+            let mut x_alt = x.clone();
+            let o = x_alt.set_prec_round(prec, rm);
+            if !x.is_negative_zero() || rm == RoundingMode::Floor {
+                assert_eq!(ComparableFloat(sum_alt), ComparableFloat(x_alt));
+                assert_eq!(o_alt, o);
+            }
+        }
+    });
+}
+
+#[test]
+fn add_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (sum, o) = x.clone().add_prec(y.clone(), prec);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_prec_val_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_val(y.clone(), prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_ref_ref(&y, prec);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (rug_sum, rug_o) = rug_add_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sum)),
+            ComparableFloatRef(&sum)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn add_round_properties() {
+    float_float_rounding_mode_triple_gen_var_1().test_properties(|(x, y, rm)| {
+        let (sum, o) = x.clone().add_round(y.clone(), rm);
+        assert!(sum.is_valid());
+
+        let (sum_alt, o_alt) = x.clone().add_round_val_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_round_ref_val(y.clone(), rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) = x.add_round_ref_ref(&y, rm);
+        assert!(sum_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        let (sum_alt, o_alt) =
+            x.add_prec_round_ref_ref(&y, max(x.significant_bits(), y.significant_bits()), rm);
+        assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sum, rug_o) =
+                rug_add_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sum)),
+                ComparableFloatRef(&sum)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_properties_helper(x: Float, y: Float) {
+    let sum = x.clone() + y.clone();
+    assert!(sum.is_valid());
+
+    let sum_alt = x.clone() + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + y.clone();
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = &x + &y;
+    assert!(sum_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let mut x_alt = x.clone();
+    x_alt += &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sum));
+
+    let sum_alt = x
+        .add_prec_round_ref_ref(
+            &y,
+            max(x.significant_bits(), y.significant_bits()),
+            RoundingMode::Nearest,
+        )
+        .0;
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_add(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y)
+        ))),
+        ComparableFloatRef(&sum)
+    );
+
+    let sum_alt = &x - -&y;
+    assert_eq!(ComparableFloatRef(&sum_alt), ComparableFloatRef(&sum));
+
+    if !sum.is_zero() {
+        assert_eq!(ComparableFloat(-(-&x + -&y)), ComparableFloat(sum.clone()));
+    }
+
+    assert_eq!(ComparableFloat(&y + &x), ComparableFloat(sum));
+}
+
+#[test]
+fn add_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        add_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x + Float::NAN).is_nan());
+        assert!((Float::NAN + &x).is_nan());
+        if !x.is_nan() {
+            if x != Float::NEGATIVE_INFINITY {
+                assert_eq!(&x + Float::INFINITY, Float::INFINITY);
+                assert_eq!(Float::INFINITY + &x, Float::INFINITY);
+            }
+            if x != Float::INFINITY {
+                assert_eq!(&x + Float::NEGATIVE_INFINITY, Float::NEGATIVE_INFINITY);
+                assert_eq!(Float::NEGATIVE_INFINITY + &x, Float::NEGATIVE_INFINITY);
+            }
+        }
+        assert_eq!(
+            ComparableFloatRef(&(&x + Float::NEGATIVE_ZERO)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(
+            ComparableFloatRef(&(Float::NEGATIVE_ZERO + &x)),
+            ComparableFloatRef(&x)
+        );
+        if x.is_finite() && !x.is_zero() {
+            assert_eq!(ComparableFloat(&x + -&x), ComparableFloat(Float::ZERO));
+        }
+    });
+}
@@ -0,0 +1,3777 @@
+use malachite_base::num::arithmetic::traits::{AddMul, AddMulAssign};
+use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::add_mul::{
+    add_mul_prec_round_naive, rug_add_mul, rug_add_mul_prec, rug_add_mul_prec_round,
+    rug_add_mul_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_float_rounding_mode_quadruple_gen_var_1,
+    float_float_float_unsigned_quadruple_gen_var_1,
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_1, float_pair_gen, float_triple_gen,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_add_mul() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let result = x.clone().add_mul(y.clone(), z.clone());
+        assert!(result.is_valid());
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let result_alt = x.clone().add_mul(y.clone(), &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = x.clone().add_mul(&y, z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = x.clone().add_mul(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = (&x).add_mul(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+
+        let mut result_alt = x.clone();
+        result_alt.add_mul_assign(y.clone(), z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.add_mul_assign(y.clone(), &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.add_mul_assign(&y, z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.add_mul_assign(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_add_mul(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z)
+            ))),
+            ComparableFloatRef(&result)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "NaN", "NaN",
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN",
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0", "-0.0", "-0x0.0",
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", "1.0", "0x1.0#1", "1.0", "0x1.0#1",
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1",
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", "3.0", "0x3.0#2", "8.0", "0x8.0#2",
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "0.0", "0x0.0",
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "0.0", "0x0.0",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "11.681326876263359",
+        "0xb.ae6b702b6fdd0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-4.2474634453408564",
+        "-0x4.3f59c3acb1c84#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "6.728011747499565",
+        "0x6.ba5efa569d4c0#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "3.7182818284590451",
+        "0x3.b7e151628aed2#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "2.0e60",
+        "0x1.0E+50#1",
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+    );
+}
+
+#[test]
+fn test_add_mul_prec() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().add_mul_prec(y.clone(), z.clone(), prec);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().add_mul_prec_val_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.add_mul_prec_ref_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_prec_assign(y.clone(), z.clone(), prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_prec_assign_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_result, rug_o) = rug_add_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "7.0",
+        "0x7.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "11.69",
+        "0xb.b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "11.68132687626335939440463482365",
+        "0xb.ae6b702b6fdd1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-4.25",
+        "-0x4.40#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-4.24746344534085632529732876085",
+        "-0x4.3f59c3acb1c83a6af73102248#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "6.727",
+        "0x6.ba#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "6.728011747499564733368250042616",
+        "0x6.ba5efa569d4bf5b33ef2c5c90#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "3.719",
+        "0x3.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "3.718281828459045201817900760943",
+        "0x3.b7e151628aed2800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        "-0.667",
+        "-0x0.aac#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        100,
+        "-0.6666666666666667406815349750104",
+        "-0x0.aaaaaaaaaaaab000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0e60",
+        "0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.607e60",
+        "0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1.606938044258990275541962092341e60",
+        "0x1.0000000000000000000000000E+50#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_add_mul_round() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().add_mul_round(y.clone(), z.clone(), rm);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().add_mul_round_val_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.add_mul_round_ref_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_round_assign(y.clone(), z.clone(), rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_round_assign_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_add_mul_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "6.0",
+        "0x6.0#2",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "6.0",
+        "0x6.0#2",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "11.681326876263359",
+        "0xb.ae6b702b6fdd0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "11.681326876263361",
+        "0xb.ae6b702b6fdd8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "11.681326876263359",
+        "0xb.ae6b702b6fdd0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "11.681326876263361",
+        "0xb.ae6b702b6fdd8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "11.681326876263359",
+        "0xb.ae6b702b6fdd0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-4.2474634453408564",
+        "-0x4.3f59c3acb1c84#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-4.2474634453408555",
+        "-0x4.3f59c3acb1c80#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-4.2474634453408555",
+        "-0x4.3f59c3acb1c80#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-4.2474634453408564",
+        "-0x4.3f59c3acb1c84#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-4.2474634453408564",
+        "-0x4.3f59c3acb1c84#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "6.728011747499564",
+        "0x6.ba5efa569d4bc#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "6.728011747499565",
+        "0x6.ba5efa569d4c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "6.728011747499564",
+        "0x6.ba5efa569d4bc#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "6.728011747499565",
+        "0x6.ba5efa569d4c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "6.728011747499565",
+        "0x6.ba5efa569d4c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "3.7182818284590451",
+        "0x3.b7e151628aed2#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "3.7182818284590455",
+        "0x3.b7e151628aed4#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "3.7182818284590451",
+        "0x3.b7e151628aed2#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "3.7182818284590455",
+        "0x3.b7e151628aed4#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "3.7182818284590451",
+        "0x3.b7e151628aed2#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Down,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Up,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Exact,
+        "-0.6666666666666667",
+        "-0x0.aaaaaaaaaaaab0#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "2.0e60",
+        "0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "3.0e60",
+        "0x2.0E+50#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "2.0e60",
+        "0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "3.0e60",
+        "0x2.0E+50#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.0e60",
+        "0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2676506002282297e30",
+        "0x1.0000000000001E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_add_mul_prec_round() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().add_mul_prec_round(y.clone(), z.clone(), prec, rm);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().add_mul_prec_round_val_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.add_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_add_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "7.0",
+        "0x7.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "7.0",
+        "0x7.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "11.67",
+        "0xb.ac#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "11.69",
+        "0xb.b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "11.67",
+        "0xb.ac#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "11.69",
+        "0xb.b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "11.69",
+        "0xb.b0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "11.68132687626335939440463482365",
+        "0xb.ae6b702b6fdd1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "11.68132687626335939440463482367",
+        "0xb.ae6b702b6fdd1679e124a69c#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Down,
+        "11.68132687626335939440463482365",
+        "0xb.ae6b702b6fdd1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Up,
+        "11.68132687626335939440463482367",
+        "0xb.ae6b702b6fdd1679e124a69c#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "11.68132687626335939440463482365",
+        "0xb.ae6b702b6fdd1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-4.25",
+        "-0x4.40#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-4.24",
+        "-0x4.3e#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-4.24",
+        "-0x4.3e#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-4.25",
+        "-0x4.40#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-4.25",
+        "-0x4.40#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "6.727",
+        "0x6.ba#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "6.734",
+        "0x6.bc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "6.727",
+        "0x6.ba#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "6.734",
+        "0x6.bc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "6.727",
+        "0x6.ba#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "6.728011747499564733368250042616",
+        "0x6.ba5efa569d4bf5b33ef2c5c90#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "3.715",
+        "0x3.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "3.719",
+        "0x3.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "3.715",
+        "0x3.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "3.719",
+        "0x3.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "3.719",
+        "0x3.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "3.718281828459045201817900760943",
+        "0x3.b7e151628aed2800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "-0.667",
+        "-0x0.aac#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.666",
+        "-0x0.aa8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "-0.666",
+        "-0x0.aa8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "-0.667",
+        "-0x0.aac#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.667",
+        "-0x0.aac#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        100,
+        RoundingMode::Exact,
+        "-0.6666666666666667406815349750104",
+        "-0x0.aaaaaaaaaaaab000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "1.607e60",
+        "0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.61e60",
+        "0x1.008E+50#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "1.607e60",
+        "0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "1.61e60",
+        "0x1.008E+50#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.607e60",
+        "0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.008E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn add_mul_prec_fail() {
+    assert_panic!(Float::NAN.add_mul_prec(Float::NAN, Float::NAN, 0));
+    assert_panic!(Float::ONE.add_mul_prec(Float::TWO, Float::TWO, 0));
+    assert_panic!(Float::ONE.add_mul_prec_val_ref_ref(&Float::TWO, &Float::TWO, 0));
+    assert_panic!(Float::ONE.add_mul_prec_ref_ref_ref(&Float::TWO, &Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_mul_prec_assign(Float::TWO, Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_mul_prec_assign_ref_ref(&Float::TWO, &Float::TWO, 0)
+    });
+}
+
+#[test]
+fn add_mul_round_fail() {
+    let three = Float::from(3u32);
+    let third = Float::from(1.0 / 3.0);
+    assert_panic!(Float::ONE.add_mul_round(three.clone(), third.clone(), RoundingMode::Exact));
+    assert_panic!(Float::ONE.add_mul_round_val_ref_ref(&three, &third, RoundingMode::Exact));
+    assert_panic!(Float::ONE.add_mul_round_ref_ref_ref(&three, &third, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_mul_round_assign(three.clone(), third.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.add_mul_round_assign_ref_ref(&three, &third, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn add_mul_prec_round_fail() {
+    let pi = Float::from(std::f64::consts::PI);
+    assert_panic!(Float::one_prec(1).add_mul_prec_round(
+        Float::two_prec(1),
+        Float::two_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::one_prec(1).add_mul_prec_round(
+        pi.clone(),
+        Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).add_mul_prec_round_val_ref_ref(
+        &pi,
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).add_mul_prec_round_ref_ref_ref(
+        &pi,
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.add_mul_prec_round_assign(pi.clone(), Float::ONE, 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.add_mul_prec_round_assign_ref_ref(&pi, &Float::ONE, 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_mul_prec_round_properties_helper(x: Float, y: Float, z: Float, prec: u64, rm: RoundingMode) {
+    let (result, o) = x.clone().add_mul_prec_round(y.clone(), z.clone(), prec, rm);
+    assert!(result.is_valid());
+
+    let (result_alt, o_alt) = x.clone().add_mul_prec_round_val_ref_ref(&y, &z, prec, rm);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let (result_alt, o_alt) = x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.add_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.add_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() && z.is_finite() {
+        let (result_alt, o_alt) =
+            add_mul_prec_round_naive(x.clone(), y.clone(), z.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+        if result.is_normal() {
+            assert_eq!(result.get_prec(), Some(prec));
+            assert_eq!(
+                result.partial_cmp(
+                    &(Rational::exact_from(&x)
+                        + Rational::exact_from(&y) * Rational::exact_from(&z))
+                ),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_result, rug_o) = rug_add_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (result_alt, o_alt) = x.add_mul_prec_round_ref_ref_ref(&z, &y, prec, rm);
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let (result_alt, o_alt) = x.sub_mul_prec_round_ref_ref_ref(&-&y, &z, prec, rm);
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    if !result.is_zero() {
+        let (result_alt, o_alt) = (-&x).add_mul_prec_round(-&y, z.clone(), prec, -rm);
+        assert_eq!(
+            ComparableFloat(-result_alt),
+            ComparableFloat(result.clone())
+        );
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (r, oo) = x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+            assert_eq!(
+                ComparableFloat(r.abs_negative_zero()),
+                ComparableFloat(result.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn add_mul_prec_round_properties() {
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_1().test_properties(
+        |(x, y, z, prec, rm)| {
+            add_mul_prec_round_properties_helper(x, y, z, prec, rm);
+        },
+    );
+}
+
+#[test]
+fn add_mul_prec_properties() {
+    float_float_float_unsigned_quadruple_gen_var_1().test_properties(|(x, y, z, prec)| {
+        let (result, o) = x.clone().add_mul_prec(y.clone(), z.clone(), prec);
+        assert!(result.is_valid());
+
+        let (result_alt, o_alt) = x.clone().add_mul_prec_val_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) = x.add_mul_prec_ref_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_mul_prec_assign(y.clone(), z.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_mul_prec_assign_ref_ref(&y, &z, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) =
+            x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (rug_result, rug_o) = rug_add_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn add_mul_round_properties() {
+    float_float_float_rounding_mode_quadruple_gen_var_1().test_properties(|(x, y, z, rm)| {
+        let (result, o) = x.clone().add_mul_round(y.clone(), z.clone(), rm);
+        assert!(result.is_valid());
+
+        let (result_alt, o_alt) = x.clone().add_mul_round_val_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) = x.add_mul_round_ref_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_mul_round_assign(y.clone(), z.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.add_mul_round_assign_ref_ref(&y, &z, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let prec = max(
+            max(x.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        let (result_alt, o_alt) = x.add_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_add_mul_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn add_mul_properties_helper(x: Float, y: Float, z: Float) {
+    let result = x.clone().add_mul(y.clone(), z.clone());
+    assert!(result.is_valid());
+
+    let result_alt = x.clone().add_mul(y.clone(), &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = x.clone().add_mul(&y, z.clone());
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = x.clone().add_mul(&y, &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = (&x).add_mul(&y, &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.add_mul_assign(y.clone(), z.clone());
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.add_mul_assign(y.clone(), &z);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.add_mul_assign(&y, z.clone());
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.add_mul_assign(&y, &z);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let prec = max(
+        max(x.significant_bits(), y.significant_bits()),
+        z.significant_bits(),
+    );
+    let result_alt = x
+        .add_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Nearest)
+        .0;
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_add_mul(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z)
+        ))),
+        ComparableFloatRef(&result)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&(&x).add_mul(&z, &y)),
+        ComparableFloatRef(&result)
+    );
+
+    if !result.is_zero() {
+        assert_eq!(
+            ComparableFloat(-(-&x).add_mul(-&y, &z)),
+            ComparableFloat(result.clone())
+        );
+    }
+}
+
+#[test]
+fn add_mul_properties() {
+    float_triple_gen().test_properties(|(x, y, z)| {
+        add_mul_properties_helper(x, y, z);
+    });
+
+    float_pair_gen().test_properties(|(x, y)| {
+        assert!((&x).add_mul(&y, &Float::NAN).is_nan());
+        assert!((&x).add_mul(&Float::NAN, &y).is_nan());
+        assert!(Float::NAN.add_mul(&x, &y).is_nan());
+        if !x.is_nan() && !y.is_nan() {
+            let (result, o) = x.add_mul_prec_round_ref_ref_ref(
+                &y,
+                &Float::ONE,
+                max(x.significant_bits(), y.significant_bits()),
+                RoundingMode::Nearest,
+            );
+            let (result_alt, o_alt) = x.add_prec_round_ref_ref(
+                &y,
+                max(x.significant_bits(), y.significant_bits()),
+                RoundingMode::Nearest,
+            );
+            assert_eq!(ComparableFloat(result), ComparableFloat(result_alt));
+            assert_eq!(o, o_alt);
+        }
+        if x.is_finite() && y.is_finite() {
+            assert_eq!((&x).add_mul(&y, &Float::ZERO), x);
+        }
+    });
+}
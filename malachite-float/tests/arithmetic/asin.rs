@@ -0,0 +1,2260 @@
+use malachite_base::num::arithmetic::traits::{Asin, AsinAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::asin::{
+    rug_asin, rug_asin_prec, rug_asin_prec_round, rug_asin_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_22, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_18,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asin() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let asin = x.clone().asin();
+        assert!(asin.is_valid());
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let asin_alt = (&x).asin();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        let mut asin_alt = x.clone();
+        asin_alt.asin_assign();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asin)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("1.0", "0x1.000#10", "1.57", "0x1.920#10");
+    test("3.0", "0x3.0#2", "NaN", "NaN");
+    test("3.1415926535897931", "0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test("2.7182818284590451", "0x2.b7e151628aed2#53", "NaN", "NaN");
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.33983690945412198",
+        "0x0.56ff8d3c14444c#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("1.0e30", "0x1.0E+25#1", "NaN", "NaN");
+    test("15.999999999999998", "0xf.ffffffffffff8#53", "NaN", "NaN");
+    test("0.5", "0x0.8#1", "0.5", "0x0.8#1");
+    test("-0.5", "-0x0.8#1", "-0.5", "-0x0.8#1");
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+    );
+}
+
+#[test]
+fn test_asin_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_assign(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "1.57", "0x1.920#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.57079632679489661923132169164",
+        "-0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "NaN", "NaN", Ordering::Equal);
+    test("3.0", "0x3.0#2", 10, "NaN", "NaN", Ordering::Equal);
+    test("3.0", "0x3.0#2", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3398369094541219763487039804861",
+        "0x0.56ff8d3c14444b8ce8bc0a2f78#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test("1.0e30", "0x1.0E+25#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0e30", "0x1.0E+25#1", 100, "NaN", "NaN", Ordering::Equal);
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "0.523", "0x0.860#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "0.523598775598298873077107230547",
+        "0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Greater,
+    );
+    test("-0.5", "-0x0.8#1", 1, "-0.5", "-0x0.8#1", Ordering::Greater);
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        100,
+        "-0.523598775598298873077107230547",
+        "-0x0.860a91c16b9b2c232dd99707b#100",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        100,
+        "0.7853981633974483779737474224393",
+        "0x0.c90fdaa22168c721c0b63a141#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_asin_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_round_assign(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.33983690945412193",
+        "0x0.56ff8d3c144448#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.33983690945412198",
+        "0x0.56ff8d3c14444c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.33983690945412193",
+        "0x0.56ff8d3c144448#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.33983690945412198",
+        "0x0.56ff8d3c14444c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.33983690945412198",
+        "0x0.56ff8d3c14444c#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Floor,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Ceiling,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Down,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Up,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        RoundingMode::Nearest,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_asin_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asin.to_string(), out);
+        assert_eq!(to_hex_string(&asin), out_hex);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asin_alt = x.clone();
+        let o_alt = asin_alt.asin_prec_round_assign(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin), ComparableFloatRef(&asin_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.572",
+        "-0x1.928#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.572",
+        "-0x1.928#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "0.523",
+        "0x0.860#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.524",
+        "0x0.864#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "0.523",
+        "0x0.860#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "0.524",
+        "0x0.864#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "0.523",
+        "0x0.860#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "-0.524",
+        "-0x0.864#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "-0.524",
+        "-0x0.864#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.5",
+        "-0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.523",
+        "-0x0.860#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.7071067811865476",
+        "0x0.b504f333f9de68#53",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asin_prec_fail() {
+    assert_panic!(Float::NAN.asin_prec(0));
+    assert_panic!(Float::ONE.asin_prec(0));
+}
+
+#[test]
+fn asin_prec_ref_fail() {
+    assert_panic!(Float::NAN.asin_prec_ref(0));
+    assert_panic!(Float::ONE.asin_prec_ref(0));
+}
+
+#[test]
+fn asin_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.asin_prec_assign(0)
+    });
+}
+
+#[test]
+fn asin_round_fail() {
+    assert_panic!(Float::ONE_HALF.asin_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.asin_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asin_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).asin_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE_HALF.asin_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.asin_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.asin_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asin_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_18().test_properties(|(x, prec, rm)| {
+        let (asin, o) = x.clone().asin_prec_round(prec, rm);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 && x <= 1u32 && x >= -1i32 {
+            assert!(asin.is_normal());
+            assert_eq!(asin.get_prec(), Some(prec));
+            assert!((asin > 0u32) == (x > 0u32));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.asin_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(asin.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.asin_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn asin_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (asin, o) = x.clone().asin_prec(prec);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_prec_ref(prec);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let (rug_asin, rug_o) = rug_asin_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin)),
+            ComparableFloatRef(&asin)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn asin_round_properties() {
+    float_rounding_mode_pair_gen_var_22().test_properties(|(x, rm)| {
+        let (asin, o) = x.clone().asin_round(rm);
+        assert!(asin.is_valid());
+
+        let (asin_alt, o_alt) = x.asin_round_ref(rm);
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asin_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        let (asin_alt, o_alt) = x.asin_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asin, rug_o) = rug_asin_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asin)),
+                ComparableFloatRef(&asin)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn asin_properties() {
+    float_gen().test_properties(|x| {
+        let asin = x.clone().asin();
+        assert!(asin.is_valid());
+
+        let asin_alt = (&x).asin();
+        assert!(asin_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+
+        let mut x_alt = x.clone();
+        x_alt.asin_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asin));
+
+        let asin_alt = x
+            .asin_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&asin_alt), ComparableFloatRef(&asin));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asin(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asin)
+        );
+    });
+
+    assert!(Float::NAN.asin().is_nan());
+    assert!(Float::INFINITY.asin().is_nan());
+    assert!(Float::NEGATIVE_INFINITY.asin().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ZERO.asin()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.asin()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+    assert!(Float::from(2u32).asin().is_nan());
+}
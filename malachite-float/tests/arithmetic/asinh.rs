@@ -0,0 +1,1912 @@
+use malachite_base::num::arithmetic::traits::{Asinh, AsinhAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::asinh::{
+    rug_asinh, rug_asinh_prec, rug_asinh_prec_round, rug_asinh_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_28, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_24,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_asinh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let asinh = x.clone().asinh();
+        assert!(asinh.is_valid());
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let asinh_alt = (&x).asinh();
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+
+        let mut asinh_alt = x.clone();
+        asinh_alt.asinh_assign();
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asinh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("1.0", "0x1.000#10", "0.882", "0x0.e1c#10");
+    test("3.0", "0x3.0#2", "2.0", "0x2.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.8622957433108482",
+        "-0x1.dcbf69f10006d#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.32745015023725849",
+        "0x0.53d3c5e656dd24#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("1.0e30", "0x1.0E+25#1", "6.0e1", "0x4.0E+1#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "3.4667110378847248",
+        "0x3.777a5fe465a90#53",
+    );
+}
+
+#[test]
+fn test_asinh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec(prec);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_assign(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_asinh, rug_o) = rug_asinh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.8813735870195430252326093249797",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.8813735870195430252326093249797",
+        "-0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "0.8813735870195430252326093249797",
+        "0x0.e1a1b30bcea13660d8f99e8dd#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("3.0", "0x3.0#2", 10, "1.818", "0x1.d18#10", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.818446459232066823483698963561",
+        "0x1.d185b507edc0dfdf653c5b01e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.862295743310848182743051714127",
+        "0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.861",
+        "-0x1.dc8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-1.862295743310848182743051714127",
+        "-0x1.dcbf69f10006cbe9c11ca9a5e#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.725",
+        "0x1.b98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.725382558852315044033016964024",
+        "0x1.b9b2abdf5c076eabb258a2374#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3274501502372584784308699457554",
+        "0x0.53d3c5e656dd23740620cdd110#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "70.0078652365544762511404442673",
+        "0x46.020374c5c6db00c6a6d5daf8#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "3.466711037884724645832450801577",
+        "0x3.777a5fe465a8f5b4e207ac188#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_asinh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_round(rm);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_round_assign(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.862295743310848",
+        "0x1.dcbf69f10006c#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.862295743310848",
+        "0x1.dcbf69f10006c#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.8622957433108482",
+        "0x1.dcbf69f10006d#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.8622957433108482",
+        "-0x1.dcbf69f10006d#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-1.862295743310848",
+        "-0x1.dcbf69f10006c#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-1.862295743310848",
+        "-0x1.dcbf69f10006c#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.8622957433108482",
+        "-0x1.dcbf69f10006d#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.8622957433108482",
+        "-0x1.dcbf69f10006d#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.7253825588523148",
+        "0x1.b9b2abdf5c076#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.7253825588523151",
+        "0x1.b9b2abdf5c077#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.32745015023725843",
+        "0x0.53d3c5e656dd20#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.32745015023725849",
+        "0x0.53d3c5e656dd24#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.32745015023725843",
+        "0x0.53d3c5e656dd20#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.32745015023725849",
+        "0x0.53d3c5e656dd24#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.32745015023725849",
+        "0x0.53d3c5e656dd24#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "1.0e2",
+        "0x8.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "6.0e1",
+        "0x4.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "3.4667110378847243",
+        "0x3.777a5fe465a8e#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "3.4667110378847248",
+        "0x3.777a5fe465a90#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "3.4667110378847243",
+        "0x3.777a5fe465a8e#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "3.4667110378847248",
+        "0x3.777a5fe465a90#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "3.4667110378847248",
+        "0x3.777a5fe465a90#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_asinh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+        assert!(asinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(asinh.to_string(), out);
+        assert_eq!(to_hex_string(&asinh), out_hex);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut asinh_alt = x.clone();
+        let o_alt = asinh_alt.asinh_prec_round_assign(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh), ComparableFloatRef(&asinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.881",
+        "-0x0.e18#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-0.881",
+        "-0x0.e18#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.882",
+        "-0x0.e1c#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "0.881",
+        "0x0.e18#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "0.882",
+        "0x0.e1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.818",
+        "0x1.d18#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.82",
+        "0x1.d20#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.818",
+        "0x1.d18#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.82",
+        "0x1.d20#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.818",
+        "0x1.d18#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1.818446459232066823483698963559",
+        "0x1.d185b507edc0dfdf653c5b01c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1.818446459232066823483698963561",
+        "0x1.d185b507edc0dfdf653c5b01e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "1.818446459232066823483698963559",
+        "0x1.d185b507edc0dfdf653c5b01c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "1.818446459232066823483698963561",
+        "0x1.d185b507edc0dfdf653c5b01e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1.818446459232066823483698963561",
+        "0x1.d185b507edc0dfdf653c5b01e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.863",
+        "0x1.dd0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.863",
+        "0x1.dd0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.861",
+        "0x1.dc8#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.863",
+        "-0x1.dd0#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.861",
+        "-0x1.dc8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-1.861",
+        "-0x1.dc8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-1.863",
+        "-0x1.dd0#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.861",
+        "-0x1.dc8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.725",
+        "0x1.b98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "1.725",
+        "0x1.b98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "1.727",
+        "0x1.ba0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.725",
+        "0x1.b98#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3271",
+        "0x0.53c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3271",
+        "0x0.53c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3276",
+        "0x0.53e#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.88e-31",
+        "0xf.fcE-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.88e-31",
+        "0xf.fcE-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.888609052210118054117285652822e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.888609052210118054117285652822e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "70.1",
+        "0x46.2#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "70.1",
+        "0x46.2#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "70.0",
+        "0x46.0#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "3.469",
+        "0x3.78#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "3.469",
+        "0x3.78#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "3.465",
+        "0x3.77#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn asinh_prec_fail() {
+    assert_panic!(Float::NAN.asinh_prec(0));
+    assert_panic!(Float::ONE.asinh_prec(0));
+}
+
+#[test]
+fn asinh_prec_ref_fail() {
+    assert_panic!(Float::NAN.asinh_prec_ref(0));
+    assert_panic!(Float::ONE.asinh_prec_ref(0));
+}
+
+#[test]
+fn asinh_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.asinh_prec_assign(0)
+    });
+}
+
+#[test]
+fn asinh_round_fail() {
+    assert_panic!(Float::ONE.asinh_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.asinh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.asinh_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asinh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).asinh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.asinh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.asinh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.asinh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn asinh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_24().test_properties(|(x, prec, rm)| {
+        let (asinh, o) = x.clone().asinh_prec_round(prec, rm);
+        assert!(asinh.is_valid());
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asinh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 {
+            assert!(asinh.is_normal());
+            assert_eq!(asinh.get_prec(), Some(prec));
+            assert!((asinh > 0u32) == (x > 0u32));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.asinh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(asinh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.asinh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn asinh_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (asinh, o) = x.clone().asinh_prec(prec);
+        assert!(asinh.is_valid());
+
+        let (asinh_alt, o_alt) = x.asinh_prec_ref(prec);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asinh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let (rug_asinh, rug_o) = rug_asinh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh)),
+            ComparableFloatRef(&asinh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn asinh_round_properties() {
+    float_rounding_mode_pair_gen_var_28().test_properties(|(x, rm)| {
+        let (asinh, o) = x.clone().asinh_round(rm);
+        assert!(asinh.is_valid());
+
+        let (asinh_alt, o_alt) = x.asinh_round_ref(rm);
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.asinh_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        let (asinh_alt, o_alt) = x.asinh_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_asinh, rug_o) = rug_asinh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_asinh)),
+                ComparableFloatRef(&asinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn asinh_properties() {
+    float_gen().test_properties(|x| {
+        let asinh = x.clone().asinh();
+        assert!(asinh.is_valid());
+
+        let asinh_alt = (&x).asinh();
+        assert!(asinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+
+        let mut x_alt = x.clone();
+        x_alt.asinh_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&asinh));
+
+        let asinh_alt = x
+            .asinh_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&asinh_alt), ComparableFloatRef(&asinh));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_asinh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&asinh)
+        );
+    });
+
+    assert!(Float::NAN.asinh().is_nan());
+    assert_eq!(Float::INFINITY.asinh(), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_INFINITY.asinh(), Float::NEGATIVE_INFINITY);
+    assert_eq!(
+        ComparableFloat(Float::ZERO.asinh()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.asinh()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
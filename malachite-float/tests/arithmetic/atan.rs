@@ -0,0 +1,1878 @@
+use malachite_base::num::arithmetic::traits::{Atan, AtanAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::atan::{
+    rug_atan, rug_atan_prec, rug_atan_prec_round, rug_atan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_24, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_20,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let atan = x.clone().atan();
+        assert!(atan.is_valid());
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let atan_alt = (&x).atan();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        let mut atan_alt = x.clone();
+        atan_alt.atan_assign();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&atan)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "2.0", "0x2.0#1");
+    test("-Infinity", "-Infinity", "-2.0", "-0x2.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("1.0", "0x1.000#10", "0.785", "0x0.c90#10");
+    test("3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.32175055439664224",
+        "0x0.525e3e8c9a7b88#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("1.0e30", "0x1.0E+25#1", "2.0", "0x2.0#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "1.5083775167989393",
+        "0x1.82250768ac529#53",
+    );
+}
+
+#[test]
+fn test_atan_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_assign(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "0.785", "0x0.c90#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.78539816339744830961566084582",
+        "-0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.249045772398254425829917077281",
+        "0x1.3fc176b7a855ffd77cb88581e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.262627255678911672177612146816",
+        "0x1.433b8a322ddd29618168a21ca#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.262",
+        "-0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-1.262627255678911672177612146816",
+        "-0x1.433b8a322ddd29618168a21ca#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.219",
+        "0x1.380#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.218282905017277604527928398283",
+        "0x1.37e1637253388ca9ebdfe2c3e#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3217505543966422267080953531133",
+        "0x0.525e3e8c9a7b86f8733aa5fb88#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "1.508",
+        "0x1.820#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "1.508377516798939263845448261922",
+        "0x1.82250768ac528f069c5acb69c#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_atan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_round_assign(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.2626272556789115",
+        "0x1.433b8a322ddd2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2626272556789118",
+        "0x1.433b8a322ddd3#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-1.2626272556789115",
+        "-0x1.433b8a322ddd2#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.2626272556789118",
+        "-0x1.433b8a322ddd3#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.2182829050172774",
+        "0x1.37e1637253388#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.2182829050172777",
+        "0x1.37e1637253389#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.32175055439664219",
+        "0x0.525e3e8c9a7b84#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.32175055439664224",
+        "0x0.525e3e8c9a7b88#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.32175055439664219",
+        "0x0.525e3e8c9a7b84#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.32175055439664224",
+        "0x0.525e3e8c9a7b88#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.32175055439664224",
+        "0x0.525e3e8c9a7b88#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "1.5083775167989391",
+        "0x1.82250768ac528#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "1.5083775167989393",
+        "0x1.82250768ac529#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "1.5083775167989391",
+        "0x1.82250768ac528#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "1.5083775167989393",
+        "0x1.82250768ac529#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "1.5083775167989393",
+        "0x1.82250768ac529#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_atan_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan.to_string(), out);
+        assert_eq!(to_hex_string(&atan), out_hex);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan_alt = x.clone();
+        let o_alt = atan_alt.atan_prec_round_assign(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan), ComparableFloatRef(&atan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.786",
+        "-0x0.c94#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-0.786",
+        "-0x0.c94#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.785",
+        "-0x0.c90#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.248",
+        "0x1.3f8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.248",
+        "0x1.3f8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.25",
+        "0x1.400#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1.24904577239825442582991707728",
+        "0x1.3fc176b7a855ffd77cb88581c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1.249045772398254425829917077281",
+        "0x1.3fc176b7a855ffd77cb88581e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "1.24904577239825442582991707728",
+        "0x1.3fc176b7a855ffd77cb88581c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "1.249045772398254425829917077281",
+        "0x1.3fc176b7a855ffd77cb88581e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1.249045772398254425829917077281",
+        "0x1.3fc176b7a855ffd77cb88581e#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.264",
+        "0x1.438#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.264",
+        "0x1.438#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.264",
+        "-0x1.438#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.262",
+        "-0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-1.262",
+        "-0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-1.264",
+        "-0x1.438#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.262",
+        "-0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.217",
+        "0x1.378#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.219",
+        "0x1.380#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "1.217",
+        "0x1.378#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "1.219",
+        "0x1.380#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.219",
+        "0x1.380#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3213",
+        "0x0.524#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3213",
+        "0x0.524#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3218",
+        "0x0.526#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.88e-31",
+        "0xf.fcE-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.88e-31",
+        "0xf.fcE-26#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.888609052210118054117285652822e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.888609052210118054117285652822e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "1.508",
+        "0x1.820#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.51",
+        "0x1.828#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "1.508",
+        "0x1.820#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "1.51",
+        "0x1.828#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "1.508",
+        "0x1.820#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan_prec_fail() {
+    assert_panic!(Float::NAN.atan_prec(0));
+    assert_panic!(Float::ONE.atan_prec(0));
+}
+
+#[test]
+fn atan_prec_ref_fail() {
+    assert_panic!(Float::NAN.atan_prec_ref(0));
+    assert_panic!(Float::ONE.atan_prec_ref(0));
+}
+
+#[test]
+fn atan_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan_prec_assign(0)
+    });
+}
+
+#[test]
+fn atan_round_fail() {
+    assert_panic!(Float::ONE.atan_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atan_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).atan_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.atan_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_20().test_properties(|(x, prec, rm)| {
+        let (atan, o) = x.clone().atan_prec_round(prec, rm);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        if !x.is_nan() && x != 0u32 {
+            assert!(atan.is_normal());
+            assert_eq!(atan.get_prec(), Some(prec));
+            assert!((atan > 0u32) == (x > 0u32));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.atan_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(atan.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.atan_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn atan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (atan, o) = x.clone().atan_prec(prec);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_prec_ref(prec);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan, rug_o) = rug_atan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan)),
+            ComparableFloatRef(&atan)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn atan_round_properties() {
+    float_rounding_mode_pair_gen_var_24().test_properties(|(x, rm)| {
+        let (atan, o) = x.clone().atan_round(rm);
+        assert!(atan.is_valid());
+
+        let (atan_alt, o_alt) = x.atan_round_ref(rm);
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.atan_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        let (atan_alt, o_alt) = x.atan_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan, rug_o) = rug_atan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan)),
+                ComparableFloatRef(&atan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn atan_properties() {
+    float_gen().test_properties(|x| {
+        let atan = x.clone().atan();
+        assert!(atan.is_valid());
+
+        let atan_alt = (&x).atan();
+        assert!(atan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+
+        let mut x_alt = x.clone();
+        x_alt.atan_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&atan));
+
+        let atan_alt = x
+            .atan_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&atan_alt), ComparableFloatRef(&atan));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&atan)
+        );
+    });
+
+    assert!(Float::NAN.atan().is_nan());
+    assert!(Float::INFINITY.atan() > 1u32);
+    assert!(Float::NEGATIVE_INFINITY.atan() < -1i32);
+    assert_eq!(
+        ComparableFloat(Float::ZERO.atan()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.atan()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
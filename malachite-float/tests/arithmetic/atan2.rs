@@ -0,0 +1,3128 @@
+use malachite_base::num::arithmetic::traits::{Atan2, Atan2Assign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::atan2::{
+    rug_atan2, rug_atan2_prec, rug_atan2_prec_round, rug_atan2_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_5,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_7, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_unsigned_rounding_mode_triple_gen_var_20,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::max;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_atan2() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let atan2 = x.clone().atan2(y.clone());
+        assert!(atan2.is_valid());
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let atan2_alt = x.clone().atan2(&y);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let atan2_alt = (&x).atan2(y.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let atan2_alt = (&x).atan2(&y);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+
+        let mut atan2_alt = x.clone();
+        atan2_alt.atan2_assign(y.clone());
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        let mut atan2_alt = x.clone();
+        atan2_alt.atan2_assign(&y);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&atan2)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "1.0", "0x1.0#1",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "2.0",
+        "0x2.0#1",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-2.0",
+        "-0x2.0#1",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "2.0", "0x2.0#1");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "4.0", "0x4.0#1");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "-4.0", "-0x4.0#1");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-2.0", "-0x2.0#1");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test("3.0", "0x3.0#2", "3.0", "0x3.0#2", "0.8", "0x0.c#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "0.11065722117389566",
+        "0x0.1c54081a021431#53",
+    );
+    test("1.0", "0x1.0#1", "8.0e-31", "0x1.0E-25#1", "2.0", "0x2.0#1");
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+}
+
+#[test]
+fn test_atan2_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_prec(y.clone(), prec);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_val_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_val(y.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_ref_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_assign(y.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_assign_ref(&y, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-3.141",
+        "-0x3.24#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "-1.57",
+        "-0x1.920#10",
+        Ordering::Greater,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "0.4639",
+        "0x0.76c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "0.4636476090008061162142562314613",
+        "0x0.76b19c1586ed3da2b7f222f660#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.356194490192344928846982537459",
+        "0x2.5b2f8fe6643a469e4e5327a28#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "2.285",
+        "0x2.49#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "2.284080867233946966429167082179",
+        "0x2.48b98612306a2e79cb49e648c#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.857",
+        "-0x0.db8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.8575117863558462720334763011",
+        "-0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-2.285",
+        "-0x2.49#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-2.284080867233946966429167082179",
+        "-0x2.48b98612306a2e79cb49e648c#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.1107",
+        "0x0.1c58#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.1106572211738956587445145327301",
+        "0x0.1c54081a021430c75f2f0422ae#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.57079632679489661923132169164",
+        "0x1.921fb54442d18469898cc5170#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.570796326794896619231321691637",
+        "0x1.921fb54442d18469898cc516c#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.78539816339744830961566084582",
+        "0x0.c90fdaa22168c234c4c6628b8#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_atan2_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_round(y.clone(), rm);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_round_val_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_round_ref_val(y.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_round_ref_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_round_assign(y.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_round_assign_ref(&y, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.8575117863558464",
+        "0x0.db85e4765538e0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.8575117863558462",
+        "0x0.db85e4765538d8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "2.2840808672339472",
+        "0x2.48b98612306a4#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "2.2840808672339472",
+        "0x2.48b98612306a4#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "2.2840808672339468",
+        "0x2.48b98612306a2#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.8575117863558464",
+        "-0x0.db85e4765538e0#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.8575117863558464",
+        "-0x0.db85e4765538e0#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.8575117863558462",
+        "-0x0.db85e4765538d8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-2.2840808672339472",
+        "-0x2.48b98612306a4#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-2.2840808672339472",
+        "-0x2.48b98612306a4#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-2.2840808672339468",
+        "-0x2.48b98612306a2#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.7853981633974484",
+        "0x0.c90fdaa22168c8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.7853981633974483",
+        "0x0.c90fdaa22168c0#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.11065722117389565",
+        "0x0.1c54081a021430#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.11065722117389566",
+        "0x0.1c54081a021431#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.11065722117389565",
+        "0x0.1c54081a021430#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "0.11065722117389566",
+        "0x0.1c54081a021431#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.11065722117389566",
+        "0x0.1c54081a021431#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.5707963267948968",
+        "0x1.921fb54442d19#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.5707963267948968",
+        "0x1.921fb54442d19#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_atan2_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (atan2, o) = x.clone().atan2_prec_round(y.clone(), prec, rm);
+        assert!(atan2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(atan2.to_string(), out);
+        assert_eq!(to_hex_string(&atan2), out_hex);
+
+        let (atan2_alt, o_alt) = x.clone().atan2_prec_round_val_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (atan2_alt, o_alt) = x.atan2_prec_round_ref_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign(y.clone(), prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut atan2_alt = x.clone();
+        let o_alt = atan2_alt.atan2_prec_round_assign_ref(&y, prec, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2), ComparableFloatRef(&atan2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) = rug_atan2_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.4634",
+        "0x0.76a#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.4639",
+        "0x0.76c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "0.4634",
+        "0x0.76a#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "0.4639",
+        "0x0.76c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.4639",
+        "0x0.76c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.359",
+        "0x2.5c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.359",
+        "0x2.5c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.355",
+        "0x2.5b#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.858",
+        "0x0.dbc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.858",
+        "0x0.dbc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.857",
+        "0x0.db8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.8575117863558462720334763011007",
+        "0x0.db85e4765538da5947cfa3e55#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Up,
+        "0.8575117863558462720334763011007",
+        "0x0.db85e4765538da5947cfa3e55#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "0.8575117863558462720334763011",
+        "0x0.db85e4765538da5947cfa3e54#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "2.281",
+        "0x2.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.285",
+        "0x2.49#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "2.281",
+        "0x2.48#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "2.285",
+        "0x2.49#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "2.285",
+        "0x2.49#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.858",
+        "-0x0.dbc#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.857",
+        "-0x0.db8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.857",
+        "-0x0.db8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.858",
+        "-0x0.dbc#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.857",
+        "-0x0.db8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-2.285",
+        "-0x2.49#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-2.281",
+        "-0x2.48#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-2.281",
+        "-0x2.48#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-2.285",
+        "-0x2.49#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-2.285",
+        "-0x2.49#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.1106",
+        "0x0.1c50#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.1107",
+        "0x0.1c58#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.1106",
+        "0x0.1c50#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.1107",
+        "0x0.1c58#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.1107",
+        "0x0.1c58#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.11065722117389565874451453273",
+        "0x0.1c54081a021430c75f2f0422ac#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.1106572211738956587445145327301",
+        "0x0.1c54081a021430c75f2f0422ae#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "0.11065722117389565874451453273",
+        "0x0.1c54081a021430c75f2f0422ac#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "0.1106572211738956587445145327301",
+        "0x0.1c54081a021430c75f2f0422ae#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.1106572211738956587445145327301",
+        "0x0.1c54081a021430c75f2f0422ae#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.572",
+        "0x1.928#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.57",
+        "0x1.920#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "0.786",
+        "0x0.c94#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "0.785",
+        "0x0.c90#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn atan2_prec_fail() {
+    assert_panic!(Float::NAN.atan2_prec(Float::NAN, 0));
+    assert_panic!(Float::ONE.atan2_prec(Float::TWO, 0));
+}
+
+#[test]
+fn atan2_prec_val_ref_fail() {
+    assert_panic!(Float::NAN.atan2_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.atan2_prec_val_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn atan2_prec_ref_val_fail() {
+    assert_panic!(Float::NAN.atan2_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::ONE.atan2_prec_ref_val(Float::TWO, 0));
+}
+
+#[test]
+fn atan2_prec_ref_ref_fail() {
+    assert_panic!(Float::NAN.atan2_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.atan2_prec_ref_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn atan2_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn atan2_round_fail() {
+    assert_panic!(Float::ONE.atan2_round(Float::TWO, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_val_ref(&Float::TWO, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_val(Float::TWO, RoundingMode::Exact));
+    assert_panic!(Float::ONE.atan2_round_ref_ref(&Float::TWO, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign(Float::TWO, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.atan2_round_assign_ref(&Float::TWO, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn atan2_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).atan2_prec_round(Float::two_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).atan2_prec_round(Float::ONE, 1, RoundingMode::Exact));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_val_ref(&Float::ONE, 1, RoundingMode::Exact));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_ref_val(Float::ONE, 1, RoundingMode::Exact));
+    assert_panic!(Float::one_prec(1).atan2_prec_round_ref_ref(&Float::ONE, 1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan2_prec_round_assign(Float::ONE, 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.atan2_prec_round_assign_ref(&Float::ONE, 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_prec_round_properties_helper(y: Float, x: Float, prec: u64, rm: RoundingMode) {
+    let (atan2, o) = y.clone().atan2_prec_round(x.clone(), prec, rm);
+    assert!(atan2.is_valid());
+
+    let (atan2_alt, o_alt) = y.clone().atan2_prec_round_val_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(x.clone(), prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_round_assign(x.clone(), prec, rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    let mut y_alt = y.clone();
+    let o_alt = y_alt.atan2_prec_round_assign_ref(&x, prec, rm);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+    assert_eq!(o_alt, o);
+
+    if !y.is_nan() && !x.is_nan() {
+        assert_eq!(atan2.is_sign_positive(), y.is_sign_positive());
+        if (y != 0u32 || x.is_sign_negative()) && (y.is_infinite() || x != Float::INFINITY) {
+            assert!(atan2.is_normal());
+            assert_eq!(atan2.get_prec(), Some(prec));
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_atan2, rug_o) = rug_atan2_prec_round(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (s, oo) = y.atan2_prec_round_ref_ref(&x, prec, rm);
+            assert_eq!(ComparableFloat(s), ComparableFloat(atan2.clone()));
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(y.atan2_prec_round_ref_ref(&x, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn atan2_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_7().test_properties(|(y, x, prec, rm)| {
+        atan2_prec_round_properties_helper(y, x, prec, rm);
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_20().test_properties(|(y, prec, rm)| {
+        let (atan2, o) = y.atan2_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (atan2, o) = Float::NAN.atan2_prec_round_val_ref(&y, prec, rm);
+        assert!(atan2.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_val(Float::ONE, prec, rm);
+        let (atan, o) = y.atan_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloat(atan2_alt), ComparableFloat(atan));
+        assert_eq!(o_alt, o);
+    });
+}
+
+#[test]
+fn atan2_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(y, x, prec)| {
+        let (atan2, o) = y.clone().atan2_prec(x.clone(), prec);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = y.clone().atan2_prec_val_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_val(x.clone(), prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_ref_ref(&x, prec);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_assign(x.clone(), prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_prec_assign_ref(&x, prec);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_prec_round_ref_ref(&x, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (rug_atan2, rug_o) = rug_atan2_prec(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_atan2)),
+            ComparableFloatRef(&atan2)
+        );
+        assert_eq!(rug_o, o);
+
+        if !y.is_nan() && !x.is_nan() {
+            let (atan2_alt, o_alt) = (-&y).atan2_prec_ref_ref(&x, prec);
+            assert_eq!(ComparableFloat(-atan2_alt), ComparableFloat(atan2));
+            assert_eq!(o_alt.reverse(), o);
+        }
+    });
+}
+
+#[test]
+fn atan2_round_properties() {
+    float_float_rounding_mode_triple_gen_var_5().test_properties(|(y, x, rm)| {
+        let (atan2, o) = y.clone().atan2_round(x.clone(), rm);
+        assert!(atan2.is_valid());
+
+        let (atan2_alt, o_alt) = y.clone().atan2_round_val_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_val(x.clone(), rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) = y.atan2_round_ref_ref(&x, rm);
+        assert!(atan2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_round_assign(x.clone(), rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let mut y_alt = y.clone();
+        let o_alt = y_alt.atan2_round_assign_ref(&x, rm);
+        assert!(y_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        let (atan2_alt, o_alt) =
+            y.atan2_prec_round_ref_ref(&x, max(y.significant_bits(), x.significant_bits()), rm);
+        assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_atan2, rug_o) =
+                rug_atan2_round(&rug::Float::exact_from(&y), &rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_atan2)),
+                ComparableFloatRef(&atan2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn atan2_properties_helper(y: Float, x: Float) {
+    let atan2 = y.clone().atan2(x.clone());
+    assert!(atan2.is_valid());
+
+    let atan2_alt = y.clone().atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let atan2_alt = (&y).atan2(x.clone());
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let atan2_alt = (&y).atan2(&x);
+    assert!(atan2_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    let mut y_alt = y.clone();
+    y_alt.atan2_assign(x.clone());
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+
+    let mut y_alt = y.clone();
+    y_alt.atan2_assign(&x);
+    assert!(y_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&y_alt), ComparableFloatRef(&atan2));
+
+    let atan2_alt = y
+        .atan2_prec_round_ref_ref(
+            &x,
+            max(y.significant_bits(), x.significant_bits()),
+            RoundingMode::Nearest,
+        )
+        .0;
+    assert_eq!(ComparableFloatRef(&atan2_alt), ComparableFloatRef(&atan2));
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_atan2(
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&x)
+        ))),
+        ComparableFloatRef(&atan2)
+    );
+
+    assert_eq!(ComparableFloat(-(-&y).atan2(&x)), ComparableFloat(atan2));
+}
+
+#[test]
+fn atan2_properties() {
+    float_pair_gen().test_properties(|(y, x)| {
+        atan2_properties_helper(y, x);
+    });
+
+    float_gen().test_properties(|y| {
+        assert!((&y).atan2(Float::NAN).is_nan());
+        assert!(Float::NAN.atan2(&y).is_nan());
+        if !y.is_nan() {
+            assert_eq!(
+                ComparableFloat((&y).atan2(Float::ONE)),
+                ComparableFloat(
+                    y.atan_prec_round_ref(y.significant_bits(), RoundingMode::Nearest)
+                        .0
+                )
+            );
+        }
+    });
+
+    assert!(Float::NAN.atan2(Float::ONE).is_nan());
+    assert!(Float::ONE.atan2(Float::NAN).is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ZERO.atan2(Float::ONE)),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.atan2(Float::ONE)),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+    assert!(Float::ZERO.atan2(Float::NEGATIVE_ONE) > 3u32);
+    assert!(Float::NEGATIVE_ZERO.atan2(Float::NEGATIVE_ONE) < -3i32);
+    assert!(Float::ONE.atan2(Float::ZERO) > 1u32);
+    assert!(Float::NEGATIVE_ONE.atan2(Float::ZERO) < -1i32);
+    assert_eq!(
+        ComparableFloat(Float::ONE.atan2(Float::INFINITY)),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ONE.atan2(Float::INFINITY)),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
@@ -0,0 +1,2103 @@
+use malachite_base::num::arithmetic::traits::{Cos, CosAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::cos::{
+    rug_cos, rug_cos_prec, rug_cos_prec_round, rug_cos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_20, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_16,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_cos() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let cos = x.clone().cos();
+        assert!(cos.is_valid());
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let cos_alt = (&x).cos();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        let mut cos_alt = x.clone();
+        cos_alt.cos_assign();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cos)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "0.5", "0x0.8#1");
+    test("-1.0", "-0x1.0#1", "0.5", "0x0.8#1");
+    test("1.0", "0x1.000#10", "0.54", "0x0.8a4#10");
+    test("3.0", "0x3.0#2", "-1.0", "-0x1.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-1.0",
+        "-0x1.0000000000000#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("1.0e30", "0x1.0E+25#1", "0.5", "0x0.8#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "-0.9576594803233851",
+        "-0x0.f5292bf4b17bc0#53",
+    );
+    test("3.0e120", "0x1.0E+100#1", "-1.0", "-0x1.0#1");
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        "6.123233995736766e-17",
+        "0x4.69898cc51701cE-14#53",
+    );
+}
+
+#[test]
+fn test_cos_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_assign(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("1.0", "0x1.0#1", 10, "0.54", "0x0.8a4#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("-1.0", "-0x1.0#1", 10, "0.54", "0x0.8a4#10", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "0.5403023058681397174009366074433",
+        "0x0.8a51407da8345c91c2466d977#100",
+        Ordering::Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test("3.0", "0x3.0#2", 10, "-0.99", "-0x0.fd8#10", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "-0.9899924966004454572715727947315",
+        "-0x0.fd7025f42f2e9307dff82fdf7#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.9117339147869650385092477381996",
+        "-0x0.e96764d2aa0034ce60b62d755#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.9449569463147376522796478103984",
+        "0x0.f1e8b2cc8cc1648c0c2de8f4d#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "0.4891786569747214499057893087514",
+        "0x0.7d3acffd9b8db34b71d86c7ff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "-0.958",
+        "-0x0.f54#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "-0.957659480323385153318662876654",
+        "-0x0.f5292bf4b17bc3901e50b26de#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        "-0.992",
+        "-0x0.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        100,
+        "-0.9917978877888190980222269173873",
+        "-0x0.fde677644b7bab1e22fc63e1f#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        1,
+        "6.0e-17",
+        "0x4.0E-14#1",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        "6.13e-17",
+        "0x4.6aE-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        100,
+        "6.123233995736765886130329661374e-17",
+        "0x4.69898cc51701b839a252049c0E-14#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_cos_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_round_assign(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "-0.8",
+        "-0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "-0.8",
+        "-0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-0.9999999999999999",
+        "-0x0.fffffffffffff8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.911733914786965",
+        "-0x0.e96764d2aa0030#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.9117339147869651",
+        "-0x0.e96764d2aa0038#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.9449569463147376",
+        "0x0.f1e8b2cc8cc160#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.9449569463147377",
+        "0x0.f1e8b2cc8cc168#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "-0.9576594803233852",
+        "-0x0.f5292bf4b17bc8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "-0.9576594803233851",
+        "-0x0.f5292bf4b17bc0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "-0.9576594803233851",
+        "-0x0.f5292bf4b17bc0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "-0.9576594803233852",
+        "-0x0.f5292bf4b17bc8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "-0.9576594803233851",
+        "-0x0.f5292bf4b17bc0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Floor,
+        "6.123233995736765e-17",
+        "0x4.69898cc517018E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Ceiling,
+        "6.123233995736766e-17",
+        "0x4.69898cc51701cE-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Down,
+        "6.123233995736765e-17",
+        "0x4.69898cc517018E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Up,
+        "6.123233995736766e-17",
+        "0x4.69898cc51701cE-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Nearest,
+        "6.123233995736766e-17",
+        "0x4.69898cc51701cE-14#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_cos_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cos.to_string(), out);
+        assert_eq!(to_hex_string(&cos), out_hex);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cos_alt = x.clone();
+        let o_alt = cos_alt.cos_prec_round_assign(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "-0.99",
+        "-0x0.fd8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "-0.989",
+        "-0x0.fd4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "-0.989",
+        "-0x0.fd4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "-0.99",
+        "-0x0.fd8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "-0.99",
+        "-0x0.fd8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "-0.9899924966004454572715727947315",
+        "-0x0.fd7025f42f2e9307dff82fdf7#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "-0.989992496600445457271572794731",
+        "-0x0.fd7025f42f2e9307dff82fdf6#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "-0.989992496600445457271572794731",
+        "-0x0.fd7025f42f2e9307dff82fdf6#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "-0.9899924966004454572715727947315",
+        "-0x0.fd7025f42f2e9307dff82fdf7#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "-0.9899924966004454572715727947315",
+        "-0x0.fd7025f42f2e9307dff82fdf7#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.911",
+        "-0x0.e94#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.911",
+        "-0x0.e94#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.944",
+        "0x0.f1c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.944",
+        "0x0.f1c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.945",
+        "0x0.f20#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "0.4888",
+        "0x0.7d2#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "0.4888",
+        "0x0.7d2#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "0.4893",
+        "0x0.7d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "-0.958",
+        "-0x0.f54#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "-0.958",
+        "-0x0.f54#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.958",
+        "-0x0.f54#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Floor,
+        "-0.992",
+        "-0x0.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.991",
+        "-0x0.fdc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Down,
+        "-0.991",
+        "-0x0.fdc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Up,
+        "-0.992",
+        "-0x0.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.992",
+        "-0x0.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Floor,
+        "6.11e-17",
+        "0x4.68E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Ceiling,
+        "6.13e-17",
+        "0x4.6aE-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Down,
+        "6.11e-17",
+        "0x4.68E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Up,
+        "6.13e-17",
+        "0x4.6aE-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Nearest,
+        "6.13e-17",
+        "0x4.6aE-14#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn cos_prec_fail() {
+    assert_panic!(Float::NAN.cos_prec(0));
+    assert_panic!(Float::ONE.cos_prec(0));
+}
+
+#[test]
+fn cos_prec_ref_fail() {
+    assert_panic!(Float::NAN.cos_prec_ref(0));
+    assert_panic!(Float::ONE.cos_prec_ref(0));
+}
+
+#[test]
+fn cos_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cos_prec_assign(0)
+    });
+}
+
+#[test]
+fn cos_round_fail() {
+    assert_panic!(Float::ONE.cos_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.cos_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cos_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn cos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).cos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.cos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.cos_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cos_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_16().test_properties(|(x, prec, rm)| {
+        let (cos, o) = x.clone().cos_prec_round(prec, rm);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(cos.is_normal());
+            assert_eq!(cos.get_prec(), Some(prec));
+            assert!(cos <= 1u32 && cos >= -1i32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.cos_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(cos.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.cos_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (cos, o) = x.clone().cos_prec(prec);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_prec_ref(prec);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let (rug_cos, rug_o) = rug_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn cos_round_properties() {
+    float_rounding_mode_pair_gen_var_20().test_properties(|(x, rm)| {
+        let (cos, o) = x.clone().cos_round(rm);
+        assert!(cos.is_valid());
+
+        let (cos_alt, o_alt) = x.cos_round_ref(rm);
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cos_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        let (cos_alt, o_alt) = x.cos_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cos, rug_o) = rug_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn cos_properties() {
+    float_gen().test_properties(|x| {
+        let cos = x.clone().cos();
+        assert!(cos.is_valid());
+
+        let cos_alt = (&x).cos();
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+        let mut x_alt = x.clone();
+        x_alt.cos_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cos));
+
+        let cos_alt = x
+            .cos_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cos)
+        );
+    });
+
+    assert!(Float::NAN.cos().is_nan());
+    assert!(Float::INFINITY.cos().is_nan());
+    assert!(Float::NEGATIVE_INFINITY.cos().is_nan());
+    assert_eq!(Float::ZERO.cos(), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ZERO.cos(), Float::ONE);
+}
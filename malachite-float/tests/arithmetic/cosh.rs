@@ -0,0 +1,2016 @@
+use malachite_base::num::arithmetic::traits::{Cosh, CoshAssign, PowerOf2};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::cosh::{
+    rug_cosh, rug_cosh_prec, rug_cosh_prec_round, rug_cosh_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_26, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_22,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_cosh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let cosh = x.clone().cosh();
+        assert!(cosh.is_valid());
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let cosh_alt = (&x).cosh();
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+
+        let mut cosh_alt = x.clone();
+        cosh_alt.cosh_assign();
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cosh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "Infinity", "Infinity");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "2.0", "0x2.0#1");
+    test("1.0", "0x1.000#10", "1.543", "0x1.8b0#10");
+    test("3.0", "0x3.0#2", "1.0e1", "0xc.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("100.0", "0x64.0#7", "1.34e43", "0x9.aE+35#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "4443055.2602539845",
+        "0x43cbaf.42a00150#53",
+    );
+    test("-100.0", "-0x64.0#7", "1.34e43", "0x9.aE+35#7");
+}
+
+#[test]
+fn test_cosh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec(prec);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_assign(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_cosh, rug_o) = rug_cosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "1.543", "0x1.8b0#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.543080634815243778477905620758",
+        "0x1.8b07551d9f5504c2bd281001a#100",
+        Ordering::Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test("3.0", "0x3.0#2", 10, "10.06", "0xa.10#10", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "10.06766199577776584195393603512",
+        "0xa.11524beb0c2f251cb107aa83#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "11.59195327552151921343908324729",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "11.59195327552151921343908324729",
+        "0xb.978a3ff7200027b1b4b07db3#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "7.61",
+        "0x7.9c#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "7.610125138662287272802774883445",
+        "0x7.9c31293d05a112a04c47df6f8#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "1.056071867829939402092389523592",
+        "0x1.0e5ab9d68e232cb1ed85c0c40#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "1.0e43",
+        "0x8.0E+35#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "1.34405857090806772420631277579e43",
+        "0x9.a4a54d8b8dfa566112849991E+35#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "4.0e6",
+        "0x4.0E+5#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "4443055.260253984693517272114713",
+        "0x43cbaf.42a00150e98f328050a0#100",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        1,
+        "1.0e43",
+        "0x8.0E+35#1",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        100,
+        "1.34405857090806772420631277579e43",
+        "0x9.a4a54d8b8dfa566112849991E+35#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_cosh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_round(rm);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_round_assign(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "11.59195327552152",
+        "0xb.978a3ff720008#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "11.591953275521519",
+        "0xb.978a3ff720000#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "7.610125138662288",
+        "0x7.9c31293d05a14#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "7.610125138662288",
+        "0x7.9c31293d05a14#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "7.610125138662287",
+        "0x7.9c31293d05a10#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.0560718678299392",
+        "0x1.0e5ab9d68e232#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.0560718678299392",
+        "0x1.0e5ab9d68e232#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.0560718678299394",
+        "0x1.0e5ab9d68e233#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "4443055.2602539845",
+        "0x43cbaf.42a00150#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "4443055.260253985",
+        "0x43cbaf.42a00154#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "4443055.2602539845",
+        "0x43cbaf.42a00150#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "4443055.260253985",
+        "0x43cbaf.42a00154#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "4443055.2602539845",
+        "0x43cbaf.42a00150#53",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Floor,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Ceiling,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Down,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Up,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Nearest,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_cosh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+        assert!(cosh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(cosh.to_string(), out);
+        assert_eq!(to_hex_string(&cosh), out_hex);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut cosh_alt = x.clone();
+        let o_alt = cosh_alt.cosh_prec_round_assign(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh), ComparableFloatRef(&cosh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.545",
+        "0x1.8b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.543",
+        "0x1.8b0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "10.06",
+        "0xa.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "10.08",
+        "0xa.14#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "10.06",
+        "0xa.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "10.08",
+        "0xa.14#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "10.06",
+        "0xa.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "10.06766199577776584195393603512",
+        "0xa.11524beb0c2f251cb107aa83#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "10.06766199577776584195393603513",
+        "0xa.11524beb0c2f251cb107aa84#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "10.06766199577776584195393603512",
+        "0xa.11524beb0c2f251cb107aa83#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "10.06766199577776584195393603513",
+        "0xa.11524beb0c2f251cb107aa84#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "10.06766199577776584195393603512",
+        "0xa.11524beb0c2f251cb107aa83#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "11.58",
+        "0xb.94#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "11.58",
+        "0xb.94#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "11.58",
+        "0xb.94#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "11.58",
+        "0xb.94#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "11.59",
+        "0xb.98#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "7.61",
+        "0x7.9c#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "7.617",
+        "0x7.9e#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "7.61",
+        "0x7.9c#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "7.617",
+        "0x7.9e#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "7.61",
+        "0x7.9c#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.055",
+        "0x1.0e0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.057",
+        "0x1.0e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "4.45e6",
+        "0x4.3eE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "4.45e6",
+        "0x4.3eE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn cosh_prec_fail() {
+    assert_panic!(Float::NAN.cosh_prec(0));
+    assert_panic!(Float::ONE.cosh_prec(0));
+}
+
+#[test]
+fn cosh_prec_ref_fail() {
+    assert_panic!(Float::NAN.cosh_prec_ref(0));
+    assert_panic!(Float::ONE.cosh_prec_ref(0));
+}
+
+#[test]
+fn cosh_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cosh_prec_assign(0)
+    });
+}
+
+#[test]
+fn cosh_round_fail() {
+    assert_panic!(Float::ONE.cosh_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.cosh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cosh_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn cosh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).cosh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.cosh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.cosh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.cosh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn cosh_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).cosh());
+    assert_panic!(Float::power_of_2(100i64).cosh_prec(10));
+    assert_panic!(Float::power_of_2(100i64).cosh_round(RoundingMode::Floor));
+}
+
+#[test]
+fn cosh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_22().test_properties(|(x, prec, rm)| {
+        let (cosh, o) = x.clone().cosh_prec_round(prec, rm);
+        assert!(cosh.is_valid());
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cosh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(cosh.is_normal());
+            assert_eq!(cosh.get_prec(), Some(prec));
+            assert!(cosh >= 1u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.cosh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(cosh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.cosh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn cosh_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (cosh, o) = x.clone().cosh_prec(prec);
+        assert!(cosh.is_valid());
+
+        let (cosh_alt, o_alt) = x.cosh_prec_ref(prec);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cosh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let (rug_cosh, rug_o) = rug_cosh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh)),
+            ComparableFloatRef(&cosh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn cosh_round_properties() {
+    float_rounding_mode_pair_gen_var_26().test_properties(|(x, rm)| {
+        let (cosh, o) = x.clone().cosh_round(rm);
+        assert!(cosh.is_valid());
+
+        let (cosh_alt, o_alt) = x.cosh_round_ref(rm);
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.cosh_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        let (cosh_alt, o_alt) = x.cosh_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_cosh, rug_o) = rug_cosh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cosh)),
+                ComparableFloatRef(&cosh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn cosh_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let cosh = x.clone().cosh();
+        assert!(cosh.is_valid());
+
+        let cosh_alt = (&x).cosh();
+        assert!(cosh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+
+        let mut x_alt = x.clone();
+        x_alt.cosh_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&cosh));
+
+        let cosh_alt = x
+            .cosh_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&cosh_alt), ComparableFloatRef(&cosh));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cosh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&cosh)
+        );
+    });
+
+    assert!(Float::NAN.cosh().is_nan());
+    assert_eq!(Float::INFINITY.cosh(), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_INFINITY.cosh(), Float::INFINITY);
+    assert_eq!(Float::ZERO.cosh(), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ZERO.cosh(), Float::ONE);
+}
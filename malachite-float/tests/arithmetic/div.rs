@@ -0,0 +1,3823 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::div::{
+    div_prec_round_naive, rug_div, rug_div_prec, rug_div_prec_round, rug_div_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_4,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_4, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_div() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let quotient = x.clone() / y.clone();
+        assert!(quotient.is_valid());
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let quotient_alt = x.clone() / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let quotient_alt = &x / &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        let mut quotient_alt = x.clone();
+        quotient_alt /= y.clone();
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        let mut quotient_alt = x.clone();
+        quotient_alt /= &y;
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_div(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&quotient)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "Infinity", "Infinity", "0.0", "0x0.0", "Infinity", "Infinity",
+    );
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-Infinity", "-Infinity");
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test("-1.0", "-0x1.0#1", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "-Infinity", "-Infinity");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "0.0", "0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "-0.0", "-0x0.0");
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("3.0", "0x3.0#2", "3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0000000000000#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "0.11111111111111112",
+        "0x0.1c71c71c71c71d#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "4.0350571827946165e29",
+        "0x5.17cc1b727220cE+24#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+}
+
+#[test]
+fn test_div_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_assign_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_quotient, rug_o) = rug_div_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-1.155727349790921734322182875179",
+        "-0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-1.155727349790921734322182875179",
+        "-0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.1111111111111111234469224958351",
+        "0x0.1c71c71c71c71d555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "3.0e29",
+        "0x4.0E+24#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "4.036e29",
+        "0x5.18E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "403505718279461645592810442158.5",
+        "0x517cc1b727220b79e27534dae.8#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_div_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_round_assign_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) =
+                rug_div_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-1.155727349790922",
+        "-0x1.27ddbf6271dbf#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-1.155727349790922",
+        "-0x1.27ddbf6271dbf#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-1.155727349790922",
+        "-0x1.27ddbf6271dbf#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-1.155727349790922",
+        "-0x1.27ddbf6271dbf#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-1.1557273497909217",
+        "-0x1.27ddbf6271dbe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "1.155727349790922",
+        "0x1.27ddbf6271dbf#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "1.1557273497909217",
+        "0x1.27ddbf6271dbe#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.11111111111111112",
+        "0x0.1c71c71c71c71d#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.11111111111111113",
+        "0x0.1c71c71c71c71e#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.11111111111111112",
+        "0x0.1c71c71c71c71d#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "0.11111111111111113",
+        "0x0.1c71c71c71c71e#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.11111111111111112",
+        "0x0.1c71c71c71c71d#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "4.035057182794616e29",
+        "0x5.17cc1b7272208E+24#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "4.0350571827946165e29",
+        "0x5.17cc1b727220cE+24#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "4.035057182794616e29",
+        "0x5.17cc1b7272208E+24#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "4.0350571827946165e29",
+        "0x5.17cc1b727220cE+24#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "4.0350571827946165e29",
+        "0x5.17cc1b727220cE+24#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_div_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+        assert!(quotient.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(quotient.to_string(), out);
+        assert_eq!(to_hex_string(&quotient), out_hex);
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign(y.clone(), prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut quotient_alt = x.clone();
+        let o_alt = quotient_alt.div_prec_round_assign_ref(&y, prec, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient),
+            ComparableFloatRef(&quotient_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) = rug_div_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.154",
+        "0x1.278#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "1.154",
+        "0x1.278#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "1.155727349790921734322182875177",
+        "0x1.27ddbf6271dbe02c839f8eb68#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "1.155727349790921734322182875177",
+        "0x1.27ddbf6271dbe02c839f8eb68#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Up,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "1.155727349790921734322182875179",
+        "0x1.27ddbf6271dbe02c839f8eb6a#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.154",
+        "-0x1.278#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-1.154",
+        "-0x1.278#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.154",
+        "-0x1.278#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-1.154",
+        "-0x1.278#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.156",
+        "-0x1.280#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "1.154",
+        "0x1.278#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "1.154",
+        "0x1.278#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "1.156",
+        "0x1.280#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.1112",
+        "0x0.1c78#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.1112",
+        "0x0.1c78#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.1111",
+        "0x0.1c70#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.111111111111111123446922495835",
+        "0x0.1c71c71c71c71d555555555554#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.1111111111111111234469224958351",
+        "0x0.1c71c71c71c71d555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "0.111111111111111123446922495835",
+        "0x0.1c71c71c71c71d555555555554#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "0.1111111111111111234469224958351",
+        "0x0.1c71c71c71c71d555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.1111111111111111234469224958351",
+        "0x0.1c71c71c71c71d555555555556#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "4.03e29",
+        "0x5.16E+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "4.036e29",
+        "0x5.18E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "4.03e29",
+        "0x5.16E+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "4.036e29",
+        "0x5.18E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "4.036e29",
+        "0x5.18E+24#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn div_prec_fail() {
+    assert_panic!(Float::NAN.div_prec(Float::NAN, 0));
+    assert_panic!(Float::ONE.div_prec(Float::TWO, 0));
+}
+
+#[test]
+fn div_prec_val_ref_fail() {
+    assert_panic!(Float::NAN.div_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.div_prec_val_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn div_prec_ref_val_fail() {
+    assert_panic!(Float::NAN.div_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::ONE.div_prec_ref_val(Float::TWO, 0));
+}
+
+#[test]
+fn div_prec_ref_ref_fail() {
+    assert_panic!(Float::NAN.div_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.div_prec_ref_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn div_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn div_round_fail() {
+    let three = Float::from(3u32);
+    assert_panic!(Float::ONE.div_round(three.clone(), RoundingMode::Exact));
+    assert_panic!(Float::ONE.div_round_val_ref(&three, RoundingMode::Exact));
+    assert_panic!(Float::ONE.div_round_ref_val(three.clone(), RoundingMode::Exact));
+    assert_panic!(Float::ONE.div_round_ref_ref(&three, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_round_assign(three.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.div_round_assign_ref(&three, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn div_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).div_prec_round(Float::two_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).div_prec_round(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).div_prec_round_val_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).div_prec_round_ref_val(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).div_prec_round_ref_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.div_prec_round_assign(Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.div_prec_round_assign_ref(&Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_prec_round_properties_helper(x: Float, y: Float, prec: u64, rm: RoundingMode) {
+    let (quotient, o) = x.clone().div_prec_round(y.clone(), prec, rm);
+    assert!(quotient.is_valid());
+
+    let (quotient_alt, o_alt) = x.clone().div_prec_round_val_ref(&y, prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, rm);
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.div_prec_round_assign(y.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.div_prec_round_assign_ref(&y, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() && y != 0u32 {
+        let (quotient_alt, o_alt) = div_prec_round_naive(x.clone(), y.clone(), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+        if quotient.is_normal() {
+            assert_eq!(quotient.get_prec(), Some(prec));
+            assert_eq!(
+                quotient.partial_cmp(&(Rational::exact_from(&x) / Rational::exact_from(&y))),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_quotient, rug_o) = rug_div_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (quotient_alt, o_alt) = (-&x).div_prec_round_val_ref(&y, prec, -rm);
+    assert_eq!(
+        ComparableFloat(-quotient_alt),
+        ComparableFloat(quotient.clone())
+    );
+    assert_eq!(o_alt.reverse(), o);
+
+    let (quotient_alt, o_alt) = x.div_prec_round_ref_val(-&y, prec, -rm);
+    assert_eq!(
+        ComparableFloat(-quotient_alt),
+        ComparableFloat(quotient.clone())
+    );
+    assert_eq!(o_alt.reverse(), o);
+
+    let (quotient_alt, o_alt) = (-&x).div_prec_round(-&y, prec, rm);
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+    assert_eq!(o_alt, o);
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (p, oo) = x.div_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(ComparableFloat(p), ComparableFloat(quotient.clone()));
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.div_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn div_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_4().test_properties(|(x, y, prec, rm)| {
+        div_prec_round_properties_helper(x, y, prec, rm);
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (quotient, o) = x.div_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(quotient.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (quotient, o) = Float::NAN.div_prec_round_val_ref(&x, prec, rm);
+        assert!(quotient.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if !x.is_nan() {
+            let (quotient_alt, o_alt) = x.div_prec_round_ref_val(Float::ONE, prec, rm);
+            let mut x_alt = x.clone();
+            let o = x_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(quotient_alt), ComparableFloat(x_alt));
+            assert_eq!(o_alt, o);
+
+            let (quotient_alt, o_alt) = x.div_prec_round_ref_val(Float::NEGATIVE_ONE, prec, rm);
+            let mut x_alt = -&x;
+            let o = x_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(quotient_alt), ComparableFloat(x_alt));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[test]
+fn div_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (quotient, o) = x.clone().div_prec(y.clone(), prec);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_prec_val_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_val(y.clone(), prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_ref_ref(&y, prec);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (rug_quotient, rug_o) = rug_div_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_quotient)),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn div_round_properties() {
+    float_float_rounding_mode_triple_gen_var_4().test_properties(|(x, y, rm)| {
+        let (quotient, o) = x.clone().div_round(y.clone(), rm);
+        assert!(quotient.is_valid());
+
+        let (quotient_alt, o_alt) = x.clone().div_round_val_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_val(y.clone(), rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) = x.div_round_ref_ref(&y, rm);
+        assert!(quotient_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.div_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+        assert_eq!(o_alt, o);
+
+        let (quotient_alt, o_alt) =
+            x.div_prec_round_ref_ref(&y, max(x.significant_bits(), y.significant_bits()), rm);
+        assert_eq!(
+            ComparableFloatRef(&quotient_alt),
+            ComparableFloatRef(&quotient)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_quotient, rug_o) =
+                rug_div_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_quotient)),
+                ComparableFloatRef(&quotient)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn div_properties_helper(x: Float, y: Float) {
+    let quotient = x.clone() / y.clone();
+    assert!(quotient.is_valid());
+
+    let quotient_alt = x.clone() / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / y.clone();
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let quotient_alt = &x / &y;
+    assert!(quotient_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt /= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let mut x_alt = x.clone();
+    x_alt /= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&quotient));
+
+    let quotient_alt = x
+        .div_prec_round_ref_ref(
+            &y,
+            max(x.significant_bits(), y.significant_bits()),
+            RoundingMode::Nearest,
+        )
+        .0;
+    assert_eq!(
+        ComparableFloatRef(&quotient_alt),
+        ComparableFloatRef(&quotient)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_div(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y)
+        ))),
+        ComparableFloatRef(&quotient)
+    );
+
+    assert_eq!(
+        ComparableFloat(-(-&x / &y)),
+        ComparableFloat(quotient.clone())
+    );
+    assert_eq!(
+        ComparableFloat(-(&x / -&y)),
+        ComparableFloat(quotient.clone())
+    );
+    assert_eq!(ComparableFloat(-&x / -&y), ComparableFloat(quotient));
+
+    if x.is_finite() && y.is_finite() && y != 0u32 {
+        let (product, o) = x.mul_prec_ref_ref(&y, x.significant_bits() + y.significant_bits());
+        assert_eq!(o, Ordering::Equal);
+        let (quotient, o) = product.div_prec(y, x.significant_bits());
+        assert_eq!(o, Ordering::Equal);
+        assert_eq!(ComparableFloat(quotient), ComparableFloat(x));
+    }
+}
+
+#[test]
+fn div_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        div_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x / Float::NAN).is_nan());
+        assert!((Float::NAN / &x).is_nan());
+        if !x.is_nan() {
+            assert_eq!(
+                ComparableFloatRef(&(&x / Float::ONE)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(
+                ComparableFloat(&x / Float::NEGATIVE_ONE),
+                ComparableFloat(-&x)
+            );
+        }
+        if x.is_finite() && x != 0u32 {
+            assert_eq!(
+                ComparableFloat(&x / &x),
+                ComparableFloat(Float::one_prec(x.get_prec().unwrap()))
+            );
+            assert_eq!(
+                ComparableFloat(&x / -&x),
+                ComparableFloat(-Float::one_prec(x.get_prec().unwrap()))
+            );
+        }
+        if x.is_finite() {
+            let quotient = &x / Float::INFINITY;
+            assert!(quotient.is_zero());
+            assert_eq!(quotient.is_sign_positive(), x.is_sign_positive());
+        } else if !x.is_nan() {
+            assert!((&x / Float::INFINITY).is_nan());
+            assert!((Float::NEGATIVE_INFINITY / &x).is_nan());
+        }
+        if x > 0u32 {
+            assert_eq!(&x / Float::ZERO, Float::INFINITY);
+            assert_eq!(Float::NEGATIVE_ZERO / &x, Float::NEGATIVE_ZERO);
+        }
+        if x < 0u32 {
+            assert_eq!(&x / Float::ZERO, Float::NEGATIVE_INFINITY);
+            assert_eq!(Float::NEGATIVE_ZERO / &x, Float::ZERO);
+        }
+    });
+}
@@ -0,0 +1,1916 @@
+use malachite_base::num::arithmetic::traits::{Exp, ExpAssign, PowerOf2};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::exp::{
+    rug_exp, rug_exp_prec, rug_exp_prec_round, rug_exp_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_11, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_7,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp = x.clone().exp();
+        assert!(exp.is_valid());
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let exp_alt = (&x).exp();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+
+        let mut exp_alt = x.clone();
+        exp_alt.exp_assign();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "0.2", "0x0.4#1");
+    test("1.0", "0x1.000#10", "2.719", "0x2.b8#10");
+    test("3.0", "0x3.0#2", "24.0", "0x18.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("100.0", "0x64.0#7", "2.68e43", "0x1.34E+36#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "8886110.520507857",
+        "0x87975e.854000c0#53",
+    );
+}
+
+#[test]
+fn test_exp_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_assign(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.3678794411714423215955237701614",
+        "0x0.5e2d58d8b3bcdf1abadec78290#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "2.0e1", "0x1.0E+1#1", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "20.09",
+        "0x14.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "20.08553692318766774092852965458",
+        "0x14.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "23.14069263277926617181157008936",
+        "0x17.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.03",
+        "0x0.08#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.04321",
+        "0x0.0b10#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.04321391826377225506659640520612",
+        "0x0.0b10113dacc77cca4f307226e2#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "15.16",
+        "0xf.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "15.15426224147926199898921968819",
+        "0xf.277dbaf2293cdcf6ff773788#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "1.395612425086089580276160245719",
+        "0x1.6546db1ba2d134c33c5f6384c#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "2.0e43",
+        "0x1.0E+36#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "2.68811714181613544841262555158e43",
+        "0x1.3494a9b171bf4acc225093322E+36#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "8.0e6",
+        "0x8.0E+5#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "8886110.52050785685185982497011",
+        "0x87975e.854000be7d62b61843a#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_exp_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_round_assign(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "16.0",
+        "0x10.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "24.0",
+        "0x18.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "16.0",
+        "0x10.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "24.0",
+        "0x18.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "24.0",
+        "0x18.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "23.140692632779263",
+        "0x17.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "23.140692632779267",
+        "0x17.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.04321391826377225",
+        "0x0.0b10113dacc77c8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.043213918263772258",
+        "0x0.0b10113dacc77d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "15.154262241479261",
+        "0xf.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "15.154262241479262",
+        "0xf.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.3956124250860897",
+        "0x1.6546db1ba2d14#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.3956124250860897",
+        "0x1.6546db1ba2d14#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "2.72e43",
+        "0x1.38E+36#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "2.72e43",
+        "0x1.38E+36#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "8886110.520507855",
+        "0x87975e.854000b8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "8886110.520507857",
+        "0x87975e.854000c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "8886110.520507855",
+        "0x87975e.854000b8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "8886110.520507857",
+        "0x87975e.854000c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "8886110.520507857",
+        "0x87975e.854000c0#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_exp_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp.to_string(), out);
+        assert_eq!(to_hex_string(&exp), out_hex);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp_alt = x.clone();
+        let o_alt = exp_alt.exp_prec_round_assign(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp), ComparableFloatRef(&exp_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.3682",
+        "0x0.5e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.3682",
+        "0x0.5e4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.3677",
+        "0x0.5e2#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "20.06",
+        "0x14.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "20.09",
+        "0x14.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "20.06",
+        "0x14.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "20.09",
+        "0x14.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "20.09",
+        "0x14.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "20.08553692318766774092852965458",
+        "0x14.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "20.0855369231876677409285296546",
+        "0x14.15e5bf6fb105f2d4bdfc5376#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "20.08553692318766774092852965458",
+        "0x14.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "20.0855369231876677409285296546",
+        "0x14.15e5bf6fb105f2d4bdfc5376#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "20.08553692318766774092852965458",
+        "0x14.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "23.12",
+        "0x17.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "23.12",
+        "0x17.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "23.16",
+        "0x17.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.04321",
+        "0x0.0b10#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.04327",
+        "0x0.0b14#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.04321",
+        "0x0.0b10#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.04327",
+        "0x0.0b14#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.04321",
+        "0x0.0b10#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "15.14",
+        "0xf.24#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "15.16",
+        "0xf.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "15.14",
+        "0xf.24#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "15.16",
+        "0xf.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "15.16",
+        "0xf.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "2.692e43",
+        "0x1.350E+36#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "2.692e43",
+        "0x1.350E+36#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.9e6",
+        "0x8.7cE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "8.9e6",
+        "0x8.7cE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp_prec_fail() {
+    assert_panic!(Float::NAN.exp_prec(0));
+    assert_panic!(Float::ONE.exp_prec(0));
+}
+
+#[test]
+fn exp_prec_ref_fail() {
+    assert_panic!(Float::NAN.exp_prec_ref(0));
+    assert_panic!(Float::ONE.exp_prec_ref(0));
+}
+
+#[test]
+fn exp_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp_prec_assign(0)
+    });
+}
+
+#[test]
+fn exp_round_fail() {
+    assert_panic!(Float::ONE.exp_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.exp_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.exp_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.exp_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).exp());
+    assert_panic!(Float::power_of_2(100i64).exp_prec(10));
+    assert_panic!(Float::power_of_2(100i64).exp_round(RoundingMode::Floor));
+}
+
+#[test]
+fn exp_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_7().test_properties(|(x, prec, rm)| {
+        let (exp, o) = x.clone().exp_prec_round(prec, rm);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(exp.is_normal());
+            assert_eq!(exp.get_prec(), Some(prec));
+            assert!(exp > 0u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (exp, o) = x.clone().exp_prec(prec);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_prec_ref(prec);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let (rug_exp, rug_o) = rug_exp_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp)),
+            ComparableFloatRef(&exp)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn exp_round_properties() {
+    float_rounding_mode_pair_gen_var_11().test_properties(|(x, rm)| {
+        let (exp, o) = x.clone().exp_round(rm);
+        assert!(exp.is_valid());
+
+        let (exp_alt, o_alt) = x.exp_round_ref(rm);
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        let (exp_alt, o_alt) = x.exp_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp, rug_o) = rug_exp_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp)),
+                ComparableFloatRef(&exp)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let exp = x.clone().exp();
+        assert!(exp.is_valid());
+
+        let exp_alt = (&x).exp();
+        assert!(exp_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+
+        let mut x_alt = x.clone();
+        x_alt.exp_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp));
+
+        let exp_alt = x
+            .exp_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&exp_alt), ComparableFloatRef(&exp));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp)
+        );
+    });
+
+    assert!(Float::NAN.exp().is_nan());
+    assert_eq!(Float::INFINITY.exp(), Float::INFINITY);
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_INFINITY.exp()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(Float::ZERO.exp(), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ZERO.exp(), Float::ONE);
+}
@@ -0,0 +1,2174 @@
+use malachite_base::num::arithmetic::traits::{Exp10, Exp10Assign, PowerOf2};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::exp10::{
+    rug_exp10, rug_exp10_prec, rug_exp10_prec_round, rug_exp10_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_13, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_9,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp10() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp10 = x.clone().exp10();
+        assert!(exp10.is_valid());
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let exp10_alt = (&x).exp10();
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+
+        let mut exp10_alt = x.clone();
+        exp10_alt.exp10_assign();
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp10)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "8.0", "0x8.0#1");
+    test("-1.0", "-0x1.0#1", "0.1", "0x0.2#1");
+    test("1.0", "0x1.000#10", "10.0", "0xa.00#10");
+    test("3.0", "0x3.0#2", "1.0e3", "0x4.0E+2#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("100.0", "0x64.0#7", "9.98e99", "0x1.24E+83#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "9999999999999960.0",
+        "0x2386f26fc0ffd8.0#53",
+    );
+    test("3.0", "0x3.0#2", "1.0e3", "0x4.0E+2#2");
+    test("-2.0", "-0x2.0#1", "0.008", "0x0.02#1");
+}
+
+#[test]
+fn test_exp10_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec(prec);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_assign(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp10, rug_o) = rug_exp10_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test("1.0", "0x1.0#1", 10, "10.0", "0xa.00#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.1", "0x0.2#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.1",
+        "0x0.1999999999999999999999999a#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1385.455731367010698462705619402",
+        "0x569.74aacf951283c59a6deee40#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.0005",
+        "0x0.002#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.000722",
+        "0x0.002f5#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.000721784159074727947145550098821",
+        "0x0.002f4d875ba5adf20a2136d311e0#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "5.0e2",
+        "0x2.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "523.0",
+        "0x20b.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "522.7352996704364809010943192324",
+        "0x20a.bc3c996548c57e66a44d548#100",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "2.154434690031883905344633130149",
+        "0x2.278908270e09e68bd19bfe838#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "9.0e99",
+        "0x1.0E+83#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "1.0e100",
+        "0x1.249ad2594c37ceb0b2784c4ceE+83#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "9.0e15",
+        "0x2.0E+13#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "9.99e15",
+        "0x2.38E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "9999999999999959.09787221758974",
+        "0x2386f26fc0ffd7.190e2755bc24#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-2.0", "-0x2.0#1", 1, "0.008", "0x0.02#1", Ordering::Less);
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        "0.00999",
+        "0x0.028f#10",
+        Ordering::Less,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        100,
+        "0.01",
+        "0x0.028f5c28f5c28f5c28f5c28f5c4#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_exp10_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_round(rm);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_round_assign(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.06",
+        "0x0.1#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Exact,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1385.4557313670109",
+        "0x569.74aacf9512c#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1385.4557313670107",
+        "0x569.74aacf95128#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.0007217841590747281",
+        "0x0.002f4d875ba5adf4#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.0007217841590747281",
+        "0x0.002f4d875ba5adf4#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.0007217841590747279",
+        "0x0.002f4d875ba5adf2#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "522.7352996704366",
+        "0x20a.bc3c996548e#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "522.7352996704366",
+        "0x20a.bc3c996548e#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "522.7352996704365",
+        "0x20a.bc3c996548c#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "2.1544346900318843",
+        "0x2.278908270e0a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "2.1544346900318843",
+        "0x2.278908270e0a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "2.1544346900318838",
+        "0x2.278908270e09e#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "9.98e99",
+        "0x1.24E+83#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "1.01e100",
+        "0x1.28E+83#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "9.98e99",
+        "0x1.24E+83#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "1.01e100",
+        "0x1.28E+83#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "9.98e99",
+        "0x1.24E+83#7",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "9999999999999958.0",
+        "0x2386f26fc0ffd6.0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "9999999999999960.0",
+        "0x2386f26fc0ffd8.0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "9999999999999958.0",
+        "0x2386f26fc0ffd6.0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "9999999999999960.0",
+        "0x2386f26fc0ffd8.0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "9999999999999960.0",
+        "0x2386f26fc0ffd8.0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0e2",
+        "0x3.0E+2#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#2",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Floor,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Ceiling,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Down,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Up,
+        "0.02",
+        "0x0.04#1",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Nearest,
+        "0.008",
+        "0x0.02#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_exp10_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+        assert!(exp10.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp10.to_string(), out);
+        assert_eq!(to_hex_string(&exp10), out_hex);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp10_alt = x.clone();
+        let o_alt = exp10_alt.exp10_prec_round_assign(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10), ComparableFloatRef(&exp10_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.1001",
+        "0x0.19a0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.1001",
+        "0x0.19a0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.09998",
+        "0x0.1998#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "10.0",
+        "0xa.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        RoundingMode::Exact,
+        "10.0",
+        "0xa.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "5.0e2",
+        "0x2.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "5.0e2",
+        "0x2.0E+2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "1.0e3",
+        "0x4.0E+2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1384.0",
+        "0x568.0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1384.0",
+        "0x568.0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1386.0",
+        "0x56a.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.000721",
+        "0x0.002f4#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.000722",
+        "0x0.002f5#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.000721",
+        "0x0.002f4#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.000722",
+        "0x0.002f5#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.000722",
+        "0x0.002f5#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "522.0",
+        "0x20a.0#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "523.0",
+        "0x20b.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "522.0",
+        "0x20a.0#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "523.0",
+        "0x20b.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "523.0",
+        "0x20b.0#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "2.152",
+        "0x2.27#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "2.152",
+        "0x2.27#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "2.156",
+        "0x2.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000003",
+        "0x1.0000000000000000000000004#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000003",
+        "0x1.0000000000000000000000004#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "1.001e100",
+        "0x1.250E+83#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "1.001e100",
+        "0x1.250E+83#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "9.996e99",
+        "0x1.248E+83#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "9.99e15",
+        "0x2.38E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.001e16",
+        "0x2.39E+13#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "9.99e15",
+        "0x2.38E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "1.001e16",
+        "0x2.39E+13#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "9.99e15",
+        "0x2.38E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1000.0",
+        "0x3e8.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1000.0",
+        "0x3e8.00000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.00999",
+        "0x0.028f#10",
+        Ordering::Less,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.01001",
+        "0x0.0290#10",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "0.00999",
+        "0x0.028f#10",
+        Ordering::Less,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "0.01001",
+        "0x0.0290#10",
+        Ordering::Greater,
+    );
+    test(
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.00999",
+        "0x0.028f#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp10_prec_fail() {
+    assert_panic!(Float::NAN.exp10_prec(0));
+    assert_panic!(Float::ONE.exp10_prec(0));
+}
+
+#[test]
+fn exp10_prec_ref_fail() {
+    assert_panic!(Float::NAN.exp10_prec_ref(0));
+    assert_panic!(Float::ONE.exp10_prec_ref(0));
+}
+
+#[test]
+fn exp10_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp10_prec_assign(0)
+    });
+}
+
+#[test]
+fn exp10_round_fail() {
+    assert_panic!(Float::ONE_HALF.exp10_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.exp10_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp10_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp10_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp10_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE_HALF.exp10_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.exp10_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp10_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp10_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).exp10());
+    assert_panic!(Float::power_of_2(100i64).exp10_prec(10));
+    assert_panic!(Float::power_of_2(100i64).exp10_round(RoundingMode::Floor));
+}
+
+#[test]
+fn exp10_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_9().test_properties(|(x, prec, rm)| {
+        let (exp10, o) = x.clone().exp10_prec_round(prec, rm);
+        assert!(exp10.is_valid());
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp10_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(exp10.is_normal());
+            assert_eq!(exp10.get_prec(), Some(prec));
+            assert!(exp10 > 0u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp10_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp10.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp10_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp10_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (exp10, o) = x.clone().exp10_prec(prec);
+        assert!(exp10.is_valid());
+
+        let (exp10_alt, o_alt) = x.exp10_prec_ref(prec);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp10_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let (rug_exp10, rug_o) = rug_exp10_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10)),
+            ComparableFloatRef(&exp10)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn exp10_round_properties() {
+    float_rounding_mode_pair_gen_var_13().test_properties(|(x, rm)| {
+        let (exp10, o) = x.clone().exp10_round(rm);
+        assert!(exp10.is_valid());
+
+        let (exp10_alt, o_alt) = x.exp10_round_ref(rm);
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp10_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        let (exp10_alt, o_alt) = x.exp10_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp10, rug_o) = rug_exp10_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp10)),
+                ComparableFloatRef(&exp10)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp10_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let exp10 = x.clone().exp10();
+        assert!(exp10.is_valid());
+
+        let exp10_alt = (&x).exp10();
+        assert!(exp10_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+
+        let mut x_alt = x.clone();
+        x_alt.exp10_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp10));
+
+        let exp10_alt = x
+            .exp10_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&exp10_alt), ComparableFloatRef(&exp10));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp10(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp10)
+        );
+    });
+
+    assert!(Float::NAN.exp10().is_nan());
+    assert_eq!(Float::INFINITY.exp10(), Float::INFINITY);
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_INFINITY.exp10()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(Float::ZERO.exp10(), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ZERO.exp10(), Float::ONE);
+    assert_eq!(Float::from(2u32).exp10_prec(7).0, 100u32);
+}
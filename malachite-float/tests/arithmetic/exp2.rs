@@ -0,0 +1,2304 @@
+use malachite_base::num::arithmetic::traits::{Exp2, Exp2Assign, PowerOf2};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, OneHalf, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::exp2::{
+    rug_exp2, rug_exp2_prec, rug_exp2_prec_round, rug_exp2_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_12, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_8,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_exp2() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let exp2 = x.clone().exp2();
+        assert!(exp2.is_valid());
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let exp2_alt = (&x).exp2();
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+
+        let mut exp2_alt = x.clone();
+        exp2_alt.exp2_assign();
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp2)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1");
+    test("-0.0", "-0x0.0", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "0.5", "0x0.8#1");
+    test("1.0", "0x1.000#10", "2.0", "0x2.00#10");
+    test("3.0", "0x3.0#2", "8.0", "0x8.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0", "0x1.0#1");
+    test("100.0", "0x64.0#7", "1.27e30", "0x1.00E+25#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "65535.99999999992",
+        "0xffff.ffffffffa8#53",
+    );
+    test("5.0", "0x5.0#3", "3.0e1", "0x20.0#3");
+    test("-3.0", "-0x3.0#2", "0.1", "0x0.2#2");
+    test("0.5", "0x0.8#1", "1.0", "0x1.0#1");
+}
+
+#[test]
+fn test_exp2_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec(prec);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_assign(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_exp2, rug_o) = rug_exp2_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test("-Infinity", "-Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "0.5", "0x0.800#10", Ordering::Equal);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("1.0", "0x1.000#10", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("3.0", "0x3.0#2", 1, "8.0", "0x8.0#1", Ordering::Equal);
+    test("3.0", "0x3.0#2", 10, "8.0", "0x8.00#10", Ordering::Equal);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "8.82497782707628687473894169658",
+        "0x8.d331bf3337c170dfcdee149f#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "0.1133",
+        "0x0.1d00#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "0.1133147322967608826391219318593",
+        "0x0.1d0231bd5e9cfd1c56d8c57fba#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "6.58",
+        "0x6.94#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "6.580885991017920311416449878895",
+        "0x6.94b4f1be206373370bd63ee70#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "1.259921049894873197086199701958",
+        "0x1.428a2f98d728b0766bf71877c#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "7.0e4",
+        "0x1.0E+4#1",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "6.55e4",
+        "0x1.000E+4#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "65535.99999999991930704790168151",
+        "0xffff.ffffffffa746f40417185#100",
+        Ordering::Less,
+    );
+    test("5.0", "0x5.0#3", 1, "3.0e1", "0x2.0E+1#1", Ordering::Equal);
+    test("5.0", "0x5.0#3", 10, "32.0", "0x20.0#10", Ordering::Equal);
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        "32.0",
+        "0x20.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-3.0", "-0x3.0#2", 1, "0.1", "0x0.2#1", Ordering::Equal);
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("0.5", "0x0.8#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test("0.5", "0x0.8#1", 10, "1.414", "0x1.6a0#10", Ordering::Less);
+    test(
+        "0.5",
+        "0x0.8#1",
+        100,
+        "1.414213562373095048801688724209",
+        "0x1.6a09e667f3bcc908b2fb1366e#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_exp2_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_round(rm);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_round_assign(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "8.824977827076285",
+        "0x8.d331bf3337c10#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "8.824977827076287",
+        "0x8.d331bf3337c18#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.11331473229676089",
+        "0x0.1d0231bd5e9cfe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.11331473229676089",
+        "0x0.1d0231bd5e9cfe#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.11331473229676088",
+        "0x0.1d0231bd5e9cfd#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "6.5808859910179196",
+        "0x6.94b4f1be20634#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "6.5808859910179196",
+        "0x6.94b4f1be20634#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "6.5808859910179205",
+        "0x6.94b4f1be20638#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.2599210498948734",
+        "0x1.428a2f98d728c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.2599210498948734",
+        "0x1.428a2f98d728c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.2599210498948732",
+        "0x1.428a2f98d728b#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Exact,
+        "1.27e30",
+        "0x1.00E+25#7",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "65535.999999999913",
+        "0xffff.ffffffffa0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "65535.99999999992",
+        "0xffff.ffffffffa8#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "65535.999999999913",
+        "0xffff.ffffffffa0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "65535.99999999992",
+        "0xffff.ffffffffa8#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "65535.99999999992",
+        "0xffff.ffffffffa8#53",
+        Ordering::Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Floor,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Down,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Up,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Nearest,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        RoundingMode::Exact,
+        "3.0e1",
+        "0x20.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Down,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Up,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Nearest,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        RoundingMode::Exact,
+        "0.1",
+        "0x0.2#2",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_exp2_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+        assert!(exp2.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(exp2.to_string(), out);
+        assert_eq!(to_hex_string(&exp2), out_hex);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut exp2_alt = x.clone();
+        let o_alt = exp2_alt.exp2_prec_round_assign(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2), ComparableFloatRef(&exp2_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "8.0",
+        "0x8.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "8.81",
+        "0x8.d0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "8.81",
+        "0x8.d0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "8.83",
+        "0x8.d4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.1133",
+        "0x0.1d00#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.1134",
+        "0x0.1d08#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.1133",
+        "0x0.1d00#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.1134",
+        "0x0.1d08#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.1133",
+        "0x0.1d00#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "6.58",
+        "0x6.94#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "6.586",
+        "0x6.96#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "6.58",
+        "0x6.94#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "6.586",
+        "0x6.96#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "6.58",
+        "0x6.94#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.262",
+        "0x1.430#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.26",
+        "0x1.428#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000000000000000002",
+        "0x1.0000000000000000000000002#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Equal,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        RoundingMode::Exact,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "6.547e4",
+        "0xf.fcE+3#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "6.55e4",
+        "0x1.000E+4#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "6.547e4",
+        "0xf.fcE+3#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "6.55e4",
+        "0x1.000E+4#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "6.55e4",
+        "0x1.000E+4#10",
+        Ordering::Greater,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        RoundingMode::Floor,
+        "32.0",
+        "0x20.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        RoundingMode::Ceiling,
+        "32.0",
+        "0x20.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        RoundingMode::Down,
+        "32.0",
+        "0x20.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        RoundingMode::Up,
+        "32.0",
+        "0x20.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        10,
+        RoundingMode::Nearest,
+        "32.0",
+        "0x20.0#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        100,
+        RoundingMode::Exact,
+        "32.0",
+        "0x20.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.125",
+        "0x0.200#10",
+        Ordering::Equal,
+    );
+    test(
+        "-3.0",
+        "-0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "0.125",
+        "0x0.20000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Floor,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.416",
+        "0x1.6a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Down,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Up,
+        "1.416",
+        "0x1.6a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.5",
+        "0x0.8#1",
+        10,
+        RoundingMode::Nearest,
+        "1.414",
+        "0x1.6a0#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn exp2_prec_fail() {
+    assert_panic!(Float::NAN.exp2_prec(0));
+    assert_panic!(Float::ONE.exp2_prec(0));
+}
+
+#[test]
+fn exp2_prec_ref_fail() {
+    assert_panic!(Float::NAN.exp2_prec_ref(0));
+    assert_panic!(Float::ONE.exp2_prec_ref(0));
+}
+
+#[test]
+fn exp2_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.exp2_prec_assign(0)
+    });
+}
+
+#[test]
+fn exp2_round_fail() {
+    assert_panic!(Float::ONE_HALF.exp2_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.exp2_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp2_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp2_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).exp2_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE_HALF.exp2_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE_HALF.exp2_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE_HALF;
+        x.exp2_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn exp2_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).exp2());
+    assert_panic!(Float::power_of_2(100i64).exp2_prec(10));
+    assert_panic!(Float::power_of_2(100i64).exp2_round(RoundingMode::Floor));
+}
+
+#[test]
+fn exp2_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_8().test_properties(|(x, prec, rm)| {
+        let (exp2, o) = x.clone().exp2_prec_round(prec, rm);
+        assert!(exp2.is_valid());
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp2_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() {
+            assert!(exp2.is_normal());
+            assert_eq!(exp2.get_prec(), Some(prec));
+            assert!(exp2 > 0u32);
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.exp2_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(exp2.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.exp2_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn exp2_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (exp2, o) = x.clone().exp2_prec(prec);
+        assert!(exp2.is_valid());
+
+        let (exp2_alt, o_alt) = x.exp2_prec_ref(prec);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp2_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let (rug_exp2, rug_o) = rug_exp2_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2)),
+            ComparableFloatRef(&exp2)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn exp2_round_properties() {
+    float_rounding_mode_pair_gen_var_12().test_properties(|(x, rm)| {
+        let (exp2, o) = x.clone().exp2_round(rm);
+        assert!(exp2.is_valid());
+
+        let (exp2_alt, o_alt) = x.exp2_round_ref(rm);
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.exp2_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        let (exp2_alt, o_alt) = x.exp2_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_exp2, rug_o) = rug_exp2_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_exp2)),
+                ComparableFloatRef(&exp2)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn exp2_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let exp2 = x.clone().exp2();
+        assert!(exp2.is_valid());
+
+        let exp2_alt = (&x).exp2();
+        assert!(exp2_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+
+        let mut x_alt = x.clone();
+        x_alt.exp2_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&exp2));
+
+        let exp2_alt = x
+            .exp2_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&exp2_alt), ComparableFloatRef(&exp2));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_exp2(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&exp2)
+        );
+    });
+
+    assert!(Float::NAN.exp2().is_nan());
+    assert_eq!(Float::INFINITY.exp2(), Float::INFINITY);
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_INFINITY.exp2()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(Float::ZERO.exp2(), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ZERO.exp2(), Float::ONE);
+    assert_eq!(Float::ONE.exp2(), 2u32);
+    assert_eq!(Float::from(-3i32).exp2(), Float::power_of_2(-3i64));
+}
@@ -0,0 +1,2029 @@
+use malachite_base::num::arithmetic::traits::{Expm1, Expm1Assign, PowerOf2};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::expm1::{
+    rug_expm1, rug_expm1_prec, rug_expm1_prec_round, rug_expm1_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_14, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_10,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_expm1() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let expm1 = x.clone().expm1();
+        assert!(expm1.is_valid());
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let expm1_alt = (&x).expm1();
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+
+        let mut expm1_alt = x.clone();
+        expm1_alt.expm1_assign();
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&expm1)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-1.0", "-0x1.0#1");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-0.5", "-0x0.8#1");
+    test("1.0", "0x1.000#10", "1.719", "0x1.b80#10");
+    test("3.0", "0x3.0#2", "16.0", "0x10.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.39561242508608957",
+        "0x0.6546db1ba2d134#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("100.0", "0x64.0#7", "2.68e43", "0x1.34E+36#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "8886109.520507857",
+        "0x87975d.854000c0#53",
+    );
+    test("-100.0", "-0x64.0#7", "-1.0", "-0x1.00#7");
+}
+
+#[test]
+fn test_expm1_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec(prec);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_assign(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_expm1, rug_o) = rug_expm1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-0.5", "-0x0.8#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-0.6321205588285576784044762298386",
+        "-0x0.a1d2a7274c4320e54521387d7#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.718281828459045235360287471353",
+        "0x1.b7e151628aed2a6abf715880a#100",
+        Ordering::Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "2.0e1", "0x1.0E+1#1", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "19.09",
+        "0x13.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "19.08553692318766774092852965458",
+        "0x13.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "22.14069263277926617181157008936",
+        "0x16.24046eb09338d2991a30893e#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-0.956786081736227744933403594794",
+        "-0x0.f4efeec253388335b0cf8dd92#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "14.16",
+        "0xe.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "14.15426224147926199898921968819",
+        "0xe.277dbaf2293cdcf6ff773788#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3956124250860895802761602457202",
+        "0x0.6546db1ba2d134c33c5f6384d0#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "2.0e43",
+        "0x1.0E+36#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "2.68811714181613544841262555158e43",
+        "0x1.3494a9b171bf4acc225093322E+36#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "8.0e6",
+        "0x8.0E+5#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "8886109.52050785685185982497011",
+        "0x87975d.854000be7d62b61843a#100",
+        Ordering::Less,
+    );
+    test("-100.0", "-0x64.0#7", 1, "-1.0", "-0x1.0#1", Ordering::Less);
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_expm1_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_round(rm);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_round_assign(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "16.0",
+        "0x10.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "24.0",
+        "0x18.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "16.0",
+        "0x10.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "24.0",
+        "0x18.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "16.0",
+        "0x10.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "22.140692632779263",
+        "0x16.24046eb09338#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "22.140692632779267",
+        "0x16.24046eb09339#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-0.9567860817362278",
+        "-0x0.f4efeec2533888#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-0.9567860817362277",
+        "-0x0.f4efeec2533880#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "14.154262241479261",
+        "0xe.277dbaf2293c8#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "14.154262241479262",
+        "0xe.277dbaf2293d0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.39561242508608957",
+        "0x0.6546db1ba2d134#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.39561242508608963",
+        "0x0.6546db1ba2d138#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.39561242508608957",
+        "0x0.6546db1ba2d134#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.39561242508608963",
+        "0x0.6546db1ba2d138#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.39561242508608957",
+        "0x0.6546db1ba2d134#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "2.72e43",
+        "0x1.38E+36#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "2.72e43",
+        "0x1.38E+36#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "2.68e43",
+        "0x1.34E+36#7",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "8886109.520507855",
+        "0x87975d.854000b8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "8886109.520507857",
+        "0x87975d.854000c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "8886109.520507855",
+        "0x87975d.854000b8#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "8886109.520507857",
+        "0x87975d.854000c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "8886109.520507857",
+        "0x87975d.854000c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.00#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Ceiling,
+        "-0.99",
+        "-0x0.fe#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Down,
+        "-0.99",
+        "-0x0.fe#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.00#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.00#7",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_expm1_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+        assert!(expm1.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(expm1.to_string(), out);
+        assert_eq!(to_hex_string(&expm1), out_hex);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut expm1_alt = x.clone();
+        let o_alt = expm1_alt.expm1_prec_round_assign(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1), ComparableFloatRef(&expm1_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-0.633",
+        "-0x0.a20#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-0.633",
+        "-0x0.a20#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.632",
+        "-0x0.a1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "3.0e1",
+        "0x2.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "19.06",
+        "0x13.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "19.09",
+        "0x13.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "19.06",
+        "0x13.10#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "19.09",
+        "0x13.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "19.09",
+        "0x13.18#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "19.08553692318766774092852965458",
+        "0x13.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "19.0855369231876677409285296546",
+        "0x13.15e5bf6fb105f2d4bdfc5376#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "19.08553692318766774092852965458",
+        "0x13.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "19.0855369231876677409285296546",
+        "0x13.15e5bf6fb105f2d4bdfc5376#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "19.08553692318766774092852965458",
+        "0x13.15e5bf6fb105f2d4bdfc5374#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "22.12",
+        "0x16.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "22.12",
+        "0x16.20#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "22.16",
+        "0x16.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.956",
+        "-0x0.f4c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-0.956",
+        "-0x0.f4c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.957",
+        "-0x0.f50#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "14.14",
+        "0xe.24#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "14.16",
+        "0xe.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "14.14",
+        "0xe.24#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "14.16",
+        "0xe.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "14.16",
+        "0xe.28#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.396",
+        "0x0.656#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.396",
+        "0x0.656#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3955",
+        "0x0.654#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "2.692e43",
+        "0x1.350E+36#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "2.692e43",
+        "0x1.350E+36#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "2.687e43",
+        "0x1.348E+36#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.9e6",
+        "0x8.7cE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "8.9e6",
+        "0x8.7cE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "8.88e6",
+        "0x8.78E+5#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn expm1_prec_fail() {
+    assert_panic!(Float::NAN.expm1_prec(0));
+    assert_panic!(Float::ONE.expm1_prec(0));
+}
+
+#[test]
+fn expm1_prec_ref_fail() {
+    assert_panic!(Float::NAN.expm1_prec_ref(0));
+    assert_panic!(Float::ONE.expm1_prec_ref(0));
+}
+
+#[test]
+fn expm1_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.expm1_prec_assign(0)
+    });
+}
+
+#[test]
+fn expm1_round_fail() {
+    assert_panic!(Float::ONE.expm1_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.expm1_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.expm1_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn expm1_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).expm1_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.expm1_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.expm1_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.expm1_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn expm1_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).expm1());
+    assert_panic!(Float::power_of_2(100i64).expm1_prec(10));
+    assert_panic!(Float::power_of_2(100i64).expm1_round(RoundingMode::Floor));
+}
+
+#[test]
+fn expm1_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_10().test_properties(|(x, prec, rm)| {
+        let (expm1, o) = x.clone().expm1_prec_round(prec, rm);
+        assert!(expm1.is_valid());
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.expm1_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 {
+            assert!(expm1.is_normal());
+            assert_eq!(expm1.get_prec(), Some(prec));
+            assert!((expm1 > 0u32) == (x > 0u32));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.expm1_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(expm1.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.expm1_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn expm1_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (expm1, o) = x.clone().expm1_prec(prec);
+        assert!(expm1.is_valid());
+
+        let (expm1_alt, o_alt) = x.expm1_prec_ref(prec);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.expm1_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let (rug_expm1, rug_o) = rug_expm1_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1)),
+            ComparableFloatRef(&expm1)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn expm1_round_properties() {
+    float_rounding_mode_pair_gen_var_14().test_properties(|(x, rm)| {
+        let (expm1, o) = x.clone().expm1_round(rm);
+        assert!(expm1.is_valid());
+
+        let (expm1_alt, o_alt) = x.expm1_round_ref(rm);
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.expm1_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        let (expm1_alt, o_alt) = x.expm1_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_expm1, rug_o) = rug_expm1_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_expm1)),
+                ComparableFloatRef(&expm1)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn expm1_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let expm1 = x.clone().expm1();
+        assert!(expm1.is_valid());
+
+        let expm1_alt = (&x).expm1();
+        assert!(expm1_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+
+        let mut x_alt = x.clone();
+        x_alt.expm1_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&expm1));
+
+        let expm1_alt = x
+            .expm1_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&expm1_alt), ComparableFloatRef(&expm1));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_expm1(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&expm1)
+        );
+    });
+
+    assert!(Float::NAN.expm1().is_nan());
+    assert_eq!(Float::INFINITY.expm1(), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_INFINITY.expm1(), Float::NEGATIVE_ONE);
+    assert_eq!(
+        ComparableFloat(Float::ZERO.expm1()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.expm1()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
@@ -0,0 +1,5030 @@
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::fmod::{
+    fmod_prec_round_naive, rational_rem, rug_fmod, rug_fmod_prec, rug_fmod_prec_round,
+    rug_fmod_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen, float_float_unsigned_rounding_mode_quadruple_gen_var_5,
+    float_float_unsigned_triple_gen_var_1, float_gen, float_pair_gen,
+    float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fmod() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let remainder = x.clone() % y.clone();
+        assert!(remainder.is_valid());
+
+        assert_eq!(remainder.to_string(), out);
+        assert_eq!(to_hex_string(&remainder), out_hex);
+
+        let remainder_alt = x.clone() % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        let remainder_alt = &x % y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        let remainder_alt = &x % &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+
+        let mut remainder_alt = x.clone();
+        remainder_alt %= y.clone();
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        let mut remainder_alt = x.clone();
+        remainder_alt %= &y;
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_fmod(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&remainder)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity", "NaN", "NaN");
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "-0.0", "-0x0.0");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "NaN", "NaN");
+    test("Infinity", "Infinity", "1.0", "0x1.0#1", "NaN", "NaN");
+    test(
+        "-1.0", "-0x1.0#1", "Infinity", "Infinity", "-1.0", "-0x1.0#1",
+    );
+    test("1.0", "0x1.0#1", "-0.0", "-0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "-1.0", "-0x1.0#1", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "-Infinity", "-Infinity", "1.0", "0x1.0#1");
+    test("-Infinity", "-Infinity", "1.0", "0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "1.0", "0x1.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "0.0", "0x0.0");
+    test("3.0", "0x3.0#2", "3.0", "0x3.0#2", "0.0", "0x0.0");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.0",
+        "0x0.0",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+    );
+    test("1.0", "0x1.0#1", "8.0e-31", "0x1.0E-25#1", "0.0", "0x0.0");
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+    );
+    test("5.5", "0x5.8#4", "2.0", "0x2.0#1", "1.5", "0x1.8#4");
+    test("-5.5", "-0x5.8#4", "2.0", "0x2.0#1", "-1.5", "-0x1.8#4");
+    test("5.0", "0x5.0#3", "2.0", "0x2.0#1", "1.0", "0x1.0#3");
+    test("7.0", "0x7.0#3", "-2.0", "-0x2.0#1", "1.0", "0x1.0#3");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+    );
+    test("1.0e30", "0x1.0E+25#1", "3.0", "0x3.0#2", "1.0", "0x1.0#2");
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "0.0",
+        "0x0.0",
+    );
+}
+
+#[test]
+fn test_fmod_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (remainder, o) = x.clone().fmod_prec(y.clone(), prec);
+        assert!(remainder.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(remainder.to_string(), out);
+        assert_eq!(to_hex_string(&remainder), out_hex);
+
+        let (remainder_alt, o_alt) = x.clone().fmod_prec_val_ref(&y, prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_ref_val(y.clone(), prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_ref_ref(&y, prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_prec_assign(y.clone(), prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_prec_assign_ref(&y, prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_remainder, rug_o) = rug_fmod_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_remainder)),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.951",
+        "0x1.f38#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.951681027687492075983755057678",
+        "0x1.f3a15e2a24360000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "-1.5",
+        "-0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.1416",
+        "0x0.244#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "0.1415926535897931159979634685442",
+        "0x0.243f6a8885a300000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_fmod_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (remainder, o) = x.clone().fmod_round(y.clone(), rm);
+        assert!(remainder.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(remainder.to_string(), out);
+        assert_eq!(to_hex_string(&remainder), out_hex);
+
+        let (remainder_alt, o_alt) = x.clone().fmod_round_val_ref(&y, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_round_ref_val(y.clone(), rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_round_ref_ref(&y, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_round_assign(y.clone(), rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_round_assign_ref(&y, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_remainder, rug_o) =
+                rug_fmod_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_remainder)),
+                ComparableFloatRef(&remainder)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "0.42331082513074803",
+        "0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Exact,
+        "-0.42331082513074803",
+        "-0x0.6c5e1925fab5e0#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "1.9516810276874921",
+        "0x1.f3a15e2a24360#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "-1.5",
+        "-0x1.8#4",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.14159265358979312",
+        "0x0.243f6a8885a300#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_fmod_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (remainder, o) = x.clone().fmod_prec_round(y.clone(), prec, rm);
+        assert!(remainder.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(remainder.to_string(), out);
+        assert_eq!(to_hex_string(&remainder), out_hex);
+
+        let (remainder_alt, o_alt) = x.clone().fmod_prec_round_val_ref(&y, prec, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_round_ref_ref(&y, prec, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_prec_round_assign(y.clone(), prec, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut remainder_alt = x.clone();
+        let o_alt = remainder_alt.fmod_prec_round_assign_ref(&y, prec, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder),
+            ComparableFloatRef(&remainder_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_remainder, rug_o) = rug_fmod_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_remainder)),
+                ComparableFloatRef(&remainder)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Up,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.4229",
+        "0x0.6c4#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4233",
+        "0x0.6c6#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "0.4233108251307480252023651701165",
+        "0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.4229",
+        "-0x0.6c4#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.4233",
+        "-0x0.6c6#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Exact,
+        "-0.4233108251307480252023651701165",
+        "-0x0.6c5e1925fab5e0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "0.3333333333333333703407674875052",
+        "0x0.55555555555558000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.951",
+        "0x1.f38#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.953",
+        "0x1.f40#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.951",
+        "0x1.f38#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.953",
+        "0x1.f40#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.951",
+        "0x1.f38#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "1.951681027687492075983755057678",
+        "0x1.f3a15e2a24360000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.5",
+        "0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.5",
+        "0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.5",
+        "-0x1.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "-5.5",
+        "-0x5.8#4",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.5",
+        "-0x1.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "5.0",
+        "0x5.0#3",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "7.0",
+        "0x7.0#3",
+        "-2.0",
+        "-0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.1414",
+        "0x0.243#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.1416",
+        "0x0.244#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "0.1414",
+        "0x0.243#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "0.1416",
+        "0x0.244#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.1416",
+        "0x0.244#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.1415926535897931159979634685442",
+        "0x0.243f6a8885a300000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn fmod_prec_fail() {
+    assert_panic!(Float::NAN.fmod_prec(Float::NAN, 0));
+    assert_panic!(Float::ONE.fmod_prec(Float::TWO, 0));
+}
+
+#[test]
+fn fmod_prec_val_ref_fail() {
+    assert_panic!(Float::NAN.fmod_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.fmod_prec_val_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn fmod_prec_ref_val_fail() {
+    assert_panic!(Float::NAN.fmod_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::ONE.fmod_prec_ref_val(Float::TWO, 0));
+}
+
+#[test]
+fn fmod_prec_ref_ref_fail() {
+    assert_panic!(Float::NAN.fmod_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.fmod_prec_ref_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn fmod_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.fmod_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.fmod_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn fmod_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).fmod_prec_round(Float::two_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::from(std::f64::consts::PI).fmod_prec_round(
+        Float::one_prec(1),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::from(std::f64::consts::PI).fmod_prec_round_val_ref(
+        &Float::one_prec(1),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::from(std::f64::consts::PI).fmod_prec_round_ref_val(
+        Float::one_prec(1),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::from(std::f64::consts::PI).fmod_prec_round_ref_ref(
+        &Float::one_prec(1),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::from(std::f64::consts::PI);
+        x.fmod_prec_round_assign(Float::one_prec(1), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::from(std::f64::consts::PI);
+        x.fmod_prec_round_assign_ref(&Float::one_prec(1), 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn fmod_prec_round_properties_helper(x: Float, y: Float, prec: u64, rm: RoundingMode) {
+    let (remainder, o) = x.clone().fmod_prec_round(y.clone(), prec, rm);
+    assert!(remainder.is_valid());
+
+    let (remainder_alt, o_alt) = x.clone().fmod_prec_round_val_ref(&y, prec, rm);
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    assert_eq!(o_alt, o);
+
+    let (remainder_alt, o_alt) = x.fmod_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    assert_eq!(o_alt, o);
+
+    let (remainder_alt, o_alt) = x.fmod_prec_round_ref_ref(&y, prec, rm);
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.fmod_prec_round_assign(y.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.fmod_prec_round_assign_ref(&y, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() && y != 0u32 {
+        let (remainder_alt, o_alt) = fmod_prec_round_naive(x.clone(), y.clone(), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+        if remainder.is_normal() {
+            assert_eq!(remainder.get_prec(), Some(prec));
+            assert_eq!(
+                remainder.partial_cmp(&rational_rem(
+                    &Rational::exact_from(&x),
+                    &Rational::exact_from(&y),
+                    RoundingMode::Down
+                )),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_remainder, rug_o) = rug_fmod_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_remainder)),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (remainder_alt, o_alt) = (-&x).fmod_prec_round_val_ref(&y, prec, -rm);
+    assert_eq!(
+        ComparableFloat(-remainder_alt),
+        ComparableFloat(remainder.clone())
+    );
+    assert_eq!(o_alt.reverse(), o);
+
+    let (remainder_alt, o_alt) = x.fmod_prec_round_ref_val(-&y, prec, rm);
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+    assert_eq!(o_alt, o);
+
+    let (remainder_alt, o_alt) = (-&x).fmod_prec_round(-&y, prec, -rm);
+    assert_eq!(
+        ComparableFloat(-remainder_alt),
+        ComparableFloat(remainder.clone())
+    );
+    assert_eq!(o_alt.reverse(), o);
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (p, oo) = x.fmod_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(ComparableFloat(p), ComparableFloat(remainder.clone()));
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.fmod_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn fmod_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_5().test_properties(|(x, y, prec, rm)| {
+        fmod_prec_round_properties_helper(x, y, prec, rm);
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (remainder, o) = x.fmod_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(remainder.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (remainder, o) = Float::NAN.fmod_prec_round_val_ref(&x, prec, rm);
+        assert!(remainder.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (remainder, o) = x.fmod_prec_round_ref_val(Float::ZERO, prec, rm);
+        assert!(remainder.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (remainder, o) = Float::INFINITY.fmod_prec_round_val_ref(&x, prec, rm);
+        assert!(remainder.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if x.is_finite() {
+            let (remainder_alt, o_alt) = x.fmod_prec_round_ref_val(Float::INFINITY, prec, rm);
+            let mut x_alt = x.clone();
+            let o = x_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(remainder_alt), ComparableFloat(x_alt));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[test]
+fn fmod_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (remainder, o) = x.clone().fmod_prec(y.clone(), prec);
+        assert!(remainder.is_valid());
+
+        let (remainder_alt, o_alt) = x.clone().fmod_prec_val_ref(&y, prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_ref_val(y.clone(), prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_ref_ref(&y, prec);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.fmod_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.fmod_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) = x.fmod_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let (rug_remainder, rug_o) = rug_fmod_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_remainder)),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn fmod_round_properties() {
+    float_float_rounding_mode_triple_gen().test_properties(|(x, y, rm)| {
+        let (remainder, o) = x.clone().fmod_round(y.clone(), rm);
+        assert!(remainder.is_valid());
+        assert_eq!(o, Ordering::Equal);
+
+        let (remainder_alt, o_alt) = x.clone().fmod_round_val_ref(&y, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) = x.fmod_round_ref_val(y.clone(), rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) = x.fmod_round_ref_ref(&y, rm);
+        assert!(remainder_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.fmod_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.fmod_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+        assert_eq!(o_alt, o);
+
+        let (remainder_alt, o_alt) =
+            x.fmod_prec_round_ref_ref(&y, max(x.significant_bits(), y.significant_bits()), rm);
+        assert_eq!(
+            ComparableFloatRef(&remainder_alt),
+            ComparableFloatRef(&remainder)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_remainder, rug_o) =
+                rug_fmod_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_remainder)),
+                ComparableFloatRef(&remainder)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn fmod_properties_helper(x: Float, y: Float) {
+    let remainder = x.clone() % y.clone();
+    assert!(remainder.is_valid());
+
+    let remainder_alt = x.clone() % &y;
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+
+    let remainder_alt = &x % y.clone();
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+
+    let remainder_alt = &x % &y;
+    assert!(remainder_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt %= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+
+    let mut x_alt = x.clone();
+    x_alt %= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&remainder));
+
+    let remainder_alt = x
+        .fmod_prec_round_ref_ref(
+            &y,
+            max(x.significant_bits(), y.significant_bits()),
+            RoundingMode::Nearest,
+        )
+        .0;
+    assert_eq!(
+        ComparableFloatRef(&remainder_alt),
+        ComparableFloatRef(&remainder)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_fmod(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y)
+        ))),
+        ComparableFloatRef(&remainder)
+    );
+
+    assert_eq!(
+        ComparableFloat(-(-&x % &y)),
+        ComparableFloat(remainder.clone())
+    );
+    assert_eq!(
+        ComparableFloat(&x % -&y),
+        ComparableFloat(remainder.clone())
+    );
+    assert_eq!(
+        ComparableFloat(-(-&x % -&y)),
+        ComparableFloat(remainder.clone())
+    );
+
+    if x.is_finite() && y.is_finite() && y != 0u32 {
+        assert!((&remainder).abs() < (&y).abs());
+        assert_eq!(remainder.is_sign_positive(), x.is_sign_positive());
+        if (&x).abs() < (&y).abs() {
+            assert_eq!(remainder, x);
+        }
+    }
+}
+
+#[test]
+fn fmod_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        fmod_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x % Float::NAN).is_nan());
+        assert!((Float::NAN % &x).is_nan());
+        assert!((&x % Float::ZERO).is_nan());
+        assert!((Float::INFINITY % &x).is_nan());
+        if x.is_finite() {
+            assert_eq!(
+                ComparableFloatRef(&(&x % Float::INFINITY)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(
+                ComparableFloatRef(&(&x % Float::NEGATIVE_INFINITY)),
+                ComparableFloatRef(&x)
+            );
+        }
+        if x.is_finite() && x != 0u32 {
+            let remainder = &x % &x;
+            assert!(remainder.is_zero());
+            assert_eq!(remainder.is_sign_positive(), x.is_sign_positive());
+            assert_eq!(
+                ComparableFloat(Float::ZERO % &x),
+                ComparableFloat(Float::ZERO)
+            );
+            assert_eq!(
+                ComparableFloat(Float::NEGATIVE_ZERO % &x),
+                ComparableFloat(Float::NEGATIVE_ZERO)
+            );
+        }
+    });
+}
@@ -0,0 +1,3621 @@
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Two, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::mul::{
+    mul_prec_round_naive, rug_mul, rug_mul_prec, rug_mul_prec_round, rug_mul_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_rounding_mode_triple_gen_var_3,
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3, float_float_unsigned_triple_gen_var_1,
+    float_gen, float_pair_gen, float_triple_gen, float_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mul() {
+    let test = |s, s_hex, t, t_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let product = x.clone() * y.clone();
+        assert!(product.is_valid());
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let product_alt = x.clone() * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let product_alt = &x * &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        let mut product_alt = x.clone();
+        product_alt *= y.clone();
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        let mut product_alt = x.clone();
+        product_alt *= &y;
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_mul(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y)
+            ))),
+            ComparableFloatRef(&product)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test("NaN", "NaN", "Infinity", "Infinity", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "Infinity", "Infinity", "Infinity", "Infinity", "Infinity", "Infinity",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test("Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0");
+    test("0.0", "0x0.0", "1.0", "0x1.0#1", "0.0", "0x0.0");
+    test("-1.0", "-0x1.0#1", "0.0", "0x0.0", "-0.0", "-0x0.0");
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+    );
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1", "2.0", "0x2.0#1");
+    test("1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("3.0", "0x3.0#2", "3.0", "0x3.0#2", "8.0", "0x8.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "1.0",
+        "0x1.0000000000000#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "6.0e-61",
+        "0x1.0E-50#1",
+    );
+}
+
+#[test]
+fn test_mul_prec() {
+    let test = |s, s_hex, t, t_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_assign_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_product, rug_o) = rug_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", "NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "9.0",
+        "0x9.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "-8.53973422267356627840667135511",
+        "-0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-8.53973422267356627840667135511",
+        "-0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        100,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "9.88",
+        "0x9.e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "9.86960440108935784936621351116",
+        "0x9.de9e64df22eef5b33ef2c5c9#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "5.0e30",
+        "0x4.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "3.981e30",
+        "0x3.24E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "3982441812995697208445926113280.0",
+        "0x3243f6a8885a30000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "6.22301527786114170714406405378e-61",
+        "0x1.0000000000000000000000000E-50#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_mul_round() {
+    let test = |s, s_hex, t, t_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_round_assign_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) =
+                rug_mul_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-8.539734222673568",
+        "-0x8.8a2c05a2ea3a8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-8.539734222673568",
+        "-0x8.8a2c05a2ea3a8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-8.539734222673568",
+        "-0x8.8a2c05a2ea3a8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-8.539734222673568",
+        "-0x8.8a2c05a2ea3a8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-8.539734222673566",
+        "-0x8.8a2c05a2ea3a0#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "8.539734222673568",
+        "0x8.8a2c05a2ea3a8#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "8.539734222673566",
+        "0x8.8a2c05a2ea3a0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "9.869604401089356",
+        "0x9.de9e64df22ee8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "9.869604401089356",
+        "0x9.de9e64df22ee8#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "9.869604401089358",
+        "0x9.de9e64df22ef0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0000000000000002",
+        "0x1.0000000000001#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0000000000000002",
+        "0x1.0000000000001#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "3.9824418129956972e30",
+        "0x3.243f6a8885a30E+25#53",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "6.0e-61",
+        "0x1.0E-50#1",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_mul_prec_round() {
+    let test = |s, s_hex, t, t_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+
+        let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+        assert!(product.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(product.to_string(), out);
+        assert_eq!(to_hex_string(&product), out_hex);
+
+        let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign(y.clone(), prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut product_alt = x.clone();
+        let o_alt = product_alt.mul_prec_round_assign_ref(&y, prec, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product),
+            ComparableFloatRef(&product_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) = rug_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "9.0",
+        "0x9.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "9.0",
+        "0x9.000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "8.53",
+        "0x8.88#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "8.53",
+        "0x8.88#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Floor,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Ceiling,
+        "8.53973422267356627840667135512",
+        "0x8.8a2c05a2ea3a1679e124a69c#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Up,
+        "8.53973422267356627840667135512",
+        "0x8.8a2c05a2ea3a1679e124a69c#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Nearest,
+        "8.53973422267356627840667135511",
+        "0x8.8a2c05a2ea3a1679e124a69b#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-8.53",
+        "-0x8.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-8.53",
+        "-0x8.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-8.53",
+        "-0x8.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-8.53",
+        "-0x8.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-8.55",
+        "-0x8.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "8.53",
+        "0x8.88#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "8.53",
+        "0x8.88#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "8.55",
+        "0x8.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "9.86",
+        "0x9.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "9.88",
+        "0x9.e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "9.86",
+        "0x9.dc#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "9.88",
+        "0x9.e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "9.88",
+        "0x9.e0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "9.86960440108935784936621351116",
+        "0x9.de9e64df22eef5b33ef2c5c9#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.002",
+        "0x1.008#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1.000000000000000111022302462516",
+        "0x1.0000000000000800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "3.981e30",
+        "0x3.24E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "3.986e30",
+        "0x3.25E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "3.981e30",
+        "0x3.24E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "3.986e30",
+        "0x3.25E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "3.981e30",
+        "0x3.24E+25#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "3982441812995697208445926113280.0",
+        "0x3243f6a8885a30000000000000.0#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "6.22e-61",
+        "0x1.000E-50#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "6.22301527786114170714406405378e-61",
+        "0x1.0000000000000000000000000E-50#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn mul_prec_fail() {
+    assert_panic!(Float::NAN.mul_prec(Float::NAN, 0));
+    assert_panic!(Float::ONE.mul_prec(Float::TWO, 0));
+}
+
+#[test]
+fn mul_prec_val_ref_fail() {
+    assert_panic!(Float::NAN.mul_prec_val_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.mul_prec_val_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn mul_prec_ref_val_fail() {
+    assert_panic!(Float::NAN.mul_prec_ref_val(Float::NAN, 0));
+    assert_panic!(Float::ONE.mul_prec_ref_val(Float::TWO, 0));
+}
+
+#[test]
+fn mul_prec_ref_ref_fail() {
+    assert_panic!(Float::NAN.mul_prec_ref_ref(&Float::NAN, 0));
+    assert_panic!(Float::ONE.mul_prec_ref_ref(&Float::TWO, 0));
+}
+
+#[test]
+fn mul_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_assign(Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.mul_prec_assign_ref(&Float::TWO, 0)
+    });
+}
+
+#[test]
+fn mul_round_fail() {
+    let three = Float::from(3u32);
+    let third = Float::from(1.0 / 3.0);
+    assert_panic!(three.clone().mul_round(third.clone(), RoundingMode::Exact));
+    assert_panic!(three.clone().mul_round_val_ref(&third, RoundingMode::Exact));
+    assert_panic!(three.mul_round_ref_val(third.clone(), RoundingMode::Exact));
+    assert_panic!(three.mul_round_ref_ref(&third, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = three.clone();
+        x.mul_round_assign(third.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = three.clone();
+        x.mul_round_assign_ref(&third, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn mul_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).mul_prec_round(Float::two_prec(1), 0, RoundingMode::Floor));
+    assert_panic!(Float::one_prec(1).mul_prec_round(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).mul_prec_round_val_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).mul_prec_round_ref_val(
+        Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).mul_prec_round_ref_ref(
+        &Float::from(std::f64::consts::PI),
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.mul_prec_round_assign(Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.mul_prec_round_assign_ref(&Float::from(std::f64::consts::PI), 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_prec_round_properties_helper(x: Float, y: Float, prec: u64, rm: RoundingMode) {
+    let (product, o) = x.clone().mul_prec_round(y.clone(), prec, rm);
+    assert!(product.is_valid());
+
+    let (product_alt, o_alt) = x.clone().mul_prec_round_val_ref(&y, prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_round_ref_val(y.clone(), prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, rm);
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.mul_prec_round_assign(y.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.mul_prec_round_assign_ref(&y, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() {
+        let (product_alt, o_alt) = mul_prec_round_naive(x.clone(), y.clone(), prec, rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+        if product.is_normal() {
+            assert_eq!(product.get_prec(), Some(prec));
+            assert_eq!(
+                product.partial_cmp(&(Rational::exact_from(&x) * Rational::exact_from(&y))),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_product, rug_o) = rug_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (product_alt, o_alt) = y.mul_prec_round_ref_ref(&x, prec, rm);
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    let (product_alt, o_alt) = (-&x).mul_prec_round_val_ref(&y, prec, -rm);
+    assert_eq!(
+        ComparableFloat(-product_alt),
+        ComparableFloat(product.clone())
+    );
+    assert_eq!(o_alt.reverse(), o);
+
+    let (product_alt, o_alt) = (-&x).mul_prec_round(-&y, prec, rm);
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+    assert_eq!(o_alt, o);
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (p, oo) = x.mul_prec_round_ref_ref(&y, prec, rm);
+            assert_eq!(ComparableFloat(p), ComparableFloat(product.clone()));
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn mul_prec_round_properties() {
+    float_float_unsigned_rounding_mode_quadruple_gen_var_3().test_properties(|(x, y, prec, rm)| {
+        mul_prec_round_properties_helper(x, y, prec, rm);
+    });
+
+    float_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(x, prec, rm)| {
+        let (product, o) = x.mul_prec_round_ref_val(Float::NAN, prec, rm);
+        assert!(product.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        let (product, o) = Float::NAN.mul_prec_round_val_ref(&x, prec, rm);
+        assert!(product.is_nan());
+        assert_eq!(o, Ordering::Equal);
+
+        if !x.is_nan() {
+            let (product_alt, o_alt) = x.mul_prec_round_ref_val(Float::ONE, prec, rm);
+            let mut x_alt = x.clone();
+            let o = x_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(product_alt), ComparableFloat(x_alt));
+            assert_eq!(o_alt, o);
+
+            let (product_alt, o_alt) = x.mul_prec_round_ref_val(Float::NEGATIVE_ONE, prec, rm);
+            let mut x_alt = -&x;
+            let o = x_alt.set_prec_round(prec, rm);
+            assert_eq!(ComparableFloat(product_alt), ComparableFloat(x_alt));
+            assert_eq!(o_alt, o);
+        }
+    });
+}
+
+#[test]
+fn mul_prec_properties() {
+    float_float_unsigned_triple_gen_var_1().test_properties(|(x, y, prec)| {
+        let (product, o) = x.clone().mul_prec(y.clone(), prec);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_prec_val_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_val(y.clone(), prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_ref_ref(&y, prec);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_assign(y.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_prec_assign_ref(&y, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_prec_round_ref_ref(&y, prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (rug_product, rug_o) = rug_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_product)),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn mul_round_properties() {
+    float_float_rounding_mode_triple_gen_var_3().test_properties(|(x, y, rm)| {
+        let (product, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(product.is_valid());
+
+        let (product_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert!(product_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_round_assign(y.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.mul_round_assign_ref(&y, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+        assert_eq!(o_alt, o);
+
+        let (product_alt, o_alt) =
+            x.mul_prec_round_ref_ref(&y, max(x.significant_bits(), y.significant_bits()), rm);
+        assert_eq!(
+            ComparableFloatRef(&product_alt),
+            ComparableFloatRef(&product)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_product, rug_o) =
+                rug_mul_round(&rug::Float::exact_from(&x), &rug::Float::exact_from(&y), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_product)),
+                ComparableFloatRef(&product)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn mul_properties_helper(x: Float, y: Float) {
+    let product = x.clone() * y.clone();
+    assert!(product.is_valid());
+
+    let product_alt = x.clone() * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * y.clone();
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let product_alt = &x * &y;
+    assert!(product_alt.is_valid());
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    let mut x_alt = x.clone();
+    x_alt *= y.clone();
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let mut x_alt = x.clone();
+    x_alt *= &y;
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&product));
+
+    let product_alt = x
+        .mul_prec_round_ref_ref(
+            &y,
+            max(x.significant_bits(), y.significant_bits()),
+            RoundingMode::Nearest,
+        )
+        .0;
+    assert_eq!(
+        ComparableFloatRef(&product_alt),
+        ComparableFloatRef(&product)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_mul(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y)
+        ))),
+        ComparableFloatRef(&product)
+    );
+
+    assert_eq!(
+        ComparableFloat(-(-&x * &y)),
+        ComparableFloat(product.clone())
+    );
+    assert_eq!(ComparableFloat(-&x * -&y), ComparableFloat(product.clone()));
+    assert_eq!(ComparableFloat(&y * &x), ComparableFloat(product));
+}
+
+#[test]
+fn mul_properties() {
+    float_pair_gen().test_properties(|(x, y)| {
+        mul_properties_helper(x, y);
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x * Float::NAN).is_nan());
+        assert!((Float::NAN * &x).is_nan());
+        if !x.is_nan() {
+            assert_eq!(
+                ComparableFloatRef(&(&x * Float::ONE)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(
+                ComparableFloatRef(&(Float::ONE * &x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(
+                ComparableFloat(&x * Float::NEGATIVE_ONE),
+                ComparableFloat(-&x)
+            );
+        }
+        if x.is_finite() {
+            let product = &x * Float::ZERO;
+            assert!(product.is_zero());
+            assert_eq!(product.is_sign_positive(), x.is_sign_positive());
+        } else if !x.is_nan() {
+            assert!((&x * Float::ZERO).is_nan());
+            assert!((Float::NEGATIVE_ZERO * &x).is_nan());
+        }
+        if x > 0u32 {
+            assert_eq!(&x * Float::INFINITY, Float::INFINITY);
+            assert_eq!(Float::NEGATIVE_INFINITY * &x, Float::NEGATIVE_INFINITY);
+        }
+        if x < 0u32 {
+            assert_eq!(&x * Float::INFINITY, Float::NEGATIVE_INFINITY);
+            assert_eq!(Float::NEGATIVE_INFINITY * &x, Float::INFINITY);
+        }
+    });
+
+    float_triple_gen().test_properties(|(x, y, z)| {
+        if x.is_finite() && y.is_finite() && z.is_finite() {
+            let (p, o) = x.mul_prec_ref_ref(&y, x.significant_bits() + y.significant_bits());
+            assert_eq!(o, Ordering::Equal);
+            let (q, o) = y.mul_prec_ref_ref(&z, y.significant_bits() + z.significant_bits());
+            assert_eq!(o, Ordering::Equal);
+            let prec = x.significant_bits() + y.significant_bits() + z.significant_bits();
+            assert_eq!(
+                ComparableFloat(p.mul_prec(z, prec).0.abs_negative_zero()),
+                ComparableFloat(x.mul_prec(q, prec).0.abs_negative_zero())
+            );
+        }
+    });
+}
@@ -0,0 +1,2480 @@
+use malachite_base::num::arithmetic::traits::{ReciprocalSqrt, ReciprocalSqrtAssign, Square};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::reciprocal_sqrt::{
+    rug_reciprocal_sqrt, rug_reciprocal_sqrt_prec, rug_reciprocal_sqrt_prec_round,
+    rug_reciprocal_sqrt_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_10, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_5,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_reciprocal_sqrt() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let reciprocal_sqrt = x.clone().reciprocal_sqrt();
+        assert!(reciprocal_sqrt.is_valid());
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let reciprocal_sqrt_alt = (&x).reciprocal_sqrt();
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        reciprocal_sqrt_alt.reciprocal_sqrt_assign();
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt(&rug::Float::exact_from(
+                &x
+            )))),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "0.0", "0x0.0");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "Infinity", "Infinity");
+    test("-0.0", "-0x0.0", "Infinity", "Infinity");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "NaN", "NaN");
+    test("1.0", "0x1.000#10", "1.0", "0x1.000#10");
+    test("3.0", "0x3.0#2", "0.5", "0x0.8#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+    );
+    test("-3.1415926535897931", "-0x3.243f6a8885a30#53", "NaN", "NaN");
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "1.0e15", "0x4.0E+12#1");
+    test("1.0e30", "0x1.0E+25#1", "9.0e-16", "0x4.0E-13#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "0.25",
+        "0x0.40000000000000#53",
+    );
+    test("4.0", "0x4.0#1", "0.5", "0x0.8#1");
+    test("2.0", "0x2.0#1", "0.5", "0x0.8#1");
+    test("9.0", "0x9.0#4", "0.34", "0x0.58#4");
+    test("0.2", "0x0.4#1", "2.0", "0x2.0#1");
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_assign(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let (rug_reciprocal_sqrt, rug_o) =
+            rug_reciprocal_sqrt_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "Infinity", "Infinity", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "Infinity", "Infinity", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test("1.0", "0x1.0#1", 10, "1.0", "0x1.000#10", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("-1.0", "-0x1.0#1", 1, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 10, "NaN", "NaN", Ordering::Equal);
+    test("-1.0", "-0x1.0#1", 100, "NaN", "NaN", Ordering::Equal);
+    test("1.0", "0x1.000#10", 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("3.0", "0x3.0#2", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("3.0", "0x3.0#2", 10, "0.577", "0x0.93c#10", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "0.564189583547756297944619165494",
+        "0x0.906eba8214db69584b8553dd8#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "0.6065306597126334397321706584727",
+        "0x0.9b4597e37cb0511d5a89eae2c#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "1.732050807568877197379312022327",
+        "0x1.bb67ae8584caa04d86ba175d4#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "8.88178419700125232338905334473e-16",
+        "0x4.0000000000000000000000000E-13#100",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "0.25",
+        "0x0.400#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "0.2500000000000000138777878078145",
+        "0x0.40000000000001000000000000#100",
+        Ordering::Less,
+    );
+    test("4.0", "0x4.0#1", 1, "0.5", "0x0.8#1", Ordering::Equal);
+    test("4.0", "0x4.0#1", 10, "0.5", "0x0.800#10", Ordering::Equal);
+    test(
+        "4.0",
+        "0x4.0#1",
+        100,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("2.0", "0x2.0#1", 1, "0.5", "0x0.8#1", Ordering::Less);
+    test("2.0", "0x2.0#1", 10, "0.707", "0x0.b50#10", Ordering::Less);
+    test(
+        "2.0",
+        "0x2.0#1",
+        100,
+        "0.7071067811865475244008443621046",
+        "0x0.b504f333f9de6484597d89b37#100",
+        Ordering::Less,
+    );
+    test("9.0", "0x9.0#4", 1, "0.2", "0x0.4#1", Ordering::Less);
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        100,
+        "0.3333333333333333333333333333335",
+        "0x0.55555555555555555555555558#100",
+        Ordering::Greater,
+    );
+    test("0.2", "0x0.4#1", 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("0.2", "0x0.4#1", 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_reciprocal_sqrt_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_round_assign(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#2",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "0.5641895835477564",
+        "0x0.906eba8214db70#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "0.5641895835477563",
+        "0x0.906eba8214db68#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "0.6065306597126335",
+        "0x0.9b4597e37cb058#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "0.6065306597126335",
+        "0x0.9b4597e37cb058#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "0.6065306597126334",
+        "0x0.9b4597e37cb050#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.7320508075688774",
+        "0x1.bb67ae8584cab#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.7320508075688774",
+        "0x1.bb67ae8584cab#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.7320508075688772",
+        "0x1.bb67ae8584caa#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Exact,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Exact,
+        "9.0e-16",
+        "0x4.0E-13#1",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "0.25",
+        "0x0.40000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "0.25000000000000006",
+        "0x0.40000000000004#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "0.25",
+        "0x0.40000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "0.25000000000000006",
+        "0x0.40000000000004#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "0.25",
+        "0x0.40000000000000#53",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Up,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Floor,
+        "0.31",
+        "0x0.50#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Ceiling,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Down,
+        "0.31",
+        "0x0.50#4",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Up,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        RoundingMode::Nearest,
+        "0.34",
+        "0x0.58#4",
+        Ordering::Greater,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_reciprocal_sqrt_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(reciprocal_sqrt.to_string(), out);
+        assert_eq!(to_hex_string(&reciprocal_sqrt), out_hex);
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        let mut reciprocal_sqrt_alt = x.clone();
+        let o_alt = reciprocal_sqrt_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt),
+            ComparableFloatRef(&reciprocal_sqrt_alt)
+        );
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "0.577350269189625764509148780501",
+        "0x0.93cd3a2c8198e2690c7c0f257#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "0.577350269189625764509148780502",
+        "0x0.93cd3a2c8198e2690c7c0f258#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "0.563",
+        "0x0.904#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "0.563",
+        "0x0.904#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "0.564",
+        "0x0.908#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.607",
+        "0x0.9b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "0.607",
+        "0x0.9b8#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.606",
+        "0x0.9b4#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.73",
+        "0x1.bb0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.73",
+        "0x1.bb0#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.732",
+        "0x1.bb8#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0e15",
+        "0x4.0E+12#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.126e15",
+        "0x4.00E+12#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Exact,
+        "1125899906842624.0",
+        "0x4000000000000.0000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "8.88e-16",
+        "0x4.00E-13#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        RoundingMode::Exact,
+        "8.88178419700125232338905334473e-16",
+        "0x4.0000000000000000000000000E-13#100",
+        Ordering::Equal,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "0.25",
+        "0x0.400#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.2505",
+        "0x0.402#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "0.25",
+        "0x0.400#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "0.2505",
+        "0x0.402#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "0.25",
+        "0x0.400#10",
+        Ordering::Less,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Up,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.5",
+        "0x0.800#10",
+        Ordering::Equal,
+    );
+    test(
+        "4.0",
+        "0x4.0#1",
+        100,
+        RoundingMode::Exact,
+        "0.5",
+        "0x0.8000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Floor,
+        "0.707",
+        "0x0.b50#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.708",
+        "0x0.b54#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Down,
+        "0.707",
+        "0x0.b50#10",
+        Ordering::Less,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Up,
+        "0.708",
+        "0x0.b54#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.0",
+        "0x2.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.707",
+        "0x0.b50#10",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Floor,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Ceiling,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Down,
+        "0.333",
+        "0x0.554#10",
+        Ordering::Less,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Up,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "9.0",
+        "0x9.0#4",
+        10,
+        RoundingMode::Nearest,
+        "0.3335",
+        "0x0.556#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.2",
+        "0x0.4#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn reciprocal_sqrt_prec_fail() {
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec(0));
+    assert_panic!(Float::ONE.reciprocal_sqrt_prec(0));
+}
+
+#[test]
+fn reciprocal_sqrt_prec_ref_fail() {
+    assert_panic!(Float::NAN.reciprocal_sqrt_prec_ref(0));
+    assert_panic!(Float::ONE.reciprocal_sqrt_prec_ref(0));
+}
+
+#[test]
+fn reciprocal_sqrt_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.reciprocal_sqrt_prec_assign(0)
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_round_fail() {
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_round(RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_sqrt_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).reciprocal_sqrt_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).reciprocal_sqrt_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.reciprocal_sqrt_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_5().test_properties(|(x, prec, rm)| {
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec_round(prec, rm);
+        assert!(reciprocal_sqrt.is_valid());
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_sqrt_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x > 0u32 {
+            assert!(reciprocal_sqrt.is_normal());
+            assert_eq!(reciprocal_sqrt.get_prec(), Some(prec));
+            assert_eq!(
+                (Rational::exact_from(&reciprocal_sqrt).square() * Rational::exact_from(&x))
+                    .partial_cmp(&1u32),
+                Some(o)
+            );
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.reciprocal_sqrt_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(reciprocal_sqrt.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_prec(prec);
+        assert!(reciprocal_sqrt.is_valid());
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_prec_ref(prec);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_sqrt_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let (reciprocal_sqrt_alt, o_alt) =
+            x.reciprocal_sqrt_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let (rug_reciprocal_sqrt, rug_o) =
+            rug_reciprocal_sqrt_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_round_properties() {
+    float_rounding_mode_pair_gen_var_10().test_properties(|(x, rm)| {
+        let (reciprocal_sqrt, o) = x.clone().reciprocal_sqrt_round(rm);
+        assert!(reciprocal_sqrt.is_valid());
+
+        let (reciprocal_sqrt_alt, o_alt) = x.reciprocal_sqrt_round_ref(rm);
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.reciprocal_sqrt_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        let (reciprocal_sqrt_alt, o_alt) =
+            x.reciprocal_sqrt_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_reciprocal_sqrt, rug_o) =
+                rug_reciprocal_sqrt_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt)),
+                ComparableFloatRef(&reciprocal_sqrt)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn reciprocal_sqrt_properties() {
+    float_gen().test_properties(|x| {
+        let reciprocal_sqrt = x.clone().reciprocal_sqrt();
+        assert!(reciprocal_sqrt.is_valid());
+
+        let reciprocal_sqrt_alt = (&x).reciprocal_sqrt();
+        assert!(reciprocal_sqrt_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+
+        let mut x_alt = x.clone();
+        x_alt.reciprocal_sqrt_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(
+            ComparableFloatRef(&x_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+
+        let reciprocal_sqrt_alt = x
+            .reciprocal_sqrt_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(
+            ComparableFloatRef(&reciprocal_sqrt_alt),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_reciprocal_sqrt(&rug::Float::exact_from(
+                &x
+            )))),
+            ComparableFloatRef(&reciprocal_sqrt)
+        );
+
+        if x > 0u32 && x.is_finite() {
+            assert!(reciprocal_sqrt > 0u32);
+        } else if x < 0u32 {
+            assert!(reciprocal_sqrt.is_nan());
+        }
+    });
+
+    assert!(Float::NAN.reciprocal_sqrt().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::INFINITY.reciprocal_sqrt()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert!(Float::NEGATIVE_INFINITY.reciprocal_sqrt().is_nan());
+    assert_eq!(Float::ZERO.reciprocal_sqrt(), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_ZERO.reciprocal_sqrt(), Float::INFINITY);
+    assert_eq!(Float::ONE.reciprocal_sqrt(), Float::ONE);
+    assert!(Float::NEGATIVE_ONE.reciprocal_sqrt().is_nan());
+    assert_eq!(Float::from(4u32).reciprocal_sqrt(), 0.5);
+}
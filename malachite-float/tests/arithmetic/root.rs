@@ -0,0 +1,3891 @@
+use malachite_base::num::arithmetic::traits::{Parity, Pow, Root, RootAssign, Sqrt};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::root::{
+    rug_root, rug_root_prec, rug_root_prec_round, rug_root_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_unsigned_pair_gen_var_1, float_unsigned_rounding_mode_triple_gen_var_6,
+    float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1,
+    float_unsigned_unsigned_triple_gen_var_1,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_root() {
+    let test = |s, s_hex, exp, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let root = x.clone().root(exp);
+        assert!(root.is_valid());
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let root_alt = (&x).root(exp);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+
+        let mut root_alt = x.clone();
+        root_alt.root_assign(exp);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root(&rug::Float::exact_from(&x), exp))),
+            ComparableFloatRef(&root)
+        );
+    };
+    test("NaN", "NaN", 3, "NaN", "NaN");
+    test("Infinity", "Infinity", 3, "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", 3, "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", 3, "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", 3, "-0.0", "-0x0.0");
+    test("-Infinity", "-Infinity", 2, "NaN", "NaN");
+    test("-0.0", "-0x0.0", 2, "0.0", "0x0.0");
+    test("0.0", "0x0.0", 0, "NaN", "NaN");
+    test("Infinity", "Infinity", 0, "NaN", "NaN");
+    test("1.0", "0x1.0#1", 3, "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", 3, "-1.0", "-0x1.0#1");
+    test("1.0", "0x1.000#10", 3, "1.0", "0x1.000#10");
+    test("3.0", "0x3.0#2", 3, "1.5", "0x1.8#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        "-1.4645918875615231",
+        "-0x1.76ef7e73104b7#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", 3, "1.0e-10", "0x8.0E-9#1");
+    test("1.0e30", "0x1.0E+25#1", 3, "9.0e9", "0x2.0E+8#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        "2.5198420997897464",
+        "0x2.85145f31ae516#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        "1.3313353638003897",
+        "0x1.54d264f787eb7#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        "1.2840254166877414",
+        "0x1.48b5e3c3e8186#53",
+    );
+    test("81.0", "0x51.0#7", 4, "3.0", "0x3.00#7");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        "NaN",
+        "NaN",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1.0115130699114478",
+        "0x1.02f28542bf08f#53",
+    );
+    test("8.0", "0x8.0#1", 3, "2.0", "0x2.0#1");
+    test("-8.0", "-0x8.0#1", 3, "-2.0", "-0x2.0#1");
+    test("27.0", "0x1b.0#5", 3, "3.0", "0x3.0#5");
+}
+
+#[test]
+fn test_root_prec() {
+    let test = |s, s_hex, exp, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_prec(exp, prec);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_prec_ref(exp, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_prec_assign(exp, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_root, rug_o) = rug_root_prec(&rug::Float::exact_from(&x), exp, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root)),
+            ComparableFloatRef(&root)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 3, 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 3, 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 3, 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test("-0.0", "-0x0.0", 2, 10, "0.0", "0x0.0", Ordering::Equal);
+    test("0.0", "0x0.0", 0, 10, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 0, 10, "NaN", "NaN", Ordering::Equal);
+    test("1.0", "0x1.0#1", 3, 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("1.0", "0x1.000#10", 3, 1, "1.0", "0x1.0#1", Ordering::Equal);
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test("3.0", "0x3.0#2", 3, 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        "1.441",
+        "0x1.710#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        "1.442249570307408382321638310779",
+        "0x1.7137449123ef65cdde7f16c56#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        100,
+        "1.464591887561523243989373053169",
+        "0x1.76ef7e73104b7750833131288#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        1,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        "-1.465",
+        "-0x1.770#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        100,
+        "-1.464591887561523243989373053169",
+        "-0x1.76ef7e73104b7750833131288#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        100,
+        "1.395612425086089503887463243268",
+        "0x1.6546db1ba2d12f421cf34179a#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        1,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        100,
+        "0.6933612743506347305028739803697",
+        "0x0.b1801fdda61bad1761104f85a#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        "9.24e-11",
+        "0x6.5aE-9#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        "9.23989021666464090086348377676e-11",
+        "0x6.597fa94f5b8f20ac16666ad10E-9#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        1,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        "1.082e10",
+        "0x2.85E+8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        100,
+        "10822639409.68092896148637802281",
+        "0x285145f31.ae515c447bb56e2b8#100",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        "2.52",
+        "0x2.85#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        100,
+        "2.519842099789746236281530627677",
+        "0x2.85145f31ae51558c45623f054#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        100,
+        "1.77245385090551599275151910314",
+        "0x1.c5bf891b4ef6a7fc7dc11ccfa#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        100,
+        "1.331335363800389699823099880941",
+        "0x1.54d264f787eb6c811fe1485aa#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        "1.283",
+        "0x1.488#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        100,
+        "1.28402541668774146700153959989",
+        "0x1.48b5e3c3e818653b901605812#100",
+        Ordering::Greater,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        100,
+        "3.141592653589793115997963468544",
+        "0x3.243f6a8885a30000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        100,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        "1.012",
+        "0x1.030#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        100,
+        "1.011513069911447955141394746898",
+        "0x1.02f28542bf08f7aeb699ab2dc#100",
+        Ordering::Less,
+    );
+    test("8.0", "0x8.0#1", 3, 1, "2.0", "0x2.0#1", Ordering::Equal);
+    test("8.0", "0x8.0#1", 3, 10, "2.0", "0x2.00#10", Ordering::Equal);
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        100,
+        "-2.0",
+        "-0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        1,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_root_round() {
+    let test = |s, s_hex, exp, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_round(exp, rm);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_round_ref(exp, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_round_assign(exp, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_root, rug_o) = rug_root_round(&rug::Float::exact_from(&x), exp, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        0,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        0,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        RoundingMode::Ceiling,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        RoundingMode::Up,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        RoundingMode::Nearest,
+        "1.5",
+        "0x1.8#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Floor,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Ceiling,
+        "1.4645918875615234",
+        "0x1.76ef7e73104b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Down,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Up,
+        "1.4645918875615234",
+        "0x1.76ef7e73104b8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Nearest,
+        "1.4645918875615231",
+        "0x1.76ef7e73104b7#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Floor,
+        "-1.4645918875615234",
+        "-0x1.76ef7e73104b8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Ceiling,
+        "-1.4645918875615231",
+        "-0x1.76ef7e73104b7#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Down,
+        "-1.4645918875615231",
+        "-0x1.76ef7e73104b7#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Up,
+        "-1.4645918875615234",
+        "-0x1.76ef7e73104b8#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        RoundingMode::Nearest,
+        "-1.4645918875615231",
+        "-0x1.76ef7e73104b7#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Floor,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Ceiling,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Down,
+        "1.3956124250860893",
+        "0x1.6546db1ba2d12#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Up,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        RoundingMode::Nearest,
+        "1.3956124250860895",
+        "0x1.6546db1ba2d13#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        RoundingMode::Floor,
+        "0.6933612743506347",
+        "0x0.b1801fdda61ba8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        RoundingMode::Ceiling,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        RoundingMode::Down,
+        "0.6933612743506347",
+        "0x0.b1801fdda61ba8#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        RoundingMode::Up,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        RoundingMode::Nearest,
+        "0.6933612743506348",
+        "0x0.b1801fdda61bb0#53",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Floor,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Ceiling,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Down,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Up,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        RoundingMode::Nearest,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Floor,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Ceiling,
+        "2.0e10",
+        "0x4.0E+8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Down,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Up,
+        "2.0e10",
+        "0x4.0E+8#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        RoundingMode::Nearest,
+        "9.0e9",
+        "0x2.0E+8#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        RoundingMode::Floor,
+        "2.5198420997897459",
+        "0x2.85145f31ae514#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        RoundingMode::Ceiling,
+        "2.5198420997897464",
+        "0x2.85145f31ae516#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        RoundingMode::Down,
+        "2.5198420997897459",
+        "0x2.85145f31ae514#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        RoundingMode::Up,
+        "2.5198420997897464",
+        "0x2.85145f31ae516#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        RoundingMode::Nearest,
+        "2.5198420997897464",
+        "0x2.85145f31ae516#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Floor,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Ceiling,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Down,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Up,
+        "1.7724538509055161",
+        "0x1.c5bf891b4ef6b#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Nearest,
+        "1.7724538509055159",
+        "0x1.c5bf891b4ef6a#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        RoundingMode::Floor,
+        "1.3313353638003895",
+        "0x1.54d264f787eb6#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        RoundingMode::Ceiling,
+        "1.3313353638003897",
+        "0x1.54d264f787eb7#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        RoundingMode::Down,
+        "1.3313353638003895",
+        "0x1.54d264f787eb6#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        RoundingMode::Up,
+        "1.3313353638003897",
+        "0x1.54d264f787eb7#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        RoundingMode::Nearest,
+        "1.3313353638003897",
+        "0x1.54d264f787eb7#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        RoundingMode::Floor,
+        "1.2840254166877414",
+        "0x1.48b5e3c3e8186#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        RoundingMode::Ceiling,
+        "1.2840254166877416",
+        "0x1.48b5e3c3e8187#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        RoundingMode::Down,
+        "1.2840254166877414",
+        "0x1.48b5e3c3e8186#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        RoundingMode::Up,
+        "1.2840254166877416",
+        "0x1.48b5e3c3e8187#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        RoundingMode::Nearest,
+        "1.2840254166877414",
+        "0x1.48b5e3c3e8186#53",
+        Ordering::Less,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.00#7",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Exact,
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "1.0115130699114478",
+        "0x1.02f28542bf08f#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "1.0115130699114481",
+        "0x1.02f28542bf090#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Down,
+        "1.0115130699114478",
+        "0x1.02f28542bf08f#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Up,
+        "1.0115130699114481",
+        "0x1.02f28542bf090#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "1.0115130699114478",
+        "0x1.02f28542bf08f#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        RoundingMode::Exact,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0#5",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn test_root_prec_round() {
+    let test = |s, s_hex, exp, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (root, o) = x.clone().root_prec_round(exp, prec, rm);
+        assert!(root.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(root.to_string(), out);
+        assert_eq!(to_hex_string(&root), out_hex);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(exp, prec, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut root_alt = x.clone();
+        let o_alt = root_alt.root_prec_round_assign(exp, prec, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&root_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_root, rug_o) = rug_root_prec_round(&rug::Float::exact_from(&x), exp, prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        3,
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        0,
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        3,
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        3,
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        3,
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        3,
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        3,
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        2,
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        2,
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        0,
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        0,
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "-1.0",
+        "-0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        3,
+        100,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        1,
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        1,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.441",
+        "0x1.710#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.443",
+        "0x1.718#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.441",
+        "0x1.710#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.443",
+        "0x1.718#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.441",
+        "0x1.710#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        RoundingMode::Floor,
+        "1.442249570307408382321638310779",
+        "0x1.7137449123ef65cdde7f16c56#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "1.442249570307408382321638310781",
+        "0x1.7137449123ef65cdde7f16c58#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        RoundingMode::Down,
+        "1.442249570307408382321638310779",
+        "0x1.7137449123ef65cdde7f16c56#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        RoundingMode::Up,
+        "1.442249570307408382321638310781",
+        "0x1.7137449123ef65cdde7f16c58#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "1.442249570307408382321638310779",
+        "0x1.7137449123ef65cdde7f16c56#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.463",
+        "0x1.768#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.463",
+        "0x1.768#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.465",
+        "0x1.770#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-1.465",
+        "-0x1.770#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-1.463",
+        "-0x1.768#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Down,
+        "-1.463",
+        "-0x1.768#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Up,
+        "-1.465",
+        "-0x1.770#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-1.465",
+        "-0x1.770#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.395",
+        "0x1.650#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.396",
+        "0x1.658#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "0.694",
+        "0x0.b1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        RoundingMode::Down,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        RoundingMode::Up,
+        "0.694",
+        "0x0.b1c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        RoundingMode::Floor,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        RoundingMode::Ceiling,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        RoundingMode::Down,
+        "6.0e-11",
+        "0x4.0E-9#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        RoundingMode::Up,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        1,
+        RoundingMode::Nearest,
+        "1.0e-10",
+        "0x8.0E-9#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "9.23e-11",
+        "0x6.58E-9#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "9.24e-11",
+        "0x6.5aE-9#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "9.23e-11",
+        "0x6.58E-9#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "9.24e-11",
+        "0x6.5aE-9#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "9.24e-11",
+        "0x6.5aE-9#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        RoundingMode::Floor,
+        "9.23989021666464090086348377675e-11",
+        "0x6.597fa94f5b8f20ac16666ad08E-9#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        RoundingMode::Ceiling,
+        "9.23989021666464090086348377676e-11",
+        "0x6.597fa94f5b8f20ac16666ad10E-9#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        RoundingMode::Down,
+        "9.23989021666464090086348377675e-11",
+        "0x6.597fa94f5b8f20ac16666ad08E-9#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        RoundingMode::Up,
+        "9.23989021666464090086348377676e-11",
+        "0x6.597fa94f5b8f20ac16666ad10E-9#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        3,
+        100,
+        RoundingMode::Nearest,
+        "9.23989021666464090086348377676e-11",
+        "0x6.597fa94f5b8f20ac16666ad10E-9#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "1.082e10",
+        "0x2.85E+8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "1.084e10",
+        "0x2.86E+8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "1.082e10",
+        "0x2.85E+8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "1.084e10",
+        "0x2.86E+8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "1.082e10",
+        "0x2.85E+8#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        RoundingMode::Floor,
+        "2.52",
+        "0x2.85#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "2.523",
+        "0x2.86#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        RoundingMode::Down,
+        "2.52",
+        "0x2.85#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        RoundingMode::Up,
+        "2.523",
+        "0x2.86#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "2.52",
+        "0x2.85#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Floor,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Ceiling,
+        "1.773",
+        "0x1.c60#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Down,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Up,
+        "1.773",
+        "0x1.c60#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Nearest,
+        "1.771",
+        "0x1.c58#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        2,
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        RoundingMode::Floor,
+        "1.33",
+        "0x1.548#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        RoundingMode::Ceiling,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        RoundingMode::Down,
+        "1.33",
+        "0x1.548#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        RoundingMode::Up,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        4,
+        10,
+        RoundingMode::Nearest,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        RoundingMode::Floor,
+        "1.283",
+        "0x1.488#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        RoundingMode::Ceiling,
+        "1.285",
+        "0x1.490#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        RoundingMode::Down,
+        "1.283",
+        "0x1.488#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        RoundingMode::Up,
+        "1.285",
+        "0x1.490#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        4,
+        10,
+        RoundingMode::Nearest,
+        "1.283",
+        "0x1.488#10",
+        Ordering::Less,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "81.0",
+        "0x51.0#7",
+        4,
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        RoundingMode::Down,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        100,
+        RoundingMode::Exact,
+        "3.141592653589793115997963468544",
+        "0x3.243f6a8885a30000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        RoundingMode::Ceiling,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        RoundingMode::Down,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        RoundingMode::Up,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        10,
+        RoundingMode::Nearest,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        0,
+        100,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        RoundingMode::Floor,
+        "1.01",
+        "0x1.028#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        RoundingMode::Ceiling,
+        "1.012",
+        "0x1.030#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        RoundingMode::Down,
+        "1.01",
+        "0x1.028#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        RoundingMode::Up,
+        "1.012",
+        "0x1.030#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        10,
+        RoundingMode::Nearest,
+        "1.012",
+        "0x1.030#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "8.0",
+        "0x8.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-8.0",
+        "-0x8.0#1",
+        3,
+        100,
+        RoundingMode::Exact,
+        "-2.0",
+        "-0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Floor,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Down,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Up,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        10,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "27.0",
+        "0x1b.0#5",
+        3,
+        100,
+        RoundingMode::Exact,
+        "3.0",
+        "0x3.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+}
+
+#[test]
+fn root_prec_fail() {
+    assert_panic!(Float::NAN.root_prec(3, 0));
+    assert_panic!(Float::ONE.root_prec(3, 0));
+}
+
+#[test]
+fn root_prec_ref_fail() {
+    assert_panic!(Float::NAN.root_prec_ref(3, 0));
+    assert_panic!(Float::ONE.root_prec_ref(3, 0));
+}
+
+#[test]
+fn root_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.root_prec_assign(3, 0)
+    });
+}
+
+#[test]
+fn root_round_fail() {
+    assert_panic!(Float::from(3u32).root_round(3, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).root_round_ref(3, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.root_round_assign(3, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn root_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).root_prec_round(3, 0, RoundingMode::Floor));
+    assert_panic!(Float::from(3u32).root_prec_round(3, 1, RoundingMode::Exact));
+    assert_panic!(Float::from(3u32).root_prec_round_ref(3, 1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::from(3u32);
+        x.root_prec_round_assign(3, 1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn root_prec_round_properties() {
+    float_unsigned_unsigned_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, exp, prec, rm)| {
+            let (root, o) = x.clone().root_prec_round(exp, prec, rm);
+            assert!(root.is_valid());
+
+            let (root_alt, o_alt) = x.root_prec_round_ref(exp, prec, rm);
+            assert!(root_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            let mut x_alt = x.clone();
+            let o_alt = x_alt.root_prec_round_assign(exp, prec, rm);
+            assert!(x_alt.is_valid());
+            assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+            assert_eq!(o_alt, o);
+
+            if exp == 2 && (x != 0u32 || x.is_sign_positive()) {
+                let (root_alt, o_alt) = x.sqrt_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+                assert_eq!(o_alt, o);
+            }
+
+            if x.is_finite() && x != 0u32 && (x > 0u32 || exp.odd()) {
+                assert!(root.is_normal());
+                assert_eq!(root.get_prec(), Some(prec));
+                assert_eq!(
+                    Rational::exact_from(&root)
+                        .pow(exp)
+                        .partial_cmp(&Rational::exact_from(&x)),
+                    Some(o)
+                );
+            }
+
+            if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+                let (rug_root, rug_o) =
+                    rug_root_prec_round(&rug::Float::exact_from(&x), exp, prec, rm);
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_root)),
+                    ComparableFloatRef(&root)
+                );
+                assert_eq!(rug_o, o);
+            }
+
+            if exp.odd() {
+                let (root_alt, o_alt) = (-&x).root_prec_round(exp, prec, -rm);
+                assert_eq!(ComparableFloat(-root_alt), ComparableFloat(root.clone()));
+                assert_eq!(o_alt.reverse(), o);
+            }
+
+            if o == Ordering::Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (s, oo) = x.root_prec_round_ref(exp, prec, rm);
+                    assert_eq!(ComparableFloat(s), ComparableFloat(root.clone()));
+                    assert_eq!(oo, Ordering::Equal);
+                }
+            } else {
+                assert_panic!(x.root_prec_round_ref(exp, prec, RoundingMode::Exact));
+            }
+        },
+    );
+}
+
+#[test]
+fn root_prec_properties() {
+    float_unsigned_unsigned_triple_gen_var_1().test_properties(|(x, exp, prec)| {
+        let (root, o) = x.clone().root_prec(exp, prec);
+        assert!(root.is_valid());
+
+        let (root_alt, o_alt) = x.root_prec_ref(exp, prec);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.root_prec_assign(exp, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(exp, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let (rug_root, rug_o) = rug_root_prec(&rug::Float::exact_from(&x), exp, prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root)),
+            ComparableFloatRef(&root)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn root_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_6().test_properties(|(x, exp, rm)| {
+        let (root, o) = x.clone().root_round(exp, rm);
+        assert!(root.is_valid());
+
+        let (root_alt, o_alt) = x.root_round_ref(exp, rm);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.root_round_assign(exp, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        let (root_alt, o_alt) = x.root_prec_round_ref(exp, x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_root, rug_o) = rug_root_round(&rug::Float::exact_from(&x), exp, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_root)),
+                ComparableFloatRef(&root)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn root_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, exp)| {
+        let root = x.clone().root(exp);
+        assert!(root.is_valid());
+
+        let root_alt = (&x).root(exp);
+        assert!(root_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+
+        let mut x_alt = x.clone();
+        x_alt.root_assign(exp);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&root));
+
+        let root_alt = x
+            .root_prec_round_ref(exp, x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&root_alt), ComparableFloatRef(&root));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_root(&rug::Float::exact_from(&x), exp))),
+            ComparableFloatRef(&root)
+        );
+
+        if exp == 1 {
+            assert_eq!(ComparableFloatRef(&root), ComparableFloatRef(&x));
+        }
+        if x < 0u32 && exp.even() {
+            assert!(root.is_nan());
+        }
+    });
+
+    float_gen().test_properties(|x| {
+        assert!((&x).root(0).is_nan());
+        if x != 0u32 || x.is_sign_positive() {
+            assert_eq!(ComparableFloat((&x).root(2)), ComparableFloat(x.sqrt()));
+        }
+    });
+
+    assert!(Float::NAN.root(3).is_nan());
+    assert_eq!(Float::INFINITY.root(3), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_INFINITY.root(3), Float::NEGATIVE_INFINITY);
+    assert!(Float::NEGATIVE_INFINITY.root(4).is_nan());
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.root(3)),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.root(4)),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(Float::ONE.root(3), Float::ONE);
+    assert_eq!(Float::NEGATIVE_ONE.root(3), Float::NEGATIVE_ONE);
+    assert!(Float::NEGATIVE_ONE.root(4).is_nan());
+    assert_eq!(Float::from(8u32).root(3), 2u32);
+}
@@ -0,0 +1,620 @@
+use malachite_base::num::arithmetic::traits::{Cos, Sin, SinCos};
+use malachite_base::num::basic::traits::{NaN, One};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::sin_cos::{
+    rug_sin_cos, rug_sin_cos_prec, rug_sin_cos_prec_round, rug_sin_cos_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_19, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_15,
+};
+use malachite_float::{ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sin_cos_prec_round() {
+    let test = |s,
+                s_hex,
+                prec,
+                rm,
+                out_sin: &str,
+                out_sin_hex: &str,
+                o_sin_out,
+                out_cos: &str,
+                out_cos_hex: &str,
+                o_cos_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let ((sin, o_sin), (cos, o_cos)) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+        assert_eq!(o_sin, o_sin_out);
+        assert_eq!(o_cos, o_cos_out);
+
+        assert_eq!(sin.to_string(), out_sin);
+        assert_eq!(to_hex_string(&sin), out_sin_hex);
+        assert_eq!(cos.to_string(), out_cos);
+        assert_eq!(to_hex_string(&cos), out_cos_hex);
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) = x.sin_cos_prec_round_ref(prec, rm);
+        assert!(sin_alt.is_valid());
+        assert!(cos_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sin), ComparableFloatRef(&sin_alt));
+        assert_eq!(ComparableFloatRef(&cos), ComparableFloatRef(&cos_alt));
+        assert_eq!(o_sin_alt, o_sin_out);
+        assert_eq!(o_cos_alt, o_cos_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_o_sin), (rug_cos, rug_o_cos)) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.842",
+        "0x0.d78#10",
+        Ordering::Greater,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "0.842",
+        "0x0.d78#10",
+        Ordering::Greater,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Down,
+        "0.84147098480789650665250232163",
+        "0x0.d76aa47848677020c6e9e909c#100",
+        Ordering::Less,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.841",
+        "-0x0.d74#10",
+        Ordering::Greater,
+        "0.541",
+        "0x0.8a8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.842",
+        "-0x0.d78#10",
+        Ordering::Less,
+        "0.54",
+        "0x0.8a4#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Down,
+        "-0.84147098480789650665250232163",
+        "-0x0.d76aa47848677020c6e9e909c#100",
+        Ordering::Greater,
+        "0.5403023058681397174009366074426",
+        "0x0.8a51407da8345c91c2466d976#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Ordering::Less,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Down,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Ordering::Less,
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "-2.0e-16",
+        "-0x1.0E-13#1",
+        Ordering::Less,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Ordering::Greater,
+        "-0.999",
+        "-0x0.ffc#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Ordering::Less,
+        "-1.0",
+        "-0x1.000#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Down,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Ordering::Greater,
+        "-0.999999999999999999999999999999",
+        "-0x0.fffffffffffffffffffffffff#100",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        RoundingMode::Floor,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.4111",
+        "0x0.694#10",
+        Ordering::Greater,
+        "-0.911",
+        "-0x0.e94#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "0.4106",
+        "0x0.692#10",
+        Ordering::Less,
+        "-0.912",
+        "-0x0.e98#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        RoundingMode::Down,
+        "0.4107812905029088272805394916098",
+        "0x0.6928f67084c77a976544888ac0#100",
+        Ordering::Less,
+        "-0.911733914786965038509247738199",
+        "-0x0.e96764d2aa0034ce60b62d754#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "4.0e-31",
+        "0x8.0E-26#1",
+        Ordering::Less,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Greater,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.888609052210118054117285652822e-31",
+        "0xf.ffffffffffffffffffffffffE-26#100",
+        Ordering::Less,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        1,
+        RoundingMode::Floor,
+        "0.1",
+        "0x0.2#1",
+        Ordering::Less,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Ceiling,
+        "0.1279",
+        "0x0.20c#10",
+        Ordering::Greater,
+        "-0.991",
+        "-0x0.fdc#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Nearest,
+        "0.1279",
+        "0x0.20c#10",
+        Ordering::Greater,
+        "-0.992",
+        "-0x0.fe0#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        100,
+        RoundingMode::Down,
+        "0.1278160779308964756593769921504",
+        "0x0.20b88df29dbe7a5297c5126354#100",
+        Ordering::Less,
+        "-0.9917978877888190980222269173873",
+        "-0x0.fde677644b7bab1e22fc63e1f#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sin_cos_prec_fail() {
+    assert_panic!(Float::NAN.sin_cos_prec(0));
+    assert_panic!(Float::ONE.sin_cos_prec(0));
+    assert_panic!(Float::ONE.sin_cos_prec_ref(0));
+}
+
+#[test]
+fn sin_cos_round_fail() {
+    assert_panic!(Float::ONE.sin_cos_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.sin_cos_round_ref(RoundingMode::Exact));
+}
+
+#[test]
+fn sin_cos_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).sin_cos_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sin_cos_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.sin_cos_prec_round_ref(1, RoundingMode::Exact));
+}
+
+#[test]
+fn sin_cos_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_15().test_properties(|(x, prec, rm)| {
+        let ((sin, o_sin), (cos, o_cos)) = x.clone().sin_cos_prec_round(prec, rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) = x.sin_cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let (sin_alt, o_sin_alt) = x.sin_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(o_sin_alt, o_sin);
+        let (cos_alt, o_cos_alt) = x.cos_prec_round_ref(prec, rm);
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_cos_alt, o_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_o_sin), (rug_cos, rug_o_cos)) =
+                rug_sin_cos_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let ((sin, o_sin), (cos, o_cos)) = x.clone().sin_cos_prec(prec);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) = x.sin_cos_prec_ref(prec);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) =
+            x.sin_cos_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let ((rug_sin, rug_o_sin), (rug_cos, rug_o_cos)) =
+            rug_sin_cos_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+        assert_eq!(rug_o_sin, o_sin);
+        assert_eq!(rug_o_cos, o_cos);
+    });
+}
+
+#[test]
+fn sin_cos_round_properties() {
+    float_rounding_mode_pair_gen_var_19().test_properties(|(x, rm)| {
+        let ((sin, o_sin), (cos, o_cos)) = x.clone().sin_cos_round(rm);
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) = x.sin_cos_round_ref(rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        let ((sin_alt, o_sin_alt), (cos_alt, o_cos_alt)) =
+            x.sin_cos_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+        assert_eq!(o_sin_alt, o_sin);
+        assert_eq!(o_cos_alt, o_cos);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let ((rug_sin, rug_o_sin), (rug_cos, rug_o_cos)) =
+                rug_sin_cos_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sin)),
+                ComparableFloatRef(&sin)
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_cos)),
+                ComparableFloatRef(&cos)
+            );
+            assert_eq!(rug_o_sin, o_sin);
+            assert_eq!(rug_o_cos, o_cos);
+        }
+    });
+}
+
+#[test]
+fn sin_cos_properties() {
+    float_gen().test_properties(|x| {
+        let (sin, cos) = x.clone().sin_cos();
+        assert!(sin.is_valid());
+        assert!(cos.is_valid());
+
+        let (sin_alt, cos_alt) = (&x).sin_cos();
+        assert_eq!(ComparableFloatRef(&sin_alt), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&cos_alt), ComparableFloatRef(&cos));
+
+        assert_eq!(ComparableFloatRef(&(&x).sin()), ComparableFloatRef(&sin));
+        assert_eq!(ComparableFloatRef(&(&x).cos()), ComparableFloatRef(&cos));
+
+        let (rug_sin, rug_cos) = rug_sin_cos(&rug::Float::exact_from(&x));
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sin)),
+            ComparableFloatRef(&sin)
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_cos)),
+            ComparableFloatRef(&cos)
+        );
+    });
+}
@@ -0,0 +1,2029 @@
+use malachite_base::num::arithmetic::traits::{PowerOf2, Sinh, SinhAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::sinh::{
+    rug_sinh, rug_sinh_prec, rug_sinh_prec_round, rug_sinh_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen_var_6, float_rounding_mode_pair_gen_var_25, float_unsigned_pair_gen_var_2,
+    float_unsigned_rounding_mode_triple_gen_var_21,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sinh() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let sinh = x.clone().sinh();
+        assert!(sinh.is_valid());
+
+        assert_eq!(sinh.to_string(), out);
+        assert_eq!(to_hex_string(&sinh), out_hex);
+
+        let sinh_alt = (&x).sinh();
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+
+        let mut sinh_alt = x.clone();
+        sinh_alt.sinh_assign();
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&sinh)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "Infinity", "Infinity");
+    test("-Infinity", "-Infinity", "-Infinity", "-Infinity");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "1.0", "0x1.0#1");
+    test("-1.0", "-0x1.0#1", "-1.0", "-0x1.0#1");
+    test("1.0", "0x1.000#10", "1.176", "0x1.2d0#10");
+    test("3.0", "0x3.0#2", "1.0e1", "0xc.0#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "11.548739357257746",
+        "0xb.8c7a2eb973388#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "-11.548739357257746",
+        "-0xb.8c7a2eb973388#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.33954055725615018",
+        "0x0.56ec214514ae08#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("100.0", "0x64.0#7", "1.34e43", "0x9.aE+35#7");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "4443055.260253872",
+        "0x43cbaf.429fff6c#53",
+    );
+    test("-100.0", "-0x64.0#7", "-1.34e43", "-0x9.aE+35#7");
+}
+
+#[test]
+fn test_sinh_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sinh, o) = x.clone().sinh_prec(prec);
+        assert!(sinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sinh.to_string(), out);
+        assert_eq!(to_hex_string(&sinh), out_hex);
+
+        let (sinh_alt, o_alt) = x.sinh_prec_ref(prec);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sinh_alt = x.clone();
+        let o_alt = sinh_alt.sinh_prec_assign(prec);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_sinh, rug_o) = rug_sinh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.175201193643801456882381850595",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Ordering::Less,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-1.0", "-0x1.0#1", Ordering::Greater);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.176",
+        "-0x1.2d0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.175201193643801456882381850595",
+        "-0x1.2cd9fc44eb9825a80249487f0#100",
+        Ordering::Greater,
+    );
+    test("1.0", "0x1.000#10", 1, "1.0", "0x1.0#1", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.175201193643801456882381850595",
+        "0x1.2cd9fc44eb9825a80249487f0#100",
+        Ordering::Less,
+    );
+    test("3.0", "0x3.0#2", 1, "8.0", "0x8.0#1", Ordering::Less);
+    test("3.0", "0x3.0#2", 10, "10.02", "0xa.04#10", Ordering::Less);
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "10.01787492740990189897459361946",
+        "0xa.04937384a4d6cdb80cf4a8f1#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "11.55",
+        "0xb.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "11.54873935725774695837248684208",
+        "0xb.8c7a2eb97338aae765800b8c#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "-11.55",
+        "-0xb.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "-11.54873935725774695837248684208",
+        "-0xb.8c7a2eb97338aae765800b8c#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "7.547",
+        "0x7.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "7.54413710281697472618644480474",
+        "0x7.8b4c91b5239bca56b32f58188#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3395405572561501781837707221282",
+        "0x0.56ec214514ae08114ed9a2c0d8#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        1,
+        "1.0e43",
+        "0x8.0E+35#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        100,
+        "1.34405857090806772420631277579e43",
+        "0x9.a4a54d8b8dfa566112849991E+35#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "4.0e6",
+        "0x4.0E+5#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "4443055.2602538721583425528554",
+        "0x43cbaf.429fff6d93d38397f300#100",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        1,
+        "-1.0e43",
+        "-0x8.0E+35#1",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        "-1.344e43",
+        "-0x9.a4E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        100,
+        "-1.34405857090806772420631277579e43",
+        "-0x9.a4a54d8b8dfa566112849991E+35#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_sinh_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sinh, o) = x.clone().sinh_round(rm);
+        assert!(sinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sinh.to_string(), out);
+        assert_eq!(to_hex_string(&sinh), out_hex);
+
+        let (sinh_alt, o_alt) = x.sinh_round_ref(rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sinh_alt = x.clone();
+        let o_alt = sinh_alt.sinh_round_assign(rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sinh, rug_o) = rug_sinh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.0e1",
+        "0xc.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "11.548739357257746",
+        "0xb.8c7a2eb973388#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "11.548739357257748",
+        "0xb.8c7a2eb973390#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "11.548739357257746",
+        "0xb.8c7a2eb973388#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "11.548739357257748",
+        "0xb.8c7a2eb973390#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "11.548739357257746",
+        "0xb.8c7a2eb973388#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-11.548739357257748",
+        "-0xb.8c7a2eb973390#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-11.548739357257746",
+        "-0xb.8c7a2eb973388#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-11.548739357257746",
+        "-0xb.8c7a2eb973388#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-11.548739357257748",
+        "-0xb.8c7a2eb973390#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-11.548739357257746",
+        "-0xb.8c7a2eb973388#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "7.5441371028169755",
+        "0x7.8b4c91b5239c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "7.5441371028169755",
+        "0x7.8b4c91b5239c0#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "7.5441371028169746",
+        "0x7.8b4c91b5239bc#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.33954055725615018",
+        "0x0.56ec214514ae08#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.33954055725615023",
+        "0x0.56ec214514ae0c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.33954055725615018",
+        "0x0.56ec214514ae08#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.33954055725615023",
+        "0x0.56ec214514ae0c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.33954055725615018",
+        "0x0.56ec214514ae08#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Floor,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Ceiling,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Down,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Up,
+        "1.36e43",
+        "0x9.cE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        RoundingMode::Nearest,
+        "1.34e43",
+        "0x9.aE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "4443055.260253872",
+        "0x43cbaf.429fff6c#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "4443055.260253873",
+        "0x43cbaf.429fff70#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "4443055.260253872",
+        "0x43cbaf.429fff6c#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "4443055.260253873",
+        "0x43cbaf.429fff70#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "4443055.260253872",
+        "0x43cbaf.429fff6c#53",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Floor,
+        "-1.36e43",
+        "-0x9.cE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Ceiling,
+        "-1.34e43",
+        "-0x9.aE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Down,
+        "-1.34e43",
+        "-0x9.aE+35#7",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Up,
+        "-1.36e43",
+        "-0x9.cE+35#7",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        RoundingMode::Nearest,
+        "-1.34e43",
+        "-0x9.aE+35#7",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_sinh_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (sinh, o) = x.clone().sinh_prec_round(prec, rm);
+        assert!(sinh.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(sinh.to_string(), out);
+        assert_eq!(to_hex_string(&sinh), out_hex);
+
+        let (sinh_alt, o_alt) = x.sinh_prec_round_ref(prec, rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut sinh_alt = x.clone();
+        let o_alt = sinh_alt.sinh_prec_round_assign(prec, rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh), ComparableFloatRef(&sinh_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sinh, rug_o) = rug_sinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "-Infinity",
+        "-Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.176",
+        "-0x1.2d0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.174",
+        "-0x1.2c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.174",
+        "-0x1.2c8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.176",
+        "-0x1.2d0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.176",
+        "-0x1.2d0#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.174",
+        "0x1.2c8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.176",
+        "0x1.2d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "2.0e1",
+        "0x1.0E+1#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "10.02",
+        "0xa.04#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "10.03",
+        "0xa.08#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "10.02",
+        "0xa.04#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "10.03",
+        "0xa.08#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "10.02",
+        "0xa.04#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "10.01787492740990189897459361946",
+        "0xa.04937384a4d6cdb80cf4a8f1#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "10.01787492740990189897459361948",
+        "0xa.04937384a4d6cdb80cf4a8f2#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "10.01787492740990189897459361946",
+        "0xa.04937384a4d6cdb80cf4a8f1#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "10.01787492740990189897459361948",
+        "0xa.04937384a4d6cdb80cf4a8f2#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "10.01787492740990189897459361946",
+        "0xa.04937384a4d6cdb80cf4a8f1#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "11.55",
+        "0xb.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "11.56",
+        "0xb.90#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "11.55",
+        "0xb.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "11.56",
+        "0xb.90#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "11.55",
+        "0xb.8c#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-11.56",
+        "-0xb.90#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-11.55",
+        "-0xb.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-11.55",
+        "-0xb.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-11.56",
+        "-0xb.90#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-11.55",
+        "-0xb.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "7.54",
+        "0x7.8a#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "7.547",
+        "0x7.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "7.54",
+        "0x7.8a#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "7.547",
+        "0x7.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "7.547",
+        "0x7.8c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.3398",
+        "0x0.570#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3394",
+        "0x0.56e#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "1.346e43",
+        "0x9.a8E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "100.0",
+        "0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "1.344e43",
+        "0x9.a4E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "4.45e6",
+        "0x4.3eE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "4.45e6",
+        "0x4.3eE+5#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "4.44e6",
+        "0x4.3cE+5#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Floor,
+        "-1.346e43",
+        "-0x9.a8E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Ceiling,
+        "-1.344e43",
+        "-0x9.a4E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Down,
+        "-1.344e43",
+        "-0x9.a4E+35#10",
+        Ordering::Greater,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Up,
+        "-1.346e43",
+        "-0x9.a8E+35#10",
+        Ordering::Less,
+    );
+    test(
+        "-100.0",
+        "-0x64.0#7",
+        10,
+        RoundingMode::Nearest,
+        "-1.344e43",
+        "-0x9.a4E+35#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sinh_prec_fail() {
+    assert_panic!(Float::NAN.sinh_prec(0));
+    assert_panic!(Float::ONE.sinh_prec(0));
+}
+
+#[test]
+fn sinh_prec_ref_fail() {
+    assert_panic!(Float::NAN.sinh_prec_ref(0));
+    assert_panic!(Float::ONE.sinh_prec_ref(0));
+}
+
+#[test]
+fn sinh_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sinh_prec_assign(0)
+    });
+}
+
+#[test]
+fn sinh_round_fail() {
+    assert_panic!(Float::ONE.sinh_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.sinh_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sinh_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn sinh_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).sinh_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.sinh_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.sinh_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sinh_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn sinh_overflow_fail() {
+    assert_panic!(Float::power_of_2(100i64).sinh());
+    assert_panic!(Float::power_of_2(100i64).sinh_prec(10));
+    assert_panic!(Float::power_of_2(100i64).sinh_round(RoundingMode::Floor));
+}
+
+#[test]
+fn sinh_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_21().test_properties(|(x, prec, rm)| {
+        let (sinh, o) = x.clone().sinh_prec_round(prec, rm);
+        assert!(sinh.is_valid());
+
+        let (sinh_alt, o_alt) = x.sinh_prec_round_ref(prec, rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sinh_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 {
+            assert!(sinh.is_normal());
+            assert_eq!(sinh.get_prec(), Some(prec));
+            assert!((sinh > 0u32) == (x > 0u32));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sinh, rug_o) = rug_sinh_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.sinh_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(sinh.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.sinh_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn sinh_prec_properties() {
+    float_unsigned_pair_gen_var_2().test_properties(|(x, prec)| {
+        let (sinh, o) = x.clone().sinh_prec(prec);
+        assert!(sinh.is_valid());
+
+        let (sinh_alt, o_alt) = x.sinh_prec_ref(prec);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sinh_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let (sinh_alt, o_alt) = x.sinh_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let (rug_sinh, rug_o) = rug_sinh_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh)),
+            ComparableFloatRef(&sinh)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn sinh_round_properties() {
+    float_rounding_mode_pair_gen_var_25().test_properties(|(x, rm)| {
+        let (sinh, o) = x.clone().sinh_round(rm);
+        assert!(sinh.is_valid());
+
+        let (sinh_alt, o_alt) = x.sinh_round_ref(rm);
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sinh_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        let (sinh_alt, o_alt) = x.sinh_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_sinh, rug_o) = rug_sinh_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_sinh)),
+                ComparableFloatRef(&sinh)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn sinh_properties() {
+    float_gen_var_6().test_properties(|x| {
+        let sinh = x.clone().sinh();
+        assert!(sinh.is_valid());
+
+        let sinh_alt = (&x).sinh();
+        assert!(sinh_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+
+        let mut x_alt = x.clone();
+        x_alt.sinh_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&sinh));
+
+        let sinh_alt = x
+            .sinh_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&sinh_alt), ComparableFloatRef(&sinh));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sinh(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&sinh)
+        );
+    });
+
+    assert!(Float::NAN.sinh().is_nan());
+    assert_eq!(Float::INFINITY.sinh(), Float::INFINITY);
+    assert_eq!(Float::NEGATIVE_INFINITY.sinh(), Float::NEGATIVE_INFINITY);
+    assert_eq!(
+        ComparableFloat(Float::ZERO.sinh()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.sinh()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
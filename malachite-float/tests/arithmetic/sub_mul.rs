@@ -0,0 +1,3777 @@
+use malachite_base::num::arithmetic::traits::{SubMul, SubMulAssign};
+use malachite_base::num::basic::traits::{NaN, One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::sub_mul::{
+    rug_sub_mul, rug_sub_mul_prec, rug_sub_mul_prec_round, rug_sub_mul_round,
+    sub_mul_prec_round_naive,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_float_float_rounding_mode_quadruple_gen_var_2,
+    float_float_float_unsigned_quadruple_gen_var_1,
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_2, float_pair_gen, float_triple_gen,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+use std::cmp::{max, Ordering};
+use std::panic::catch_unwind;
+
+#[test]
+fn test_sub_mul() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let result = x.clone().sub_mul(y.clone(), z.clone());
+        assert!(result.is_valid());
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let result_alt = x.clone().sub_mul(y.clone(), &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = x.clone().sub_mul(&y, z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = x.clone().sub_mul(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let result_alt = (&x).sub_mul(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+
+        let mut result_alt = x.clone();
+        result_alt.sub_mul_assign(y.clone(), z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.sub_mul_assign(y.clone(), &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.sub_mul_assign(&y, z.clone());
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        let mut result_alt = x.clone();
+        result_alt.sub_mul_assign(&y, &z);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_sub_mul(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z)
+            ))),
+            ComparableFloatRef(&result)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN");
+    test(
+        "NaN", "NaN", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "NaN", "NaN",
+    );
+    test(
+        "1.0", "0x1.0#1", "NaN", "NaN", "1.0", "0x1.0#1", "NaN", "NaN",
+    );
+    test(
+        "1.0", "0x1.0#1", "Infinity", "Infinity", "0.0", "0x0.0", "NaN", "NaN",
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "NaN",
+        "NaN",
+    );
+    test(
+        "Infinity", "Infinity", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "Infinity", "Infinity",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+    );
+    test(
+        "0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "0.0", "0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "-0.0", "-0x0.0", "-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0",
+    );
+    test(
+        "-0.0", "-0x0.0", "0.0", "0x0.0", "0.0", "0x0.0", "-0.0", "-0x0.0",
+    );
+    test(
+        "1.0", "0x1.0#1", "0.0", "0x0.0", "1.0", "0x1.0#1", "1.0", "0x1.0#1",
+    );
+    test(
+        "0.0", "0x0.0", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "1.0", "0x1.0#1",
+    );
+    test(
+        "1.0", "0x1.0#1", "2.0", "0x2.0#1", "3.0", "0x3.0#2", "-4.0", "-0x4.0#2",
+    );
+    test(
+        "1.0", "0x1.0#1", "1.0", "0x1.0#1", "-1.0", "-0x1.0#1", "2.0", "0x2.0#1",
+    );
+    test(
+        "-1.0", "-0x1.0#1", "1.0", "0x1.0#1", "1.0", "0x1.0#1", "-2.0", "-0x2.0#1",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-5.398141569083773",
+        "-0x5.65ec9b1a64970#53",
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "10.530648752520442",
+        "0xa.87d898bdbd0e0#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-13.011197054679151",
+        "-0xd.02ddcf67a8920#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "1.7182818284590451",
+        "0x1.b7e151628aed2#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0",
+        "0x1.0#1",
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "-2.0e60",
+        "-0x1.0E+50#1",
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+    );
+}
+
+#[test]
+fn test_sub_mul_prec() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().sub_mul_prec(y.clone(), z.clone(), prec);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().sub_mul_prec_val_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.sub_mul_prec_ref_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_prec_assign(y.clone(), z.clone(), prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_prec_assign_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_result, rug_o) = rug_sub_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "-5.0",
+        "-0x5.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        "-2.0",
+        "-0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-5.4",
+        "-0x5.66#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-5.398141569083773162408707886572",
+        "-0x5.65ec9b1a64971679e124a69b8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "8.0",
+        "0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "10.53",
+        "0xa.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "10.53064875252044255729325569793",
+        "0xa.87d898bdbd0e3a6af7310224#100",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-2.0e1",
+        "-0x1.0E+1#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-13.02",
+        "-0xd.04#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-13.0111970546791509653641769797",
+        "-0xd.02ddcf67a891f5b33ef2c5c9#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        1,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        "1.718281828459044979773295835912",
+        "0x1.b7e151628aed1800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        "1.334",
+        "0x1.558#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        100,
+        "1.333333333333333481363069950021",
+        "0x1.5555555555556000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "-2.0e60",
+        "-0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "-1.607e60",
+        "-0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "-1.606938044258990275541962092341e60",
+        "-0x1.0000000000000000000000000E+50#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "1.0e30",
+        "0x1.0E+25#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "1267650600228229401496703205376.0",
+        "0x10000000000000000000000000.0#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_sub_mul_round() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().sub_mul_round(y.clone(), z.clone(), rm);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().sub_mul_round_val_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.sub_mul_round_ref_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_round_assign(y.clone(), z.clone(), rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_round_assign_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_sub_mul_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "-6.0",
+        "-0x6.0#2",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "-4.0",
+        "-0x4.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "-4.0",
+        "-0x4.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "-6.0",
+        "-0x6.0#2",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#2",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Exact,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-5.398141569083774",
+        "-0x5.65ec9b1a64974#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-5.398141569083773",
+        "-0x5.65ec9b1a64970#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-5.398141569083773",
+        "-0x5.65ec9b1a64970#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-5.398141569083774",
+        "-0x5.65ec9b1a64974#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-5.398141569083773",
+        "-0x5.65ec9b1a64970#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "10.530648752520442",
+        "0xa.87d898bdbd0e0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "10.530648752520444",
+        "0xa.87d898bdbd0e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "10.530648752520442",
+        "0xa.87d898bdbd0e0#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "10.530648752520444",
+        "0xa.87d898bdbd0e8#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "10.530648752520442",
+        "0xa.87d898bdbd0e0#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-13.011197054679151",
+        "-0xd.02ddcf67a8920#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-13.011197054679149",
+        "-0xd.02ddcf67a8918#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-13.011197054679149",
+        "-0xd.02ddcf67a8918#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-13.011197054679151",
+        "-0xd.02ddcf67a8920#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-13.011197054679151",
+        "-0xd.02ddcf67a8920#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "1.7182818284590449",
+        "0x1.b7e151628aed1#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "1.7182818284590451",
+        "0x1.b7e151628aed2#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "1.7182818284590449",
+        "0x1.b7e151628aed1#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "1.7182818284590451",
+        "0x1.b7e151628aed2#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "1.7182818284590451",
+        "0x1.b7e151628aed2#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Down,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Up,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        RoundingMode::Exact,
+        "1.3333333333333335",
+        "0x1.5555555555556#53",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "-2.0e60",
+        "-0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "-8.0e59",
+        "-0x8.0E+49#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "-8.0e59",
+        "-0x8.0E+49#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "-2.0e60",
+        "-0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "-2.0e60",
+        "-0x1.0E+50#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.2676506002282293e30",
+        "0xf.ffffffffffff8E+24#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.2676506002282293e30",
+        "0xf.ffffffffffff8E+24#53",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2676506002282294e30",
+        "0x1.0000000000000E+25#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_sub_mul_prec_round() {
+    let test = |s, s_hex, t, t_hex, u, u_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+        let y = parse_hex_string(t_hex);
+        assert_eq!(y.to_string(), t);
+        let z = parse_hex_string(u_hex);
+        assert_eq!(z.to_string(), u);
+
+        let (result, o) = x.clone().sub_mul_prec_round(y.clone(), z.clone(), prec, rm);
+        assert!(result.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(result.to_string(), out);
+        assert_eq!(to_hex_string(&result), out_hex);
+
+        let (result_alt, o_alt) = x.clone().sub_mul_prec_round_val_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (result_alt, o_alt) = x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut result_alt = x.clone();
+        let o_alt = result_alt.sub_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result), ComparableFloatRef(&result_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_sub_mul_prec_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                prec,
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        1,
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "NaN",
+        "NaN",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "Infinity",
+        "Infinity",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "-Infinity",
+        "-Infinity",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "Infinity",
+        "Infinity",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        "0.0",
+        "0x0.0",
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Exact,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "-5.0",
+        "-0x5.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "2.0",
+        "0x2.0#1",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "-5.0",
+        "-0x5.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "2.0",
+        "0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.00#10",
+        Ordering::Equal,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        "1.0",
+        "0x1.0#1",
+        100,
+        RoundingMode::Exact,
+        "-2.0",
+        "-0x2.0000000000000000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Floor,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Ceiling,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Down,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Up,
+        "-8.0",
+        "-0x8.0#1",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        RoundingMode::Nearest,
+        "-4.0",
+        "-0x4.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-5.4",
+        "-0x5.66#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-5.39",
+        "-0x5.64#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-5.39",
+        "-0x5.64#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-5.4",
+        "-0x5.66#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-5.4",
+        "-0x5.66#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Floor,
+        "-5.398141569083773162408707886572",
+        "-0x5.65ec9b1a64971679e124a69b8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Ceiling,
+        "-5.398141569083773162408707886566",
+        "-0x5.65ec9b1a64971679e124a69b0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Down,
+        "-5.398141569083773162408707886566",
+        "-0x5.65ec9b1a64971679e124a69b0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Up,
+        "-5.398141569083773162408707886572",
+        "-0x5.65ec9b1a64971679e124a69b8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Nearest,
+        "-5.398141569083773162408707886572",
+        "-0x5.65ec9b1a64971679e124a69b8#100",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "10.52",
+        "0xa.84#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "10.53",
+        "0xa.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "10.52",
+        "0xa.84#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "10.53",
+        "0xa.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-2.7182818284590451",
+        "-0x2.b7e151628aed2#53",
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "10.53",
+        "0xa.88#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-13.02",
+        "-0xd.04#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-13.0",
+        "-0xd.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-13.0",
+        "-0xd.00#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-13.02",
+        "-0xd.04#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-13.02",
+        "-0xd.04#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        RoundingMode::Exact,
+        "-13.0111970546791509653641769797",
+        "-0xd.02ddcf67a891f5b33ef2c5c9#100",
+        Ordering::Equal,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "1.717",
+        "0x1.b78#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "1.719",
+        "0x1.b80#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Exact,
+        "1.718281828459044979773295835912",
+        "0x1.b7e151628aed1800000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.334",
+        "0x1.558#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "1.332",
+        "0x1.550#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "1.334",
+        "0x1.558#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "1.334",
+        "0x1.558#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "3.0",
+        "0x3.0#2",
+        "-0.33333333333333337",
+        "-0x0.55555555555558#53",
+        100,
+        RoundingMode::Exact,
+        "1.333333333333333481363069950021",
+        "0x1.5555555555556000000000000#100",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "0.5",
+        "0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "0.999",
+        "0x0.ffc#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.000#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "0.999999999999999999999999999999",
+        "0x0.fffffffffffffffffffffffff#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0000000000000000000000000#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "-1.607e60",
+        "-0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.605e60",
+        "-0xf.fcE+49#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "-1.605e60",
+        "-0xf.fcE+49#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "-1.607e60",
+        "-0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.607e60",
+        "-0x1.000E+50#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.266e30",
+        "0xf.fcE+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.266e30",
+        "0xf.fcE+24#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        "8.0e-31",
+        "0x1.0E-25#1",
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.268e30",
+        "0x1.000E+25#10",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn sub_mul_prec_fail() {
+    assert_panic!(Float::NAN.sub_mul_prec(Float::NAN, Float::NAN, 0));
+    assert_panic!(Float::ONE.sub_mul_prec(Float::TWO, Float::TWO, 0));
+    assert_panic!(Float::ONE.sub_mul_prec_val_ref_ref(&Float::TWO, &Float::TWO, 0));
+    assert_panic!(Float::ONE.sub_mul_prec_ref_ref_ref(&Float::TWO, &Float::TWO, 0));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_mul_prec_assign(Float::TWO, Float::TWO, 0)
+    });
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.sub_mul_prec_assign_ref_ref(&Float::TWO, &Float::TWO, 0)
+    });
+}
+
+#[test]
+fn sub_mul_round_fail() {
+    let three = Float::from(3u32);
+    let third = Float::from(1.0 / 3.0);
+    assert_panic!(Float::TWO.sub_mul_round(three.clone(), third.clone(), RoundingMode::Exact));
+    assert_panic!(Float::TWO.sub_mul_round_val_ref_ref(&three, &third, RoundingMode::Exact));
+    assert_panic!(Float::TWO.sub_mul_round_ref_ref_ref(&three, &third, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.sub_mul_round_assign(three.clone(), third.clone(), RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::TWO;
+        x.sub_mul_round_assign_ref_ref(&three, &third, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn sub_mul_prec_round_fail() {
+    let pi = Float::from(std::f64::consts::PI);
+    assert_panic!(Float::one_prec(1).sub_mul_prec_round(
+        Float::two_prec(1),
+        Float::two_prec(1),
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::one_prec(1).sub_mul_prec_round(
+        pi.clone(),
+        Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).sub_mul_prec_round_val_ref_ref(
+        &pi,
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::one_prec(1).sub_mul_prec_round_ref_ref_ref(
+        &pi,
+        &Float::ONE,
+        1,
+        RoundingMode::Exact
+    ));
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sub_mul_prec_round_assign(pi.clone(), Float::ONE, 1, RoundingMode::Exact)
+    });
+    assert_panic!({
+        let mut x = Float::one_prec(1);
+        x.sub_mul_prec_round_assign_ref_ref(&pi, &Float::ONE, 1, RoundingMode::Exact)
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_mul_prec_round_properties_helper(x: Float, y: Float, z: Float, prec: u64, rm: RoundingMode) {
+    let (result, o) = x.clone().sub_mul_prec_round(y.clone(), z.clone(), prec, rm);
+    assert!(result.is_valid());
+
+    let (result_alt, o_alt) = x.clone().sub_mul_prec_round_val_ref_ref(&y, &z, prec, rm);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let (result_alt, o_alt) = x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sub_mul_prec_round_assign(y.clone(), z.clone(), prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let mut x_alt = x.clone();
+    let o_alt = x_alt.sub_mul_prec_round_assign_ref_ref(&y, &z, prec, rm);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    if x.is_finite() && y.is_finite() && z.is_finite() {
+        let (result_alt, o_alt) =
+            sub_mul_prec_round_naive(x.clone(), y.clone(), z.clone(), prec, rm);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+        if result.is_normal() {
+            assert_eq!(result.get_prec(), Some(prec));
+            assert_eq!(
+                result.partial_cmp(
+                    &(Rational::exact_from(&x)
+                        - Rational::exact_from(&y) * Rational::exact_from(&z))
+                ),
+                Some(o)
+            );
+        }
+    }
+
+    if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+        let (rug_result, rug_o) = rug_sub_mul_prec_round(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+            rm,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    }
+
+    let (result_alt, o_alt) = x.sub_mul_prec_round_ref_ref_ref(&z, &y, prec, rm);
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    let (result_alt, o_alt) = x.add_mul_prec_round_ref_ref_ref(&-&y, &z, prec, rm);
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+    assert_eq!(o_alt, o);
+
+    if !result.is_zero() {
+        let (result_alt, o_alt) = (-&x).sub_mul_prec_round(-&y, z.clone(), prec, -rm);
+        assert_eq!(
+            ComparableFloat(-result_alt),
+            ComparableFloat(result.clone())
+        );
+        assert_eq!(o_alt.reverse(), o);
+    }
+
+    if o == Ordering::Equal {
+        for rm in exhaustive_rounding_modes() {
+            let (r, oo) = x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+            assert_eq!(
+                ComparableFloat(r.abs_negative_zero()),
+                ComparableFloat(result.abs_negative_zero_ref())
+            );
+            assert_eq!(oo, Ordering::Equal);
+        }
+    } else {
+        assert_panic!(x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Exact));
+    }
+}
+
+#[test]
+fn sub_mul_prec_round_properties() {
+    float_float_float_unsigned_rounding_mode_quintuple_gen_var_2().test_properties(
+        |(x, y, z, prec, rm)| {
+            sub_mul_prec_round_properties_helper(x, y, z, prec, rm);
+        },
+    );
+}
+
+#[test]
+fn sub_mul_prec_properties() {
+    float_float_float_unsigned_quadruple_gen_var_1().test_properties(|(x, y, z, prec)| {
+        let (result, o) = x.clone().sub_mul_prec(y.clone(), z.clone(), prec);
+        assert!(result.is_valid());
+
+        let (result_alt, o_alt) = x.clone().sub_mul_prec_val_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) = x.sub_mul_prec_ref_ref_ref(&y, &z, prec);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_mul_prec_assign(y.clone(), z.clone(), prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_mul_prec_assign_ref_ref(&y, &z, prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) =
+            x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (rug_result, rug_o) = rug_sub_mul_prec(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z),
+            prec,
+        );
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_result)),
+            ComparableFloatRef(&result)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn sub_mul_round_properties() {
+    float_float_float_rounding_mode_quadruple_gen_var_2().test_properties(|(x, y, z, rm)| {
+        let (result, o) = x.clone().sub_mul_round(y.clone(), z.clone(), rm);
+        assert!(result.is_valid());
+
+        let (result_alt, o_alt) = x.clone().sub_mul_round_val_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let (result_alt, o_alt) = x.sub_mul_round_ref_ref_ref(&y, &z, rm);
+        assert!(result_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_mul_round_assign(y.clone(), z.clone(), rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.sub_mul_round_assign_ref_ref(&y, &z, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        let prec = max(
+            max(x.significant_bits(), y.significant_bits()),
+            z.significant_bits(),
+        );
+        let (result_alt, o_alt) = x.sub_mul_prec_round_ref_ref_ref(&y, &z, prec, rm);
+        assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_result, rug_o) = rug_sub_mul_round(
+                &rug::Float::exact_from(&x),
+                &rug::Float::exact_from(&y),
+                &rug::Float::exact_from(&z),
+                rm,
+            );
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_result)),
+                ComparableFloatRef(&result)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn sub_mul_properties_helper(x: Float, y: Float, z: Float) {
+    let result = x.clone().sub_mul(y.clone(), z.clone());
+    assert!(result.is_valid());
+
+    let result_alt = x.clone().sub_mul(y.clone(), &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = x.clone().sub_mul(&y, z.clone());
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = x.clone().sub_mul(&y, &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let result_alt = (&x).sub_mul(&y, &z);
+    assert!(result_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.sub_mul_assign(y.clone(), z.clone());
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.sub_mul_assign(y.clone(), &z);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.sub_mul_assign(&y, z.clone());
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let mut x_alt = x.clone();
+    x_alt.sub_mul_assign(&y, &z);
+    assert!(x_alt.is_valid());
+    assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&result));
+
+    let prec = max(
+        max(x.significant_bits(), y.significant_bits()),
+        z.significant_bits(),
+    );
+    let result_alt = x
+        .sub_mul_prec_round_ref_ref_ref(&y, &z, prec, RoundingMode::Nearest)
+        .0;
+    assert_eq!(ComparableFloatRef(&result_alt), ComparableFloatRef(&result));
+
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_sub_mul(
+            &rug::Float::exact_from(&x),
+            &rug::Float::exact_from(&y),
+            &rug::Float::exact_from(&z)
+        ))),
+        ComparableFloatRef(&result)
+    );
+
+    assert_eq!(
+        ComparableFloatRef(&(&x).sub_mul(&z, &y)),
+        ComparableFloatRef(&result)
+    );
+
+    if !result.is_zero() {
+        assert_eq!(
+            ComparableFloat(-(-&x).sub_mul(-&y, &z)),
+            ComparableFloat(result.clone())
+        );
+    }
+}
+
+#[test]
+fn sub_mul_properties() {
+    float_triple_gen().test_properties(|(x, y, z)| {
+        sub_mul_properties_helper(x, y, z);
+    });
+
+    float_pair_gen().test_properties(|(x, y)| {
+        assert!((&x).sub_mul(&y, &Float::NAN).is_nan());
+        assert!((&x).sub_mul(&Float::NAN, &y).is_nan());
+        assert!(Float::NAN.sub_mul(&x, &y).is_nan());
+        if !x.is_nan() && !y.is_nan() {
+            let (result, o) = x.sub_mul_prec_round_ref_ref_ref(
+                &y,
+                &Float::ONE,
+                max(x.significant_bits(), y.significant_bits()),
+                RoundingMode::Nearest,
+            );
+            let (result_alt, o_alt) = x.sub_prec_round_ref_ref(
+                &y,
+                max(x.significant_bits(), y.significant_bits()),
+                RoundingMode::Nearest,
+            );
+            assert_eq!(ComparableFloat(result), ComparableFloat(result_alt));
+            assert_eq!(o, o_alt);
+        }
+        if x.is_finite() && y.is_finite() {
+            assert_eq!((&x).sub_mul(&y, &Float::ZERO), x);
+        }
+    });
+}
@@ -0,0 +1,2122 @@
+use malachite_base::num::arithmetic::traits::{Tan, TanAssign};
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::arithmetic::tan::{
+    rug_tan, rug_tan_prec, rug_tan_prec_round, rug_tan_round,
+};
+use malachite_float::test_util::common::{
+    parse_hex_string, rug_round_try_from_rounding_mode, to_hex_string,
+};
+use malachite_float::test_util::generators::{
+    float_gen, float_rounding_mode_pair_gen_var_21, float_unsigned_pair_gen_var_1,
+    float_unsigned_rounding_mode_triple_gen_var_17,
+};
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_tan() {
+    let test = |s, s_hex, out: &str, out_hex: &str| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let tan = x.clone().tan();
+        assert!(tan.is_valid());
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let tan_alt = (&x).tan();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        let mut tan_alt = x.clone();
+        tan_alt.tan_assign();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&tan)
+        );
+    };
+    test("NaN", "NaN", "NaN", "NaN");
+    test("Infinity", "Infinity", "NaN", "NaN");
+    test("-Infinity", "-Infinity", "NaN", "NaN");
+    test("0.0", "0x0.0", "0.0", "0x0.0");
+    test("-0.0", "-0x0.0", "-0.0", "-0x0.0");
+    test("1.0", "0x1.0#1", "2.0", "0x2.0#1");
+    test("-1.0", "-0x1.0#1", "-2.0", "-0x2.0#1");
+    test("1.0", "0x1.000#10", "1.557", "0x1.8e8#10");
+    test("3.0", "0x3.0#2", "-0.1", "-0x0.2#2");
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        "0.34625354951057552",
+        "0x0.58a41297459738#53",
+    );
+    test("8.0e-31", "0x1.0E-25#1", "8.0e-31", "0x1.0E-25#1");
+    test("1.0e30", "0x1.0E+25#1", "-2.0", "-0x2.0#1");
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        "0.30063224202390143",
+        "0x0.4cf63c0f9da5f4#53",
+    );
+    test("3.0e120", "0x1.0E+100#1", "-0.1", "-0x0.2#1");
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        "16331239353195370.0",
+        "0x3a052cf8639b6a.0#53",
+    );
+}
+
+#[test]
+fn test_tan_prec() {
+    let test = |s, s_hex, prec, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec(prec);
+        assert!(tan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_assign(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test("NaN", "NaN", 1, "NaN", "NaN", Ordering::Equal);
+    test("Infinity", "Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("-Infinity", "-Infinity", 1, "NaN", "NaN", Ordering::Equal);
+    test("0.0", "0x0.0", 1, "0.0", "0x0.0", Ordering::Equal);
+    test("-0.0", "-0x0.0", 1, "-0.0", "-0x0.0", Ordering::Equal);
+    test("1.0", "0x1.0#1", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test("1.0", "0x1.0#1", 10, "1.557", "0x1.8e8#10", Ordering::Less);
+    test(
+        "1.0",
+        "0x1.0#1",
+        100,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Ordering::Greater,
+    );
+    test("-1.0", "-0x1.0#1", 1, "-2.0", "-0x2.0#1", Ordering::Less);
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        "-1.557",
+        "-0x1.8e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        100,
+        "-1.557407724654902230506974807459",
+        "-0x1.8eb245cbee3a5b8acc7d41324#100",
+        Ordering::Less,
+    );
+    test("1.0", "0x1.000#10", 1, "2.0", "0x2.0#1", Ordering::Greater);
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        100,
+        "1.557407724654902230506974807459",
+        "0x1.8eb245cbee3a5b8acc7d41324#100",
+        Ordering::Greater,
+    );
+    test("3.0", "0x3.0#2", 1, "-0.1", "-0x0.2#1", Ordering::Greater);
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        "-0.1426",
+        "-0x0.248#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        "-0.1425465430742778052956354105339",
+        "-0x0.247dee24a970de1996164fbff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        1,
+        "-1.0e-16",
+        "-0x8.0E-14#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        100,
+        "-1.224646799147353177226065932275e-16",
+        "-0x8.d313198a2e03707344a40938E-14#100",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        1,
+        "1.0e-16",
+        "0x8.0E-14#1",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        100,
+        "1.224646799147353177226065932275e-16",
+        "0x8.d313198a2e03707344a40938E-14#100",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        1,
+        "-0.5",
+        "-0x0.8#1",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        "-0.4507",
+        "-0x0.736#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        100,
+        "-0.4505495340698076696212154646931",
+        "-0x0.735736da0ed0069bd28756eab8#100",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        "0.3462",
+        "0x0.58a#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        100,
+        "0.3462535495105755324828552721302",
+        "0x0.58a41297459738e43f7600f1f8#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        1,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        "-1.783",
+        "-0x1.c88#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        100,
+        "-1.782955149376719088625262891671",
+        "-0x1.c86fbfa8cecc31efc7a121160#100",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        1,
+        "0.2",
+        "0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        "0.3008",
+        "0x0.4d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        100,
+        "0.300632242023901428887913093063",
+        "0x0.4cf63c0f9da5f3e4d2f87e2cc0#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        1,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        "-0.1289",
+        "-0x0.210#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        100,
+        "-0.128873109637144150878883031737",
+        "-0x0.20fdd3ff39b0f79912d9af68c8#100",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        1,
+        "2.0e16",
+        "0x4.0E+13#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        "1.633e16",
+        "0x3.a0E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        100,
+        "16331239353195369.75596773704153",
+        "0x3a052cf8639b69.c1871a036cac#100",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_tan_round() {
+    let test = |s, s_hex, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_round(rm);
+        assert!(tan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let (tan_alt, o_alt) = x.tan_round_ref(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_round_assign(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Floor,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Ceiling,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Down,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Up,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        RoundingMode::Nearest,
+        "2.0",
+        "0x2.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Floor,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Down,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Up,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        RoundingMode::Nearest,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Floor,
+        "-0.19",
+        "-0x0.3#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Ceiling,
+        "-0.1",
+        "-0x0.2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Down,
+        "-0.1",
+        "-0x0.2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Up,
+        "-0.19",
+        "-0x0.3#2",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        RoundingMode::Nearest,
+        "-0.1",
+        "-0x0.2#2",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "-1.224646799147353e-16",
+        "-0x8.d313198a2e030E-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "-1.2246467991473532e-16",
+        "-0x8.d313198a2e038E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Floor,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Ceiling,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Down,
+        "1.224646799147353e-16",
+        "0x8.d313198a2e030E-14#53",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Up,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        RoundingMode::Nearest,
+        "1.2246467991473532e-16",
+        "0x8.d313198a2e038E-14#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Floor,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Ceiling,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Down,
+        "-0.45054953406980763",
+        "-0x0.735736da0ed004#53",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Up,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        RoundingMode::Nearest,
+        "-0.45054953406980769",
+        "-0x0.735736da0ed008#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Floor,
+        "0.34625354951057552",
+        "0x0.58a41297459738#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Ceiling,
+        "0.34625354951057558",
+        "0x0.58a4129745973c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Down,
+        "0.34625354951057552",
+        "0x0.58a41297459738#53",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Up,
+        "0.34625354951057558",
+        "0x0.58a4129745973c#53",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        RoundingMode::Nearest,
+        "0.34625354951057552",
+        "0x0.58a41297459738#53",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Floor,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Ceiling,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Down,
+        "-1.0",
+        "-0x1.0#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Up,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        RoundingMode::Nearest,
+        "-2.0",
+        "-0x2.0#1",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Floor,
+        "0.30063224202390137",
+        "0x0.4cf63c0f9da5f0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Ceiling,
+        "0.30063224202390143",
+        "0x0.4cf63c0f9da5f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Down,
+        "0.30063224202390137",
+        "0x0.4cf63c0f9da5f0#53",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Up,
+        "0.30063224202390143",
+        "0x0.4cf63c0f9da5f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        RoundingMode::Nearest,
+        "0.30063224202390143",
+        "0x0.4cf63c0f9da5f4#53",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Floor,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Down,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Up,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        RoundingMode::Nearest,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Floor,
+        "16331239353195368.0",
+        "0x3a052cf8639b68.0#53",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Ceiling,
+        "16331239353195370.0",
+        "0x3a052cf8639b6a.0#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Down,
+        "16331239353195368.0",
+        "0x3a052cf8639b68.0#53",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Up,
+        "16331239353195370.0",
+        "0x3a052cf8639b6a.0#53",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        RoundingMode::Nearest,
+        "16331239353195370.0",
+        "0x3a052cf8639b6a.0#53",
+        Ordering::Greater,
+    );
+}
+
+#[test]
+fn test_tan_prec_round() {
+    let test = |s, s_hex, prec, rm, out: &str, out_hex: &str, o_out| {
+        let x = parse_hex_string(s_hex);
+        assert_eq!(x.to_string(), s);
+
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+        assert_eq!(o, o_out);
+
+        assert_eq!(tan.to_string(), out);
+        assert_eq!(to_hex_string(&tan), out_hex);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        let mut tan_alt = x.clone();
+        let o_alt = tan_alt.tan_prec_round_assign(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan), ComparableFloatRef(&tan_alt));
+        assert_eq!(o_alt, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(
+        "NaN",
+        "NaN",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        1,
+        RoundingMode::Floor,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        1,
+        RoundingMode::Floor,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        1,
+        RoundingMode::Floor,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "NaN",
+        "NaN",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "Infinity",
+        "Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "-Infinity",
+        "-Infinity",
+        10,
+        RoundingMode::Exact,
+        "NaN",
+        "NaN",
+        Ordering::Equal,
+    );
+    test(
+        "0.0",
+        "0x0.0",
+        10,
+        RoundingMode::Exact,
+        "0.0",
+        "0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "-0.0",
+        "-0x0.0",
+        10,
+        RoundingMode::Exact,
+        "-0.0",
+        "-0x0.0",
+        Ordering::Equal,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Floor,
+        "-1.559",
+        "-0x1.8f0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.557",
+        "-0x1.8e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Down,
+        "-1.557",
+        "-0x1.8e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Up,
+        "-1.559",
+        "-0x1.8f0#10",
+        Ordering::Less,
+    );
+    test(
+        "-1.0",
+        "-0x1.0#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.557",
+        "-0x1.8e8#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Floor,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Ceiling,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Down,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Up,
+        "1.559",
+        "0x1.8f0#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0",
+        "0x1.000#10",
+        10,
+        RoundingMode::Nearest,
+        "1.557",
+        "0x1.8e8#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Floor,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Ceiling,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Down,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Up,
+        "-0.2",
+        "-0x0.4#1",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        1,
+        RoundingMode::Nearest,
+        "-0.1",
+        "-0x0.2#1",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Floor,
+        "-0.1426",
+        "-0x0.248#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Ceiling,
+        "-0.1423",
+        "-0x0.247#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Down,
+        "-0.1423",
+        "-0x0.247#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Up,
+        "-0.1426",
+        "-0x0.248#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        10,
+        RoundingMode::Nearest,
+        "-0.1426",
+        "-0x0.248#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Floor,
+        "-0.1425465430742778052956354105341",
+        "-0x0.247dee24a970de1996164fbff4#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Ceiling,
+        "-0.1425465430742778052956354105339",
+        "-0x0.247dee24a970de1996164fbff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Down,
+        "-0.1425465430742778052956354105339",
+        "-0x0.247dee24a970de1996164fbff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Up,
+        "-0.1425465430742778052956354105341",
+        "-0x0.247dee24a970de1996164fbff4#100",
+        Ordering::Less,
+    );
+    test(
+        "3.0",
+        "0x3.0#2",
+        100,
+        RoundingMode::Nearest,
+        "-0.1425465430742778052956354105339",
+        "-0x0.247dee24a970de1996164fbff0#100",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "-1.223e-16",
+        "-0x8.d0E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "3.1415926535897931",
+        "0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "-1.225e-16",
+        "-0x8.d4E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Floor,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Down,
+        "1.223e-16",
+        "0x8.d0E-14#10",
+        Ordering::Less,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Up,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "-3.1415926535897931",
+        "-0x3.243f6a8885a30#53",
+        10,
+        RoundingMode::Nearest,
+        "1.225e-16",
+        "0x8.d4E-14#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Floor,
+        "-0.4507",
+        "-0x0.736#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Ceiling,
+        "-0.4502",
+        "-0x0.734#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Down,
+        "-0.4502",
+        "-0x0.734#10",
+        Ordering::Greater,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Up,
+        "-0.4507",
+        "-0x0.736#10",
+        Ordering::Less,
+    );
+    test(
+        "2.7182818284590451",
+        "0x2.b7e151628aed2#53",
+        10,
+        RoundingMode::Nearest,
+        "-0.4507",
+        "-0x0.736#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Floor,
+        "0.3462",
+        "0x0.58a#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Down,
+        "0.3462",
+        "0x0.58a#10",
+        Ordering::Less,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Up,
+        "0.3467",
+        "0x0.58c#10",
+        Ordering::Greater,
+    );
+    test(
+        "0.33333333333333337",
+        "0x0.55555555555558#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3462",
+        "0x0.58a#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Floor,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Ceiling,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Down,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Up,
+        "2.0e-30",
+        "0x2.0E-25#1",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        1,
+        RoundingMode::Nearest,
+        "8.0e-31",
+        "0x1.0E-25#1",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Floor,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Ceiling,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Down,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Up,
+        "7.9e-31",
+        "0x1.008E-25#10",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        10,
+        RoundingMode::Nearest,
+        "7.89e-31",
+        "0x1.000E-25#10",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Floor,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Ceiling,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Down,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Up,
+        "7.88860905221011805411728565284e-31",
+        "0x1.0000000000000000000000002E-25#100",
+        Ordering::Greater,
+    );
+    test(
+        "8.0e-31",
+        "0x1.0E-25#1",
+        100,
+        RoundingMode::Nearest,
+        "7.88860905221011805411728565283e-31",
+        "0x1.0000000000000000000000000E-25#100",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Floor,
+        "-1.783",
+        "-0x1.c88#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Ceiling,
+        "-1.781",
+        "-0x1.c80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Down,
+        "-1.781",
+        "-0x1.c80#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Up,
+        "-1.783",
+        "-0x1.c88#10",
+        Ordering::Less,
+    );
+    test(
+        "1.0e30",
+        "0x1.0E+25#1",
+        10,
+        RoundingMode::Nearest,
+        "-1.783",
+        "-0x1.c88#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Floor,
+        "0.3003",
+        "0x0.4ce#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Ceiling,
+        "0.3008",
+        "0x0.4d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Down,
+        "0.3003",
+        "0x0.4ce#10",
+        Ordering::Less,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Up,
+        "0.3008",
+        "0x0.4d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "15.999999999999998",
+        "0xf.ffffffffffff8#53",
+        10,
+        RoundingMode::Nearest,
+        "0.3008",
+        "0x0.4d0#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Floor,
+        "-0.1289",
+        "-0x0.210#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Ceiling,
+        "-0.1287",
+        "-0x0.20f#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Down,
+        "-0.1287",
+        "-0x0.20f#10",
+        Ordering::Greater,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Up,
+        "-0.1289",
+        "-0x0.210#10",
+        Ordering::Less,
+    );
+    test(
+        "3.0e120",
+        "0x1.0E+100#1",
+        10,
+        RoundingMode::Nearest,
+        "-0.1289",
+        "-0x0.210#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Floor,
+        "1.633e16",
+        "0x3.a0E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Ceiling,
+        "1.634e16",
+        "0x3.a1E+13#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Down,
+        "1.633e16",
+        "0x3.a0E+13#10",
+        Ordering::Less,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Up,
+        "1.634e16",
+        "0x3.a1E+13#10",
+        Ordering::Greater,
+    );
+    test(
+        "1.5707963267948966",
+        "0x1.921fb54442d18#53",
+        10,
+        RoundingMode::Nearest,
+        "1.633e16",
+        "0x3.a0E+13#10",
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn tan_prec_fail() {
+    assert_panic!(Float::NAN.tan_prec(0));
+    assert_panic!(Float::ONE.tan_prec(0));
+}
+
+#[test]
+fn tan_prec_ref_fail() {
+    assert_panic!(Float::NAN.tan_prec_ref(0));
+    assert_panic!(Float::ONE.tan_prec_ref(0));
+}
+
+#[test]
+fn tan_prec_assign_fail() {
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.tan_prec_assign(0)
+    });
+}
+
+#[test]
+fn tan_round_fail() {
+    assert_panic!(Float::ONE.tan_round(RoundingMode::Exact));
+    assert_panic!(Float::ONE.tan_round_ref(RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.tan_round_assign(RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn tan_prec_round_fail() {
+    assert_panic!(Float::one_prec(1).tan_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ONE.tan_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ONE.tan_prec_round_ref(1, RoundingMode::Exact));
+    assert_panic!({
+        let mut x = Float::ONE;
+        x.tan_prec_round_assign(1, RoundingMode::Exact)
+    });
+}
+
+#[test]
+fn tan_prec_round_properties() {
+    float_unsigned_rounding_mode_triple_gen_var_17().test_properties(|(x, prec, rm)| {
+        let (tan, o) = x.clone().tan_prec_round(prec, rm);
+        assert!(tan.is_valid());
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.tan_prec_round_assign(prec, rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        if x.is_finite() && x != 0u32 {
+            assert!(tan.is_normal());
+            assert_eq!(tan.get_prec(), Some(prec));
+        }
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_prec_round(&rug::Float::exact_from(&x), prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+
+        if o == Ordering::Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (s, oo) = x.tan_prec_round_ref(prec, rm);
+                assert_eq!(ComparableFloat(s), ComparableFloat(tan.clone()));
+                assert_eq!(oo, Ordering::Equal);
+            }
+        } else {
+            assert_panic!(x.tan_prec_round_ref(prec, RoundingMode::Exact));
+        }
+    });
+}
+
+#[test]
+fn tan_prec_properties() {
+    float_unsigned_pair_gen_var_1().test_properties(|(x, prec)| {
+        let (tan, o) = x.clone().tan_prec(prec);
+        assert!(tan.is_valid());
+
+        let (tan_alt, o_alt) = x.tan_prec_ref(prec);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.tan_prec_assign(prec);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let (rug_tan, rug_o) = rug_tan_prec(&rug::Float::exact_from(&x), prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan)),
+            ComparableFloatRef(&tan)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn tan_round_properties() {
+    float_rounding_mode_pair_gen_var_21().test_properties(|(x, rm)| {
+        let (tan, o) = x.clone().tan_round(rm);
+        assert!(tan.is_valid());
+
+        let (tan_alt, o_alt) = x.tan_round_ref(rm);
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let mut x_alt = x.clone();
+        let o_alt = x_alt.tan_round_assign(rm);
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        let (tan_alt, o_alt) = x.tan_prec_round_ref(x.significant_bits(), rm);
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+        assert_eq!(o_alt, o);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_tan, rug_o) = rug_tan_round(&rug::Float::exact_from(&x), rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_tan)),
+                ComparableFloatRef(&tan)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+}
+
+#[test]
+fn tan_properties() {
+    float_gen().test_properties(|x| {
+        let tan = x.clone().tan();
+        assert!(tan.is_valid());
+
+        let tan_alt = (&x).tan();
+        assert!(tan_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+
+        let mut x_alt = x.clone();
+        x_alt.tan_assign();
+        assert!(x_alt.is_valid());
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&tan));
+
+        let tan_alt = x
+            .tan_prec_round_ref(x.significant_bits(), RoundingMode::Nearest)
+            .0;
+        assert_eq!(ComparableFloatRef(&tan_alt), ComparableFloatRef(&tan));
+
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_tan(&rug::Float::exact_from(&x)))),
+            ComparableFloatRef(&tan)
+        );
+    });
+
+    assert!(Float::NAN.tan().is_nan());
+    assert!(Float::INFINITY.tan().is_nan());
+    assert!(Float::NEGATIVE_INFINITY.tan().is_nan());
+    assert_eq!(
+        ComparableFloat(Float::ZERO.tan()),
+        ComparableFloat(Float::ZERO)
+    );
+    assert_eq!(
+        ComparableFloat(Float::NEGATIVE_ZERO.tan()),
+        ComparableFloat(Float::NEGATIVE_ZERO)
+    );
+}
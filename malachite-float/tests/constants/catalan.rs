@@ -0,0 +1,261 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::catalan::{rug_catalan_prec, rug_catalan_prec_round};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_catalan_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::catalan_prec(prec);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_x, rug_o) = rug_catalan_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "1.0", "0x1.0#1", Ordering::Greater);
+    test(2, "1.0", "0x1.0#2", Ordering::Greater);
+    test(3, "0.9", "0x0.e#3", Ordering::Less);
+    test(10, "0.916", "0x0.ea8#10", Ordering::Greater);
+    test(
+        100,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(1000, "0.915965594177219015054603514932384110774149374281672134266498119621763019776254769479356512926115106248574422619196199579035898803325859059431594737481158406995332028773319460519038727478164087865909024706484152163000228727640942388259957741508816397470252482011560707644883807873370489900864775113226", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af91a#1000", Ordering::Greater);
+
+    // Large precisions, where the series is summed by binary splitting
+    let (x, o) = Float::catalan_prec(100000);
+    let (rug_x, rug_o) = rug_catalan_prec(100000);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+#[should_panic]
+fn catalan_prec_fail() {
+    Float::catalan_prec(0);
+}
+
+#[test]
+fn test_catalan_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::catalan_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_catalan_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(
+        1,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Floor, "0.8", "0x0.c#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.8", "0x0.c#2", Ordering::Less);
+    test(2, RoundingMode::Up, "1.0", "0x1.0#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "1.0",
+        "0x1.0#2",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Floor, "0.9", "0x0.e#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.9", "0x0.e#3", Ordering::Less);
+    test(3, RoundingMode::Up, "1.0", "0x1.0#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "0.9", "0x0.e#3", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.915",
+        "0x0.ea4#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.915",
+        "0x0.ea4#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.916",
+        "0x0.ea8#10",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.915965594177219015054603514932",
+        "0x0.ea7cb89f409ae845215822e37#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.9159655941772190150546035149325",
+        "0x0.ea7cb89f409ae845215822e38#100",
+        Ordering::Greater,
+    );
+    test(1000, RoundingMode::Floor, "0.9159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062485744226191961995790358988033258590594315947374811584069953320287733194605190387274781640878659090247064841521630002287276409423882599577415088163974702524820115607076448838078733704899008647751132259", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af919#1000", Ordering::Less);
+    test(1000, RoundingMode::Ceiling, "0.915965594177219015054603514932384110774149374281672134266498119621763019776254769479356512926115106248574422619196199579035898803325859059431594737481158406995332028773319460519038727478164087865909024706484152163000228727640942388259957741508816397470252482011560707644883807873370489900864775113226", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af91a#1000", Ordering::Greater);
+    test(1000, RoundingMode::Down, "0.9159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062485744226191961995790358988033258590594315947374811584069953320287733194605190387274781640878659090247064841521630002287276409423882599577415088163974702524820115607076448838078733704899008647751132259", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af919#1000", Ordering::Less);
+    test(1000, RoundingMode::Up, "0.915965594177219015054603514932384110774149374281672134266498119621763019776254769479356512926115106248574422619196199579035898803325859059431594737481158406995332028773319460519038727478164087865909024706484152163000228727640942388259957741508816397470252482011560707644883807873370489900864775113226", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af91a#1000", Ordering::Greater);
+    test(1000, RoundingMode::Nearest, "0.915965594177219015054603514932384110774149374281672134266498119621763019776254769479356512926115106248574422619196199579035898803325859059431594737481158406995332028773319460519038727478164087865909024706484152163000228727640942388259957741508816397470252482011560707644883807873370489900864775113226", "0x0.ea7cb89f409ae845215822e37d32d0c63ec43e1381c2ff8094a263e5a3ccd76f94dc058a46eec5858f924d663f739c42ec95f8da12f16bfc58bb20c7f2063a6c6f6de500cb94f358cfdec842b851f77bda255282ce0920a17ff4ff46738f8e5a7c6e6898f5a0182d43759a75680d01c68c37800513b3a8dd76669af91a#1000", Ordering::Greater);
+}
+
+#[test]
+fn catalan_prec_round_fail() {
+    assert_panic!(Float::catalan_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::catalan_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::catalan_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn catalan_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (x, o) = Float::catalan_prec(prec);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (x_alt, o_alt) = Float::catalan_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+        assert_eq!(o_alt, o);
+
+        let (rug_x, rug_o) = rug_catalan_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn catalan_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (x, o) = Float::catalan_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        match rm {
+            RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+            RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+            _ => assert_ne!(o, Ordering::Equal),
+        }
+
+        // A more precise approximation, rounded down, agrees with the directly computed one
+        let (mut x_alt, o_alt) = Float::catalan_prec_round(prec + 10, RoundingMode::Floor);
+        assert_eq!(o_alt, Ordering::Less);
+        x_alt.set_prec_round(prec, RoundingMode::Floor);
+        let (floor, _) = Float::catalan_prec_round(prec, RoundingMode::Floor);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(floor));
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_catalan_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::catalan_prec_round(prec, RoundingMode::Exact));
+        let (floor, o_floor) = Float::catalan_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, o_ceiling) = Float::catalan_prec_round(prec, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        assert_eq!(o_floor, Ordering::Less);
+        assert_eq!(o_ceiling, Ordering::Greater);
+    });
+}
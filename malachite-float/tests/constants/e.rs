@@ -0,0 +1,249 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::e::{rug_e_prec, rug_e_prec_round};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_e_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::e_prec(prec);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_x, rug_o) = rug_e_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "2.0", "0x2.0#1", Ordering::Less);
+    test(2, "3.0", "0x3.0#2", Ordering::Greater);
+    test(3, "2.5", "0x2.8#3", Ordering::Less);
+    test(10, "2.719", "0x2.b8#10", Ordering::Greater);
+    test(
+        100,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(1000, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920695", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60acc#1000", Ordering::Less);
+
+    // Large precisions, where the series is summed by binary splitting
+    let (x, o) = Float::e_prec(100000);
+    let (rug_x, rug_o) = rug_e_prec(100000);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+#[should_panic]
+fn e_prec_fail() {
+    Float::e_prec(0);
+}
+
+#[test]
+fn test_e_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::e_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_e_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "2.0", "0x2.0#1", Ordering::Less);
+    test(1, RoundingMode::Up, "4.0", "0x4.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "2.0", "0x2.0#1", Ordering::Less);
+    test(2, RoundingMode::Floor, "2.0", "0x2.0#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "2.0", "0x2.0#2", Ordering::Less);
+    test(2, RoundingMode::Up, "3.0", "0x3.0#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "3.0",
+        "0x3.0#2",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Floor, "2.5", "0x2.8#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "3.0",
+        "0x3.0#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "2.5", "0x2.8#3", Ordering::Less);
+    test(3, RoundingMode::Up, "3.0", "0x3.0#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "2.5", "0x2.8#3", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Floor,
+        "2.715",
+        "0x2.b7#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(10, RoundingMode::Down, "2.715", "0x2.b7#10", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Up,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "2.719",
+        "0x2.b8#10",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Floor,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "2.718281828459045235360287471354",
+        "0x2.b7e151628aed2a6abf715880c#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "2.718281828459045235360287471351",
+        "0x2.b7e151628aed2a6abf7158808#100",
+        Ordering::Less,
+    );
+    test(1000, RoundingMode::Floor, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920695", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60acc#1000", Ordering::Less);
+    test(1000, RoundingMode::Ceiling, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920699", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60ad0#1000", Ordering::Greater);
+    test(1000, RoundingMode::Down, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920695", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60acc#1000", Ordering::Less);
+    test(1000, RoundingMode::Up, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920699", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60ad0#1000", Ordering::Greater);
+    test(1000, RoundingMode::Nearest, "2.7182818284590452353602874713526624977572470936999595749669676277240766303535475945713821785251664274274663919320030599218174135966290435729003342952605956307381323286279434907632338298807531952510190115738341879307021540891499348841675092447614606680822648001684774118537423454424371075390777449920695", "0x2.b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef324e7738926cfbe5f4bf8d8d8c31d763da06c80abb1185eb4f7c7b5757f5958490cfd47d7c19bb42158d9554f7b46bced55c4d79fd5f24d6613c31c3839a2ddf8a9a276bcfbfa1c877c56284dab79cd4c2b3293d20e9e5eaf02ac60acc#1000", Ordering::Less);
+}
+
+#[test]
+fn e_prec_round_fail() {
+    assert_panic!(Float::e_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::e_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::e_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn e_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (x, o) = Float::e_prec(prec);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (x_alt, o_alt) = Float::e_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+        assert_eq!(o_alt, o);
+
+        let (rug_x, rug_o) = rug_e_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn e_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (x, o) = Float::e_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        match rm {
+            RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+            RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+            _ => assert_ne!(o, Ordering::Equal),
+        }
+
+        // A more precise approximation, rounded down, agrees with the directly computed one
+        let (mut x_alt, o_alt) = Float::e_prec_round(prec + 10, RoundingMode::Floor);
+        assert_eq!(o_alt, Ordering::Less);
+        x_alt.set_prec_round(prec, RoundingMode::Floor);
+        let (floor, _) = Float::e_prec_round(prec, RoundingMode::Floor);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(floor));
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_e_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::e_prec_round(prec, RoundingMode::Exact));
+        let (floor, o_floor) = Float::e_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, o_ceiling) = Float::e_prec_round(prec, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        assert_eq!(o_floor, Ordering::Less);
+        assert_eq!(o_ceiling, Ordering::Greater);
+    });
+}
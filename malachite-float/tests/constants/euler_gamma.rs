@@ -0,0 +1,257 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::euler_gamma::{
+    rug_euler_gamma_prec, rug_euler_gamma_prec_round,
+};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_euler_gamma_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::euler_gamma_prec(prec);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_x, rug_o) = rug_euler_gamma_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, "0.5", "0x0.8#2", Ordering::Less);
+    test(3, "0.6", "0x0.a#3", Ordering::Greater);
+    test(10, "0.577", "0x0.93c#10", Ordering::Less);
+    test(
+        100,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(1000, "0.577215664901532860606512090082402431042159335939923598805767234884867726777664670936947063291746749514631447249807082480960504014486542836224173997644923536253500333742937337737673942792595258247094916008735203948165670853233151776611528621199501507984793745085705740029921354786146694029604325421519", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3ce#1000", Ordering::Less);
+
+    // Large precisions, where the series is summed by binary splitting
+    let (x, o) = Float::euler_gamma_prec(100000);
+    let (rug_x, rug_o) = rug_euler_gamma_prec(100000);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+#[should_panic]
+fn euler_gamma_prec_fail() {
+    Float::euler_gamma_prec(0);
+}
+
+#[test]
+fn test_euler_gamma_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::euler_gamma_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_euler_gamma_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, RoundingMode::Floor, "0.5", "0x0.8#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.5", "0x0.8#2", Ordering::Less);
+    test(2, RoundingMode::Up, "0.8", "0x0.c#2", Ordering::Greater);
+    test(2, RoundingMode::Nearest, "0.5", "0x0.8#2", Ordering::Less);
+    test(3, RoundingMode::Floor, "0.5", "0x0.8#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.5", "0x0.8#3", Ordering::Less);
+    test(3, RoundingMode::Up, "0.6", "0x0.a#3", Ordering::Greater);
+    test(
+        3,
+        RoundingMode::Nearest,
+        "0.6",
+        "0x0.a#3",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.578",
+        "0x0.940#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.577",
+        "0x0.93c#10",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.577215664901532860606512090083",
+        "0x0.93c467e37db0c7a4d1be3f811#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.5772156649015328606065120900823",
+        "0x0.93c467e37db0c7a4d1be3f810#100",
+        Ordering::Less,
+    );
+    test(1000, RoundingMode::Floor, "0.577215664901532860606512090082402431042159335939923598805767234884867726777664670936947063291746749514631447249807082480960504014486542836224173997644923536253500333742937337737673942792595258247094916008735203948165670853233151776611528621199501507984793745085705740029921354786146694029604325421519", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3ce#1000", Ordering::Less);
+    test(1000, RoundingMode::Ceiling, "0.5772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495146314472498070824809605040144865428362241739976449235362535003337429373377376739427925952582470949160087352039481656708532331517766115286211995015079847937450857057400299213547861466940296043254215191", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3cf#1000", Ordering::Greater);
+    test(1000, RoundingMode::Down, "0.577215664901532860606512090082402431042159335939923598805767234884867726777664670936947063291746749514631447249807082480960504014486542836224173997644923536253500333742937337737673942792595258247094916008735203948165670853233151776611528621199501507984793745085705740029921354786146694029604325421519", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3ce#1000", Ordering::Less);
+    test(1000, RoundingMode::Up, "0.5772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495146314472498070824809605040144865428362241739976449235362535003337429373377376739427925952582470949160087352039481656708532331517766115286211995015079847937450857057400299213547861466940296043254215191", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3cf#1000", Ordering::Greater);
+    test(1000, RoundingMode::Nearest, "0.577215664901532860606512090082402431042159335939923598805767234884867726777664670936947063291746749514631447249807082480960504014486542836224173997644923536253500333742937337737673942792595258247094916008735203948165670853233151776611528621199501507984793745085705740029921354786146694029604325421519", "0x0.93c467e37db0c7a4d1be3f810152cb56a1cecc3af65cc0190c03df34709affbd8e4b59fa03a9f0eed0649ccb621057d11056ae9132135a08e43b4673d74bafea58deb878cc86d733dbe7bf38154b36cf8a96d1567899aaae0c09d4c8b6b7b86fd2a1ea1de62ff8643ec7c271827977225e6ac2f0bd61c746961542a3ce#1000", Ordering::Less);
+}
+
+#[test]
+fn euler_gamma_prec_round_fail() {
+    assert_panic!(Float::euler_gamma_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::euler_gamma_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::euler_gamma_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn euler_gamma_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (x, o) = Float::euler_gamma_prec(prec);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (x_alt, o_alt) = Float::euler_gamma_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+        assert_eq!(o_alt, o);
+
+        let (rug_x, rug_o) = rug_euler_gamma_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn euler_gamma_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (x, o) = Float::euler_gamma_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        match rm {
+            RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+            RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+            _ => assert_ne!(o, Ordering::Equal),
+        }
+
+        // A more precise approximation, rounded down, agrees with the directly computed one
+        let (mut x_alt, o_alt) = Float::euler_gamma_prec_round(prec + 10, RoundingMode::Floor);
+        assert_eq!(o_alt, Ordering::Less);
+        x_alt.set_prec_round(prec, RoundingMode::Floor);
+        let (floor, _) = Float::euler_gamma_prec_round(prec, RoundingMode::Floor);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(floor));
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_euler_gamma_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::euler_gamma_prec_round(prec, RoundingMode::Exact));
+        let (floor, o_floor) = Float::euler_gamma_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, o_ceiling) = Float::euler_gamma_prec_round(prec, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        assert_eq!(o_floor, Ordering::Less);
+        assert_eq!(o_ceiling, Ordering::Greater);
+    });
+}
@@ -0,0 +1,261 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::ln_2::{rug_ln_2_prec, rug_ln_2_prec_round};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_ln_2_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::ln_2_prec(prec);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_x, rug_o) = rug_ln_2_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, "0.8", "0x0.c#2", Ordering::Greater);
+    test(3, "0.8", "0x0.c#3", Ordering::Greater);
+    test(10, "0.693", "0x0.b18#10", Ordering::Greater);
+    test(
+        100,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(1000, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437404", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c50f#1000", Ordering::Less);
+
+    // Large precisions, where the series is summed by binary splitting
+    let (x, o) = Float::ln_2_prec(100000);
+    let (rug_x, rug_o) = rug_ln_2_prec(100000);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+#[should_panic]
+fn ln_2_prec_fail() {
+    Float::ln_2_prec(0);
+}
+
+#[test]
+fn test_ln_2_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::ln_2_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_ln_2_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "0.5", "0x0.8#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "1.0",
+        "0x1.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "0.5", "0x0.8#1", Ordering::Less);
+    test(1, RoundingMode::Up, "1.0", "0x1.0#1", Ordering::Greater);
+    test(1, RoundingMode::Nearest, "0.5", "0x0.8#1", Ordering::Less);
+    test(2, RoundingMode::Floor, "0.5", "0x0.8#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "0.5", "0x0.8#2", Ordering::Less);
+    test(2, RoundingMode::Up, "0.8", "0x0.c#2", Ordering::Greater);
+    test(
+        2,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.c#2",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Floor, "0.6", "0x0.a#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "0.8",
+        "0x0.c#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "0.6", "0x0.a#3", Ordering::Less);
+    test(3, RoundingMode::Up, "0.8", "0x0.c#3", Ordering::Greater);
+    test(
+        3,
+        RoundingMode::Nearest,
+        "0.8",
+        "0x0.c#3",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Floor,
+        "0.692",
+        "0x0.b14#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Down,
+        "0.692",
+        "0x0.b14#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Up,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "0.693",
+        "0x0.b18#10",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Floor,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "0.693147180559945309417232121459",
+        "0x0.b17217f7d1cf79abc9e3b3981#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "0.693147180559945309417232121459",
+        "0x0.b17217f7d1cf79abc9e3b3981#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "0.693147180559945309417232121458",
+        "0x0.b17217f7d1cf79abc9e3b3980#100",
+        Ordering::Less,
+    );
+    test(1000, RoundingMode::Floor, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437404", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c50f#1000", Ordering::Less);
+    test(1000, RoundingMode::Ceiling, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437405", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c510#1000", Ordering::Greater);
+    test(1000, RoundingMode::Down, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437404", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c50f#1000", Ordering::Less);
+    test(1000, RoundingMode::Up, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437405", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c510#1000", Ordering::Greater);
+    test(1000, RoundingMode::Nearest, "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875420014810205706857336855202357581305570326707516350759619307275708283714351903070386238916734711233501153644979552391204751726815749320651555247341395258829504530070953263666426541042391578149520437404", "0x0.b17217f7d1cf79abc9e3b39803f2f6af40f343267298b62d8a0d175b8baafa2be7b876206debac98559552fb4afa1b10ed2eae35c138214427573b291169b8253e96ca16224ae8c51acbda11317c387eb9ea9bc3b136603b256fa0ec7657f74b72ce87b19d6548caf5dfa6bd38303248655fa1872f20e3a2da2d97c50f#1000", Ordering::Less);
+}
+
+#[test]
+fn ln_2_prec_round_fail() {
+    assert_panic!(Float::ln_2_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::ln_2_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::ln_2_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn ln_2_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (x, o) = Float::ln_2_prec(prec);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (x_alt, o_alt) = Float::ln_2_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+        assert_eq!(o_alt, o);
+
+        let (rug_x, rug_o) = rug_ln_2_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn ln_2_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (x, o) = Float::ln_2_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        match rm {
+            RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+            RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+            _ => assert_ne!(o, Ordering::Equal),
+        }
+
+        // A more precise approximation, rounded down, agrees with the directly computed one
+        let (mut x_alt, o_alt) = Float::ln_2_prec_round(prec + 10, RoundingMode::Floor);
+        assert_eq!(o_alt, Ordering::Less);
+        x_alt.set_prec_round(prec, RoundingMode::Floor);
+        let (floor, _) = Float::ln_2_prec_round(prec, RoundingMode::Floor);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(floor));
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_ln_2_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::ln_2_prec_round(prec, RoundingMode::Exact));
+        let (floor, o_floor) = Float::ln_2_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, o_ceiling) = Float::ln_2_prec_round(prec, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        assert_eq!(o_floor, Ordering::Less);
+        assert_eq!(o_ceiling, Ordering::Greater);
+    });
+}
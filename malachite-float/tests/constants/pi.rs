@@ -0,0 +1,249 @@
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::unsigned_gen_var_11;
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::constants::pi::{rug_pi_prec, rug_pi_prec_round};
+use malachite_float::test_util::generators::unsigned_rounding_mode_pair_gen_var_3;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_pi_prec() {
+    let test = |prec, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::pi_prec(prec);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        let (rug_x, rug_o) = rug_pi_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    };
+    test(1, "4.0", "0x4.0#1", Ordering::Greater);
+    test(2, "3.0", "0x3.0#2", Ordering::Less);
+    test(3, "3.0", "0x3.0#3", Ordering::Less);
+    test(10, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        100,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(1000, "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811174502841027019385211055596446229489549303819644288109756659334461284756482337867831652712019091456485669234603486104543266482133936072602491412736", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d870#1000", Ordering::Less);
+
+    // Large precisions, where the series is summed by binary splitting
+    let (x, o) = Float::pi_prec(100000);
+    let (rug_x, rug_o) = rug_pi_prec(100000);
+    assert_eq!(
+        ComparableFloatRef(&Float::from(&rug_x)),
+        ComparableFloatRef(&x)
+    );
+    assert_eq!(rug_o, o);
+}
+
+#[test]
+#[should_panic]
+fn pi_prec_fail() {
+    Float::pi_prec(0);
+}
+
+#[test]
+fn test_pi_prec_round() {
+    let test = |prec, rm, out: &str, out_hex: &str, o_out| {
+        let (x, o) = Float::pi_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(to_hex_string(&x), out_hex);
+        assert_eq!(o, o_out);
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_pi_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    };
+    test(1, RoundingMode::Floor, "2.0", "0x2.0#1", Ordering::Less);
+    test(
+        1,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(1, RoundingMode::Down, "2.0", "0x2.0#1", Ordering::Less);
+    test(1, RoundingMode::Up, "4.0", "0x4.0#1", Ordering::Greater);
+    test(
+        1,
+        RoundingMode::Nearest,
+        "4.0",
+        "0x4.0#1",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Floor, "3.0", "0x3.0#2", Ordering::Less);
+    test(
+        2,
+        RoundingMode::Ceiling,
+        "4.0",
+        "0x4.0#2",
+        Ordering::Greater,
+    );
+    test(2, RoundingMode::Down, "3.0", "0x3.0#2", Ordering::Less);
+    test(2, RoundingMode::Up, "4.0", "0x4.0#2", Ordering::Greater);
+    test(2, RoundingMode::Nearest, "3.0", "0x3.0#2", Ordering::Less);
+    test(3, RoundingMode::Floor, "3.0", "0x3.0#3", Ordering::Less);
+    test(
+        3,
+        RoundingMode::Ceiling,
+        "3.5",
+        "0x3.8#3",
+        Ordering::Greater,
+    );
+    test(3, RoundingMode::Down, "3.0", "0x3.0#3", Ordering::Less);
+    test(3, RoundingMode::Up, "3.5", "0x3.8#3", Ordering::Greater);
+    test(3, RoundingMode::Nearest, "3.0", "0x3.0#3", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Floor,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        10,
+        RoundingMode::Ceiling,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(10, RoundingMode::Down, "3.141", "0x3.24#10", Ordering::Less);
+    test(
+        10,
+        RoundingMode::Up,
+        "3.145",
+        "0x3.25#10",
+        Ordering::Greater,
+    );
+    test(
+        10,
+        RoundingMode::Nearest,
+        "3.141",
+        "0x3.24#10",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Floor,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Ceiling,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Down,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(
+        100,
+        RoundingMode::Up,
+        "3.141592653589793238462643383282",
+        "0x3.243f6a8885a308d313198a2e4#100",
+        Ordering::Greater,
+    );
+    test(
+        100,
+        RoundingMode::Nearest,
+        "3.141592653589793238462643383279",
+        "0x3.243f6a8885a308d313198a2e0#100",
+        Ordering::Less,
+    );
+    test(1000, RoundingMode::Floor, "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811174502841027019385211055596446229489549303819644288109756659334461284756482337867831652712019091456485669234603486104543266482133936072602491412736", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d870#1000", Ordering::Less);
+    test(1000, RoundingMode::Ceiling, "3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117067982148086513282306647093844609550582231725359408128481117450284102701938521105559644622948954930381964428810975665933446128475648233786783165271201909145648566923460348610454326648213393607260249141274", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d874#1000", Ordering::Greater);
+    test(1000, RoundingMode::Down, "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811174502841027019385211055596446229489549303819644288109756659334461284756482337867831652712019091456485669234603486104543266482133936072602491412736", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d870#1000", Ordering::Less);
+    test(1000, RoundingMode::Up, "3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117067982148086513282306647093844609550582231725359408128481117450284102701938521105559644622948954930381964428810975665933446128475648233786783165271201909145648566923460348610454326648213393607260249141274", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d874#1000", Ordering::Greater);
+    test(1000, RoundingMode::Nearest, "3.1415926535897932384626433832795028841971693993751058209749445923078164062862089986280348253421170679821480865132823066470938446095505822317253594081284811174502841027019385211055596446229489549303819644288109756659334461284756482337867831652712019091456485669234603486104543266482133936072602491412736", "0x3.243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89452821e638d01377be5466cf34e90c6cc0ac29b7c97c50dd3f84d5b5b54709179216d5d98979fb1bd1310ba698dfb5ac2ffd72dbd01adfb7b8e1afed6a267e96ba7c9045f12c7f9924a19947b3916cf70801f2e2858efc16636920d870#1000", Ordering::Less);
+}
+
+#[test]
+fn pi_prec_round_fail() {
+    assert_panic!(Float::pi_prec_round(0, RoundingMode::Floor));
+    assert_panic!(Float::pi_prec_round(1, RoundingMode::Exact));
+    assert_panic!(Float::pi_prec_round(1000, RoundingMode::Exact));
+}
+
+#[test]
+fn pi_prec_properties() {
+    unsigned_gen_var_11().test_properties(|prec| {
+        let (x, o) = Float::pi_prec(prec);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        assert_ne!(o, Ordering::Equal);
+
+        let (x_alt, o_alt) = Float::pi_prec_round(prec, RoundingMode::Nearest);
+        assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+        assert_eq!(o_alt, o);
+
+        let (rug_x, rug_o) = rug_pi_prec(prec);
+        assert_eq!(
+            ComparableFloatRef(&Float::from(&rug_x)),
+            ComparableFloatRef(&x)
+        );
+        assert_eq!(rug_o, o);
+    });
+}
+
+#[test]
+fn pi_prec_round_properties() {
+    unsigned_rounding_mode_pair_gen_var_3().test_properties(|(prec, rm)| {
+        let (x, o) = Float::pi_prec_round(prec, rm);
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.get_prec(), Some(prec));
+        match rm {
+            RoundingMode::Floor | RoundingMode::Down => assert_eq!(o, Ordering::Less),
+            RoundingMode::Ceiling | RoundingMode::Up => assert_eq!(o, Ordering::Greater),
+            _ => assert_ne!(o, Ordering::Equal),
+        }
+
+        // A more precise approximation, rounded down, agrees with the directly computed one
+        let (mut x_alt, o_alt) = Float::pi_prec_round(prec + 10, RoundingMode::Floor);
+        assert_eq!(o_alt, Ordering::Less);
+        x_alt.set_prec_round(prec, RoundingMode::Floor);
+        let (floor, _) = Float::pi_prec_round(prec, RoundingMode::Floor);
+        assert_eq!(ComparableFloat(x_alt), ComparableFloat(floor));
+
+        if let Ok(rm) = rug_round_try_from_rounding_mode(rm) {
+            let (rug_x, rug_o) = rug_pi_prec_round(prec, rm);
+            assert_eq!(
+                ComparableFloatRef(&Float::from(&rug_x)),
+                ComparableFloatRef(&x)
+            );
+            assert_eq!(rug_o, o);
+        }
+    });
+
+    unsigned_gen_var_11().test_properties(|prec| {
+        assert_panic!(Float::pi_prec_round(prec, RoundingMode::Exact));
+        let (floor, o_floor) = Float::pi_prec_round(prec, RoundingMode::Floor);
+        let (ceiling, o_ceiling) = Float::pi_prec_round(prec, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        assert_eq!(o_floor, Ordering::Less);
+        assert_eq!(o_ceiling, Ordering::Greater);
+    });
+}
@@ -0,0 +1,77 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeOne, NegativeZero, One, Zero,
+};
+use malachite_base::strings::string_is_subset;
+use malachite_base::test_util::generators::string_gen;
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_q::Rational;
+
+#[test]
+fn test_serde() {
+    let test = |x: Float, out: &str| {
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        let y = serde_json::from_str::<Float>(out).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+    };
+    test(Float::NAN, "\"NaN\"");
+    test(Float::INFINITY, "\"Infinity\"");
+    test(Float::NEGATIVE_INFINITY, "\"-Infinity\"");
+    test(Float::ZERO, "\"0x0.0\"");
+    test(Float::NEGATIVE_ZERO, "\"-0x0.0\"");
+    test(Float::ONE, "\"0x1.0#1\"");
+    test(Float::NEGATIVE_ONE, "\"-0x1.0#1\"");
+    test(
+        Float::one_prec(100),
+        "\"0x1.0000000000000000000000000#100\"",
+    );
+    test(Float::from(123u32), "\"0x7b.0#7\"");
+    test(Float::from(-1.5), "\"-0x1.8000000000000#53\"");
+    test(
+        Float::from(std::f64::consts::PI),
+        "\"0x3.243f6a8885a30#53\"",
+    );
+    test(Float::power_of_2(100u64), "\"0x1.0E+25#1\"");
+    test(Float::power_of_2(-100i64), "\"0x1.0E-25#1\"");
+    test(
+        Float::from_rational_prec(Rational::from_signeds(1, 3), 10).0,
+        "\"0x0.556#10\"",
+    );
+}
+
+#[test]
+fn test_serde_fail() {
+    let test = |s: &str| {
+        assert!(serde_json::from_str::<Float>(s).is_err());
+    };
+    test("\"\"");
+    test("\"nan\"");
+    test("\"inf\"");
+    test("\"1.5\"");
+    test("\"0x1.8\"");
+    test("\"0x1.8#1\"");
+    test("\"0x1.8#0\"");
+    test("\"0x0.0#5\"");
+    test("\"1.8#2\"");
+    test("\"0x1.g#2\"");
+    test("1.5");
+    test("null");
+}
+
+#[test]
+fn serde_properties() {
+    float_gen().test_properties(|x| {
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(s, format!("\"{:#x}\"", ComparableFloatRef(&x)));
+        let y = serde_json::from_str::<Float>(&s).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloat(y), ComparableFloat(x));
+        assert!(string_is_subset(&s, "\"#+-.0123456789ENIabcdefinftxy"));
+    });
+
+    string_gen().test_properties(|s| {
+        let _x: Result<Float, _> = serde_json::from_str(&s);
+    });
+}
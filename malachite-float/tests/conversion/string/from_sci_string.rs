@@ -0,0 +1,616 @@
+use malachite_base::num::conversion::string::from_sci_string::preprocess_sci_string;
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, FromSciString};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::{
+    string_from_sci_string_options_pair_gen_var_2, string_from_sci_string_options_pair_gen_var_3,
+    string_gen_var_15,
+};
+use malachite_float::test_util::common::{rug_round_try_from_rounding_mode, to_hex_string};
+use malachite_float::test_util::generators::string_unsigned_rounding_mode_triple_gen_var_1;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+use std::cmp::Ordering;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_from_sci_string_prec_round() {
+    let test =
+        |s: &str, base: u8, prec: u64, rm: RoundingMode, out: Option<(&str, &str, Ordering)>| {
+            let mut options = FromSciStringOptions::default();
+            options.set_base(base);
+            let result = Float::from_sci_string_prec_round(s, options, prec, rm);
+            if let Some((x, _)) = &result {
+                assert!(x.is_valid());
+            }
+            assert_eq!(
+                result
+                    .as_ref()
+                    .map(|(x, o)| (x.to_string(), to_hex_string(x), *o)),
+                out.map(|(x, x_hex, o)| (x.to_string(), x_hex.to_string(), o))
+            );
+
+            if rm == RoundingMode::Nearest {
+                let result_alt = Float::from_sci_string_prec(s, options, prec);
+                assert_eq!(
+                    result_alt.map(|(x, o)| (ComparableFloat(x), o)),
+                    result.clone().map(|(x, o)| (ComparableFloat(x), o))
+                );
+            }
+
+            if base <= 10 {
+                if let (Ok(rug_rm), Ok(incomplete), Some((x, o))) = (
+                    rug_round_try_from_rounding_mode(rm),
+                    rug::Float::parse_radix(s, i32::from(base)),
+                    result,
+                ) {
+                    let (rug_x, rug_o) =
+                        rug::Float::with_val_round(u32::exact_from(prec), incomplete, rug_rm);
+                    assert_eq!(
+                        ComparableFloatRef(&Float::from(&rug_x)),
+                        ComparableFloatRef(&x)
+                    );
+                    assert_eq!(rug_o, o);
+                }
+            }
+        };
+    test(
+        "0",
+        10,
+        10,
+        RoundingMode::Nearest,
+        Some(("0.0", "0x0.0", Ordering::Equal)),
+    );
+    test(
+        "-0",
+        10,
+        10,
+        RoundingMode::Nearest,
+        Some(("-0.0", "-0x0.0", Ordering::Equal)),
+    );
+    test(
+        "-0.000e5",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("-0.0", "-0x0.0", Ordering::Equal)),
+    );
+    test(
+        "1",
+        10,
+        1,
+        RoundingMode::Exact,
+        Some(("1.0", "0x1.0#1", Ordering::Equal)),
+    );
+    test(
+        "1",
+        10,
+        100,
+        RoundingMode::Exact,
+        Some(("1.0", "0x1.0000000000000000000000000#100", Ordering::Equal)),
+    );
+    test(
+        "123",
+        10,
+        4,
+        RoundingMode::Floor,
+        Some(("1.2e2", "0x78.0#4", Ordering::Less)),
+    );
+    test(
+        "123",
+        10,
+        4,
+        RoundingMode::Ceiling,
+        Some(("1.3e2", "0x8.0E+1#4", Ordering::Greater)),
+    );
+    test(
+        "123",
+        10,
+        4,
+        RoundingMode::Down,
+        Some(("1.2e2", "0x78.0#4", Ordering::Less)),
+    );
+    test(
+        "123",
+        10,
+        4,
+        RoundingMode::Up,
+        Some(("1.3e2", "0x8.0E+1#4", Ordering::Greater)),
+    );
+    test(
+        "123",
+        10,
+        4,
+        RoundingMode::Nearest,
+        Some(("1.2e2", "0x78.0#4", Ordering::Less)),
+    );
+    test(
+        "-123",
+        10,
+        4,
+        RoundingMode::Floor,
+        Some(("-1.3e2", "-0x8.0E+1#4", Ordering::Less)),
+    );
+    test(
+        "-123",
+        10,
+        4,
+        RoundingMode::Ceiling,
+        Some(("-1.2e2", "-0x78.0#4", Ordering::Greater)),
+    );
+    test(
+        "-123",
+        10,
+        4,
+        RoundingMode::Nearest,
+        Some(("-1.2e2", "-0x78.0#4", Ordering::Greater)),
+    );
+    test(
+        "0.1",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("0.09998", "0x0.1998#10", Ordering::Less)),
+    );
+    test(
+        "0.1",
+        10,
+        10,
+        RoundingMode::Ceiling,
+        Some(("0.1001", "0x0.19a0#10", Ordering::Greater)),
+    );
+    test(
+        "0.1",
+        10,
+        10,
+        RoundingMode::Nearest,
+        Some(("0.09998", "0x0.1998#10", Ordering::Less)),
+    );
+    test(
+        "0.1",
+        10,
+        100,
+        RoundingMode::Nearest,
+        Some((
+            "0.1",
+            "0x0.1999999999999999999999999a#100",
+            Ordering::Greater,
+        )),
+    );
+    test(
+        "-.5",
+        10,
+        1,
+        RoundingMode::Exact,
+        Some(("-0.5", "-0x0.8#1", Ordering::Equal)),
+    );
+    test(
+        "+5.",
+        10,
+        3,
+        RoundingMode::Exact,
+        Some(("5.0", "0x5.0#3", Ordering::Equal)),
+    );
+    test(
+        "1.5e3",
+        10,
+        10,
+        RoundingMode::Exact,
+        Some(("1500.0", "0x5dc.0#10", Ordering::Equal)),
+    );
+    test(
+        "1.5E+3",
+        10,
+        10,
+        RoundingMode::Nearest,
+        Some(("1500.0", "0x5dc.0#10", Ordering::Equal)),
+    );
+    test(
+        "1e-1000",
+        10,
+        10,
+        RoundingMode::Nearest,
+        Some(("9.997e-1001", "0x4.34E-831#10", Ordering::Less)),
+    );
+    test(
+        "1e-1000",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("9.997e-1001", "0x4.34E-831#10", Ordering::Less)),
+    );
+    test(
+        "1e1000",
+        10,
+        10,
+        RoundingMode::Ceiling,
+        Some(("1.001e1000", "0x3.cfE+830#10", Ordering::Greater)),
+    );
+    test(
+        "-3.7e-100000",
+        10,
+        20,
+        RoundingMode::Up,
+        Some(("-3.700006e-100000", "-0x2.1c754E-83048#20", Ordering::Less)),
+    );
+    test(
+        "2.5",
+        10,
+        2,
+        RoundingMode::Nearest,
+        Some(("2.0", "0x2.0#2", Ordering::Less)),
+    );
+    test(
+        "3.5",
+        10,
+        2,
+        RoundingMode::Nearest,
+        Some(("4.0", "0x4.0#2", Ordering::Greater)),
+    );
+    test(
+        "101.1",
+        2,
+        4,
+        RoundingMode::Exact,
+        Some(("5.5", "0x5.8#4", Ordering::Equal)),
+    );
+    test(
+        "101.1",
+        2,
+        2,
+        RoundingMode::Nearest,
+        Some(("6.0", "0x6.0#2", Ordering::Greater)),
+    );
+    test(
+        "0.1",
+        3,
+        10,
+        RoundingMode::Nearest,
+        Some(("0.3335", "0x0.556#10", Ordering::Greater)),
+    );
+    test(
+        "ff.8",
+        16,
+        12,
+        RoundingMode::Exact,
+        Some(("255.5", "0xff.8#12", Ordering::Equal)),
+    );
+    test(
+        "ffE+5",
+        16,
+        10,
+        RoundingMode::Nearest,
+        Some(("2.674e8", "0xf.f0E+6#10", Ordering::Equal)),
+    );
+    test(
+        "ffE-5",
+        16,
+        10,
+        RoundingMode::Nearest,
+        Some(("0.0002432", "0x0.000ff0#10", Ordering::Equal)),
+    );
+    test(
+        "zz.z",
+        36,
+        20,
+        RoundingMode::Nearest,
+        Some(("1295.973", "0x50f.f90#20", Ordering::Greater)),
+    );
+    test(
+        "1e-5",
+        36,
+        20,
+        RoundingMode::Nearest,
+        Some(("1.653817e-8", "0x4.707e8E-7#20", Ordering::Less)),
+    );
+    test(
+        "nan",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("NaN", "NaN", Ordering::Equal)),
+    );
+    test(
+        "-NaN",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("NaN", "NaN", Ordering::Equal)),
+    );
+    test(
+        "inf",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("Infinity", "Infinity", Ordering::Equal)),
+    );
+    test(
+        "-Infinity",
+        16,
+        10,
+        RoundingMode::Floor,
+        Some(("-Infinity", "-Infinity", Ordering::Equal)),
+    );
+    test(
+        "+INF",
+        10,
+        10,
+        RoundingMode::Floor,
+        Some(("Infinity", "Infinity", Ordering::Equal)),
+    );
+    test(
+        "@nan@",
+        36,
+        10,
+        RoundingMode::Floor,
+        Some(("NaN", "NaN", Ordering::Equal)),
+    );
+    test(
+        "-@inf@",
+        36,
+        10,
+        RoundingMode::Floor,
+        Some(("-Infinity", "-Infinity", Ordering::Equal)),
+    );
+    test("", 10, 10, RoundingMode::Floor, None);
+    test("-", 10, 10, RoundingMode::Floor, None);
+    test(".", 10, 10, RoundingMode::Floor, None);
+    test("e5", 10, 10, RoundingMode::Floor, None);
+    test("1e", 10, 10, RoundingMode::Floor, None);
+    test("--1", 10, 10, RoundingMode::Floor, None);
+    test("1.2.3", 10, 10, RoundingMode::Floor, None);
+    test("12a", 10, 10, RoundingMode::Floor, None);
+    test("2", 2, 10, RoundingMode::Floor, None);
+    test(
+        "nan",
+        36,
+        10,
+        RoundingMode::Floor,
+        Some(("3.018e4", "0x7.5eE+3#10", Ordering::Less)),
+    );
+    test(
+        "inf",
+        36,
+        10,
+        RoundingMode::Floor,
+        Some(("2.416e4", "0x5.e6E+3#10", Ordering::Less)),
+    );
+    test("1e9223372036854775807", 10, 10, RoundingMode::Floor, None);
+}
+
+#[test]
+fn from_sci_string_prec_round_fail() {
+    let options = FromSciStringOptions::default();
+    assert_panic!(Float::from_sci_string_prec_round(
+        "1",
+        options,
+        0,
+        RoundingMode::Floor
+    ));
+    assert_panic!(Float::from_sci_string_prec_round(
+        "0.1",
+        options,
+        10,
+        RoundingMode::Exact
+    ));
+    assert_panic!(Float::from_sci_string_prec_round(
+        "1e-1000",
+        options,
+        10,
+        RoundingMode::Exact
+    ));
+}
+
+#[test]
+fn test_from_sci_string() {
+    let test = |s: &str, base: u8, out: Option<(&str, &str)>| {
+        let mut options = FromSciStringOptions::default();
+        options.set_base(base);
+        let x = Float::from_sci_string_with_options(s, options);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(
+            x.as_ref().map(|x| (x.to_string(), to_hex_string(x))),
+            out.map(|(x, x_hex)| (x.to_string(), x_hex.to_string()))
+        );
+        if base == 10 {
+            assert_eq!(
+                Float::from_sci_string(s).map(ComparableFloat),
+                x.map(ComparableFloat)
+            );
+        }
+    };
+    test("0", 10, Some(("0.0", "0x0.0")));
+    test("-0", 10, Some(("-0.0", "-0x0.0")));
+    test("00.00", 10, Some(("0.0", "0x0.0")));
+    test("1", 10, Some(("1.0", "0x1.0#5")));
+    test("123", 10, Some(("123.0", "0x7b.0#11")));
+    test("-123", 10, Some(("-123.0", "-0x7b.0#11")));
+    test("0.1", 10, Some(("0.1", "0x0.1a#5")));
+    test("0.100", 10, Some(("0.09998", "0x0.1998#11")));
+    test("1.25e-3", 10, Some(("0.00125", "0x0.0051f#11")));
+    test("1e100", 10, Some(("9.8e99", "0x1.2E+83#5")));
+    test("3.14159", 10, Some(("3.14159", "0x3.243f4#21")));
+    test(
+        "123456789012345678901234567890",
+        10,
+        Some((
+            "123456789012345678901234567890.0",
+            "0x18ee90ff6c373e0ee4e3f0ad2.0#101",
+        )),
+    );
+    test("NaN", 10, Some(("NaN", "NaN")));
+    test("-inf", 10, Some(("-Infinity", "-Infinity")));
+    test("Infinity", 10, Some(("Infinity", "Infinity")));
+    test("101.1", 2, Some(("5.5", "0x5.8#4")));
+    test("0.1", 3, Some(("0.3", "0x0.5#3")));
+    test("ff", 16, Some(("255.0", "0xff.0#8")));
+    test("ff.8", 16, Some(("255.5", "0xff.8#12")));
+    test("-ffE-5", 16, Some(("-0.000243", "-0x0.000ff#8")));
+    test("zz", 36, Some(("1295.0", "0x50f.0#12")));
+    test("", 10, None);
+    test("abc", 10, None);
+    test("1.2.3", 10, None);
+    test("nan", 36, Some(("30191.0", "0x75ef.0#17")));
+}
+
+#[test]
+fn test_from_sci_string_with_options() {
+    let test = |s: &str, rm: RoundingMode, out: Option<&str>| {
+        let mut options = FromSciStringOptions::default();
+        options.set_rounding_mode(rm);
+        let x = Float::from_sci_string_with_options(s, options);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(x.as_ref().map(to_hex_string).as_deref(), out);
+    };
+    test("1.5", RoundingMode::Exact, Some("0x1.80#8"));
+    test("1.5", RoundingMode::Floor, Some("0x1.80#8"));
+    test("0.1", RoundingMode::Exact, None);
+    test("0.1", RoundingMode::Floor, Some("0x0.19#5"));
+    test("0.1", RoundingMode::Ceiling, Some("0x0.1a#5"));
+    test("1e-3", RoundingMode::Exact, None);
+    test("1e-3", RoundingMode::Floor, Some("0x0.0040#5"));
+    test("1e-3", RoundingMode::Ceiling, Some("0x0.0044#5"));
+    test("1.25", RoundingMode::Exact, Some("0x1.400#11"));
+    test("nan", RoundingMode::Exact, Some("NaN"));
+    test("-inf", RoundingMode::Exact, Some("-Infinity"));
+}
+
+// The precision implied by a string, as described in the documentation of
+// `Float::from_sci_string_with_options`.
+fn implied_precision(s: &str, options: FromSciStringOptions) -> u64 {
+    let base = options.get_base();
+    let digits = preprocess_sci_string(s, options)
+        .unwrap()
+        .0
+        .into_iter()
+        .skip_while(|&c| c == b'+' || c == b'-' || c == b'0')
+        .count() as u64;
+    if base.is_power_of_two() {
+        digits * u64::from(base.trailing_zeros())
+    } else {
+        let mut power = Natural::from(1u32);
+        let mut prec = 1;
+        for _ in 0..digits {
+            power *= Natural::from(base);
+        }
+        while Natural::from(1u32) << prec < power {
+            prec += 1;
+        }
+        prec + 1
+    }
+}
+
+#[test]
+fn from_sci_string_prec_round_properties() {
+    string_unsigned_rounding_mode_triple_gen_var_1().test_properties(|(s, prec, rm)| {
+        let options = FromSciStringOptions::default();
+        let result = Float::from_sci_string_prec_round(&s, options, prec, rm);
+        let q = Rational::from_sci_string(&s);
+        assert_eq!(result.is_some(), q.is_some());
+        if let (Some((x, o)), Some(q)) = (result, q) {
+            assert!(x.is_valid());
+            let (x_alt, o_alt) = Float::from_rational_prec_round(q.clone(), prec, rm);
+            if q == 0u32 {
+                assert_eq!(x, 0u32);
+                assert_eq!(x.is_sign_negative(), s.starts_with('-'));
+            } else {
+                assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+                assert_eq!(x.get_prec(), Some(prec));
+            }
+            assert_eq!(o_alt, o);
+
+            if rm == RoundingMode::Nearest {
+                let (x_alt, o_alt) = Float::from_sci_string_prec(&s, options, prec).unwrap();
+                assert_eq!(ComparableFloatRef(&x_alt), ComparableFloatRef(&x));
+                assert_eq!(o_alt, o);
+            }
+
+            if let Ok(incomplete) = rug::Float::parse(&s) {
+                let (rug_x, rug_o) = rug::Float::with_val_round(
+                    u32::exact_from(prec),
+                    incomplete,
+                    rug_round_try_from_rounding_mode(rm).unwrap(),
+                );
+                assert_eq!(
+                    ComparableFloatRef(&Float::from(&rug_x)),
+                    ComparableFloatRef(&x)
+                );
+                assert_eq!(rug_o, o);
+            }
+        }
+    });
+}
+
+#[test]
+fn from_sci_string_properties() {
+    string_gen_var_15().test_properties(|s| {
+        let x = Float::from_sci_string(&s);
+        let q = Rational::from_sci_string(&s);
+        assert_eq!(x.is_some(), q.is_some());
+        if let (Some(x), Some(q)) = (x, q) {
+            assert!(x.is_valid());
+            if q == 0u32 {
+                assert_eq!(x, 0u32);
+                assert_eq!(x.is_sign_negative(), s.starts_with('-'));
+            } else {
+                let prec = implied_precision(&s, FromSciStringOptions::default());
+                assert_eq!(x.get_prec(), Some(prec));
+                assert_eq!(
+                    ComparableFloat(Float::from_rational_prec(q, prec).0),
+                    ComparableFloat(x)
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn from_sci_string_with_options_properties() {
+    string_from_sci_string_options_pair_gen_var_2().test_properties(|(s, options)| {
+        if let Some(x) = Float::from_sci_string_with_options(&s, options) {
+            assert!(x.is_valid());
+        }
+    });
+
+    string_from_sci_string_options_pair_gen_var_3().test_properties(|(s, options)| {
+        let x = Float::from_sci_string_with_options(&s, options);
+        let q = Rational::from_sci_string_with_options(&s, options);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        let rm = options.get_rounding_mode();
+        if rm != RoundingMode::Exact {
+            assert_eq!(x.is_some(), q.is_some());
+        } else if q.is_none() {
+            assert!(x.is_none());
+        }
+        if let Some(q) = q {
+            if q == 0u32 {
+                let x = x.unwrap();
+                assert_eq!(x, 0u32);
+                assert_eq!(x.is_sign_negative(), s.starts_with('-'));
+            } else {
+                let prec = implied_precision(&s, options);
+                let (x_alt, o) = Float::from_rational_prec_round(
+                    q,
+                    prec,
+                    if rm == RoundingMode::Exact {
+                        RoundingMode::Floor
+                    } else {
+                        rm
+                    },
+                );
+                if rm == RoundingMode::Exact && o != Ordering::Equal {
+                    assert!(x.is_none());
+                } else {
+                    assert_eq!(ComparableFloat(x_alt), ComparableFloat(x.unwrap()));
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,122 @@
+use malachite_base::num::conversion::string::options::FromSciStringOptions;
+use malachite_base::num::conversion::traits::{FromSciString, FromStringBase};
+use malachite_base::test_util::generators::{string_gen_var_15, string_unsigned_pair_gen_var_1};
+use malachite_float::test_util::common::{parse_hex_string, to_hex_string};
+use malachite_float::test_util::generators::float_gen;
+use malachite_float::{ComparableFloat, ComparableFloatRef, Float};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test = |s: &str, out: Option<&str>| {
+        let x = Float::from_str(s);
+        if let Ok(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(x.as_ref().ok().map(to_hex_string).as_deref(), out);
+    };
+    test("0", Some("0x0.0"));
+    test("-0.0", Some("-0x0.0"));
+    test("1", Some("0x1.0#5"));
+    test("123.0", Some("0x7b.00#15"));
+    test("-123", Some("-0x7b.0#11"));
+    test("0.1", Some("0x0.1a#5"));
+    test("1.25e-3", Some("0x0.0051f#11"));
+    test("3.14159", Some("0x3.243f4#21"));
+    test("NaN", Some("NaN"));
+    test("nan", Some("NaN"));
+    test("Infinity", Some("Infinity"));
+    test("inf", Some("Infinity"));
+    test("-Infinity", Some("-Infinity"));
+    test("-INF", Some("-Infinity"));
+    test("", None);
+    test("-", None);
+    test(".", None);
+    test("--1", None);
+    test("1.2.3", None);
+    test("0x1.8#2", None);
+    test("abc", None);
+}
+
+#[test]
+fn test_from_string_base() {
+    let test = |base: u8, s: &str, out: Option<&str>| {
+        let x = Float::from_string_base(base, s);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(x.as_ref().map(to_hex_string).as_deref(), out);
+    };
+    test(16, "NaN", Some("NaN"));
+    test(16, "Infinity", Some("Infinity"));
+    test(16, "-Infinity", Some("-Infinity"));
+    test(16, "0x0.0", Some("0x0.0"));
+    test(16, "-0x0.0", Some("-0x0.0"));
+    test(16, "0x1.0#1", Some("0x1.0#1"));
+    test(16, "-0x1.8#2", Some("-0x1.8#2"));
+    test(16, "0x0.1998#10", Some("0x0.1998#10"));
+    test(16, "0x8.0E+1#4", Some("0x8.0E+1#4"));
+    test(16, "0x4.34E-831#10", Some("0x4.34E-831#10"));
+    test(16, "ff", Some("0xff.0#8"));
+    test(16, "ff.8", Some("0xff.8#12"));
+    test(10, "123", Some("0x7b.0#11"));
+    test(2, "-101.1", Some("-0x5.8#4"));
+    test(3, "0.1", Some("0x0.5#3"));
+    test(36, "zz", Some("0x50f.0#12"));
+
+    // missing or zero precision
+    test(16, "0x1.8", None);
+    test(16, "0x1.8#0", None);
+    // not exactly representable with the given precision
+    test(16, "0x1.8#1", None);
+    test(16, "0x#1", None);
+    test(16, "0xg#1", None);
+    test(10, "12a", None);
+    test(2, "2", None);
+}
+
+#[allow(clippy::unnecessary_operation)]
+#[test]
+fn from_string_base_fail() {
+    assert_panic!(Float::from_string_base(1, "0"));
+    assert_panic!(Float::from_string_base(37, "0"));
+}
+
+#[test]
+fn from_str_properties() {
+    string_gen_var_15().test_properties(|s| {
+        let x = Float::from_str(&s);
+        assert_eq!(
+            x.clone().ok().map(ComparableFloat),
+            Float::from_sci_string(&s).map(ComparableFloat)
+        );
+        if let Ok(x) = x {
+            assert!(x.is_valid());
+        }
+    });
+}
+
+#[test]
+fn from_string_base_properties() {
+    float_gen().test_properties(|x| {
+        let y = parse_hex_string(&to_hex_string(&x));
+        assert!(y.is_valid());
+        assert_eq!(ComparableFloatRef(&y), ComparableFloatRef(&x));
+    });
+
+    string_unsigned_pair_gen_var_1().test_properties(|(s, base)| {
+        let x = Float::from_string_base(base, &s);
+        if let Some(x) = &x {
+            assert!(x.is_valid());
+        }
+        if base != 16 || !(s.contains('#') || s.starts_with("0x") || s.starts_with("-0x")) {
+            let mut options = FromSciStringOptions::default();
+            options.set_base(base);
+            assert_eq!(
+                x.map(ComparableFloat),
+                Float::from_sci_string_with_options(&s, options).map(ComparableFloat)
+            );
+        }
+    });
+}
@@ -0,0 +1,282 @@
+use malachite_base::num::arithmetic::traits::PowerOf2;
+use malachite_base::num::basic::traits::{
+    Infinity, NaN, NegativeInfinity, NegativeZero, One, Zero,
+};
+use malachite_base::num::conversion::string::options::ToSciOptions;
+use malachite_base::num::conversion::traits::{ExactFrom, ToSci};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::test_util::generators::{
+    float_gen, float_to_sci_options_pair_gen, float_to_sci_options_pair_gen_var_1,
+};
+use malachite_float::Float;
+use malachite_q::Rational;
+
+#[test]
+pub fn test_to_sci() {
+    let test = |x: Float, out: &str| {
+        assert_eq!(x.to_sci().to_string(), out);
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            out
+        );
+        if x.is_finite() && x != 0u32 {
+            assert_eq!(Rational::exact_from(&x).to_sci().to_string(), out);
+        }
+    };
+    test(Float::NAN, "NaN");
+    test(Float::INFINITY, "Infinity");
+    test(Float::NEGATIVE_INFINITY, "-Infinity");
+    test(Float::ZERO, "0");
+    test(Float::NEGATIVE_ZERO, "-0");
+    test(Float::ONE, "1");
+    test(Float::from(123.0), "123");
+    test(Float::from(-2.5), "-2.5");
+    test(Float::from(0.5), "0.5");
+    test(Float::from(0.1), "0.1");
+    test(Float::from(1.0 / 3.0), "0.3333333333333333");
+    test(Float::from(std::f64::consts::PI), "3.141592653589793");
+    test(Float::from(-std::f64::consts::E), "-2.718281828459045");
+    test(Float::from(0.001), "0.001");
+    test(Float::from(1.0e-7), "1e-7");
+    test(Float::from(1.0e100), "1e100");
+    test(Float::from(u64::MAX), "1.844674407370955e19");
+    test(Float::one_prec(128), "1");
+    test(
+        Float::from_rational_prec(Rational::from_signeds(1, 3), 128).0,
+        "0.3333333333333333",
+    );
+    test(Float::power_of_2(1000000u64), "9.900656229295898e301029");
+    test(-Float::power_of_2(1000000u64), "-9.900656229295898e301029");
+    test(Float::power_of_2(-1000000i64), "1.01003405919803e-301030");
+    test(-Float::power_of_2(-1000000i64), "-1.01003405919803e-301030");
+}
+
+#[test]
+pub fn test_to_sci_with_options() {
+    let test = |x: Float, options: ToSciOptions, out: &str| {
+        assert!(x.fmt_sci_valid(options));
+        assert_eq!(x.to_sci_with_options(options).to_string(), out);
+        if x.is_finite() && x != 0u32 {
+            assert_eq!(
+                Rational::exact_from(&x)
+                    .to_sci_with_options(options)
+                    .to_string(),
+                out
+            );
+        }
+    };
+    let pi = Float::from(std::f64::consts::PI);
+    let mut options = ToSciOptions::default();
+    test(Float::NAN, options, "NaN");
+    test(Float::NEGATIVE_INFINITY, options, "-Infinity");
+
+    options.set_precision(3);
+    test(pi.clone(), options, "3.14");
+    test(-&pi, options, "-3.14");
+    test(Float::from(0.125), options, "0.125");
+    test(Float::from(1000.0), options, "1e3");
+    test(Float::from(999.5), options, "1e3");
+    test(Float::from(99.95), options, "100");
+    options.set_rounding_mode(RoundingMode::Floor);
+    test(pi.clone(), options, "3.14");
+    test(-&pi, options, "-3.15");
+    test(Float::from(999.5), options, "999");
+    options.set_rounding_mode(RoundingMode::Ceiling);
+    test(pi.clone(), options, "3.15");
+    test(-&pi, options, "-3.14");
+    options.set_rounding_mode(RoundingMode::Down);
+    test(-&pi, options, "-3.14");
+    options.set_rounding_mode(RoundingMode::Up);
+    test(-&pi, options, "-3.15");
+    options.set_include_trailing_zeros(true);
+    test(Float::ONE, options, "1.00");
+    test(Float::ZERO, options, "0.00");
+    test(Float::NEGATIVE_ZERO, options, "-0.00");
+
+    options = ToSciOptions::default();
+    options.set_scale(2);
+    test(pi.clone(), options, "3.14");
+    test(Float::from(2.5), options, "2.5");
+    test(Float::from(1234.5678), options, "1234.57");
+    test(Float::from(0.001), options, "0");
+    test(Float::from(0.005), options, "0.01");
+    test(Float::from(-0.001), options, "-0");
+    options.set_include_trailing_zeros(true);
+    test(Float::from(2.5), options, "2.50");
+    options.set_scale(0);
+    test(Float::from(2.5), options, "2");
+    test(Float::from(3.5), options, "4");
+
+    options = ToSciOptions::default();
+    options.set_size_complete();
+    test(
+        Float::from(0.1),
+        options,
+        "0.1000000000000000055511151231257827021181583404541015625",
+    );
+    test(Float::from(-5.5), options, "-5.5");
+    test(Float::power_of_2(-10i64), options, "0.0009765625");
+    options.set_base(2);
+    test(Float::from(-5.5), options, "-101.1");
+    options.set_base(3);
+    test(Float::from(-5.0), options, "-12");
+
+    options = ToSciOptions::default();
+    options.set_base(16);
+    test(pi.clone(), options, "3.243f6a8885a3");
+    options.set_uppercase();
+    test(pi.clone(), options, "3.243F6A8885A3");
+    options.set_base(36);
+    options.set_precision(5);
+    test(Float::from(1.0e10), options, "4.LDQPe+6");
+    test(Float::from(1.0e20), options, "L.3R42e+12");
+    options.set_base(2);
+    options.set_precision(4);
+    test(Float::from(0.1), options, "0.0001101");
+
+    options = ToSciOptions::default();
+    options.set_precision(6);
+    let x = Float::power_of_2(1000i64);
+    test(x.clone(), options, "1.07151e301");
+    options.set_e_uppercase();
+    test(x.clone(), options, "1.07151E301");
+    options.set_force_exponent_plus_sign(true);
+    test(x, options, "1.07151E+301");
+
+    options = ToSciOptions::default();
+    test(Float::from(1.0e-6), options, "1e-6");
+    test(Float::from(1.0e-5), options, "0.00001");
+    options.set_neg_exp_threshold(-3);
+    test(Float::from(1.0e-3), options, "1e-3");
+    test(Float::from(1.0e-2), options, "0.01");
+    options.set_neg_exp_threshold(-10);
+    test(Float::from(1.0e-6), options, "0.000001");
+
+    options = ToSciOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    options.set_precision(3);
+    test(Float::from(0.125), options, "0.125");
+    test(Float::from(-1.5e10), options, "-1.5e10");
+}
+
+#[test]
+pub fn test_fmt_sci_valid() {
+    let test = |x: Float, options: ToSciOptions, out: bool| {
+        assert_eq!(x.fmt_sci_valid(options), out);
+    };
+    let mut options = ToSciOptions::default();
+    test(Float::NAN, options, true);
+    test(Float::from(u128::MAX), options, true);
+    options.set_rounding_mode(RoundingMode::Exact);
+    test(Float::from(u128::MAX), options, false);
+    test(Float::INFINITY, options, true);
+    test(Float::NEGATIVE_ZERO, options, true);
+    test(Float::from(0.125), options, true);
+    options.set_precision(2);
+    test(Float::from(0.125), options, false);
+    options.set_scale(3);
+    test(Float::from(0.125), options, true);
+    options.set_scale(2);
+    test(Float::from(0.125), options, false);
+
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    test(Float::from(0.125), options, true);
+    test(Float::power_of_2(-100i64), options, true);
+    options.set_base(3);
+    test(Float::from(0.125), options, false);
+    test(Float::from(12.0), options, true);
+    test(Float::NEGATIVE_INFINITY, options, true);
+}
+
+#[should_panic]
+#[test]
+pub fn to_sci_with_options_fail() {
+    let mut options = ToSciOptions::default();
+    options.set_rounding_mode(RoundingMode::Exact);
+    options.set_precision(2);
+    Float::from(123).to_sci_with_options(options).to_string();
+}
+
+#[should_panic]
+#[test]
+pub fn to_sci_with_options_fail_2() {
+    let mut options = ToSciOptions::default();
+    options.set_size_complete();
+    options.set_base(3);
+    Float::from(0.5).to_sci_with_options(options).to_string();
+}
+
+#[test]
+fn to_sci_properties() {
+    float_gen().test_properties(|x| {
+        assert!(x.fmt_sci_valid(ToSciOptions::default()));
+        let s = x.to_sci().to_string();
+        assert_eq!(
+            x.to_sci_with_options(ToSciOptions::default()).to_string(),
+            s
+        );
+        if x.is_nan() {
+            assert_eq!(s, "NaN");
+        } else if x == 0u32 {
+            assert_eq!(s, if x.is_sign_positive() { "0" } else { "-0" });
+        } else if x.is_finite() {
+            assert_eq!(Rational::exact_from(&x).to_sci().to_string(), s);
+        }
+        if !x.is_nan() {
+            let s_neg = (-&x).to_sci().to_string();
+            if let Some(s_abs) = s_neg.strip_prefix('-') {
+                assert_eq!(s_abs, s);
+            } else {
+                assert_eq!(s.strip_prefix('-'), Some(s_neg.as_str()));
+            }
+        }
+    });
+}
+
+#[test]
+fn to_sci_with_options_properties() {
+    float_to_sci_options_pair_gen_var_1().test_properties(|(x, options)| {
+        assert!(x.fmt_sci_valid(options));
+        let s = x.to_sci_with_options(options).to_string();
+        if x.is_nan() {
+            assert_eq!(s, "NaN");
+        } else if x == 0u32 {
+            let s_zero = Rational::ZERO.to_sci_with_options(options).to_string();
+            if x.is_sign_positive() {
+                assert_eq!(s, s_zero);
+            } else {
+                assert_eq!(s, format!("-{s_zero}"));
+            }
+        } else if x.is_finite() {
+            assert_eq!(
+                Rational::exact_from(&x)
+                    .to_sci_with_options(options)
+                    .to_string(),
+                s
+            );
+        }
+        if !x.is_nan() {
+            let mut options_neg = options;
+            options_neg.set_rounding_mode(-options.get_rounding_mode());
+            let s_neg = (-&x).to_sci_with_options(options_neg).to_string();
+            if let Some(s_abs) = s_neg.strip_prefix('-') {
+                assert_eq!(s_abs, s);
+            } else {
+                assert_eq!(s.strip_prefix('-'), Some(s_neg.as_str()));
+            }
+        }
+    });
+}
+
+#[test]
+fn fmt_sci_valid_properties() {
+    float_to_sci_options_pair_gen().test_properties(|(x, options)| {
+        let valid = x.fmt_sci_valid(options);
+        if x.is_finite() {
+            assert_eq!(valid, Rational::exact_from(&x).fmt_sci_valid(options));
+        } else {
+            assert!(valid);
+        }
+    });
+}
@@ -0,0 +1,43 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+// With the `tuned_thresholds` feature, copies the file named by `MALACHITE_NZ_THRESHOLDS` into the
+// build directory, where the platform module includes it. If the variable is unset, the built-in
+// thresholds are used, so that builds with `--all-features` (including docs.rs) keep working.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=MALACHITE_NZ_THRESHOLDS");
+    println!("cargo:rustc-check-cfg=cfg(malachite_nz_tuned)");
+    if env::var_os("CARGO_FEATURE_TUNED_THRESHOLDS").is_none() {
+        return;
+    }
+    let path = match env::var_os("MALACHITE_NZ_THRESHOLDS") {
+        Some(path) => PathBuf::from(path),
+        None => {
+            println!(
+                "cargo:warning=the tuned_thresholds feature is enabled, but \
+                MALACHITE_NZ_THRESHOLDS is not set; using the built-in thresholds"
+            );
+            return;
+        }
+    };
+    // Build scripts run in the package directory, not wherever cargo was invoked, so a relative
+    // path would silently point somewhere else.
+    if !path.is_absolute() {
+        panic!(
+            "MALACHITE_NZ_THRESHOLDS must be an absolute path, but it is {}",
+            path.display()
+        );
+    }
+    println!("cargo:rerun-if-changed={}", path.display());
+    let thresholds = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "could not read MALACHITE_NZ_THRESHOLDS file {}: {e}",
+            path.display()
+        )
+    });
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    fs::write(out_dir.join("thresholds.rs"), thresholds).unwrap();
+    println!("cargo:rustc-cfg=malachite_nz_tuned");
+}
@@ -0,0 +1,74 @@
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::{
+    integer_bit_bucketer, pair_2_integer_bit_bucketer,
+};
+use malachite_nz::test_util::generators::{integer_gen, integer_gen_rm};
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_integer_express_as_power);
+    register_demo!(runner, demo_integer_is_perfect_power);
+
+    register_bench!(runner, benchmark_integer_express_as_power);
+    register_bench!(
+        runner,
+        benchmark_integer_is_perfect_power_library_comparison
+    );
+}
+
+fn demo_integer_express_as_power(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in integer_gen().get(gm, config).take(limit) {
+        println!("{}.express_as_power() = {:?}", n, n.express_as_power());
+    }
+}
+
+fn demo_integer_is_perfect_power(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in integer_gen().get(gm, config).take(limit) {
+        if n.is_perfect_power() {
+            println!("{n} is a perfect power");
+        } else {
+            println!("{n} is not a perfect power");
+        }
+    }
+}
+
+fn benchmark_integer_express_as_power(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.express_as_power()",
+        BenchmarkType::Single,
+        integer_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &integer_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.express_as_power()))],
+    );
+}
+
+fn benchmark_integer_is_perfect_power_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Integer.is_perfect_power()",
+        BenchmarkType::LibraryComparison,
+        integer_gen_rm().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_integer_bit_bucketer("n"),
+        &mut [
+            ("Malachite", &mut |(_, n)| no_out!(n.is_perfect_power())),
+            ("rug", &mut |(n, _)| no_out!(n.is_perfect_power())),
+        ],
+    );
+}
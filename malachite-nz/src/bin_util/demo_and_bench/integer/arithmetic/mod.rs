@@ -14,6 +14,7 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod::register(runner);
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
+    is_perfect_power::register(runner);
     kronecker_symbol::register(runner);
     mod_op::register(runner);
     mod_power_of_2::register(runner);
@@ -49,6 +50,7 @@ mod divisible_by_power_of_2;
 mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
+mod is_perfect_power;
 mod kronecker_symbol;
 mod mod_op;
 mod mod_power_of_2;
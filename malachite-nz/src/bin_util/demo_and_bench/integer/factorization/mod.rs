@@ -0,0 +1,7 @@
+use malachite_base::test_util::runner::Runner;
+
+pub(crate) fn register(runner: &mut Runner) {
+    is_prime::register(runner);
+}
+
+mod is_prime;
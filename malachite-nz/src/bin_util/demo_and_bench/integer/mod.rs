@@ -5,6 +5,7 @@ pub(crate) fn register(runner: &mut Runner) {
     basic::register(runner);
     comparison::register(runner);
     conversion::register(runner);
+    factorization::register(runner);
     logic::register(runner);
 }
 
@@ -12,4 +13,5 @@ mod arithmetic;
 mod basic;
 mod comparison;
 mod conversion;
+mod factorization;
 mod logic;
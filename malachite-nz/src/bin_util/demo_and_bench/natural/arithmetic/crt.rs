@@ -0,0 +1,113 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::crt::SubproductTree;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::vec_natural_sum_bits_bucketer;
+use malachite_nz::test_util::generators::natural_vec_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_subproduct_tree_remainders);
+    register_demo!(runner, demo_natural_crt);
+
+    register_bench!(runner, benchmark_subproduct_tree_remainders_algorithms);
+    register_bench!(runner, benchmark_natural_crt);
+}
+
+// Turns a list of `Natural`s into a list of nonzero moduli, and a number to reduce modulo them.
+fn moduli_and_value(xs: Vec<Natural>) -> Option<(Vec<Natural>, Natural)> {
+    if xs.is_empty() {
+        None
+    } else {
+        let x = xs.iter().sum();
+        Some((xs.into_iter().map(|x| x + Natural::ONE).collect(), x))
+    }
+}
+
+fn demo_subproduct_tree_remainders(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (moduli, x) in natural_vec_gen()
+        .get(gm, config)
+        .filter_map(moduli_and_value)
+        .take(limit)
+    {
+        let tree = SubproductTree::new(&moduli);
+        println!(
+            "SubproductTree::new({:?}).remainders({}) = {:?}",
+            moduli,
+            x,
+            tree.remainders(&x)
+        );
+    }
+}
+
+fn demo_natural_crt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (moduli, x) in natural_vec_gen()
+        .get(gm, config)
+        .filter_map(moduli_and_value)
+        .take(limit)
+    {
+        let tree = SubproductTree::new(&moduli);
+        if tree.moduli_are_pairwise_coprime() {
+            let residues = tree.remainders(&x);
+            println!(
+                "Natural::crt({:?}, {:?}) = {}",
+                residues,
+                moduli,
+                Natural::crt(&residues, &moduli)
+            );
+        } else {
+            println!("{moduli:?} are not pairwise coprime");
+        }
+    }
+}
+
+#[allow(unused_must_use)]
+fn benchmark_subproduct_tree_remainders_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "SubproductTree.remainders(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_vec_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [
+            ("subproduct tree", &mut |xs| {
+                if let Some((moduli, x)) = moduli_and_value(xs) {
+                    no_out!(SubproductTree::new(&moduli).remainders(&x))
+                }
+            }),
+            ("naive", &mut |xs| {
+                if let Some((moduli, x)) = moduli_and_value(xs) {
+                    no_out!(moduli.iter().map(|m| &x % m).collect::<Vec<_>>())
+                }
+            }),
+        ],
+    );
+}
+
+fn benchmark_natural_crt(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural::crt(&[Natural], &[Natural])",
+        BenchmarkType::Single,
+        natural_vec_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &vec_natural_sum_bits_bucketer(),
+        &mut [("Malachite", &mut |xs| {
+            if let Some((moduli, x)) = moduli_and_value(xs) {
+                let tree = SubproductTree::new(&moduli);
+                if tree.moduli_are_pairwise_coprime() {
+                    no_out!(Natural::crt(&tree.remainders(&x), &moduli))
+                }
+            }
+        })],
+    );
+}
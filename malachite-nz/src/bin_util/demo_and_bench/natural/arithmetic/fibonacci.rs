@@ -0,0 +1,141 @@
+use malachite_base::num::arithmetic::traits::{Fibonacci, FibonacciPair, Lucas};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::bench::bucketers::unsigned_direct_bucketer;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{fibonacci_pair_naive, lucas_naive};
+use rug::Complete;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_fibonacci);
+    register_demo!(runner, demo_fibonacci_pair);
+    register_demo!(runner, demo_lucas);
+
+    register_bench!(runner, benchmark_fibonacci_library_comparison);
+    register_bench!(runner, benchmark_fibonacci_algorithms);
+    register_bench!(runner, benchmark_fibonacci_pair_library_comparison);
+    register_bench!(runner, benchmark_lucas_library_comparison);
+    register_bench!(runner, benchmark_lucas_algorithms);
+}
+
+fn demo_fibonacci(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("F_{} = {}", n, Natural::fibonacci(n));
+    }
+}
+
+fn demo_fibonacci_pair(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("(F_{n}, F_({n} - 1)) = {:?}", Natural::fibonacci_pair(n));
+    }
+}
+
+fn demo_lucas(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in unsigned_gen_var_5().get(gm, config).take(limit) {
+        println!("L_{} = {}", n, Natural::lucas(n));
+    }
+}
+
+fn benchmark_fibonacci_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.fibonacci(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::fibonacci(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_algorithms(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.fibonacci(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::fibonacci(n))),
+            ("naive", &mut |n| no_out!(fibonacci_pair_naive(n).0)),
+        ],
+    );
+}
+
+fn benchmark_fibonacci_pair_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.fibonacci_pair(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::fibonacci_pair(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::fibonacci_2(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_library_comparison(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.lucas(u64)",
+        BenchmarkType::LibraryComparison,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("Malachite", &mut |n| no_out!(Natural::lucas(n))),
+            ("rug", &mut |n| {
+                no_out!(rug::Integer::lucas(u32::exact_from(n)).complete())
+            }),
+        ],
+    );
+}
+
+fn benchmark_lucas_algorithms(gm: GenMode, config: &GenConfig, limit: usize, file_name: &str) {
+    run_benchmark(
+        "Natural.lucas(u64)",
+        BenchmarkType::Algorithms,
+        unsigned_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &unsigned_direct_bucketer(),
+        &mut [
+            ("default", &mut |n| no_out!(Natural::lucas(n))),
+            ("naive", &mut |n| no_out!(lucas_naive(n))),
+        ],
+    );
+}
@@ -7,6 +7,7 @@ pub(crate) fn register(runner: &mut Runner) {
     checked_sub::register(runner);
     checked_sub_mul::register(runner);
     coprime_with::register(runner);
+    crt::register(runner);
     div::register(runner);
     div_exact::register(runner);
     div_mod::register(runner);
@@ -17,7 +18,9 @@ pub(crate) fn register(runner: &mut Runner) {
     eq_mod_power_of_2::register(runner);
     extended_gcd::register(runner);
     factorial::register(runner);
+    fibonacci::register(runner);
     gcd::register(runner);
+    is_perfect_power::register(runner);
     is_power_of_2::register(runner);
     kronecker_symbol::register(runner);
     lcm::register(runner);
@@ -27,6 +30,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_add::register(runner);
     mod_inverse::register(runner);
     mod_is_reduced::register(runner);
+    mod_log::register(runner);
     mod_mul::register(runner);
     mod_neg::register(runner);
     mod_op::register(runner);
@@ -44,8 +48,10 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_power_of_2_sub::register(runner);
     mod_shl::register(runner);
     mod_shr::register(runner);
+    mod_sqrt::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    montgomery::register(runner);
     mul::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
@@ -75,6 +81,7 @@ mod binomial_coefficient;
 mod checked_sub;
 mod checked_sub_mul;
 mod coprime_with;
+mod crt;
 mod div;
 mod div_exact;
 mod div_mod;
@@ -85,7 +92,9 @@ mod eq_mod;
 mod eq_mod_power_of_2;
 mod extended_gcd;
 mod factorial;
+mod fibonacci;
 mod gcd;
+mod is_perfect_power;
 mod is_power_of_2;
 mod kronecker_symbol;
 mod lcm;
@@ -95,6 +104,7 @@ mod log_base_power_of_2;
 mod mod_add;
 mod mod_inverse;
 mod mod_is_reduced;
+mod mod_log;
 mod mod_mul;
 mod mod_neg;
 mod mod_op;
@@ -112,8 +122,10 @@ mod mod_power_of_2_square;
 mod mod_power_of_2_sub;
 mod mod_shl;
 mod mod_shr;
+mod mod_sqrt;
 mod mod_square;
 mod mod_sub;
+mod montgomery;
 mod mul;
 mod neg;
 mod next_power_of_2;
@@ -0,0 +1,62 @@
+use malachite_base::num::arithmetic::traits::ModLog;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_3;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_log);
+    register_demo!(runner, demo_natural_mod_log_ref);
+
+    register_bench!(runner, benchmark_natural_mod_log_evaluation_strategy);
+}
+
+fn demo_natural_mod_log(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (h, g, m) in natural_triple_gen_var_3().get(gm, config).take(limit) {
+        let h_old = h.clone();
+        let g_old = g.clone();
+        let m_old = m.clone();
+        if let Some(x) = h.mod_log(g, m) {
+            println!("{g_old}^{x} ≡ {h_old} mod {m_old}");
+        } else {
+            println!("{h_old} is not a power of {g_old} mod {m_old}");
+        }
+    }
+}
+
+fn demo_natural_mod_log_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (h, g, m) in natural_triple_gen_var_3().get(gm, config).take(limit) {
+        if let Some(x) = (&h).mod_log(&g, &m) {
+            println!("{g}^{x} ≡ {h} mod {m}");
+        } else {
+            println!("{h} is not a power of {g} mod {m}");
+        }
+    }
+}
+
+fn benchmark_natural_mod_log_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_log(Natural, Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_triple_gen_var_3().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_log(Natural, Natural)", &mut |(h, g, m)| {
+                no_out!(h.mod_log(g, m))
+            }),
+            (
+                "(&Natural).mod_log(&Natural, &Natural)",
+                &mut |(h, g, m)| no_out!((&h).mod_log(&g, &m)),
+            ),
+        ],
+    );
+}
@@ -0,0 +1,90 @@
+use malachite_base::num::arithmetic::traits::ModSqrt;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_mod_sqrt);
+    register_demo!(runner, demo_natural_mod_sqrt_val_ref);
+    register_demo!(runner, demo_natural_mod_sqrt_ref_val);
+    register_demo!(runner, demo_natural_mod_sqrt_ref_ref);
+
+    register_bench!(runner, benchmark_natural_mod_sqrt_evaluation_strategy);
+}
+
+fn demo_natural_mod_sqrt(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        let m_old = m.clone();
+        if let Some(root) = x.mod_sqrt(m) {
+            println!("√{x_old} ≡ {root} mod {m_old}");
+        } else {
+            println!("{x_old} is not a square mod {m_old}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_val_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        let x_old = x.clone();
+        if let Some(root) = x.mod_sqrt(&m) {
+            println!("√{x_old} ≡ {root} mod {m}");
+        } else {
+            println!("{x_old} is not a square mod {m}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_ref_val(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        let m_old = m.clone();
+        if let Some(root) = (&x).mod_sqrt(m) {
+            println!("√{x} ≡ {root} mod {m_old}");
+        } else {
+            println!("{x} is not a square mod {m_old}");
+        }
+    }
+}
+
+fn demo_natural_mod_sqrt_ref_ref(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, m) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        if let Some(root) = (&x).mod_sqrt(&m) {
+            println!("√{x} ≡ {root} mod {m}");
+        } else {
+            println!("{x} is not a square mod {m}");
+        }
+    }
+}
+
+fn benchmark_natural_mod_sqrt_evaluation_strategy(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.mod_sqrt(Natural)",
+        BenchmarkType::EvaluationStrategy,
+        natural_pair_gen_var_8().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [
+            ("Natural.mod_sqrt(Natural)", &mut |(x, m)| {
+                no_out!(x.mod_sqrt(m))
+            }),
+            ("Natural.mod_sqrt(&Natural)", &mut |(x, m)| {
+                no_out!(x.mod_sqrt(&m))
+            }),
+            ("(&Natural).mod_sqrt(Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_sqrt(m))
+            }),
+            ("(&Natural).mod_sqrt(&Natural)", &mut |(x, m)| {
+                no_out!((&x).mod_sqrt(&m))
+            }),
+        ],
+    );
+}
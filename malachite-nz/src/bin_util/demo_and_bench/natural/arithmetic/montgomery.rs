@@ -0,0 +1,70 @@
+use malachite_base::num::arithmetic::traits::ModMul;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+use malachite_nz::test_util::bench::bucketers::triple_3_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_8;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_montgomery_context_mul);
+
+    register_bench!(runner, benchmark_montgomery_context_mul_chain_algorithms);
+}
+
+fn demo_montgomery_context_mul(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, y, m) in natural_triple_gen_var_8().get(gm, config).take(limit) {
+        let ctx = MontgomeryContext::new(m);
+        let product = ctx.mul(&ctx.to_montgomery(&x), &ctx.to_montgomery(&y));
+        let product = ctx.from_montgomery(&product);
+        let m = ctx.modulus();
+        println!("{x} * {y} ≡ {product} mod {m}");
+    }
+}
+
+// Multiplies y by x 100 times, modulo m.
+fn benchmark_montgomery_context_mul_chain_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryContext.mul(&MontgomeryResidue, &MontgomeryResidue) 100 times",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_8().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_3_natural_bit_bucketer("m"),
+        &mut [
+            ("Montgomery", &mut |(x, y, m)| {
+                let ctx = MontgomeryContext::new(m);
+                let x = ctx.to_montgomery(&x);
+                let mut y = ctx.to_montgomery(&y);
+                for _ in 0..100 {
+                    y = ctx.mul(&x, &y);
+                }
+                no_out!(ctx.from_montgomery(&y))
+            }),
+            ("Montgomery in place", &mut |(x, y, m)| {
+                let ctx = MontgomeryContext::new(m);
+                let x = ctx.to_montgomery(&x);
+                let mut y = ctx.to_montgomery(&y);
+                let mut scratch = ctx.scratch();
+                for _ in 0..100 {
+                    ctx.mul_assign(&mut y, &x, &mut scratch);
+                }
+                no_out!(ctx.from_montgomery(&y))
+            }),
+            ("Natural.mod_mul(&Natural, &Natural)", &mut |(x, y, m)| {
+                let x = x % &m;
+                let mut y = y % &m;
+                for _ in 0..100 {
+                    y = (&x).mod_mul(y, &m);
+                }
+                no_out!(y)
+            }),
+        ],
+    );
+}
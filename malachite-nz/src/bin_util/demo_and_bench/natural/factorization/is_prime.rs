@@ -0,0 +1,56 @@
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_is_prime);
+    register_demo!(runner, demo_natural_is_prime_with_extra_rounds);
+
+    register_bench!(runner, benchmark_natural_is_prime_algorithms);
+}
+
+fn demo_natural_is_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        if n.is_prime() {
+            println!("{n} is prime");
+        } else {
+            println!("{n} is not prime");
+        }
+    }
+}
+
+fn demo_natural_is_prime_with_extra_rounds(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen().get(gm, config).take(limit) {
+        println!(
+            "{}.is_prime_with_extra_rounds(10) = {}",
+            n,
+            n.is_prime_with_extra_rounds(10)
+        );
+    }
+}
+
+fn benchmark_natural_is_prime_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.is_prime()",
+        BenchmarkType::Algorithms,
+        natural_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [
+            ("default", &mut |n| no_out!(n.is_prime())),
+            ("with 10 extra rounds", &mut |n| {
+                no_out!(n.is_prime_with_extra_rounds(10))
+            }),
+        ],
+    );
+}
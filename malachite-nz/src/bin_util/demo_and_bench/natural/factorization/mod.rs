@@ -1,7 +1,27 @@
 use malachite_base::test_util::runner::Runner;
 
 pub(crate) fn register(runner: &mut Runner) {
+    carmichael_lambda::register(runner);
+    divisor_count::register(runner);
+    divisor_sum::register(runner);
+    divisors::register(runner);
+    factor::register(runner);
+    is_prime::register(runner);
+    moebius::register(runner);
+    next_prime::register(runner);
+    preceding_prime::register(runner);
     primes::register(runner);
+    totient::register(runner);
 }
 
+mod carmichael_lambda;
+mod divisor_count;
+mod divisor_sum;
+mod divisors;
+mod factor;
+mod is_prime;
+mod moebius;
+mod next_prime;
+mod preceding_prime;
 mod primes;
+mod totient;
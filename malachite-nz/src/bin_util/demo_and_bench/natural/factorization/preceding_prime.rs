@@ -0,0 +1,40 @@
+use malachite_base::num::factorization::traits::PrecedingPrime;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_natural_preceding_prime);
+
+    register_bench!(runner, benchmark_natural_preceding_prime);
+}
+
+fn demo_natural_preceding_prime(gm: GenMode, config: &GenConfig, limit: usize) {
+    for n in natural_gen()
+        .get(gm, config)
+        .filter(|n| *n > 2u32)
+        .take(limit)
+    {
+        println!("{}.preceding_prime() = {}", n, n.preceding_prime());
+    }
+}
+
+fn benchmark_natural_preceding_prime(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural.preceding_prime()",
+        BenchmarkType::Single,
+        natural_gen().get(gm, config).filter(|n| *n > 2u32),
+        gm.name(),
+        limit,
+        file_name,
+        &natural_bit_bucketer("n"),
+        &mut [("Malachite", &mut |n| no_out!(n.preceding_prime()))],
+    );
+}
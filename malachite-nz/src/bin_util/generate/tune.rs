@@ -0,0 +1,342 @@
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::random::random_primitive_ints;
+use malachite_base::random::EXAMPLE_SEED;
+use malachite_nz::natural::arithmetic::div_mod::{
+    limbs_div_mod_barrett, limbs_div_mod_barrett_scratch_len, limbs_div_mod_divide_and_conquer,
+    limbs_div_mod_schoolbook, limbs_two_limb_inverse_helper,
+};
+#[cfg(feature = "parallel")]
+use malachite_nz::natural::arithmetic::mul::fft::MUL_FFT_PARALLEL_THRESHOLD_FOR_TUNING;
+use malachite_nz::natural::arithmetic::mul::fft::{
+    limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_is_valid,
+    limbs_mul_greater_to_out_fft_scratch_len,
+};
+use malachite_nz::natural::arithmetic::mul::fft_small::limbs_mul_greater_to_out_fft_small;
+use malachite_nz::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
+#[cfg(feature = "parallel")]
+use malachite_nz::natural::arithmetic::mul::toom::MUL_TOOM_PARALLEL_THRESHOLD_FOR_TUNING;
+use malachite_nz::natural::arithmetic::mul::toom::{
+    limbs_mul_greater_to_out_toom_22, limbs_mul_greater_to_out_toom_22_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_22_scratch_len, limbs_mul_greater_to_out_toom_33,
+    limbs_mul_greater_to_out_toom_33_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_33_scratch_len, limbs_mul_greater_to_out_toom_44,
+    limbs_mul_greater_to_out_toom_44_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_44_scratch_len, limbs_mul_greater_to_out_toom_6h,
+    limbs_mul_greater_to_out_toom_6h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_6h_scratch_len, limbs_mul_greater_to_out_toom_8h,
+    limbs_mul_greater_to_out_toom_8h_input_sizes_valid,
+    limbs_mul_greater_to_out_toom_8h_scratch_len,
+};
+use malachite_nz::platform::Limb;
+#[cfg(not(feature = "parallel"))]
+use malachite_nz::platform::{MUL_FFT_PARALLEL_THRESHOLD, MUL_TOOM_PARALLEL_THRESHOLD};
+use std::cmp::max;
+#[cfg(feature = "parallel")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+// A crossover is only accepted once the faster algorithm has won at this many consecutive sizes.
+// This filters out most of the noise in the measurements.
+const CONFIRMATIONS: usize = 3;
+
+// Each algorithm is run at least 3 times, and for at least this long; the fastest run is used.
+const MIN_MEASUREMENT_TIME: Duration = Duration::from_millis(20);
+
+fn time<F: FnMut()>(mut f: F) -> f64 {
+    let start = Instant::now();
+    let mut best = f64::INFINITY;
+    let mut runs = 0;
+    while runs < 3 || start.elapsed() < MIN_MEASUREMENT_TIME {
+        let run_start = Instant::now();
+        f();
+        best = best.min(run_start.elapsed().as_secs_f64());
+        runs += 1;
+    }
+    best
+}
+
+fn random_limbs(len: usize, key: &str) -> Vec<Limb> {
+    random_primitive_ints(EXAMPLE_SEED.fork(key))
+        .take(len)
+        .collect()
+}
+
+// Finds the smallest size `n` in `min..max` at which `fast(n)` takes less time than `slow(n)`, and
+// keeps doing so for `CONFIRMATIONS` consecutive sizes. The sizes grow by about 5% at each step. A
+// time of `None` means that an algorithm can't handle size `n`, and the size is skipped. If no
+// crossover is found, `max` is returned.
+//
+// This is similar to `one` from `tune/tuneup.c`, GMP 6.2.1, though GMP varies the threshold of the
+// full multiplication or division routine, while here the two algorithms are timed directly.
+fn find_crossover<S: FnMut(usize) -> Option<f64>, F: FnMut(usize) -> Option<f64>>(
+    name: &str,
+    min: usize,
+    max_n: usize,
+    mut slow: S,
+    mut fast: F,
+) -> usize {
+    let mut crossover = min;
+    let mut wins = 0;
+    let mut n = min;
+    while n < max_n {
+        if let (Some(slow_time), Some(fast_time)) = (slow(n), fast(n)) {
+            eprintln!("{name}: n = {n}, {slow_time:.3e}s vs. {fast_time:.3e}s");
+            if fast_time < slow_time {
+                if wins == 0 {
+                    crossover = n;
+                }
+                wins += 1;
+                if wins == CONFIRMATIONS {
+                    return crossover;
+                }
+            } else {
+                wins = 0;
+            }
+        }
+        n = max(n + 1, n + n / 20);
+    }
+    max_n
+}
+
+type MulFn = fn(&mut [Limb], &[Limb], &[Limb], &mut [Limb]);
+
+// A multiplication algorithm, together with its scratch length and valid input sizes.
+#[derive(Clone, Copy)]
+struct MulAlgorithm {
+    mul: MulFn,
+    scratch_len: fn(usize, usize) -> usize,
+    input_sizes_valid: fn(usize, usize) -> bool,
+}
+
+const BASECASE: MulAlgorithm = MulAlgorithm {
+    mul: |out, xs, ys, _| limbs_mul_greater_to_out_basecase(out, xs, ys),
+    scratch_len: |_, _| 0,
+    input_sizes_valid: |_, _| true,
+};
+
+const TOOM_22: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_toom_22,
+    scratch_len: limbs_mul_greater_to_out_toom_22_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_toom_22_input_sizes_valid,
+};
+
+const TOOM_33: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_toom_33,
+    scratch_len: limbs_mul_greater_to_out_toom_33_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_toom_33_input_sizes_valid,
+};
+
+const TOOM_44: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_toom_44,
+    scratch_len: limbs_mul_greater_to_out_toom_44_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_toom_44_input_sizes_valid,
+};
+
+const TOOM_6H: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_toom_6h,
+    scratch_len: limbs_mul_greater_to_out_toom_6h_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_toom_6h_input_sizes_valid,
+};
+
+const TOOM_8H: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_toom_8h,
+    scratch_len: limbs_mul_greater_to_out_toom_8h_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_toom_8h_input_sizes_valid,
+};
+
+const FFT: MulAlgorithm = MulAlgorithm {
+    mul: limbs_mul_greater_to_out_fft,
+    scratch_len: limbs_mul_greater_to_out_fft_scratch_len,
+    input_sizes_valid: limbs_mul_greater_to_out_fft_is_valid,
+};
+
+const FFT_SMALL: MulAlgorithm = MulAlgorithm {
+    mul: |out, xs, ys, _| limbs_mul_greater_to_out_fft_small(out, xs, ys),
+    scratch_len: |_, _| 0,
+    input_sizes_valid: |_, _| true,
+};
+
+// Times the multiplication of two `n`-limb numbers.
+fn time_mul(algorithm: MulAlgorithm, n: usize) -> Option<f64> {
+    if !(algorithm.input_sizes_valid)(n, n) {
+        return None;
+    }
+    let xs = random_limbs(n, "xs");
+    let ys = random_limbs(n, "ys");
+    let mut out = vec![0; n << 1];
+    let mut scratch = vec![0; (algorithm.scratch_len)(n, n)];
+    Some(time(|| (algorithm.mul)(&mut out, &xs, &ys, &mut scratch)))
+}
+
+fn find_mul_crossover(
+    name: &str,
+    min: usize,
+    max_n: usize,
+    slow: MulAlgorithm,
+    fast: MulAlgorithm,
+) -> usize {
+    find_crossover(
+        name,
+        min,
+        max_n,
+        |n| time_mul(slow, n),
+        |n| time_mul(fast, n),
+    )
+}
+
+// Finds the smallest value `t` of a parallel threshold, in `min..max_n`, at which `algorithm` gets
+// faster when it may run in parallel. At each `t`, `algorithm` multiplies two `input_len(t)`-limb
+// numbers, with `threshold` set to either `t` or `usize::MAX`. `input_len(t)` should be chosen so
+// that only the outermost level of the algorithm reaches the threshold. Afterwards, `threshold` is
+// restored.
+#[cfg(feature = "parallel")]
+fn find_parallel_crossover(
+    name: &str,
+    min: usize,
+    max_n: usize,
+    algorithm: MulAlgorithm,
+    threshold: &AtomicUsize,
+    input_len: fn(usize) -> usize,
+) -> usize {
+    let original = threshold.load(Ordering::Relaxed);
+    let crossover = find_crossover(
+        name,
+        min,
+        max_n,
+        |t| {
+            threshold.store(usize::MAX, Ordering::Relaxed);
+            time_mul(algorithm, input_len(t))
+        },
+        |t| {
+            threshold.store(t, Ordering::Relaxed);
+            time_mul(algorithm, input_len(t))
+        },
+    );
+    threshold.store(original, Ordering::Relaxed);
+    crossover
+}
+
+// Returns `MUL_TOOM_PARALLEL_THRESHOLD` and `MUL_FFT_PARALLEL_THRESHOLD`.
+#[cfg(feature = "parallel")]
+fn parallel_thresholds(fft: usize) -> (usize, usize) {
+    // When two `6 * (m - 1)`-limb numbers are multiplied with Toom-6.5, the products at the
+    // evaluation points have `m`-limb factors; the recursive products inside them are much smaller.
+    let toom = find_parallel_crossover(
+        "MUL_TOOM_PARALLEL_THRESHOLD",
+        50,
+        5000,
+        TOOM_6H,
+        &MUL_TOOM_PARALLEL_THRESHOLD_FOR_TUNING,
+        |m| 6 * (m - 1),
+    );
+    // This threshold is compared with the length of the product.
+    let fft = find_parallel_crossover(
+        "MUL_FFT_PARALLEL_THRESHOLD",
+        fft << 1,
+        200000,
+        FFT,
+        &MUL_FFT_PARALLEL_THRESHOLD_FOR_TUNING,
+        |len| len >> 1,
+    );
+    (toom, fft)
+}
+
+// Without the `parallel` feature, the parallel thresholds can't be measured, so the built-in ones
+// are kept.
+#[cfg(not(feature = "parallel"))]
+fn parallel_thresholds(_fft: usize) -> (usize, usize) {
+    eprintln!("Build with the `parallel` feature to tune the parallel thresholds");
+    (MUL_TOOM_PARALLEL_THRESHOLD, MUL_FFT_PARALLEL_THRESHOLD)
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum DivAlgorithm {
+    Schoolbook,
+    DivideAndConquer,
+    Barrett,
+}
+
+// Times the division of a `2 * n`-limb number by a normalized `n`-limb number.
+fn time_div_mod(algorithm: DivAlgorithm, n: usize) -> Option<f64> {
+    if n < 6 {
+        return None;
+    }
+    let ns = random_limbs(n << 1, "ns");
+    let mut ds = random_limbs(n, "ds");
+    ds[n - 1] |= 1 << (Limb::WIDTH - 1);
+    let d_inv = limbs_two_limb_inverse_helper(ds[n - 1], ds[n - 2]);
+    let mut qs = vec![0; n];
+    let mut rs = vec![0; n];
+    let mut ns_copy = ns.clone();
+    Some(match algorithm {
+        DivAlgorithm::Schoolbook => time(|| {
+            ns_copy.copy_from_slice(&ns);
+            limbs_div_mod_schoolbook(&mut qs, &mut ns_copy, &ds, d_inv);
+        }),
+        DivAlgorithm::DivideAndConquer => time(|| {
+            ns_copy.copy_from_slice(&ns);
+            limbs_div_mod_divide_and_conquer(&mut qs, &mut ns_copy, &ds, d_inv);
+        }),
+        DivAlgorithm::Barrett => {
+            let mut scratch = vec![0; limbs_div_mod_barrett_scratch_len(n << 1, n)];
+            time(|| {
+                ns_copy.copy_from_slice(&ns);
+                limbs_div_mod_barrett(&mut qs, &mut rs, &ns_copy, &ds, &mut scratch);
+            })
+        }
+    })
+}
+
+// Measures the multiplication and division thresholds on the current machine, and prints them as
+// a Rust source file, which may be passed to a build with the `tuned_thresholds` feature enabled.
+// The parallel thresholds are only measured if the `parallel` feature is enabled.
+// Progress is reported on stderr. Each search starts where the previous one ended, so that the
+// thresholds stay in increasing order. Algorithms that recurse use the compiled-in thresholds for
+// their recursive calls.
+//
+// This is similar to `tune/tuneup.c`, GMP 6.2.1, though only a subset of the thresholds is tuned.
+pub(crate) fn generate_tuned_thresholds() {
+    let toom_22 = find_mul_crossover("MUL_TOOM22_THRESHOLD", 4, 400, BASECASE, TOOM_22);
+    let toom_33 = find_mul_crossover("MUL_TOOM33_THRESHOLD", toom_22, 1000, TOOM_22, TOOM_33);
+    let toom_44 = find_mul_crossover("MUL_TOOM44_THRESHOLD", toom_33, 2000, TOOM_33, TOOM_44);
+    let toom_6h = find_mul_crossover("MUL_TOOM6H_THRESHOLD", toom_44, 3000, TOOM_44, TOOM_6H);
+    let toom_8h = find_mul_crossover("MUL_TOOM8H_THRESHOLD", toom_6h, 4000, TOOM_6H, TOOM_8H);
+    let fft = find_mul_crossover("MUL_FFT_THRESHOLD", toom_8h, 20000, TOOM_8H, FFT);
+    // This threshold is compared with the length of the product.
+    let fft_small = find_mul_crossover("MUL_FFT_SMALL_THRESHOLD", fft, 500000, FFT, FFT_SMALL) << 1;
+    let (toom_parallel, fft_parallel) = parallel_thresholds(fft);
+    let dc_div_qr = find_crossover(
+        "DC_DIV_QR_THRESHOLD",
+        6,
+        1000,
+        |n| time_div_mod(DivAlgorithm::Schoolbook, n),
+        |n| time_div_mod(DivAlgorithm::DivideAndConquer, n),
+    );
+    // `MU_DIV_QR_THRESHOLD` must be larger than `MUPI_DIV_QR_THRESHOLD`, which is 74.
+    let mu_div_qr = find_crossover(
+        "MU_DIV_QR_THRESHOLD",
+        max(dc_div_qr, 100),
+        20000,
+        |n| time_div_mod(DivAlgorithm::DivideAndConquer, n),
+        |n| time_div_mod(DivAlgorithm::Barrett, n),
+    );
+    println!(
+        "// This file was generated by `cargo run --release --features bin_build -- -g tune`."
+    );
+    println!("// To use it, build malachite-nz with the `tuned_thresholds` feature, and set the");
+    println!(
+        "// `MALACHITE_NZ_THRESHOLDS` environment variable to the absolute path of this file."
+    );
+    println!("const TUNED_LIMB_WIDTH: u32 = {};", Limb::WIDTH);
+    println!("pub const MUL_TOOM22_THRESHOLD: usize = {toom_22};");
+    println!("pub const MUL_TOOM33_THRESHOLD: usize = {toom_33};");
+    println!("pub const MUL_TOOM44_THRESHOLD: usize = {toom_44};");
+    println!("pub const MUL_TOOM6H_THRESHOLD: usize = {toom_6h};");
+    println!("pub const MUL_TOOM8H_THRESHOLD: usize = {toom_8h};");
+    println!("pub const MUL_TOOM_PARALLEL_THRESHOLD: usize = {toom_parallel};");
+    println!("pub const MUL_FFT_THRESHOLD: usize = {fft};");
+    println!("pub const MUL_FFT_PARALLEL_THRESHOLD: usize = {fft_parallel};");
+    println!("pub const MUL_FFT_SMALL_THRESHOLD: usize = {fft_small};");
+    println!("pub const DC_DIV_QR_THRESHOLD: usize = {dc_div_qr};");
+    println!("pub const MU_DIV_QR_THRESHOLD: usize = {mu_div_qr};");
+}
@@ -1,9 +1,9 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
+use core::iter::Sum;
+use core::mem::swap;
+use core::ops::{Add, AddAssign};
 use malachite_base::num::basic::traits::Zero;
-use std::iter::Sum;
-use std::mem::swap;
-use std::ops::{Add, AddAssign};
 
 impl Add<Integer> for Integer {
     type Output = Integer;
@@ -156,7 +156,7 @@ impl<'a, 'b> Add<&'a Integer> for &'b Integer {
     /// ```
     fn add(self, other: &'a Integer) -> Integer {
         match (self, other) {
-            (x, y) if std::ptr::eq(x, y) => x << 1,
+            (x, y) if core::ptr::eq(x, y) => x << 1,
             (&integer_zero!(), y) => y.clone(),
             (x, &integer_zero!()) => x.clone(),
             // e.g. 10 + 5 or -10 + -5; sign of result is sign of self
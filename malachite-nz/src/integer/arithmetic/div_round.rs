@@ -1,7 +1,7 @@
 use crate::integer::Integer;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::{DivRound, DivRoundAssign};
 use malachite_base::rounding_modes::RoundingMode;
-use std::cmp::Ordering;
 
 impl DivRound<Integer> for Integer {
     type Output = Integer;
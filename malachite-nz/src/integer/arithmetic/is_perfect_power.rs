@@ -0,0 +1,89 @@
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{
+    ExpressAsPower, IsPerfectPower, Parity, SquareAssign,
+};
+use malachite_base::num::basic::traits::NegativeOne;
+
+impl ExpressAsPower for Integer {
+    /// Expresses an [`Integer`] as $x^k$, where $k > 1$ is as large as possible, or returns `None`
+    /// if the [`Integer`] is not a perfect power.
+    ///
+    /// 0 and 1 can be raised to any power, so they are returned as $0^2$ and $1^2$. $-1$ can be
+    /// raised to any odd power, so it is returned as $(-1)^3$. Other negative [`Integer`]s can
+    /// only be odd powers, so the largest odd exponent is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ExpressAsPower, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-1).express_as_power().to_debug_string(), "Some((-1, 3))");
+    /// assert_eq!(Integer::from(64).express_as_power().to_debug_string(), "Some((2, 6))");
+    /// assert_eq!(Integer::from(-64).express_as_power().to_debug_string(), "Some((-4, 3))");
+    /// assert_eq!(Integer::from(-100).express_as_power().to_debug_string(), "None");
+    /// assert_eq!(
+    ///     Integer::from(-12).pow(99).express_as_power().to_debug_string(),
+    ///     "Some((-12, 99))"
+    /// );
+    /// ```
+    fn express_as_power(&self) -> Option<(Integer, u64)> {
+        if self.sign {
+            return self
+                .abs
+                .express_as_power()
+                .map(|(x, e)| (Integer::from(x), e));
+        }
+        if self.abs == 1u32 {
+            return Some((Integer::NEGATIVE_ONE, 3));
+        }
+        let (mut x, mut e) = self.abs.express_as_power()?;
+        while e.even() {
+            x.square_assign();
+            e >>= 1;
+        }
+        if e == 1 {
+            None
+        } else {
+            Some((-Integer::from(x), e))
+        }
+    }
+}
+
+impl IsPerfectPower for Integer {
+    /// Determines whether an [`Integer`] is a perfect power; that is, whether it can be written as
+    /// $x^k$ for some [`Integer`] $x$ and some $k > 1$.
+    ///
+    /// 0, 1, and $-1$ are perfect powers. Other negative [`Integer`]s are perfect powers if they are
+    /// odd powers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(-1).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(64).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-64).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-100).is_perfect_power(), false);
+    /// assert_eq!(Integer::from(-12).pow(99).is_perfect_power(), true);
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        self.express_as_power().is_some()
+    }
+}
@@ -50,6 +50,10 @@ pub mod eq_mod_power_of_2;
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
+/// Implementations of [`ExpressAsPower`](malachite_base::num::arithmetic::traits::ExpressAsPower)
+/// and [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower), traits for
+/// determining whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
 /// Implementations of [`LegendreSymbol`](malachite_base::num::arithmetic::traits::LegendreSymbol),
 /// [`JacobiSymbol`](malachite_base::num::arithmetic::traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
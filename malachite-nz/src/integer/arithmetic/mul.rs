@@ -1,7 +1,8 @@
 use crate::integer::Integer;
+use alloc::vec::Vec;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
 use malachite_base::num::basic::traits::{One, Zero};
-use std::iter::Product;
-use std::ops::{Mul, MulAssign};
 
 impl Mul<Integer> for Integer {
     type Output = Integer;
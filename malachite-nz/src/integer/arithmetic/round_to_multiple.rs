@@ -1,7 +1,7 @@
 use crate::integer::Integer;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::{RoundToMultiple, RoundToMultipleAssign};
 use malachite_base::rounding_modes::RoundingMode;
-use std::cmp::Ordering;
 
 impl RoundToMultiple<Integer> for Integer {
     type Output = Integer;
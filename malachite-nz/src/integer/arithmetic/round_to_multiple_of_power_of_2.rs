@@ -1,9 +1,9 @@
 use crate::integer::Integer;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::{
     RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
 };
 use malachite_base::rounding_modes::RoundingMode;
-use std::cmp::Ordering;
 
 impl RoundToMultipleOfPowerOf2<u64> for Integer {
     type Output = Integer;
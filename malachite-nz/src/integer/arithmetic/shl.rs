@@ -1,8 +1,8 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
+use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
 use malachite_base::num::arithmetic::traits::UnsignedAbs;
 use malachite_base::num::basic::traits::Zero;
-use std::ops::{Shl, ShlAssign, Shr, ShrAssign};
 
 fn shl_unsigned<T>(x: Integer, bits: T) -> Integer
 where
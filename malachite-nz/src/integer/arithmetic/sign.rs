@@ -1,6 +1,6 @@
 use crate::integer::Integer;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::Sign;
-use std::cmp::Ordering;
 
 impl Sign for Integer {
     /// Compares an [`Integer`] to zero.
@@ -0,0 +1,147 @@
+use crate::integer::Integer;
+use crate::natural::conversion::from_bytes::bytes_asc_to_limbs;
+use crate::natural::Natural;
+
+impl Integer {
+    /// Converts a slice of bytes in little-endian order to an [`Integer`], so that
+    /// less-significant bytes have lower indices in the input slice.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the last byte indicates
+    /// the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. An empty slice represents 0. The interpretation of the input does not depend on
+    /// the width of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128, 0]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_le(&[128, 255, 255]), -128);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_le(&[0, 16, 165, 212, 232, 0]),
+    ///     1000000000000i64
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_le(&[0, 240, 90, 43, 23, 255]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_twos_complement_bytes_le(xs: &[u8]) -> Integer {
+        let pad = match xs.last() {
+            Some(&last) if last >= 0x80 => u8::MAX,
+            _ => 0,
+        };
+        Integer::from_owned_twos_complement_limbs_asc(bytes_asc_to_limbs(xs, pad))
+    }
+
+    /// Converts a slice of bytes in big-endian order to an [`Integer`], so that less-significant
+    /// bytes have higher indices in the input slice.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the first byte
+    /// indicates the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is
+    /// one it is negative. An empty slice represents 0. The interpretation of the input does not
+    /// depend on the width of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[]), 0);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[123]), 123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[133]), -123);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[0, 128]), 128);
+    /// assert_eq!(Integer::from_twos_complement_bytes_be(&[255, 255, 128]), -128);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_be(&[0, 232, 212, 165, 16, 0]),
+    ///     1000000000000i64
+    /// );
+    /// assert_eq!(
+    ///     Integer::from_twos_complement_bytes_be(&[255, 23, 43, 90, 240, 0]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_twos_complement_bytes_be(xs: &[u8]) -> Integer {
+        let mut bytes = xs.to_vec();
+        bytes.reverse();
+        Integer::from_twos_complement_bytes_le(&bytes)
+    }
+
+    /// Converts a sign and a slice of bytes in little-endian order to an [`Integer`]. The bytes
+    /// represent the absolute value, with less-significant bytes at lower indices, and the sign is
+    /// `true` for a non-negative result and `false` for a negative one.
+    ///
+    /// If the bytes represent 0, the sign is ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_le(true, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(true, &[123]), 123);
+    /// assert_eq!(Integer::from_sign_and_bytes_le(false, &[128, 0]), -128);
+    /// assert_eq!(
+    ///     Integer::from_sign_and_bytes_le(false, &[0, 16, 165, 212, 232]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_sign_and_bytes_le(sign: bool, xs: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_le(xs))
+    }
+
+    /// Converts a sign and a slice of bytes in big-endian order to an [`Integer`]. The bytes
+    /// represent the absolute value, with less-significant bytes at higher indices, and the sign
+    /// is `true` for a non-negative result and `false` for a negative one.
+    ///
+    /// If the bytes represent 0, the sign is ignored.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from_sign_and_bytes_be(true, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[]), 0);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(true, &[123]), 123);
+    /// assert_eq!(Integer::from_sign_and_bytes_be(false, &[0, 128]), -128);
+    /// assert_eq!(
+    ///     Integer::from_sign_and_bytes_be(false, &[232, 212, 165, 16, 0]),
+    ///     -1000000000000i64
+    /// );
+    /// ```
+    pub fn from_sign_and_bytes_be(sign: bool, xs: &[u8]) -> Integer {
+        Integer::from_sign_and_abs(sign, Natural::from_bytes_be(xs))
+    }
+}
@@ -1,6 +1,9 @@
 /// An implementation of the [`From`] trait for converting a [`bool`] to an
 /// [`Integer`](crate::integer::Integer).
 pub mod from_bool;
+/// Functions for constructing an [`Integer`](crate::integer::Integer) from bytes, either in two's
+/// complement or together with a sign.
+pub mod from_bytes;
 /// Functions for converting a [`Natural`](crate::natural::Natural) to an
 /// [`Integer`](crate::integer::Integer), and an implementation of the [`From`] trait.
 pub mod from_natural;
@@ -359,11 +362,15 @@ pub mod primitive_float_from_integer;
 /// ```
 pub mod primitive_int_from_integer;
 /// Implementations of traits for serialization and deserialization using
-/// [serde](https://serde.rs/).
+/// [serde](https://serde.rs/), and a compact binary representation for use with serde's `with`
+/// attribute.
 pub mod serde;
 /// Implementations of traits for converting [`Integer`](crate::integer::Integer)s to and from
 /// [`String`]s.
 pub mod string;
+/// Functions for extracting bytes from an [`Integer`](crate::integer::Integer), either in two's
+/// complement or together with a sign.
+pub mod to_bytes;
 /// Functions for extracting two's complement [`Limb`](crate#limbs)s from an
 /// [`Integer`](crate::integer::Integer).
 pub mod to_twos_complement_limbs;
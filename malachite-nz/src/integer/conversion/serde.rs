@@ -1,7 +1,8 @@
 use crate::integer::{Integer, SerdeInteger};
 use crate::natural::Natural;
+use alloc::string::String;
+use core::convert::TryFrom;
 use malachite_base::num::conversion::traits::FromStringBase;
-use std::convert::TryFrom;
 
 impl From<Integer> for SerdeInteger {
     #[inline]
@@ -41,3 +42,58 @@ impl TryFrom<SerdeInteger> for Integer {
         }
     }
 }
+
+/// A compact binary representation of [`Integer`]s, for use with serde's `with` attribute.
+///
+/// By default, an [`Integer`] is serialized as a hexadecimal string. With this module, it is
+/// serialized as its two's complement bytes in little-endian order instead (see
+/// [`Integer::to_twos_complement_bytes_le`]), which keeps the output of binary formats like
+/// bincode or CBOR small and avoids a base conversion.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Point {
+///     #[serde(with = "malachite_nz::integer::conversion::serde::compact")]
+///     x: Integer,
+///     #[serde(with = "malachite_nz::integer::conversion::serde::compact")]
+///     y: Integer,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod compact {
+    use crate::integer::Integer;
+    use crate::natural::conversion::serde::BytesVisitor;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes an [`Integer`] as its two's complement bytes in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    #[inline]
+    pub fn serialize<S: Serializer>(x: &Integer, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&x.to_twos_complement_bytes_le())
+    }
+
+    /// Deserializes an [`Integer`] from its two's complement bytes in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Integer, D::Error> {
+        Ok(Integer::from_twos_complement_bytes_le(
+            &deserializer.deserialize_bytes(BytesVisitor)?,
+        ))
+    }
+}
@@ -1,8 +1,8 @@
 use crate::integer::Integer;
 use crate::natural::Natural;
+use core::ops::Neg;
+use core::str::FromStr;
 use malachite_base::num::conversion::traits::FromStringBase;
-use std::ops::Neg;
-use std::str::FromStr;
 
 impl FromStr for Integer {
     type Err = ();
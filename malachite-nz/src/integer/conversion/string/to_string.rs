@@ -1,11 +1,12 @@
 use crate::integer::Integer;
 use crate::natural::conversion::string::to_string::BaseFmtWrapper;
+use alloc::string::{String, ToString};
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex, Write};
 use malachite_base::num::conversion::string::to_string::{
     digit_to_display_byte_lower, digit_to_display_byte_upper,
 };
 use malachite_base::num::conversion::traits::{Digits, ToStringBase};
 use malachite_base::vecs::vec_pad_left;
-use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex, Write};
 
 impl<'a> Display for BaseFmtWrapper<&'a Integer> {
     /// Writes a wrapped [`Integer`] to a string using a specified base.
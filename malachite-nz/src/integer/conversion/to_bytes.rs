@@ -0,0 +1,169 @@
+use crate::integer::Integer;
+use crate::natural::conversion::to_bytes::limbs_to_bytes_asc;
+use alloc::vec::Vec;
+
+impl Integer {
+    /// Returns the bytes of an [`Integer`] in little-endian order, so that less-significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the last byte indicates
+    /// the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is one it is
+    /// negative. There are no trailing zero bytes if the [`Integer`] is non-negative or trailing
+    /// `u8::MAX` bytes if it is negative, except as necessary to include the correct sign bit.
+    /// Zero is a special case: it contains no bytes. The output does not depend on the width of a
+    /// [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_le().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_le(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_le(), &[133]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_le(), &[128, 0]);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_le(), &[128]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Integer::from(10u32).pow(12).to_twos_complement_bytes_le(),
+    ///     &[0, 16, 165, 212, 232, 0]
+    /// );
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_twos_complement_bytes_le(),
+    ///     &[0, 240, 90, 43, 23, 255]
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_le(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = self
+            .to_twos_complement_limbs_asc()
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        // The limbs are minimal, but there may be redundant sign-extension bytes in the last limb.
+        while let [.., penultimate, last] = *bytes.as_slice() {
+            if (last == 0 && penultimate < 0x80) || (last == u8::MAX && penultimate >= 0x80) {
+                bytes.pop();
+            } else {
+                break;
+            }
+        }
+        bytes
+    }
+
+    /// Returns the bytes of an [`Integer`] in big-endian order, so that less-significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// The bytes are in two's complement, and the most significant bit of the first byte
+    /// indicates the sign; if the bit is zero, the [`Integer`] is non-negative, and if the bit is
+    /// one it is negative. There are no leading zero bytes if the [`Integer`] is non-negative or
+    /// leading `u8::MAX` bytes if it is negative, except as necessary to include the correct sign
+    /// bit. Zero is a special case: it contains no bytes. The output does not depend on the width
+    /// of a [`Limb`](crate#limbs).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert!(Integer::ZERO.to_twos_complement_bytes_be().is_empty());
+    /// assert_eq!(Integer::from(123).to_twos_complement_bytes_be(), &[123]);
+    /// assert_eq!(Integer::from(-123).to_twos_complement_bytes_be(), &[133]);
+    /// assert_eq!(Integer::from(128).to_twos_complement_bytes_be(), &[0, 128]);
+    /// assert_eq!(Integer::from(-128).to_twos_complement_bytes_be(), &[128]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(
+    ///     Integer::from(10u32).pow(12).to_twos_complement_bytes_be(),
+    ///     &[0, 232, 212, 165, 16, 0]
+    /// );
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_twos_complement_bytes_be(),
+    ///     &[255, 23, 43, 90, 240, 0]
+    /// );
+    /// ```
+    pub fn to_twos_complement_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_twos_complement_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Returns the sign of an [`Integer`], along with the bytes of its absolute value in
+    /// little-endian order, so that less-significant bytes have lower indices in the output
+    /// vector.
+    ///
+    /// The sign is `true` if the [`Integer`] is non-negative and `false` if it is negative. There
+    /// are no trailing zero bytes; in particular, the absolute value of 0 is represented by an
+    /// empty vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_le(), (true, vec![]));
+    /// assert_eq!(Integer::from(123).to_sign_and_bytes_le(), (true, vec![123]));
+    /// assert_eq!(Integer::from(-128).to_sign_and_bytes_le(), (false, vec![128]));
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_sign_and_bytes_le(),
+    ///     (false, vec![0, 16, 165, 212, 232])
+    /// );
+    /// ```
+    pub fn to_sign_and_bytes_le(&self) -> (bool, Vec<u8>) {
+        (self.sign, limbs_to_bytes_asc(&self.abs.to_limbs_asc()))
+    }
+
+    /// Returns the sign of an [`Integer`], along with the bytes of its absolute value in
+    /// big-endian order, so that less-significant bytes have higher indices in the output vector.
+    ///
+    /// The sign is `true` if the [`Integer`] is non-negative and `false` if it is negative. There
+    /// are no leading zero bytes; in particular, the absolute value of 0 is represented by an
+    /// empty vector.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.to_sign_and_bytes_be(), (true, vec![]));
+    /// assert_eq!(Integer::from(123).to_sign_and_bytes_be(), (true, vec![123]));
+    /// assert_eq!(Integer::from(-128).to_sign_and_bytes_be(), (false, vec![128]));
+    /// assert_eq!(
+    ///     (-Integer::from(10u32).pow(12)).to_sign_and_bytes_be(),
+    ///     (false, vec![232, 212, 165, 16, 0])
+    /// );
+    /// ```
+    pub fn to_sign_and_bytes_be(&self) -> (bool, Vec<u8>) {
+        (self.sign, self.abs.to_bytes_be())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::integer::Integer;
+use malachite_base::num::factorization::traits::IsPrime;
+
+impl Integer {
+    /// Determines whether an [`Integer`] is prime, optionally running extra rounds of
+    /// Miller-Rabin.
+    ///
+    /// Negative [`Integer`]s are never considered prime. For non-negative [`Integer`]s, this is
+    /// the same as [`Natural::is_prime_with_extra_rounds`](crate::natural::Natural).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::from(97).is_prime_with_extra_rounds(5), true);
+    /// assert_eq!(Integer::from(-97).is_prime_with_extra_rounds(5), false);
+    /// assert_eq!(
+    ///     (Integer::power_of_2(127) - Integer::ONE).is_prime_with_extra_rounds(5),
+    ///     true
+    /// );
+    /// ```
+    #[inline]
+    pub fn is_prime_with_extra_rounds(&self, rounds: u64) -> bool {
+        self.sign && self.abs.is_prime_with_extra_rounds(rounds)
+    }
+}
+
+impl IsPrime for Integer {
+    /// Determines whether an [`Integer`] is prime.
+    ///
+    /// Negative [`Integer`]s are never considered prime. For non-negative [`Integer`]s, this is
+    /// the same as [`Natural::is_prime`](crate::natural::Natural).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        self.sign && self.abs.is_prime()
+    }
+}
@@ -0,0 +1,19 @@
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for determining whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(Integer::from(0).is_prime(), false);
+/// assert_eq!(Integer::from(1).is_prime(), false);
+/// assert_eq!(Integer::from(2).is_prime(), true);
+/// assert_eq!(Integer::from(-2).is_prime(), false);
+/// assert_eq!(Integer::from(97).is_prime(), true);
+/// assert_eq!(Integer::from(-97).is_prime(), false);
+/// assert_eq!((Integer::power_of_2(127) - Integer::ONE).is_prime(), true);
+/// ```
+pub mod is_prime;
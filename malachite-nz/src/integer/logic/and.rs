@@ -3,12 +3,13 @@ use crate::natural::arithmetic::add::{limbs_add_limb_to_out, limbs_slice_add_lim
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::{max, Ordering};
+use core::ops::{BitAnd, BitAndAssign};
 use malachite_base::num::arithmetic::traits::WrappingNegAssign;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::logic::traits::NotAssign;
 use malachite_base::slices::{slice_leading_zeros, slice_set_zero};
-use std::cmp::{max, Ordering};
-use std::ops::{BitAnd, BitAndAssign};
 
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of an `Integer`, returns the
 // limbs of the bitwise and of the `Integer` and a negative number whose lowest limb is given by
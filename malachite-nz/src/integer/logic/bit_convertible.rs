@@ -3,6 +3,7 @@ use crate::integer::Integer;
 use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
 use crate::natural::Natural;
 use crate::platform::{Limb, SignedLimb};
+use alloc::vec::Vec;
 use itertools::Itertools;
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::Zero;
@@ -1,8 +1,8 @@
 use crate::integer::Integer;
 use crate::natural::logic::bit_iterable::NaturalBitIterator;
 use crate::natural::Natural;
+use core::ops::Index;
 use malachite_base::num::logic::traits::{BitAccess, BitIterable};
-use std::ops::Index;
 
 /// A double-ended iterator over the two's complement bits of the negative of an [`Integer`].
 ///
@@ -5,11 +5,11 @@ use crate::natural::logic::hamming_distance::limbs_hamming_distance_same_length;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
+use core::cmp::Ordering;
 use malachite_base::num::logic::traits::{
     CheckedHammingDistance, CountOnes, CountZeros, HammingDistance,
 };
 use malachite_base::slices::slice_leading_zeros;
-use std::cmp::Ordering;
 
 // Interpreting a slice of `Limb`s as the limbs of a `Natural` in ascending order, returns the
 // Hamming distance between the negative of that `Natural` (two's complement) and the negative of a
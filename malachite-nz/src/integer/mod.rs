@@ -1,4 +1,5 @@
 use crate::natural::Natural;
+use alloc::string::String;
 use malachite_base::named::Named;
 use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
 
@@ -108,6 +109,8 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Integer`]s without repetition.
 pub mod exhaustive;
+/// Traits for primality testing.
+pub mod factorization;
 /// Traits for logic and bit manipulation.
 pub mod logic;
 /// Iterators that generate [`Integer`]s randomly.
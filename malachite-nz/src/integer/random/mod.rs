@@ -5,6 +5,7 @@ use crate::natural::random::{
     StripedRandomNaturalInclusiveRange,
 };
 use crate::natural::Natural;
+use alloc::boxed::Box;
 use malachite_base::bools::random::{random_bools, RandomBools};
 use malachite_base::num::arithmetic::traits::{PowerOf2, UnsignedAbs};
 use malachite_base::num::basic::traits::{One, Zero};
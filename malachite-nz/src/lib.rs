@@ -96,6 +96,29 @@
 //! # Features
 //! - `32_bit_limbs`: Sets the type of [`Limb`](crate#limbs) to [`u32`] instead of the default,
 //!   [`u64`].
+//! - `parallel`: Spreads the work of multiplying and squaring very large numbers across a thread
+//!   pool, using [rayon](https://crates.io/crates/rayon). Division, square roots, and base
+//!   conversion of very large numbers depend on multiplication, so they benefit too. The products
+//!   at the evaluation points of the Toom-6.5 and Toom-8.5 algorithms (and their squaring
+//!   counterparts) are computed in pairs once their factors have at least
+//!   `MUL_TOOM_PARALLEL_THRESHOLD` limbs, and the FFT spreads its transforms and pointwise products
+//!   across threads once the product has at least `MUL_FFT_PARALLEL_THRESHOLD` limbs. Smaller
+//!   numbers are multiplied on a single thread as usual. Both thresholds can be tuned with the
+//!   `tuned_thresholds` feature.
+//! - `tuned_thresholds`: Replaces the built-in thresholds that decide when to switch between
+//!   multiplication and division algorithms with ones measured on your own machine. To produce
+//!   them, run
+//!   ```text
+//!   cargo run --features bin_build --release -- -g tune > thresholds.rs
+//!   ```
+//!   and then build with this feature enabled and the `MALACHITE_NZ_THRESHOLDS` environment
+//!   variable set to the absolute path of `thresholds.rs`. A relative path is rejected, since the
+//!   build script would resolve it against this crate's directory rather than the one cargo was
+//!   run from. The tuning run takes several minutes. If the variable is not set, the built-in
+//!   thresholds are used and a warning is printed. The file records the limb width it was
+//!   measured with, and building it into a crate with a different limb width is a compile error.
+//!   The parallel thresholds are only measured if the tuning program is built with the `parallel`
+//!   feature as well.
 //! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
 //!   typical user, building this code would result in an unnecessarily long compilation time and
 //!   an unnecessarily large binary. Some of it is also used for testing `malachite-q`, so it can't
@@ -104,7 +127,12 @@
 //!   However, doctests don't require it, since they only test the public interface.
 //! - `bin_build`: This feature is used to build the code for demos and benchmarks, which also
 //!   takes a long time to build. Enabling this feature also enables `test_build`.
+//! - `std`: Enabled by default. Without it, this crate only depends on `core` and `alloc`, so
+//!   [`Natural`](natural::Natural)s and [`Integer`](integer::Integer)s can be used on targets that
+//!   have an allocator but no standard library. `parallel`, `test_build`, and `bin_build` require
+//!   it.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(
     unstable_name_collisions,
     clippy::assertions_on_constants,
@@ -142,7 +170,11 @@
     clippy::unused_self
 )]
 
+#[macro_use]
+extern crate alloc;
 extern crate itertools;
+#[cfg(not(feature = "std"))]
+extern crate libm;
 #[macro_use]
 extern crate malachite_base;
 #[cfg(feature = "serde")]
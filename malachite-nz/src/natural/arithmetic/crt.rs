@@ -0,0 +1,314 @@
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{ModInverse, ModMul, ModSub};
+use malachite_base::num::basic::traits::Zero;
+
+// Returns the inverse of `x` modulo `m`, or `None` if `x` and `m` are not coprime. Unlike
+// `ModInverse`, this works when `x` is not reduced modulo `m` and when `m` is 1.
+fn inverse_mod(x: &Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        return Some(Natural::ZERO);
+    }
+    let x = x % m;
+    if x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+/// A subproduct tree of a list of moduli.
+///
+/// The leaves of the tree are the moduli, and each internal node is the product of its two
+/// children. The tree can be used to reduce a [`Natural`] modulo every modulus at once (see
+/// [`SubproductTree::remainders`]) and, if the moduli are pairwise coprime, to combine residues
+/// modulo each modulus into a single residue modulo their product, using the Chinese remainder
+/// theorem (see [`SubproductTree::crt`]).
+///
+/// Building the tree once and reusing it is much faster than reducing or combining with each
+/// modulus separately when there are many moduli, as in multi-modular algorithms.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubproductTree {
+    // `levels[0]` contains the moduli, and each element of `levels[k + 1]` is the product of two
+    // adjacent elements of `levels[k]`. An unpaired last element is carried up unchanged. The last
+    // level contains a single element, the product of all the moduli.
+    levels: Vec<Vec<Natural>>,
+    // `inverses[k][j]` is the inverse of `levels[k][2j]` modulo `levels[k][2j + 1]`. This is `None`
+    // if the moduli are not pairwise coprime.
+    inverses: Option<Vec<Vec<Natural>>>,
+}
+
+impl SubproductTree {
+    /// Builds a subproduct tree from a list of moduli.
+    ///
+    /// If the moduli are pairwise coprime, the data needed to apply the Chinese remainder theorem
+    /// is precomputed as well.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the moduli's numbers of
+    /// significant bits.
+    ///
+    /// # Panics
+    /// Panics if `moduli` is empty or if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ]);
+    /// assert_eq!(*tree.product(), 105);
+    /// assert_eq!(tree.moduli_are_pairwise_coprime(), true);
+    ///
+    /// let tree = SubproductTree::new(&[Natural::from(4u32), Natural::from(6u32)]);
+    /// assert_eq!(*tree.product(), 24);
+    /// assert_eq!(tree.moduli_are_pairwise_coprime(), false);
+    /// ```
+    pub fn new(moduli: &[Natural]) -> SubproductTree {
+        assert!(!moduli.is_empty(), "There must be at least one modulus");
+        assert!(
+            moduli.iter().all(|m| *m != 0u32),
+            "Cannot use a modulus of zero"
+        );
+        let mut levels = vec![moduli.to_vec()];
+        let mut inverses = Some(Vec::new());
+        while levels.last().unwrap().len() > 1 {
+            let level = levels.last().unwrap();
+            let mut products = Vec::with_capacity((level.len() + 1) >> 1);
+            let mut level_inverses = Vec::with_capacity(level.len() >> 1);
+            for pair in level.chunks(2) {
+                if let [x, y] = pair {
+                    products.push(x * y);
+                    if inverses.is_some() {
+                        if let Some(inverse) = inverse_mod(x, y) {
+                            level_inverses.push(inverse);
+                        } else {
+                            inverses = None;
+                        }
+                    }
+                } else {
+                    products.push(pair[0].clone());
+                }
+            }
+            if let Some(inverses) = inverses.as_mut() {
+                inverses.push(level_inverses);
+            }
+            levels.push(products);
+        }
+        SubproductTree { levels, inverses }
+    }
+
+    /// Returns the moduli that the tree was built from.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[Natural::from(3u32), Natural::from(5u32)]);
+    /// assert_eq!(tree.moduli().to_debug_string(), "[3, 5]");
+    /// ```
+    #[inline]
+    pub fn moduli(&self) -> &[Natural] {
+        &self.levels[0]
+    }
+
+    /// Returns the product of the moduli.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[Natural::from(3u32), Natural::from(5u32)]);
+    /// assert_eq!(*tree.product(), 15);
+    /// ```
+    #[inline]
+    pub fn product(&self) -> &Natural {
+        &self.levels.last().unwrap()[0]
+    }
+
+    /// Determines whether the moduli are pairwise coprime; that is, whether
+    /// [`SubproductTree::crt`] can be used.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[Natural::from(3u32), Natural::from(5u32)]);
+    /// assert_eq!(tree.moduli_are_pairwise_coprime(), true);
+    ///
+    /// let tree = SubproductTree::new(&[Natural::from(3u32), Natural::from(6u32)]);
+    /// assert_eq!(tree.moduli_are_pairwise_coprime(), false);
+    /// ```
+    #[inline]
+    pub const fn moduli_are_pairwise_coprime(&self) -> bool {
+        self.inverses.is_some()
+    }
+
+    /// Reduces a [`Natural`] modulo each of the moduli.
+    ///
+    /// The [`Natural`] is first reduced modulo the product of the moduli, and the result is then
+    /// reduced down the tree, so that each reduction is by a number roughly half the size of the
+    /// previous one.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the maximum of `x.significant_bits()`
+    /// and the sum of the moduli's numbers of significant bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ]);
+    /// assert_eq!(tree.remainders(&Natural::from(100u32)).to_debug_string(), "[1, 0, 2]");
+    /// ```
+    pub fn remainders(&self, x: &Natural) -> Vec<Natural> {
+        let mut remainders = vec![x % self.product()];
+        for level in self.levels.iter().rev().skip(1) {
+            remainders = level
+                .iter()
+                .enumerate()
+                .map(|(i, m)| &remainders[i >> 1] % m)
+                .collect();
+        }
+        remainders
+    }
+
+    /// Given residues modulo each of the moduli, uses the Chinese remainder theorem to find the
+    /// unique residue modulo the product of the moduli that is congruent to each of them.
+    ///
+    /// Residues are combined pairwise going up the tree, so that most of the work is done on
+    /// numbers that are much smaller than the product.
+    ///
+    /// $f((r_i)_{i=0}^{k-1}) = x$, where $0 \leq x < \prod_{i=0}^{k-1} m_i$ and $x \equiv r_i \mod
+    /// m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the moduli's numbers of
+    /// significant bits.
+    ///
+    /// # Panics
+    /// Panics if the moduli are not pairwise coprime, if the number of residues is not equal to
+    /// the number of moduli, or if any residue is not less than its modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::crt::SubproductTree;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let tree = SubproductTree::new(&[
+    ///     Natural::from(3u32),
+    ///     Natural::from(5u32),
+    ///     Natural::from(7u32),
+    /// ]);
+    /// assert_eq!(
+    ///     tree.crt(&[Natural::from(1u32), Natural::from(0u32), Natural::from(2u32)]),
+    ///     100
+    /// );
+    /// ```
+    pub fn crt(&self, residues: &[Natural]) -> Natural {
+        let inverses = self
+            .inverses
+            .as_ref()
+            .expect("The moduli must be pairwise coprime");
+        let moduli = self.moduli();
+        assert_eq!(residues.len(), moduli.len());
+        assert!(
+            residues.iter().zip(moduli.iter()).all(|(r, m)| r < m),
+            "Each residue must be less than its modulus"
+        );
+        let mut xs = residues.to_vec();
+        for (level, level_inverses) in self.levels.iter().zip(inverses.iter()) {
+            let mut combined = Vec::with_capacity((xs.len() + 1) >> 1);
+            let mut xs_iter = xs.into_iter();
+            for (i, pair) in level.chunks(2).enumerate() {
+                let x = xs_iter.next().unwrap();
+                if let [m, n] = pair {
+                    // x + m * (((y - x) / m) mod n) is congruent to x mod m and to y mod n.
+                    let y = xs_iter.next().unwrap();
+                    let t = y.mod_sub(&x % n, n).mod_mul(&level_inverses[i], n);
+                    combined.push(x + m * t);
+                } else {
+                    combined.push(x);
+                }
+            }
+            xs = combined;
+        }
+        xs.pop().unwrap()
+    }
+}
+
+impl Natural {
+    /// Uses the Chinese remainder theorem to find the unique residue modulo the product of some
+    /// pairwise coprime moduli that is congruent to each of some given residues.
+    ///
+    /// To solve many systems of congruences with the same moduli, build a [`SubproductTree`] once
+    /// and use [`SubproductTree::crt`] instead.
+    ///
+    /// $f((r_i)_{i=0}^{k-1}, (m_i)_{i=0}^{k-1}) = x$, where $0 \leq x < \prod_{i=0}^{k-1} m_i$ and
+    /// $x \equiv r_i \mod m_i$ for all $i$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^3 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the moduli's numbers of
+    /// significant bits.
+    ///
+    /// # Panics
+    /// Panics if `moduli` is empty, if any modulus is zero, if the moduli are not pairwise coprime,
+    /// if the number of residues is not equal to the number of moduli, or if any residue is not
+    /// less than its modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::crt(
+    ///         &[Natural::from(2u32), Natural::from(3u32), Natural::from(2u32)],
+    ///         &[Natural::from(3u32), Natural::from(5u32), Natural::from(7u32)]
+    ///     ),
+    ///     23
+    /// );
+    /// ```
+    #[inline]
+    pub fn crt(residues: &[Natural], moduli: &[Natural]) -> Natural {
+        SubproductTree::new(moduli).crt(residues)
+    }
+}
@@ -29,6 +29,12 @@ use crate::natural::Natural;
 use crate::platform::{
     DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, FUDGE, MU_DIVAPPR_Q_THRESHOLD,
 };
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::once;
+use core::mem::swap;
+use core::ops::{Div, DivAssign};
 use malachite_base::fail_on_untested_path;
 #[cfg(feature = "test_build")]
 use malachite_base::num::arithmetic::traits::DivRem;
@@ -40,10 +46,6 @@ use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{ExactFrom, JoinHalves, SplitInHalf};
 use malachite_base::num::logic::traits::LeadingZeros;
 use malachite_base::slices::{slice_move_left, slice_set_zero};
-use std::cmp::Ordering;
-use std::iter::once;
-use std::mem::swap;
-use std::ops::{Div, DivAssign};
 
 // Divide an number by a divisor of B - 1, where B is the limb base.
 //
@@ -1372,8 +1374,8 @@ fn limbs_div_dc_condition(n_len: usize, d_len: usize) -> bool {
     let d_64 = d_len as f64;
     d_len < MUPI_DIV_Q_THRESHOLD
         || n_len < MU_DIV_Q_THRESHOLD << 1
-        || (((MU_DIV_Q_THRESHOLD - MUPI_DIV_Q_THRESHOLD) << 1) as f64)
-            .mul_add(d_64, MUPI_DIV_Q_THRESHOLD as f64 * n_64)
+        || (((MU_DIV_Q_THRESHOLD - MUPI_DIV_Q_THRESHOLD) << 1) as f64) * d_64
+            + MUPI_DIV_Q_THRESHOLD as f64 * n_64
             > d_64 * n_64
 }
 
@@ -32,6 +32,9 @@ use crate::platform::{
     DoubleLimb, Limb, DC_DIVAPPR_Q_THRESHOLD, DC_DIV_QR_THRESHOLD, INV_MULMOD_BNM1_THRESHOLD,
     INV_NEWTON_THRESHOLD, MAYBE_DCP1_DIVAPPR, MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
 };
+use alloc::vec::Vec;
+use core::cmp::{min, Ordering};
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{
     CeilingDivAssignNegMod, CeilingDivNegMod, DivAssignMod, DivAssignRem, DivMod, DivRem,
     WrappingAddAssign, WrappingSub, WrappingSubAssign, XMulYToZZ, XXDivModYToQR,
@@ -41,8 +44,6 @@ use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{JoinHalves, SplitInHalf};
 use malachite_base::num::logic::traits::LeadingZeros;
 use malachite_base::slices::{slice_move_left, slice_set_zero};
-use std::cmp::{min, Ordering};
-use std::mem::swap;
 
 // The highest bit of the input must be set.
 //
@@ -1524,8 +1525,8 @@ fn limbs_div_mod_dc_condition(n_len: usize, d_len: usize) -> bool {
     let d_64 = d_len as f64;
     d_len < MUPI_DIV_QR_THRESHOLD
         || n_len < MU_DIV_QR_THRESHOLD << 1
-        || (((MU_DIV_QR_THRESHOLD - MUPI_DIV_QR_THRESHOLD) << 1) as f64)
-            .mul_add(d_64, MUPI_DIV_QR_THRESHOLD as f64 * n_64)
+        || (((MU_DIV_QR_THRESHOLD - MUPI_DIV_QR_THRESHOLD) << 1) as f64) * d_64
+            + MUPI_DIV_QR_THRESHOLD as f64 * n_64
             > d_64 * n_64
 }
 
@@ -12,6 +12,7 @@ use crate::natural::comparison::cmp::limbs_cmp;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb, BMOD_1_TO_MOD_1_THRESHOLD};
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::{
     DivisibleBy, DivisibleByPowerOf2, EqMod, EqModPowerOf2, Parity, PowerOf2, WrappingAddAssign,
 };
@@ -20,7 +21,6 @@ use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::SplitInHalf;
 use malachite_base::num::logic::traits::TrailingZeros;
 use malachite_base::slices::slice_trailing_zeros;
-use std::cmp::Ordering;
 
 // See the description for `limbs_mod_exact_odd_limb`. divisor must be odd.
 //
@@ -0,0 +1,158 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedFibonacciPair, CheckedLucas, Fibonacci, FibonacciPair, Lucas, Parity,
+    Square,
+};
+use malachite_base::num::basic::traits::{One, Two};
+
+// Returns $(F_n, F_{n-1})$, using the doubling formulas $F_{2k} = F_k(F_k + 2F_{k-1})$ and
+// $F_{2k-1} = F_k^2 + F_{k-1}^2$.
+fn fibonacci_pair_natural(n: u64) -> (Natural, Natural) {
+    if let Some((f, f_prev)) = Limb::checked_fibonacci_pair(n) {
+        return (Natural::from(f), Natural::from(f_prev));
+    }
+    let (f, f_prev) = fibonacci_pair_natural(n >> 1);
+    let f_2k_prev = (&f).square() + (&f_prev).square();
+    let f_2k = ((f_prev << 1u32) + &f) * f;
+    if n.even() {
+        (f_2k, f_2k_prev)
+    } else {
+        (&f_2k + f_2k_prev, f_2k)
+    }
+}
+
+impl Fibonacci for Natural {
+    /// Computes the $n$th Fibonacci number.
+    ///
+    /// $$
+    /// f(n) = F_n,
+    /// $$
+    /// where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(3), 2);
+    /// assert_eq!(Natural::fibonacci(10), 55);
+    /// assert_eq!(Natural::fibonacci(100).to_string(), "354224848179261915075");
+    /// ```
+    fn fibonacci(n: u64) -> Natural {
+        if let Some(f) = Limb::checked_fibonacci(n) {
+            return Natural::from(f);
+        }
+        // F_{2k} = F_k(F_k + 2F_{k-1}) and F_{2k+1} = (2F_k + F_{k-1})(2F_k - F_{k-1}) + 2(-1)^k
+        let k = n >> 1;
+        let (f, f_prev) = fibonacci_pair_natural(k);
+        if n.even() {
+            ((f_prev << 1u32) + &f) * f
+        } else {
+            let f_double = f << 1u32;
+            let f = (&f_double + &f_prev) * (f_double - f_prev);
+            if k.even() {
+                f + Natural::TWO
+            } else {
+                f - Natural::TWO
+            }
+        }
+    }
+}
+
+impl FibonacciPair for Natural {
+    /// Computes the $n$th and $(n-1)$th Fibonacci numbers.
+    ///
+    /// $$
+    /// f(n) = (F_n, F_{n-1}),
+    /// $$
+    /// where $F_{-1} = 1$, $F_0 = 0$, and $F_n = F_{n-1} + F_{n-2}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::FibonacciPair;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci_pair(0).to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::fibonacci_pair(1).to_debug_string(), "(1, 0)");
+    /// assert_eq!(Natural::fibonacci_pair(10).to_debug_string(), "(55, 34)");
+    /// assert_eq!(
+    ///     Natural::fibonacci_pair(100).to_debug_string(),
+    ///     "(354224848179261915075, 218922995834555169026)"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci_pair(n: u64) -> (Natural, Natural) {
+        fibonacci_pair_natural(n)
+    }
+}
+
+impl Lucas for Natural {
+    /// Computes the $n$th Lucas number.
+    ///
+    /// $$
+    /// f(n) = L_n,
+    /// $$
+    /// where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt{5})/2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(3), 4);
+    /// assert_eq!(Natural::lucas(10), 123);
+    /// assert_eq!(Natural::lucas(100).to_string(), "792070839848372253127");
+    /// ```
+    fn lucas(n: u64) -> Natural {
+        if let Some(l) = Limb::checked_lucas(n) {
+            return Natural::from(l);
+        }
+        // L_{2k} = L_k^2 - 2(-1)^k and L_{2k+1} = L_k L_{k+1} - (-1)^k, where L_k = F_k + 2F_{k-1}
+        // and L_{k+1} = 3F_k + F_{k-1}
+        let k = n >> 1;
+        let (f, f_prev) = fibonacci_pair_natural(k);
+        let l = (&f_prev << 1u32) + &f;
+        if n.even() {
+            let l = l.square();
+            if k.even() {
+                l - Natural::TWO
+            } else {
+                l + Natural::TWO
+            }
+        } else {
+            let l = l * (f * Natural::from(3u32) + f_prev);
+            if k.even() {
+                l - Natural::ONE
+            } else {
+                l + Natural::ONE
+            }
+        }
+    }
+}
@@ -21,6 +21,8 @@ use crate::natural::comparison::cmp::limbs_cmp_same_length;
 use crate::natural::InnerNatural::Small;
 use crate::natural::Natural;
 use crate::platform::Limb;
+use core::cmp::{max, Ordering};
+use core::mem::swap;
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     DivExact, ExtendedGcd, NegAssign, OverflowingAddAssign,
@@ -29,8 +31,6 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::slices::{slice_set_zero, slice_test_zero, slice_trailing_zeros};
-use std::cmp::{max, Ordering};
-use std::mem::swap;
 
 // This is equivalent to `gcdext_ctx` from `gmp-impl.h`, GMP 6.2.1.
 struct ExtendedGcdContext<'a> {
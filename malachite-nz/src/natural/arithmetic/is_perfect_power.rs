@@ -0,0 +1,163 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, ExpressAsPower, IsPerfectPower, ModPow,
+};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The number of primes q for which `x` is checked to be a `p`th power residue mod q, before its
+// `p`th root is computed.
+const POWER_RESIDUE_TESTS: usize = 4;
+
+// Returns `false` if `x` is certainly not a `p`th power, where `p` is prime.
+//
+// If q is a prime with q ≡ 1 (mod p), then the nonzero `p`th powers mod q are exactly the residues
+// r with r^((q - 1) / p) ≡ 1 (mod q), and they make up only 1/p of the nonzero residues. Checking
+// this for a few such q rules out most `x` that are not `p`th powers, at the cost of a single pass
+// over `x`'s limbs for each q.
+fn might_be_power(x: &Natural, p: u64) -> bool {
+    let mut tests = 0;
+    let mut q = 1u64;
+    while tests < POWER_RESIDUE_TESTS {
+        q = match q.checked_add(p) {
+            Some(q) => q,
+            None => break,
+        };
+        if !Limb::convertible_from(q) {
+            break;
+        }
+        if !q.is_prime() {
+            continue;
+        }
+        let q_limb = Limb::wrapping_from(q);
+        let r = match *x {
+            Natural(Small(small)) => small % q_limb,
+            Natural(Large(ref xs)) => limbs_mod_limb(xs, q_limb),
+        };
+        if r != 0 && r.mod_pow((q - 1) / p, q_limb) != 1 {
+            return false;
+        }
+        tests += 1;
+    }
+    true
+}
+
+impl ExpressAsPower for Natural {
+    /// Expresses a [`Natural`] as $x^k$, where $k > 1$ is as large as possible, or returns `None`
+    /// if the [`Natural`] is not a perfect power.
+    ///
+    /// 0 and 1 can be raised to any power, so they are returned as $0^2$ and $1^2$.
+    ///
+    /// Prime exponents $p$ are tried in ascending order, using
+    /// [`CheckedRoot`](malachite_base::num::arithmetic::traits::CheckedRoot). Exponents greater
+    /// than or equal to the number's number of significant bits, and exponents that don't divide
+    /// the number of trailing zeros of an even number, are skipped. Before a root is computed, the
+    /// number is checked to be a $p$th power residue modulo a few small primes, which rules out
+    /// most candidate exponents cheaply.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ExpressAsPower, Pow};
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.express_as_power().to_debug_string(), "Some((0, 2))");
+    /// assert_eq!(Natural::from(10u32).express_as_power().to_debug_string(), "None");
+    /// assert_eq!(
+    ///     Natural::from(1000000u32).express_as_power().to_debug_string(),
+    ///     "Some((10, 6))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(12u32).pow(100).express_as_power().to_debug_string(),
+    ///     "Some((12, 100))"
+    /// );
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(100) + Natural::ONE)
+    ///         .express_as_power()
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    fn express_as_power(&self) -> Option<(Natural, u64)> {
+        if let Natural(Small(small)) = *self {
+            return small.express_as_power().map(|(x, e)| (Natural::from(x), e));
+        }
+        let mut x = self.clone();
+        let mut e = 1;
+        // If x = y^(pq) for primes p <= q, then x is a pth power whose pth root is a qth power. So
+        // trying the prime exponents in ascending order, and replacing x by its root whenever one
+        // is found, produces the largest exponent.
+        for p in u64::primes() {
+            // The `p`th power of a number greater than 1 has more than `p` significant bits.
+            if p >= x.significant_bits() {
+                break;
+            }
+            // An even number can only be a `p`th power if `p` divides its number of trailing
+            // zeros.
+            let zeros = x.trailing_zeros().unwrap();
+            if zeros != 0 && zeros % p != 0 {
+                continue;
+            }
+            while might_be_power(&x, p) {
+                if let Some(root) = (&x).checked_root(p) {
+                    x = root;
+                    e *= p;
+                } else {
+                    break;
+                }
+            }
+        }
+        if e == 1 {
+            None
+        } else {
+            Some((x, e))
+        }
+    }
+}
+
+impl IsPerfectPower for Natural {
+    /// Determines whether a [`Natural`] is a perfect power; that is, whether it can be written as
+    /// $x^k$ for some [`Natural`] $x$ and some $k > 1$.
+    ///
+    /// 0 and 1 are perfect powers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.is_perfect_power(), true);
+    /// assert_eq!(Natural::ONE.is_perfect_power(), true);
+    /// assert_eq!(Natural::from(10u32).is_perfect_power(), false);
+    /// assert_eq!(Natural::from(1000000u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(12u32).pow(100).is_perfect_power(), true);
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(100) + Natural::ONE).is_perfect_power(),
+    ///     false
+    /// );
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        self.express_as_power().is_some()
+    }
+}
@@ -18,6 +18,9 @@ pub mod checked_sub_mul;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
+/// Functions for solving systems of congruences using the Chinese remainder theorem, and
+/// [`SubproductTree`](crt::SubproductTree), a structure for working modulo many moduli at once.
+pub mod crt;
 /// Division of [`Natural`](super::Natural)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -63,10 +66,19 @@ pub mod eq_mod_power_of_2;
 /// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
 /// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
 pub mod factorial;
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci),
+/// [`FibonacciPair`](malachite_base::num::arithmetic::traits::FibonacciPair), and
+/// [`Lucas`](malachite_base::num::arithmetic::traits::Lucas), traits for computing Fibonacci and
+/// Lucas numbers.
+pub mod fibonacci;
 /// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
 /// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
 /// (greatest common divisor) of two numbers.
 pub mod gcd;
+/// Implementations of [`ExpressAsPower`](malachite_base::num::arithmetic::traits::ExpressAsPower)
+/// and [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower), traits for
+/// determining whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
 /// Implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a trait
 /// for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -111,6 +123,9 @@ pub mod mod_inverse;
 /// Implementations of [`ModIsReduced`](malachite_base::num::arithmetic::traits::ModIsReduced), a
 /// trait for checking whether a number is reduced modulo another number.
 pub mod mod_is_reduced;
+/// Implementations of [`ModLog`](malachite_base::num::arithmetic::traits::ModLog), a trait for
+/// finding the discrete logarithm of a number with respect to a base, modulo another number.
+pub mod mod_log;
 /// Implementations of traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](malachite_base::num::arithmetic::traits::ModMul),
@@ -478,6 +493,9 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt), a trait
+/// for finding square roots of a number modulo another number.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -490,6 +508,9 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// [`MontgomeryContext`](montgomery::MontgomeryContext), a structure for doing arithmetic modulo an
+/// odd [`Natural`](super::Natural) in Montgomery form.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
@@ -0,0 +1,380 @@
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivisibleBy, Gcd, ModInverse, ModLog, ModMul, ModPow, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+
+// Returns the smallest $d$ such that $g^d \equiv h \mod m$, where $g$ has order $n$, using the
+// baby-step giant-step algorithm. Returns `None` if there is no such $d$.
+fn baby_step_giant_step(g: &Natural, h: &Natural, n: &Natural, m: &Natural) -> Option<Natural> {
+    let s = n.ceiling_sqrt();
+    let mut baby_steps = BTreeMap::new();
+    let mut x = Natural::ONE;
+    let mut j = Natural::ZERO;
+    while j < s {
+        let x_next = (&x).mod_mul(g, m);
+        baby_steps.entry(x).or_insert_with(|| j.clone());
+        x = x_next;
+        j += Natural::ONE;
+    }
+    // x is now g^s.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h.clone();
+    let mut i = Natural::ZERO;
+    while i < s {
+        if let Some(j) = baby_steps.get(&y) {
+            return Some(i * s + j);
+        }
+        y = y.mod_mul(&giant_step, m);
+        i += Natural::ONE;
+    }
+    None
+}
+
+// Given the factorization of a multiple of the order of `g` mod `m`, returns the order and its
+// factorization.
+fn order_factorization(
+    g: &Natural,
+    m: &Natural,
+    factors: &[(Natural, u64)],
+) -> (Natural, Vec<(Natural, u64)>) {
+    let mut n: Natural = factors.iter().map(|(p, k)| p.pow(*k)).product();
+    let mut order_factors = Vec::new();
+    for (p, k) in factors {
+        n /= p.pow(*k);
+        let mut x = g.mod_pow(&n, m);
+        let mut e = 0;
+        while x != 1u32 {
+            x = x.mod_pow(p, m);
+            e += 1;
+        }
+        if e != 0 {
+            n *= p.pow(e);
+            order_factors.push((p.clone(), e));
+        }
+    }
+    (n, order_factors)
+}
+
+// Returns the smallest $x$ such that $g^x \equiv h \mod m$, where $g$ is coprime to $m$ and has
+// order $n$, with the given factorization. Returns `None` if there is no such $x$.
+fn pohlig_hellman(
+    g: &Natural,
+    h: &Natural,
+    m: &Natural,
+    n: &Natural,
+    order_factors: &[(Natural, u64)],
+) -> Option<Natural> {
+    let g_inverse = g.mod_inverse(m).unwrap();
+    let mut x = Natural::ZERO;
+    let mut modulus = Natural::ONE;
+    for (q, e) in order_factors {
+        let e = *e;
+        // Find x mod q^e by working in the subgroup of order q^e, one base-q digit at a time.
+        let q_e = q.pow(e);
+        let cofactor = n / &q_e;
+        let g_q_inverse = (&g_inverse).mod_pow(&cofactor, m);
+        let h_q = h.mod_pow(&cofactor, m);
+        // gamma has order q.
+        let gamma = g.mod_pow(&cofactor, m).mod_pow(q.pow(e - 1), m);
+        let mut x_q = Natural::ZERO;
+        let mut q_i = Natural::ONE;
+        for i in 0..e {
+            let t = (&g_q_inverse).mod_pow(&x_q, m).mod_mul(&h_q, m);
+            let d = baby_step_giant_step(&gamma, &t.mod_pow(q.pow(e - 1 - i), m), q, m)?;
+            x_q += d * &q_i;
+            q_i *= q;
+        }
+        // Combine with the previous residues using the Chinese remainder theorem.
+        if modulus == 1u32 {
+            x = x_q;
+        } else {
+            let t = x_q
+                .mod_sub(&x % &q_e, &q_e)
+                .mod_mul((&modulus % &q_e).mod_inverse(&q_e).unwrap(), &q_e);
+            x += &modulus * t;
+        }
+        modulus *= q_e;
+    }
+    Some(x)
+}
+
+// Returns the factorization of $\phi(m)$.
+fn totient_factorization(m: &Natural) -> Vec<(Natural, u64)> {
+    let mut factors = BTreeMap::new();
+    for (p, k) in m.factor() {
+        if k != 1 {
+            *factors.entry(p.clone()).or_insert(0) += k - 1;
+        }
+        for (q, j) in (p - Natural::ONE).factor() {
+            *factors.entry(q).or_insert(0) += j;
+        }
+    }
+    factors.into_iter().collect()
+}
+
+impl Natural {
+    /// Computes the discrete logarithm of a [`Natural`] with respect to a base $g$, modulo
+    /// another [`Natural`] $m$, given the factorization of a multiple of the multiplicative order
+    /// of $g$: the smallest $x$ such that $g^x \equiv y \mod m$. Returns `None` if there is no
+    /// such $x$.
+    ///
+    /// The base must be coprime to $m$. Any multiple of the order can be used; for example, the
+    /// factorization of $p - 1$ when $m = p$ is prime, or of $\phi(m)$ in general. This avoids the
+    /// factorizations performed by [`mod_log`](ModLog::mod_log).
+    ///
+    /// $$
+    /// f(y, g, m) = \\begin{cases}
+    ///     \operatorname{Some}(\min\\{x : g^x \equiv y \mod m\\})
+    ///         & \text{if} \\quad \exists x : g^x \equiv y \mod m, \\\\
+    ///     \operatorname{None} & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// The Pohlig-Hellman algorithm reduces the problem to subgroups of prime order, where the
+    /// baby-step giant-step algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2} n \log n \log\log n)$
+    ///
+    /// $M(n) = O(2^{n/2} n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` are greater than or equal to `m`, if `base` is not coprime to
+    /// `m`, or if the product of the prime powers is not a multiple of the order of `base`. The
+    /// primes are not checked for primality; if they are not prime, the result is unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // 2^61 - 1 is prime, and 2^61 - 2 = 2 * 3^2 * 5^2 * 7 * 11 * 13 * 31 * 41 * 61 * 151 * 331
+    /// // * 1321
+    /// let p = Natural::from(2u32).pow(61) - Natural::from(1u32);
+    /// let factors: Vec<(Natural, u64)> = [
+    ///     (2u32, 1),
+    ///     (3, 2),
+    ///     (5, 2),
+    ///     (7, 1),
+    ///     (11, 1),
+    ///     (13, 1),
+    ///     (31, 1),
+    ///     (41, 1),
+    ///     (61, 1),
+    ///     (151, 1),
+    ///     (331, 1),
+    ///     (1321, 1),
+    /// ]
+    /// .iter()
+    /// .map(|&(q, e)| (Natural::from(q), e))
+    /// .collect();
+    /// assert_eq!(
+    ///     Natural::from(1234567891011u64)
+    ///         .mod_log_with_order_factorization(&Natural::from(37u32), &p, &factors)
+    ///         .to_debug_string(),
+    ///     "Some(1248484803653020093)"
+    /// );
+    /// ```
+    pub fn mod_log_with_order_factorization(
+        &self,
+        base: &Natural,
+        m: &Natural,
+        order_factors: &[(Natural, u64)],
+    ) -> Option<Natural> {
+        assert!(self < m, "self must be reduced mod m, but {self} >= {m}");
+        assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+        assert_eq!(base.gcd(m), 1u32, "base must be coprime to m");
+        if *m == 1u32 {
+            return Some(Natural::ZERO);
+        }
+        let product: Natural = order_factors.iter().map(|(p, k)| p.pow(*k)).product();
+        assert_eq!(
+            base.mod_pow(product, m),
+            1u32,
+            "the order of base must divide the product of the prime powers"
+        );
+        let (n, order_factors) = order_factorization(base, m, order_factors);
+        let x = pohlig_hellman(base, self, m, &n, &order_factors)?;
+        if base.mod_pow(&x, m) == *self {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+fn mod_log_helper(h: &Natural, g: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(h < m, "h must be reduced mod m, but {h} >= {m}");
+    assert!(g < m, "g must be reduced mod m, but {g} >= {m}");
+    if let (Natural(Small(h)), Natural(Small(g)), Natural(Small(m))) = (h, g, m) {
+        return h.mod_log(*g, *m).map(Natural::from);
+    }
+    // Divide out the common factors of g and m. If d = gcd(g, m) and g^x ≡ h mod m with x > 0,
+    // then d | h and (g / d) g^(x - 1) ≡ h / d mod m / d. Each step produces a coefficient c that
+    // is coprime to the new modulus.
+    let mut m = m.clone();
+    let mut h = h.clone();
+    let mut c = Natural::ONE % &m;
+    let mut k = 0u64;
+    loop {
+        if c == h {
+            return Some(Natural::from(k));
+        }
+        let g = g % &m;
+        let d = (&g).gcd(&m);
+        if d == 1u32 {
+            break;
+        }
+        if !(&h).divisible_by(&d) {
+            return None;
+        }
+        m /= &d;
+        h /= &d;
+        c = (c % &m).mod_mul((g / d) % &m, &m);
+        k += 1;
+    }
+    // Now c g^x ≡ h mod m, with g and c coprime to m.
+    let g = g % &m;
+    let h = h.mod_mul(c.mod_inverse(&m).unwrap(), &m);
+    let (n, order_factors) = order_factorization(&g, &m, &totient_factorization(&m));
+    let x = pohlig_hellman(&g, &h, &m, &n, &order_factors)?;
+    if (&g).mod_pow(&x, &m) == h {
+        Some(x + Natural::from(k))
+    } else {
+        None
+    }
+}
+
+impl ModLog<Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] with respect to a base $g$, modulo another
+    /// [`Natural`] $m$: the smallest $x$ such that $g^x \equiv y \mod m$. Returns `None` if there
+    /// is no such $x$. Assumes the first [`Natural`] and the base are already reduced modulo $m$.
+    /// All three [`Natural`]s are taken by value.
+    ///
+    /// $$
+    /// f(y, g, m) = \\begin{cases}
+    ///     \operatorname{Some}(\min\\{x : g^x \equiv y \mod m\\})
+    ///         & \text{if} \\quad \exists x : g^x \equiv y \mod m, \\\\
+    ///     \operatorname{None} & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// Common factors of $g$ and $m$ are removed first. Then the order of $g$ is found from the
+    /// factorization of $\phi(m)$, and the Pohlig-Hellman algorithm reduces the problem to
+    /// subgroups of prime order, where the baby-step giant-step algorithm is used. If the
+    /// factorization of the order of $g$ is already known, use
+    /// [`mod_log_with_order_factorization`](Natural::mod_log_with_order_factorization) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2} n \log n \log\log n)$
+    ///
+    /// $M(n) = O(2^{n/2} n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(Natural::from(2u32), Natural::from(11u32))
+    ///         .to_debug_string(),
+    ///     "Some(8)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_log(Natural::from(2u32), Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(123456789u32)
+    ///         .mod_log(Natural::from(5u32), Natural::from(1000000007u32))
+    ///         .to_debug_string(),
+    ///     "Some(981640996)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: Natural, m: Natural) -> Option<Natural> {
+        mod_log_helper(&self, &base, &m)
+    }
+}
+
+impl<'a, 'b, 'c> ModLog<&'b Natural, &'c Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Computes the discrete logarithm of a [`Natural`] with respect to a base $g$, modulo another
+    /// [`Natural`] $m$: the smallest $x$ such that $g^x \equiv y \mod m$. Returns `None` if there
+    /// is no such $x$. Assumes the first [`Natural`] and the base are already reduced modulo $m$.
+    /// All three [`Natural`]s are taken by reference.
+    ///
+    /// $$
+    /// f(y, g, m) = \\begin{cases}
+    ///     \operatorname{Some}(\min\\{x : g^x \equiv y \mod m\\})
+    ///         & \text{if} \\quad \exists x : g^x \equiv y \mod m, \\\\
+    ///     \operatorname{None} & \text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// Common factors of $g$ and $m$ are removed first. Then the order of $g$ is found from the
+    /// factorization of $\phi(m)$, and the Pohlig-Hellman algorithm reduces the problem to
+    /// subgroups of prime order, where the baby-step giant-step algorithm is used. If the
+    /// factorization of the order of $g$ is already known, use
+    /// [`mod_log_with_order_factorization`](Natural::mod_log_with_order_factorization) instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2} n \log n \log\log n)$
+    ///
+    /// $M(n) = O(2^{n/2} n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `base` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModLog;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(&Natural::from(2u32), &Natural::from(11u32))
+    ///         .to_debug_string(),
+    ///     "Some(8)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_log(&Natural::from(2u32), &Natural::from(7u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(123456789u32))
+    ///         .mod_log(&Natural::from(5u32), &Natural::from(1000000007u32))
+    ///         .to_debug_string(),
+    ///     "Some(981640996)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_log(self, base: &'b Natural, m: &'c Natural) -> Option<Natural> {
+        mod_log_helper(self, base, m)
+    }
+}
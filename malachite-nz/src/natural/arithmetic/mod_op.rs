@@ -32,6 +32,10 @@ use crate::platform::{
     MOD_1_2_TO_MOD_1_4_THRESHOLD, MOD_1_NORM_THRESHOLD, MOD_1_UNNORM_THRESHOLD,
     MU_DIV_QR_SKEW_THRESHOLD, MU_DIV_QR_THRESHOLD,
 };
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::mem::swap;
+use core::ops::{Rem, RemAssign};
 use malachite_base::num::arithmetic::traits::{
     Mod, ModAssign, ModPowerOf2, NegMod, NegModAssign, OverflowingAddAssign, Parity, PowerOf2,
     WrappingAddAssign, WrappingMulAssign, WrappingSubAssign,
@@ -41,9 +45,6 @@ use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{JoinHalves, SplitInHalf};
 use malachite_base::num::logic::traits::LeadingZeros;
 use malachite_base::slices::{slice_move_left, slice_set_zero};
-use std::cmp::Ordering;
-use std::mem::swap;
-use std::ops::{Rem, RemAssign};
 
 // # Worst-case complexity
 // Constant time and additional memory.
@@ -736,8 +737,8 @@ fn limbs_mod_dc_condition(n_len: usize, d_len: usize) -> bool {
     let d_64 = d_len as f64;
     d_len < MUPI_DIV_QR_THRESHOLD
         || n_len < MU_DIV_QR_THRESHOLD << 1
-        || (((MU_DIV_QR_THRESHOLD - MUPI_DIV_QR_THRESHOLD) << 1) as f64)
-            .mul_add(d_64, MUPI_DIV_QR_THRESHOLD as f64 * n_64)
+        || (((MU_DIV_QR_THRESHOLD - MUPI_DIV_QR_THRESHOLD) << 1) as f64) * d_64
+            + MUPI_DIV_QR_THRESHOLD as f64 * n_64
             > d_64 * n_64
 }
 
@@ -1928,7 +1929,7 @@ impl<'a, 'b> Rem<&'b Natural> for &'a Natural {
         match (self, other) {
             (_, &Natural::ZERO) => panic!("division by zero"),
             (_, &Natural::ONE) => Natural::ZERO,
-            (n, d) if std::ptr::eq(n, d) => Natural::ZERO,
+            (n, d) if core::ptr::eq(n, d) => Natural::ZERO,
             (n, Natural(Small(d))) => Natural(Small(n.rem_limb_ref(*d))),
             (Natural(Small(_)), _) => self.clone(),
             (&Natural(Large(ref ns)), Natural(Large(ref ds))) => {
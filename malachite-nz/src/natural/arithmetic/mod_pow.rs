@@ -32,6 +32,8 @@ use crate::natural::logic::significant_bits::limbs_significant_bits;
 use crate::natural::InnerNatural::Small;
 use crate::natural::Natural;
 use crate::platform::{Limb, MUL_TOOM22_THRESHOLD, SQR_BASECASE_THRESHOLD, SQR_TOOM2_THRESHOLD};
+use alloc::vec::Vec;
+use core::cmp::{max, min, Ordering};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     ModPow, ModPowAssign, ModPowerOf2, ModPowerOf2Assign, Parity, PowerOf2, WrappingNegAssign,
@@ -41,7 +43,6 @@ use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, WrappingFrom};
 use malachite_base::num::logic::traits::TrailingZeros;
 use malachite_base::slices::{slice_leading_zeros, slice_set_zero};
-use std::cmp::{max, min, Ordering};
 
 // Equivalent to limbs_slice_get_bits(xs, end.saturating_sub(len), end)[0]
 //
@@ -101,7 +102,7 @@ fn limbs_redc_limb_raw(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Li
 //
 // This is equivalent to `MPN_REDC_1` from `mpn/generic/powm.c`, GMP 6.2.1. Investigate changes
 // from 6.1.2?
-fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
+pub(crate) fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
     if limbs_redc_limb_raw(out, xs, ms, m_inv) {
         limbs_sub_same_length_in_place_left(&mut out[..ms.len()], ms);
     }
@@ -124,6 +125,15 @@ pub(crate) fn get_window_size(width: u64) -> u64 {
     )
 }
 
+// The number of scratch limbs needed by `limbs_redc` when `ms` has length `ms_len`.
+//
+// # Worst-case complexity
+// Constant time and additional memory.
+pub(crate) fn limbs_redc_scratch_len(ms_len: usize) -> usize {
+    let n = limbs_mul_mod_base_pow_n_minus_1_next_size(ms_len);
+    limbs_mul_mod_base_pow_n_minus_1_scratch_len(n, ms_len, ms_len) + ms_len + n
+}
+
 // # Worst-case complexity
 // $T(n) = O(n \log n \log\log n)$
 //
@@ -132,12 +142,16 @@ pub(crate) fn get_window_size(width: u64) -> u64 {
 // where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
 //
 // This is equivalent to `mpn_redc_n` from `mpn/generic/redc_n.c`, GMP 6.2.1.
-fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
+pub(crate) fn limbs_redc(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ms: &[Limb],
+    is: &[Limb],
+    scratch: &mut [Limb],
+) {
     let ms_len = ms.len();
     assert!(ms_len > 8);
     let n = limbs_mul_mod_base_pow_n_minus_1_next_size(ms_len);
-    let mut scratch =
-        vec![0; limbs_mul_mod_base_pow_n_minus_1_scratch_len(n, ms_len, ms_len) + ms_len + n];
     let (scratch_0, scratch) = scratch.split_at_mut(ms_len);
     limbs_mul_low_same_length(scratch_0, &xs[..ms_len], &is[..ms_len]);
     let (scratch_1, scratch_2) = scratch.split_at_mut(n);
@@ -166,7 +180,7 @@ fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
 //
 // This is equivalent to `redcify` from `mpn/generic/powm.c`, 6.2.1. Investigate changes from
 // 6.1.2?
-fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
+pub(crate) fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
     let xs_len = xs.len();
     let ms_len = ms.len();
     if ms_len == 1 {
@@ -182,7 +196,7 @@ fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
 }
 
 //TODO tune
-const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
+pub(crate) const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
 
 // # Worst-case complexity
 // Constant time and additional memory.
@@ -217,7 +231,8 @@ fn limbs_redc_limb_helper(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], is: &[
 //
 // where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
 fn limbs_redc_helper(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], is: &[Limb]) {
-    limbs_redc(out, xs, ms, is)
+    let mut scratch = vec![0; limbs_redc_scratch_len(ms.len())];
+    limbs_redc(out, xs, ms, is, &mut scratch)
 }
 
 // # Worst-case complexity
@@ -13,6 +13,8 @@ use crate::natural::logic::not::limbs_not_in_place;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{
     ModPowerOf2Neg, ModPowerOf2NegAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ShrRound,
 };
@@ -20,7 +22,6 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::Zero;
 use malachite_base::num::conversion::traits::ExactFrom;
 use malachite_base::rounding_modes::RoundingMode;
-use std::mem::swap;
 
 // # Worst-case complexity
 // $T(n) = O(n)$
@@ -403,7 +404,7 @@ impl<'a, 'b> ModPowerOf2Sub<&'a Natural> for &'b Natural {
     /// ```
     fn mod_power_of_2_sub(self, other: &'a Natural, pow: u64) -> Natural {
         match (self, other) {
-            (x, y) if std::ptr::eq(x, y) => Natural::ZERO,
+            (x, y) if core::ptr::eq(x, y) => Natural::ZERO,
             (x, &Natural(Small(y))) => x.mod_power_of_2_sub_limb_ref(y, pow),
             (&Natural(Small(x)), y) => y.mod_power_of_2_right_sub_limb_ref(x, pow),
             (&Natural(Large(ref xs)), &Natural(Large(ref ys))) => {
@@ -486,7 +487,7 @@ impl<'a> ModPowerOf2SubAssign<&'a Natural> for Natural {
     /// ```
     fn mod_power_of_2_sub_assign(&mut self, other: &'a Natural, pow: u64) {
         match (&mut *self, other) {
-            (x, y) if std::ptr::eq(x, y) => *self = Natural::ZERO,
+            (x, y) if core::ptr::eq(x, y) => *self = Natural::ZERO,
             (x, &Natural(Small(y))) => x.mod_power_of_2_sub_assign_limb(y, pow),
             (&mut Natural(Small(x)), y) => *self = y.mod_power_of_2_right_sub_limb_ref(x, pow),
             (&mut Natural(Large(ref mut xs)), &Natural(Large(ref ys))) => {
@@ -0,0 +1,432 @@
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivisibleBy, LegendreSymbol, ModAdd, ModInverse, ModMul, ModPow, ModPowerOf2,
+    ModSqrt, ModSub, Parity, Pow, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Returns the smallest positive quadratic non-residue mod the odd prime `p`.
+fn non_residue(p: &Natural) -> Natural {
+    let mut z = Natural::TWO;
+    while (&z).legendre_symbol(p) != -1 {
+        z += Natural::ONE;
+    }
+    z
+}
+
+// Tonelli-Shanks. `x` is a nonzero quadratic residue mod the odd prime `p`, and `p - 1 = q * 2^s`
+// with `q` odd.
+fn mod_sqrt_prime_tonelli_shanks(x: &Natural, p: &Natural, q: &Natural, s: u64) -> Natural {
+    let mut c = non_residue(p).mod_pow(q, p);
+    let mut t = x.mod_pow(q, p);
+    let mut r = x.mod_pow((q >> 1u32) + Natural::ONE, p);
+    let mut m = s;
+    while t != 1u32 {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut u = t.clone();
+        while u != 1u32 {
+            u = (&u).mod_mul(&u, p);
+            i += 1;
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = (&b).mod_mul(&b, p);
+        }
+        r = r.mod_mul(&b, p);
+        c = (&b).mod_mul(&b, p);
+        t = t.mod_mul(&c, p);
+        m = i;
+    }
+    r
+}
+
+// Cipolla's algorithm. `x` is a nonzero quadratic residue mod the odd prime `p`.
+fn mod_sqrt_prime_cipolla(x: &Natural, p: &Natural) -> Natural {
+    // Find a such that w = a^2 - x is a non-residue. Then (a + sqrt(w))^((p + 1) / 2), computed in
+    // F_p(sqrt(w)), is a square root of x.
+    let mut a = Natural::ZERO;
+    let w = loop {
+        a += Natural::ONE;
+        let w = (&a).mod_mul(&a, p).mod_sub(x, p);
+        if (&w).legendre_symbol(p) == -1 {
+            break w;
+        }
+    };
+    let mul = |(x_0, x_1): (&Natural, &Natural), (y_0, y_1): (&Natural, &Natural)| {
+        (
+            x_0.mod_mul(y_0, p)
+                .mod_add(x_1.mod_mul(y_1, p).mod_mul(&w, p), p),
+            x_0.mod_mul(y_1, p).mod_add(x_1.mod_mul(y_0, p), p),
+        )
+    };
+    let mut out = (Natural::ONE, Natural::ZERO);
+    let exp = (p >> 1u32) + Natural::ONE;
+    for i in (0..exp.significant_bits()).rev() {
+        out = mul((&out.0, &out.1), (&out.0, &out.1));
+        if exp.get_bit(i) {
+            out = mul((&out.0, &out.1), (&a, &Natural::ONE));
+        }
+    }
+    out.0
+}
+
+// Returns the smaller square root of `x` mod the prime `p`, if `x` is a quadratic residue. `x` must
+// be reduced.
+fn mod_sqrt_prime(x: &Natural, p: &Natural) -> Option<Natural> {
+    if *x == 0u32 || *p == 2u32 {
+        return Some(x.clone());
+    }
+    if x.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let p_minus_1 = p - Natural::ONE;
+    let s = p_minus_1.trailing_zeros().unwrap();
+    let r = if s == 1 {
+        x.mod_pow((p >> 2u32) + Natural::ONE, p)
+    } else if s * (s - 1) > (p.significant_bits() << 3) + 20 {
+        // Tonelli-Shanks takes O(s^2) multiplications and Cipolla's algorithm takes O(log p), but
+        // each of Cipolla's steps is more expensive. This is the usual crossover point.
+        mod_sqrt_prime_cipolla(x, p)
+    } else {
+        mod_sqrt_prime_tonelli_shanks(x, p, &(p_minus_1 >> s), s)
+    };
+    let r_neg = p - &r;
+    Some(min(r, r_neg))
+}
+
+// Returns a square root of `x` mod `p^k`, where `x` is coprime to the prime `p`, `k > 0`, and
+// `q = p^k`. `x` must be reduced.
+fn mod_sqrt_prime_power_coprime(x: &Natural, p: &Natural, k: u64, q: &Natural) -> Option<Natural> {
+    if *p == 2u32 {
+        // x is odd. Every odd number is a square mod 2, only numbers congruent to 1 are squares mod
+        // 4, and only numbers congruent to 1 mod 8 are squares mod higher powers of 2.
+        return match k {
+            1 => Some(Natural::ONE),
+            2 => Some(Natural::ONE).filter(|_| x.mod_power_of_2(2) == 1u32),
+            _ if x.mod_power_of_2(3) != 1u32 => None,
+            _ => {
+                // If r^2 ≡ x mod 2^i, then either r or r + 2^(i - 1) is a square root mod
+                // 2^(i + 1).
+                let mut r = Natural::ONE;
+                for i in 3..k {
+                    if (&r).square().mod_power_of_2(i + 1) != x.mod_power_of_2(i + 1) {
+                        r.set_bit(i - 1);
+                    }
+                }
+                let r_neg = q - &r;
+                Some(min(r, r_neg))
+            }
+        };
+    }
+    let mut r = mod_sqrt_prime(&(x % p), p)?;
+    if k != 1 {
+        // Hensel lifting, via Newton's method: each step doubles the power of p modulo which r is
+        // a square root of x.
+        loop {
+            let d = (&r).mod_mul(&r, q).mod_sub(x, q);
+            if d == 0u32 {
+                break;
+            }
+            let inverse = (&r).mod_add(&r, q).mod_inverse(q).unwrap();
+            r = r.mod_sub(d.mod_mul(inverse, q), q);
+        }
+        let r_neg = q - &r;
+        r = min(r, r_neg);
+    }
+    Some(r)
+}
+
+// Returns a square root of `x` mod `p^k`, where `p` is prime, `k > 0`, and `q = p^k`. `x` must be
+// reduced.
+fn mod_sqrt_prime_power(x: &Natural, p: &Natural, k: u64, q: &Natural) -> Option<Natural> {
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    // Write x = p^v * y, with y coprime to p. Since x < p^k, v < k. A square root of x exists if
+    // and only if v is even and y is a square mod p^(k - v), and then p^(v / 2) times a square
+    // root of y is a square root of x.
+    let mut v = 0u64;
+    let mut y = x.clone();
+    while (&y).divisible_by(p) {
+        y = y.div_exact(p);
+        v += 1;
+    }
+    if v == 0 {
+        return mod_sqrt_prime_power_coprime(x, p, k, q);
+    }
+    if v.odd() {
+        return None;
+    }
+    let p_half_v = p.pow(v >> 1);
+    let q_over_p_v = q.div_exact((&p_half_v).square());
+    Some(mod_sqrt_prime_power_coprime(&y, p, k - v, &q_over_p_v)? * p_half_v)
+}
+
+impl Natural {
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, given the prime
+    /// factorization of $m$, or returns `None` if there is none. Assumes the first [`Natural`] is
+    /// already reduced modulo $m$.
+    ///
+    /// The factorization is a list of distinct primes paired with their multiplicities, as
+    /// returned by [`Factor::factor`]. This function is useful when $m$ is too large to factor
+    /// but its factorization is known, for example because $m$ was constructed as a product of
+    /// primes.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// A square root is found modulo each prime power dividing $m$, and the results are combined
+    /// using the Chinese remainder theorem. If $m$ is prime, the smaller of the two square roots is
+    /// returned; otherwise, the root returned is one of possibly many.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the factorization contains an exponent of zero, or if `self` is greater than or
+    /// equal to the product of the prime powers. The primes are not checked for primality; if
+    /// they are not prime, the result is unspecified.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let p = Natural::from(2u32).pow(127) - Natural::from(1u32);
+    /// let q = Natural::from(2u32).pow(89) - Natural::from(1u32);
+    /// let factors = [(q, 1), (p, 2)];
+    /// assert_eq!(
+    ///     Natural::from(4u32).mod_sqrt_with_factorization(&factors).to_debug_string(),
+    ///     "Some(2)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_sqrt_with_factorization(&factors).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    pub fn mod_sqrt_with_factorization(&self, factors: &[(Natural, u64)]) -> Option<Natural> {
+        assert!(factors.iter().all(|(_, k)| *k != 0));
+        let prime_powers: Vec<Natural> = factors.iter().map(|(p, k)| p.pow(*k)).collect();
+        assert!(
+            *self < prime_powers.iter().product::<Natural>(),
+            "self must be reduced modulo the product of the prime powers"
+        );
+        // Find square roots modulo each prime power, and combine them using the Chinese remainder
+        // theorem.
+        let mut root = Natural::ZERO;
+        let mut m = Natural::ONE;
+        for ((p, k), q) in factors.iter().zip(prime_powers.iter()) {
+            let r = mod_sqrt_prime_power(&(self % q), p, *k, q)?;
+            if m == 1u32 {
+                root = r;
+            } else {
+                let t = r
+                    .mod_sub(&root % q, q)
+                    .mod_mul((&m % q).mod_inverse(q).unwrap(), q);
+                root += &m * t;
+            }
+            m *= q;
+        }
+        Some(root)
+    }
+}
+
+fn mod_sqrt_helper(x: &Natural, m: &Natural) -> Option<Natural> {
+    assert!(x < m, "x must be reduced mod m, but {x} >= {m}");
+    match (x, m) {
+        (&Natural(Small(x)), &Natural(Small(m))) => x.mod_sqrt(m).map(Natural::from),
+        _ => x.mod_sqrt_with_factorization(&m.factor()),
+    }
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, or returns `None` if
+    /// there is none. Assumes the first [`Natural`] is already reduced modulo $m$. Both
+    /// [`Natural`]s are taken by value.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// If $m$ is prime, the smaller of the two square roots is returned. Otherwise, $m$ is
+    /// factored, a square root is found modulo each prime power dividing $m$, and the results are
+    /// combined using the Chinese remainder theorem; the root returned is one of possibly many. If
+    /// the factorization of $m$ is already known, use
+    /// [`mod_sqrt_with_factorization`](Natural::mod_sqrt_with_factorization) instead.
+    ///
+    /// Square roots modulo odd primes are found using the Tonelli-Shanks algorithm, or Cipolla's
+    /// algorithm if $p - 1$ is divisible by a large power of 2. They are lifted to roots modulo
+    /// prime powers using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ModSqrt, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_sqrt(Natural::from(7u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32)
+    ///         .mod_sqrt(Natural::from(10u32).pow(20) + Natural::from(39u32))
+    ///         .to_debug_string(),
+    ///     "Some(10836537005096534152)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, m: Natural) -> Option<Natural> {
+        mod_sqrt_helper(&self, &m)
+    }
+}
+
+impl<'a> ModSqrt<&'a Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, or returns `None` if
+    /// there is none. Assumes the first [`Natural`] is already reduced modulo $m$. The first
+    /// [`Natural`] is taken by value and the second by reference.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// See [`ModSqrt<Natural> for Natural`](Natural#impl-ModSqrt<Natural>-for-Natural) for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_sqrt(&Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_sqrt(&Natural::from(7u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, m: &'a Natural) -> Option<Natural> {
+        mod_sqrt_helper(&self, m)
+    }
+}
+
+impl<'a> ModSqrt<Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, or returns `None` if
+    /// there is none. Assumes the first [`Natural`] is already reduced modulo $m$. The first
+    /// [`Natural`] is taken by reference and the second by value.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// See [`ModSqrt<Natural> for Natural`](Natural#impl-ModSqrt<Natural>-for-Natural) for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_sqrt(Natural::from(7u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, m: Natural) -> Option<Natural> {
+        mod_sqrt_helper(self, &m)
+    }
+}
+
+impl<'a, 'b> ModSqrt<&'b Natural> for &'a Natural {
+    type Output = Natural;
+
+    /// Finds a square root of a [`Natural`] modulo another [`Natural`] $m$, or returns `None` if
+    /// there is none. Assumes the first [`Natural`] is already reduced modulo $m$. Both
+    /// [`Natural`]s are taken by reference.
+    ///
+    /// $f(x, m) = y$, where $y < m$ and $y^2 \equiv x \mod m$.
+    ///
+    /// See [`ModSqrt<Natural> for Natural`](Natural#impl-ModSqrt<Natural>-for-Natural) for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_sqrt(&Natural::from(7u32)).to_debug_string(),
+    ///     "Some(3)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_sqrt(&Natural::from(7u32)).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, m: &'b Natural) -> Option<Natural> {
+        mod_sqrt_helper(self, m)
+    }
+}
@@ -0,0 +1,716 @@
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    limbs_redc, limbs_redc_limb, limbs_redc_scratch_len, to_redc, REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::arithmetic::sub::{
+    limbs_sub_same_length_in_place_left, limbs_sub_same_length_in_place_right,
+};
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::{max, Ordering};
+use malachite_base::num::arithmetic::traits::{ModInverse, Parity, WrappingNegAssign};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+use malachite_base::slices::slice_test_zero;
+
+/// A [`Natural`] residue in Montgomery form, with respect to some [`MontgomeryContext`].
+///
+/// If the context's modulus $m$ has $n$ limbs and $R = 2^{nW}$, where $W$ is the width of a
+/// [`Limb`], a number $x$ is represented by $xR \mod m$. Residues can only be meaningfully
+/// combined with other residues created by the same context.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MontgomeryResidue(Vec<Limb>);
+
+impl MontgomeryResidue {
+    /// Determines whether the residue represents zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of limbs of the
+    /// context's modulus.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.zero().is_zero(), true);
+    /// assert_eq!(ctx.to_montgomery(&Natural::from(202u32)).is_zero(), true);
+    /// assert_eq!(ctx.one().is_zero(), false);
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        slice_test_zero(&self.0)
+    }
+}
+
+/// Reusable scratch space for the in-place operations of a [`MontgomeryContext`].
+///
+/// Scratch space is created with [`MontgomeryContext::scratch`] and may be passed to any number
+/// of calls to [`MontgomeryContext::mul_assign`] and [`MontgomeryContext::square_assign`] on the
+/// same context, so that chains of multiplications don't allocate.
+#[derive(Clone, Debug)]
+pub struct MontgomeryScratch(Vec<Limb>);
+
+/// Precomputed data for doing arithmetic modulo an odd [`Natural`] $m$ in Montgomery form.
+///
+/// Residues are converted into Montgomery form once, using [`MontgomeryContext::to_montgomery`],
+/// and can then be added, subtracted, multiplied, squared, raised to powers, and inverted
+/// without leaving it. Multiplication uses Montgomery reduction instead of division, so long
+/// chains of modular operations, as in primality tests or elliptic-curve arithmetic, are cheaper
+/// than repeated calls to [`ModMul`](malachite_base::num::arithmetic::traits::ModMul). The
+/// results are converted back with [`MontgomeryContext::from_montgomery`].
+///
+/// Each operation also has an `_assign` variant that overwrites its first argument. The
+/// multiplicative ones take a [`MontgomeryScratch`], so that hot loops need not allocate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MontgomeryContext {
+    modulus: Natural,
+    // The limbs of the modulus.
+    ms: Vec<Limb>,
+    // If `ms` is shorter than `REDC_1_TO_REDC_N_THRESHOLD`, this contains a single limb, $-m^{-1}
+    // \mod 2^W$. Otherwise, it contains $m^{-1} \mod R$.
+    is: Vec<Limb>,
+    // $R \mod m$, the Montgomery form of 1.
+    one: Vec<Limb>,
+    // $R^3 \mod m$.
+    r_cubed: Vec<Limb>,
+}
+
+impl MontgomeryContext {
+    /// Creates a context for doing arithmetic modulo an odd [`Natural`] in Montgomery form.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(*ctx.modulus(), 101);
+    /// ```
+    pub fn new(m: Natural) -> MontgomeryContext {
+        assert!(m.odd(), "The modulus must be odd");
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let is = if n < REDC_1_TO_REDC_N_THRESHOLD {
+            let mut inverse = limbs_modular_invert_limb(ms[0]);
+            inverse.wrapping_neg_assign();
+            vec![inverse]
+        } else {
+            let mut is = vec![0; n];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let mut one = vec![0; n];
+        to_redc(&mut one, &[1], &ms);
+        let mut r_squared = vec![0; n];
+        to_redc(&mut r_squared, &one, &ms);
+        let mut r_cubed = vec![0; n];
+        to_redc(&mut r_cubed, &r_squared, &ms);
+        MontgomeryContext {
+            modulus: m,
+            ms,
+            is,
+            one,
+            r_cubed,
+        }
+    }
+
+    /// Returns the modulus of the context.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(*ctx.modulus(), 101);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    // Computes $xy/R \mod m$, given the $2n$-limb product $xy$. The product is destroyed.
+    //
+    // # Worst-case complexity
+    // $T(n) = O(n^2)$
+    //
+    // $M(n) = O(1)$ if $n$ is less than `REDC_1_TO_REDC_N_THRESHOLD`, and $O(n \log n)$
+    // otherwise.
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.ms.len()`.
+    fn reduce(&self, out: &mut [Limb], product: &mut [Limb], scratch: &mut [Limb]) {
+        if self.ms.len() < REDC_1_TO_REDC_N_THRESHOLD {
+            limbs_redc_limb(out, product, &self.ms, self.is[0]);
+        } else {
+            limbs_redc(out, product, &self.ms, &self.is, scratch);
+        }
+        if limbs_cmp_same_length(out, &self.ms) != Ordering::Less {
+            limbs_sub_same_length_in_place_left(out, &self.ms);
+        }
+    }
+
+    // # Worst-case complexity
+    // $T(n) = O(n^2)$
+    //
+    // $M(n) = O(1)$ if $n$ is less than `REDC_1_TO_REDC_N_THRESHOLD`, and $O(n \log n)$
+    // otherwise.
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.ms.len()`.
+    fn mul_limbs_in_place(&self, xs: &mut [Limb], ys: &[Limb], scratch: &mut [Limb]) {
+        let (product, scratch) = scratch.split_at_mut(self.ms.len() << 1);
+        limbs_mul_same_length_to_out(product, xs, ys, scratch);
+        self.reduce(xs, product, scratch);
+    }
+
+    // # Worst-case complexity
+    // $T(n) = O(n^2)$
+    //
+    // $M(n) = O(1)$ if $n$ is less than `REDC_1_TO_REDC_N_THRESHOLD`, and $O(n \log n)$
+    // otherwise.
+    //
+    // where $T$ is time, $M$ is additional memory, and $n$ is `self.ms.len()`.
+    fn square_limbs_in_place(&self, xs: &mut [Limb], scratch: &mut [Limb]) {
+        let (product, scratch) = scratch.split_at_mut(self.ms.len() << 1);
+        limbs_square_to_out(product, xs, scratch);
+        self.reduce(xs, product, scratch);
+    }
+
+    /// Allocates scratch space for the in-place multiplicative operations of this context.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut scratch = ctx.scratch();
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// ctx.square_assign(&mut x, &mut scratch);
+    /// assert_eq!(ctx.from_montgomery(&x), 65);
+    /// ```
+    pub fn scratch(&self) -> MontgomeryScratch {
+        let n = self.ms.len();
+        let mut len = max(
+            limbs_mul_same_length_to_out_scratch_len(n),
+            limbs_square_to_out_scratch_len(n),
+        );
+        if n >= REDC_1_TO_REDC_N_THRESHOLD {
+            len = max(len, limbs_redc_scratch_len(n));
+        }
+        MontgomeryScratch(vec![0; (n << 1) + len])
+    }
+
+    /// Converts a [`Natural`] into Montgomery form. The [`Natural`] does not need to be reduced
+    /// modulo $m$.
+    ///
+    /// $f(x) = xR \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), m.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(150u32));
+    /// assert_eq!(ctx.from_montgomery(&x), 49);
+    /// ```
+    pub fn to_montgomery(&self, x: &Natural) -> MontgomeryResidue {
+        let mut out = vec![0; self.ms.len()];
+        if *x != 0u32 {
+            to_redc(&mut out, &x.to_limbs_asc(), &self.ms);
+        }
+        MontgomeryResidue(out)
+    }
+
+    /// Converts a residue out of Montgomery form.
+    ///
+    /// $f(x) = xR^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.one()), 1);
+    /// assert_eq!(ctx.from_montgomery(&ctx.to_montgomery(&Natural::from(7u32))), 7);
+    /// ```
+    pub fn from_montgomery(&self, x: &MontgomeryResidue) -> Natural {
+        let n = self.ms.len();
+        let mut scratch = self.scratch().0;
+        let (product, scratch) = scratch.split_at_mut(n << 1);
+        product[..n].copy_from_slice(&x.0);
+        let mut out = vec![0; n];
+        self.reduce(&mut out, product, scratch);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    /// Returns the Montgomery form of 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.zero()), 0);
+    /// ```
+    #[inline]
+    pub fn zero(&self) -> MontgomeryResidue {
+        MontgomeryResidue(vec![0; self.ms.len()])
+    }
+
+    /// Returns the Montgomery form of 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.one()), 1);
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(1u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.one()), 0);
+    /// ```
+    #[inline]
+    pub fn one(&self) -> MontgomeryResidue {
+        MontgomeryResidue(self.one.clone())
+    }
+
+    /// Adds two residues in Montgomery form.
+    ///
+    /// $f(x, y) = x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.add(&x, &y)), 29);
+    /// ```
+    #[inline]
+    pub fn add(&self, x: &MontgomeryResidue, y: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.clone();
+        self.add_assign(&mut out, y);
+        out
+    }
+
+    /// Adds a residue in Montgomery form to another, in place.
+    ///
+    /// $x \gets x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// ctx.add_assign(&mut x, &y);
+    /// assert_eq!(ctx.from_montgomery(&x), 29);
+    /// ```
+    pub fn add_assign(&self, x: &mut MontgomeryResidue, y: &MontgomeryResidue) {
+        if limbs_slice_add_same_length_in_place_left(&mut x.0, &y.0)
+            || limbs_cmp_same_length(&x.0, &self.ms) != Ordering::Less
+        {
+            limbs_sub_same_length_in_place_left(&mut x.0, &self.ms);
+        }
+    }
+
+    /// Subtracts two residues in Montgomery form.
+    ///
+    /// $f(x, y) = x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.sub(&x, &y)), 91);
+    /// ```
+    #[inline]
+    pub fn sub(&self, x: &MontgomeryResidue, y: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.clone();
+        self.sub_assign(&mut out, y);
+        out
+    }
+
+    /// Subtracts a residue in Montgomery form from another, in place.
+    ///
+    /// $x \gets x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// ctx.sub_assign(&mut x, &y);
+    /// assert_eq!(ctx.from_montgomery(&x), 91);
+    /// ```
+    pub fn sub_assign(&self, x: &mut MontgomeryResidue, y: &MontgomeryResidue) {
+        if limbs_sub_same_length_in_place_left(&mut x.0, &y.0) {
+            limbs_slice_add_same_length_in_place_left(&mut x.0, &self.ms);
+        }
+    }
+
+    /// Negates a residue in Montgomery form.
+    ///
+    /// $f(x) = -x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.neg(&x)), 41);
+    /// assert_eq!(ctx.neg(&ctx.zero()), ctx.zero());
+    /// ```
+    #[inline]
+    pub fn neg(&self, x: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.clone();
+        self.neg_assign(&mut out);
+        out
+    }
+
+    /// Negates a residue in Montgomery form, in place.
+    ///
+    /// $x \gets -x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// ctx.neg_assign(&mut x);
+    /// assert_eq!(ctx.from_montgomery(&x), 41);
+    /// ```
+    pub fn neg_assign(&self, x: &mut MontgomeryResidue) {
+        if !x.is_zero() {
+            limbs_sub_same_length_in_place_right(&self.ms, &mut x.0);
+        }
+    }
+
+    /// Multiplies two residues in Montgomery form.
+    ///
+    /// $f(x, y) = xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.mul(&x, &y)), 59);
+    /// ```
+    #[inline]
+    pub fn mul(&self, x: &MontgomeryResidue, y: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.clone();
+        self.mul_assign(&mut out, y, &mut self.scratch());
+        out
+    }
+
+    /// Multiplies a residue in Montgomery form by another, in place, using the provided scratch
+    /// space.
+    ///
+    /// $x \gets xy \mod m$.
+    ///
+    /// If $m$ has fewer than 100 limbs, this does not allocate.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// May panic if `scratch` was not created by this context.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut scratch = ctx.scratch();
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// let y = ctx.to_montgomery(&Natural::from(70u32));
+    /// ctx.mul_assign(&mut x, &y, &mut scratch);
+    /// assert_eq!(ctx.from_montgomery(&x), 59);
+    /// ```
+    #[inline]
+    pub fn mul_assign(
+        &self,
+        x: &mut MontgomeryResidue,
+        y: &MontgomeryResidue,
+        scratch: &mut MontgomeryScratch,
+    ) {
+        self.mul_limbs_in_place(&mut x.0, &y.0, &mut scratch.0);
+    }
+
+    /// Squares a residue in Montgomery form.
+    ///
+    /// $f(x) = x^2 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.square(&x)), 65);
+    /// ```
+    #[inline]
+    pub fn square(&self, x: &MontgomeryResidue) -> MontgomeryResidue {
+        let mut out = x.clone();
+        self.square_assign(&mut out, &mut self.scratch());
+        out
+    }
+
+    /// Squares a residue in Montgomery form, in place, using the provided scratch space.
+    ///
+    /// $x \gets x^2 \mod m$.
+    ///
+    /// If $m$ has fewer than 100 limbs, this does not allocate.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// May panic if `scratch` was not created by this context.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let mut scratch = ctx.scratch();
+    /// let mut x = ctx.to_montgomery(&Natural::from(60u32));
+    /// ctx.square_assign(&mut x, &mut scratch);
+    /// assert_eq!(ctx.from_montgomery(&x), 65);
+    /// ```
+    #[inline]
+    pub fn square_assign(&self, x: &mut MontgomeryResidue, scratch: &mut MontgomeryScratch) {
+        self.square_limbs_in_place(&mut x.0, &mut scratch.0);
+    }
+
+    /// Raises a residue in Montgomery form to a [`Natural`] power.
+    ///
+    /// $f(x, k) = x^k \mod m$, where $0^0 = 1$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $k$ is
+    /// `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(3u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.pow(&x, &Natural::from(100u32))), 1);
+    /// assert_eq!(ctx.from_montgomery(&ctx.pow(&x, &Natural::from(10u32))), 65);
+    /// ```
+    pub fn pow(&self, x: &MontgomeryResidue, exp: &Natural) -> MontgomeryResidue {
+        let mut out = self.one();
+        let mut scratch = self.scratch();
+        for i in (0..exp.significant_bits()).rev() {
+            self.square_assign(&mut out, &mut scratch);
+            if exp.get_bit(i) {
+                self.mul_assign(&mut out, x, &mut scratch);
+            }
+        }
+        out
+    }
+
+    /// Inverts a residue in Montgomery form, returning `None` if it has no inverse modulo $m$.
+    ///
+    /// $$
+    /// f(x) = \\begin{cases}
+    ///     \operatorname{Some}(y) & \text{if} \\quad \gcd(x, m) = 1 \\ \text{and} \\ xy \equiv 1
+    ///         \mod m, \\\\
+    ///     \operatorname{None} & \\text{otherwise}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ctx = MontgomeryContext::new(Natural::from(101u32));
+    /// let x = ctx.to_montgomery(&Natural::from(60u32));
+    /// assert_eq!(ctx.from_montgomery(&ctx.inverse(&x).unwrap()), 32);
+    /// assert_eq!(ctx.inverse(&ctx.zero()), None);
+    /// ```
+    pub fn inverse(&self, x: &MontgomeryResidue) -> Option<MontgomeryResidue> {
+        if self.modulus == 1u32 {
+            return Some(self.zero());
+        } else if x.is_zero() {
+            return None;
+        }
+        // If x represents a, then x = aR and x⁻¹ = a⁻¹R⁻¹, so the Montgomery product of x⁻¹ and
+        // R³ is a⁻¹R.
+        let mut inverse = Natural::from_limbs_asc(&x.0)
+            .mod_inverse(&self.modulus)?
+            .into_limbs_asc();
+        inverse.resize(self.ms.len(), 0);
+        self.mul_limbs_in_place(&mut inverse, &self.r_cubed, &mut self.scratch().0);
+        Some(MontgomeryResidue(inverse))
+    }
+}
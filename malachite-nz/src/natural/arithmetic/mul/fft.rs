@@ -13,7 +13,14 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_limb_in_place, limbs_sub_same_length_in_place_left,
     limbs_sub_same_length_in_place_right, limbs_sub_same_length_to_out,
 };
+#[cfg(feature = "parallel")]
+use crate::platform::MUL_FFT_PARALLEL_THRESHOLD;
 use crate::platform::{Limb, SignedLimb, FFT_TAB, MULMOD_TAB};
+use alloc::vec::Vec;
+use core::cmp::{max, min, Ordering};
+use core::mem::swap;
+#[cfg(all(feature = "parallel", feature = "bin_build"))]
+use core::sync::atomic::{self, AtomicUsize};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     CeilingLogBase2, Parity, PowerOf2, WrappingAddAssign, WrappingSubAssign, XXAddYYToZZ,
@@ -23,8 +30,8 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
 use malachite_base::num::logic::traits::LowMask;
 use malachite_base::slices::slice_set_zero;
-use std::cmp::{max, min, Ordering};
-use std::mem::swap;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 // This is equivalent to `mpn_addmod_2expp1_1` from `fft.h`, FLINT 2.7.1. `limbs` is one less than
 // the length of `r`.
@@ -2089,6 +2096,297 @@ pub_const_test! {
     xs_len > 28
 }}
 
+// When building the tuning program, the threshold can be changed at run time, so that the serial
+// and parallel code can be timed against each other.
+#[cfg(all(feature = "parallel", feature = "bin_build"))]
+pub static MUL_FFT_PARALLEL_THRESHOLD_FOR_TUNING: AtomicUsize =
+    AtomicUsize::new(MUL_FFT_PARALLEL_THRESHOLD);
+
+// Whether an FFT multiplication or squaring with an output of `out_len` limbs should spread its
+// work across threads.
+#[cfg(feature = "parallel")]
+#[inline]
+fn limbs_fft_is_parallel(out_len: usize) -> bool {
+    #[cfg(not(feature = "bin_build"))]
+    let threshold = MUL_FFT_PARALLEL_THRESHOLD;
+    #[cfg(feature = "bin_build")]
+    let threshold = MUL_FFT_PARALLEL_THRESHOLD_FOR_TUNING.load(atomic::Ordering::Relaxed);
+    out_len >= threshold
+}
+
+#[cfg(not(feature = "parallel"))]
+#[inline]
+const fn limbs_fft_is_parallel(_out_len: usize) -> bool {
+    false
+}
+
+// Evaluates `f` and `g`, on separate threads if the `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+#[inline]
+pub(crate) fn limbs_fft_join<A: Send, B: Send, F: FnOnce() -> A + Send, G: FnOnce() -> B + Send>(
+    f: F,
+    g: G,
+) -> (A, B) {
+    rayon::join(f, g)
+}
+
+#[cfg(not(feature = "parallel"))]
+#[inline]
+pub(crate) fn limbs_fft_join<A, B, F: FnOnce() -> A, G: FnOnce() -> B>(f: F, g: G) -> (A, B) {
+    (f(), g())
+}
+
+// Calls `f` on each of `items`, on a thread pool if the `parallel` feature is enabled. Each thread
+// gets its own scratch space of length `scratch_len`.
+#[cfg(feature = "parallel")]
+fn limbs_fft_for_each_with_scratch<T: Send, F: Fn(T, &mut [Limb]) + Send + Sync>(
+    items: Vec<T>,
+    scratch_len: usize,
+    f: F,
+) {
+    items
+        .into_par_iter()
+        .for_each_init(|| vec![0; scratch_len], |scratch, x| f(x, scratch));
+}
+
+#[cfg(not(feature = "parallel"))]
+fn limbs_fft_for_each_with_scratch<T, F: Fn(T, &mut [Limb])>(
+    items: Vec<T>,
+    scratch_len: usize,
+    f: F,
+) {
+    let mut scratch = vec![0; scratch_len];
+    for x in items {
+        f(x, &mut scratch);
+    }
+}
+
+// Splits `xs` into coefficients of `bits` bits each, zeroes the remaining coefficients, and
+// applies the truncated sqrt2 transform. Returns the number of coefficients taken from `xs`.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_split_and_truncate_sqrt<'a>(
+    xss: &mut [&'a mut [Limb]],
+    xs: &[Limb],
+    bits: usize,
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    scratch: &mut [Limb],
+    trunc: usize,
+) -> usize {
+    let j = limbs_fft_split_bits(xss, xs, bits);
+    for xs in &mut xss[j..] {
+        slice_set_zero(xs);
+    }
+    limbs_fft_truncate_sqrt(xss, w, ts, us, scratch, trunc);
+    j
+}
+
+// Splits `xs` into coefficients of `bits` bits each, zeroes the remaining coefficients, and
+// applies the outer layers of the matrix Fourier algorithm.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_split_and_mfa_truncate_sqrt_outer<'a>(
+    xss: &mut [&'a mut [Limb]],
+    xs: &[Limb],
+    bits: usize,
+    w: usize,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    ss: &mut &'a mut [Limb],
+    sqrt: usize,
+    trunc: usize,
+) -> usize {
+    let j = limbs_fft_split_bits(xss, xs, bits);
+    for xs in &mut xss[j..] {
+        slice_set_zero(xs);
+    }
+    limbs_fft_mfa_truncate_sqrt_outer(xss, w, ts, us, ss, sqrt, trunc);
+    j
+}
+
+// Multiplies two coefficients of a transform of length `size`, modulo 2^((size - 1) * Limb::WIDTH
+// - k) + 1, and stores the result in `xs`.
+fn limbs_fft_pointwise_mul(xs: &mut [Limb], ys: &mut [Limb], k: usize, scratch: &mut [Limb]) {
+    limbs_fft_normmod_2expp1(xs);
+    limbs_fft_normmod_2expp1(ys);
+    let (xs_last, xs_init) = xs.split_last_mut().unwrap();
+    let (ys_last, ys_init) = ys.split_last().unwrap();
+    assert_eq!(*xs_last, 0);
+    let c = *ys_last;
+    assert!(c < 2);
+    *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
+        xs_init,
+        ys_init,
+        c == 1,
+        k,
+        scratch,
+    ));
+}
+
+// Squares a coefficient of a transform of length `size`, modulo 2^b + 1, and stores the result in
+// `xs`.
+fn limbs_fft_pointwise_square(xs: &mut [Limb], b: usize, scratch: &mut [Limb]) {
+    limbs_fft_normmod_2expp1(xs);
+    let (xs_last, xs_init) = xs.split_last_mut().unwrap();
+    assert_eq!(*xs_last, 0);
+    *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs_init, b, scratch));
+}
+
+// Performs the convolution of one row of the matrix Fourier algorithm: transforms the row of `xss`
+// and the row of `yss`, multiplies them pointwise modulo 2^(n * w) + 1, and applies the inverse
+// transform to the product, which is left in `xss`. If `basecase` is false, the pointwise products
+// are themselves computed using an FFT.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_convolve_row<'a>(
+    xss: &mut [&'a mut [Limb]],
+    yss: &mut [&'a mut [Limb]],
+    n: usize,
+    w: usize,
+    wy: usize,
+    basecase: bool,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    scratch: &mut [Limb],
+) {
+    let nw = n * w;
+    limbs_fft_radix2(xss, wy, ts, us);
+    limbs_fft_radix2(yss, wy, ts, us);
+    if basecase {
+        let limbs = nw >> Limb::LOG_WIDTH;
+        let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
+        let k = (n_2 << Limb::LOG_WIDTH) - nw;
+        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()) {
+            limbs_fft_normmod_2expp1(xs);
+            limbs_fft_normmod_2expp1(ys);
+            xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
+                &mut xs[..n_2],
+                &ys[..n_2],
+                false,
+                k,
+                scratch,
+            ));
+        }
+    } else {
+        let depth2 = nw.ceiling_log_base_2();
+        let off = if depth2 < 12 {
+            MULMOD_TAB[0]
+        } else {
+            MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
+        };
+        let depth2 = (depth2 >> 1) - u64::from(off);
+        let w2 = nw >> (depth2 << 1);
+        let n3 = usize::power_of_2(depth2);
+        let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
+        let two_n3 = n3 << 1;
+        let yss_scratch_len = (n3 + n3 * size) << 1;
+        let (scratch, combine_scratch) = scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
+        let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
+        let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+        for _ in 0..two_n3 {
+            let (lo, hi) = xss_scratch.split_at_mut(size);
+            xss2.push(lo);
+            xss_scratch = hi;
+        }
+        let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+        let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+        let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+        let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+        for _ in 0..two_n3 {
+            let (lo, hi) = yss_scratch.split_at_mut(size);
+            yss2.push(lo);
+            yss_scratch = hi;
+        }
+        let yss0 = yss_scratch;
+        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()) {
+            limbs_fft_normmod_2expp1(xs);
+            limbs_fft_normmod_2expp1(ys);
+            limbs_fft_mulmod_2expp1(
+                xs,
+                ys,
+                n,
+                w,
+                &mut xss2,
+                xss0,
+                &mut yss2,
+                yss0,
+                &mut ts2,
+                &mut us2,
+                &mut ss2,
+                combine_scratch,
+            );
+        }
+    }
+    limbs_ifft_radix2(xss, wy, ts, us);
+}
+
+// Like `limbs_fft_mfa_convolve_row`, but squares the row of `xss` instead.
+#[allow(clippy::mut_mut)]
+fn limbs_fft_mfa_convolve_row_same<'a>(
+    xss: &mut [&'a mut [Limb]],
+    n: usize,
+    w: usize,
+    wy: usize,
+    basecase: bool,
+    ts: &mut &'a mut [Limb],
+    us: &mut &'a mut [Limb],
+    scratch: &mut [Limb],
+) {
+    let nw = n * w;
+    let limbs = nw >> Limb::LOG_WIDTH;
+    limbs_fft_radix2(xss, wy, ts, us);
+    if basecase {
+        for xs in &mut *xss {
+            limbs_fft_normmod_2expp1(xs);
+            xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, scratch));
+        }
+    } else {
+        let depth2 = nw.ceiling_log_base_2();
+        let off = if depth2 < 12 {
+            MULMOD_TAB[0]
+        } else {
+            MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
+        };
+        let depth2 = (depth2 >> 1) - u64::from(off);
+        let w3 = nw >> (depth2 << 1);
+        let n3 = usize::power_of_2(depth2);
+        let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
+        let two_n3 = n3 << 1;
+        let (mut xss_scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
+        let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
+        for _ in 0..two_n3 {
+            let (lo, hi) = xss_scratch.split_at_mut(size);
+            xss2.push(lo);
+            xss_scratch = hi;
+        }
+        let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
+        let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
+        let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+        for xs in &mut *xss {
+            limbs_fft_normmod_2expp1(xs);
+            limbs_fft_mulmod_2expp1_same(
+                xs,
+                n,
+                w,
+                &mut xss2,
+                xss0,
+                &mut ts2,
+                &mut us2,
+                &mut ss2,
+                combine_scratch,
+            );
+        }
+    }
+    limbs_ifft_radix2(xss, wy, ts, us);
+}
+
+// Whether the `i`th row, of `sqrt` coefficients, of a matrix Fourier algorithm transform of length
+// `2 * two_n` needs to be convolved. All rows of the lower half do, but only those rows of the
+// upper half that contribute to the first `trunc` coefficients.
+fn limbs_fft_mfa_row_is_needed(i: usize, two_n: usize, sqrt: usize, trunc: usize) -> bool {
+    let len = two_n / sqrt;
+    i < len || n_revbin(i - len, len.ceiling_log_base_2()) < (trunc - two_n) / sqrt
+}
+
 const FFT_MULMOD_2EXPP1_CUTOFF: usize = 50;
 
 pub_test! {limbs_mul_greater_to_out_fft_with_cutoff_scratch_len(
@@ -2249,9 +2547,13 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let out = &mut out[..xs_len + ys_len];
         let len = b >> Limb::LOG_WIDTH;
         let size = len + 1;
-        let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
-        let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
         let four_n = n << 2;
+        let parallel = limbs_fft_is_parallel(xs_len + ys_len);
+        // When running in parallel, the transform of ys gets its own temporary buffers
+        let mut par_scratch = vec![0; if parallel { 3 * size } else { 0 }];
+        let mut par_buffers: Vec<&mut [Limb]> = par_scratch.chunks_mut(size).collect();
         let (scratch, combine_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
@@ -2276,34 +2578,42 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         }
         // trunc must be divisible by 2
         trunc = (trunc + 1) >> 1 << 1;
-        j1 = limbs_fft_split_bits(&mut xss, xs, bits);
-        for xs in &mut xss[j1..] {
-            slice_set_zero(xs);
-        }
-        limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
-        j2 = limbs_fft_split_bits(&mut yss, ys, bits);
-        for xs in &mut yss[j2..] {
-            slice_set_zero(xs);
-        }
-        limbs_fft_truncate_sqrt(&mut yss, w, &mut ts, &mut us, ss, trunc);
+        let (j1, j2) = if parallel {
+            let (ts_2, par_buffers) = par_buffers.split_first_mut().unwrap();
+            let (us_2, par_buffers) = par_buffers.split_first_mut().unwrap();
+            let ss_2 = &mut par_buffers[0];
+            let split_xs = || {
+                limbs_fft_split_and_truncate_sqrt(
+                    &mut xss, xs, bits, w, &mut ts, &mut us, ss, trunc,
+                )
+            };
+            let split_ys = || {
+                limbs_fft_split_and_truncate_sqrt(&mut yss, ys, bits, w, ts_2, us_2, ss_2, trunc)
+            };
+            limbs_fft_join(split_xs, split_ys)
+        } else {
+            (
+                limbs_fft_split_and_truncate_sqrt(
+                    &mut xss, xs, bits, w, &mut ts, &mut us, ss, trunc,
+                ),
+                limbs_fft_split_and_truncate_sqrt(
+                    &mut yss, ys, bits, w, &mut ts, &mut us, ss, trunc,
+                ),
+            )
+        };
         let n_2 = (b + U_WIDTH - 1) >> Limb::LOG_WIDTH;
         assert_eq!(n_2, len);
         let k = (n_2 << Limb::LOG_WIDTH) - b;
-        for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()).take(trunc) {
-            limbs_fft_normmod_2expp1(xs);
-            limbs_fft_normmod_2expp1(ys);
-            let (xs_last, xs_init) = xs.split_last_mut().unwrap();
-            let (ys_last, ys_init) = ys.split_last().unwrap();
-            assert_eq!(*xs_last, 0);
-            let c = *ys_last;
-            assert!(c < 2);
-            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                xs_init,
-                ys_init,
-                c == 1,
-                k,
-                combine_scratch,
-            ));
+        if parallel {
+            limbs_fft_for_each_with_scratch(
+                xss.iter_mut().zip(yss.iter_mut()).take(trunc).collect(),
+                combine_scratch.len(),
+                |(xs, ys), scratch| limbs_fft_pointwise_mul(xs, ys, k, scratch),
+            );
+        } else {
+            for (xs, ys) in xss.iter_mut().zip(yss.iter_mut()).take(trunc) {
+                limbs_fft_pointwise_mul(xs, ys, k, combine_scratch);
+            }
         }
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
@@ -2324,8 +2634,15 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         let sqrt = usize::power_of_2(depth >> 1);
         let limbs = nw >> Limb::LOG_WIDTH;
         let size = limbs + 1;
-        let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
-        let mut j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let j2 = ((ys_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let two_n = n << 1;
+        let len = two_n / sqrt;
+        let parallel = limbs_fft_is_parallel(xs_len + ys_len);
+        // When running in parallel, the transform of ys gets its own temporary buffers, and so does
+        // each row convolution
+        let mut par_scratch = vec![0; if parallel { (3 + (len << 2)) * size } else { 0 }];
+        let mut par_buffers: Vec<&mut [Limb]> = par_scratch.chunks_mut(size).collect();
         let (scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 3) + 3 * size);
         let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut((n * size) << 2);
         let four_n = n << 2;
@@ -2348,144 +2665,85 @@ pub_test! {limbs_mul_greater_to_out_fft_with_cutoff(
         // trunc must be divisible by 2*sqrt
         let two_sqrt = sqrt << 1;
         trunc = two_sqrt * ((trunc + two_sqrt - 1) / two_sqrt);
-        j1 = limbs_fft_split_bits(&mut xss, xs, bits);
-        for ps in &mut xss[j1..] {
-            slice_set_zero(ps);
-        }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        j2 = limbs_fft_split_bits(&mut yss, ys, bits);
-        for qs in &mut yss[j2..] {
-            slice_set_zero(qs);
-        }
-        limbs_fft_mfa_truncate_sqrt_outer(&mut yss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        let two_n = four_n >> 1;
-        let len = two_n / sqrt;
-        let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
-        let yss_hi = &mut yss[two_n..];
+        let (j1, j2) = if parallel {
+            let (ts_2, par_buffers) = par_buffers.split_first_mut().unwrap();
+            let (us_2, par_buffers) = par_buffers.split_first_mut().unwrap();
+            let ss_2 = &mut par_buffers[0];
+            limbs_fft_join(
+                || {
+                    limbs_fft_split_and_mfa_truncate_sqrt_outer(
+                        &mut xss, xs, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+                    )
+                },
+                || {
+                    limbs_fft_split_and_mfa_truncate_sqrt_outer(
+                        &mut yss, ys, bits, w, ts_2, us_2, ss_2, sqrt, trunc,
+                    )
+                },
+            )
+        } else {
+            (
+                limbs_fft_split_and_mfa_truncate_sqrt_outer(
+                    &mut xss, xs, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+                ),
+                limbs_fft_split_and_mfa_truncate_sqrt_outer(
+                    &mut yss, ys, bits, w, &mut ts, &mut us, &mut ss, sqrt, trunc,
+                ),
+            )
+        };
         let wy = w * len;
-        if bits >> Limb::LOG_WIDTH <= cutoff {
-            let n_2 = (nw + U_WIDTH - 1) >> Limb::LOG_WIDTH;
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
-                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                        &mut xs[..n_2],
-                        &ys[..n_2],
-                        false,
-                        k,
-                        misc_scratch,
-                    ));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    let k = (n_2 << Limb::LOG_WIDTH) - nw;
-                    xs[limbs] = Limb::from(limbs_fft_mulmod_2expp1_basecase_same(
-                        &mut xs[..n_2],
-                        &ys[..n_2],
-                        false,
-                        k,
-                        misc_scratch,
-                    ));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+        let basecase = bits >> Limb::LOG_WIDTH <= cutoff;
+        if parallel {
+            let rows: Vec<_> = xss
+                .chunks_mut(sqrt)
+                .zip(yss.chunks_mut(sqrt))
+                .enumerate()
+                .filter(|&(i, _)| limbs_fft_mfa_row_is_needed(i, two_n, sqrt, trunc))
+                .map(|(_, rows)| rows)
+                .zip(par_buffers[3..].chunks_mut(2))
+                .collect();
+            limbs_fft_for_each_with_scratch(
+                rows,
+                misc_scratch.len(),
+                |((xss, yss), tss), scratch| {
+                    let (ts, us) = tss.split_at_mut(1);
+                    limbs_fft_mfa_convolve_row(
+                        xss, yss, n, w, wy, basecase, &mut ts[0], &mut us[0], scratch,
+                    );
+                },
+            );
         } else {
-            let depth2 = nw.ceiling_log_base_2();
-            let off = if depth2 < 12 {
-                MULMOD_TAB[0]
-            } else {
-                MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
-            };
-            let depth2 = (depth2 >> 1) - u64::from(off);
-            let w2 = nw >> (depth2 << 1);
-            let n3 = usize::power_of_2(depth2);
-            let size = ((n3 * w2) >> Limb::LOG_WIDTH) + 1;
-            let two_n3 = n3 << 1;
-            let yss_scratch_len = (n3 + n3 * size) << 1;
-            let (scratch, combine_scratch) =
-                misc_scratch.split_at_mut((yss_scratch_len << 1) + 3 * size);
-            let (mut yss_scratch, mut xss_scratch) = scratch.split_at_mut(yss_scratch_len);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
-            let mut yss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = yss_scratch.split_at_mut(size);
-                yss2.push(lo);
-                yss_scratch = hi;
-            }
-            let yss0 = yss_scratch;
+            let depth = len.ceiling_log_base_2();
+            // convolutions on relevant rows
+            let xss_hi = &mut xss[two_n..];
+            let yss_hi = &mut yss[two_n..];
             for s in 0..(trunc - two_n) / sqrt {
                 let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                let yss_hi = &mut yss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_hi, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_hi.iter_mut().zip(yss_hi.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+                limbs_fft_mfa_convolve_row(
+                    &mut xss_hi[start..][..sqrt],
+                    &mut yss_hi[start..][..sqrt],
+                    n,
+                    w,
+                    wy,
+                    basecase,
+                    &mut ts,
+                    &mut us,
+                    misc_scratch,
+                );
             }
             // convolutions on rows
             for (xss_chunk, yss_chunk) in xss.chunks_mut(sqrt).zip(yss.chunks_mut(sqrt)).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                limbs_fft_radix2(yss_chunk, wy, &mut ts, &mut us);
-                for (xs, ys) in xss_chunk.iter_mut().zip(yss_chunk.iter_mut()) {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_normmod_2expp1(ys);
-                    limbs_fft_mulmod_2expp1(
-                        xs,
-                        ys,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut yss2,
-                        yss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                limbs_fft_mfa_convolve_row(
+                    xss_chunk,
+                    yss_chunk,
+                    n,
+                    w,
+                    wy,
+                    basecase,
+                    &mut ts,
+                    &mut us,
+                    misc_scratch,
+                );
             }
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
@@ -2668,15 +2926,16 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             slice_set_zero(xs);
         }
         limbs_fft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, ss, trunc);
-        for xs in &mut xss[..trunc] {
-            limbs_fft_normmod_2expp1(xs);
-            let (xs_last, xs_init) = xs.split_last_mut().unwrap();
-            assert_eq!(*xs_last, 0);
-            *xs_last = Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(
-                xs_init,
-                b,
-                combine_scratch,
-            ));
+        if limbs_fft_is_parallel(xs_len << 1) {
+            limbs_fft_for_each_with_scratch(
+                xss[..trunc].iter_mut().collect(),
+                combine_scratch.len(),
+                |xs, scratch| limbs_fft_pointwise_square(xs, b, scratch),
+            );
+        } else {
+            for xs in &mut xss[..trunc] {
+                limbs_fft_pointwise_square(xs, b, combine_scratch);
+            }
         }
         limbs_ifft_truncate_sqrt(&mut xss, w, &mut ts, &mut us, &mut ss, trunc);
         let depth = depth + 2;
@@ -2698,6 +2957,12 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
         let limbs = nw >> Limb::LOG_WIDTH;
         let size = limbs + 1;
         let mut j1 = ((xs_len << Limb::LOG_WIDTH) - 1) / bits + 1;
+        let two_n = n << 1;
+        let len = two_n / sqrt;
+        let parallel = limbs_fft_is_parallel(xs_len << 1);
+        // When running in parallel, each row convolution gets its own temporary buffers
+        let mut par_scratch = vec![0; if parallel { (len << 2) * size } else { 0 }];
+        let mut par_buffers: Vec<&mut [Limb]> = par_scratch.chunks_mut(size).collect();
         let (mut xss_scratch, misc_scratch) = scratch.split_at_mut(((n * size) << 2) + 3 * size);
         let four_n = n << 2;
         let mut xss: Vec<&mut [Limb]> = Vec::with_capacity(four_n);
@@ -2718,95 +2983,51 @@ pub_test! {limbs_square_to_out_fft_with_cutoff(
             slice_set_zero(ps);
         }
         limbs_fft_mfa_truncate_sqrt_outer(&mut xss, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
-        let two_n = four_n >> 1;
-        let len = two_n / sqrt;
-        let depth = len.ceiling_log_base_2();
-        // convolutions on relevant rows
-        let xss_hi = &mut xss[two_n..];
         let wy = w * len;
-        if nw >> Limb::LOG_WIDTH <= cutoff {
-            for s in 0..(trunc - two_n) / sqrt {
-                let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in &mut *xss_hi {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
-            }
-            // convolutions on rows
-            for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in &mut *xss_chunk {
-                    limbs_fft_normmod_2expp1(xs);
-                    xs[limbs] =
-                        Limb::from(limbs_fft_mulmod_2expp1_basecase_same2(xs, nw, misc_scratch));
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
-            }
+        let basecase = nw >> Limb::LOG_WIDTH <= cutoff;
+        if parallel {
+            let rows: Vec<_> = xss
+                .chunks_mut(sqrt)
+                .enumerate()
+                .filter(|&(i, _)| limbs_fft_mfa_row_is_needed(i, two_n, sqrt, trunc))
+                .map(|(_, row)| row)
+                .zip(par_buffers.chunks_mut(2))
+                .collect();
+            limbs_fft_for_each_with_scratch(rows, misc_scratch.len(), |(xss, tss), scratch| {
+                let (ts, us) = tss.split_at_mut(1);
+                limbs_fft_mfa_convolve_row_same(
+                    xss, n, w, wy, basecase, &mut ts[0], &mut us[0], scratch,
+                );
+            });
         } else {
-            let depth2 = nw.ceiling_log_base_2();
-            let off = if depth2 < 12 {
-                MULMOD_TAB[0]
-            } else {
-                MULMOD_TAB[min(usize::exact_from(depth2), MULMOD_TAB.len() + 11) - 12]
-            };
-            let depth2 = (depth2 >> 1) - u64::from(off);
-            let w3 = nw >> (depth2 << 1);
-            let n3 = usize::power_of_2(depth2);
-            let size = ((n3 * w3) >> Limb::LOG_WIDTH) + 1;
-            let two_n3 = n3 << 1;
-            let (mut xss_scratch, combine_scratch) =
-                misc_scratch.split_at_mut(((n * size) << 2) + 3 * size);
-            let mut xss2: Vec<&mut [Limb]> = Vec::with_capacity(two_n3);
-            for _ in 0..two_n3 {
-                let (lo, hi) = xss_scratch.split_at_mut(size);
-                xss2.push(lo);
-                xss_scratch = hi;
-            }
-            let (xss0, scratch_hi) = xss_scratch.split_at_mut(two_n3);
-            let (mut ts2, scratch_hi) = scratch_hi.split_at_mut(size);
-            let (mut us2, mut ss2) = scratch_hi.split_at_mut(size);
+            let depth = len.ceiling_log_base_2();
+            // convolutions on relevant rows
+            let xss_hi = &mut xss[two_n..];
             for s in 0..(trunc - two_n) / sqrt {
                 let start = sqrt * n_revbin(s, depth);
-                let xss_hi = &mut xss_hi[start..][..sqrt];
-                limbs_fft_radix2(xss_hi, wy, &mut ts, &mut us);
-                for xs in &mut *xss_hi {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_hi, wy, &mut ts, &mut us);
+                limbs_fft_mfa_convolve_row_same(
+                    &mut xss_hi[start..][..sqrt],
+                    n,
+                    w,
+                    wy,
+                    basecase,
+                    &mut ts,
+                    &mut us,
+                    misc_scratch,
+                );
             }
             // convolutions on rows
             for xss_chunk in xss.chunks_mut(sqrt).take(len) {
-                limbs_fft_radix2(xss_chunk, wy, &mut ts, &mut us);
-                for xs in &mut *xss_chunk {
-                    limbs_fft_normmod_2expp1(xs);
-                    limbs_fft_mulmod_2expp1_same(
-                        xs,
-                        n,
-                        w,
-                        &mut xss2,
-                        xss0,
-                        &mut ts2,
-                        &mut us2,
-                        &mut ss2,
-                        combine_scratch,
-                    );
-                }
-                limbs_ifft_radix2(xss_chunk, wy, &mut ts, &mut us);
+                limbs_fft_mfa_convolve_row_same(
+                    xss_chunk,
+                    n,
+                    w,
+                    wy,
+                    basecase,
+                    &mut ts,
+                    &mut us,
+                    misc_scratch,
+                );
             }
         }
         limbs_ifft_mfa_truncate_sqrt_outer(&mut xss, n, w, &mut ts, &mut us, &mut ss, sqrt, trunc);
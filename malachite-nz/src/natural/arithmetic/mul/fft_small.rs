@@ -0,0 +1,554 @@
+use crate::natural::arithmetic::mul::fft::limbs_fft_join;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::min;
+use core::iter::repeat;
+use malachite_base::num::arithmetic::traits::{
+    CeilingLogBase2, ModInverse, ModPow, Parity, PowerOf2, XMulYToZZ, XXAddYYToZZ, XXXAddYYYToZZZ,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::{FromOtherTypeSlice, WrappingFrom};
+use malachite_base::slices::slice_set_zero;
+
+// The primes used by the transforms. Each is less than $2^{62}$ and one more than a multiple of
+// $2^{40}$, so transforms of any power-of-2 length up to $2^{40}$ exist. Each prime is paired with
+// a primitive root. The product of the primes exceeds $2^{185}$, which is enough to recover every
+// coefficient of the product of two sequences of 64-bit words, each shorter than $2^{57}$ words.
+const FFT_SMALL_PRIMES: [(u64, u64); 3] =
+    [(0x3fffc00000000001, 11), (0x3fffbe0000000001, 3), (0x3fff840000000001, 19)];
+
+const FFT_SMALL_MAX_LOG_LEN: u64 = 40;
+
+// Blocks of at most this many words are transformed level by level rather than recursively.
+const FFT_SMALL_BASECASE_LEN: usize = 1024;
+
+// The number of limbs in a 64-bit word.
+const WORD_LIMBS: usize = (u64::WIDTH / Limb::WIDTH) as usize;
+
+// A prime together with the twiddle factors of a transform of a particular length.
+//
+// Residues are kept in $[0, 2p)$ or $[0, 4p)$ between butterflies and only fully reduced when
+// needed, as in Harvey's "Faster arithmetic for number-theoretic transforms". Since $p < 2^{62}$,
+// no intermediate value overflows.
+struct FftSmallPrime {
+    p: u64,
+    // $\lfloor 2^{124}/p \rfloor$
+    barrett: u64,
+    // For each `half` that is a power of 2 less than the transform length, `roots[half + j]`
+    // contains $\omega^j$ and $\lfloor 2^{64}\omega^j/p \rfloor$, where $\omega$ is a primitive
+    // `2 * half`th root of unity and $j <$ `half`. Keeping the twiddle factors of each level
+    // contiguous keeps the butterfly loops simple and cache-friendly.
+    roots: Vec<[u64; 2]>,
+    // The same, for $\omega^{-j}$.
+    inverse_roots: Vec<[u64; 2]>,
+}
+
+// Returns $\lfloor 2^{64}x/p \rfloor$, where $x < p$.
+fn shoup_precompute(x: u64, p: u64) -> u64 {
+    u64::wrapping_from((u128::from(x) << 64) / u128::from(p))
+}
+
+// Returns a value congruent to $xw$ mod $p$ and less than $2p$, given $w < p$, $w' = \lfloor
+// 2^{64}w/p \rfloor$, and any `x`. This is Shoup's multiplication by a precomputed constant.
+#[inline]
+fn mul_shoup_lazy(x: u64, w: u64, w_shoup: u64, p: u64) -> u64 {
+    x.wrapping_mul(w)
+        .wrapping_sub(u64::x_mul_y_to_zz(x, w_shoup).0.wrapping_mul(p))
+}
+
+// Reduces `x` from $[0, 2m)$ to $[0, m)$, without branching.
+#[inline]
+fn reduce_once(x: u64, m: u64) -> u64 {
+    min(x, x.wrapping_sub(m))
+}
+
+impl FftSmallPrime {
+    fn new(i: usize, log_len: u64) -> FftSmallPrime {
+        assert!(log_len <= FFT_SMALL_MAX_LOG_LEN);
+        let (p, g) = FFT_SMALL_PRIMES[i];
+        let half_len = if log_len == 0 { 1 } else { 1 << (log_len - 1) };
+        let omega = g.mod_pow((p - 1) >> log_len, p);
+        // $\lfloor 2^{128}\omega/p \rfloor$, as two words. It lets the Shoup precomputation of
+        // each power of $\omega$ be obtained from the previous power with two multiplications
+        // instead of a division.
+        let omega_shoup = shoup_precompute(omega, p);
+        let omega_shoup_lo = shoup_precompute(omega_shoup.wrapping_mul(p).wrapping_neg(), p);
+        let mut roots = vec![[0; 2]; half_len << 1];
+        let (mut root, mut root_shoup) = (1, shoup_precompute(1, p));
+        for r in &mut roots[half_len..] {
+            *r = [root, root_shoup];
+            let next = reduce_once(mul_shoup_lazy(root, omega, omega_shoup, p), p);
+            root_shoup = root
+                .wrapping_mul(omega_shoup)
+                .wrapping_add(u64::x_mul_y_to_zz(root, omega_shoup_lo).0);
+            // The estimate is at most 1 too small; check the remainder.
+            if root_shoup.wrapping_mul(p).wrapping_neg() >= p {
+                root_shoup += 1;
+            }
+            root = next;
+        }
+        // omega_(half)^j = omega_(2 * half)^(2 * j)
+        let mut half = half_len >> 1;
+        while half != 0 {
+            for j in 0..half {
+                roots[half + j] = roots[(half + j) << 1];
+            }
+            half >>= 1;
+        }
+        // omega^(-j) = -omega^(half - j), and the Shoup precomputation of p - x is the complement
+        // of that of x.
+        let mut inverse_roots = vec![[0; 2]; half_len << 1];
+        let mut half = half_len;
+        while half != 0 {
+            inverse_roots[half] = roots[half];
+            for j in 1..half {
+                let [root, root_shoup] = roots[(half << 1) - j];
+                inverse_roots[half + j] = [p - root, !root_shoup];
+            }
+            half >>= 1;
+        }
+        FftSmallPrime {
+            p,
+            barrett: u64::wrapping_from((1u128 << 124) / u128::from(p)),
+            roots,
+            inverse_roots,
+        }
+    }
+
+    // Returns $xy$ mod $p$, given $x, y < p$. This uses Barrett reduction.
+    #[inline]
+    fn mul_mod(&self, x: u64, y: u64) -> u64 {
+        let p = self.p;
+        let (product_hi, product_lo) = u64::x_mul_y_to_zz(x, y);
+        // product < 2^124, so product >> 61 fits in a word
+        let (q_hi, q_lo) = u64::x_mul_y_to_zz((product_hi << 3) | (product_lo >> 61), self.barrett);
+        let q = (q_hi << 1) | (q_lo >> 63);
+        // The estimated quotient is at most 2 too small.
+        let r = product_lo.wrapping_sub(q.wrapping_mul(p));
+        reduce_once(reduce_once(r, p << 1), p)
+    }
+
+    // Applies two levels of decimation-in-frequency butterflies to `xs`, whose length is a power of
+    // 4. Inputs and outputs are in $[0, 2p)$.
+    #[inline]
+    fn forward_radix_4(&self, xs: &mut [u64]) {
+        let p = self.p;
+        let p_2 = p << 1;
+        let q = xs.len() >> 2;
+        let (xs_lo, xs_hi) = xs.split_at_mut(q << 1);
+        let (xs_0, xs_1) = xs_lo.split_at_mut(q);
+        let (xs_2, xs_3) = xs_hi.split_at_mut(q);
+        let (ws_0, ws_1) = self.roots[q << 1..q << 2].split_at(q);
+        let ws_2 = &self.roots[q..q << 1];
+        for (((x_0, x_1), (x_2, x_3)), ((w_0, w_1), w_2)) in xs_0
+            .iter_mut()
+            .zip(xs_1.iter_mut())
+            .zip(xs_2.iter_mut().zip(xs_3.iter_mut()))
+            .zip(ws_0.iter().zip(ws_1.iter()).zip(ws_2.iter()))
+        {
+            let y_0 = reduce_once(*x_0 + *x_2, p_2);
+            let y_2 = mul_shoup_lazy(*x_0 + p_2 - *x_2, w_0[0], w_0[1], p);
+            let y_1 = reduce_once(*x_1 + *x_3, p_2);
+            let y_3 = mul_shoup_lazy(*x_1 + p_2 - *x_3, w_1[0], w_1[1], p);
+            *x_0 = reduce_once(y_0 + y_1, p_2);
+            *x_1 = mul_shoup_lazy(y_0 + p_2 - y_1, w_2[0], w_2[1], p);
+            *x_2 = reduce_once(y_2 + y_3, p_2);
+            *x_3 = mul_shoup_lazy(y_2 + p_2 - y_3, w_2[0], w_2[1], p);
+        }
+    }
+
+    // Applies one level of decimation-in-frequency butterflies to `xs`.
+    #[inline]
+    fn forward_radix_2(&self, xs: &mut [u64]) {
+        let p = self.p;
+        let p_2 = p << 1;
+        let half = xs.len() >> 1;
+        let (xs_lo, xs_hi) = xs.split_at_mut(half);
+        for ((x, y), w) in xs_lo
+            .iter_mut()
+            .zip(xs_hi.iter_mut())
+            .zip(self.roots[half..half << 1].iter())
+        {
+            let (u, v) = (*x, *y);
+            *x = reduce_once(u + v, p_2);
+            *y = mul_shoup_lazy(u + p_2 - v, w[0], w[1], p);
+        }
+    }
+
+    // Replaces `xs` with its transform, in bit-reversed order. `xs.len()` must be a power of 2, no
+    // greater than the length the twiddle factors were computed for. Inputs and outputs are in
+    // $[0, 2p)$.
+    //
+    // Large blocks are split recursively, so that the work on small blocks stays in cache; small
+    // blocks are transformed level by level.
+    fn forward(&self, xs: &mut [u64]) {
+        let mut len = xs.len();
+        if len > FFT_SMALL_BASECASE_LEN {
+            self.forward_radix_4(xs);
+            for xs in xs.chunks_exact_mut(len >> 2) {
+                self.forward(xs);
+            }
+            return;
+        }
+        while len >= 4 {
+            for xs in xs.chunks_exact_mut(len) {
+                self.forward_radix_4(xs);
+            }
+            len >>= 2;
+        }
+        if len == 2 {
+            for xs in xs.chunks_exact_mut(2) {
+                self.forward_radix_2(xs);
+            }
+        }
+    }
+
+    // Applies two levels of decimation-in-time butterflies to `xs`, whose length is a power of 4.
+    // Inputs and outputs are in $[0, 4p)$.
+    #[inline]
+    fn inverse_radix_4(&self, xs: &mut [u64]) {
+        let p = self.p;
+        let p_2 = p << 1;
+        let q = xs.len() >> 2;
+        let (xs_lo, xs_hi) = xs.split_at_mut(q << 1);
+        let (xs_0, xs_1) = xs_lo.split_at_mut(q);
+        let (xs_2, xs_3) = xs_hi.split_at_mut(q);
+        let (ws_0, ws_1) = self.inverse_roots[q << 1..q << 2].split_at(q);
+        let ws_2 = &self.inverse_roots[q..q << 1];
+        for (((x_0, x_1), (x_2, x_3)), ((w_0, w_1), w_2)) in xs_0
+            .iter_mut()
+            .zip(xs_1.iter_mut())
+            .zip(xs_2.iter_mut().zip(xs_3.iter_mut()))
+            .zip(ws_0.iter().zip(ws_1.iter()).zip(ws_2.iter()))
+        {
+            let u = reduce_once(*x_0, p_2);
+            let t = mul_shoup_lazy(*x_1, w_2[0], w_2[1], p);
+            let y_0 = reduce_once(u + t, p_2);
+            let y_1 = u + p_2 - t;
+            let u = reduce_once(*x_2, p_2);
+            let t = mul_shoup_lazy(*x_3, w_2[0], w_2[1], p);
+            let y_2 = reduce_once(u + t, p_2);
+            let y_3 = u + p_2 - t;
+            let t = mul_shoup_lazy(y_2, w_0[0], w_0[1], p);
+            *x_0 = y_0 + t;
+            *x_2 = y_0 + p_2 - t;
+            let u = reduce_once(y_1, p_2);
+            let t = mul_shoup_lazy(y_3, w_1[0], w_1[1], p);
+            *x_1 = u + t;
+            *x_3 = u + p_2 - t;
+        }
+    }
+
+    // Applies one level of decimation-in-time butterflies to `xs`.
+    #[inline]
+    fn inverse_radix_2(&self, xs: &mut [u64]) {
+        let p = self.p;
+        let p_2 = p << 1;
+        let half = xs.len() >> 1;
+        let (xs_lo, xs_hi) = xs.split_at_mut(half);
+        for ((x, y), w) in xs_lo
+            .iter_mut()
+            .zip(xs_hi.iter_mut())
+            .zip(self.inverse_roots[half..half << 1].iter())
+        {
+            let u = reduce_once(*x, p_2);
+            let t = mul_shoup_lazy(*y, w[0], w[1], p);
+            *x = u + t;
+            *y = u + p_2 - t;
+        }
+    }
+
+    // Undoes `forward`, except that the result is multiplied by `xs.len()`. The input is in
+    // bit-reversed order and the output is in natural order. Outputs are in $[0, 4p)$.
+    fn inverse(&self, xs: &mut [u64]) {
+        let len = xs.len();
+        if len > FFT_SMALL_BASECASE_LEN {
+            for xs in xs.chunks_exact_mut(len >> 2) {
+                self.inverse(xs);
+            }
+            self.inverse_radix_4(xs);
+            return;
+        }
+        let mut block_len = 1;
+        if len.trailing_zeros().odd() {
+            for xs in xs.chunks_exact_mut(2) {
+                self.inverse_radix_2(xs);
+            }
+            block_len = 2;
+        }
+        while block_len < len {
+            block_len <<= 2;
+            for xs in xs.chunks_exact_mut(block_len) {
+                self.inverse_radix_4(xs);
+            }
+        }
+    }
+
+    // Writes the words of `xs` (packed from limbs), reduced to $[0, 2p)$, into `out`, and zeroes
+    // the rest of `out`.
+    fn load(&self, out: &mut [u64], xs: &[Limb]) {
+        let p = self.p;
+        let words = xs.chunks(WORD_LIMBS);
+        let word_len = words.len();
+        for (o, chunk) in out.iter_mut().zip(words) {
+            // floor(2^64 / p) == 4
+            *o = mul_shoup_lazy(u64::from_other_type_slice(chunk), 1, 4, p);
+        }
+        slice_set_zero(&mut out[word_len..]);
+    }
+
+    // Computes `out.len()` times the cyclic convolution of `xs` and `ys` (interpreted as sequences
+    // of words) modulo `p`, writing it to `out`. The length of `out` is the transform length, and
+    // the outputs are in $[0, 4p)$.
+    fn convolve(&self, out: &mut [u64], xs: &[Limb], ys: &[Limb]) {
+        let p = self.p;
+        self.load(out, xs);
+        self.forward(out);
+        let mut ts = vec![0; out.len()];
+        self.load(&mut ts, ys);
+        self.forward(&mut ts);
+        for (x, &y) in out.iter_mut().zip(ts.iter()) {
+            *x = self.mul_mod(reduce_once(*x, p), reduce_once(y, p));
+        }
+        self.inverse(out);
+    }
+
+    // Computes `out.len()` times the cyclic convolution of `xs` with itself modulo `p`, writing it
+    // to `out`.
+    fn convolve_same(&self, out: &mut [u64], xs: &[Limb]) {
+        let p = self.p;
+        self.load(out, xs);
+        self.forward(out);
+        for x in out.iter_mut() {
+            let y = reduce_once(*x, p);
+            *x = self.mul_mod(y, y);
+        }
+        self.inverse(out);
+    }
+}
+
+// A constant $w < p$ together with $\lfloor 2^{64}w/p \rfloor$.
+#[derive(Clone, Copy)]
+struct ShoupConstant {
+    w: u64,
+    w_shoup: u64,
+}
+
+impl ShoupConstant {
+    fn new(w: u64, p: u64) -> ShoupConstant {
+        ShoupConstant {
+            w,
+            w_shoup: shoup_precompute(w, p),
+        }
+    }
+
+    // Returns $xw$ mod $p$.
+    #[inline]
+    fn mul(self, x: u64, p: u64) -> u64 {
+        reduce_once(mul_shoup_lazy(x, self.w, self.w_shoup, p), p)
+    }
+}
+
+// Constants for recovering a coefficient from its residues modulo the three primes, using Garner's
+// algorithm.
+struct FftSmallCrt {
+    p_0: u64,
+    p_1: u64,
+    p_2: u64,
+    // The inverse of the transform length modulo each prime; the transforms leave their outputs
+    // multiplied by the length.
+    len_inverses: [ShoupConstant; 3],
+    // p_0^(-1) mod p_1
+    inv_0_1: ShoupConstant,
+    // (p_0 p_1)^(-1) mod p_2
+    inv_01_2: ShoupConstant,
+    // p_0 mod p_2
+    p_0_mod_2: ShoupConstant,
+    // p_0 p_1, as two words
+    p_01_hi: u64,
+    p_01_lo: u64,
+}
+
+impl FftSmallCrt {
+    fn new(log_len: u64) -> FftSmallCrt {
+        let p_0 = FFT_SMALL_PRIMES[0].0;
+        let p_1 = FFT_SMALL_PRIMES[1].0;
+        let p_2 = FFT_SMALL_PRIMES[2].0;
+        let len_inverses = [p_0, p_1, p_2]
+            .map(|p| ShoupConstant::new(u64::power_of_2(log_len).mod_inverse(p).unwrap(), p));
+        // The primes are decreasing and close together, so a single subtraction reduces modulo a
+        // later prime.
+        let p_0_mod_2 = p_0 - p_2;
+        let inv_0_1 = ShoupConstant::new((p_0 - p_1).mod_inverse(p_1).unwrap(), p_1);
+        let inv_01_2 = ShoupConstant::new(
+            ShoupConstant::new(p_0_mod_2, p_2)
+                .mul(p_1 - p_2, p_2)
+                .mod_inverse(p_2)
+                .unwrap(),
+            p_2,
+        );
+        let (p_01_hi, p_01_lo) = u64::x_mul_y_to_zz(p_0, p_1);
+        FftSmallCrt {
+            p_0,
+            p_1,
+            p_2,
+            len_inverses,
+            inv_0_1,
+            inv_01_2,
+            p_0_mod_2: ShoupConstant::new(p_0_mod_2, p_2),
+            p_01_hi,
+            p_01_lo,
+        }
+    }
+
+    // Given the outputs of the three convolutions at some index, each less than 4 times its prime,
+    // returns the coefficient at that index, as three words in descending order.
+    fn combine(&self, r_0: u64, r_1: u64, r_2: u64) -> (u64, u64, u64) {
+        let (p_0, p_1, p_2) = (self.p_0, self.p_1, self.p_2);
+        let r_0 = self.len_inverses[0].mul(r_0, p_0);
+        let r_1 = self.len_inverses[1].mul(r_1, p_1);
+        let r_2 = self.len_inverses[2].mul(r_2, p_2);
+        // x_01 = r_0 + a_1 * p_0 < p_0 * p_1 is congruent to r_0 mod p_0 and to r_1 mod p_1
+        let a_1 = self.inv_0_1.mul(r_1 + p_1 - reduce_once(r_0, p_1), p_1);
+        let (x_01_hi, x_01_lo) = u64::x_mul_y_to_zz(a_1, p_0);
+        let (x_01_hi, x_01_lo) = u64::xx_add_yy_to_zz(x_01_hi, x_01_lo, 0, r_0);
+        let x_01_mod_2 = reduce_once(
+            reduce_once(r_0, p_2) + self.p_0_mod_2.mul(reduce_once(a_1, p_2), p_2),
+            p_2,
+        );
+        // x = x_01 + a_2 * p_0 * p_1
+        let a_2 = self.inv_01_2.mul(r_2 + p_2 - x_01_mod_2, p_2);
+        let (hi_hi, hi_lo) = u64::x_mul_y_to_zz(a_2, self.p_01_hi);
+        let (lo_hi, lo_lo) = u64::x_mul_y_to_zz(a_2, self.p_01_lo);
+        let (y_2, y_1, y_0) = u64::xxx_add_yyy_to_zzz(hi_hi, hi_lo, lo_lo, 0, lo_hi, 0);
+        u64::xxx_add_yyy_to_zzz(y_2, y_1, y_0, 0, x_01_hi, x_01_lo)
+    }
+}
+
+// Writes the 64-bit word with index `i` of a number to its limbs, `out`. Limbs past the end of
+// `out` are dropped.
+#[inline]
+fn limbs_fft_small_store_word(out: &mut [Limb], i: usize, word: u64) {
+    let start = i * WORD_LIMBS;
+    for (k, o) in out[start..].iter_mut().take(WORD_LIMBS).enumerate() {
+        *o = Limb::wrapping_from(word >> (u64::wrapping_from(k) * Limb::WIDTH));
+    }
+}
+
+// Recovers the coefficients of a product from their residues and adds them up, writing the
+// product to `out`.
+fn limbs_fft_small_combine(
+    out: &mut [Limb],
+    rss: &[Vec<u64>; 3],
+    log_len: u64,
+    coefficient_len: usize,
+) {
+    let crt = FftSmallCrt::new(log_len);
+    let word_len = (out.len() + WORD_LIMBS - 1) / WORD_LIMBS;
+    let [rs_0, rs_1, rs_2] = rss;
+    let coefficients = rs_0[..coefficient_len]
+        .iter()
+        .zip(&rs_1[..coefficient_len])
+        .zip(&rs_2[..coefficient_len])
+        .map(|((&r_0, &r_1), &r_2)| crt.combine(r_0, r_1, r_2))
+        .chain(repeat((0, 0, 0)));
+    let (mut carry_1, mut carry_0) = (0, 0);
+    for (i, (x_2, x_1, x_0)) in coefficients.take(word_len).enumerate() {
+        let (y_2, y_1, y_0) = u64::xxx_add_yyy_to_zzz(x_2, x_1, x_0, 0, carry_1, carry_0);
+        limbs_fft_small_store_word(out, i, y_0);
+        carry_1 = y_2;
+        carry_0 = y_1;
+    }
+    assert_eq!((carry_1, carry_0), (0, 0));
+}
+
+// Returns the base-2 logarithm of the transform length needed to multiply numbers of `xs_len` and
+// `ys_len` limbs.
+fn limbs_fft_small_log_len(xs_len: usize, ys_len: usize) -> u64 {
+    let xs_word_len = (xs_len + WORD_LIMBS - 1) / WORD_LIMBS;
+    let ys_word_len = (ys_len + WORD_LIMBS - 1) / WORD_LIMBS;
+    u64::wrapping_from(xs_word_len + ys_word_len - 1).ceiling_log_base_2()
+}
+
+// Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, writes
+// the `xs.len() + ys.len()` least-significant limbs of the product of the `Natural`s to an output
+// slice. The output must be at least as long as `xs.len() + ys.len()`, `xs` must be as least as
+// long as `ys`, and `ys` cannot be empty.
+//
+// The product is computed by splitting the inputs into 64-bit words, convolving the word sequences
+// modulo three word-sized primes with number-theoretic transforms, and recovering the exact
+// convolution with the Chinese remainder theorem. If the `parallel` feature is enabled, the three
+// convolutions run on separate threads.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is shorter than `ys`, `ys` is empty, or the product is too
+// large for the transform (more than $2^{40}$ words).
+//
+// This is equivalent to `mpn_mul_default_mpn_ctx` from `fft_small/mpn_mul.c`, FLINT 3.0.0, though
+// it uses fewer primes and a simpler transform.
+pub_crate_test! {limbs_mul_greater_to_out_fft_small(out: &mut [Limb], xs: &[Limb], ys: &[Limb]) {
+    let xs_len = xs.len();
+    let ys_len = ys.len();
+    assert!(xs_len >= ys_len);
+    assert_ne!(ys_len, 0);
+    let log_len = limbs_fft_small_log_len(xs_len, ys_len);
+    let len = 1 << log_len;
+    let mut rss = [vec![0; len], vec![0; len], vec![0; len]];
+    let [rs_0, rs_1, rs_2] = &mut rss;
+    limbs_fft_join(
+        || FftSmallPrime::new(0, log_len).convolve(rs_0, xs, ys),
+        || {
+            limbs_fft_join(
+                || FftSmallPrime::new(1, log_len).convolve(rs_1, xs, ys),
+                || FftSmallPrime::new(2, log_len).convolve(rs_2, xs, ys),
+            )
+        },
+    );
+    let coefficient_len =
+        (xs_len + WORD_LIMBS - 1) / WORD_LIMBS + (ys_len + WORD_LIMBS - 1) / WORD_LIMBS - 1;
+    limbs_fft_small_combine(&mut out[..xs_len + ys_len], &rss, log_len, coefficient_len);
+}}
+
+// Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, writes the
+// `2 * xs.len()` least-significant limbs of the square of the `Natural` to an output slice. The
+// output must be at least twice as long as `xs.len()`, and `xs` cannot be empty.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+//
+// # Panics
+// Panics if `out` is too short, `xs` is empty, or the square is too large for the transform (more
+// than $2^{40}$ words).
+//
+// This is equivalent to `mpn_mul_default_mpn_ctx` from `fft_small/mpn_mul.c`, FLINT 3.0.0, where
+// the two inputs are the same.
+pub_crate_test! {limbs_square_to_out_fft_small(out: &mut [Limb], xs: &[Limb]) {
+    let xs_len = xs.len();
+    assert_ne!(xs_len, 0);
+    let log_len = limbs_fft_small_log_len(xs_len, xs_len);
+    let len = 1 << log_len;
+    let mut rss = [vec![0; len], vec![0; len], vec![0; len]];
+    let [rs_0, rs_1, rs_2] = &mut rss;
+    limbs_fft_join(
+        || FftSmallPrime::new(0, log_len).convolve_same(rs_0, xs),
+        || {
+            limbs_fft_join(
+                || FftSmallPrime::new(1, log_len).convolve_same(rs_1, xs),
+                || FftSmallPrime::new(2, log_len).convolve_same(rs_2, xs),
+            )
+        },
+    );
+    let coefficient_len = (((xs_len + WORD_LIMBS - 1) / WORD_LIMBS) << 1) - 1;
+    limbs_fft_small_combine(&mut out[..xs_len << 1], &rss, log_len, coefficient_len);
+}}
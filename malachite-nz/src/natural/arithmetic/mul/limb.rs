@@ -1,6 +1,7 @@
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
+use alloc::vec::Vec;
 use malachite_base::num::arithmetic::traits::XMulYToZZ;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::SplitInHalf;
@@ -3,6 +3,7 @@ use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in
 use crate::natural::arithmetic::mul::fft::{
     limbs_mul_greater_to_out_fft, limbs_mul_greater_to_out_fft_scratch_len,
 };
+use crate::natural::arithmetic::mul::fft_small::limbs_mul_greater_to_out_fft_small;
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
 use crate::natural::arithmetic::mul::toom::MUL_TOOM33_THRESHOLD_LIMIT;
 use crate::natural::arithmetic::mul::toom::{
@@ -20,16 +21,17 @@ use crate::natural::arithmetic::mul::toom::{
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
-    Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM32_TO_TOOM43_THRESHOLD,
-    MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM42_TO_TOOM53_THRESHOLD,
-    MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD, MUL_TOOM6H_THRESHOLD,
-    MUL_TOOM8H_THRESHOLD,
+    Limb, MUL_FFT_SMALL_THRESHOLD, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD,
+    MUL_TOOM32_TO_TOOM43_THRESHOLD, MUL_TOOM32_TO_TOOM53_THRESHOLD, MUL_TOOM33_THRESHOLD,
+    MUL_TOOM42_TO_TOOM53_THRESHOLD, MUL_TOOM42_TO_TOOM63_THRESHOLD, MUL_TOOM44_THRESHOLD,
+    MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD,
 };
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::basic::traits::Zero;
-use std::cmp::max;
-use std::iter::Product;
-use std::ops::{Mul, MulAssign};
 
 // Interpreting two slices of `Limb`s as the limbs (in ascending order) of two `Natural`s, returns
 // the limbs of the product of the `Natural`s. `xs` must be as least as long as `ys` and `ys`
@@ -100,8 +102,10 @@ pub_crate_test! { limbs_mul_same_length_to_out_scratch_len(len: usize) -> usize
         limbs_mul_greater_to_out_toom_6h_scratch_len(len, len)
     } else if len < MUL_FFT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h_scratch_len(len, len)
-    } else {
+    } else if len << 1 < MUL_FFT_SMALL_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(len, len)
+    } else {
+        0
     }
 }}
 
@@ -143,8 +147,10 @@ pub_crate_test! {limbs_mul_same_length_to_out(
         limbs_mul_greater_to_out_toom_6h(out, xs, ys, scratch);
     } else if len < MUL_FFT_THRESHOLD {
         limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
-    } else {
+    } else if len << 1 < MUL_FFT_SMALL_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_fft_small(out, xs, ys);
     }
 }}
 
@@ -236,8 +242,10 @@ pub_crate_test! { limbs_mul_greater_to_out_scratch_len(xs_len: usize, ys_len: us
         } else {
             limbs_mul_greater_to_out_toom_8h_scratch_len(xs_len, ys_len)
         }
-    } else {
+    } else if xs_len + ys_len < MUL_FFT_SMALL_THRESHOLD {
         limbs_mul_greater_to_out_fft_scratch_len(xs_len, ys_len)
+    } else {
+        0
     }
 }}
 
@@ -386,8 +394,10 @@ pub_crate_test! {limbs_mul_greater_to_out(
         } else {
             limbs_mul_greater_to_out_toom_8h(out, xs, ys, scratch);
         }
-    } else {
+    } else if xs_len + ys_len < MUL_FFT_SMALL_THRESHOLD {
         limbs_mul_greater_to_out_fft(out, xs, ys, scratch);
+    } else {
+        limbs_mul_greater_to_out_fft_small(out, xs, ys);
     }
     out[xs_len + ys_len - 1]
 }}
@@ -810,6 +820,9 @@ impl<'a> Product<&'a Natural> for Natural {
 
 /// Code for the Schönhage-Strassen (FFT) multiplication algorithm.
 pub mod fft;
+/// Code for multiplying very large [`Natural`]s using number-theoretic transforms modulo several
+/// word-sized primes.
+pub mod fft_small;
 /// Code for multiplying a many-limbed [`Natural`] by a single [limb](crate#limbs).
 pub mod limb;
 /// Code for computing only the lowest [limbs](crate#limbs) of the product of two [`Natural`]s.
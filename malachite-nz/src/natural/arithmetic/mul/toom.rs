@@ -5,6 +5,7 @@ use crate::natural::arithmetic::add::{
     limbs_slice_add_same_length_in_place_left,
 };
 use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in_place_left;
+use crate::natural::arithmetic::mul::fft::limbs_fft_join;
 use crate::natural::arithmetic::mul::poly_eval::{
     limbs_mul_toom_evaluate_deg_3_poly_in_1_and_neg_1,
     limbs_mul_toom_evaluate_deg_3_poly_in_2_and_neg_2, limbs_mul_toom_evaluate_poly_in_1_and_neg_1,
@@ -34,10 +35,15 @@ use crate::natural::arithmetic::sub::{
     limbs_sub_same_length_with_borrow_in_to_out,
 };
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+#[cfg(feature = "parallel")]
+use crate::platform::MUL_TOOM_PARALLEL_THRESHOLD;
 use crate::platform::{
     Limb, MUL_FFT_THRESHOLD, MUL_TOOM22_THRESHOLD, MUL_TOOM33_THRESHOLD, MUL_TOOM44_THRESHOLD,
     MUL_TOOM6H_THRESHOLD, MUL_TOOM8H_THRESHOLD,
 };
+use core::cmp::{max, Ordering};
+#[cfg(all(feature = "parallel", feature = "bin_build"))]
+use core::sync::atomic::{self, AtomicUsize};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, DivRound, EqModPowerOf2, ShrRound, WrappingAddAssign, WrappingSubAssign,
@@ -46,7 +52,6 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::logic::traits::NotAssign;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::slices::{slice_set_zero, slice_test_zero};
-use std::cmp::{max, Ordering};
 
 //TODO tune
 pub(crate) const MUL_TOOM33_THRESHOLD_LIMIT: usize = MUL_TOOM33_THRESHOLD;
@@ -2959,6 +2964,52 @@ pub_test! {limbs_mul_greater_to_out_toom_6h_input_sizes_valid(
     }
 }}
 
+// When building the tuning program, the threshold can be changed at run time, so that the serial
+// and parallel code can be timed against each other.
+#[cfg(all(feature = "parallel", feature = "bin_build"))]
+pub static MUL_TOOM_PARALLEL_THRESHOLD_FOR_TUNING: AtomicUsize =
+    AtomicUsize::new(MUL_TOOM_PARALLEL_THRESHOLD);
+
+// Whether the two products of `n`-limb numbers at a pair of Toom evaluation points should be
+// computed on separate threads.
+#[cfg(feature = "parallel")]
+#[inline]
+fn limbs_toom_is_parallel(n: usize) -> bool {
+    #[cfg(not(feature = "bin_build"))]
+    let threshold = MUL_TOOM_PARALLEL_THRESHOLD;
+    #[cfg(feature = "bin_build")]
+    let threshold = MUL_TOOM_PARALLEL_THRESHOLD_FOR_TUNING.load(atomic::Ordering::Relaxed);
+    n >= threshold
+}
+
+#[cfg(not(feature = "parallel"))]
+#[inline]
+const fn limbs_toom_is_parallel(_n: usize) -> bool {
+    false
+}
+
+// Computes the two products of `n`-limb numbers at a pair of Toom evaluation points, by calling
+// `f` and `g` with scratch space. The products must be written to disjoint parts of the output.
+// If the `parallel` feature is enabled and `n` is at least `MUL_TOOM_PARALLEL_THRESHOLD`, `f` and
+// `g` run on separate threads, and `g` gets its own scratch space, as long as `scratch`.
+pub(crate) fn limbs_toom_evaluation_pair<
+    F: FnOnce(&mut [Limb]) + Send,
+    G: FnOnce(&mut [Limb]) + Send,
+>(
+    n: usize,
+    scratch: &mut [Limb],
+    f: F,
+    g: G,
+) {
+    if limbs_toom_is_parallel(n) {
+        let mut scratch_2 = vec![0; scratch.len()];
+        limbs_fft_join(|| f(scratch), || g(&mut scratch_2));
+    } else {
+        f(scratch);
+        g(scratch);
+    }
+}
+
 //TODO tune
 const TOOM_6H_MAYBE_MUL_BASECASE: bool =
     TUNE_PROGRAM_BUILD || MUL_TOOM6H_THRESHOLD < 6 * MUL_TOOM22_THRESHOLD;
@@ -3206,10 +3257,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
             );
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r5, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r5, v2, v3, scratch),
+    );
     if half {
         limbs_toom_couple_handling(r5, &mut out_lo[..r], v_neg_half_neg, n, 2, 1);
     } else {
@@ -3228,10 +3282,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
     }
     // X(-1) * Y(-1)
     // X(1) * Y(1)
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r3, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r3, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut out_lo[..r], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let out_lo_lo = &mut out_lo[..m];
@@ -3239,10 +3296,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
         limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, p, xs, n, 2, out_lo_lo)
             != limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v3, v1, q, ys, n, 2, out_lo_lo);
     // X(-4) * Y(-4)
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r1, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r1, v2, v3, scratch),
+    );
     // X(4) * B(4)
     limbs_toom_couple_handling(r1, &mut out_lo[..r], v_neg_4_neg, n, 2, 4);
     // 1/4, -1/4
@@ -3254,10 +3314,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_6h(
             );
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_6h_recursive(r4, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(out_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_6h_recursive(r4, v2, v3, scratch),
+    );
     if half {
         limbs_toom_couple_handling(r4, &mut out_lo[..r], v_neg_quarter_neg, n, 4, 2);
     } else {
@@ -3693,10 +3756,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/8) * Y(-1/8) * 8^
     // X(1/8) * Y(1/8) * 8^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r7, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r7, v2, v3, scratch),
+    );
     let limit = if BIT_CORRECTION { m << 1 } else { u };
     let pp_lo_lo = &mut pp_lo[..limit];
     if half {
@@ -3715,10 +3781,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/4) * Y(-1/4) * 4^
     // X(1/4) * Y(1/4) * 4^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r5, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r5, v2, v3, scratch),
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r5, pp_lo_lo, v_neg_quarter_neg, n, 4, 2);
@@ -3731,10 +3800,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
         != limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v3, v1, q, ys, n, pp_lo_lo);
     // X(-2) * Y(-2)
     // X(2) * Y(2)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r3, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r3, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut pp_lo[..u], v_neg_2_neg, n, 1, 2);
     // 8, -8
     let pp_lo_lo = &mut pp_lo[..m];
@@ -3743,10 +3815,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             != limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v3, v1, q, ys, n, 3, pp_lo_lo);
     // X(-8) * Y(-8)
     // X(8) * Y(8)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r1, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r1, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(
         &mut scratch[3 * r..],
         &mut pp_lo[..limit],
@@ -3765,10 +3840,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
             );
     // X(-1/2) * Y(-1/2) * 2^
     // X(1/2) * Y(1/2) * 2^
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r6, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r6, v2, v3, scratch),
+    );
     let pp_lo_lo = &mut pp_lo[..u];
     if half {
         limbs_toom_couple_handling(r6, pp_lo_lo, v_neg_half_neg, n, 2, 1);
@@ -3787,10 +3865,13 @@ pub_crate_test! {limbs_mul_greater_to_out_toom_8h(
     }
     // X(-1) * Y(-1)
     // X(1) * Y(1)
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, mul_scratch);
-    // size: m
-    limbs_mul_same_length_to_out_toom_8h_recursive(r4, v2, v3, mul_scratch);
+    // size: m, m
+    limbs_toom_evaluation_pair(
+        m,
+        mul_scratch,
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(pp_lo, v0, v1, scratch),
+        |scratch| limbs_mul_same_length_to_out_toom_8h_recursive(r4, v2, v3, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut pp_lo[..u], v_neg_1_neg, n, 0, 0);
     // 4, -4
     let pp_lo_lo = &mut pp_lo[..m];
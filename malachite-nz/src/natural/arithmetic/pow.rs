@@ -12,6 +12,8 @@ use crate::natural::Natural;
 #[cfg(feature = "test_build")]
 use crate::platform::DoubleLimb;
 use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{
     EqModPowerOf2, Parity, Pow, PowAssign, Square, SquareAssign,
 };
@@ -30,7 +32,6 @@ use malachite_base::num::logic::traits::{
 #[cfg(feature = "test_build")]
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_base::slices::slice_leading_zeros;
-use std::mem::swap;
 
 /// This is equivalent to `GMP_NUMB_HALFMAX` from `mpz/n_pow_ui.c`, GMP 6.2.1.
 const HALF_MAX: Limb = (1 << (Limb::WIDTH >> 1)) - 1;
@@ -1,6 +1,6 @@
 use crate::natural::Natural;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::Sign;
-use std::cmp::Ordering;
 
 impl Sign for Natural {
     /// Compares a [`Natural`] to zero.
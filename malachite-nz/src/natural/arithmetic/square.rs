@@ -7,6 +7,7 @@ use crate::natural::arithmetic::add_mul::limbs_slice_add_mul_limb_same_length_in
 use crate::natural::arithmetic::mul::fft::{
     limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len,
 };
+use crate::natural::arithmetic::mul::fft_small::limbs_square_to_out_fft_small;
 use crate::natural::arithmetic::mul::limb::limbs_mul_limb_to_out;
 use crate::natural::arithmetic::mul::limbs_mul_greater_to_out_basecase;
 use crate::natural::arithmetic::mul::poly_eval::{
@@ -21,7 +22,8 @@ use crate::natural::arithmetic::mul::poly_interpolate::{
     limbs_mul_toom_interpolate_5_points, limbs_mul_toom_interpolate_7_points,
 };
 use crate::natural::arithmetic::mul::toom::{
-    limbs_toom_couple_handling, BIT_CORRECTION, TUNE_PROGRAM_BUILD, WANT_FAT_BINARY,
+    limbs_toom_couple_handling, limbs_toom_evaluation_pair, BIT_CORRECTION, TUNE_PROGRAM_BUILD,
+    WANT_FAT_BINARY,
 };
 use crate::natural::arithmetic::shl::{limbs_shl_to_out, limbs_slice_shl_in_place};
 use crate::natural::arithmetic::sub::{
@@ -31,9 +33,11 @@ use crate::natural::comparison::cmp::limbs_cmp_same_length;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::{
-    DoubleLimb, Limb, SQR_BASECASE_THRESHOLD, SQR_TOOM2_THRESHOLD, SQR_TOOM3_THRESHOLD,
-    SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
+    DoubleLimb, Limb, MUL_FFT_SMALL_THRESHOLD, SQR_BASECASE_THRESHOLD, SQR_TOOM2_THRESHOLD,
+    SQR_TOOM3_THRESHOLD, SQR_TOOM4_THRESHOLD, SQR_TOOM6_THRESHOLD, SQR_TOOM8_THRESHOLD,
 };
+use alloc::vec::Vec;
+use core::cmp::{max, Ordering};
 use malachite_base::fail_on_untested_path;
 use malachite_base::num::arithmetic::traits::{
     ArithmeticCheckedShl, DivRound, ShrRound, Square, SquareAssign, WrappingAddAssign,
@@ -43,7 +47,6 @@ use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::{SplitInHalf, WrappingFrom};
 use malachite_base::rounding_modes::RoundingMode;
-use std::cmp::{max, Ordering};
 
 const SQR_FFT_MODF_THRESHOLD: usize = SQR_TOOM3_THRESHOLD * 3;
 
@@ -684,18 +687,36 @@ pub_test! {limbs_square_to_out_toom_6(out: &mut [Limb], xs: &[Limb], scratch: &m
         &mut out_lo[..m],
     );
     split_into_chunks_mut!(scratch, 3 * n + 1, [r5, r3, r1], wse);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1/2) ^ 2 * 2 ^
-    limbs_square_to_out_toom_6_recursive(r5, v2, wse); // X(1/2) ^ 2 * 2 ^
+    // X(-1/2) ^ 2 * 2 ^
+    // X(1/2) ^ 2 * 2 ^
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_6_recursive(r5, v2, scratch),
+    );
     limbs_toom_couple_handling(r5, &mut out_lo[..k], false, n, 1, 0);
     // +/- 1
     limbs_mul_toom_evaluate_poly_in_1_and_neg_1(v2, v0, 5, xs, n, &mut out_lo[..m]);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1) ^ 2
-    limbs_square_to_out_toom_6_recursive(r3, v2, wse); // X(1) ^ 2
+    // X(-1) ^ 2
+    // X(1) ^ 2
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_6_recursive(r3, v2, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut out_lo[..k], false, n, 0, 0);
     // +/- 4
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 5, xs, n, 2, &mut out_lo[..m]);
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-4) ^ 2
-    limbs_square_to_out_toom_6_recursive(r1, v2, wse); // X(4) ^ 2
+    // X(-4) ^ 2
+    // X(4) ^ 2
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_6_recursive(r1, v2, scratch),
+    );
     limbs_toom_couple_handling(r1, &mut out_lo[..k], false, n, 2, 4);
     // +/- 1/4
     limbs_mul_toom_evaluate_poly_in_2_pow_neg_and_neg_2_pow_neg(
@@ -707,8 +728,14 @@ pub_test! {limbs_square_to_out_toom_6(out: &mut [Limb], xs: &[Limb], scratch: &m
         2,
         &mut out_lo[..m],
     );
-    limbs_square_to_out_toom_6_recursive(out_lo, v0, wse); // X(-1/4) ^ 2 * 4 ^
-    limbs_square_to_out_toom_6_recursive(r4, v2, wse); // X(1/4) ^ 2 * 4 ^
+    // X(-1/4) ^ 2 * 4 ^
+    // X(1/4) ^ 2 * 4 ^
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_6_recursive(out_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_6_recursive(r4, v2, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut out_lo[..k], false, n, 2, 0);
     // +/- 2
     limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v2, v0, 5, xs, n, &mut out_lo[..m]);
@@ -873,8 +900,12 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
     let (r3, r1_wse) = remainder.split_at_mut(p);
     let (r1, wse) = r1_wse.split_at_mut(p);
     // A(-1/8) * B(-1/8) * 8 ^, A(1/8) * B(1/8) * 8 ^
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r7_r5, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r7_r5, v2, scratch),
+    );
     let limit = if BIT_CORRECTION { m << 1 } else { k };
     limbs_toom_couple_handling(r7_r5, &mut pp_lo[..limit], false, n, 3, 0);
     // +/- 1/4
@@ -888,21 +919,33 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
         &mut pp_lo[..m],
     );
     // A(-1/4) * B(-1/4) * 4 ^, A(1/4) * B(1/4) * 4^
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
     let (r7, r5) = r7_r5.split_at_mut(p);
-    limbs_square_to_out_toom_8_recursive(r5, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r5, v2, scratch),
+    );
     limbs_toom_couple_handling(r5, &mut pp_lo[..k], false, n, 2, 0);
     // +/- 2
     limbs_mul_toom_evaluate_poly_in_2_and_neg_2(v2, v0, 7, xs, n, &mut pp_lo[..m]);
     // A(-2)*B(-2), A(+2)*B(+2)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r3, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r3, v2, scratch),
+    );
     limbs_toom_couple_handling(r3, &mut pp_lo[..k], false, n, 1, 2);
     // +/- 8
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 7, xs, n, 3, &mut pp_lo[..m]);
     // A(-8) * B(-8), A(8) * B(8)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r1, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r1, v2, scratch),
+    );
     limbs_toom_couple_handling(r1_wse, &mut pp_lo[..limit], false, n, 3, 6);
     // +/- 1/2
     limbs_mul_toom_evaluate_poly_in_2_pow_neg_and_neg_2_pow_neg(
@@ -916,14 +959,22 @@ pub_test! {limbs_square_to_out_toom_8(out: &mut [Limb], xs: &[Limb], scratch: &m
     );
     // A(-1/2) * B(-1/2) * 2 ^, A(1/2) * B(1/2) * 2 ^
     let (r1, wse) = r1_wse.split_at_mut(p);
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r6, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r6, v2, scratch),
+    );
     limbs_toom_couple_handling(r6, &mut pp_lo[..k], false, n, 1, 0);
     // +/- 1
     limbs_mul_toom_evaluate_poly_in_1_and_neg_1(v2, v0, 7, xs, n, &mut pp_lo[..m]);
     // A(-1) * B(-1), A(1) * B(1)
-    limbs_square_to_out_toom_8_recursive(pp_lo, v0, wse);
-    limbs_square_to_out_toom_8_recursive(r4, v2, wse);
+    limbs_toom_evaluation_pair(
+        m,
+        wse,
+        |scratch| limbs_square_to_out_toom_8_recursive(pp_lo, v0, scratch),
+        |scratch| limbs_square_to_out_toom_8_recursive(r4, v2, scratch),
+    );
     limbs_toom_couple_handling(r4, &mut pp_lo[..k], false, n, 0, 0);
     // +/- 4
     limbs_mul_toom_evaluate_poly_in_2_pow_and_neg_2_pow(v2, v0, 7, xs, n, 2, &mut pp_lo[..m]);
@@ -952,8 +1003,10 @@ limbs_square_to_out_scratch_len(n: usize) -> usize {
         limbs_square_to_out_toom_6_scratch_len(n)
     } else if n < SQR_FFT_THRESHOLD {
         limbs_square_to_out_toom_8_scratch_len(n)
-    } else {
+    } else if n << 1 < MUL_FFT_SMALL_THRESHOLD {
         limbs_square_to_out_fft_scratch_len(n)
+    } else {
+        0
     }
 }}
 
@@ -986,8 +1039,10 @@ limbs_square_to_out(out: &mut [Limb], xs: &[Limb], scratch: &mut [Limb]) {
         limbs_square_to_out_toom_6(out, xs, scratch);
     } else if n < SQR_FFT_THRESHOLD {
         limbs_square_to_out_toom_8(out, xs, scratch);
-    } else {
+    } else if n << 1 < MUL_FFT_SMALL_THRESHOLD {
         limbs_square_to_out_fft(out, xs, scratch);
+    } else {
+        limbs_square_to_out_fft_small(out, xs);
     }
 }}
 
@@ -1,6 +1,6 @@
 use crate::natural::Natural;
+use core::cmp::Ordering;
 use malachite_base::num::comparison::traits::PartialOrdAbs;
-use std::cmp::Ordering;
 
 macro_rules! impl_unsigned {
     ($t: ident) => {
@@ -0,0 +1,82 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::basic::integers::PrimitiveInt;
+
+// Converts bytes, in ascending order, to limbs in ascending order. If the number of bytes is not
+// a multiple of the number of bytes in a `Limb`, the last limb is padded with `pad`.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+pub(crate) fn bytes_asc_to_limbs(xs: &[u8], pad: u8) -> Vec<Limb> {
+    const LIMB_BYTES: usize = (Limb::WIDTH >> 3) as usize;
+    xs.chunks(LIMB_BYTES)
+        .map(|chunk| {
+            let mut bytes = [pad; LIMB_BYTES];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            Limb::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+impl Natural {
+    /// Converts a slice of bytes in little-endian order to a [`Natural`], so that
+    /// less-significant bytes have lower indices in the input slice.
+    ///
+    /// Trailing zero bytes are allowed. The interpretation of the input does not depend on the
+    /// width of a [`Limb`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_le(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_le(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_le(&[0, 1, 0, 0]), 256);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from_bytes_le(&[0, 16, 165, 212, 232]), 1000000000000u64);
+    /// ```
+    pub fn from_bytes_le(xs: &[u8]) -> Natural {
+        Natural::from_owned_limbs_asc(bytes_asc_to_limbs(xs, 0))
+    }
+
+    /// Converts a slice of bytes in big-endian order to a [`Natural`], so that less-significant
+    /// bytes have higher indices in the input slice.
+    ///
+    /// Leading zero bytes are allowed. The interpretation of the input does not depend on the
+    /// width of a [`Limb`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from_bytes_be(&[]), 0);
+    /// assert_eq!(Natural::from_bytes_be(&[123]), 123);
+    /// assert_eq!(Natural::from_bytes_be(&[0, 0, 1, 0]), 256);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from_bytes_be(&[232, 212, 165, 16, 0]), 1000000000000u64);
+    /// ```
+    pub fn from_bytes_be(xs: &[u8]) -> Natural {
+        let mut bytes = xs.to_vec();
+        bytes.reverse();
+        Natural::from_bytes_le(&bytes)
+    }
+}
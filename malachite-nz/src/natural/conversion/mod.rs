@@ -3,6 +3,8 @@ pub mod digits;
 /// An implementation of the [`From`] trait for converting a [`bool`] to a
 /// [`Natural`](crate::natural::Natural).
 pub mod from_bool;
+/// Functions for constructing a [`Natural`](crate::natural::Natural) from bytes.
+pub mod from_bytes;
 /// Functions for constructing a [`Natural`](crate::natural::Natural) from [`Limb`](crate#limbs)s.
 pub mod from_limbs;
 /// Implementations of traits for converting a primitive float to a
@@ -457,10 +459,13 @@ pub mod primitive_float_from_natural;
 /// ```
 pub mod primitive_int_from_natural;
 /// Implementations of traits for serialization and deserialization using
-/// [serde](https://serde.rs/).
+/// [serde](https://serde.rs/), and a compact binary representation for use with serde's `with`
+/// attribute.
 pub mod serde;
 /// Implementations of traits for converting [`Natural`](crate::natural::Natural)s to and from
 /// [`String`]s.
 pub mod string;
+/// Functions for extracting bytes from a [`Natural`](crate::natural::Natural).
+pub mod to_bytes;
 /// Functions for extracting [`Limb`](crate#limbs)s from a [`Natural`](crate::natural::Natural).
 pub mod to_limbs;
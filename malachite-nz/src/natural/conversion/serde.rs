@@ -1,6 +1,13 @@
 use crate::natural::{Natural, SerdeNatural};
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+#[cfg(feature = "serde")]
+use core::fmt::{self, Formatter};
 use malachite_base::num::conversion::traits::FromStringBase;
-use std::convert::TryFrom;
+#[cfg(feature = "serde")]
+use serde::de::{self, SeqAccess, Visitor};
 
 impl From<Natural> for SerdeNatural {
     #[inline]
@@ -22,3 +29,87 @@ impl TryFrom<SerdeNatural> for Natural {
         }
     }
 }
+
+/// A compact binary representation of [`Natural`]s, for use with serde's `with` attribute.
+///
+/// By default, a [`Natural`] is serialized as a hexadecimal string. With this module, it is
+/// serialized as its bytes in little-endian order instead (see [`Natural::to_bytes_le`]), which
+/// keeps the output of binary formats like bincode or CBOR small and avoids a base conversion.
+///
+/// # Examples
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Point {
+///     #[serde(with = "malachite_nz::natural::conversion::serde::compact")]
+///     x: Natural,
+///     #[serde(with = "malachite_nz::natural::conversion::serde::compact")]
+///     y: Natural,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod compact {
+    use super::BytesVisitor;
+    use crate::natural::Natural;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes a [`Natural`] as its bytes in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    #[inline]
+    pub fn serialize<S: Serializer>(x: &Natural, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&x.to_bytes_le())
+    }
+
+    /// Deserializes a [`Natural`] from its bytes in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Natural, D::Error> {
+        Ok(Natural::from_bytes_le(
+            &deserializer.deserialize_bytes(BytesVisitor)?,
+        ))
+    }
+}
+
+// Accepts a byte string, or, for formats like JSON that have no byte strings, a sequence of
+// bytes.
+#[cfg(feature = "serde")]
+pub(crate) struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: de::Error>(self, xs: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(xs.to_vec())
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, xs: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(xs)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        let mut xs = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(x) = seq.next_element()? {
+            xs.push(x);
+        }
+        Ok(xs)
+    }
+}
@@ -1,11 +1,11 @@
 use crate::natural::Natural;
 use crate::platform::Limb;
+use core::str::FromStr;
 use malachite_base::num::arithmetic::traits::{ModPowerOf2, ShrRound};
 use malachite_base::num::basic::integers::PrimitiveInt;
 use malachite_base::num::conversion::string::from_string::digit_from_display_byte;
 use malachite_base::num::conversion::traits::{Digits, ExactFrom, FromStringBase, WrappingFrom};
 use malachite_base::rounding_modes::RoundingMode;
-use std::str::FromStr;
 
 impl FromStr for Natural {
     type Err = ();
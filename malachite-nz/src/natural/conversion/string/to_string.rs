@@ -5,6 +5,10 @@ use crate::natural::logic::significant_bits::limbs_significant_bits;
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
+use alloc::string::String;
+#[cfg(feature = "test_build")]
+use core::fmt::Write;
+use core::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex};
 #[cfg(feature = "test_build")]
 use itertools::Itertools;
 use malachite_base::num::arithmetic::traits::{DivRound, Parity, ShrRound};
@@ -18,9 +22,6 @@ use malachite_base::num::conversion::traits::{Digits, ExactFrom, ToStringBase, W
 #[cfg(feature = "test_build")]
 use malachite_base::num::logic::traits::{BitIterable, SignificantBits};
 use malachite_base::rounding_modes::RoundingMode;
-#[cfg(feature = "test_build")]
-use std::fmt::Write;
-use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, Result, UpperHex};
 
 /// A `struct` that allows for formatting a [`Natural`] or [`Integer`](crate::integer::Integer) and
 /// rendering its digits in a specified base.
@@ -124,7 +125,7 @@ impl<'a> Display for BaseFmtWrapper<&'a Natural> {
                     *digit = digit_to_display_byte_lower(*digit).unwrap();
                 }
             }
-            f.pad_integral(true, "", std::str::from_utf8(&digits).unwrap())
+            f.pad_integral(true, "", core::str::from_utf8(&digits).unwrap())
         }
     }
 }
@@ -276,7 +277,7 @@ impl Display for Natural {
                 for digit in &mut digits {
                     *digit = digit_to_display_byte_lower(*digit).unwrap();
                 }
-                f.pad_integral(true, "", std::str::from_utf8(&digits).unwrap())
+                f.pad_integral(true, "", core::str::from_utf8(&digits).unwrap())
             }
         }
     }
@@ -432,7 +433,7 @@ impl Binary for Natural {
                     limb >>= 1;
                     remaining_bits -= 1;
                 }
-                f.pad_integral(true, "0b", std::str::from_utf8(&bits).unwrap())
+                f.pad_integral(true, "0b", core::str::from_utf8(&bits).unwrap())
             }
         }
     }
@@ -652,7 +653,7 @@ impl Octal for Natural {
                         }
                     }
                 }
-                f.pad_integral(true, "0o", std::str::from_utf8(&digits).unwrap())
+                f.pad_integral(true, "0o", core::str::from_utf8(&digits).unwrap())
             }
         }
     }
@@ -782,7 +783,7 @@ impl LowerHex for Natural {
                     limb >>= 4;
                     remaining_digits -= 1;
                 }
-                f.pad_integral(true, "0x", std::str::from_utf8(&digits).unwrap())
+                f.pad_integral(true, "0x", core::str::from_utf8(&digits).unwrap())
             }
         }
     }
@@ -848,7 +849,7 @@ impl UpperHex for Natural {
                     limb >>= 4;
                     remaining_digits -= 1;
                 }
-                f.pad_integral(true, "0x", std::str::from_utf8(&digits).unwrap())
+                f.pad_integral(true, "0x", core::str::from_utf8(&digits).unwrap())
             }
         }
     }
@@ -0,0 +1,82 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+
+// Converts limbs, in ascending order, to bytes in ascending order. There are no trailing zero
+// bytes, provided that `xs` has no trailing zero limbs.
+//
+// # Worst-case complexity
+// $T(n) = O(n)$
+//
+// $M(n) = O(n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+pub(crate) fn limbs_to_bytes_asc(xs: &[Limb]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = xs.iter().flat_map(|x| x.to_le_bytes()).collect();
+    while bytes.last() == Some(&0) {
+        bytes.pop();
+    }
+    bytes
+}
+
+impl Natural {
+    /// Returns the bytes of a [`Natural`] in little-endian order, so that less-significant bytes
+    /// have lower indices in the output vector.
+    ///
+    /// There are no trailing zero bytes; in particular, 0 is represented by an empty vector. The
+    /// output does not depend on the width of a [`Limb`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_le().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_le(), &[123]);
+    /// assert_eq!(Natural::from(256u32).to_bytes_le(), &[0, 1]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from(10u32).pow(12).to_bytes_le(), &[0, 16, 165, 212, 232]);
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        limbs_to_bytes_asc(&self.to_limbs_asc())
+    }
+
+    /// Returns the bytes of a [`Natural`] in big-endian order, so that less-significant bytes
+    /// have higher indices in the output vector.
+    ///
+    /// There are no leading zero bytes; in particular, 0 is represented by an empty vector. The
+    /// output does not depend on the width of a [`Limb`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert!(Natural::ZERO.to_bytes_be().is_empty());
+    /// assert_eq!(Natural::from(123u32).to_bytes_be(), &[123]);
+    /// assert_eq!(Natural::from(256u32).to_bytes_be(), &[1, 0]);
+    /// // 10^12 = 0xe8d4a51000
+    /// assert_eq!(Natural::from(10u32).pow(12).to_bytes_be(), &[232, 212, 165, 16, 0]);
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+}
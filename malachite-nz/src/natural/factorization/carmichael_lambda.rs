@@ -0,0 +1,36 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{LcmAssign, Pow, PowerOf2};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor};
+
+impl CarmichaelLambda for Natural {
+    /// Computes the Carmichael function of a [`Natural`]: the smallest positive exponent $e$ such
+    /// that $a^e \equiv 1 \mod n$ for every $a$ coprime to $n$.
+    ///
+    /// $\lambda(n)$ is the least common multiple of $\lambda(p^k)$ over the prime powers $p^k$
+    /// exactly dividing $n$, where $\lambda(2^k) = 2^{k-2}$ for $k \geq 3$ and $\lambda(p^k) =
+    /// \varphi(p^k)$ otherwise.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by that of [`Factor::factor`].
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::carmichael_lambda#carmichael_lambda).
+    fn carmichael_lambda(&self) -> Natural {
+        assert_ne!(*self, 0u32, "Cannot take the Carmichael function of 0");
+        let mut lambda = Natural::ONE;
+        for (p, k) in self.factor() {
+            // λ(2^k) = φ(2^k) / 2 when k >= 3; otherwise, λ(p^k) = φ(p^k).
+            let lambda_p = if p == Natural::TWO && k >= 3 {
+                Natural::power_of_2(k - 2)
+            } else {
+                (&p).pow(k - 1) * (p - Natural::ONE)
+            };
+            lambda.lcm_assign(lambda_p);
+        }
+        lambda
+    }
+}
@@ -0,0 +1,24 @@
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::{DivisorCount, Factor};
+
+impl DivisorCount for Natural {
+    /// Counts the positive divisors of a [`Natural`].
+    ///
+    /// $$
+    /// f(n) = d(n) = \prod_{p^k \\| n} (k + 1),
+    /// $$
+    /// where the product is taken over the prime powers exactly dividing $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by that of [`Factor::factor`].
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisor_count#divisor_count).
+    fn divisor_count(&self) -> u64 {
+        assert_ne!(*self, 0u32, "Cannot count the divisors of 0");
+        self.factor().iter().map(|&(_, k)| k + 1).product()
+    }
+}
@@ -0,0 +1,43 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{DivisorSum, Factor};
+
+impl DivisorSum for Natural {
+    /// Computes the sum of the $k$th powers of the positive divisors of a [`Natural`].
+    ///
+    /// $$
+    /// f(n, k) = \sigma_k(n) = \sum_{d | n} d^k.
+    /// $$
+    ///
+    /// In particular, $\sigma_0(n)$ is the number of divisors of $n$ and $\sigma_1(n)$ is their
+    /// sum.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by that of [`Factor::factor`], unless $k$ is large.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisor_sum#divisor_sum).
+    fn divisor_sum(&self, k: u64) -> Natural {
+        assert_ne!(*self, 0u32, "Cannot sum the divisors of 0");
+        let mut sum = Natural::ONE;
+        for (p, e) in self.factor() {
+            if k == 0 {
+                sum *= Natural::from(e + 1);
+            } else {
+                // 1 + q + q^2 + ... + q^e, where q = p^k
+                let q = p.pow(k);
+                let mut p_sum = Natural::ONE;
+                for _ in 0..e {
+                    p_sum *= &q;
+                    p_sum += Natural::ONE;
+                }
+                sum *= p_sum;
+            }
+        }
+        sum
+    }
+}
@@ -0,0 +1,25 @@
+use crate::natural::Natural;
+use malachite_base::num::factorization::divisors::{divisors_from_factorization, DivisorsIterator};
+use malachite_base::num::factorization::traits::{Divisors, Factor};
+
+impl Divisors for Natural {
+    type I = DivisorsIterator<Natural>;
+
+    /// Returns an iterator that generates the positive divisors of a [`Natural`].
+    ///
+    /// The divisors are not generated in ascending order; see [`DivisorsIterator`] for details.
+    ///
+    /// # Worst-case complexity
+    /// Creating the iterator takes as long as [`Factor::factor`]. Each iteration then takes
+    /// $O(n)$ time and additional memory, where $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisors).
+    fn divisors(&self) -> DivisorsIterator<Natural> {
+        assert_ne!(*self, 0u32, "Cannot generate the divisors of 0");
+        divisors_from_factorization(self.factor())
+    }
+}
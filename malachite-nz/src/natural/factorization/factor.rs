@@ -0,0 +1,306 @@
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, DivExact, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModInverse, ModMul, ModPow,
+    ModSquare, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+
+// Primes less than this are removed by trial division before any other method is tried.
+const TRIAL_DIVISION_LIMIT: Limb = 1 << 12;
+
+// The smoothness bound used by stage 1 of Pollard's p - 1 algorithm.
+const POLLARD_P_MINUS_1_BOUND: Limb = 10000;
+
+// The number of steps Brent's variant of Pollard's rho takes between gcd computations.
+const POLLARD_RHO_BATCH_SIZE: u64 = 128;
+
+// The number of iterations of Pollard's rho that are tried before falling back to ECM.
+const POLLARD_RHO_MAX_ITERATIONS: u64 = 1 << 14;
+
+// The stage 1 bound used by the first ECM curves. Every `ECM_CURVES_PER_BOUND` curves, the bound
+// is increased by this amount.
+const ECM_INITIAL_BOUND: Limb = 2000;
+
+const ECM_CURVES_PER_BOUND: u64 = 16;
+
+fn u64_factors_to_natural_factors(
+    factors: Vec<(u64, u64)>,
+    e: u64,
+) -> impl Iterator<Item = (Natural, u64)> {
+    factors
+        .into_iter()
+        .map(move |(p, f)| (Natural::from(p), e * f))
+}
+
+// Finds a nontrivial factor of `n` using stage 1 of Pollard's p - 1 algorithm, which succeeds when
+// `n` has a prime factor p such that p - 1 is `POLLARD_P_MINUS_1_BOUND`-smooth.
+fn pollard_p_minus_1(n: &Natural) -> Option<Natural> {
+    let mut a = Natural::TWO;
+    for p in Limb::primes_less_than_or_equal_to(&POLLARD_P_MINUS_1_BOUND) {
+        // Raise a to the largest power of p that doesn't exceed the bound.
+        let mut power = p;
+        while let Some(next_power) = power.checked_mul(p) {
+            if next_power > POLLARD_P_MINUS_1_BOUND {
+                break;
+            }
+            power = next_power;
+        }
+        a = a.mod_pow(Natural::from(power), n);
+    }
+    if a == 0u32 {
+        return None;
+    }
+    let g = (a - Natural::ONE).gcd(n);
+    if g != 1u32 && g != *n {
+        Some(g)
+    } else {
+        None
+    }
+}
+
+fn abs_diff(x: &Natural, y: &Natural) -> Natural {
+    if x > y {
+        x - y
+    } else {
+        y - x
+    }
+}
+
+// Attempts to find a nontrivial factor of `n` using Brent's variant of Pollard's rho algorithm,
+// iterating x -> x^2 + 1 mod n. Gives up after roughly `POLLARD_RHO_MAX_ITERATIONS` iterations.
+fn pollard_rho(n: &Natural) -> Option<Natural> {
+    let f = |x: Natural| x.mod_square(n).mod_add(Natural::ONE, n);
+    let mut x;
+    let mut y = Natural::TWO;
+    let mut ys = Natural::TWO;
+    let mut q = Natural::ONE;
+    let mut g;
+    let mut r = 1;
+    loop {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(y);
+        }
+        g = Natural::ONE;
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            for _ in 0..min(POLLARD_RHO_BATCH_SIZE, r - k) {
+                y = f(y);
+                q = q.mod_mul(abs_diff(&x, &y), n);
+            }
+            g = (&q).gcd(n);
+            k += POLLARD_RHO_BATCH_SIZE;
+        }
+        if g != 1u32 {
+            break;
+        }
+        if r >= POLLARD_RHO_MAX_ITERATIONS {
+            return None;
+        }
+        r <<= 1;
+    }
+    if g == *n {
+        // The batched gcd overshot; backtrack one step at a time.
+        loop {
+            ys = f(ys);
+            g = abs_diff(&x, &ys).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// A point on an elliptic curve modulo n, in affine coordinates. `None` is the point at infinity.
+type EcPoint = Option<(Natural, Natural)>;
+
+// Returns the inverse of `x` modulo `n`. If `x` is not invertible, returns `gcd(x, n)` as an error;
+// this is how ECM finds factors.
+fn ec_invert(x: Natural, n: &Natural) -> Result<Natural, Natural> {
+    if x == 0u32 {
+        return Err(n.clone());
+    }
+    match (&x).mod_inverse(n) {
+        Some(inverse) => Ok(inverse),
+        None => Err(x.gcd(n)),
+    }
+}
+
+// Adds two points on the curve y^2 = x^3 + ax + b modulo `n`. (b is implied by the points.)
+fn ec_add(p: &EcPoint, q: &EcPoint, a: &Natural, n: &Natural) -> Result<EcPoint, Natural> {
+    let ((x_1, y_1), (x_2, y_2)) = match (p, q) {
+        (None, _) => return Ok(q.clone()),
+        (_, None) => return Ok(p.clone()),
+        (Some(p), Some(q)) => (p, q),
+    };
+    let lambda = if x_1 == x_2 {
+        if y_1 != y_2 || *y_1 == 0u32 {
+            return Ok(None);
+        }
+        // lambda = (3x^2 + a) / 2y
+        let numerator = Natural::from(3u32)
+            .mod_mul(x_1.mod_square(n), n)
+            .mod_add(a, n);
+        numerator.mod_mul(ec_invert(y_1.mod_add(y_1, n), n)?, n)
+    } else {
+        // lambda = (y_2 - y_1) / (x_2 - x_1)
+        y_2.mod_sub(y_1, n)
+            .mod_mul(ec_invert(x_2.mod_sub(x_1, n), n)?, n)
+    };
+    let x_3 = (&lambda).mod_square(n).mod_sub(x_1, n).mod_sub(x_2, n);
+    let y_3 = lambda.mod_mul(x_1.mod_sub(&x_3, n), n).mod_sub(y_1, n);
+    Ok(Some((x_3, y_3)))
+}
+
+// Multiplies a point on the curve y^2 = x^3 + ax + b modulo `n` by `k`, using double-and-add.
+fn ec_mul(p: &EcPoint, mut k: Limb, a: &Natural, n: &Natural) -> Result<EcPoint, Natural> {
+    let mut result = None;
+    let mut p = p.clone();
+    while k != 0 {
+        if k.odd() {
+            result = ec_add(&result, &p, a, n)?;
+        }
+        k >>= 1;
+        if k != 0 {
+            p = ec_add(&p, &p, a, n)?;
+        }
+    }
+    Ok(result)
+}
+
+// Runs stage 1 of Lenstra's elliptic curve method on the curve y^2 = x^3 + ax + 1 modulo `n`,
+// starting from the point (0, 1). Succeeds when `n` has a prime factor p such that the order of the
+// point modulo p is `bound`-smooth.
+fn ecm_with_curve(n: &Natural, a: &Natural, bound: Limb) -> Option<Natural> {
+    let mut point = Some((Natural::ZERO, Natural::ONE));
+    for p in Limb::primes_less_than_or_equal_to(&bound) {
+        let mut power = p;
+        while let Some(next_power) = power.checked_mul(p) {
+            if next_power > bound {
+                break;
+            }
+            power = next_power;
+        }
+        match ec_mul(&point, power, a, n) {
+            Ok(None) => return None,
+            Ok(next_point) => point = next_point,
+            Err(g) => return if g == *n { None } else { Some(g) },
+        }
+    }
+    None
+}
+
+// Finds a nontrivial factor of `n` using Lenstra's elliptic curve method, trying more curves, with
+// increasing bounds, until one succeeds.
+fn ecm(n: &Natural) -> Natural {
+    let mut bound = ECM_INITIAL_BOUND;
+    let mut curve = 1u64;
+    loop {
+        if let Some(f) = ecm_with_curve(n, &(Natural::from(curve) % n), bound) {
+            return f;
+        }
+        if curve % ECM_CURVES_PER_BOUND == 0 {
+            bound += ECM_INITIAL_BOUND;
+        }
+        curve += 1;
+    }
+}
+
+// Finds a nontrivial factor of `n`, which must be odd, composite, not a perfect square, and have no
+// prime factors less than `TRIAL_DIVISION_LIMIT`. The cheaper methods are tried first.
+fn find_factor(n: &Natural) -> Natural {
+    pollard_p_minus_1(n)
+        .or_else(|| pollard_rho(n))
+        .unwrap_or_else(|| ecm(n))
+}
+
+// Factors `n`, which has no prime factors less than `TRIAL_DIVISION_LIMIT`, and pushes its prime
+// factors onto `factors`, each with multiplicity `e`. The same prime may be pushed more than once.
+fn factor_cofactor(n: Natural, e: u64, factors: &mut Vec<(Natural, u64)>) {
+    if let Ok(x) = u64::try_from(&n) {
+        factors.extend(u64_factors_to_natural_factors(x.factor(), e));
+    } else if n.is_prime() {
+        factors.push((n, e));
+    } else if let Some(r) = (&n).checked_sqrt() {
+        factor_cofactor(r, e << 1, factors);
+    } else {
+        let d = find_factor(&n);
+        let q = (&n).div_exact(&d);
+        factor_cofactor(d, e, factors);
+        factor_cofactor(q, e, factors);
+    }
+}
+
+impl Factor for Natural {
+    /// Factors a [`Natural`] into primes.
+    ///
+    /// The result is a list of the distinct prime factors in ascending order, each paired with
+    /// its multiplicity. Factoring 1 produces an empty list.
+    ///
+    /// Small factors are found by trial division. If what remains fits in a [`u64`], it is
+    /// factored as one. Otherwise, factors are found using Pollard's $p - 1$ algorithm, then
+    /// Brent's variant of Pollard's rho algorithm, and finally Lenstra's elliptic curve method,
+    /// which is used with increasing bounds until it succeeds. Primality is determined using
+    /// [`IsPrime`].
+    ///
+    /// # Worst-case complexity
+    /// The running time is subexponential in `self.significant_bits()`, and depends mostly on the
+    /// size of the second-largest prime factor.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(*self, 0u32, "Cannot factor 0");
+        if let Ok(x) = u64::try_from(self) {
+            return u64_factors_to_natural_factors(x.factor(), 1).collect();
+        }
+        let mut n = self.clone();
+        let mut factors = Vec::new();
+        let zeros = n.trailing_zeros().unwrap();
+        if zeros != 0 {
+            factors.push((Natural::TWO, zeros));
+            n >>= zeros;
+        }
+        for p in Limb::primes_less_than(&TRIAL_DIVISION_LIMIT).skip(1) {
+            let p = Natural::from(p);
+            let mut e = 0;
+            while (&n).divisible_by(&p) {
+                n.div_exact_assign(&p);
+                e += 1;
+            }
+            if e != 0 {
+                factors.push((p, e));
+            }
+            if u64::convertible_from(&n) {
+                break;
+            }
+        }
+        if n != 1u32 {
+            factor_cofactor(n, 1, &mut factors);
+            factors.sort_unstable_by(|(p, _), (q, _)| p.cmp(q));
+            factors.dedup_by(|(p, e), (q, f)| {
+                if p == q {
+                    *f += *e;
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+        factors
+    }
+}
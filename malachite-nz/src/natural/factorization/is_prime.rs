@@ -0,0 +1,214 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, DivisibleBy, JacobiSymbol, ModAdd, ModMul, ModMulAssign, ModPow, ModSquare,
+    ModSquareAssign, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::num::logic::traits::BitIterable;
+
+// Odd primes less than this limit are tried as factors before any probable-prime tests are run.
+const TRIAL_DIVISION_LIMIT: Limb = 1000;
+
+// Returns whether the `Natural` with limbs `xs` is divisible by any of the primes in `ps`, whose
+// product is `product`.
+fn limbs_divisible_by_any(xs: &[Limb], product: Limb, ps: &[Limb]) -> bool {
+    let r = limbs_mod_limb(xs, product);
+    ps.iter().any(|&p| r.divisible_by(p))
+}
+
+// Returns whether the `Natural` with limbs `xs` is divisible by an odd prime less than
+// `TRIAL_DIVISION_LIMIT`. To avoid a full pass over `xs` for each prime, the primes are grouped so
+// that the product of each group fits in a `Limb`. `xs` must have at least two limbs.
+fn limbs_has_small_odd_factor(xs: &[Limb]) -> bool {
+    let mut ps = Vec::new();
+    let mut product: Limb = 1;
+    for p in Limb::primes_less_than(&TRIAL_DIVISION_LIMIT).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            if limbs_divisible_by_any(xs, product, &ps) {
+                return true;
+            }
+            ps.clear();
+            product = p;
+        }
+        ps.push(p);
+    }
+    limbs_divisible_by_any(xs, product, &ps)
+}
+
+// Determines whether odd `n` is a strong probable prime to base `a`, where 1 < `a` < `n - 1`.
+// `n - 1 = d * 2^s`, with `d` odd.
+fn is_strong_probable_prime(
+    n: &Natural,
+    n_minus_1: &Natural,
+    d: &Natural,
+    s: u64,
+    a: Natural,
+) -> bool {
+    let mut x = a.mod_pow(d, n);
+    if x == 1u32 || x == *n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_square_assign(n);
+        if x == *n_minus_1 {
+            return true;
+        } else if x == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Halves `x` modulo odd `n`, where `x < n`.
+fn mod_half(x: Natural, n: &Natural) -> Natural {
+    if x.even() {
+        x >> 1
+    } else {
+        (x + n) >> 1
+    }
+}
+
+// Determines whether odd `n` > 2^64, which is not a perfect square and has no small factors, is a
+// strong Lucas probable prime, with parameters chosen using Selfridge's method A: D is the first of
+// 5, -7, 9, -11, ... for which the Jacobi symbol (D/n) is -1, P = 1, and Q = (1 - D) / 4.
+fn is_strong_lucas_probable_prime(n: &Natural) -> bool {
+    // Since n has no small factors, |D| < n throughout.
+    let mut abs_d: u64 = 5;
+    let mut negative = false;
+    let d_mod_n = loop {
+        let d_mod_n = if negative {
+            n - Natural::from(abs_d)
+        } else {
+            Natural::from(abs_d)
+        };
+        match (&d_mod_n).jacobi_symbol(n) {
+            -1 => break d_mod_n,
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    let q = if negative {
+        Natural::from((abs_d + 1) >> 2)
+    } else {
+        n - Natural::from((abs_d - 1) >> 2)
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let d = n_plus_1 >> s;
+    // Compute U_d, V_d, and Q^d, starting from U_1 = 1, V_1 = P = 1, and Q^1 = Q.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for bit in d.bits().rev().skip(1) {
+        // U_{2k} = U_k * V_k, V_{2k} = V_k^2 - 2Q^k
+        u.mod_mul_assign(&v, n);
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        q_k.mod_square_assign(n);
+        if bit {
+            // U_{2k+1} = (P * U_{2k} + V_{2k}) / 2, V_{2k+1} = (D * U_{2k} + P * V_{2k}) / 2
+            let new_u = mod_half((&u).mod_add(&v, n), n);
+            v = mod_half((&d_mod_n).mod_mul(u, n).mod_add(v, n), n);
+            u = new_u;
+            q_k.mod_mul_assign(&q, n);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        // V_{2k} = V_k^2 - 2Q^k
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_square_assign(n);
+    }
+    false
+}
+
+impl Natural {
+    /// Determines whether a [`Natural`] is prime, optionally running extra rounds of Miller-Rabin.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is deterministic and exact. Otherwise,
+    /// the Baillie-PSW test is used; no counterexamples to it are known. After it passes, `rounds`
+    /// additional rounds of Miller-Rabin are run, using the odd primes $3, 5, 7, \ldots$ as bases.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::PowerOf2;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(97u32).is_prime_with_extra_rounds(5), true);
+    /// assert_eq!(Natural::from(561u32).is_prime_with_extra_rounds(5), false);
+    /// assert_eq!(
+    ///     (Natural::power_of_2(127) - Natural::ONE).is_prime_with_extra_rounds(5),
+    ///     true
+    /// );
+    /// assert_eq!(
+    ///     (Natural::power_of_2(128) + Natural::ONE).is_prime_with_extra_rounds(5),
+    ///     false
+    /// );
+    /// ```
+    pub fn is_prime_with_extra_rounds(&self, rounds: u64) -> bool {
+        if let Ok(x) = u64::try_from(self) {
+            return x.is_prime();
+        }
+        let xs = match *self {
+            Natural(Large(ref xs)) => xs,
+            Natural(Small(_)) => unreachable!(),
+        };
+        if xs[0].even() || limbs_has_small_odd_factor(xs) {
+            return false;
+        }
+        let n_minus_1 = self - Natural::ONE;
+        let s = n_minus_1.trailing_zeros().unwrap();
+        let d = &n_minus_1 >> s;
+        is_strong_probable_prime(self, &n_minus_1, &d, s, Natural::from(2u32))
+            && self.checked_sqrt().is_none()
+            && is_strong_lucas_probable_prime(self)
+            && Limb::primes()
+                .skip(1)
+                .take(usize::exact_from(rounds))
+                .all(|a| is_strong_probable_prime(self, &n_minus_1, &d, s, Natural::from(a)))
+    }
+}
+
+impl IsPrime for Natural {
+    /// Determines whether a [`Natural`] is prime.
+    ///
+    /// If the [`Natural`] is less than $2^{64}$, the result is deterministic and exact. Otherwise,
+    /// the Baillie-PSW test is used; no counterexamples to it are known. To run additional rounds
+    /// of Miller-Rabin, use [`Natural::is_prime_with_extra_rounds`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::is_prime#is_prime).
+    #[inline]
+    fn is_prime(&self) -> bool {
+        self.is_prime_with_extra_rounds(0)
+    }
+}
@@ -1,3 +1,190 @@
+/// An implementation of
+/// [`CarmichaelLambda`](malachite_base::num::factorization::traits::CarmichaelLambda), a trait for
+/// computing the Carmichael function of a number.
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).carmichael_lambda(), 1);
+/// assert_eq!(Natural::from(561u32).carmichael_lambda(), 80);
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE).carmichael_lambda().to_string(),
+///     "72057331223781120"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(100) - Natural::ONE).carmichael_lambda().to_string(),
+///     "14499000"
+/// );
+/// ```
+pub mod carmichael_lambda;
+/// An implementation of
+/// [`DivisorCount`](malachite_base::num::factorization::traits::DivisorCount), a trait for
+/// counting the positive divisors of a number.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::DivisorCount;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).divisor_count(), 1);
+/// assert_eq!(Natural::from(720720u32).divisor_count(), 240);
+/// assert_eq!(Natural::from(10u32).pow(30).divisor_count(), 961);
+/// assert_eq!((Natural::power_of_2(100) - Natural::ONE).divisor_count(), 8192);
+/// ```
+pub mod divisor_count;
+/// An implementation of [`DivisorSum`](malachite_base::num::factorization::traits::DivisorSum), a
+/// trait for computing the sum of the $k$th powers of the positive divisors of a number.
+///
+/// # divisor_sum
+/// ```
+/// use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::DivisorSum;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(12u32).divisor_sum(0), 6);
+/// assert_eq!(Natural::from(12u32).divisor_sum(1), 28);
+/// assert_eq!(Natural::from(12u32).divisor_sum(2), 210);
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).divisor_sum(1).to_string(),
+///     "2499999998835846781730114984557"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE).divisor_sum(2).to_string(),
+///     "340282366925465118592016216137989881860"
+/// );
+/// ```
+pub mod divisor_sum;
+/// An implementation of [`Divisors`](malachite_base::num::factorization::traits::Divisors), a
+/// trait for generating the positive divisors of a number.
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Divisors;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::from(12u32).divisors().collect_vec().to_debug_string(),
+///     "[1, 2, 4, 3, 6, 12]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE).divisors().sorted().collect_vec()
+///         .to_debug_string(),
+///     "[1, 274177, 67280421310721, 18446744073709551617]"
+/// );
+/// assert_eq!(Natural::from(10u32).pow(20).divisors().count(), 441);
+/// ```
+pub mod divisors;
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for factoring a number into primes.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).factor().to_debug_string(), "[]");
+/// assert_eq!(Natural::from(360u32).factor().to_debug_string(), "[(2, 3), (3, 2), (5, 1)]");
+/// assert_eq!(
+///     (Natural::power_of_2(64) + Natural::ONE).factor().to_debug_string(),
+///     "[(274177, 1), (67280421310721, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(100) - Natural::ONE).factor().to_debug_string(),
+///     "[(3, 1), (5, 3), (11, 1), (31, 1), (41, 1), (101, 1), (251, 1), (601, 1), (1801, 1), \
+///     (4051, 1), (8101, 1), (268501, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for determining whether a number is prime.
+///
+/// # is_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::IsPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).is_prime(), false);
+/// assert_eq!(Natural::from(1u32).is_prime(), false);
+/// assert_eq!(Natural::from(2u32).is_prime(), true);
+/// assert_eq!(Natural::from(561u32).is_prime(), false);
+/// assert_eq!(Natural::from(1000000007u32).is_prime(), true);
+/// assert_eq!((Natural::power_of_2(521) - Natural::ONE).is_prime(), true);
+/// assert_eq!((Natural::power_of_2(523) - Natural::ONE).is_prime(), false);
+/// ```
+pub mod is_prime;
+/// An implementation of [`Moebius`](malachite_base::num::factorization::traits::Moebius), a trait
+/// for computing the Möbius function of a number.
+///
+/// # moebius
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Moebius;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).moebius(), 1);
+/// assert_eq!(Natural::from(12u32).moebius(), 0);
+/// assert_eq!((Natural::power_of_2(64) + Natural::ONE).moebius(), 1);
+/// assert_eq!((Natural::power_of_2(127) - Natural::ONE).moebius(), -1);
+/// assert_eq!((Natural::power_of_2(100) - Natural::ONE).moebius(), 0);
+/// ```
+pub mod moebius;
+/// An implementation of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime), a
+/// trait for finding the smallest prime greater than a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::factorization::traits::NextPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).next_prime(), 2);
+/// assert_eq!(Natural::from(100u32).next_prime(), 101);
+/// assert_eq!(
+///     Natural::from(18446744073709551557u64).next_prime().to_string(),
+///     "18446744073709551629"
+/// );
+/// assert_eq!(
+///     Natural::power_of_2(100).next_prime().to_string(),
+///     "1267650600228229401496703205653"
+/// );
+/// ```
+pub mod next_prime;
+/// An implementation of
+/// [`PrecedingPrime`](malachite_base::num::factorization::traits::PrecedingPrime), a trait for
+/// finding the largest prime less than a number.
+///
+/// # preceding_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::PowerOf2;
+/// use malachite_base::num::factorization::traits::PrecedingPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(3u32).preceding_prime(), 2);
+/// assert_eq!(Natural::from(100u32).preceding_prime(), 97);
+/// assert_eq!(Natural::power_of_2(64).preceding_prime().to_string(), "18446744073709551557");
+/// assert_eq!(
+///     Natural::power_of_2(100).preceding_prime().to_string(),
+///     "1267650600228229401496703205361"
+/// );
+/// ```
+pub mod preceding_prime;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait
 /// for generating prime numbers.
 ///
@@ -64,3 +251,25 @@
 /// );
 /// ```
 pub mod primes;
+/// An implementation of [`Totient`](malachite_base::num::factorization::traits::Totient), a trait
+/// for computing Euler's totient function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::arithmetic::traits::{Pow, PowerOf2};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_base::num::factorization::traits::Totient;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).totient(), 1);
+/// assert_eq!(Natural::from(561u32).totient(), 320);
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).totient().to_string(),
+///     "400000000000000000000000000000"
+/// );
+/// assert_eq!(
+///     (Natural::power_of_2(127) - Natural::ONE).totient().to_string(),
+///     "170141183460469231731687303715884105726"
+/// );
+/// ```
+pub mod totient;
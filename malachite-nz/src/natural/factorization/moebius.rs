@@ -0,0 +1,34 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::factorization::traits::{Factor, Moebius};
+
+impl Moebius for Natural {
+    /// Computes the Möbius function of a [`Natural`].
+    ///
+    /// $$
+    /// f(n) = \mu(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad p^2 | n \\ \text{for some prime} \\ p, \\\\
+    ///     (-1)^k & \text{if} \\quad n \\ \text{is the product of} \\ k \\ \text{distinct primes}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by that of [`Factor::factor`].
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::moebius#moebius).
+    fn moebius(&self) -> i8 {
+        assert_ne!(*self, 0u32, "Cannot take the Möbius function of 0");
+        let factors = self.factor();
+        if factors.iter().any(|&(_, k)| k > 1) {
+            0
+        } else if factors.len().even() {
+            1
+        } else {
+            -1
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{Parity, PowerOf2};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, Primes};
+
+// Candidates that are divisible by an odd prime less than this are skipped without running a
+// primality test.
+const SIEVE_LIMIT: Limb = 1000;
+
+// The largest prime less than 2^64.
+pub(crate) const LARGEST_U64_PRIME: u64 = 18446744073709551557;
+
+// Returns the residues of `x` modulo each odd prime less than `SIEVE_LIMIT`, paired with the
+// primes. To avoid a full pass over `x`'s limbs for each prime, the primes are grouped so that the
+// product of each group fits in a `Limb`. `x` must be at least 2^64.
+pub(crate) fn small_prime_residues(x: &Natural) -> Vec<(Limb, Limb)> {
+    let xs = match *x {
+        Natural(Large(ref xs)) => xs,
+        Natural(Small(_)) => unreachable!(),
+    };
+    let mut residues = Vec::new();
+    let mut ps = Vec::new();
+    let mut product: Limb = 1;
+    let mut flush = |ps: &mut Vec<Limb>, product: Limb| {
+        let r = limbs_mod_limb(xs, product);
+        residues.extend(ps.drain(..).map(|p| (p, r % p)));
+    };
+    for p in Limb::primes_less_than(&SIEVE_LIMIT).skip(1) {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            flush(&mut ps, product);
+            product = p;
+        }
+        ps.push(p);
+    }
+    flush(&mut ps, product);
+    residues
+}
+
+impl NextPrime for Natural {
+    /// Finds the smallest prime greater than a [`Natural`].
+    ///
+    /// If the result is less than $2^{64}$, it is found using the implementation for [`u64`].
+    /// Otherwise, successive odd numbers are tried. Those with a prime factor less than 1000 are
+    /// skipped by keeping track of their residues modulo those primes, and the remaining ones are
+    /// tested using [`IsPrime`].
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    fn next_prime(&self) -> Natural {
+        if let Ok(x) = u64::try_from(self) {
+            if x < LARGEST_U64_PRIME {
+                return Natural::from(x.next_prime());
+            }
+        }
+        let mut candidate = if u64::convertible_from(self) {
+            // `self` is at least the largest prime less than 2^64.
+            Natural::power_of_2(u64::WIDTH) + Natural::ONE
+        } else {
+            let mut candidate = self + Natural::ONE;
+            if candidate.even() {
+                candidate += Natural::ONE;
+            }
+            candidate
+        };
+        let mut residues = small_prime_residues(&candidate);
+        loop {
+            if residues.iter().all(|&(_, r)| r != 0) && candidate.is_prime() {
+                return candidate;
+            }
+            candidate += Natural::TWO;
+            for (p, r) in &mut residues {
+                *r += 2;
+                if *r >= *p {
+                    *r -= *p;
+                }
+            }
+        }
+    }
+}
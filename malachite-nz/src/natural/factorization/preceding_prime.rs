@@ -0,0 +1,56 @@
+use crate::natural::factorization::next_prime::{small_prime_residues, LARGEST_U64_PRIME};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::conversion::traits::ConvertibleFrom;
+use malachite_base::num::factorization::traits::{IsPrime, PrecedingPrime};
+
+impl PrecedingPrime for Natural {
+    /// Finds the largest prime less than a [`Natural`].
+    ///
+    /// If the result is less than $2^{64}$, it is found using the implementation for [`u64`].
+    /// Otherwise, successive odd numbers are tried, in descending order. Those with a prime factor
+    /// less than 1000 are skipped by keeping track of their residues modulo those primes, and the
+    /// remaining ones are tested using [`IsPrime`].
+    ///
+    /// # Expected complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is less than or equal to 2.
+    ///
+    /// # Examples
+    /// See [here](super::preceding_prime#preceding_prime).
+    fn preceding_prime(&self) -> Natural {
+        assert!(*self > 2u32, "There is no prime less than {self}");
+        if let Ok(x) = u64::try_from(self) {
+            return Natural::from(x.preceding_prime());
+        }
+        let mut candidate = self - Natural::ONE;
+        if candidate.even() {
+            candidate -= Natural::ONE;
+        }
+        // Since `self` is at least 2^64, once a candidate is less than 2^64, the result is the
+        // largest prime less than 2^64.
+        if u64::convertible_from(&candidate) {
+            return Natural::from(LARGEST_U64_PRIME);
+        }
+        let mut residues = small_prime_residues(&candidate);
+        loop {
+            if residues.iter().all(|&(_, r)| r != 0) && candidate.is_prime() {
+                return candidate;
+            }
+            candidate -= Natural::TWO;
+            if u64::convertible_from(&candidate) {
+                return Natural::from(LARGEST_U64_PRIME);
+            }
+            for (p, r) in &mut residues {
+                *r = if *r < 2 { *r + *p - 2 } else { *r - 2 };
+            }
+        }
+    }
+}
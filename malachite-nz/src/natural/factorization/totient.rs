@@ -0,0 +1,31 @@
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{Factor, Totient};
+
+impl Totient for Natural {
+    /// Computes Euler's totient function of a [`Natural`]: the number of positive integers less
+    /// than or equal to it that are coprime to it.
+    ///
+    /// $$
+    /// f(n) = \varphi(n) = n \prod_{p | n} \left ( 1 - \frac{1}{p} \right ),
+    /// $$
+    /// where the product is taken over the distinct primes dividing $n$.
+    ///
+    /// # Worst-case complexity
+    /// The running time is dominated by that of [`Factor::factor`].
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::totient#totient).
+    fn totient(&self) -> Natural {
+        assert_ne!(*self, 0u32, "Cannot take the totient of 0");
+        let mut totient = Natural::ONE;
+        for (p, k) in self.factor() {
+            totient *= (&p).pow(k - 1) * (p - Natural::ONE);
+        }
+        totient
+    }
+}
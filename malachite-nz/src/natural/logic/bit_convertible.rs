@@ -1,6 +1,7 @@
 use crate::natural::arithmetic::shr::limbs_slice_shr_in_place;
 use crate::natural::Natural;
 use crate::platform::Limb;
+use alloc::vec::Vec;
 use itertools::Itertools;
 use malachite_base::num::arithmetic::traits::Parity;
 use malachite_base::num::basic::integers::PrimitiveInt;
@@ -1,8 +1,9 @@
 use crate::natural::InnerNatural::{Large, Small};
 use crate::natural::Natural;
 use crate::platform::Limb;
-use std::mem::swap;
-use std::ops::{BitXor, BitXorAssign};
+use alloc::vec::Vec;
+use core::mem::swap;
+use core::ops::{BitXor, BitXorAssign};
 
 // Interpreting a slice of `Limb`s as the limbs (in ascending order) of a `Natural`, returns the
 // limbs of the bitwise xor of the `Natural` and a `Limb`. `xs` cannot be empty.
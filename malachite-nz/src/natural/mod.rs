@@ -1,5 +1,7 @@
 use crate::natural::InnerNatural::{Large, Small};
 use crate::platform::Limb;
+use alloc::string::String;
+use alloc::vec::Vec;
 #[cfg(feature = "doc-images")]
 use embed_doc_image::embed_doc_image;
 use malachite_base::comparison::traits::Min;
@@ -133,7 +135,8 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Natural`]s without repetition.
 pub mod exhaustive;
-/// Traits for generating primes, primality testing, and factorization (TODO!)
+/// Traits for generating primes, primality testing, factorization, and arithmetic functions derived
+/// from factorizations.
 pub mod factorization;
 /// Traits for logic and bit manipulation.
 pub mod logic;
@@ -16,15 +16,24 @@ pub const AORSMUL_FASTER_AORS_AORSLSH: bool = true;
 pub const AORSMUL_FASTER_AORS_2AORSLSH: bool = true;
 
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM22_THRESHOLD: usize = 118;
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM33_THRESHOLD: usize = 101;
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM44_THRESHOLD: usize = 530;
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM6H_THRESHOLD: usize = 738;
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM8H_THRESHOLD: usize = 984;
+// Only used when the `parallel` feature is enabled. Compared with the length of the factors of
+// the two products at a pair of Toom-6, Toom-6.5, Toom-8, or Toom-8.5 evaluation points.
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_TOOM_PARALLEL_THRESHOLD: usize = 500;
 
 //TODO tune
 pub const MUL_TOOM32_TO_TOOM43_THRESHOLD: usize = 315;
@@ -36,9 +45,19 @@ pub const MUL_TOOM42_TO_TOOM53_THRESHOLD: usize = 328;
 pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 295;
 
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_FFT_THRESHOLD: usize = 5608;
+//TODO tune
+// Only used when the `parallel` feature is enabled
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_FFT_PARALLEL_THRESHOLD: usize = 100000;
+//TODO tune
+// Compared with the length of the product, in limbs
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_FFT_SMALL_THRESHOLD: usize = 8000;
 
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const DC_DIV_QR_THRESHOLD: usize = 7;
 //TODO tune
 pub const DC_DIVAPPR_Q_THRESHOLD: usize = 151;
@@ -47,6 +66,7 @@ pub const MAYBE_DCP1_DIVAPPR: bool = true;
 //TODO tune
 pub const INV_NEWTON_THRESHOLD: usize = 618;
 //TODO tune
+#[cfg(not(malachite_nz_tuned))]
 pub const MU_DIV_QR_THRESHOLD: usize = 2243;
 //TODO tune
 pub const INV_MULMOD_BNM1_THRESHOLD: usize = 68;
@@ -469,3 +489,15 @@ pub const TABLE_LIMIT_2N_MINUS_POPC_2N: u64 = 49;
 pub const FFT_TAB: [[u8; 2]; 5] = [[3, 3], [3, 2], [2, 1], [2, 1], [0, 0]];
 
 pub const MULMOD_TAB: [u8; 15] = [4, 3, 3, 3, 3, 2, 2, 2, 3, 2, 2, 2, 2, 1, 1];
+
+// With the `tuned_thresholds` feature, the thresholds excluded above are read from the output of
+// `cargo run --features bin_build --release -- -g tune` instead; see `build.rs`.
+#[cfg(malachite_nz_tuned)]
+include!(concat!(env!("OUT_DIR"), "/thresholds.rs"));
+
+// The tuned thresholds only make sense for the limb width that they were measured with.
+#[cfg(malachite_nz_tuned)]
+const _: () = assert!(
+    TUNED_LIMB_WIDTH == Limb::BITS,
+    "MALACHITE_NZ_THRESHOLDS was generated for a different limb width"
+);
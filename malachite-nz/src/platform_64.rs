@@ -11,23 +11,41 @@ pub const AORSMUL_FASTER_3AORSLSH: bool = true;
 pub const AORSMUL_FASTER_AORS_AORSLSH: bool = true;
 pub const AORSMUL_FASTER_AORS_2AORSLSH: bool = true;
 
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM22_THRESHOLD: usize = 20;
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM33_THRESHOLD: usize = 39;
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM44_THRESHOLD: usize = 340; // unclear when 44 is better than 33
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM6H_THRESHOLD: usize = 345;
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_TOOM8H_THRESHOLD: usize = 640;
+// Only used when the `parallel` feature is enabled. Compared with the length of the factors of
+// the two products at a pair of Toom-6, Toom-6.5, Toom-8, or Toom-8.5 evaluation points.
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_TOOM_PARALLEL_THRESHOLD: usize = 250;
 
 pub const MUL_TOOM32_TO_TOOM43_THRESHOLD: usize = 60;
 pub const MUL_TOOM32_TO_TOOM53_THRESHOLD: usize = 300;
 pub const MUL_TOOM42_TO_TOOM53_THRESHOLD: usize = 600;
 pub const MUL_TOOM42_TO_TOOM63_THRESHOLD: usize = 103;
 
+#[cfg(not(malachite_nz_tuned))]
 pub const MUL_FFT_THRESHOLD: usize = 1500;
+// Only used when the `parallel` feature is enabled
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_FFT_PARALLEL_THRESHOLD: usize = 50000;
+// Compared with the length of the product, in limbs
+#[cfg(not(malachite_nz_tuned))]
+pub const MUL_FFT_SMALL_THRESHOLD: usize = 4000;
 
+#[cfg(not(malachite_nz_tuned))]
 pub const DC_DIV_QR_THRESHOLD: usize = 85;
 pub const DC_DIVAPPR_Q_THRESHOLD: usize = 211;
 pub const MAYBE_DCP1_DIVAPPR: bool = true;
 pub const INV_NEWTON_THRESHOLD: usize = 789;
+#[cfg(not(malachite_nz_tuned))]
 pub const MU_DIV_QR_THRESHOLD: usize = 2094;
 pub const INV_MULMOD_BNM1_THRESHOLD: usize = 62;
 pub const MU_DIV_QR_SKEW_THRESHOLD: usize = 231;
@@ -2087,3 +2105,15 @@ pub const TABLE_LIMIT_2N_MINUS_POPC_2N: u64 = 81;
 pub const FFT_TAB: [[u8; 2]; 5] = [[4, 4], [4, 3], [3, 2], [2, 1], [2, 1]];
 
 pub const MULMOD_TAB: [u8; 19] = [4, 4, 4, 4, 4, 3, 3, 3, 3, 3, 3, 3, 2, 2, 2, 2, 2, 1, 1];
+
+// With the `tuned_thresholds` feature, the thresholds excluded above are read from the output of
+// `cargo run --features bin_build --release -- -g tune` instead; see `build.rs`.
+#[cfg(malachite_nz_tuned)]
+include!(concat!(env!("OUT_DIR"), "/thresholds.rs"));
+
+// The tuned thresholds only make sense for the limb width that they were measured with.
+#[cfg(malachite_nz_tuned)]
+const _: () = assert!(
+    TUNED_LIMB_WIDTH == Limb::BITS,
+    "MALACHITE_NZ_THRESHOLDS was generated for a different limb width"
+);
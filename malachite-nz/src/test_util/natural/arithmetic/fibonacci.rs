@@ -0,0 +1,24 @@
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+
+pub fn fibonacci_pair_naive(n: u64) -> (Natural, Natural) {
+    let mut f = Natural::ZERO;
+    let mut f_prev = Natural::ONE;
+    for _ in 0..n {
+        let f_next = &f + f_prev;
+        f_prev = f;
+        f = f_next;
+    }
+    (f, f_prev)
+}
+
+pub fn lucas_naive(n: u64) -> Natural {
+    let mut l = Natural::TWO;
+    let mut l_next = Natural::ONE;
+    for _ in 0..n {
+        let l_next_next = &l + &l_next;
+        l = l_next;
+        l_next = l_next_next;
+    }
+    l
+}
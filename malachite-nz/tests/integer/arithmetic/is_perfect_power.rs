@@ -0,0 +1,78 @@
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower, Parity, Pow};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_express_as_power() {
+    let test = |n: &str, out: &str| {
+        let n = Integer::from_str(n).unwrap();
+        let result = n.express_as_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(n.is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("-1", "Some((-1, 3))");
+    test("-2", "None");
+    test("4", "Some((2, 2))");
+    test("-4", "None");
+    test("-8", "Some((-2, 3))");
+    test("64", "Some((2, 6))");
+    test("-64", "Some((-4, 3))");
+    test("-1000000", "Some((-100, 3))");
+    // -2^64
+    test("-18446744073709551616", "None");
+    // -2^63
+    test("-9223372036854775808", "Some((-2, 63))");
+    // -2^96
+    test("-79228162514264337593543950336", "Some((-4294967296, 3))");
+    // -(10^20 + 1)^3
+    test(
+        "-1000000000000000000030000000000000000000300000000000000000001",
+        "Some((-100000000000000000001, 3))",
+    );
+}
+
+#[test]
+fn express_as_power_properties() {
+    integer_gen().test_properties(|n| {
+        let result = n.express_as_power();
+        assert_eq!(n.is_perfect_power(), result.is_some());
+        if n >= 0 {
+            assert_eq!(
+                result,
+                Natural::exact_from(&n)
+                    .express_as_power()
+                    .map(|(x, e)| (Integer::from(x), e))
+            );
+        } else if let Some((x, e)) = result {
+            assert!(e.odd());
+            assert!(x < 0);
+            assert_eq!((&x).pow(e), n);
+            if n != -1 {
+                let (_, f) = (-&n).express_as_power().unwrap();
+                assert_eq!(f >> f.trailing_zeros(), e);
+            }
+        }
+    });
+
+    natural_gen().test_properties(|n| {
+        assert_eq!(
+            Integer::from(&n).express_as_power(),
+            n.express_as_power().map(|(x, e)| (Integer::from(x), e))
+        );
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|x| {
+        assert_eq!(
+            Integer::from(x).express_as_power(),
+            x.express_as_power().map(|(y, e)| (Integer::from(y), e))
+        );
+    });
+}
@@ -0,0 +1,87 @@
+use itertools::Itertools;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use num::BigInt;
+
+#[test]
+fn test_from_twos_complement_bytes_le() {
+    let test = |xs: &[u8], out| {
+        let x = Integer::from_twos_complement_bytes_le(xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+        let mut xs_be = xs.to_vec();
+        xs_be.reverse();
+        assert_eq!(Integer::from_twos_complement_bytes_be(&xs_be), x);
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[133], "-123");
+    test(&[128, 0], "128");
+    test(&[128], "-128");
+    test(&[128, 255, 255, 255, 255, 255, 255, 255, 255], "-128");
+    test(&[127, 255], "-129");
+    test(&[255], "-1");
+    test(&[255, 255, 255, 255, 255, 255, 255, 255, 255], "-1");
+    test(&[0, 16, 165, 212, 232, 0], "1000000000000");
+    test(&[0, 240, 90, 43, 23, 255], "-1000000000000");
+    test(&[0, 0, 0, 128], "-2147483648");
+    test(&[0, 0, 0, 0, 0, 0, 0, 128], "-9223372036854775808");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 255], "-18446744073709551616");
+}
+
+#[test]
+fn test_from_sign_and_bytes_le() {
+    let test = |sign, xs: &[u8], out| {
+        let x = Integer::from_sign_and_bytes_le(sign, xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+        let mut xs_be = xs.to_vec();
+        xs_be.reverse();
+        assert_eq!(Integer::from_sign_and_bytes_be(sign, &xs_be), x);
+    };
+    test(true, &[], "0");
+    test(false, &[], "0");
+    test(false, &[0, 0], "0");
+    test(true, &[123], "123");
+    test(false, &[123], "-123");
+    test(false, &[128, 0], "-128");
+    test(false, &[0, 16, 165, 212, 232], "-1000000000000");
+}
+
+#[test]
+fn from_twos_complement_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Integer::from_twos_complement_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(x, Integer::from(&BigInt::from_signed_bytes_le(&xs)));
+        assert_eq!(
+            Integer::from_twos_complement_bytes_be(&xs.iter().cloned().rev().collect_vec()),
+            x
+        );
+        assert_eq!(
+            Integer::from_twos_complement_bytes_le(&x.to_twos_complement_bytes_le()),
+            x
+        );
+    });
+}
+
+#[test]
+fn from_sign_and_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        for sign in [false, true] {
+            let x = Integer::from_sign_and_bytes_le(sign, &xs);
+            assert!(x.is_valid());
+            assert_eq!(
+                x,
+                Integer::from_sign_and_abs(sign, Natural::from_bytes_le(&xs))
+            );
+            assert_eq!(
+                Integer::from_sign_and_bytes_be(sign, &xs.iter().cloned().rev().collect_vec()),
+                x
+            );
+        }
+    });
+}
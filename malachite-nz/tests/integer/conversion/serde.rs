@@ -2,6 +2,7 @@ use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::{string_gen, string_gen_var_9};
 use malachite_nz::integer::Integer;
 use malachite_nz::test_util::generators::integer_gen;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 #[test]
@@ -46,6 +47,26 @@ fn test_serde() {
     );
 }
 
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct CompactInteger(#[serde(with = "malachite_nz::integer::conversion::serde::compact")] Integer);
+
+#[test]
+fn test_serde_compact() {
+    let test = |n, out| {
+        let x = CompactInteger(Integer::from_str(n).unwrap());
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        assert_eq!(serde_json::from_str::<CompactInteger>(out).unwrap(), x);
+    };
+    test("0", "[]");
+    test("100", "[100]");
+    test("128", "[128,0]");
+    test("1000000000000", "[0,16,165,212,232,0]");
+    test("-1", "[255]");
+    test("-128", "[128]");
+    test("-129", "[127,255]");
+    test("-1000000000000", "[0,240,90,43,23,255]");
+}
+
 #[test]
 fn serde_properties() {
     integer_gen().test_properties(|x| {
@@ -62,3 +83,13 @@ fn serde_properties() {
         let _n: Integer = serde_json::from_str(&s).unwrap();
     });
 }
+
+#[test]
+fn serde_compact_properties() {
+    integer_gen().test_properties(|x| {
+        let x = CompactInteger(x);
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<CompactInteger>(&s).unwrap(), x);
+        assert!(string_is_subset(&s, ",0123456789[]"));
+    });
+}
@@ -0,0 +1,95 @@
+use itertools::Itertools;
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::generators::integer_gen;
+use num::BigInt;
+use std::str::FromStr;
+
+#[test]
+fn test_to_twos_complement_bytes_le() {
+    let test = |n, out: &[u8]| {
+        let n = Integer::from_str(n).unwrap();
+        assert_eq!(n.to_twos_complement_bytes_le(), out);
+        let mut out_be = out.to_vec();
+        out_be.reverse();
+        assert_eq!(n.to_twos_complement_bytes_be(), out_be);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("-123", &[133]);
+    test("127", &[127]);
+    test("128", &[128, 0]);
+    test("-128", &[128]);
+    test("-129", &[127, 255]);
+    test("255", &[255, 0]);
+    test("-1", &[255]);
+    test("1000000000000", &[0, 16, 165, 212, 232, 0]);
+    test("-1000000000000", &[0, 240, 90, 43, 23, 255]);
+    test("4294967295", &[255, 255, 255, 255, 0]);
+    test("-4294967296", &[0, 0, 0, 0, 255]);
+    test("2147483648", &[0, 0, 0, 128, 0]);
+    test("-2147483648", &[0, 0, 0, 128]);
+    test(
+        "9223372036854775807",
+        &[255, 255, 255, 255, 255, 255, 255, 127],
+    );
+    test("-9223372036854775808", &[0, 0, 0, 0, 0, 0, 0, 128]);
+    test(
+        "-9223372036854775809",
+        &[255, 255, 255, 255, 255, 255, 255, 127, 255],
+    );
+    test("18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test("-18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 255]);
+}
+
+#[test]
+fn test_to_sign_and_bytes_le() {
+    let test = |n, sign, out: &[u8]| {
+        let n = Integer::from_str(n).unwrap();
+        assert_eq!(n.to_sign_and_bytes_le(), (sign, out.to_vec()));
+        let mut out_be = out.to_vec();
+        out_be.reverse();
+        assert_eq!(n.to_sign_and_bytes_be(), (sign, out_be));
+    };
+    test("0", true, &[]);
+    test("123", true, &[123]);
+    test("-123", false, &[123]);
+    test("-128", false, &[128]);
+    test("1000000000000", true, &[0, 16, 165, 212, 232]);
+    test("-1000000000000", false, &[0, 16, 165, 212, 232]);
+    test("-18446744073709551616", false, &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+}
+
+#[test]
+fn to_twos_complement_bytes_le_properties() {
+    integer_gen().test_properties(|x| {
+        let bytes = x.to_twos_complement_bytes_le();
+        assert_eq!(Integer::from_twos_complement_bytes_le(&bytes), x);
+        assert_eq!(bytes.is_empty(), x == 0);
+        if x != 0 {
+            assert_eq!(bytes, BigInt::from(&x).to_signed_bytes_le());
+        }
+        assert_eq!(
+            x.to_twos_complement_bytes_be(),
+            bytes.iter().cloned().rev().collect_vec()
+        );
+        // The output is minimal
+        if let [.., penultimate, last] = *bytes.as_slice() {
+            assert!(last != 0 || penultimate >= 0x80);
+            assert!(last != u8::MAX || penultimate < 0x80);
+        }
+    });
+}
+
+#[test]
+fn to_sign_and_bytes_le_properties() {
+    integer_gen().test_properties(|x| {
+        let (sign, bytes) = x.to_sign_and_bytes_le();
+        assert_eq!(sign, x >= 0);
+        assert_eq!(bytes, (&x).unsigned_abs().to_bytes_le());
+        assert_eq!(Integer::from_sign_and_bytes_le(sign, &bytes), x);
+        let (sign_be, bytes_be) = x.to_sign_and_bytes_be();
+        assert_eq!(sign_be, sign);
+        assert_eq!(bytes_be, bytes.iter().cloned().rev().collect_vec());
+    });
+}
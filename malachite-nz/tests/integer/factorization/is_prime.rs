@@ -0,0 +1,45 @@
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::integer_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |n: &str, out: bool| {
+        let n = Integer::from_str(n).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_prime_with_extra_rounds(10), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("-1", false);
+    test("-2", false);
+    test("561", false);
+    test("1000000007", true);
+    test("-1000000007", false);
+    test("170141183460469231731687303715884105727", true);
+    test("-170141183460469231731687303715884105727", false);
+    test("340282366920938461286658806734041124249", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    integer_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_prime_with_extra_rounds(2), is_prime);
+        assert_eq!(is_prime, n > 0u32 && n.unsigned_abs_ref().is_prime());
+        if is_prime {
+            assert!(!(-n).is_prime());
+        }
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|x| {
+        assert_eq!(
+            Integer::from(x).is_prime(),
+            x > 0 && x.unsigned_abs().is_prime()
+        );
+    });
+}
@@ -60,6 +60,7 @@ pub mod integer {
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
+        pub mod is_perfect_power;
         pub mod kronecker_symbol;
         pub mod mod_op;
         pub mod mod_power_of_2;
@@ -106,6 +107,7 @@ pub mod integer {
     pub mod conversion {
         pub mod clone;
         pub mod from_bool;
+        pub mod from_bytes;
         pub mod from_natural;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
@@ -122,6 +124,7 @@ pub mod integer {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_bytes;
         pub mod to_twos_complement_limbs;
     }
     pub mod exhaustive {
@@ -139,6 +142,9 @@ pub mod integer {
         pub mod integer_increasing_range;
         pub mod integer_increasing_range_to_infinity;
     }
+    pub mod factorization {
+        pub mod is_prime;
+    }
     pub mod logic {
         pub mod and;
         pub mod assign_bit;
@@ -194,6 +200,7 @@ pub mod natural {
         pub mod checked_sub;
         pub mod checked_sub_mul;
         pub mod coprime_with;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -204,7 +211,9 @@ pub mod natural {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod gcd;
+        pub mod is_perfect_power;
         pub mod is_power_of_2;
         pub mod kronecker_symbol;
         pub mod lcm;
@@ -214,6 +223,7 @@ pub mod natural {
         pub mod mod_add;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_log;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
@@ -231,8 +241,10 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod montgomery;
         pub mod mul;
         pub mod neg;
         pub mod next_power_of_2;
@@ -282,6 +294,7 @@ pub mod natural {
             pub mod to_power_of_2_digits;
         }
         pub mod from_bool;
+        pub mod from_bytes;
         pub mod from_limbs;
         pub mod from_primitive_float;
         pub mod from_primitive_int;
@@ -300,6 +313,7 @@ pub mod natural {
             pub mod to_sci;
             pub mod to_string;
         }
+        pub mod to_bytes;
         pub mod to_limbs;
     }
     pub mod exhaustive {
@@ -310,7 +324,17 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod carmichael_lambda;
+        pub mod divisor_count;
+        pub mod divisor_sum;
+        pub mod divisors;
+        pub mod factor;
+        pub mod is_prime;
+        pub mod moebius;
+        pub mod next_prime;
+        pub mod preceding_prime;
         pub mod primes;
+        pub mod totient;
     }
     pub mod logic {
         pub mod and;
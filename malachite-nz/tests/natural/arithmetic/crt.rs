@@ -0,0 +1,173 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, ModInverse};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::arithmetic::crt::SubproductTree;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen, natural_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn parse_naturals(xs: &[&str]) -> Vec<Natural> {
+    xs.iter().map(|x| Natural::from_str(x).unwrap()).collect()
+}
+
+// Combines the residues one at a time.
+fn crt_naive(residues: &[Natural], moduli: &[Natural]) -> Natural {
+    let mut x = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (r, n) in residues.iter().zip(moduli.iter()) {
+        if *n != 1u32 {
+            let inverse = (&m % n).mod_inverse(n).unwrap();
+            let t = ((r + n - &x % n) * inverse) % n;
+            x += &m * t;
+        }
+        m *= n;
+    }
+    x
+}
+
+#[test]
+fn test_subproduct_tree() {
+    let test = |moduli: &[&str], product: &str, coprime: bool, x: &str, remainders: &str| {
+        let tree = SubproductTree::new(&parse_naturals(moduli));
+        assert_eq!(tree.product().to_string(), product);
+        assert_eq!(tree.moduli_are_pairwise_coprime(), coprime);
+        let x = Natural::from_str(x).unwrap();
+        let rs = tree.remainders(&x);
+        assert_eq!(rs.to_debug_string(), remainders);
+        if coprime {
+            assert_eq!(tree.crt(&rs), x % tree.product());
+        }
+    };
+    test(&["1"], "1", true, "10", "[0]");
+    test(&["7"], "7", true, "10", "[3]");
+    test(&["3", "5", "7"], "105", true, "100", "[1, 0, 2]");
+    test(&["3", "5", "7"], "105", true, "1000", "[1, 0, 6]");
+    test(&["1", "1", "2"], "2", true, "5", "[0, 0, 1]");
+    test(&["4", "6"], "24", false, "11", "[3, 5]");
+    test(
+        &["2", "3", "5", "7", "11", "13", "17", "19", "23"],
+        "223092870",
+        true,
+        "123456789",
+        "[1, 0, 4, 1, 5, 1, 1, 14, 11]",
+    );
+    test(&["6", "5", "7", "9"], "1890", false, "100", "[4, 0, 2, 1]");
+    test(
+        &["1000000000000000000000000000057", "18446744073709551557", "4294967291"],
+        "79228162422030616971593122091516005258055745167380807958959",
+        true,
+        "1000000000000000000000000000000000000000000000000000000000000000000",
+        "[3249000000, 8999740049877470215, 751539378]",
+    );
+}
+
+#[test]
+fn subproduct_tree_fail() {
+    assert_panic!(SubproductTree::new(&[]));
+    assert_panic!(SubproductTree::new(&[Natural::from(3u32), Natural::ZERO]));
+    let tree = SubproductTree::new(&[Natural::from(4u32), Natural::from(6u32)]);
+    assert_panic!(tree.crt(&[Natural::ONE, Natural::ONE]));
+    let tree = SubproductTree::new(&[Natural::from(3u32), Natural::from(5u32)]);
+    assert_panic!(tree.crt(&[Natural::ONE]));
+    assert_panic!(tree.crt(&[Natural::ONE, Natural::from(5u32)]));
+}
+
+#[test]
+fn test_crt() {
+    let test = |residues: &[&str], moduli: &[&str], out: &str| {
+        let residues = parse_naturals(residues);
+        let moduli = parse_naturals(moduli);
+        let x = Natural::crt(&residues, &moduli);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(crt_naive(&residues, &moduli), x);
+    };
+    test(&["0"], &["1"], "0");
+    test(&["3"], &["7"], "3");
+    test(&["2", "3", "2"], &["3", "5", "7"], "23");
+    test(&["0", "0", "0"], &["3", "5", "7"], "0");
+    test(&["2", "4", "6"], &["3", "5", "7"], "104");
+    test(&["1", "2", "3", "4"], &["2", "3", "5", "7"], "53");
+    test(&["0", "1"], &["1", "2"], "1");
+    test(
+        &["1", "2", "3"],
+        &["1000000000000000000000000000057", "18446744073709551557", "4294967291"],
+        "28504139267298666758622210663624735938236024005241466007735",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Natural::crt(&[], &[]));
+    assert_panic!(Natural::crt(&[Natural::ONE], &[Natural::ZERO]));
+    assert_panic!(Natural::crt(
+        &[Natural::ONE, Natural::ONE],
+        &[Natural::from(4u32), Natural::from(6u32)]
+    ));
+    assert_panic!(Natural::crt(
+        &[Natural::ONE],
+        &[Natural::from(3u32), Natural::from(5u32)]
+    ));
+    assert_panic!(Natural::crt(
+        &[Natural::from(3u32), Natural::ONE],
+        &[Natural::from(3u32), Natural::from(5u32)]
+    ));
+}
+
+#[test]
+fn subproduct_tree_properties() {
+    natural_vec_gen().test_properties(|xs| {
+        if xs.is_empty() {
+            return;
+        }
+        let moduli: Vec<Natural> = xs.iter().map(|x| x + Natural::ONE).collect();
+        let tree = SubproductTree::new(&moduli);
+        assert_eq!(tree.moduli(), moduli);
+        assert_eq!(*tree.product(), moduli.iter().product::<Natural>());
+
+        let mut coprime = true;
+        for (i, m) in moduli.iter().enumerate() {
+            for n in &moduli[i + 1..] {
+                if !m.coprime_with(n) {
+                    coprime = false;
+                }
+            }
+        }
+        assert_eq!(tree.moduli_are_pairwise_coprime(), coprime);
+
+        let x: Natural = xs.iter().sum::<Natural>() * xs.iter().product::<Natural>();
+        let rs = tree.remainders(&x);
+        assert_eq!(rs.len(), moduli.len());
+        for (r, m) in rs.iter().zip(moduli.iter()) {
+            assert_eq!(*r, &x % m);
+        }
+        if coprime {
+            let y = tree.crt(&rs);
+            assert_eq!(y, &x % tree.product());
+            assert_eq!(y, crt_naive(&rs, &moduli));
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        let m = x + Natural::ONE;
+        let n = &m + Natural::ONE;
+        let tree = SubproductTree::new(&[m.clone(), n.clone()]);
+        assert!(tree.moduli_are_pairwise_coprime());
+        let z = &y % tree.product();
+        assert_eq!(tree.crt(&[&y % m, &y % n]), z);
+    });
+}
+
+#[test]
+fn crt_properties() {
+    let primes: Vec<Natural> = u64::primes().take(100).map(Natural::from).collect();
+    let tree = SubproductTree::new(&primes);
+    assert!(tree.moduli_are_pairwise_coprime());
+    natural_gen().test_properties(|x| {
+        let rs = tree.remainders(&x);
+        let y = tree.crt(&rs);
+        assert_eq!(y, &x % tree.product());
+        assert_eq!(Natural::crt(&rs, &primes), y);
+    });
+}
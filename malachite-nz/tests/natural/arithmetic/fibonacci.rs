@@ -0,0 +1,152 @@
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, FibonacciPair, Lucas, Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::natural::arithmetic::fibonacci::{fibonacci_pair_naive, lucas_naive};
+use rug::Complete;
+
+#[test]
+fn test_fibonacci() {
+    fn test(n: u64, out: &str) {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(fibonacci_pair_naive(n).0.to_string(), out);
+        assert_eq!(
+            rug::Integer::fibonacci(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "1");
+    test(3, "2");
+    test(4, "3");
+    test(5, "5");
+    test(10, "55");
+    test(93, "12200160415121876738");
+    test(94, "19740274219868223167");
+    test(95, "31940434634990099905");
+    test(100, "354224848179261915075");
+    test(200, "280571172992510140037611932413038677189525");
+}
+
+#[test]
+fn test_fibonacci_pair() {
+    fn test(n: u64, out_0: &str, out_1: &str) {
+        let (f, f_prev) = Natural::fibonacci_pair(n);
+        assert!(f.is_valid());
+        assert!(f_prev.is_valid());
+        assert_eq!(f.to_string(), out_0);
+        assert_eq!(f_prev.to_string(), out_1);
+        let (f_alt, f_prev_alt) = fibonacci_pair_naive(n);
+        assert_eq!(f_alt, f);
+        assert_eq!(f_prev_alt, f_prev);
+        let (rug_f, rug_f_prev) = rug::Integer::fibonacci_2(u32::exact_from(n)).complete();
+        assert_eq!(rug_f.to_string(), out_0);
+        assert_eq!(rug_f_prev.to_string(), out_1);
+    }
+    test(0, "0", "1");
+    test(1, "1", "0");
+    test(2, "1", "1");
+    test(10, "55", "34");
+    test(94, "19740274219868223167", "12200160415121876738");
+    test(100, "354224848179261915075", "218922995834555169026");
+}
+
+#[test]
+fn test_lucas() {
+    fn test(n: u64, out: &str) {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+        assert_eq!(lucas_naive(n).to_string(), out);
+        assert_eq!(
+            rug::Integer::lucas(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(3, "4");
+    test(10, "123");
+    test(92, "16860207025497407047");
+    test(93, "27280388024614569596");
+    test(94, "44140595050111976643");
+    test(100, "792070839848372253127");
+    test(200, "627376215338105766356982006981782561278127");
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(fibonacci_pair_naive(n).0, f);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::fibonacci(u32::exact_from(n)).complete()),
+            f
+        );
+        assert_eq!(Natural::fibonacci(n + 2), Natural::fibonacci(n + 1) + &f);
+        // F_{2n} = F_n L_n
+        assert_eq!(Natural::fibonacci(n << 1), &f * Natural::lucas(n));
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(f) = Limb::checked_fibonacci(n) {
+            assert_eq!(Natural::fibonacci(n), f);
+        }
+    });
+}
+
+#[test]
+fn fibonacci_pair_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let (f, f_prev) = Natural::fibonacci_pair(n);
+        assert!(f.is_valid());
+        assert!(f_prev.is_valid());
+        assert_eq!(Natural::fibonacci(n), f);
+        let (f_next, f_2) = Natural::fibonacci_pair(n + 1);
+        assert_eq!(f_2, f);
+        assert_eq!(f_next, &f + &f_prev);
+        // Cassini's identity: F_{n+1}F_{n-1} - F_n^2 = (-1)^n
+        if n & 1 == 0 {
+            assert_eq!(f_next * f_prev, f.square() + Natural::ONE);
+        } else {
+            assert_eq!(f_next * f_prev + Natural::ONE, f.square());
+        }
+    });
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(lucas_naive(n), l);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::lucas(u32::exact_from(n)).complete()),
+            l
+        );
+        assert_eq!(Natural::lucas(n + 2), Natural::lucas(n + 1) + &l);
+        // L_n = F_{n+1} + F_{n-1}
+        let (f_next, f) = Natural::fibonacci_pair(n + 1);
+        let f_prev = &f_next - f;
+        assert_eq!(f_next + f_prev, l);
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some(l) = Limb::checked_lucas(n) {
+            assert_eq!(Natural::lucas(n), l);
+        }
+    });
+}
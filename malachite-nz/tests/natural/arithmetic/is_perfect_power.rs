@@ -0,0 +1,96 @@
+use malachite_base::num::arithmetic::traits::{CheckedRoot, ExpressAsPower, IsPerfectPower, Pow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_gen, natural_gen_var_2, natural_unsigned_pair_gen_var_7,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_express_as_power() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let result = n.express_as_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(n.is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("2", "None");
+    test("4", "Some((2, 2))");
+    test("12", "None");
+    test("64", "Some((2, 6))");
+    test("1000000", "Some((10, 6))");
+    test("18446744073709551615", "None");
+    // 2^64
+    test("18446744073709551616", "Some((2, 64))");
+    // 2^64 + 1
+    test("18446744073709551617", "None");
+    // (2^64 - 59)^2
+    test(
+        "340282366920938461286658806734041124249",
+        "Some((18446744073709551557, 2))",
+    );
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", "None");
+    // 6^50
+    test("808281277464764060643139600456536293376", "Some((6, 50))");
+    // 10^30
+    test("1000000000000000000000000000000", "Some((10, 30))");
+    // 3^81
+    test("443426488243037769948249630619149892803", "Some((3, 81))");
+    // (10^20 + 1)^3
+    test(
+        "1000000000000000000030000000000000000000300000000000000000001",
+        "Some((100000000000000000001, 3))",
+    );
+    // 3^100 + 1
+    test("515377520732011331036461129765621272702107522002", "None");
+}
+
+#[test]
+fn express_as_power_properties() {
+    natural_gen().test_properties(|n| {
+        let result = n.express_as_power();
+        assert_eq!(n.is_perfect_power(), result.is_some());
+        if let Some((x, e)) = result {
+            assert!(e > 1);
+            assert_eq!((&x).pow(e), n);
+            if n > 1u32 {
+                assert!(!x.is_perfect_power());
+            }
+        } else {
+            assert!(n > 1u32);
+            for e in 2..n.significant_bits() {
+                assert!((&n).checked_root(e).is_none());
+            }
+        }
+    });
+
+    natural_unsigned_pair_gen_var_7::<u64>().test_properties_with_limit(SMALL_LIMIT, |(x, e)| {
+        if x <= 1u32 || e < 2 {
+            return;
+        }
+        let (y, f) = (&x).pow(e).express_as_power().unwrap();
+        assert_eq!(f % e, 0);
+        assert_eq!(y.pow(f / e), x);
+    });
+
+    unsigned_gen::<Limb>().test_properties(|x| {
+        assert_eq!(
+            Natural::from(x).express_as_power(),
+            x.express_as_power().map(|(y, e)| (Natural::from(y), e))
+        );
+        assert_eq!(Natural::from(x).is_perfect_power(), x.is_perfect_power());
+    });
+
+    // By Mihăilescu's theorem, n^2 + 1 is never a perfect power when n is positive.
+    natural_gen_var_2().test_properties(|n| {
+        assert!(!((&n).pow(2) + Natural::ONE).is_perfect_power());
+    });
+}
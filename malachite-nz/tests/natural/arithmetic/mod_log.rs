@@ -0,0 +1,173 @@
+use malachite_base::num::arithmetic::traits::{ModLog, ModMul, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_pair_gen_var_8, natural_triple_gen_var_3};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_log() {
+    let test = |h, g, m, out| {
+        let h = Natural::from_str(h).unwrap();
+        let g = Natural::from_str(g).unwrap();
+        let m = Natural::from_str(m).unwrap();
+
+        let x = h.clone().mod_log(g.clone(), m.clone());
+        assert_eq!(x.to_debug_string(), out);
+        assert_eq!((&h).mod_log(&g, &m), x);
+        if let Some(x) = x {
+            assert_eq!(g.mod_pow(x, &m), h);
+        }
+    };
+    test("0", "0", "1", "Some(0)");
+    test("0", "0", "2", "Some(1)");
+    test("3", "2", "11", "Some(8)");
+    test("1", "2", "11", "Some(0)");
+    test("0", "2", "11", "None");
+    test("3", "2", "7", "None");
+    test("13", "5", "23", "Some(14)");
+    test("0", "2", "8", "Some(3)");
+    test("9", "3", "18", "Some(2)");
+    test("123456789", "5", "1000000007", "Some(981640996)");
+    // p - 1 = 2 * 3^3 * 7^2 * 19 * 43 * 73 * 127 * 337 * 5419 * 92737 * 649657 * 77158673929
+    test(
+        "12345678901234567890123456789",
+        "43",
+        "170141183460469231731687303715884105727",
+        "Some(76718981288249231177429940104838389527)",
+    );
+    // m = 2^10 * (2^61 - 1)
+    test("7776", "6", "2361183241434822605824", "Some(5)");
+    test(
+        "507227786639038825472",
+        "6",
+        "2361183241434822605824",
+        "Some(1000)",
+    );
+    test("3", "6", "2361183241434822605824", "None");
+}
+
+#[test]
+fn mod_log_fail() {
+    assert_panic!(Natural::ZERO.mod_log(Natural::ZERO, Natural::ZERO));
+    assert_panic!(Natural::from(10u32).mod_log(Natural::ONE, Natural::from(10u32)));
+    assert_panic!(Natural::ONE.mod_log(Natural::from(10u32), Natural::from(10u32)));
+    assert_panic!((&Natural::from(10u32)).mod_log(&Natural::ONE, &Natural::from(10u32)));
+    assert_panic!((&Natural::ONE).mod_log(&Natural::from(10u32), &Natural::from(10u32)));
+}
+
+#[test]
+fn test_mod_log_with_order_factorization() {
+    let test = |h, g, m, factors: &[(&str, u64)], out| {
+        let h = Natural::from_str(h).unwrap();
+        let g = Natural::from_str(g).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, k)| (Natural::from_str(p).unwrap(), k))
+            .collect();
+        let x = h.mod_log_with_order_factorization(&g, &m, &factors);
+        assert_eq!(x.to_debug_string(), out);
+        assert_eq!(x, (&h).mod_log(&g, &m));
+    };
+    test("0", "0", "1", &[], "Some(0)");
+    test("3", "2", "11", &[("2", 1), ("5", 1)], "Some(8)");
+    test("0", "2", "11", &[("2", 1), ("5", 1)], "None");
+    // A multiple of the order is enough
+    test("3", "2", "11", &[("2", 3), ("3", 1), ("5", 2)], "Some(8)");
+    test("4", "2", "7", &[("3", 1)], "Some(2)");
+    test("3", "2", "7", &[("3", 1)], "None");
+    test(
+        "12345678901234567890123456789",
+        "43",
+        "170141183460469231731687303715884105727",
+        &[
+            ("2", 1),
+            ("3", 3),
+            ("7", 2),
+            ("19", 1),
+            ("43", 1),
+            ("73", 1),
+            ("127", 1),
+            ("337", 1),
+            ("5419", 1),
+            ("92737", 1),
+            ("649657", 1),
+            ("77158673929", 1),
+        ],
+        "Some(76718981288249231177429940104838389527)",
+    );
+}
+
+#[test]
+fn mod_log_with_order_factorization_fail() {
+    let factors = [(Natural::from(2u32), 1), (Natural::from(5u32), 1)];
+    // not reduced
+    assert_panic!(Natural::from(11u32).mod_log_with_order_factorization(
+        &Natural::from(2u32),
+        &Natural::from(11u32),
+        &factors
+    ));
+    // base not coprime to m
+    assert_panic!(Natural::from(4u32).mod_log_with_order_factorization(
+        &Natural::from(2u32),
+        &Natural::from(8u32),
+        &[(Natural::from(2u32), 2)]
+    ));
+    // not a multiple of the order
+    assert_panic!(Natural::from(3u32).mod_log_with_order_factorization(
+        &Natural::from(2u32),
+        &Natural::from(11u32),
+        &[(Natural::from(2u32), 1)]
+    ));
+}
+
+#[test]
+fn mod_log_properties() {
+    natural_triple_gen_var_3().test_properties(|(h, g, m)| {
+        // Large moduli may require large baby-step giant-step tables.
+        if m.significant_bits() > 40 {
+            return;
+        }
+        let x = (&h).mod_log(&g, &m);
+        assert_eq!(h.clone().mod_log(g.clone(), m.clone()), x);
+        if let Some(x) = &x {
+            assert!(*x < m);
+            assert_eq!((&g).mod_pow(x, &m), h);
+        }
+        if Limb::convertible_from(&m) {
+            assert_eq!(
+                x,
+                Limb::wrapping_from(&h)
+                    .mod_log(Limb::wrapping_from(&g), Limb::wrapping_from(&m))
+                    .map(Natural::from)
+            );
+        }
+    });
+
+    natural_pair_gen_var_8().test_properties(|(g, m)| {
+        if m.significant_bits() > 40 {
+            return;
+        }
+        let h = (&g).mod_pow(Natural::from(10u32), &m);
+        assert!((&h).mod_log(&g, &m).unwrap() <= 10u32);
+        assert_eq!((&(Natural::ONE % &m)).mod_log(&g, &m), Some(Natural::ZERO));
+        let h = (&g).mod_mul(&g, &m);
+        assert!((&h).mod_log(&g, &m).unwrap() <= 2u32);
+    });
+
+    unsigned_triple_gen_var_12::<Limb>().test_properties(|(h, g, m)| {
+        if m.significant_bits() > 24 {
+            return;
+        }
+        assert_eq!(
+            Natural::from(h).mod_log(Natural::from(g), Natural::from(m)),
+            h.mod_log(g, m).map(Natural::from)
+        );
+    });
+}
@@ -0,0 +1,190 @@
+use malachite_base::num::arithmetic::traits::{ModMul, ModSqrt, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{
+    natural_gen_var_2, natural_pair_gen, natural_pair_gen_var_8,
+};
+use std::cmp::min;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |x, m, out| {
+        let x = Natural::from_str(x).unwrap();
+        let m = Natural::from_str(m).unwrap();
+
+        let root = x.clone().mod_sqrt(m.clone());
+        assert_eq!(root.to_debug_string(), out);
+        assert_eq!(x.clone().mod_sqrt(&m), root);
+        assert_eq!((&x).mod_sqrt(m.clone()), root);
+        assert_eq!((&x).mod_sqrt(&m), root);
+        if let Some(root) = root {
+            assert_eq!((&root).mod_mul(&root, &m), x);
+        }
+    };
+    test("0", "1", "Some(0)");
+    test("0", "7", "Some(0)");
+    test("2", "7", "Some(3)");
+    test("3", "7", "None");
+    test("4", "15", "Some(7)");
+    test("17", "64", "Some(9)");
+    test("36", "81", "Some(6)");
+    test("27", "81", "None");
+    // p ≡ 3 mod 4
+    test(
+        "2",
+        "170141183460469231731687303715884105727",
+        "Some(18446744073709551616)",
+    );
+    test("3", "170141183460469231731687303715884105727", "None");
+    // Tonelli-Shanks
+    test(
+        "2",
+        "1000000000000000000000000000057",
+        "Some(395746790562060615678394010553)",
+    );
+    test("5", "1000000000000000000000000000057", "None");
+    test(
+        "5",
+        "1000000000000000000000000000529",
+        "Some(336390493073553609331305307279)",
+    );
+    test("3", "1000000000000000000000000000529", "None");
+    // Cipolla
+    test(
+        "2",
+        "209162349037657851246956028887041",
+        "Some(2708877323875654048997667164873)",
+    );
+    test(
+        "7",
+        "209162349037657851246956028887041",
+        "Some(59938732704164335071816963885447)",
+    );
+    // Composite
+    test(
+        "4",
+        "1000000000000000000000000000114000000000000000000000000003249",
+        "Some(2)",
+    );
+    test(
+        "5",
+        "1000000000000000000000000000114000000000000000000000000003249",
+        "None",
+    );
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_sqrt(Natural::ZERO));
+    assert_panic!(Natural::from(10u32).mod_sqrt(Natural::from(10u32)));
+    assert_panic!(Natural::from(10u32).mod_sqrt(&Natural::from(10u32)));
+    assert_panic!((&Natural::from(10u32)).mod_sqrt(Natural::from(10u32)));
+    assert_panic!((&Natural::from(10u32)).mod_sqrt(&Natural::from(10u32)));
+}
+
+#[test]
+fn test_mod_sqrt_with_factorization() {
+    let test = |x, factors: &[(&str, u64)], out| {
+        let x = Natural::from_str(x).unwrap();
+        let factors: Vec<(Natural, u64)> = factors
+            .iter()
+            .map(|&(p, k)| (Natural::from_str(p).unwrap(), k))
+            .collect();
+        let root = x.mod_sqrt_with_factorization(&factors);
+        assert_eq!(root.to_debug_string(), out);
+        if let Some(root) = root {
+            let m: Natural = factors.iter().map(|(p, k)| p.pow(*k)).product();
+            assert_eq!((&root).mod_mul(&root, &m), x);
+        }
+    };
+    test("0", &[], "Some(0)");
+    test("2", &[("7", 1)], "Some(3)");
+    test("36", &[("3", 4)], "Some(6)");
+    test("4", &[("3", 1), ("5", 1)], "Some(7)");
+    let p = "170141183460469231731687303715884105727";
+    let q = "618970019642690137449562111";
+    test("4", &[(q, 1), (p, 2)], "Some(2)");
+    test("3", &[(q, 1), (p, 2)], "None");
+    test("2", &[(p, 3)], "Some(200248784617585320224533284953395510740080360386319521705137782343828307781905667057000879489024)");
+}
+
+#[test]
+fn mod_sqrt_with_factorization_fail() {
+    assert_panic!(Natural::ONE.mod_sqrt_with_factorization(&[]));
+    assert_panic!(Natural::ONE.mod_sqrt_with_factorization(&[(Natural::from(3u32), 0)]));
+    assert_panic!(Natural::from(9u32).mod_sqrt_with_factorization(&[(Natural::from(3u32), 2)]));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        // Factoring large moduli is slow.
+        if m.significant_bits() > 80 {
+            return;
+        }
+        let root = x.clone().mod_sqrt(m.clone());
+        assert_eq!(x.clone().mod_sqrt(&m), root);
+        assert_eq!((&x).mod_sqrt(m.clone()), root);
+        assert_eq!((&x).mod_sqrt(&m), root);
+        if let Some(root) = &root {
+            assert!(*root < m);
+            assert_eq!(root.mod_mul(root, &m), x);
+            if m.is_prime() && *root != 0u32 {
+                assert!(*root <= &m - root);
+            }
+        }
+        assert!((&x).mod_mul(&x, &m).mod_sqrt(&m).is_some());
+        if Limb::convertible_from(&m) {
+            assert_eq!(
+                root,
+                Limb::wrapping_from(&x)
+                    .mod_sqrt(Limb::wrapping_from(&m))
+                    .map(Natural::from)
+            );
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        // Finding large primes is slow.
+        if y.significant_bits() > 256 {
+            return;
+        }
+        let p = (y + Natural::ONE).next_prime();
+        let x = x % &p;
+        let square = (&x).mod_mul(&x, &p);
+        let root = min(x.clone(), &p - &x) % &p;
+        assert_eq!(
+            square.mod_sqrt_with_factorization(&[(p.clone(), 1)]),
+            Some(root)
+        );
+        let q = (&p).pow(2);
+        let square = (&x).mod_mul(&x, &q);
+        let root = square.mod_sqrt_with_factorization(&[(p, 2)]).unwrap();
+        assert_eq!((&root).mod_mul(&root, &q), square);
+    });
+
+    natural_gen_var_2().test_properties(|m| {
+        if m.significant_bits() > 80 {
+            return;
+        }
+        assert_eq!(Natural::ZERO.mod_sqrt(&m), Some(Natural::ZERO));
+        if m != 1u32 {
+            assert_eq!(Natural::ONE.mod_sqrt(&m), Some(Natural::ONE));
+        }
+    });
+
+    unsigned_pair_gen_var_16::<Limb>().test_properties(|(x, m)| {
+        assert_eq!(
+            Natural::from(x).mod_sqrt(Natural::from(m)),
+            x.mod_sqrt(m).map(Natural::from)
+        );
+    });
+}
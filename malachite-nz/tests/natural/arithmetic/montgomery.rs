@@ -0,0 +1,227 @@
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMul, ModNeg, ModPow, ModSquare, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::arithmetic::montgomery::MontgomeryContext;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_gen_var_8, natural_pair_gen_var_12, natural_triple_gen_var_8,
+};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_montgomery_context() {
+    let test = |x, y, m, sum, difference, product, square, inverse| {
+        let x = Natural::from_str(x).unwrap();
+        let y = Natural::from_str(y).unwrap();
+        let ctx = MontgomeryContext::new(Natural::from_str(m).unwrap());
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        assert_eq!(ctx.from_montgomery(&ctx.add(&x_m, &y_m)).to_string(), sum);
+        assert_eq!(
+            ctx.from_montgomery(&ctx.sub(&x_m, &y_m)).to_string(),
+            difference
+        );
+        assert_eq!(
+            ctx.from_montgomery(&ctx.mul(&x_m, &y_m)).to_string(),
+            product
+        );
+        assert_eq!(ctx.from_montgomery(&ctx.square(&x_m)).to_string(), square);
+
+        let mut scratch = ctx.scratch();
+        let mut z = x_m.clone();
+        ctx.add_assign(&mut z, &y_m);
+        assert_eq!(ctx.from_montgomery(&z).to_string(), sum);
+        let mut z = x_m.clone();
+        ctx.sub_assign(&mut z, &y_m);
+        assert_eq!(ctx.from_montgomery(&z).to_string(), difference);
+        let mut z = x_m.clone();
+        ctx.mul_assign(&mut z, &y_m, &mut scratch);
+        assert_eq!(ctx.from_montgomery(&z).to_string(), product);
+        let mut z = x_m.clone();
+        ctx.square_assign(&mut z, &mut scratch);
+        assert_eq!(ctx.from_montgomery(&z).to_string(), square);
+        assert_eq!(
+            ctx.inverse(&x_m)
+                .map(|x| ctx.from_montgomery(&x))
+                .to_debug_string(),
+            inverse
+        );
+    };
+    test("0", "0", "1", "0", "0", "0", "0", "Some(0)");
+    test("0", "0", "101", "0", "0", "0", "0", "None");
+    test("60", "70", "101", "29", "91", "59", "65", "Some(32)");
+    test("150", "1", "101", "50", "48", "49", "78", "Some(33)");
+    test("3", "5", "9", "8", "7", "6", "0", "None");
+    test(
+        "1000000000000",
+        "999999999999",
+        "1000000000001",
+        "999999999998",
+        "1",
+        "2",
+        "1",
+        "Some(1000000000000)",
+    );
+    test(
+        "123456789012345678901234567890",
+        "987654321098765432109876543210",
+        "1000000000000000000000000000057",
+        "111111110111111111011111111043",
+        "135802467913580246791358024737",
+        "672763357427221473218716661518",
+        "667428798567291505096784013123",
+        "Some(702408638268987573765028300612)",
+    );
+}
+
+#[test]
+fn montgomery_context_fail() {
+    assert_panic!(MontgomeryContext::new(Natural::ZERO));
+    assert_panic!(MontgomeryContext::new(Natural::from(10u32)));
+}
+
+#[test]
+fn test_montgomery_context_pow() {
+    let test = |x, exp, m, out| {
+        let ctx = MontgomeryContext::new(Natural::from_str(m).unwrap());
+        let x_m = ctx.to_montgomery(&Natural::from_str(x).unwrap());
+        let exp = Natural::from_str(exp).unwrap();
+        assert_eq!(ctx.from_montgomery(&ctx.pow(&x_m, &exp)).to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "101", "1");
+    test("0", "5", "101", "0");
+    test("3", "10", "101", "65");
+    test("3", "100", "101", "1");
+    test("4", "13", "497", "445");
+    test(
+        "2",
+        "1000000000000000000000000000000",
+        "1000000000000000000000000000057",
+        "58312328499660157077322253375",
+    );
+}
+
+#[test]
+fn test_montgomery_context_large_modulus() {
+    // The modulus is large enough that full-size Montgomery reduction is used.
+    let m = Natural::from(3u32).pow(8000) - Natural::from(4u32);
+    let ctx = MontgomeryContext::new(m.clone());
+    let x = Natural::from(7u32).pow(5000);
+    let y = Natural::from(11u32).pow(4000);
+    let x_m = ctx.to_montgomery(&x);
+    let y_m = ctx.to_montgomery(&y);
+    assert_eq!(ctx.from_montgomery(&x_m), &x % &m);
+    assert_eq!(
+        ctx.from_montgomery(&ctx.mul(&x_m, &y_m)),
+        (&x).mod_mul(&y, &m)
+    );
+    assert_eq!(ctx.from_montgomery(&ctx.square(&x_m)), (&x).mod_square(&m));
+    let mut scratch = ctx.scratch();
+    let mut z = x_m.clone();
+    ctx.mul_assign(&mut z, &y_m, &mut scratch);
+    ctx.square_assign(&mut z, &mut scratch);
+    assert_eq!(ctx.from_montgomery(&z), (&x).mod_mul(&y, &m).mod_square(&m));
+    let exp = Natural::from(12345u32);
+    assert_eq!(
+        ctx.from_montgomery(&ctx.pow(&x_m, &exp)),
+        (&x).mod_pow(&exp, &m)
+    );
+    let inverse = ctx.inverse(&x_m).unwrap();
+    assert_eq!(ctx.mul(&inverse, &x_m), ctx.one());
+}
+
+#[test]
+fn montgomery_context_properties() {
+    natural_triple_gen_var_8().test_properties(|(x, y, m)| {
+        let ctx = MontgomeryContext::new(m.clone());
+        assert_eq!(*ctx.modulus(), m);
+        let x_m = ctx.to_montgomery(&x);
+        let y_m = ctx.to_montgomery(&y);
+        let x = x % &m;
+        let y = y % &m;
+        assert_eq!(ctx.from_montgomery(&x_m), x);
+        assert_eq!(
+            ctx.from_montgomery(&ctx.add(&x_m, &y_m)),
+            (&x).mod_add(&y, &m)
+        );
+        assert_eq!(
+            ctx.from_montgomery(&ctx.sub(&x_m, &y_m)),
+            (&x).mod_sub(&y, &m)
+        );
+        assert_eq!(
+            ctx.from_montgomery(&ctx.mul(&x_m, &y_m)),
+            (&x).mod_mul(&y, &m)
+        );
+        assert_eq!(ctx.mul(&x_m, &y_m), ctx.mul(&y_m, &x_m));
+        assert_eq!(ctx.add(&x_m, &y_m), ctx.add(&y_m, &x_m));
+        assert_eq!(ctx.sub(&x_m, &y_m), ctx.neg(&ctx.sub(&y_m, &x_m)));
+        assert_eq!(ctx.add(&ctx.sub(&x_m, &y_m), &y_m), x_m);
+
+        let mut scratch = ctx.scratch();
+        let mut z = x_m.clone();
+        ctx.add_assign(&mut z, &y_m);
+        assert_eq!(z, ctx.add(&x_m, &y_m));
+        let mut z = x_m.clone();
+        ctx.sub_assign(&mut z, &y_m);
+        assert_eq!(z, ctx.sub(&x_m, &y_m));
+        let mut z = x_m.clone();
+        ctx.mul_assign(&mut z, &y_m, &mut scratch);
+        assert_eq!(z, ctx.mul(&x_m, &y_m));
+        // The same scratch can be reused.
+        ctx.mul_assign(&mut z, &y_m, &mut scratch);
+        assert_eq!(z, ctx.mul(&ctx.mul(&x_m, &y_m), &y_m));
+    });
+
+    natural_pair_gen_var_12().test_properties(|(x, m)| {
+        let ctx = MontgomeryContext::new(m.clone());
+        let x_m = ctx.to_montgomery(&x);
+        let x = x % &m;
+        assert_eq!(x_m.is_zero(), x == 0u32);
+        assert_eq!(ctx.from_montgomery(&ctx.square(&x_m)), (&x).mod_square(&m));
+        assert_eq!(ctx.square(&x_m), ctx.mul(&x_m, &x_m));
+        assert_eq!(ctx.from_montgomery(&ctx.neg(&x_m)), (&x).mod_neg(&m));
+        assert_eq!(ctx.add(&x_m, &ctx.neg(&x_m)), ctx.zero());
+        let mut z = x_m.clone();
+        ctx.neg_assign(&mut z);
+        assert_eq!(z, ctx.neg(&x_m));
+        let mut z = x_m.clone();
+        ctx.square_assign(&mut z, &mut ctx.scratch());
+        assert_eq!(z, ctx.square(&x_m));
+        assert_eq!(ctx.mul(&x_m, &ctx.one()), x_m);
+        assert_eq!(ctx.mul(&x_m, &ctx.zero()), ctx.zero());
+        let inverse = ctx.inverse(&x_m);
+        if m == 1u32 {
+            assert_eq!(inverse, Some(ctx.zero()));
+        } else if x == 0u32 {
+            assert_eq!(inverse, None);
+        } else {
+            assert_eq!(
+                inverse.as_ref().map(|y| ctx.from_montgomery(y)),
+                (&x).mod_inverse(&m)
+            );
+            if let Some(inverse) = inverse {
+                assert_eq!(ctx.mul(&inverse, &x_m), ctx.one());
+            }
+        }
+        for exp in 0u32..10 {
+            let exp = Natural::from(exp);
+            assert_eq!(
+                ctx.from_montgomery(&ctx.pow(&x_m, &exp)),
+                (&x).mod_pow(&exp, &m)
+            );
+        }
+    });
+
+    natural_gen_var_8().test_properties(|m| {
+        let ctx = MontgomeryContext::new(m.clone());
+        assert_eq!(ctx.from_montgomery(&ctx.zero()), 0u32);
+        assert_eq!(ctx.from_montgomery(&ctx.one()), Natural::ONE % &m);
+        assert_eq!(ctx.to_montgomery(&m), ctx.zero());
+        assert_eq!(ctx.to_montgomery(&Natural::ONE), ctx.one());
+        assert_eq!(ctx.pow(&ctx.one(), &m), ctx.one());
+    });
+}
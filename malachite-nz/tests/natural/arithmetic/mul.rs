@@ -18,6 +18,9 @@ use malachite_nz::natural::arithmetic::mul::fft::{
     limbs_square_to_out_fft, limbs_square_to_out_fft_scratch_len,
     limbs_square_to_out_fft_with_cutoff, limbs_square_to_out_fft_with_cutoff_scratch_len,
 };
+use malachite_nz::natural::arithmetic::mul::fft_small::{
+    limbs_mul_greater_to_out_fft_small, limbs_square_to_out_fft_small,
+};
 use malachite_nz::natural::arithmetic::mul::limb::{
     limbs_mul_limb, limbs_mul_limb_to_out, limbs_mul_limb_with_carry_to_out,
     limbs_slice_mul_limb_in_place, limbs_slice_mul_limb_with_carry_in_place,
@@ -55,11 +58,12 @@ use malachite_nz::natural::Natural;
 use malachite_nz::platform::{DoubleLimb, Limb};
 use malachite_nz::test_util::generators::{
     natural_gen, natural_pair_gen, natural_triple_gen, natural_vec_gen,
-    unsigned_vec_pair_gen_var_33, unsigned_vec_triple_gen_var_10, unsigned_vec_triple_gen_var_11,
-    unsigned_vec_triple_gen_var_12, unsigned_vec_triple_gen_var_13, unsigned_vec_triple_gen_var_14,
-    unsigned_vec_triple_gen_var_15, unsigned_vec_triple_gen_var_16, unsigned_vec_triple_gen_var_4,
-    unsigned_vec_triple_gen_var_5, unsigned_vec_triple_gen_var_6, unsigned_vec_triple_gen_var_60,
-    unsigned_vec_triple_gen_var_7, unsigned_vec_triple_gen_var_8, unsigned_vec_triple_gen_var_9,
+    unsigned_vec_pair_gen_var_24, unsigned_vec_pair_gen_var_33, unsigned_vec_triple_gen_var_10,
+    unsigned_vec_triple_gen_var_11, unsigned_vec_triple_gen_var_12, unsigned_vec_triple_gen_var_13,
+    unsigned_vec_triple_gen_var_14, unsigned_vec_triple_gen_var_15, unsigned_vec_triple_gen_var_16,
+    unsigned_vec_triple_gen_var_4, unsigned_vec_triple_gen_var_5, unsigned_vec_triple_gen_var_6,
+    unsigned_vec_triple_gen_var_60, unsigned_vec_triple_gen_var_7, unsigned_vec_triple_gen_var_8,
+    unsigned_vec_triple_gen_var_9,
 };
 use malachite_nz::test_util::natural::arithmetic::mul::natural_product_naive;
 use malachite_nz::test_util::natural::arithmetic::mul::{
@@ -12079,6 +12083,56 @@ fn test_limbs_square_to_out_fft() {
     }
 }
 
+// These sizes are large enough that the FFT's work is spread across threads when the `parallel`
+// feature is enabled, so the results are checked against GMP.
+#[test]
+fn test_limbs_mul_greater_to_out_fft_large() {
+    let test = |xs_len: usize, ys_len: usize| {
+        let xs = series(Limb::MAX >> 1, xs_len);
+        let ys = series(Limb::MAX >> 2, ys_len);
+        let product = Natural::exact_from(
+            &(rug::Integer::from(&Natural::from_limbs_asc(&xs))
+                * rug::Integer::from(&Natural::from_limbs_asc(&ys))),
+        );
+        let mut expected = product.into_limbs_asc();
+        expected.resize(xs_len + ys_len, 0);
+        for cutoff in [1, 50] {
+            let mut out = vec![0; xs_len + ys_len];
+            let mut scratch =
+                vec![
+                    0;
+                    limbs_mul_greater_to_out_fft_with_cutoff_scratch_len(xs_len, ys_len, cutoff)
+                ];
+            limbs_mul_greater_to_out_fft_with_cutoff(&mut out, &xs, &ys, cutoff, &mut scratch);
+            assert_eq!(out, expected);
+        }
+    };
+    test(30000, 30000);
+    test(60000, 50000);
+    test(200000, 150000);
+    test(300000, 20000);
+}
+
+#[test]
+fn test_limbs_square_to_out_fft_large() {
+    let test = |xs_len: usize| {
+        let xs = series(Limb::MAX >> 1, xs_len);
+        let x = rug::Integer::from(&Natural::from_limbs_asc(&xs));
+        let mut expected = Natural::exact_from(&rug::Integer::from(&x * &x)).into_limbs_asc();
+        expected.resize(xs_len << 1, 0);
+        for cutoff in [1, 50] {
+            let mut out = vec![0; xs_len << 1];
+            let mut scratch =
+                vec![0; limbs_square_to_out_fft_with_cutoff_scratch_len(xs_len, cutoff)];
+            limbs_square_to_out_fft_with_cutoff(&mut out, &xs, cutoff, &mut scratch);
+            assert_eq!(out, expected);
+        }
+    };
+    test(30000);
+    test(60000);
+    test(200000);
+}
+
 #[test]
 fn limbs_mul_greater_to_out_fft_properties() {
     let mut config = GenConfig::new();
@@ -12122,6 +12176,88 @@ fn limbs_square_to_out_fft_properties() {
     });
 }
 
+#[test]
+fn test_limbs_mul_greater_to_out_fft_small() {
+    let test = |xs: &[Limb], ys: &[Limb]| {
+        let mut out = vec![10; xs.len() + ys.len() + 1];
+        limbs_mul_greater_to_out_fft_small(&mut out, xs, ys);
+        let expected = limbs_mul_basecase_helper(&vec![10; xs.len() + ys.len() + 1], xs, ys);
+        assert_eq!(out, expected);
+    };
+    test(&[1], &[1]);
+    test(&[2, 3], &[4]);
+    test(&[1, 2, 3], &[6, 7]);
+    test(&[Limb::MAX], &[Limb::MAX]);
+    test(&[Limb::MAX; 5], &[Limb::MAX; 3]);
+    test(&[Limb::MAX; 100], &[Limb::MAX; 100]);
+    test(&series(Limb::MAX >> 1, 1000), &series(Limb::MAX >> 2, 999));
+    // transform length equal to the number of coefficients
+    test(&[Limb::MAX; 513], &[Limb::MAX; 512]);
+
+    let test_large = |xs_len: usize, ys_len: usize| {
+        let xs = series(Limb::MAX >> 1, xs_len);
+        let ys = series(Limb::MAX >> 2, ys_len);
+        let product = Natural::exact_from(
+            &(rug::Integer::from(&Natural::from_limbs_asc(&xs))
+                * rug::Integer::from(&Natural::from_limbs_asc(&ys))),
+        );
+        let mut expected = product.into_limbs_asc();
+        expected.resize(xs_len + ys_len, 0);
+        let mut out = vec![0; xs_len + ys_len];
+        limbs_mul_greater_to_out_fft_small(&mut out, &xs, &ys);
+        assert_eq!(out, expected);
+    };
+    test_large(100000, 100000);
+    test_large(300000, 1000);
+}
+
+#[test]
+fn test_limbs_square_to_out_fft_small() {
+    let test = |xs: &[Limb]| {
+        let mut out = vec![10; (xs.len() << 1) + 1];
+        limbs_square_to_out_fft_small(&mut out, xs);
+        let expected = limbs_mul_basecase_helper(&vec![10; (xs.len() << 1) + 1], xs, xs);
+        assert_eq!(out, expected);
+    };
+    test(&[1]);
+    test(&[2, 3]);
+    test(&[Limb::MAX]);
+    test(&[Limb::MAX; 100]);
+    test(&series(Limb::MAX >> 1, 1000));
+
+    let xs = series(Limb::MAX >> 1, 200000);
+    let x = rug::Integer::from(&Natural::from_limbs_asc(&xs));
+    let mut expected = Natural::exact_from(&rug::Integer::from(&x * &x)).into_limbs_asc();
+    expected.resize(xs.len() << 1, 0);
+    let mut out = vec![0; xs.len() << 1];
+    limbs_square_to_out_fft_small(&mut out, &xs);
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn limbs_mul_greater_to_out_fft_small_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 1024);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_triple_gen_var_2().test_properties_with_config(&config, |(mut out, xs, ys)| {
+        let expected_out = limbs_mul_basecase_helper(&out, &xs, &ys);
+        limbs_mul_greater_to_out_fft_small(&mut out, &xs, &ys);
+        assert_eq!(out, expected_out);
+    });
+}
+
+#[test]
+fn limbs_square_to_out_fft_small_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 1024);
+    config.insert("mean_stripe_n", 512 << Limb::LOG_WIDTH);
+    unsigned_vec_pair_gen_var_24().test_properties_with_config(&config, |(mut out, xs)| {
+        let expected_out = limbs_mul_basecase_helper(&out, &xs, &xs);
+        limbs_square_to_out_fft_small(&mut out, &xs);
+        assert_eq!(out, expected_out);
+    });
+}
+
 #[test]
 fn limbs_mul_low_same_length_properties() {
     let mut config = GenConfig::new();
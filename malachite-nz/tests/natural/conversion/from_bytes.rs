@@ -0,0 +1,74 @@
+use itertools::Itertools;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_base::slices::slice_test_zero;
+use malachite_base::test_util::generators::unsigned_vec_gen;
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_from_bytes_le() {
+    let test = |xs: &[u8], out| {
+        let x = Natural::from_bytes_le(xs);
+        assert_eq!(x.to_string(), out);
+        assert!(x.is_valid());
+        let mut xs_be = xs.to_vec();
+        xs_be.reverse();
+        assert_eq!(Natural::from_bytes_be(&xs_be), x);
+    };
+    test(&[], "0");
+    test(&[0], "0");
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 0], "0");
+    test(&[123], "123");
+    test(&[123, 0], "123");
+    test(&[0, 1], "256");
+    test(&[0, 16, 165, 212, 232], "1000000000000");
+    test(&[255, 255, 255, 255], "4294967295");
+    test(&[0, 0, 0, 0, 1], "4294967296");
+    test(
+        &[255, 255, 255, 255, 255, 255, 255, 255, 0, 0],
+        "18446744073709551615",
+    );
+    test(&[0, 0, 0, 0, 0, 0, 0, 0, 1], "18446744073709551616");
+    test(
+        &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5],
+        "1701411834921604967429270619762735448065",
+    );
+}
+
+#[test]
+fn from_bytes_le_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_le(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            x,
+            Natural::from_power_of_2_digits_asc(8, xs.iter().cloned()).unwrap()
+        );
+        let trimmed = xs
+            .iter()
+            .cloned()
+            .rev()
+            .skip_while(|&x| x == 0)
+            .collect_vec();
+        assert_eq!(x.to_bytes_be(), trimmed);
+        assert_eq!(
+            Natural::from_bytes_be(&xs.iter().cloned().rev().collect_vec()),
+            x
+        );
+        assert_eq!(slice_test_zero(&xs), x == 0);
+    });
+}
+
+#[test]
+fn from_bytes_be_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|xs| {
+        let x = Natural::from_bytes_be(&xs);
+        assert!(x.is_valid());
+        assert_eq!(
+            x,
+            Natural::from_power_of_2_digits_desc(8, xs.iter().cloned()).unwrap()
+        );
+        if !xs.is_empty() && xs[0] != 0 {
+            assert_eq!(x.to_bytes_be(), xs);
+        }
+    });
+}
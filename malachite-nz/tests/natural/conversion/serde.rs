@@ -3,6 +3,7 @@ use malachite_base::test_util::generators::{string_gen, string_gen_var_8};
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
 use malachite_nz::test_util::generators::natural_gen;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 #[test]
@@ -32,6 +33,28 @@ fn test_serde() {
     );
 }
 
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct CompactNatural(#[serde(with = "malachite_nz::natural::conversion::serde::compact")] Natural);
+
+#[test]
+fn test_serde_compact() {
+    let test = |n, out| {
+        let x = CompactNatural(Natural::from_str(n).unwrap());
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        assert_eq!(serde_json::from_str::<CompactNatural>(out).unwrap(), x);
+    };
+    test("0", "[]");
+    test("100", "[100]");
+    test("256", "[0,1]");
+    test("1000000000000", "[0,16,165,212,232]");
+    test("4294967295", "[255,255,255,255]");
+    test("4294967296", "[0,0,0,0,1]");
+    test(
+        "340282366920938463463374607431768211455",
+        "[255,255,255,255,255,255,255,255,255,255,255,255,255,255,255,255]",
+    );
+}
+
 #[test]
 fn serde_properties() {
     natural_gen().test_properties(|x| {
@@ -51,3 +74,13 @@ fn serde_properties() {
         assert_eq!(n, i);
     });
 }
+
+#[test]
+fn serde_compact_properties() {
+    natural_gen().test_properties(|x| {
+        let x = CompactNatural(x);
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<CompactNatural>(&s).unwrap(), x);
+        assert!(string_is_subset(&s, ",0123456789[]"));
+    });
+}
@@ -0,0 +1,64 @@
+use itertools::Itertools;
+use malachite_base::num::conversion::traits::PowerOf2Digits;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use num::BigUint;
+use std::str::FromStr;
+
+#[test]
+fn test_to_bytes_le() {
+    let test = |n, out: &[u8]| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.to_bytes_le(), out);
+        let mut out_be = out.to_vec();
+        out_be.reverse();
+        assert_eq!(n.to_bytes_be(), out_be);
+    };
+    test("0", &[]);
+    test("123", &[123]);
+    test("255", &[255]);
+    test("256", &[0, 1]);
+    test("1000000000000", &[0, 16, 165, 212, 232]);
+    test("4294967295", &[255, 255, 255, 255]);
+    test("4294967296", &[0, 0, 0, 0, 1]);
+    test(
+        "18446744073709551615",
+        &[255, 255, 255, 255, 255, 255, 255, 255],
+    );
+    test("18446744073709551616", &[0, 0, 0, 0, 0, 0, 0, 0, 1]);
+    test(
+        "1701411834921604967429270619762735448065",
+        &[1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0, 5],
+    );
+}
+
+#[test]
+fn to_bytes_le_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_bytes_le();
+        assert_ne!(bytes.last(), Some(&0));
+        assert_eq!(bytes.is_empty(), x == 0);
+        assert_eq!(Natural::from_bytes_le(&bytes), x);
+        assert_eq!(bytes, PowerOf2Digits::<u8>::to_power_of_2_digits_asc(&x, 8));
+        if x != 0 {
+            assert_eq!(bytes, BigUint::from(&x).to_bytes_le());
+        }
+        assert_eq!(x.to_bytes_be(), bytes.iter().cloned().rev().collect_vec());
+    });
+}
+
+#[test]
+fn to_bytes_be_properties() {
+    natural_gen().test_properties(|x| {
+        let bytes = x.to_bytes_be();
+        assert_ne!(bytes.first(), Some(&0));
+        assert_eq!(Natural::from_bytes_be(&bytes), x);
+        assert_eq!(
+            bytes,
+            PowerOf2Digits::<u8>::to_power_of_2_digits_desc(&x, 8)
+        );
+        if x != 0 {
+            assert_eq!(bytes, BigUint::from(&x).to_bytes_be());
+        }
+    });
+}
@@ -0,0 +1,68 @@
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Lcm};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Totient};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_19};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_carmichael_lambda() {
+    let test = |n, out| {
+        assert_eq!(
+            Natural::from_str(n)
+                .unwrap()
+                .carmichael_lambda()
+                .to_string(),
+            out
+        );
+    };
+    test("1", "1");
+    test("2", "1");
+    test("8", "2");
+    test("561", "80");
+    test("720720", "60");
+    test("18446744073709551615", "17153064960");
+    // 2^64 + 1
+    test("18446744073709551617", "72057331223781120");
+    // 2^100 - 1
+    test("1267650600228229401496703205375", "14499000");
+    test(
+        "1000000000000000000000000000000",
+        "50000000000000000000000000000",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+    );
+}
+
+#[test]
+fn carmichael_lambda_fail() {
+    assert_panic!(Natural::ZERO.carmichael_lambda());
+}
+
+#[test]
+fn carmichael_lambda_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        let lambda = n_nat.carmichael_lambda();
+        assert_eq!(lambda, n.carmichael_lambda());
+        assert!(n_nat.totient().divisible_by(&lambda));
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        if x == 0 || y == 0 || z == 0 {
+            return;
+        }
+        let x = Natural::from(x);
+        let y = Natural::from(y) * Natural::from(z);
+        // λ(lcm(x, y)) = lcm(λ(x), λ(y))
+        assert_eq!(
+            (&x).lcm(&y).carmichael_lambda(),
+            x.carmichael_lambda().lcm(y.carmichael_lambda())
+        );
+    });
+}
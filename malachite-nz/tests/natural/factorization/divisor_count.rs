@@ -0,0 +1,55 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::{DivisorCount, Divisors, IsPrime};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_19};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_count() {
+    let test = |n, out| {
+        assert_eq!(Natural::from_str(n).unwrap().divisor_count(), out);
+    };
+    test("1", 1);
+    test("2", 2);
+    test("12", 6);
+    test("720720", 240);
+    test("18446744073709551615", 128);
+    // 2^64 + 1
+    test("18446744073709551617", 4);
+    // 2^100 - 1
+    test("1267650600228229401496703205375", 8192);
+    test("1000000000000000000000000000000", 961);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", 2);
+}
+
+#[test]
+fn divisor_count_fail() {
+    assert_panic!(Natural::ZERO.divisor_count());
+}
+
+#[test]
+fn divisor_count_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        let count = n_nat.divisor_count();
+        assert_eq!(count, n.divisor_count());
+        assert_eq!(count, u64::wrapping_from(n_nat.divisors().count()));
+        assert_eq!(count == 2, n.is_prime());
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        let x = Natural::from(x);
+        let y = Natural::from(y) * Natural::from(z);
+        if x != 0u32 && y != 0u32 && (&x).coprime_with(&y) {
+            assert_eq!(
+                (&x * &y).divisor_count(),
+                x.divisor_count() * y.divisor_count()
+            );
+        }
+    });
+}
@@ -0,0 +1,94 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{
+    CheckedDivisorSum, DivisorCount, DivisorSum, Divisors, IsPrime,
+};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19,
+};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_sum() {
+    let test = |n, k, out| {
+        assert_eq!(
+            Natural::from_str(n).unwrap().divisor_sum(k).to_string(),
+            out
+        );
+    };
+    test("1", 0, "1");
+    test("1", 100, "1");
+    test("12", 0, "6");
+    test("12", 1, "28");
+    test("12", 2, "210");
+    test("720720", 2, "836657822000");
+    test("18446744073709551615", 1, "31421980989189888768");
+    // 2^64 + 1
+    test("18446744073709551617", 1, "18446811354131136516");
+    test(
+        "18446744073709551617",
+        2,
+        "340282366925465118592016216137989881860",
+    );
+    // 2^100 - 1
+    test(
+        "1267650600228229401496703205375",
+        1,
+        "2473581905942106707273308962816",
+    );
+    test(
+        "1000000000000000000000000000000",
+        1,
+        "2499999998835846781730114984557",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        1,
+        "170141183460469231731687303715884105728",
+    );
+}
+
+#[test]
+fn divisor_sum_fail() {
+    assert_panic!(Natural::ZERO.divisor_sum(1));
+}
+
+#[test]
+fn divisor_sum_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        assert_eq!(n_nat.divisor_sum(0), n.divisor_count());
+        let sum = n_nat.divisor_sum(1);
+        if let Some(x) = n.checked_divisor_sum(1) {
+            assert_eq!(sum, x);
+        }
+        assert!(sum > n_nat || n == 1);
+        assert_eq!(sum == &n_nat + Natural::ONE, n.is_prime());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y)| {
+        if x == 0 || y == 0 {
+            return;
+        }
+        let n = Natural::from(x) * Natural::from(y);
+        for k in 0..3 {
+            let sum: Natural = n.divisors().map(|d| d.pow(k)).sum();
+            assert_eq!(n.divisor_sum(k), sum);
+        }
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        let x = Natural::from(x);
+        let y = Natural::from(y) * Natural::from(z);
+        if x != 0u32 && y != 0u32 && (&x).coprime_with(&y) {
+            assert_eq!(
+                (&x * &y).divisor_sum(2),
+                x.divisor_sum(2) * y.divisor_sum(2)
+            );
+        }
+    });
+}
@@ -0,0 +1,76 @@
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::{DivisorCount, Divisors};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_19};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisors() {
+    let test = |n, out| {
+        assert_eq!(
+            Natural::from_str(n)
+                .unwrap()
+                .divisors()
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test("12", "[1, 2, 4, 3, 6, 12]");
+    test("97", "[1, 97]");
+    // 2^64 + 1
+    test(
+        "18446744073709551617",
+        "[1, 274177, 67280421310721, 18446744073709551617]",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "[1, 170141183460469231731687303715884105727]",
+    );
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(Natural::ZERO.divisors());
+}
+
+fn divisors_properties_helper(n: &Natural) {
+    let divisors = n.divisors().sorted().collect_vec();
+    assert!(is_strictly_ascending(divisors.iter()));
+    assert_eq!(divisors[0], Natural::ONE);
+    assert_eq!(divisors.last().unwrap(), n);
+    assert_eq!(u64::wrapping_from(divisors.len()), n.divisor_count());
+    for d in &divisors {
+        assert!(n.divisible_by(d));
+        assert!(divisors.binary_search(&(n / d)).is_ok());
+    }
+}
+
+#[test]
+fn divisors_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        divisors_properties_helper(&n_nat);
+        assert_eq!(
+            n_nat.divisors().collect_vec(),
+            n.divisors().map(Natural::from).collect_vec()
+        );
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        if x != 0 && y != 0 && z != 0 {
+            let n = Natural::from(x) * Natural::from(y) * Natural::from(z);
+            divisors_properties_helper(&n);
+        }
+    });
+}
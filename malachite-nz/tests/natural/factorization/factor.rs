@@ -0,0 +1,106 @@
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_triple_gen_var_19};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn verify_factorization(n: &Natural, factors: &[(Natural, u64)]) {
+    assert!(is_strictly_ascending(factors.iter().map(|(p, _)| p)));
+    let mut product = Natural::ONE;
+    for (p, e) in factors {
+        assert!(p.is_prime());
+        assert_ne!(*e, 0);
+        product *= p.pow(*e);
+    }
+    assert_eq!(product, *n);
+}
+
+#[test]
+fn test_factor() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let factors = n.factor();
+        assert_eq!(factors.to_debug_string(), out);
+        verify_factorization(&n, &factors);
+    };
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    // 2^64 + 1
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    // 2^100 - 1
+    test(
+        "1267650600228229401496703205375",
+        "[(3, 1), (5, 3), (11, 1), (31, 1), (41, 1), (101, 1), (251, 1), (601, 1), (1801, 1), \
+        (4051, 1), (8101, 1), (268501, 1)]",
+    );
+    // 10^30
+    test("1000000000000000000000000000000", "[(2, 30), (5, 30)]");
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "[(170141183460469231731687303715884105727, 1)]",
+    );
+    // 1000003^5 * (2^64 - 59)
+    test(
+        "18447020776530869147535585477541331568292006028351",
+        "[(1000003, 5), (18446744073709551557, 1)]",
+    );
+    // (2^64 - 59)^2
+    test(
+        "340282366920938461286658806734041124249",
+        "[(18446744073709551557, 2)]",
+    );
+    // (2^40 + 15)(2^80 + 13)
+    test(
+        "1329227995803049760198040791552098499",
+        "[(1099511627791, 1), (1208925819614629174706189, 1)]",
+    );
+    // 2^128 + 1
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+}
+
+#[test]
+fn factor_fail() {
+    assert_panic!(Natural::ZERO.factor());
+}
+
+#[test]
+fn factor_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let factors = Natural::from(n).factor();
+        assert_eq!(
+            factors,
+            n.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), e))
+                .collect::<Vec<_>>()
+        );
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        if x == 0 || y == 0 || z == 0 {
+            return;
+        }
+        let n = Natural::from(x) * Natural::from(y) * Natural::from(z);
+        let factors = n.factor();
+        verify_factorization(&n, &factors);
+        for x in [x, y, z] {
+            for (p, _) in x.factor() {
+                assert!(factors.iter().any(|(q, _)| *q == p));
+            }
+        }
+    });
+}
@@ -0,0 +1,88 @@
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::{natural_gen, natural_pair_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |n: &str, out: bool| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.is_prime(), out);
+        assert_eq!(n.is_prime_with_extra_rounds(10), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("5", true);
+    test("561", false);
+    test("1000000007", true);
+    test("3215031751", false);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    test("18446744073709551629", true);
+    test("18446744073709551631", false);
+    // smallest strong pseudoprime to the first 12 prime bases
+    test("318665857834031151167461", false);
+    // smallest strong pseudoprime to the first 13 prime bases
+    test("3317044064679887385961981", false);
+    // 2^89 - 1
+    test("618970019642690137449562111", true);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", true);
+    // 2^128 - 159
+    test("340282366920938463463374607431768211297", true);
+    // (2^64 - 59)^2
+    test("340282366920938461286658806734041124249", false);
+    // (2^64 - 59)(2^64 - 83)
+    test("340282366920938460843936948965011886881", false);
+    // 2^521 - 1
+    test(
+        "686479766013060971498190079908139321726943530014330540939446345918554318339765605212255964\
+        0661454554977296311391480858037121987999716643812574028291115057151",
+        true,
+    );
+    // 2^523 - 1
+    test(
+        "274591906405224388599276031963255728690777412005732216375778538367421727335906242084902385\
+        62645818219909185245565923432148487951998866575250296113164460228607",
+        false,
+    );
+    // 2^607 - 1
+    test(
+        "5311379928167670986895882065524686273295931177270319231994441382004035598608522427391625\
+        0226522928566888932948624650101534657933765270723940951997876658735194383127083539321903\
+        1728127",
+        true,
+    );
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen().test_properties(|n| {
+        let is_prime = n.is_prime();
+        assert_eq!(n.is_prime_with_extra_rounds(2), is_prime);
+        if let Ok(x) = u64::try_from(&n) {
+            assert_eq!(x.is_prime(), is_prime);
+        }
+        if let Ok(x) = u128::try_from(&n) {
+            assert_eq!(x.is_prime(), is_prime);
+        }
+    });
+
+    natural_pair_gen().test_properties(|(x, y)| {
+        if x > 1u32 && y > 1u32 {
+            assert!(!(x * y).is_prime());
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|x| {
+        assert_eq!(Natural::from(x).is_prime(), x.is_prime());
+    });
+
+    assert!(!Natural::ONE.is_prime());
+}
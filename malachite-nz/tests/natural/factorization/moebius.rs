@@ -0,0 +1,69 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{Divisors, Factor, IsPrime, Moebius};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19,
+};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_moebius() {
+    let test = |n, out| {
+        assert_eq!(Natural::from_str(n).unwrap().moebius(), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("12", 0);
+    test("30", -1);
+    test("18446744073709551615", -1);
+    // 2^64 + 1
+    test("18446744073709551617", 1);
+    // 2^100 - 1
+    test("1267650600228229401496703205375", 0);
+    // 2^127 - 1
+    test("170141183460469231731687303715884105727", -1);
+    // (2^127 - 1)^2
+    test(
+        "28948022309329048855892746252171976962977213799489202546401021394546514198529",
+        0,
+    );
+}
+
+#[test]
+fn moebius_fail() {
+    assert_panic!(Natural::ZERO.moebius());
+}
+
+#[test]
+fn moebius_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        let mu = n_nat.moebius();
+        assert_eq!(mu, n.moebius());
+        if n.is_prime() {
+            assert_eq!(mu, -1);
+        }
+        assert_eq!(mu != 0, n_nat.factor().iter().all(|&(_, k)| k == 1));
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        let x = Natural::from(x);
+        let y = Natural::from(y) * Natural::from(z);
+        if x != 0u32 && y != 0u32 && (&x).coprime_with(&y) {
+            assert_eq!((&x * &y).moebius(), x.moebius() * y.moebius());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y)| {
+        if x == 0 || y == 0 {
+            return;
+        }
+        let n = Natural::from(x) * Natural::from(y);
+        // The sum of μ(d) over the divisors d of n is 1 if n is 1 and 0 otherwise.
+        let sum: i64 = n.divisors().map(|d| i64::from(d.moebius())).sum();
+        assert_eq!(sum, i64::from(n == 1u32));
+    });
+}
@@ -0,0 +1,57 @@
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrecedingPrime};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("0", "2");
+    test("1", "2");
+    test("2", "3");
+    test("100", "101");
+    test("1000000000", "1000000007");
+    test("18446744073709551556", "18446744073709551557");
+    test("18446744073709551557", "18446744073709551629");
+    test("18446744073709551615", "18446744073709551629");
+    test("18446744073709551629", "18446744073709551653");
+    // 2^100
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205653",
+    );
+    // 2^127 - 2
+    test(
+        "170141183460469231731687303715884105726",
+        "170141183460469231731687303715884105727",
+    );
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen().test_properties_with_limit(SMALL_LIMIT, |n| {
+        let p = n.next_prime();
+        assert!(p.is_valid());
+        assert!(p > n);
+        assert!(p.is_prime());
+        if p > 2u32 {
+            assert!(p.preceding_prime() <= n);
+        }
+        if let Ok(x) = u64::try_from(&n) {
+            if let Ok(q) = u64::try_from(&p) {
+                assert_eq!(x.next_prime(), q);
+            }
+        }
+    });
+
+    unsigned_gen_var_5::<u64>().test_properties(|n| {
+        assert_eq!(Natural::from(n).next_prime(), n.next_prime());
+    });
+}
@@ -0,0 +1,67 @@
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PrecedingPrime};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_preceding_prime() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let p = n.preceding_prime();
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test("3", "2");
+    test("4", "3");
+    test("100", "97");
+    test("1000000007", "999999937");
+    test("18446744073709551615", "18446744073709551557");
+    test("18446744073709551616", "18446744073709551557");
+    test("18446744073709551617", "18446744073709551557");
+    test("18446744073709551629", "18446744073709551557");
+    test("18446744073709551630", "18446744073709551629");
+    // 2^100
+    test(
+        "1267650600228229401496703205376",
+        "1267650600228229401496703205361",
+    );
+    // 2^128
+    test(
+        "340282366920938463463374607431768211456",
+        "340282366920938463463374607431768211297",
+    );
+}
+
+#[test]
+fn preceding_prime_fail() {
+    assert_panic!(Natural::ZERO.preceding_prime());
+    assert_panic!(Natural::ONE.preceding_prime());
+    assert_panic!(Natural::TWO.preceding_prime());
+}
+
+#[test]
+fn preceding_prime_properties() {
+    natural_gen().test_properties_with_limit(SMALL_LIMIT, |n| {
+        if n <= 2u32 {
+            return;
+        }
+        let p = n.preceding_prime();
+        assert!(p.is_valid());
+        assert!(p < n);
+        assert!(p.is_prime());
+        assert!(p.next_prime() >= n);
+        if let Ok(x) = u64::try_from(&n) {
+            assert_eq!(x.preceding_prime(), p);
+        }
+    });
+
+    unsigned_gen_var_5::<u64>().test_properties(|n| {
+        if n > 2 {
+            assert_eq!(Natural::from(n).preceding_prime(), n.preceding_prime());
+        }
+    });
+}
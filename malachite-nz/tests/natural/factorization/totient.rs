@@ -0,0 +1,73 @@
+use malachite_base::num::arithmetic::traits::CoprimeWith;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::{Divisors, IsPrime, Totient};
+use malachite_base::test_util::generators::common::SMALL_LIMIT;
+use malachite_base::test_util::generators::{
+    unsigned_gen_var_1, unsigned_pair_gen_var_27, unsigned_triple_gen_var_19,
+};
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_totient() {
+    let test = |n, out| {
+        assert_eq!(Natural::from_str(n).unwrap().totient().to_string(), out);
+    };
+    test("1", "1");
+    test("2", "1");
+    test("12", "4");
+    test("561", "320");
+    test("18446744073709551615", "9208981628670443520");
+    // 2^64 + 1
+    test("18446744073709551617", "18446676793287966720");
+    // 2^100 - 1
+    test(
+        "1267650600228229401496703205375",
+        "570767634000000000000000000000",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+    );
+    // 2^127 - 1
+    test(
+        "170141183460469231731687303715884105727",
+        "170141183460469231731687303715884105726",
+    );
+}
+
+#[test]
+fn totient_fail() {
+    assert_panic!(Natural::ZERO.totient());
+}
+
+#[test]
+fn totient_properties() {
+    unsigned_gen_var_1::<u64>().test_properties(|n| {
+        let n_nat = Natural::from(n);
+        let totient = n_nat.totient();
+        assert_eq!(totient, n.totient());
+        assert!(totient <= n_nat);
+        if n > 1 {
+            assert_eq!(totient == n - 1, n.is_prime());
+        }
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y, z)| {
+        let x = Natural::from(x);
+        let y = Natural::from(y) * Natural::from(z);
+        if x != 0u32 && y != 0u32 && (&x).coprime_with(&y) {
+            assert_eq!((&x * &y).totient(), x.totient() * y.totient());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties_with_limit(SMALL_LIMIT, |(x, y)| {
+        if x == 0 || y == 0 {
+            return;
+        }
+        let n = Natural::from(x) * Natural::from(y);
+        let sum: Natural = n.divisors().map(|d| d.totient()).sum();
+        assert_eq!(sum, n);
+    });
+}
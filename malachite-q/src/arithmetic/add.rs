@@ -1,11 +1,12 @@
 use crate::Rational;
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign};
 use malachite_base::num::arithmetic::traits::{
     DivExact, DivExactAssign, Gcd, GcdAssign, UnsignedAbs,
 };
 use malachite_base::num::basic::traits::Zero;
 use malachite_nz::integer::Integer;
-use std::iter::Sum;
-use std::ops::{Add, AddAssign};
 
 impl Add<Rational> for Rational {
     type Output = Rational;
@@ -4,12 +4,12 @@ use crate::exhaustive::{
     exhaustive_rationals_with_denominator_range,
 };
 use crate::Rational;
+use alloc::collections::BTreeSet;
 use malachite_base::num::arithmetic::traits::{Ceiling, Reciprocal, UnsignedAbs};
 use malachite_base::num::basic::traits::{One, Two, Zero};
 use malachite_base::num::factorization::traits::Primes;
 use malachite_nz::natural::Natural;
 use malachite_nz::platform::Limb;
-use std::collections::BTreeSet;
 
 // Returns a k such that for all n >= k, any closed interval with the given diameter is guaranteed
 // to contain rationals with (reduced) denominator n.
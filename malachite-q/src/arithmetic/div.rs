@@ -1,9 +1,9 @@
 use crate::Rational;
+use core::ops::{Div, DivAssign};
 use malachite_base::num::arithmetic::traits::{
     CheckedDiv, DivExact, DivExactAssign, Gcd, Reciprocal,
 };
 use malachite_base::num::basic::traits::Zero;
-use std::ops::{Div, DivAssign};
 
 impl Div<Rational> for Rational {
     type Output = Rational;
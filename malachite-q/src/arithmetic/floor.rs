@@ -1,10 +1,10 @@
 use crate::Rational;
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{DivRound, DivRoundAssign, Floor, FloorAssign};
 use malachite_base::num::basic::traits::One;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use std::mem::swap;
 
 impl Floor for Rational {
     type Output = Integer;
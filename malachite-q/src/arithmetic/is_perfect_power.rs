@@ -0,0 +1,125 @@
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{
+    ExpressAsPower, Gcd, IsPerfectPower, Parity, Pow, SquareAssign,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+impl ExpressAsPower for Rational {
+    /// Expresses a [`Rational`] as $x^k$, where $k > 1$ is as large as possible, or returns `None`
+    /// if the [`Rational`] is not a perfect power.
+    ///
+    /// 0 and 1 can be raised to any power, so they are returned as $0^2$ and $1^2$. $-1$ can be
+    /// raised to any odd power, so it is returned as $(-1)^3$. Other negative [`Rational`]s can
+    /// only be odd powers, so the largest odd exponent is returned.
+    ///
+    /// Since the numerator and denominator are coprime, a [`Rational`] is a $k$th power if and only
+    /// if they both are. So the largest exponent is the GCD of the largest exponents of the
+    /// numerator and denominator.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ExpressAsPower;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(64).express_as_power().to_debug_string(), "Some((2, 6))");
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 8).express_as_power().to_debug_string(),
+    ///     "Some((1/2, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-27, 8).express_as_power().to_debug_string(),
+    ///     "Some((-3/2, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(16, 81).express_as_power().to_debug_string(),
+    ///     "Some((2/3, 4))"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(4, 27).express_as_power().to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 4).express_as_power().to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    fn express_as_power(&self) -> Option<(Rational, u64)> {
+        if self.denominator == 1u32 {
+            return Integer::from_sign_and_abs_ref(self.sign, &self.numerator)
+                .express_as_power()
+                .map(|(x, e)| (Rational::from(x), e));
+        }
+        let (mut d, mut e) = self.denominator.express_as_power()?;
+        let mut n = if self.numerator == 1u32 {
+            Natural::ONE
+        } else {
+            let (n, f) = self.numerator.express_as_power()?;
+            let g = e.gcd(f);
+            if g == 1 {
+                return None;
+            }
+            d = d.pow(e / g);
+            e = g;
+            n.pow(f / g)
+        };
+        if !self.sign {
+            while e.even() {
+                n.square_assign();
+                d.square_assign();
+                e >>= 1;
+            }
+            if e == 1 {
+                return None;
+            }
+        }
+        Some((
+            Rational {
+                sign: self.sign,
+                numerator: n,
+                denominator: d,
+            },
+            e,
+        ))
+    }
+}
+
+impl IsPerfectPower for Rational {
+    /// Determines whether a [`Rational`] is a perfect power; that is, whether it can be written as
+    /// $x^k$ for some [`Rational`] $x$ and some $k > 1$.
+    ///
+    /// 0, 1, and $-1$ are perfect powers. Other negative [`Rational`]s are perfect powers if they
+    /// are odd powers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(64).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(1, 8).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(-27, 8).is_perfect_power(), true);
+    /// assert_eq!(Rational::from_signeds(4, 27).is_perfect_power(), false);
+    /// assert_eq!(Rational::from_signeds(-1, 4).is_perfect_power(), false);
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        self.express_as_power().is_some()
+    }
+}
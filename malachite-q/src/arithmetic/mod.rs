@@ -19,6 +19,10 @@ pub mod div;
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// Implementations of [`ExpressAsPower`](malachite_base::num::arithmetic::traits::ExpressAsPower)
+/// and [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower), traits for
+/// determining whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
 /// An implementation of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -57,6 +61,9 @@ pub mod pow;
 /// Implementations of [`PowerOf2`](malachite_base::num::arithmetic::traits::PowerOf2), a trait for
 /// computing a power of 2.
 pub mod power_of_2;
+/// Functions for recovering a [`Rational`](super::Rational) from its residue modulo a
+/// [`Natural`](malachite_nz::natural::Natural).
+pub mod rational_reconstruction;
 /// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
 /// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
 /// computing the reciprocal of a number.
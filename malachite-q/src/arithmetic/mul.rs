@@ -1,8 +1,9 @@
 use crate::Rational;
+use alloc::vec::Vec;
+use core::iter::Product;
+use core::ops::{Mul, MulAssign};
 use malachite_base::num::arithmetic::traits::{DivExact, DivExactAssign, Gcd};
 use malachite_base::num::basic::traits::{One, Zero};
-use std::iter::Product;
-use std::ops::{Mul, MulAssign};
 
 impl Mul<Rational> for Rational {
     type Output = Rational;
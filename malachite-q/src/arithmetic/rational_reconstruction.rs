@@ -0,0 +1,138 @@
+use crate::Rational;
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{DivMod, FloorSqrt, Gcd, UnsignedAbs};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+impl Rational {
+    /// Finds the [`Rational`] $n/d$ with $|n| \leq N$ and $0 < d \leq D$ that is congruent to a
+    /// residue modulo a modulus, where $N = D = \lfloor \sqrt{(m-1)/2} \rfloor$. Returns `None` if
+    /// there is no such [`Rational`].
+    ///
+    /// A [`Rational`] $n/d$ is congruent to $r$ modulo $m$ if $d$ is coprime to $m$ and $n \equiv
+    /// rd \mod m$. With these bounds, there is at most one such [`Rational`]. This is the last step
+    /// of multi-modular algorithms that compute a rational result: the result is computed modulo
+    /// many primes, the residues are combined with
+    /// [`Natural::crt`](malachite_nz::natural::Natural::crt), and the [`Rational`] is then
+    /// recovered using this function, provided that the modulus is large enough.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `modulus.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero or if `residue` is greater than or equal to `modulus`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// // 2/3 ≡ 68 (mod 101), since 3 * 68 = 204 ≡ 2 (mod 101).
+    /// assert_eq!(
+    ///     Rational::rational_reconstruction(&Natural::from(68u32), &Natural::from(101u32))
+    ///         .to_debug_string(),
+    ///     "Some(2/3)"
+    /// );
+    /// // -2/3 ≡ 33 (mod 101)
+    /// assert_eq!(
+    ///     Rational::rational_reconstruction(&Natural::from(33u32), &Natural::from(101u32))
+    ///         .to_debug_string(),
+    ///     "Some(-2/3)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::rational_reconstruction(&Natural::from(8u32), &Natural::from(101u32))
+    ///         .to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    pub fn rational_reconstruction(residue: &Natural, modulus: &Natural) -> Option<Rational> {
+        assert_ne!(*modulus, 0u32);
+        let bound = ((modulus - Natural::ONE) >> 1u32).floor_sqrt();
+        Rational::rational_reconstruction_with_bounds(residue, modulus, &bound, &bound)
+    }
+
+    /// Finds the [`Rational`] $n/d$ with $|n| \leq N$ and $0 < d \leq D$ that is congruent to a
+    /// residue modulo a modulus $m$, where $N$ and $D$ are given and $2ND < m$. Returns `None` if
+    /// there is no such [`Rational`].
+    ///
+    /// A [`Rational`] $n/d$ is congruent to $r$ modulo $m$ if $d$ is coprime to $m$ and $n \equiv
+    /// rd \mod m$. Since $2ND < m$, there is at most one such [`Rational`]. Unbalanced bounds are
+    /// useful when the numerator is known to be much larger than the denominator, or vice versa.
+    ///
+    /// The [`Rational`] is found by running the extended Euclidean algorithm on $m$ and $r$ until
+    /// the remainder is at most $N$, and then checking the corresponding cofactor against $D$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `modulus.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero, if `residue` is greater than or equal to `modulus`, or if
+    /// $2ND \geq m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// // 1000/3 ≡ 3669 (mod 10007)
+    /// assert_eq!(
+    ///     Rational::rational_reconstruction_with_bounds(
+    ///         &Natural::from(3669u32),
+    ///         &Natural::from(10007u32),
+    ///         &Natural::from(1000u32),
+    ///         &Natural::from(5u32)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "Some(1000/3)"
+    /// );
+    /// // With balanced bounds, 1000/3 is out of range and a different `Rational` is found.
+    /// assert_eq!(
+    ///     Rational::rational_reconstruction(&Natural::from(3669u32), &Natural::from(10007u32))
+    ///         .to_debug_string(),
+    ///     "Some(-7/30)"
+    /// );
+    /// ```
+    pub fn rational_reconstruction_with_bounds(
+        residue: &Natural,
+        modulus: &Natural,
+        numerator_bound: &Natural,
+        denominator_bound: &Natural,
+    ) -> Option<Rational> {
+        assert_ne!(*modulus, 0u32);
+        assert!(residue < modulus, "residue must be less than modulus");
+        assert!(
+            (numerator_bound * denominator_bound) << 1u32 < *modulus,
+            "2 * numerator_bound * denominator_bound must be less than modulus"
+        );
+        // Invariant: r_i ≡ residue * t_i (mod modulus), for i = 0, 1.
+        let mut r_0 = modulus.clone();
+        let mut r_1 = residue.clone();
+        let mut t_0 = Integer::ZERO;
+        let mut t_1 = Integer::ONE;
+        while r_1 > *numerator_bound {
+            let (q, r_2) = (&r_0).div_mod(&r_1);
+            r_0 = r_2;
+            swap(&mut r_0, &mut r_1);
+            t_0 -= Integer::from(q) * &t_1;
+            swap(&mut t_0, &mut t_1);
+        }
+        let sign = t_1 > 0u32;
+        let t_1 = t_1.unsigned_abs();
+        if t_1 > *denominator_bound || (&r_1).gcd(&t_1) != 1u32 {
+            None
+        } else {
+            Some(Rational::from_sign_and_naturals(sign, r_1, t_1))
+        }
+    }
+}
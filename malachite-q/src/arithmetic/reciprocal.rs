@@ -1,6 +1,6 @@
 use crate::Rational;
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{Reciprocal, ReciprocalAssign};
-use std::mem::swap;
 
 impl Reciprocal for Rational {
     type Output = Rational;
@@ -1,6 +1,6 @@
 use crate::Rational;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::Sign;
-use std::cmp::Ordering;
 
 impl Sign for Rational {
     /// Compares a [`Rational`] to zero.
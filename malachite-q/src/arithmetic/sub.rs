@@ -1,9 +1,9 @@
 use crate::Rational;
+use core::ops::{Sub, SubAssign};
 use malachite_base::num::arithmetic::traits::{
     DivExact, DivExactAssign, Gcd, GcdAssign, NegAssign, UnsignedAbs,
 };
 use malachite_nz::integer::Integer;
-use std::ops::{Sub, SubAssign};
 
 impl Sub<Rational> for Rational {
     type Output = Rational;
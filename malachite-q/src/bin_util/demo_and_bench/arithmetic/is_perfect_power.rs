@@ -0,0 +1,66 @@
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_q::test_util::bench::bucketers::rational_bit_bucketer;
+use malachite_q::test_util::generators::rational_gen;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_rational_express_as_power);
+    register_demo!(runner, demo_rational_is_perfect_power);
+
+    register_bench!(runner, benchmark_rational_express_as_power);
+    register_bench!(runner, benchmark_rational_is_perfect_power);
+}
+
+fn demo_rational_express_as_power(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in rational_gen().get(gm, config).take(limit) {
+        println!("{}.express_as_power() = {:?}", x, x.express_as_power());
+    }
+}
+
+fn demo_rational_is_perfect_power(gm: GenMode, config: &GenConfig, limit: usize) {
+    for x in rational_gen().get(gm, config).take(limit) {
+        if x.is_perfect_power() {
+            println!("{x} is a perfect power");
+        } else {
+            println!("{x} is not a perfect power");
+        }
+    }
+}
+
+fn benchmark_rational_express_as_power(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.express_as_power()",
+        BenchmarkType::Single,
+        rational_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |x| no_out!(x.express_as_power()))],
+    );
+}
+
+fn benchmark_rational_is_perfect_power(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational.is_perfect_power()",
+        BenchmarkType::Single,
+        rational_gen().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &rational_bit_bucketer("x"),
+        &mut [("Malachite", &mut |x| no_out!(x.is_perfect_power()))],
+    );
+}
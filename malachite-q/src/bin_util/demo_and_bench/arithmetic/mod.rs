@@ -8,6 +8,7 @@ pub(crate) fn register(runner: &mut Runner) {
     denominators_in_closed_interval::register(runner);
     div::register(runner);
     floor::register(runner);
+    is_perfect_power::register(runner);
     is_power_of_2::register(runner);
     log_base::register(runner);
     log_base_2::register(runner);
@@ -17,6 +18,7 @@ pub(crate) fn register(runner: &mut Runner) {
     next_power_of_2::register(runner);
     pow::register(runner);
     power_of_2::register(runner);
+    rational_reconstruction::register(runner);
     reciprocal::register(runner);
     root::register(runner);
     round_to_multiple::register(runner);
@@ -37,6 +39,7 @@ mod ceiling;
 mod denominators_in_closed_interval;
 mod div;
 mod floor;
+mod is_perfect_power;
 mod is_power_of_2;
 mod log_base;
 mod log_base_2;
@@ -46,6 +49,7 @@ mod neg;
 mod next_power_of_2;
 mod pow;
 mod power_of_2;
+mod rational_reconstruction;
 mod reciprocal;
 mod root;
 mod round_to_multiple;
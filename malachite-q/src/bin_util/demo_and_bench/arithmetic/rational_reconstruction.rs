@@ -0,0 +1,43 @@
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::test_util::bench::bucketers::pair_2_natural_bit_bucketer;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use malachite_q::Rational;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_rational_rational_reconstruction);
+
+    register_bench!(runner, benchmark_rational_rational_reconstruction);
+}
+
+fn demo_rational_rational_reconstruction(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (r, m) in natural_pair_gen_var_8().get(gm, config).take(limit) {
+        println!(
+            "Rational::rational_reconstruction({}, {}) = {:?}",
+            r,
+            m,
+            Rational::rational_reconstruction(&r, &m)
+        );
+    }
+}
+
+fn benchmark_rational_rational_reconstruction(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Rational::rational_reconstruction(&Natural, &Natural)",
+        BenchmarkType::Single,
+        natural_pair_gen_var_8().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &pair_2_natural_bit_bucketer("m"),
+        &mut [("Malachite", &mut |(r, m)| {
+            no_out!(Rational::rational_reconstruction(&r, &m))
+        })],
+    );
+}
@@ -1,8 +1,8 @@
 use crate::Rational;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::FloorLogBase2;
 use malachite_base::num::comparison::traits::{OrdAbs, PartialOrdAbs};
 use malachite_base::num::conversion::traits::ExactFrom;
-use std::cmp::Ordering;
 
 macro_rules! impl_float {
     ($t: ident) => {
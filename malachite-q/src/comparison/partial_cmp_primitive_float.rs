@@ -1,6 +1,6 @@
 use crate::Rational;
+use core::cmp::Ordering;
 use malachite_base::num::arithmetic::traits::FloorLogBase2;
-use std::cmp::Ordering;
 
 macro_rules! impl_float {
     ($t: ident) => {
@@ -2,11 +2,11 @@ use crate::conversion::continued_fraction::to_continued_fraction::RationalContin
 use crate::conversion::traits::ContinuedFraction;
 use crate::conversion::traits::Convergents;
 use crate::Rational;
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{AddMulAssign, UnsignedAbs};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use std::mem::swap;
 
 /// An iterator that produces the convergents of a [`Rational`].
 ///
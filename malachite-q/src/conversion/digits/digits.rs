@@ -1,5 +1,6 @@
 use crate::conversion::digits::power_of_2_digits::RationalPowerOf2Digits;
 use crate::Rational;
+use alloc::vec::Vec;
 use malachite_base::num::arithmetic::traits::{Abs, CheckedLogBase2, Floor, UnsignedAbs};
 use malachite_base::num::conversion::traits::Digits;
 use malachite_nz::natural::Natural;
@@ -1,4 +1,5 @@
 use crate::Rational;
+use alloc::vec::Vec;
 use malachite_base::num::arithmetic::traits::{CheckedLogBase2, Pow};
 use malachite_base::num::basic::traits::One;
 use malachite_base::num::conversion::traits::{Digits, ExactFrom};
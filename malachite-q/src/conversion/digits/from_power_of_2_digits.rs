@@ -1,4 +1,5 @@
 use crate::Rational;
+use alloc::vec::Vec;
 use malachite_base::num::conversion::traits::{ExactFrom, PowerOf2Digits};
 use malachite_base::num::logic::traits::LowMask;
 use malachite_base::rational_sequences::RationalSequence;
@@ -1,12 +1,13 @@
 use crate::conversion::digits::to_power_of_2_digits::to_power_of_2_digits_helper;
 use crate::Rational;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 use malachite_base::num::arithmetic::traits::{
     Abs, AbsAssign, CheckedLogBase2, Floor, UnsignedAbs,
 };
 use malachite_base::num::conversion::traits::Digits;
 use malachite_base::rational_sequences::RationalSequence;
 use malachite_nz::natural::Natural;
-use std::collections::HashMap;
 
 fn to_digits_helper(x: Rational, base: &Natural) -> (Vec<Natural>, RationalSequence<Natural>) {
     if let Some(log_base) = base.checked_log_base_2() {
@@ -15,7 +16,7 @@ fn to_digits_helper(x: Rational, base: &Natural) -> (Vec<Natural>, RationalSeque
     let floor = (&x).floor();
     let mut remainder = x - Rational::from(&floor);
     let before_point = floor.unsigned_abs().to_digits_asc(base);
-    let mut state_map = HashMap::new();
+    let mut state_map = BTreeMap::new();
     let mut digits = Vec::new();
     let base = Rational::from(base);
     for i in 0.. {
@@ -1,9 +1,10 @@
 use crate::Rational;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
 use malachite_base::num::arithmetic::traits::{Abs, AbsAssign, Floor, UnsignedAbs};
 use malachite_base::num::conversion::traits::PowerOf2Digits;
 use malachite_base::rational_sequences::RationalSequence;
 use malachite_nz::natural::Natural;
-use std::collections::HashMap;
 
 pub(crate) fn to_power_of_2_digits_helper(
     x: Rational,
@@ -12,7 +13,7 @@ pub(crate) fn to_power_of_2_digits_helper(
     let floor = (&x).floor();
     let mut remainder = x - Rational::from(&floor);
     let before_point = floor.unsigned_abs().to_power_of_2_digits_asc(log_base);
-    let mut state_map = HashMap::new();
+    let mut state_map = BTreeMap::new();
     let mut digits = Vec::new();
     for i in 0.. {
         if remainder == 0u32 {
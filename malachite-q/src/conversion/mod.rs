@@ -462,6 +462,9 @@ pub mod primitive_float_from_rational;
 /// );
 /// ```
 pub mod primitive_int_from_rational;
+/// A compact binary representation of [`Rational`](crate::Rational)s for serialization and
+/// deserialization using [serde](https://serde.rs/).
+pub mod serde;
 /// Implementations of traits for converting [`Rational`](crate::Rational)s to and from
 /// [`String`]s.
 pub mod string;
@@ -1,4 +1,6 @@
 use crate::Rational;
+use core::cmp::Ordering;
+use core::ops::Neg;
 use malachite_base::comparison::traits::{Max, Min};
 use malachite_base::named::Named;
 use malachite_base::num::arithmetic::traits::{DivRound, DivisibleByPowerOf2};
@@ -9,8 +11,6 @@ use malachite_base::num::logic::traits::SignificantBits;
 use malachite_base::rounding_modes::RoundingMode;
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use std::cmp::Ordering;
-use std::ops::Neg;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct UnsignedFromRationalError;
@@ -0,0 +1,94 @@
+/// A compact binary representation of [`Rational`]s, for use with serde's `with` attribute.
+///
+/// By default, a [`Rational`] is serialized as a struct containing its sign and its numerator and
+/// denominator as hexadecimal strings. With this module, it is serialized as a tuple containing
+/// the sign and the bytes of the numerator and denominator in little-endian order instead (see
+/// [`Natural::to_bytes_le`]), which keeps the output of binary formats like bincode or CBOR small
+/// and avoids base conversions.
+///
+/// Deserialization fails if the denominator is zero or if the fraction is not in lowest terms.
+///
+/// # Examples
+/// ```
+/// use malachite_q::Rational;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Point {
+///     #[serde(with = "malachite_q::conversion::serde::compact")]
+///     x: Rational,
+///     #[serde(with = "malachite_q::conversion::serde::compact")]
+///     y: Rational,
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub mod compact {
+    use crate::Rational;
+    use malachite_base::num::arithmetic::traits::CoprimeWith;
+    use malachite_nz::natural::conversion::serde::compact as compact_natural;
+    use malachite_nz::natural::Natural;
+    use serde::de::Error;
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    struct CompactNaturalRef<'a>(&'a Natural);
+
+    impl<'a> Serialize for CompactNaturalRef<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            compact_natural::serialize(self.0, serializer)
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct CompactRational(
+        bool,
+        #[serde(with = "compact_natural")] Natural,
+        #[serde(with = "compact_natural")] Natural,
+    );
+
+    /// Serializes a [`Rational`] as a tuple containing its sign and the bytes of its numerator
+    /// and denominator in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    pub fn serialize<S: Serializer>(x: &Rational, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&x.sign)?;
+        tuple.serialize_element(&CompactNaturalRef(&x.numerator))?;
+        tuple.serialize_element(&CompactNaturalRef(&x.denominator))?;
+        tuple.end()
+    }
+
+    /// Deserializes a [`Rational`] from a tuple containing its sign and the bytes of its
+    /// numerator and denominator in little-endian order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bytes.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rational, D::Error> {
+        let CompactRational(sign, numerator, denominator) =
+            CompactRational::deserialize(deserializer)?;
+        if denominator == 0u32 {
+            Err(D::Error::custom("The denominator is zero"))
+        } else if !(&numerator).coprime_with(&denominator) {
+            Err(D::Error::custom(
+                "The numerator and denominator are not coprime",
+            ))
+        } else if !sign && numerator == 0u32 {
+            Err(D::Error::custom("Zero is negative"))
+        } else {
+            Ok(Rational {
+                sign,
+                numerator,
+                denominator,
+            })
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::Rational;
-use std::fmt::{Debug, Display, Formatter, Result, Write};
+use core::fmt::{Debug, Display, Formatter, Result, Write};
 
 impl Display for Rational {
     /// Converts a [`Rational`] to a [`String`].
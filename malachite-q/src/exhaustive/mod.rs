@@ -1,4 +1,6 @@
 use crate::Rational;
+use core::iter::{once, Chain, Once};
+use core::mem::swap;
 use malachite_base::num::arithmetic::traits::{CoprimeWith, UnsignedAbs};
 use malachite_base::num::basic::traits::{One, Zero};
 use malachite_base::num::conversion::traits::RoundingFrom;
@@ -10,8 +12,6 @@ use malachite_nz::integer::exhaustive::{
 };
 use malachite_nz::integer::Integer;
 use malachite_nz::natural::Natural;
-use std::iter::{once, Chain, Once};
-use std::mem::swap;
 
 /// Generates all positive [`Rational`]s.
 ///
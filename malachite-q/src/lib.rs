@@ -56,7 +56,11 @@
 //!   doctests don't require it, since they only test the public interface.
 //! - `bin_build`: This feature is used to build the code for demos and benchmarks, which also
 //!   takes a long time to build. Enabling this feature also enables `test_build`.
+//! - `std`: Enabled by default. Without it, this crate only depends on `core` and `alloc`, so
+//!   [`Rational`]s can be used on targets that have an allocator but no standard library.
+//!   `test_build` and `bin_build` require it.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(
     unstable_name_collisions,
     clippy::assertions_on_constants,
@@ -94,6 +98,9 @@
     clippy::unused_self
 )]
 
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+extern crate libm;
 #[macro_use]
 extern crate malachite_base;
 extern crate malachite_nz;
@@ -0,0 +1,76 @@
+use malachite_base::num::arithmetic::traits::{
+    ExpressAsPower, IsPerfectPower, Parity, Pow, Reciprocal,
+};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::test_util::generators::integer_gen;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_express_as_power() {
+    let test = |n: &str, out: &str| {
+        let n = Rational::from_str(n).unwrap();
+        let result = n.express_as_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(n.is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("-1", "Some((-1, 3))");
+    test("2", "None");
+    test("64", "Some((2, 6))");
+    test("-64", "Some((-4, 3))");
+    test("1/2", "None");
+    test("1/4", "Some((1/2, 2))");
+    test("-1/4", "None");
+    test("-1/8", "Some((-1/2, 3))");
+    test("1/64", "Some((1/2, 6))");
+    test("-1/64", "Some((-1/4, 3))");
+    test("4/9", "Some((2/3, 2))");
+    test("-4/9", "None");
+    test("8/27", "Some((2/3, 3))");
+    test("4/27", "None");
+    test("16/81", "Some((2/3, 4))");
+    test("64/729", "Some((2/3, 6))");
+    test("-64/729", "Some((-4/9, 3))");
+    // 2^12 / 3^18
+    test("4096/387420489", "Some((4/27, 6))");
+    test("22/7", "None");
+    // 10^30 / 7^30
+    test(
+        "1000000000000000000000000000000/22539340290692258087863249",
+        "Some((10/7, 30))",
+    );
+}
+
+#[test]
+fn express_as_power_properties() {
+    rational_gen().test_properties(|n| {
+        let result = n.express_as_power();
+        assert_eq!(n.is_perfect_power(), result.is_some());
+        if let Some((x, e)) = &result {
+            assert!(*e > 1);
+            assert_eq!(x.pow(*e), n);
+            if n < 0u32 {
+                assert!(e.odd());
+            }
+            if n != 0u32 && n != 1u32 && n != -1 {
+                assert!(!x.is_perfect_power());
+            }
+        }
+        if n != 0u32 {
+            assert_eq!(
+                (&n).reciprocal().express_as_power(),
+                result.map(|(x, e)| (x.reciprocal(), e))
+            );
+        }
+    });
+
+    integer_gen().test_properties(|n| {
+        assert_eq!(
+            Rational::from(&n).express_as_power(),
+            n.express_as_power().map(|(x, e)| (Rational::from(x), e))
+        );
+    });
+}
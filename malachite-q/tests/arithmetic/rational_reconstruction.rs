@@ -0,0 +1,139 @@
+use malachite_base::num::arithmetic::traits::{CoprimeWith, ModInverse, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::cmp::max;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+// Returns the residue of `x` modulo `m`, where `x`'s denominator is coprime to `m`.
+fn rational_mod(x: &Rational, m: &Natural) -> Natural {
+    let d = x.to_denominator() % m;
+    let inverse = if *m == 1u32 {
+        d
+    } else {
+        d.mod_inverse(m).unwrap()
+    };
+    let r = (x.to_numerator() % m) * inverse % m;
+    if *x >= 0u32 || r == 0u32 {
+        r
+    } else {
+        m - r
+    }
+}
+
+#[test]
+fn test_rational_reconstruction() {
+    let test = |r, m, out| {
+        let r = Natural::from_str(r).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let x = Rational::rational_reconstruction(&r, &m);
+        assert_eq!(x.to_debug_string(), out);
+        if let Some(x) = x {
+            assert_eq!(rational_mod(&x, &m), r);
+        }
+    };
+    test("0", "1", "None");
+    test("0", "101", "Some(0)");
+    test("1", "101", "Some(1)");
+    test("100", "101", "Some(-1)");
+    test("68", "101", "Some(2/3)");
+    test("33", "101", "Some(-2/3)");
+    test("51", "101", "Some(1/2)");
+    test("50", "101", "Some(-1/2)");
+    test("8", "101", "None");
+    test("7", "101", "Some(7)");
+    test("3669", "10007", "Some(-7/30)");
+    test("4", "10", "None");
+    test("9", "10", "Some(-1)");
+    test("5", "10", "None");
+    test(
+        "2382260839730181774",
+        "18446744073709551557",
+        "Some(-1234567/2345678)",
+    );
+}
+
+#[test]
+fn rational_reconstruction_fail() {
+    assert_panic!(Rational::rational_reconstruction(
+        &Natural::from(0u32),
+        &Natural::from(0u32)
+    ));
+    assert_panic!(Rational::rational_reconstruction(
+        &Natural::from(10u32),
+        &Natural::from(10u32)
+    ));
+}
+
+#[test]
+fn test_rational_reconstruction_with_bounds() {
+    let test = |r, m, n, d, out| {
+        let r = Natural::from_str(r).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let d = Natural::from_str(d).unwrap();
+        let x = Rational::rational_reconstruction_with_bounds(&r, &m, &n, &d);
+        assert_eq!(x.to_debug_string(), out);
+        if let Some(x) = x {
+            assert_eq!(rational_mod(&x, &m), r);
+        }
+    };
+    test("3669", "10007", "1000", "5", "Some(1000/3)");
+    test("3669", "10007", "100", "50", "Some(-7/30)");
+    test("3669", "10007", "10", "10", "None");
+    test("68", "101", "0", "50", "None");
+    test("0", "101", "0", "50", "Some(0)");
+    test("5", "101", "50", "1", "Some(5)");
+    test("68", "101", "50", "1", "Some(-33)");
+    test("51", "101", "1", "50", "Some(1/2)");
+}
+
+#[test]
+fn rational_reconstruction_with_bounds_fail() {
+    assert_panic!(Rational::rational_reconstruction_with_bounds(
+        &Natural::from(3u32),
+        &Natural::from(101u32),
+        &Natural::from(10u32),
+        &Natural::from(10u32)
+    ));
+    assert_panic!(Rational::rational_reconstruction_with_bounds(
+        &Natural::from(101u32),
+        &Natural::from(101u32),
+        &Natural::from(1u32),
+        &Natural::from(1u32)
+    ));
+}
+
+#[test]
+fn rational_reconstruction_properties() {
+    rational_gen().test_properties(|x| {
+        // The modulus is coprime to the denominator and large enough for the default bounds.
+        let n = max(x.to_numerator(), x.to_denominator());
+        let m = n.square() * x.to_denominator() * Natural::from(2u32) + Natural::ONE;
+        let r = rational_mod(&x, &m);
+        assert_eq!(Rational::rational_reconstruction(&r, &m), Some(x.clone()));
+        let bound = x.to_numerator();
+        let d = x.to_denominator();
+        if (&bound * &d) << 1u32 < m {
+            assert_eq!(
+                Rational::rational_reconstruction_with_bounds(&r, &m, &bound, &d),
+                Some(x)
+            );
+        }
+    });
+
+    natural_pair_gen_var_8().test_properties(|(r, m)| {
+        if let Some(x) = Rational::rational_reconstruction(&r, &m) {
+            let d = x.to_denominator();
+            assert!((&d).coprime_with(&m));
+            assert_eq!(rational_mod(&x, &m), r);
+            let bound = (&m - Natural::ONE) >> 1u32;
+            assert!(x.to_numerator().square() <= bound);
+            assert!(d.square() <= bound);
+        }
+    });
+}
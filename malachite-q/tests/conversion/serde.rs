@@ -2,6 +2,7 @@ use malachite_base::strings::string_is_subset;
 use malachite_base::test_util::generators::string_gen;
 use malachite_q::test_util::generators::{rational_gen, string_gen_var_11};
 use malachite_q::Rational;
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
 #[test]
@@ -55,6 +56,37 @@ fn test_serde() {
     test("-22/7", "{\"s\":false,\"n\":\"0x16\",\"d\":\"0x7\"}");
 }
 
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct CompactRational(#[serde(with = "malachite_q::conversion::serde::compact")] Rational);
+
+#[test]
+fn test_serde_compact() {
+    let test = |n, out| {
+        let x = CompactRational(Rational::from_str(n).unwrap());
+        assert_eq!(serde_json::to_string(&x).unwrap(), out);
+        assert_eq!(serde_json::from_str::<CompactRational>(out).unwrap(), x);
+    };
+    test("0", "[true,[],[1]]");
+    test("100", "[true,[100],[1]]");
+    test("1000000000000", "[true,[0,16,165,212,232],[1]]");
+    test("22/7", "[true,[22],[7]]");
+    test("-100", "[false,[100],[1]]");
+    test("-22/7", "[false,[22],[7]]");
+    test("-1/256", "[false,[1],[0,1]]");
+}
+
+#[test]
+fn test_serde_compact_fail() {
+    let test = |s| {
+        assert!(serde_json::from_str::<CompactRational>(s).is_err());
+    };
+    test("[true,[1],[]]");
+    test("[true,[2],[4]]");
+    test("[false,[],[1]]");
+    test("[true,[1]]");
+    test("[true,[256],[1]]");
+}
+
 #[test]
 fn serde_properties() {
     rational_gen().test_properties(|x| {
@@ -71,3 +103,13 @@ fn serde_properties() {
         let _n: Rational = serde_json::from_str(&s).unwrap();
     });
 }
+
+#[test]
+fn serde_compact_properties() {
+    rational_gen().test_properties(|x| {
+        let x = CompactRational(x);
+        let s = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<CompactRational>(&s).unwrap(), x);
+        assert!(string_is_subset(&s, ",0123456789[]aeflrstu"));
+    });
+}
@@ -54,6 +54,7 @@ pub mod arithmetic {
     pub mod denominators_in_closed_interval;
     pub mod div;
     pub mod floor;
+    pub mod is_perfect_power;
     pub mod is_power_of_2;
     pub mod log_base;
     pub mod log_base_2;
@@ -63,6 +64,7 @@ pub mod arithmetic {
     pub mod next_power_of_2;
     pub mod pow;
     pub mod power_of_2;
+    pub mod rational_reconstruction;
     pub mod reciprocal;
     pub mod root;
     pub mod round_to_multiple;
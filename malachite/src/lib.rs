@@ -1,3 +1,5 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
 pub use malachite_base::*;
 
 #[cfg(feature = "naturals_and_integers")]